@@ -16,10 +16,12 @@ use ibc_chain_registry::fetchable::Fetchable;
 use ibc_chain_registry::formatter::{SimpleGrpcFormatter, UriFormatter};
 use ibc_chain_registry::paths::IBCPath;
 use ibc_chain_registry::querier::*;
+use ibc_relayer::config::dynamic_gas::DynamicGasPrice;
+use ibc_relayer::config::excluded_sequences::ExcludedSequences;
 use ibc_relayer::config::filter::{FilterPattern, PacketFilter};
 use ibc_relayer::config::gas_multiplier::GasMultiplier;
 use ibc_relayer::config::types::{MaxMsgNum, MaxTxSize, Memo};
-use ibc_relayer::config::{default, AddressType, ChainConfig, GasPrice};
+use ibc_relayer::config::{default, AddressType, ChainConfig, EventSourceMode, GasPrice};
 use ibc_relayer::keyring::Store;
 
 use tendermint_light_client_verifier::types::TrustThreshold;
@@ -118,12 +120,14 @@ where
         rpc_addr: rpc_data.rpc_address,
         websocket_addr: websocket_address,
         grpc_addr: grpc_address,
+        endpoint_health_check_interval: default::endpoint_health_check_interval(),
         rpc_timeout: default::rpc_timeout(),
         batch_delay: default::batch_delay(),
         trusted_node: default::trusted_node(),
         genesis_restart: None,
         account_prefix: chain_data.bech32_prefix,
         key_name: String::new(),
+        key_names: Vec::new(),
         key_store_type: Store::default(),
         key_store_folder: None,
         store_prefix: "ibc".to_string(),
@@ -146,10 +150,16 @@ where
             price: 0.1,
             denom: asset.base.to_owned(),
         },
+        dynamic_gas_price: DynamicGasPrice::default(),
+        event_source: EventSourceMode::default(),
         packet_filter: packet_filter.unwrap_or_default(),
+        excluded_sequences: ExcludedSequences::default(),
+        remote_signer: None,
         address_type: AddressType::default(),
         sequential_batch_tx: false,
         extension_options: Vec::new(),
+        backup_endpoints: Vec::new(),
+        channel_overrides: Vec::new(),
     })
 }
 
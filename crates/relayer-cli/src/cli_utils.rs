@@ -2,12 +2,19 @@
 
 use alloc::sync::Arc;
 use eyre::eyre;
+use serde_json::Value;
 use tokio::runtime::Runtime as TokioRuntime;
 use tracing::debug;
 
+use ibc_proto::google::protobuf::Any;
+use ibc_relayer::chain::cosmos::encode::encode_unsigned_tx;
+use ibc_relayer::chain::cosmos::gas::gas_amount_to_fee;
+use ibc_relayer::chain::cosmos::tx_json::{set_messages_signer, tx_to_json};
+use ibc_relayer::chain::cosmos::types::config::TxConfig;
 use ibc_relayer::chain::requests::{
     IncludeProof, QueryChannelRequest, QueryClientStateRequest, QueryConnectionRequest, QueryHeight,
 };
+use ibc_relayer::chain::ChainType;
 use ibc_relayer::{
     chain::{
         counterparty::{channel_connection_client, ChannelConnectionClient},
@@ -186,3 +193,49 @@ pub fn check_can_send_on_channel<Chain: ChainHandle>(
 
     Ok(())
 }
+
+/// Builds an unsigned transaction carrying the given messages, in the JSON format
+/// of the Cosmos SDK, to be signed offline, eg. with `<chain binary> tx multisign`,
+/// and then submitted with `hermes tx broadcast`.
+///
+/// The fee is computed from the `default_gas` and `gas_price` configured for the chain.
+/// If a signer is given, it replaces the relayer's account as the signer of the messages.
+pub fn unsigned_tx_json(
+    config: &Config,
+    chain_id: &ChainId,
+    msgs: &[Any],
+    signer: Option<&str>,
+) -> Result<Value, Error> {
+    let chain_config = config
+        .find_chain(chain_id)
+        .ok_or_else(|| Error::missing_chain_config(chain_id.clone()))?;
+
+    if chain_config.r#type != ChainType::CosmosSdk {
+        return Err(Error::cli_arg(format!(
+            "unsigned transactions can only be generated for Cosmos SDK chains, chain '{}' is of type {:?}",
+            chain_id, chain_config.r#type
+        )));
+    }
+
+    let tx_config = TxConfig::try_from(chain_config).map_err(Error::relayer)?;
+    let fee = gas_amount_to_fee(&tx_config.gas_config, tx_config.gas_config.default_gas);
+
+    let tx = encode_unsigned_tx(&tx_config, &chain_config.memo_prefix, msgs, &fee)
+        .map_err(Error::relayer)?;
+
+    let mut json = tx_to_json(&tx).map_err(Error::relayer)?;
+
+    if let Some(signer) = signer {
+        set_messages_signer(&mut json, signer);
+    }
+
+    Ok(json)
+}
+
+/// Converts the given messages into the JSON format of the Cosmos SDK, eg. to submit
+/// them in a governance proposal, as the `messages` of the proposal file given to
+/// `<chain binary> tx gov submit-proposal`.
+pub fn messages_json(config: &Config, chain_id: &ChainId, msgs: &[Any]) -> Result<Value, Error> {
+    let mut tx = unsigned_tx_json(config, chain_id, msgs, None)?;
+    Ok(tx["body"]["messages"].take())
+}
@@ -10,6 +10,7 @@ mod keys;
 mod listen;
 mod misbehaviour;
 mod query;
+mod reconcile;
 mod start;
 mod tx;
 mod update;
@@ -19,8 +20,8 @@ mod version;
 use self::{
     clear::ClearCmds, completions::CompletionsCmd, config::ConfigCmd, create::CreateCmds,
     fee::FeeCmd, health::HealthCheckCmd, keys::KeysCmd, listen::ListenCmd,
-    misbehaviour::MisbehaviourCmd, query::QueryCmd, start::StartCmd, tx::TxCmd, update::UpdateCmds,
-    upgrade::UpgradeCmds, version::VersionCmd,
+    misbehaviour::MisbehaviourCmd, query::QueryCmd, reconcile::ReconcileCmd, start::StartCmd,
+    tx::TxCmd, update::UpdateCmds, upgrade::UpgradeCmds, version::VersionCmd,
 };
 
 use core::time::Duration;
@@ -70,6 +71,9 @@ pub enum CliCmd {
     /// Relays packets and open handshake messages between all chains in the config.
     Start(StartCmd),
 
+    /// Create the clients, connections and channels of the paths in the config which do not exist yet
+    Reconcile(ReconcileCmd),
+
     /// Query objects from the chain
     #[clap(subcommand)]
     Query(QueryCmd),
@@ -63,6 +63,12 @@ pub struct ClearPacketsCmd {
         help = "use the given signing key for the counterparty chain (default: `counterparty_key_name` config)"
     )]
     counterparty_key_name: Option<String>,
+
+    #[clap(
+        long = "dry-run",
+        help = "Simulate the transactions to estimate their fees, without submitting them"
+    )]
+    dry_run: bool,
 }
 
 impl Override<Config> for ClearPacketsCmd {
@@ -76,6 +82,8 @@ impl Override<Config> for ClearPacketsCmd {
 
         if let Some(ref key_name) = self.key_name {
             chain_config.key_name = key_name.to_string();
+            // Only submit transactions with the given key
+            chain_config.key_names.clear();
         }
 
         Ok(config)
@@ -117,12 +125,12 @@ impl Runnable for ClearPacketsCmd {
 
         let fwd_link = match Link::new_from_opts(chains.src.clone(), chains.dst, opts, false, false)
         {
-            Ok(link) => link,
+            Ok(link) => link.with_dry_run(self.dry_run),
             Err(e) => Output::error(e).exit(),
         };
 
         let rev_link = match fwd_link.reverse(false, false) {
-            Ok(link) => link,
+            Ok(link) => link.with_dry_run(self.dry_run),
             Err(e) => Output::error(e).exit(),
         };
 
@@ -143,6 +151,13 @@ impl Runnable for ClearPacketsCmd {
             rev_link.relay_ack_packet_messages()
         });
 
+        if self.dry_run {
+            let mut simulated_txs = fwd_link.a_to_b.take_simulated_txs();
+            simulated_txs.extend(rev_link.a_to_b.take_simulated_txs());
+
+            Output::success(simulated_txs).exit()
+        }
+
         Output::success(ev_list).exit()
     }
 }
@@ -175,6 +190,7 @@ mod tests {
                 channel_id: ChannelId::from_str("channel-07").unwrap(),
                 key_name: None,
                 counterparty_key_name: None,
+                dry_run: false,
             },
             ClearPacketsCmd::parse_from([
                 "test",
@@ -196,7 +212,8 @@ mod tests {
                 port_id: PortId::from_str("port_id").unwrap(),
                 channel_id: ChannelId::from_str("channel-07").unwrap(),
                 key_name: None,
-                counterparty_key_name: None
+                counterparty_key_name: None,
+                dry_run: false,
             },
             ClearPacketsCmd::parse_from([
                 "test",
@@ -219,6 +236,7 @@ mod tests {
                 channel_id: ChannelId::from_str("channel-07").unwrap(),
                 key_name: Some("key_name".to_owned()),
                 counterparty_key_name: None,
+                dry_run: false,
             },
             ClearPacketsCmd::parse_from([
                 "test",
@@ -243,6 +261,7 @@ mod tests {
                 channel_id: ChannelId::from_str("channel-07").unwrap(),
                 key_name: None,
                 counterparty_key_name: Some("counterparty_key_name".to_owned()),
+                dry_run: false,
             },
             ClearPacketsCmd::parse_from([
                 "test",
@@ -258,6 +277,30 @@ mod tests {
         )
     }
 
+    #[test]
+    fn test_clear_packets_dry_run() {
+        assert_eq!(
+            ClearPacketsCmd {
+                chain_id: ChainId::from_string("chain_id"),
+                port_id: PortId::from_str("port_id").unwrap(),
+                channel_id: ChannelId::from_str("channel-07").unwrap(),
+                key_name: None,
+                counterparty_key_name: None,
+                dry_run: true,
+            },
+            ClearPacketsCmd::parse_from([
+                "test",
+                "--chain",
+                "chain_id",
+                "--port",
+                "port_id",
+                "--channel",
+                "channel-07",
+                "--dry-run"
+            ])
+        )
+    }
+
     #[test]
     fn test_clear_packets_no_chan() {
         assert!(ClearPacketsCmd::try_parse_from([
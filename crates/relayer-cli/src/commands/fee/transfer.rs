@@ -160,6 +160,8 @@ impl Override<Config> for FeeTransferCmd {
 
         if let Some(ref key_name) = self.key_name {
             src_chain_config.key_name = key_name.to_string();
+            // Only submit transactions with the given key
+            src_chain_config.key_names.clear();
         }
 
         Ok(config)
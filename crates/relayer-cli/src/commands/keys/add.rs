@@ -12,9 +12,7 @@ use hdpath::StandardHDPath;
 use ibc_relayer::{
     chain::ChainType,
     config::{ChainConfig, Config},
-    keyring::{
-        AnySigningKeyPair, KeyRing, Secp256k1KeyPair, SigningKeyPair, SigningKeyPairSized, Store,
-    },
+    keyring::{AnySigningKeyPair, KeyRing, Secp256k1KeyPair, SigningKeyPair, SigningKeyPairSized},
 };
 use ibc_relayer_types::core::ics24_host::identifier::ChainId;
 use tracing::warn;
@@ -199,12 +197,13 @@ pub fn add_key(
     overwrite: bool,
 ) -> eyre::Result<AnySigningKeyPair> {
     let key_pair = match config.r#type {
-        ChainType::CosmosSdk => {
+        ChainType::CosmosSdk | ChainType::Mock | ChainType::SoloMachine => {
             let mut keyring = KeyRing::new_secp256k1(
-                Store::Test,
+                config.key_store_type.persistent(),
                 &config.account_prefix,
                 &config.id,
                 &config.key_store_folder,
+                &config.remote_signer,
             )?;
 
             check_key_exists(&keyring, key_name, overwrite);
@@ -232,12 +231,13 @@ pub fn restore_key(
         fs::read_to_string(mnemonic).map_err(|_| eyre!("error reading the mnemonic file"))?;
 
     let key_pair = match config.r#type {
-        ChainType::CosmosSdk => {
+        ChainType::CosmosSdk | ChainType::Mock | ChainType::SoloMachine => {
             let mut keyring = KeyRing::new_secp256k1(
-                Store::Test,
+                config.key_store_type.persistent(),
                 &config.account_prefix,
                 &config.id,
                 &config.key_store_folder,
+                &config.remote_signer,
             )?;
 
             check_key_exists(&keyring, key_name, overwrite);
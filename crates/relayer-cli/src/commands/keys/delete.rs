@@ -5,7 +5,7 @@ use eyre::eyre;
 use ibc_relayer::{
     chain::ChainType,
     config::{ChainConfig, Config},
-    keyring::{KeyRing, Store},
+    keyring::KeyRing,
 };
 use ibc_relayer_types::core::ics24_host::identifier::ChainId;
 
@@ -114,12 +114,13 @@ impl Runnable for KeysDeleteCmd {
 
 pub fn delete_key(config: &ChainConfig, key_name: &str) -> eyre::Result<()> {
     match config.r#type {
-        ChainType::CosmosSdk => {
+        ChainType::CosmosSdk | ChainType::Mock | ChainType::SoloMachine => {
             let mut keyring = KeyRing::new_secp256k1(
-                Store::Test,
+                config.key_store_type.persistent(),
                 &config.account_prefix,
                 &config.id,
                 &config.key_store_folder,
+                &config.remote_signer,
             )?;
             keyring.remove_key(key_name)?;
         }
@@ -129,12 +130,13 @@ pub fn delete_key(config: &ChainConfig, key_name: &str) -> eyre::Result<()> {
 
 pub fn delete_all_keys(config: &ChainConfig) -> eyre::Result<()> {
     match config.r#type {
-        ChainType::CosmosSdk => {
+        ChainType::CosmosSdk | ChainType::Mock | ChainType::SoloMachine => {
             let mut keyring = KeyRing::new_secp256k1(
-                Store::Test,
+                config.key_store_type.persistent(),
                 &config.account_prefix,
                 &config.id,
                 &config.key_store_folder,
+                &config.remote_signer,
             )?;
             let keys = keyring.keys()?;
             for (key_name, _) in keys {
@@ -0,0 +1,190 @@
+use abscissa_core::clap::Parser;
+use abscissa_core::{Command, Runnable};
+
+use eyre::eyre;
+use ibc_relayer::{
+    chain::ChainType,
+    config::ChainConfig,
+    keyring::{errors::ErrorDetail, KeyRing, SigningKeyPair, Store},
+};
+use ibc_relayer_types::core::ics24_host::identifier::ChainId;
+use tracing::{info, warn};
+
+use crate::application::app_config;
+use crate::conclude::Output;
+
+/// Encrypt the keys of a chain which are stored in plaintext on disk
+/// (`Test` key store) by moving them into the encrypted `File` key store.
+///
+/// The passphrase used to encrypt the keys is read from the environment,
+/// in the same way as when Hermes uses the `File` key store.
+#[derive(Clone, Command, Debug, Parser, PartialEq, Eq)]
+#[clap(
+    override_usage = "hermes keys migrate --chain <CHAIN_ID> [--delete-plaintext] [--overwrite]"
+)]
+pub struct KeysMigrateCmd {
+    #[clap(
+        long = "chain",
+        required = true,
+        value_name = "CHAIN_ID",
+        help_heading = "REQUIRED",
+        help = "Identifier of the chain whose keys to migrate"
+    )]
+    chain_id: ChainId,
+
+    #[clap(
+        long = "delete-plaintext",
+        help = "Delete the plaintext key files once they have been migrated"
+    )]
+    delete_plaintext: bool,
+
+    #[clap(
+        long = "overwrite",
+        help = "Overwrite the keys which already exist in the encrypted key store"
+    )]
+    overwrite: bool,
+}
+
+impl Runnable for KeysMigrateCmd {
+    fn run(&self) {
+        let config = app_config();
+
+        let chain_config = match config.find_chain(&self.chain_id) {
+            Some(chain_config) => chain_config,
+            None => Output::error(format!(
+                "chain '{}' not found in configuration file",
+                self.chain_id
+            ))
+            .exit(),
+        };
+
+        match migrate_keys(chain_config, self.delete_plaintext, self.overwrite) {
+            Ok(migrated) if migrated.is_empty() => {
+                Output::success_msg(format!("No key to migrate on chain {}", chain_config.id))
+                    .exit()
+            }
+            Ok(migrated) => Output::success_msg(format!(
+                "Migrated keys ({}) on chain {} to the encrypted key store",
+                migrated.join(", "),
+                chain_config.id
+            ))
+            .exit(),
+            Err(e) => Output::error(e).exit(),
+        }
+    }
+}
+
+/// Copy the keys of the `Test` key store into the `File` key store,
+/// and return the names of the keys which were migrated.
+pub fn migrate_keys(
+    config: &ChainConfig,
+    delete_plaintext: bool,
+    overwrite: bool,
+) -> eyre::Result<Vec<String>> {
+    match config.r#type {
+        ChainType::CosmosSdk | ChainType::Mock | ChainType::SoloMachine => {
+            let mut plaintext = KeyRing::new_secp256k1(
+                Store::Test,
+                &config.account_prefix,
+                &config.id,
+                &config.key_store_folder,
+                &config.remote_signer,
+            )?;
+
+            let mut encrypted = KeyRing::new_secp256k1(
+                Store::File,
+                &config.account_prefix,
+                &config.id,
+                &config.key_store_folder,
+                &config.remote_signer,
+            )?;
+
+            let mut migrated = Vec::new();
+
+            for (key_name, key_pair) in plaintext.keys()? {
+                let exists = match encrypted.get_key(&key_name) {
+                    Ok(_) => true,
+                    Err(e) if matches!(e.detail(), ErrorDetail::KeyFileNotFound(_)) => false,
+                    // Fail rather than overwrite a key which cannot be decrypted,
+                    // eg. because it was encrypted with another passphrase
+                    Err(e) => return Err(e.into()),
+                };
+
+                if exists {
+                    if !overwrite {
+                        warn!("key '{key_name}' already exists in the encrypted key store, skipping it (use --overwrite to replace it)");
+                        continue;
+                    }
+
+                    encrypted.remove_key(&key_name)?;
+                }
+
+                encrypted.add_key(&key_name, key_pair.clone())?;
+
+                // Make sure the key can be decrypted before deleting the plaintext copy
+                let decrypted = encrypted.get_key(&key_name)?;
+                if decrypted.account() != key_pair.account() {
+                    return Err(eyre!(
+                        "key '{key_name}' read back from the encrypted key store does not match the original key"
+                    ));
+                }
+
+                if delete_plaintext {
+                    plaintext.remove_key(&key_name)?;
+                    info!("deleted plaintext key file of key '{key_name}'");
+                }
+
+                migrated.push(key_name);
+            }
+
+            if !delete_plaintext && !migrated.is_empty() {
+                warn!("the plaintext key files were kept, remove them with --delete-plaintext once Hermes is configured to use the encrypted key store");
+            }
+
+            Ok(migrated)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::KeysMigrateCmd;
+
+    use abscissa_core::clap::Parser;
+    use ibc_relayer_types::core::ics24_host::identifier::ChainId;
+
+    #[test]
+    fn test_keys_migrate() {
+        assert_eq!(
+            KeysMigrateCmd {
+                chain_id: ChainId::from_string("chain_id"),
+                delete_plaintext: false,
+                overwrite: false,
+            },
+            KeysMigrateCmd::parse_from(["test", "--chain", "chain_id"])
+        )
+    }
+
+    #[test]
+    fn test_keys_migrate_delete_plaintext_overwrite() {
+        assert_eq!(
+            KeysMigrateCmd {
+                chain_id: ChainId::from_string("chain_id"),
+                delete_plaintext: true,
+                overwrite: true,
+            },
+            KeysMigrateCmd::parse_from([
+                "test",
+                "--chain",
+                "chain_id",
+                "--delete-plaintext",
+                "--overwrite"
+            ])
+        )
+    }
+
+    #[test]
+    fn test_keys_migrate_no_chain() {
+        assert!(KeysMigrateCmd::try_parse_from(["test"]).is_err())
+    }
+}
@@ -3,6 +3,7 @@ use core::{
     fmt::{Display, Error as FmtError, Formatter},
     ops::Deref,
     str::FromStr,
+    time::Duration,
 };
 use std::thread;
 
@@ -14,7 +15,11 @@ use tendermint_rpc::{client::CompatMode, Client, HttpClient};
 use tokio::runtime::Runtime as TokioRuntime;
 use tracing::{error, info, instrument};
 
-use ibc_relayer::{chain::handle::Subscription, config::ChainConfig, event::monitor::EventMonitor};
+use ibc_relayer::{
+    chain::handle::Subscription,
+    config::{ChainConfig, EventSourceMode},
+    event::monitor::{pull::PullEventMonitor, EventMonitor, TxMonitorCmd},
+};
 use ibc_relayer_types::{core::ics24_host::identifier::ChainId, events::IbcEvent};
 
 use crate::prelude::*;
@@ -140,6 +145,22 @@ fn subscribe(
     compat_mode: CompatMode,
     rt: Arc<TokioRuntime>,
 ) -> eyre::Result<Subscription> {
+    let tx_cmd = match chain_config.event_source {
+        EventSourceMode::Push { .. } => spawn_push_monitor(chain_config, compat_mode, rt)?,
+        EventSourceMode::Pull { interval } => {
+            spawn_pull_monitor(chain_config, compat_mode, interval, rt)?
+        }
+    };
+
+    let subscription = tx_cmd.subscribe()?;
+    Ok(subscription)
+}
+
+fn spawn_push_monitor(
+    chain_config: &ChainConfig,
+    compat_mode: CompatMode,
+    rt: Arc<TokioRuntime>,
+) -> eyre::Result<TxMonitorCmd> {
     let (mut event_monitor, tx_cmd) = EventMonitor::new(
         chain_config.id.clone(),
         chain_config.websocket_addr.clone(),
@@ -158,8 +179,27 @@ fn subscribe(
 
     thread::spawn(|| event_monitor.run());
 
-    let subscription = tx_cmd.subscribe()?;
-    Ok(subscription)
+    Ok(tx_cmd)
+}
+
+fn spawn_pull_monitor(
+    chain_config: &ChainConfig,
+    compat_mode: CompatMode,
+    interval: Duration,
+    rt: Arc<TokioRuntime>,
+) -> eyre::Result<TxMonitorCmd> {
+    let mut rpc_client = HttpClient::new(chain_config.rpc_addr.clone())?;
+    rpc_client.set_compat_mode(compat_mode);
+
+    let (event_monitor, tx_cmd) =
+        PullEventMonitor::new(chain_config.id.clone(), rpc_client, interval, rt)
+            .map_err(|e| eyre!("could not initialize event monitor: {}", e))?;
+
+    info!("polling for new blocks every {:?}", interval);
+
+    thread::spawn(|| event_monitor.run());
+
+    Ok(tx_cmd)
 }
 
 fn detect_compatibility_mode(
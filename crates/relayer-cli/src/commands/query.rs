@@ -16,6 +16,8 @@ mod client;
 mod clients;
 mod connection;
 mod connections;
+mod economics;
+mod ica;
 mod packet;
 mod transfer;
 mod tx;
@@ -48,6 +50,9 @@ pub enum QueryCmd {
     #[clap(subcommand)]
     Packet(QueryPacketCmds),
 
+    /// Query the address of an interchain account on a host chain
+    IcaAddress(ica::QueryIcaAddressCmd),
+
     /// Query information about transactions
     #[clap(subcommand)]
     Tx(tx::QueryTxCmd),
@@ -55,6 +60,9 @@ pub enum QueryCmd {
     /// Query information about token transfers
     #[clap(subcommand)]
     Transfer(transfer::TransferCmd),
+
+    /// Report the fees paid and the ICS29 incentives earned per channel
+    RelayerEconomics(economics::QueryRelayerEconomicsCmd),
 }
 
 #[derive(Command, Debug, Parser, Runnable)]
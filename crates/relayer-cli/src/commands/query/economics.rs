@@ -0,0 +1,140 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use abscissa_core::clap::Parser;
+use abscissa_core::{Command, Runnable};
+use itertools::Itertools;
+
+use ibc_relayer::economics::{aggregate, ledger_path, read_ledger, ChannelEconomics};
+use ibc_relayer_types::core::ics24_host::identifier::ChainId;
+
+use crate::application::app_config;
+use crate::conclude::{json, Output};
+use crate::error::Error;
+
+/// The data structure that represents the arguments when invoking the `query relayer-economics` CLI command.
+///
+/// The command has the following format:
+///
+/// `query relayer-economics [--since <DURATION>] [--chain <CHAIN_ID>]`
+///
+/// It reports the fees paid and the ICS29 incentives earned by Hermes, per chain, channel and denom,
+/// as recorded in the economics ledger by `hermes start`.
+#[derive(Clone, Command, Debug, Parser, PartialEq, Eq)]
+pub struct QueryRelayerEconomicsCmd {
+    #[clap(
+        long = "since",
+        value_name = "DURATION",
+        help = "Only report on the transactions committed within this duration from now, eg. '24h' or '7days'"
+    )]
+    since: Option<humantime::Duration>,
+
+    #[clap(
+        long = "chain",
+        value_name = "CHAIN_ID",
+        help = "Only report on the transactions submitted to this chain"
+    )]
+    chain_id: Option<ChainId>,
+}
+
+impl Runnable for QueryRelayerEconomicsCmd {
+    fn run(&self) {
+        match self.execute() {
+            Ok(report) if json() => Output::success(report).exit(),
+            Ok(report) => Output::success_msg(format_report(&report)).exit(),
+            Err(e) => Output::error(e).exit(),
+        }
+    }
+}
+
+impl QueryRelayerEconomicsCmd {
+    fn execute(&self) -> Result<Vec<ChannelEconomics>, Error> {
+        let config = app_config();
+
+        if !config.economics.enabled {
+            return Err(Error::cli_arg(
+                "the economics ledger is not enabled, set `enabled = true` in the `[economics]` section of the configuration".to_string(),
+            ));
+        }
+
+        let path = ledger_path(&config.economics).map_err(Error::economics)?;
+
+        if !path.exists() {
+            return Ok(vec![]);
+        }
+
+        let entries = read_ledger(&path).map_err(Error::economics)?;
+
+        let since = self.since.map(|since| {
+            let now = SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .unwrap_or_default();
+
+            now.saturating_sub(*since).as_secs()
+        });
+
+        Ok(aggregate(&entries, since, self.chain_id.as_ref()))
+    }
+}
+
+fn format_report(report: &[ChannelEconomics]) -> String {
+    if report.is_empty() {
+        return "no fees paid nor incentives earned".to_string();
+    }
+
+    report
+        .iter()
+        .map(|economics| {
+            let channel = match (&economics.port_id, &economics.channel_id) {
+                (Some(port_id), Some(channel_id)) => format!("{port_id}/{channel_id}"),
+                _ => "no channel".to_string(),
+            };
+
+            format!(
+                "{} {} {}: paid {}, earned {}, net {}",
+                economics.chain_id,
+                channel,
+                economics.denom,
+                economics.fees_paid,
+                economics.incentives_earned,
+                economics.net
+            )
+        })
+        .join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::QueryRelayerEconomicsCmd;
+
+    use std::str::FromStr;
+
+    use abscissa_core::clap::Parser;
+    use ibc_relayer_types::core::ics24_host::identifier::ChainId;
+
+    #[test]
+    fn test_query_relayer_economics() {
+        assert_eq!(
+            QueryRelayerEconomicsCmd {
+                since: None,
+                chain_id: None,
+            },
+            QueryRelayerEconomicsCmd::parse_from(["test"])
+        )
+    }
+
+    #[test]
+    fn test_query_relayer_economics_since_chain() {
+        assert_eq!(
+            QueryRelayerEconomicsCmd {
+                since: Some(humantime::Duration::from_str("24h").unwrap()),
+                chain_id: Some(ChainId::from_string("chain_id")),
+            },
+            QueryRelayerEconomicsCmd::parse_from(["test", "--since", "24h", "--chain", "chain_id"])
+        )
+    }
+
+    #[test]
+    fn test_query_relayer_economics_invalid_since() {
+        assert!(QueryRelayerEconomicsCmd::try_parse_from(["test", "--since", "yesterday"]).is_err())
+    }
+}
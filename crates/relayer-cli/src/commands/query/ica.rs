@@ -0,0 +1,148 @@
+use core::str::FromStr;
+
+use abscissa_core::clap::Parser;
+use abscissa_core::{Command, Runnable};
+use http::Uri;
+use tokio::runtime::Runtime as TokioRuntime;
+
+use ibc_relayer::chain::cosmos::query::ica::query_interchain_account;
+use ibc_relayer::chain::ChainType;
+use ibc_relayer_types::core::ics24_host::identifier::{ChainId, ConnectionId};
+use ibc_relayer_types::signer::Signer;
+
+use crate::application::app_config;
+use crate::conclude::{json, Output};
+use crate::error::Error;
+
+/// The data structure that represents the arguments when invoking the `query ica-address` CLI command.
+///
+/// The command has the following format:
+///
+/// `query ica-address --chain <CHAIN_ID> --connection <CONNECTION_ID> --owner <OWNER>`
+///
+/// If successful the address of the interchain account on the host chain will be displayed.
+#[derive(Clone, Command, Debug, Parser, PartialEq, Eq)]
+pub struct QueryIcaAddressCmd {
+    #[clap(
+        long = "chain",
+        required = true,
+        value_name = "CHAIN_ID",
+        help_heading = "REQUIRED",
+        help = "Identifier of the controller chain"
+    )]
+    chain_id: ChainId,
+
+    #[clap(
+        long = "connection",
+        visible_alias = "conn",
+        required = true,
+        value_name = "CONNECTION_ID",
+        help_heading = "REQUIRED",
+        help = "Identifier of the connection to the host chain"
+    )]
+    connection_id: ConnectionId,
+
+    #[clap(
+        long = "owner",
+        required = true,
+        value_name = "OWNER",
+        help_heading = "REQUIRED",
+        help = "Address of the account owning the interchain account on the controller chain"
+    )]
+    owner: String,
+}
+
+impl Runnable for QueryIcaAddressCmd {
+    fn run(&self) {
+        match self.execute() {
+            Ok(address) if json() => Output::success(address).exit(),
+            Ok(address) => Output::success_msg(address).exit(),
+            Err(e) => Output::error(e).exit(),
+        }
+    }
+}
+
+impl QueryIcaAddressCmd {
+    fn execute(&self) -> Result<String, Error> {
+        let config = app_config();
+
+        let chain_config = config
+            .find_chain(&self.chain_id)
+            .ok_or_else(|| Error::missing_chain_config(self.chain_id.clone()))?;
+
+        if chain_config.r#type != ChainType::CosmosSdk {
+            return Err(Error::cli_arg(format!(
+                "interchain accounts can only be queried on Cosmos SDK chains, chain '{}' is of type {:?}",
+                self.chain_id, chain_config.r#type
+            )));
+        }
+
+        let owner = Signer::from_str(&self.owner).map_err(Error::signer)?;
+
+        let grpc_address = Uri::from_str(&chain_config.grpc_addr.to_string()).map_err(|e| {
+            Error::cli_arg(format!(
+                "invalid gRPC address '{}': {e}",
+                chain_config.grpc_addr
+            ))
+        })?;
+
+        let rt = TokioRuntime::new().map_err(Error::io)?;
+
+        let address = rt
+            .block_on(query_interchain_account(
+                &grpc_address,
+                &owner,
+                &self.connection_id,
+            ))
+            .map_err(Error::relayer)?;
+
+        address.ok_or_else(|| {
+            Error::cli_arg(format!(
+                "no interchain account is registered for owner '{}' on connection '{}'",
+                self.owner, self.connection_id
+            ))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::QueryIcaAddressCmd;
+
+    use abscissa_core::clap::Parser;
+    use std::str::FromStr;
+
+    use ibc_relayer_types::core::ics24_host::identifier::{ChainId, ConnectionId};
+
+    #[test]
+    fn test_query_ica_address() {
+        assert_eq!(
+            QueryIcaAddressCmd {
+                chain_id: ChainId::from_string("chain_a"),
+                connection_id: ConnectionId::from_str("connection-0").unwrap(),
+                owner: "cosmos1owner".to_owned(),
+            },
+            QueryIcaAddressCmd::parse_from([
+                "test",
+                "--chain",
+                "chain_a",
+                "--connection",
+                "connection-0",
+                "--owner",
+                "cosmos1owner"
+            ])
+        )
+    }
+
+    #[test]
+    fn test_query_ica_address_no_owner() {
+        assert!(QueryIcaAddressCmd::try_parse_from([
+            "test",
+            "--chain",
+            "chain_a",
+            "--connection",
+            "connection-0"
+        ])
+        .is_err())
+    }
+}
@@ -12,6 +12,7 @@ pub use ibc_relayer::chain::counterparty::PendingPackets;
 pub struct CollatedPendingPackets {
     pub unreceived_packets: Vec<Collated<Sequence>>,
     pub unreceived_acks: Vec<Collated<Sequence>>,
+    pub excluded: Vec<Collated<Sequence>>,
 }
 
 impl fmt::Debug for CollatedPendingPackets {
@@ -19,6 +20,7 @@ impl fmt::Debug for CollatedPendingPackets {
         f.debug_struct("PendingPackets")
             .field("unreceived_packets", &self.unreceived_packets)
             .field("unreceived_acks", &self.unreceived_acks)
+            .field("excluded", &self.excluded)
             .finish()
     }
 }
@@ -28,6 +30,7 @@ impl CollatedPendingPackets {
         Self {
             unreceived_packets: pending.unreceived_packets.into_iter().collated().collect(),
             unreceived_acks: pending.unreceived_acks.into_iter().collated().collect(),
+            excluded: pending.excluded.into_iter().collated().collect(),
         }
     }
 }
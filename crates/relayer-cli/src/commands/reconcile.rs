@@ -0,0 +1,57 @@
+use abscissa_core::clap::Parser;
+use abscissa_core::{Command, Runnable};
+
+use ibc_relayer::chain::handle::BaseChainHandle;
+use ibc_relayer::reconcile::reconcile_paths;
+use ibc_relayer::registry::SharedRegistry;
+
+use crate::conclude::Output;
+use crate::prelude::*;
+
+/// Create the clients, connections and channels of the paths
+/// declared in the configuration which do not exist yet.
+#[derive(Clone, Command, Debug, Parser, PartialEq, Eq)]
+pub struct ReconcileCmd {}
+
+impl Runnable for ReconcileCmd {
+    fn run(&self) {
+        let config = (*app_config()).clone();
+
+        if config.paths.is_empty() {
+            Output::success_msg("no paths are declared in the configuration").exit()
+        }
+
+        let registry = SharedRegistry::<BaseChainHandle>::new(config.clone());
+
+        let mut reports = Vec::new();
+        let mut errors = Vec::new();
+
+        for (path, result) in config.paths.iter().zip(reconcile_paths(&config, &registry)) {
+            match result {
+                Ok(report) => reports.push(report),
+                Err(e) => errors.push(format!(
+                    "failed to reconcile path between {} and {}: {}",
+                    path.a_chain, path.b_chain, e
+                )),
+            }
+        }
+
+        if errors.is_empty() {
+            Output::success(reports).exit()
+        } else {
+            Output::error(errors.join("\n")).exit()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ReconcileCmd;
+
+    use abscissa_core::clap::Parser;
+
+    #[test]
+    fn test_reconcile() {
+        assert_eq!(ReconcileCmd {}, ReconcileCmd::parse_from(["test"]))
+    }
+}
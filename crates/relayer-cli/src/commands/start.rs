@@ -2,6 +2,7 @@ use ibc_relayer::supervisor::SupervisorOptions;
 use ibc_relayer::util::debug_section::DebugSection;
 use std::error::Error;
 use std::io;
+use std::path::{Path, PathBuf};
 
 use abscissa_core::clap::Parser;
 use abscissa_core::{Command, Runnable};
@@ -9,12 +10,15 @@ use crossbeam_channel::Sender;
 
 use ibc_relayer::chain::handle::{CachingChainHandle, ChainHandle};
 use ibc_relayer::config::Config;
+use ibc_relayer::economics;
+use ibc_relayer::reconcile::reconcile_paths;
 use ibc_relayer::registry::SharedRegistry;
-use ibc_relayer::rest;
+use ibc_relayer::rest::{self, request::Request, RestApiError};
 use ibc_relayer::supervisor::{cmd::SupervisorCmd, spawn_supervisor, SupervisorHandle};
 
 use crate::conclude::json;
 use crate::conclude::Output;
+use crate::config::{validate_config, Diagnostic};
 use crate::prelude::*;
 
 #[derive(Clone, Command, Debug, Parser, PartialEq, Eq)]
@@ -24,6 +28,12 @@ pub struct StartCmd {
         help = "Force a full scan of the chains for clients, connections and channels"
     )]
     full_scan: bool,
+
+    #[clap(
+        long = "dry-run",
+        help = "Simulate the transactions which relay packets to estimate their fees, without submitting any transaction"
+    )]
+    dry_run: bool,
 }
 
 impl Runnable for StartCmd {
@@ -33,7 +43,6 @@ impl Runnable for StartCmd {
         if app.debug_enabled(DebugSection::ProfilingJson) {
             use chrono::prelude::*;
             use std::env;
-            use std::path::Path;
 
             use ibc_relayer::util::profiling::open_or_create_profile_file;
 
@@ -56,9 +65,14 @@ impl Runnable for StartCmd {
 
         let config = (*app_config()).clone();
 
+        if let Err(e) = economics::open_ledger(&config.economics) {
+            Output::error(format!("Hermes failed to open the economics ledger: {e}")).exit()
+        }
+
         let options = SupervisorOptions {
             force_full_scan: self.full_scan,
             health_check: true,
+            dry_run: self.dry_run,
         };
 
         let supervisor_handle = make_supervisor::<CachingChainHandle>(config, options)
@@ -67,10 +81,12 @@ impl Runnable for StartCmd {
             });
 
         match crate::config::config_path() {
-            Some(_) => {
-                register_signals(supervisor_handle.sender.clone()).unwrap_or_else(|e| {
-                    warn!("failed to install signal handler: {}", e);
-                });
+            Some(config_path) => {
+                register_signals(config_path, supervisor_handle.sender.clone()).unwrap_or_else(
+                    |e| {
+                        warn!("failed to install signal handler: {}", e);
+                    },
+                );
             }
             None => {
                 warn!("cannot figure out configuration path, skipping registration of signal handlers");
@@ -84,13 +100,13 @@ impl Runnable for StartCmd {
 }
 
 /// Register the SIGHUP and SIGUSR1 signals, and notify the supervisor.
-/// - [DEPRECATED] SIGHUP: Trigger a reload of the configuration.
+/// - SIGHUP: Reload the configuration file at the given path and apply the changes.
 /// - SIGUSR1: Ask the supervisor to dump its state and print it to the console.
-fn register_signals(tx_cmd: Sender<SupervisorCmd>) -> Result<(), io::Error> {
+fn register_signals(config_path: PathBuf, tx_cmd: Sender<SupervisorCmd>) -> Result<(), io::Error> {
     use signal_hook::{consts::signal::*, iterator::Signals};
 
     let sigs = vec![
-        SIGHUP,  // Reload of configuration (disabled)
+        SIGHUP,  // Reload of configuration
         SIGUSR1, // Dump state
     ];
 
@@ -99,10 +115,16 @@ fn register_signals(tx_cmd: Sender<SupervisorCmd>) -> Result<(), io::Error> {
     std::thread::spawn(move || {
         for signal in &mut signals {
             match signal {
-                SIGHUP => warn!(
-                    "configuration reloading via SIGHUP has been disabled, \
-                     the signal handler will be removed in the future"
-                ),
+                SIGHUP => {
+                    info!(
+                        "reloading configuration from '{}' (triggered by SIGHUP)",
+                        config_path.display()
+                    );
+
+                    if let Err(e) = apply_config(&config_path, &tx_cmd) {
+                        error!("failed to reload configuration, reason: {}", e);
+                    }
+                }
                 SIGUSR1 => {
                     info!("dumping state (triggered by SIGUSR1)");
 
@@ -133,6 +155,81 @@ fn register_signals(tx_cmd: Sender<SupervisorCmd>) -> Result<(), io::Error> {
     Ok(())
 }
 
+/// Load and validate the configuration file at the given path.
+fn reload_config(config_path: &Path) -> Result<Config, Box<dyn Error + Send + Sync>> {
+    let config = ibc_relayer::config::load(config_path)?;
+
+    if let Err(diagnostic) = validate_config(&config) {
+        match diagnostic {
+            Diagnostic::Warning(e) => warn!("relayer may be misconfigured: {}", e),
+            Diagnostic::Error(e) => return Err(e.into()),
+        }
+    }
+
+    Ok(config)
+}
+
+/// Load and validate the configuration file at the given path,
+/// and ask the supervisor to apply it.
+fn apply_config(
+    config_path: &Path,
+    tx_cmd: &Sender<SupervisorCmd>,
+) -> Result<(), Box<dyn Error + Send + Sync>> {
+    let config = reload_config(config_path)?;
+
+    tx_cmd
+        .send(SupervisorCmd::UpdateConfig(Box::new(config)))
+        .map_err(|_| "the supervisor is not running")?;
+
+    Ok(())
+}
+
+/// Handle the requests of the REST server to reload the configuration, the same way
+/// as on SIGHUP, and forward all the other requests to the supervisor.
+///
+/// The requests to reload the configuration are handled here rather than by
+/// the supervisor, which does not know the path of the configuration file.
+fn spawn_rest_forwarder(
+    rest_rx: rest::Receiver,
+    supervisor_tx: Sender<Request>,
+    tx_cmd: Sender<SupervisorCmd>,
+) {
+    let config_path = crate::config::config_path();
+
+    std::thread::spawn(move || {
+        for request in rest_rx {
+            match request {
+                Request::ReloadConfig { reply_to } => {
+                    let result = match &config_path {
+                        Some(config_path) => {
+                            info!(
+                                "reloading configuration from '{}' (triggered by REST request)",
+                                config_path.display()
+                            );
+
+                            apply_config(config_path, &tx_cmd)
+                        }
+                        None => Err("cannot figure out configuration path".into()),
+                    };
+
+                    if let Err(e) = &result {
+                        error!("failed to reload configuration, reason: {}", e);
+                    }
+
+                    reply_to
+                        .send(result.map_err(|e| RestApiError::ReloadConfig(e.to_string())))
+                        .unwrap_or_else(|e| error!("error replying to a REST request {}", e));
+                }
+                request => {
+                    if supervisor_tx.send(request).is_err() {
+                        break;
+                    }
+                }
+            }
+        }
+    });
+}
+
 #[cfg(feature = "rest-server")]
 fn spawn_rest_server(config: &Config) -> Option<rest::Receiver> {
     use ibc_relayer::util::spawn_blocking;
@@ -233,11 +330,36 @@ fn make_supervisor<Chain: ChainHandle>(
 ) -> Result<SupervisorHandle, Box<dyn Error + Send + Sync>> {
     let registry = SharedRegistry::<Chain>::new(config.clone());
 
+    // Create the pieces of the paths declared in the config which do not exist yet,
+    // failures are logged and do not prevent Hermes from starting
+    if !config.paths.is_empty() && !options.dry_run {
+        reconcile_paths(&config, &registry);
+    }
+
     spawn_telemetry_server(&config);
 
     let rest_rx = spawn_rest_server(&config);
 
-    Ok(spawn_supervisor(config, registry, rest_rx, options)?)
+    // The supervisor receives the REST requests forwarded
+    // by the handler of the requests to reload the configuration
+    let (supervisor_rest_tx, supervisor_rest_rx) = crossbeam_channel::unbounded();
+
+    let supervisor_handle = spawn_supervisor(
+        config,
+        registry,
+        rest_rx.is_some().then_some(supervisor_rest_rx),
+        options,
+    )?;
+
+    if let Some(rest_rx) = rest_rx {
+        spawn_rest_forwarder(
+            rest_rx,
+            supervisor_rest_tx,
+            supervisor_handle.sender.clone(),
+        );
+    }
+
+    Ok(supervisor_handle)
 }
 
 #[cfg(test)]
@@ -249,7 +371,10 @@ mod tests {
     #[test]
     fn test_start_required_only() {
         assert_eq!(
-            StartCmd { full_scan: false },
+            StartCmd {
+                full_scan: false,
+                dry_run: false
+            },
             StartCmd::parse_from(["test"])
         )
     }
@@ -257,8 +382,22 @@ mod tests {
     #[test]
     fn test_start_full_scan() {
         assert_eq!(
-            StartCmd { full_scan: true },
+            StartCmd {
+                full_scan: true,
+                dry_run: false
+            },
             StartCmd::parse_from(["test", "--full-scan"])
         )
     }
+
+    #[test]
+    fn test_start_dry_run() {
+        assert_eq!(
+            StartCmd {
+                full_scan: false,
+                dry_run: true
+            },
+            StartCmd::parse_from(["test", "--dry-run"])
+        )
+    }
 }
@@ -3,9 +3,11 @@ use abscissa_core::clap::Parser;
 use abscissa_core::{config::Override, Command, Runnable};
 use ibc_relayer::config::Config;
 
+mod broadcast;
 mod channel;
 pub(crate) mod client;
 mod connection;
+mod ica;
 mod packet;
 mod transfer;
 mod upgrade;
@@ -44,23 +46,58 @@ pub enum TxCmd {
     /// Confirm the closing of a channel (ChannelCloseConfirm)
     ChanCloseConfirm(channel::TxChanCloseConfirmCmd),
 
+    /// Propose an upgrade of a channel (ChannelUpgradeInit)
+    ChanUpgradeInit(channel::TxChanUpgradeInitCmd),
+
+    /// Relay the channel upgrade attempt (ChannelUpgradeTry)
+    ChanUpgradeTry(channel::TxChanUpgradeTryCmd),
+
+    /// Relay acknowledgment of a channel upgrade attempt (ChannelUpgradeAck)
+    ChanUpgradeAck(channel::TxChanUpgradeAckCmd),
+
+    /// Confirm that the counterparty channel end is flushing (ChannelUpgradeConfirm)
+    ChanUpgradeConfirm(channel::TxChanUpgradeConfirmCmd),
+
+    /// Open a channel with its upgraded parameters (ChannelUpgradeOpen)
+    ChanUpgradeOpen(channel::TxChanUpgradeOpenCmd),
+
+    /// Abort a channel upgrade which has timed out (ChannelUpgradeTimeout)
+    ChanUpgradeTimeout(channel::TxChanUpgradeTimeoutCmd),
+
+    /// Cancel a channel upgrade aborted by the counterparty (ChannelUpgradeCancel)
+    ChanUpgradeCancel(channel::TxChanUpgradeCancelCmd),
+
     /// Send a fungible token transfer test transaction (ICS20 MsgTransfer)
     FtTransfer(transfer::TxIcs20MsgTransferCmd),
 
+    /// Register an interchain account on a host chain (ICS27 MsgRegisterInterchainAccount)
+    IcaRegister(ica::TxIcaRegisterCmd),
+
+    /// Execute messages with an interchain account on a host chain (ICS27 MsgSendTx)
+    IcaSend(ica::TxIcaSendCmd),
+
     /// Relay receive or timeout packets
     PacketRecv(packet::TxPacketRecvCmd),
 
     /// Relay acknowledgment packets
     PacketAck(packet::TxPacketAckCmd),
 
+    /// Prepare the recovery of an expired or frozen client through governance
+    RecoverClient(client::TxRecoverClientCmd),
+
     /// Send an IBC upgrade plan
     UpgradeChain(upgrade::TxIbcUpgradeChainCmd),
+
+    /// Broadcast a transaction signed offline, eg. one generated with `--generate-only`
+    Broadcast(broadcast::TxBroadcastCmd),
 }
 
 impl Override<Config> for TxCmd {
     fn override_config(&self, config: Config) -> Result<Config, abscissa_core::FrameworkError> {
         match self {
             Self::FtTransfer(cmd) => cmd.override_config(config),
+            Self::IcaRegister(cmd) => cmd.override_config(config),
+            Self::IcaSend(cmd) => cmd.override_config(config),
             _ => Ok(config),
         }
     }
@@ -0,0 +1,136 @@
+use std::path::PathBuf;
+
+use abscissa_core::clap::Parser;
+use abscissa_core::{Command, Runnable};
+use tendermint_rpc::HttpClient;
+use tokio::runtime::Runtime as TokioRuntime;
+
+use ibc_relayer::chain::cosmos::tx::broadcast_signed_tx;
+use ibc_relayer::chain::cosmos::tx_json::tx_from_json;
+use ibc_relayer::chain::cosmos::types::config::TxConfig;
+use ibc_relayer::chain::ChainType;
+use ibc_relayer::event::IbcEventWithHeight;
+use ibc_relayer_types::core::ics24_host::identifier::ChainId;
+
+use crate::application::app_config;
+use crate::conclude::Output;
+use crate::error::Error;
+
+/// The data structure that represents the arguments when invoking the `tx broadcast` CLI command.
+///
+/// The command has the following format:
+///
+/// `tx broadcast --chain <CHAIN_ID> <FILE>`
+///
+/// The file holds a transaction in the JSON format of the Cosmos SDK, typically one
+/// generated with `--generate-only` and signed offline. Only the messages which can be
+/// generated by Hermes are supported, ie. `MsgTransfer`, `MsgChannelCloseInit`,
+/// `MsgUpdateClient` and `MsgUpgradeClient`. If successful, the IBC events of the
+/// committed transaction will be displayed.
+#[derive(Clone, Command, Debug, Parser, PartialEq, Eq)]
+pub struct TxBroadcastCmd {
+    #[clap(
+        long = "chain",
+        required = true,
+        value_name = "CHAIN_ID",
+        help_heading = "REQUIRED",
+        help = "Identifier of the chain to broadcast the transaction to"
+    )]
+    chain_id: ChainId,
+
+    #[clap(
+        required = true,
+        value_name = "FILE",
+        help = "Path to the signed transaction, in the JSON format of the Cosmos SDK. The supported messages are `MsgTransfer`, `MsgChannelCloseInit`, `MsgUpdateClient` and `MsgUpgradeClient`"
+    )]
+    file: PathBuf,
+}
+
+impl Runnable for TxBroadcastCmd {
+    fn run(&self) {
+        match self.execute() {
+            Ok(events) => Output::success(events).exit(),
+            Err(e) => Output::error(e).exit(),
+        }
+    }
+}
+
+impl TxBroadcastCmd {
+    fn execute(&self) -> Result<Vec<IbcEventWithHeight>, Error> {
+        let config = app_config();
+
+        let chain_config = config
+            .find_chain(&self.chain_id)
+            .ok_or_else(|| Error::missing_chain_config(self.chain_id.clone()))?;
+
+        if chain_config.r#type != ChainType::CosmosSdk {
+            return Err(Error::cli_arg(format!(
+                "transactions can only be broadcast to Cosmos SDK chains, chain '{}' is of type {:?}",
+                self.chain_id, chain_config.r#type
+            )));
+        }
+
+        let json = std::fs::read_to_string(&self.file).map_err(Error::io)?;
+
+        let json = serde_json::from_str(&json).map_err(|e| {
+            Error::cli_arg(format!(
+                "file '{}' does not hold valid JSON: {e}",
+                self.file.display()
+            ))
+        })?;
+
+        let tx = tx_from_json(&json).map_err(Error::relayer)?;
+
+        if tx.signatures.is_empty() {
+            return Err(Error::cli_arg(format!(
+                "the transaction in file '{}' is not signed",
+                self.file.display()
+            )));
+        }
+
+        let tx_config = TxConfig::try_from(chain_config).map_err(Error::relayer)?;
+
+        let rpc_client = HttpClient::new(chain_config.rpc_addr.clone()).map_err(|e| {
+            Error::cli_arg(format!(
+                "invalid RPC address '{}': {e}",
+                chain_config.rpc_addr
+            ))
+        })?;
+
+        let rt = TokioRuntime::new().map_err(Error::io)?;
+
+        rt.block_on(broadcast_signed_tx(&rpc_client, &tx_config, tx))
+            .map_err(Error::relayer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::TxBroadcastCmd;
+
+    use std::path::PathBuf;
+
+    use abscissa_core::clap::Parser;
+    use ibc_relayer_types::core::ics24_host::identifier::ChainId;
+
+    #[test]
+    fn test_broadcast() {
+        assert_eq!(
+            TxBroadcastCmd {
+                chain_id: ChainId::from_string("chain_id"),
+                file: PathBuf::from("signed.json"),
+            },
+            TxBroadcastCmd::parse_from(["test", "--chain", "chain_id", "signed.json"])
+        )
+    }
+
+    #[test]
+    fn test_broadcast_no_file() {
+        assert!(TxBroadcastCmd::try_parse_from(["test", "--chain", "chain_id"]).is_err())
+    }
+
+    #[test]
+    fn test_broadcast_no_chain() {
+        assert!(TxBroadcastCmd::try_parse_from(["test", "signed.json"]).is_err())
+    }
+}
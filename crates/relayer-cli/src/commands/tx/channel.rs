@@ -2,16 +2,21 @@ use abscissa_core::clap::Parser;
 use abscissa_core::{Command, Runnable};
 
 use ibc_relayer::chain::handle::ChainHandle;
-use ibc_relayer::chain::requests::{IncludeProof, QueryConnectionRequest, QueryHeight};
+use ibc_relayer::chain::requests::{
+    IncludeProof, QueryChannelRequest, QueryConnectionRequest, QueryHeight,
+};
 use ibc_relayer::channel::{Channel, ChannelSide};
 use ibc_relayer_types::core::ics03_connection::connection::ConnectionEnd;
 use ibc_relayer_types::core::ics04_channel::channel::Ordering;
+use ibc_relayer_types::core::ics04_channel::upgrade::UpgradeFields;
+use ibc_relayer_types::core::ics04_channel::version::Version;
 use ibc_relayer_types::core::ics24_host::identifier::{
     ChainId, ChannelId, ClientId, ConnectionId, PortId,
 };
 use ibc_relayer_types::events::IbcEvent;
+use ibc_relayer_types::signer::Signer;
 
-use crate::cli_utils::ChainHandlePair;
+use crate::cli_utils::{messages_json, unsigned_tx_json, ChainHandlePair};
 use crate::conclude::Output;
 use crate::error::Error;
 use crate::prelude::*;
@@ -150,21 +155,856 @@ impl Runnable for TxChanOpenInitCmd {
             ),
         };
 
-        info!("message ChanOpenInit: {}", channel);
+        info!("message ChanOpenInit: {}", channel);
+
+        let res: Result<IbcEvent, Error> = channel
+            .build_chan_open_init_and_send()
+            .map_err(Error::channel);
+
+        match res {
+            Ok(receipt) => Output::success(receipt).exit(),
+            Err(e) => Output::error(e).exit(),
+        }
+    }
+}
+
+#[derive(Clone, Command, Debug, Parser, PartialEq, Eq)]
+pub struct TxChanOpenTryCmd {
+    #[clap(
+        long = "dst-chain",
+        required = true,
+        value_name = "DST_CHAIN_ID",
+        help_heading = "REQUIRED",
+        help = "Identifier of the destination chain"
+    )]
+    dst_chain_id: ChainId,
+
+    #[clap(
+        long = "src-chain",
+        required = true,
+        value_name = "SRC_CHAIN_ID",
+        help_heading = "REQUIRED",
+        help = "Identifier of the source chain"
+    )]
+    src_chain_id: ChainId,
+
+    #[clap(
+        long = "dst-connection",
+        visible_alias = "dst-conn",
+        required = true,
+        value_name = "DST_CONNECTION_ID",
+        help_heading = "REQUIRED",
+        help = "Identifier of the destination connection"
+    )]
+    dst_conn_id: ConnectionId,
+
+    #[clap(
+        long = "dst-port",
+        required = true,
+        value_name = "DST_PORT_ID",
+        help_heading = "REQUIRED",
+        help = "Identifier of the destination port"
+    )]
+    dst_port_id: PortId,
+
+    #[clap(
+        long = "src-port",
+        required = true,
+        value_name = "SRC_PORT_ID",
+        help_heading = "REQUIRED",
+        help = "Identifier of the source port"
+    )]
+    src_port_id: PortId,
+
+    #[clap(
+        long = "src-channel",
+        visible_alias = "src-chan",
+        required = true,
+        value_name = "SRC_CHANNEL_ID",
+        help_heading = "REQUIRED",
+        help = "Identifier of the source channel (required)"
+    )]
+    src_chan_id: ChannelId,
+
+    #[clap(
+        long = "dst-channel",
+        visible_alias = "dst-chan",
+        value_name = "DST_CHANNEL_ID",
+        help = "Identifier of the destination channel (optional)"
+    )]
+    dst_chan_id: Option<ChannelId>,
+}
+
+impl Runnable for TxChanOpenTryCmd {
+    fn run(&self) {
+        tx_chan_cmd!(
+            "ChanOpenTry",
+            build_chan_open_try_and_send,
+            self,
+            |chains: ChainHandlePair, dst_connection: ConnectionEnd| {
+                Channel {
+                    connection_delay: Default::default(),
+                    ordering: Ordering::default(),
+                    a_side: ChannelSide::new(
+                        chains.src,
+                        ClientId::default(),
+                        ConnectionId::default(),
+                        self.src_port_id.clone(),
+                        Some(self.src_chan_id.clone()),
+                        None,
+                    ),
+                    b_side: ChannelSide::new(
+                        chains.dst,
+                        dst_connection.client_id().clone(),
+                        self.dst_conn_id.clone(),
+                        self.dst_port_id.clone(),
+                        self.dst_chan_id.clone(),
+                        None,
+                    ),
+                }
+            }
+        );
+    }
+}
+
+#[derive(Clone, Command, Debug, Parser, PartialEq, Eq)]
+pub struct TxChanOpenAckCmd {
+    #[clap(
+        long = "dst-chain",
+        required = true,
+        value_name = "DST_CHAIN_ID",
+        help_heading = "REQUIRED",
+        help = "Identifier of the destination chain"
+    )]
+    dst_chain_id: ChainId,
+
+    #[clap(
+        long = "src-chain",
+        required = true,
+        value_name = "SRC_CHAIN_ID",
+        help_heading = "REQUIRED",
+        help = "Identifier of the source chain"
+    )]
+    src_chain_id: ChainId,
+
+    #[clap(
+        long = "dst-connection",
+        visible_alias = "dst-conn",
+        required = true,
+        value_name = "DST_CONNECTION_ID",
+        help_heading = "REQUIRED",
+        help = "Identifier of the destination connection"
+    )]
+    dst_conn_id: ConnectionId,
+
+    #[clap(
+        long = "dst-port",
+        required = true,
+        value_name = "DST_PORT_ID",
+        help_heading = "REQUIRED",
+        help = "Identifier of the destination port"
+    )]
+    dst_port_id: PortId,
+
+    #[clap(
+        long = "src-port",
+        required = true,
+        value_name = "SRC_PORT_ID",
+        help_heading = "REQUIRED",
+        help = "Identifier of the source port"
+    )]
+    src_port_id: PortId,
+
+    #[clap(
+        long = "dst-channel",
+        visible_alias = "dst-chan",
+        required = true,
+        value_name = "DST_CHANNEL_ID",
+        help_heading = "REQUIRED",
+        help = "Identifier of the destination channel (required)"
+    )]
+    dst_chan_id: ChannelId,
+
+    #[clap(
+        long = "src-channel",
+        visible_alias = "src-chan",
+        required = true,
+        value_name = "SRC_CHANNEL_ID",
+        help_heading = "REQUIRED",
+        help = "Identifier of the source channel (required)"
+    )]
+    src_chan_id: ChannelId,
+}
+
+impl Runnable for TxChanOpenAckCmd {
+    fn run(&self) {
+        tx_chan_cmd!(
+            "ChanOpenAck",
+            build_chan_open_ack_and_send,
+            self,
+            |chains: ChainHandlePair, dst_connection: ConnectionEnd| {
+                Channel {
+                    connection_delay: Default::default(),
+                    ordering: Ordering::default(),
+                    a_side: ChannelSide::new(
+                        chains.src,
+                        ClientId::default(),
+                        ConnectionId::default(),
+                        self.src_port_id.clone(),
+                        Some(self.src_chan_id.clone()),
+                        None,
+                    ),
+                    b_side: ChannelSide::new(
+                        chains.dst,
+                        dst_connection.client_id().clone(),
+                        self.dst_conn_id.clone(),
+                        self.dst_port_id.clone(),
+                        Some(self.dst_chan_id.clone()),
+                        None,
+                    ),
+                }
+            }
+        );
+    }
+}
+
+#[derive(Clone, Command, Debug, Parser, PartialEq, Eq)]
+pub struct TxChanOpenConfirmCmd {
+    #[clap(
+        long = "dst-chain",
+        required = true,
+        value_name = "DST_CHAIN_ID",
+        help_heading = "REQUIRED",
+        help = "Identifier of the destination chain"
+    )]
+    dst_chain_id: ChainId,
+
+    #[clap(
+        long = "src-chain",
+        required = true,
+        value_name = "SRC_CHAIN_ID",
+        help_heading = "REQUIRED",
+        help = "Identifier of the source chain"
+    )]
+    src_chain_id: ChainId,
+
+    #[clap(
+        long = "dst-connection",
+        visible_alias = "dst-conn",
+        required = true,
+        value_name = "DST_CONNECTION_ID",
+        help_heading = "REQUIRED",
+        help = "Identifier of the destination connection"
+    )]
+    dst_conn_id: ConnectionId,
+
+    #[clap(
+        long = "dst-port",
+        required = true,
+        value_name = "DST_PORT_ID",
+        help_heading = "REQUIRED",
+        help = "Identifier of the destination port"
+    )]
+    dst_port_id: PortId,
+
+    #[clap(
+        long = "src-port",
+        required = true,
+        value_name = "SRC_PORT_ID",
+        help_heading = "REQUIRED",
+        help = "Identifier of the source port"
+    )]
+    src_port_id: PortId,
+
+    #[clap(
+        long = "dst-channel",
+        visible_alias = "dst-chan",
+        required = true,
+        value_name = "DST_CHANNEL_ID",
+        help_heading = "REQUIRED",
+        help = "Identifier of the destination channel (required)"
+    )]
+    dst_chan_id: ChannelId,
+
+    #[clap(
+        long = "src-channel",
+        visible_alias = "src-chan",
+        required = true,
+        value_name = "SRC_CHANNEL_ID",
+        help_heading = "REQUIRED",
+        help = "Identifier of the source channel (required)"
+    )]
+    src_chan_id: ChannelId,
+}
+
+impl Runnable for TxChanOpenConfirmCmd {
+    fn run(&self) {
+        tx_chan_cmd!(
+            "ChanOpenConfirm",
+            build_chan_open_confirm_and_send,
+            self,
+            |chains: ChainHandlePair, dst_connection: ConnectionEnd| {
+                Channel {
+                    connection_delay: Default::default(),
+                    ordering: Ordering::default(),
+                    a_side: ChannelSide::new(
+                        chains.src,
+                        ClientId::default(),
+                        ConnectionId::default(),
+                        self.src_port_id.clone(),
+                        Some(self.src_chan_id.clone()),
+                        None,
+                    ),
+                    b_side: ChannelSide::new(
+                        chains.dst,
+                        dst_connection.client_id().clone(),
+                        self.dst_conn_id.clone(),
+                        self.dst_port_id.clone(),
+                        Some(self.dst_chan_id.clone()),
+                        None,
+                    ),
+                }
+            }
+        );
+    }
+}
+
+#[derive(Clone, Command, Debug, Parser, PartialEq, Eq)]
+pub struct TxChanCloseInitCmd {
+    #[clap(
+        long = "dst-chain",
+        required = true,
+        value_name = "DST_CHAIN_ID",
+        help_heading = "REQUIRED",
+        help = "Identifier of the destination chain"
+    )]
+    dst_chain_id: ChainId,
+
+    #[clap(
+        long = "src-chain",
+        required = true,
+        value_name = "SRC_CHAIN_ID",
+        help_heading = "REQUIRED",
+        help = "Identifier of the source chain"
+    )]
+    src_chain_id: ChainId,
+
+    #[clap(
+        long = "dst-connection",
+        visible_alias = "dst-conn",
+        required = true,
+        value_name = "DST_CONNECTION_ID",
+        help_heading = "REQUIRED",
+        help = "Identifier of the destination connection"
+    )]
+    dst_conn_id: ConnectionId,
+
+    #[clap(
+        long = "dst-port",
+        required = true,
+        value_name = "DST_PORT_ID",
+        help_heading = "REQUIRED",
+        help = "Identifier of the destination port"
+    )]
+    dst_port_id: PortId,
+
+    #[clap(
+        long = "src-port",
+        required = true,
+        value_name = "SRC_PORT_ID",
+        help_heading = "REQUIRED",
+        help = "Identifier of the source port"
+    )]
+    src_port_id: PortId,
+
+    #[clap(
+        long = "dst-channel",
+        visible_alias = "dst-chan",
+        required = true,
+        value_name = "DST_CHANNEL_ID",
+        help_heading = "REQUIRED",
+        help = "Identifier of the destination channel (required)"
+    )]
+    dst_chan_id: ChannelId,
+
+    #[clap(
+        long = "src-channel",
+        visible_alias = "src-chan",
+        required = true,
+        value_name = "SRC_CHANNEL_ID",
+        help_heading = "REQUIRED",
+        help = "Identifier of the source channel (required)"
+    )]
+    src_chan_id: ChannelId,
+
+    #[clap(
+        long = "generate-only",
+        help = "Output the unsigned transaction in the JSON format of the Cosmos SDK, to be signed offline, instead of submitting it. With `--json`, the transaction is the `result` of the output"
+    )]
+    generate_only: bool,
+
+    #[clap(
+        long = "signer",
+        value_name = "ADDRESS",
+        requires = "generate-only",
+        help = "Address of the account signing the generated transaction, eg. a multisig account (default: the relayer's account)"
+    )]
+    signer: Option<String>,
+}
+
+impl Runnable for TxChanCloseInitCmd {
+    fn run(&self) {
+        let make_channel = |chains: ChainHandlePair, dst_connection: ConnectionEnd| Channel {
+            connection_delay: Default::default(),
+            ordering: Ordering::default(),
+            a_side: ChannelSide::new(
+                chains.src,
+                ClientId::default(),
+                ConnectionId::default(),
+                self.src_port_id.clone(),
+                Some(self.src_chan_id.clone()),
+                None,
+            ),
+            b_side: ChannelSide::new(
+                chains.dst,
+                dst_connection.client_id().clone(),
+                self.dst_conn_id.clone(),
+                self.dst_port_id.clone(),
+                Some(self.dst_chan_id.clone()),
+                None,
+            ),
+        };
+
+        if self.generate_only {
+            let config = app_config();
+
+            let chains =
+                match ChainHandlePair::spawn(&config, &self.src_chain_id, &self.dst_chain_id) {
+                    Ok(chains) => chains,
+                    Err(e) => Output::error(e).exit(),
+                };
+
+            let dst_connection = match chains.dst.query_connection(
+                QueryConnectionRequest {
+                    connection_id: self.dst_conn_id.clone(),
+                    height: QueryHeight::Latest,
+                },
+                IncludeProof::No,
+            ) {
+                Ok((connection, _)) => connection,
+                Err(e) => Output::error(e).exit(),
+            };
+
+            let tx = make_channel(chains, dst_connection)
+                .build_chan_close_init()
+                .map_err(Error::channel)
+                .and_then(|msgs| {
+                    unsigned_tx_json(&config, &self.dst_chain_id, &msgs, self.signer.as_deref())
+                });
+
+            match tx {
+                Ok(tx) => Output::success(tx).exit(),
+                Err(e) => Output::error(e).exit(),
+            }
+        }
+
+        tx_chan_cmd!(
+            "ChanCloseInit",
+            build_chan_close_init_and_send,
+            self,
+            make_channel
+        );
+    }
+}
+
+#[derive(Clone, Command, Debug, Parser, PartialEq, Eq)]
+pub struct TxChanCloseConfirmCmd {
+    #[clap(
+        long = "dst-chain",
+        required = true,
+        value_name = "DST_CHAIN_ID",
+        help_heading = "REQUIRED",
+        help = "Identifier of the destination chain"
+    )]
+    dst_chain_id: ChainId,
+
+    #[clap(
+        long = "src-chain",
+        required = true,
+        value_name = "SRC_CHAIN_ID",
+        help_heading = "REQUIRED",
+        help = "Identifier of the source chain"
+    )]
+    src_chain_id: ChainId,
+
+    #[clap(
+        long = "dst-connection",
+        visible_alias = "dst-conn",
+        required = true,
+        value_name = "DST_CONNECTION_ID",
+        help_heading = "REQUIRED",
+        help = "Identifier of the destination connection"
+    )]
+    dst_conn_id: ConnectionId,
+
+    #[clap(
+        long = "dst-port",
+        required = true,
+        value_name = "DST_PORT_ID",
+        help_heading = "REQUIRED",
+        help = "Identifier of the destination port"
+    )]
+    dst_port_id: PortId,
+
+    #[clap(
+        long = "src-port",
+        required = true,
+        value_name = "SRC_PORT_ID",
+        help_heading = "REQUIRED",
+        help = "Identifier of the source port"
+    )]
+    src_port_id: PortId,
+
+    #[clap(
+        long = "dst-channel",
+        visible_alias = "dst-chan",
+        required = true,
+        value_name = "DST_CHANNEL_ID",
+        help_heading = "REQUIRED",
+        help = "Identifier of the destination channel (required)"
+    )]
+    dst_chan_id: ChannelId,
+
+    #[clap(
+        long = "src-channel",
+        visible_alias = "src-chan",
+        required = true,
+        value_name = "SRC_CHANNEL_ID",
+        help_heading = "REQUIRED",
+        help = "Identifier of the source channel (required)"
+    )]
+    src_chan_id: ChannelId,
+}
+
+impl Runnable for TxChanCloseConfirmCmd {
+    fn run(&self) {
+        tx_chan_cmd!(
+            "ChanCloseConfirm",
+            build_chan_close_confirm_and_send,
+            self,
+            |chains: ChainHandlePair, dst_connection: ConnectionEnd| {
+                Channel {
+                    connection_delay: Default::default(),
+                    ordering: Ordering::default(),
+                    a_side: ChannelSide::new(
+                        chains.src,
+                        ClientId::default(),
+                        ConnectionId::default(),
+                        self.src_port_id.clone(),
+                        Some(self.src_chan_id.clone()),
+                        None,
+                    ),
+                    b_side: ChannelSide::new(
+                        chains.dst,
+                        dst_connection.client_id().clone(),
+                        self.dst_conn_id.clone(),
+                        self.dst_port_id.clone(),
+                        Some(self.dst_chan_id.clone()),
+                        None,
+                    ),
+                }
+            }
+        );
+    }
+}
+
+// The upgrade must be signed by the authority allowed to upgrade the channels of the
+// destination chain, usually its governance module, so the message is output in the
+// JSON format of the Cosmos SDK, to be submitted in a governance proposal.
+#[derive(Clone, Command, Debug, Parser, PartialEq, Eq)]
+pub struct TxChanUpgradeInitCmd {
+    #[clap(
+        long = "dst-chain",
+        required = true,
+        value_name = "DST_CHAIN_ID",
+        help_heading = "REQUIRED",
+        help = "Identifier of the destination chain"
+    )]
+    dst_chain_id: ChainId,
+
+    #[clap(
+        long = "src-chain",
+        required = true,
+        value_name = "SRC_CHAIN_ID",
+        help_heading = "REQUIRED",
+        help = "Identifier of the source chain"
+    )]
+    src_chain_id: ChainId,
+
+    #[clap(
+        long = "dst-connection",
+        visible_alias = "dst-conn",
+        required = true,
+        value_name = "DST_CONNECTION_ID",
+        help_heading = "REQUIRED",
+        help = "Identifier of the destination connection"
+    )]
+    dst_conn_id: ConnectionId,
+
+    #[clap(
+        long = "dst-port",
+        required = true,
+        value_name = "DST_PORT_ID",
+        help_heading = "REQUIRED",
+        help = "Identifier of the destination port"
+    )]
+    dst_port_id: PortId,
+
+    #[clap(
+        long = "src-port",
+        required = true,
+        value_name = "SRC_PORT_ID",
+        help_heading = "REQUIRED",
+        help = "Identifier of the source port"
+    )]
+    src_port_id: PortId,
+
+    #[clap(
+        long = "dst-channel",
+        visible_alias = "dst-chan",
+        required = true,
+        value_name = "DST_CHANNEL_ID",
+        help_heading = "REQUIRED",
+        help = "Identifier of the destination channel (required)"
+    )]
+    dst_chan_id: ChannelId,
+
+    #[clap(
+        long = "src-channel",
+        visible_alias = "src-chan",
+        required = true,
+        value_name = "SRC_CHANNEL_ID",
+        help_heading = "REQUIRED",
+        help = "Identifier of the source channel (required)"
+    )]
+    src_chan_id: ChannelId,
+
+    #[clap(
+        long = "version",
+        required = true,
+        value_name = "VERSION",
+        help_heading = "REQUIRED",
+        help = "Version of the upgraded channel"
+    )]
+    version: Version,
+
+    #[clap(
+        long = "ordering",
+        value_name = "ORDERING",
+        help = "Ordering of the upgraded channel, valid options 'unordered' and 'ordered'. \
+                Defaults to the ordering of the channel"
+    )]
+    ordering: Option<Ordering>,
+
+    #[clap(
+        long = "connection-hops",
+        value_name = "CONNECTION_HOPS",
+        help = "Connection of the upgraded channel. Defaults to the connection of the channel"
+    )]
+    connection_hops: Option<ConnectionId>,
+
+    #[clap(
+        long = "authority",
+        required = true,
+        value_name = "ADDRESS",
+        help_heading = "REQUIRED",
+        help = "Address of the authority allowed to upgrade the channels of the destination chain, \
+                usually its governance module account, which signs the message"
+    )]
+    authority: Signer,
+}
+
+impl Runnable for TxChanUpgradeInitCmd {
+    fn run(&self) {
+        let config = app_config();
+
+        let chains = match ChainHandlePair::spawn(&config, &self.src_chain_id, &self.dst_chain_id) {
+            Ok(chains) => chains,
+            Err(e) => Output::error(e).exit(),
+        };
+
+        // Retrieve the connection
+        let dst_connection = match chains.dst.query_connection(
+            QueryConnectionRequest {
+                connection_id: self.dst_conn_id.clone(),
+                height: QueryHeight::Latest,
+            },
+            IncludeProof::No,
+        ) {
+            Ok((connection, _)) => connection,
+            Err(e) => Output::error(e).exit(),
+        };
+
+        // Retrieve the channel, whose parameters are kept unless specified otherwise
+        let dst_channel = match chains.dst.query_channel(
+            QueryChannelRequest {
+                port_id: self.dst_port_id.clone(),
+                channel_id: self.dst_chan_id.clone(),
+                height: QueryHeight::Latest,
+            },
+            IncludeProof::No,
+        ) {
+            Ok((channel, _)) => channel,
+            Err(e) => Output::error(e).exit(),
+        };
+
+        let fields = UpgradeFields::new(
+            self.ordering.unwrap_or(*dst_channel.ordering()),
+            self.connection_hops
+                .clone()
+                .map(|connection_id| vec![connection_id])
+                .unwrap_or_else(|| dst_channel.connection_hops().clone()),
+            self.version.clone(),
+        );
+
+        let channel = Channel {
+            connection_delay: Default::default(),
+            ordering: Ordering::default(),
+            a_side: ChannelSide::new(
+                chains.src,
+                ClientId::default(),
+                ConnectionId::default(),
+                self.src_port_id.clone(),
+                Some(self.src_chan_id.clone()),
+                None,
+            ),
+            b_side: ChannelSide::new(
+                chains.dst,
+                dst_connection.client_id().clone(),
+                self.dst_conn_id.clone(),
+                self.dst_port_id.clone(),
+                Some(self.dst_chan_id.clone()),
+                None,
+            ),
+        };
+
+        info!("message ChanUpgradeInit: {}", channel);
+
+        let msgs = channel
+            .build_chan_upgrade_init(fields, self.authority.clone())
+            .map_err(Error::channel)
+            .and_then(|msgs| messages_json(&config, &self.dst_chain_id, &msgs));
+
+        match msgs {
+            Ok(msgs) => Output::success(msgs).exit(),
+            Err(e) => Output::error(e).exit(),
+        }
+    }
+}
+
+#[derive(Clone, Command, Debug, Parser, PartialEq, Eq)]
+pub struct TxChanUpgradeTryCmd {
+    #[clap(
+        long = "dst-chain",
+        required = true,
+        value_name = "DST_CHAIN_ID",
+        help_heading = "REQUIRED",
+        help = "Identifier of the destination chain"
+    )]
+    dst_chain_id: ChainId,
+
+    #[clap(
+        long = "src-chain",
+        required = true,
+        value_name = "SRC_CHAIN_ID",
+        help_heading = "REQUIRED",
+        help = "Identifier of the source chain"
+    )]
+    src_chain_id: ChainId,
+
+    #[clap(
+        long = "dst-connection",
+        visible_alias = "dst-conn",
+        required = true,
+        value_name = "DST_CONNECTION_ID",
+        help_heading = "REQUIRED",
+        help = "Identifier of the destination connection"
+    )]
+    dst_conn_id: ConnectionId,
+
+    #[clap(
+        long = "dst-port",
+        required = true,
+        value_name = "DST_PORT_ID",
+        help_heading = "REQUIRED",
+        help = "Identifier of the destination port"
+    )]
+    dst_port_id: PortId,
+
+    #[clap(
+        long = "src-port",
+        required = true,
+        value_name = "SRC_PORT_ID",
+        help_heading = "REQUIRED",
+        help = "Identifier of the source port"
+    )]
+    src_port_id: PortId,
+
+    #[clap(
+        long = "dst-channel",
+        visible_alias = "dst-chan",
+        required = true,
+        value_name = "DST_CHANNEL_ID",
+        help_heading = "REQUIRED",
+        help = "Identifier of the destination channel (required)"
+    )]
+    dst_chan_id: ChannelId,
 
-        let res: Result<IbcEvent, Error> = channel
-            .build_chan_open_init_and_send()
-            .map_err(Error::channel);
+    #[clap(
+        long = "src-channel",
+        visible_alias = "src-chan",
+        required = true,
+        value_name = "SRC_CHANNEL_ID",
+        help_heading = "REQUIRED",
+        help = "Identifier of the source channel (required)"
+    )]
+    src_chan_id: ChannelId,
+}
 
-        match res {
-            Ok(receipt) => Output::success(receipt).exit(),
-            Err(e) => Output::error(e).exit(),
-        }
+impl Runnable for TxChanUpgradeTryCmd {
+    fn run(&self) {
+        tx_chan_cmd!(
+            "ChanUpgradeTry",
+            build_chan_upgrade_try_and_send,
+            self,
+            |chains: ChainHandlePair, dst_connection: ConnectionEnd| {
+                Channel {
+                    connection_delay: Default::default(),
+                    ordering: Ordering::default(),
+                    a_side: ChannelSide::new(
+                        chains.src,
+                        ClientId::default(),
+                        ConnectionId::default(),
+                        self.src_port_id.clone(),
+                        Some(self.src_chan_id.clone()),
+                        None,
+                    ),
+                    b_side: ChannelSide::new(
+                        chains.dst,
+                        dst_connection.client_id().clone(),
+                        self.dst_conn_id.clone(),
+                        self.dst_port_id.clone(),
+                        Some(self.dst_chan_id.clone()),
+                        None,
+                    ),
+                }
+            }
+        );
     }
 }
 
 #[derive(Clone, Command, Debug, Parser, PartialEq, Eq)]
-pub struct TxChanOpenTryCmd {
+pub struct TxChanUpgradeAckCmd {
     #[clap(
         long = "dst-chain",
         required = true,
@@ -211,6 +1051,16 @@ pub struct TxChanOpenTryCmd {
     )]
     src_port_id: PortId,
 
+    #[clap(
+        long = "dst-channel",
+        visible_alias = "dst-chan",
+        required = true,
+        value_name = "DST_CHANNEL_ID",
+        help_heading = "REQUIRED",
+        help = "Identifier of the destination channel (required)"
+    )]
+    dst_chan_id: ChannelId,
+
     #[clap(
         long = "src-channel",
         visible_alias = "src-chan",
@@ -220,21 +1070,13 @@ pub struct TxChanOpenTryCmd {
         help = "Identifier of the source channel (required)"
     )]
     src_chan_id: ChannelId,
-
-    #[clap(
-        long = "dst-channel",
-        visible_alias = "dst-chan",
-        value_name = "DST_CHANNEL_ID",
-        help = "Identifier of the destination channel (optional)"
-    )]
-    dst_chan_id: Option<ChannelId>,
 }
 
-impl Runnable for TxChanOpenTryCmd {
+impl Runnable for TxChanUpgradeAckCmd {
     fn run(&self) {
         tx_chan_cmd!(
-            "ChanOpenTry",
-            build_chan_open_try_and_send,
+            "ChanUpgradeAck",
+            build_chan_upgrade_ack_and_send,
             self,
             |chains: ChainHandlePair, dst_connection: ConnectionEnd| {
                 Channel {
@@ -253,7 +1095,7 @@ impl Runnable for TxChanOpenTryCmd {
                         dst_connection.client_id().clone(),
                         self.dst_conn_id.clone(),
                         self.dst_port_id.clone(),
-                        self.dst_chan_id.clone(),
+                        Some(self.dst_chan_id.clone()),
                         None,
                     ),
                 }
@@ -263,7 +1105,7 @@ impl Runnable for TxChanOpenTryCmd {
 }
 
 #[derive(Clone, Command, Debug, Parser, PartialEq, Eq)]
-pub struct TxChanOpenAckCmd {
+pub struct TxChanUpgradeConfirmCmd {
     #[clap(
         long = "dst-chain",
         required = true,
@@ -331,11 +1173,11 @@ pub struct TxChanOpenAckCmd {
     src_chan_id: ChannelId,
 }
 
-impl Runnable for TxChanOpenAckCmd {
+impl Runnable for TxChanUpgradeConfirmCmd {
     fn run(&self) {
         tx_chan_cmd!(
-            "ChanOpenAck",
-            build_chan_open_ack_and_send,
+            "ChanUpgradeConfirm",
+            build_chan_upgrade_confirm_and_send,
             self,
             |chains: ChainHandlePair, dst_connection: ConnectionEnd| {
                 Channel {
@@ -364,7 +1206,7 @@ impl Runnable for TxChanOpenAckCmd {
 }
 
 #[derive(Clone, Command, Debug, Parser, PartialEq, Eq)]
-pub struct TxChanOpenConfirmCmd {
+pub struct TxChanUpgradeOpenCmd {
     #[clap(
         long = "dst-chain",
         required = true,
@@ -432,11 +1274,11 @@ pub struct TxChanOpenConfirmCmd {
     src_chan_id: ChannelId,
 }
 
-impl Runnable for TxChanOpenConfirmCmd {
+impl Runnable for TxChanUpgradeOpenCmd {
     fn run(&self) {
         tx_chan_cmd!(
-            "ChanOpenConfirm",
-            build_chan_open_confirm_and_send,
+            "ChanUpgradeOpen",
+            build_chan_upgrade_open_and_send,
             self,
             |chains: ChainHandlePair, dst_connection: ConnectionEnd| {
                 Channel {
@@ -465,7 +1307,7 @@ impl Runnable for TxChanOpenConfirmCmd {
 }
 
 #[derive(Clone, Command, Debug, Parser, PartialEq, Eq)]
-pub struct TxChanCloseInitCmd {
+pub struct TxChanUpgradeTimeoutCmd {
     #[clap(
         long = "dst-chain",
         required = true,
@@ -533,11 +1375,11 @@ pub struct TxChanCloseInitCmd {
     src_chan_id: ChannelId,
 }
 
-impl Runnable for TxChanCloseInitCmd {
+impl Runnable for TxChanUpgradeTimeoutCmd {
     fn run(&self) {
         tx_chan_cmd!(
-            "ChanCloseInit",
-            build_chan_close_init_and_send,
+            "ChanUpgradeTimeout",
+            build_chan_upgrade_timeout_and_send,
             self,
             |chains: ChainHandlePair, dst_connection: ConnectionEnd| {
                 Channel {
@@ -566,7 +1408,7 @@ impl Runnable for TxChanCloseInitCmd {
 }
 
 #[derive(Clone, Command, Debug, Parser, PartialEq, Eq)]
-pub struct TxChanCloseConfirmCmd {
+pub struct TxChanUpgradeCancelCmd {
     #[clap(
         long = "dst-chain",
         required = true,
@@ -634,11 +1476,11 @@ pub struct TxChanCloseConfirmCmd {
     src_chan_id: ChannelId,
 }
 
-impl Runnable for TxChanCloseConfirmCmd {
+impl Runnable for TxChanUpgradeCancelCmd {
     fn run(&self) {
         tx_chan_cmd!(
-            "ChanCloseConfirm",
-            build_chan_close_confirm_and_send,
+            "ChanUpgradeCancel",
+            build_chan_upgrade_cancel_and_send,
             self,
             |chains: ChainHandlePair, dst_connection: ConnectionEnd| {
                 Channel {
@@ -670,16 +1512,17 @@ impl Runnable for TxChanCloseConfirmCmd {
 mod tests {
     use super::{
         TxChanCloseConfirmCmd, TxChanCloseInitCmd, TxChanOpenAckCmd, TxChanOpenConfirmCmd,
-        TxChanOpenInitCmd, TxChanOpenTryCmd,
+        TxChanOpenInitCmd, TxChanOpenTryCmd, TxChanUpgradeInitCmd, TxChanUpgradeOpenCmd,
     };
 
     use std::str::FromStr;
 
     use abscissa_core::clap::Parser;
     use ibc_relayer_types::core::{
-        ics04_channel::channel::Ordering,
+        ics04_channel::{channel::Ordering, version::Version},
         ics24_host::identifier::{ChainId, ChannelId, ConnectionId, PortId},
     };
+    use ibc_relayer_types::signer::Signer;
 
     #[test]
     fn test_chan_open_init_required_only() {
@@ -1464,7 +2307,9 @@ mod tests {
                 dst_port_id: PortId::from_str("port_b").unwrap(),
                 src_port_id: PortId::from_str("port_a").unwrap(),
                 dst_chan_id: ChannelId::from_str("channel_b").unwrap(),
-                src_chan_id: ChannelId::from_str("channel_a").unwrap()
+                src_chan_id: ChannelId::from_str("channel_a").unwrap(),
+                generate_only: false,
+                signer: None,
             },
             TxChanCloseInitCmd::parse_from([
                 "test",
@@ -1496,7 +2341,9 @@ mod tests {
                 dst_port_id: PortId::from_str("port_b").unwrap(),
                 src_port_id: PortId::from_str("port_a").unwrap(),
                 dst_chan_id: ChannelId::from_str("channel_b").unwrap(),
-                src_chan_id: ChannelId::from_str("channel_a").unwrap()
+                src_chan_id: ChannelId::from_str("channel_a").unwrap(),
+                generate_only: false,
+                signer: None,
             },
             TxChanCloseInitCmd::parse_from([
                 "test",
@@ -1518,6 +2365,43 @@ mod tests {
         )
     }
 
+    #[test]
+    fn test_chan_close_init_generate_only() {
+        assert_eq!(
+            TxChanCloseInitCmd {
+                dst_chain_id: ChainId::from_string("chain_b"),
+                src_chain_id: ChainId::from_string("chain_a"),
+                dst_conn_id: ConnectionId::from_str("connection_b").unwrap(),
+                dst_port_id: PortId::from_str("port_b").unwrap(),
+                src_port_id: PortId::from_str("port_a").unwrap(),
+                dst_chan_id: ChannelId::from_str("channel_b").unwrap(),
+                src_chan_id: ChannelId::from_str("channel_a").unwrap(),
+                generate_only: true,
+                signer: Some("cosmos1multisig".to_owned()),
+            },
+            TxChanCloseInitCmd::parse_from([
+                "test",
+                "--dst-chain",
+                "chain_b",
+                "--src-chain",
+                "chain_a",
+                "--dst-connection",
+                "connection_b",
+                "--dst-port",
+                "port_b",
+                "--src-port",
+                "port_a",
+                "--dst-channel",
+                "channel_b",
+                "--src-channel",
+                "channel_a",
+                "--generate-only",
+                "--signer",
+                "cosmos1multisig"
+            ])
+        )
+    }
+
     #[test]
     fn test_chan_close_init_no_a_channel() {
         assert!(TxChanCloseInitCmd::try_parse_from([
@@ -1861,4 +2745,168 @@ mod tests {
         ])
         .is_err())
     }
+
+    #[test]
+    fn test_chan_upgrade_init_required_only() {
+        assert_eq!(
+            TxChanUpgradeInitCmd {
+                dst_chain_id: ChainId::from_string("chain_b"),
+                src_chain_id: ChainId::from_string("chain_a"),
+                dst_conn_id: ConnectionId::from_str("connection_b").unwrap(),
+                dst_port_id: PortId::from_str("port_b").unwrap(),
+                src_port_id: PortId::from_str("port_a").unwrap(),
+                dst_chan_id: ChannelId::from_str("channel_b").unwrap(),
+                src_chan_id: ChannelId::from_str("channel_a").unwrap(),
+                version: Version::new("ics20-1".to_string()),
+                ordering: None,
+                connection_hops: None,
+                authority: Signer::from_str("cosmos1authority").unwrap(),
+            },
+            TxChanUpgradeInitCmd::parse_from([
+                "test",
+                "--dst-chain",
+                "chain_b",
+                "--src-chain",
+                "chain_a",
+                "--dst-connection",
+                "connection_b",
+                "--dst-port",
+                "port_b",
+                "--src-port",
+                "port_a",
+                "--dst-channel",
+                "channel_b",
+                "--src-channel",
+                "channel_a",
+                "--version",
+                "ics20-1",
+                "--authority",
+                "cosmos1authority"
+            ])
+        )
+    }
+
+    #[test]
+    fn test_chan_upgrade_init_all_fields() {
+        assert_eq!(
+            TxChanUpgradeInitCmd {
+                dst_chain_id: ChainId::from_string("chain_b"),
+                src_chain_id: ChainId::from_string("chain_a"),
+                dst_conn_id: ConnectionId::from_str("connection_b").unwrap(),
+                dst_port_id: PortId::from_str("port_b").unwrap(),
+                src_port_id: PortId::from_str("port_a").unwrap(),
+                dst_chan_id: ChannelId::from_str("channel_b").unwrap(),
+                src_chan_id: ChannelId::from_str("channel_a").unwrap(),
+                version: Version::new("ics20-1".to_string()),
+                ordering: Some(Ordering::Ordered),
+                connection_hops: Some(ConnectionId::from_str("connection_c").unwrap()),
+                authority: Signer::from_str("cosmos1authority").unwrap(),
+            },
+            TxChanUpgradeInitCmd::parse_from([
+                "test",
+                "--dst-chain",
+                "chain_b",
+                "--src-chain",
+                "chain_a",
+                "--dst-conn",
+                "connection_b",
+                "--dst-port",
+                "port_b",
+                "--src-port",
+                "port_a",
+                "--dst-chan",
+                "channel_b",
+                "--src-chan",
+                "channel_a",
+                "--version",
+                "ics20-1",
+                "--ordering",
+                "ordered",
+                "--connection-hops",
+                "connection_c",
+                "--authority",
+                "cosmos1authority"
+            ])
+        )
+    }
+
+    #[test]
+    fn test_chan_upgrade_init_no_version() {
+        assert!(TxChanUpgradeInitCmd::try_parse_from([
+            "test",
+            "--dst-chain",
+            "chain_b",
+            "--src-chain",
+            "chain_a",
+            "--dst-connection",
+            "connection_b",
+            "--dst-port",
+            "port_b",
+            "--src-port",
+            "port_a",
+            "--dst-channel",
+            "channel_b",
+            "--src-channel",
+            "channel_a",
+            "--authority",
+            "cosmos1authority"
+        ])
+        .is_err())
+    }
+
+    #[test]
+    fn test_chan_upgrade_init_no_authority() {
+        assert!(TxChanUpgradeInitCmd::try_parse_from([
+            "test",
+            "--dst-chain",
+            "chain_b",
+            "--src-chain",
+            "chain_a",
+            "--dst-connection",
+            "connection_b",
+            "--dst-port",
+            "port_b",
+            "--src-port",
+            "port_a",
+            "--dst-channel",
+            "channel_b",
+            "--src-channel",
+            "channel_a",
+            "--version",
+            "ics20-1"
+        ])
+        .is_err())
+    }
+
+    #[test]
+    fn test_chan_upgrade_open_required_only() {
+        assert_eq!(
+            TxChanUpgradeOpenCmd {
+                dst_chain_id: ChainId::from_string("chain_b"),
+                src_chain_id: ChainId::from_string("chain_a"),
+                dst_conn_id: ConnectionId::from_str("connection_b").unwrap(),
+                dst_port_id: PortId::from_str("port_b").unwrap(),
+                src_port_id: PortId::from_str("port_a").unwrap(),
+                dst_chan_id: ChannelId::from_str("channel_b").unwrap(),
+                src_chan_id: ChannelId::from_str("channel_a").unwrap(),
+            },
+            TxChanUpgradeOpenCmd::parse_from([
+                "test",
+                "--dst-chain",
+                "chain_b",
+                "--src-chain",
+                "chain_a",
+                "--dst-connection",
+                "connection_b",
+                "--dst-port",
+                "port_b",
+                "--src-port",
+                "port_a",
+                "--dst-channel",
+                "channel_b",
+                "--src-channel",
+                "channel_a"
+            ])
+        )
+    }
 }
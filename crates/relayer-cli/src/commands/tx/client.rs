@@ -2,6 +2,7 @@ use core::{
     fmt::{Display, Error as FmtError, Formatter},
     time::Duration,
 };
+use std::path::PathBuf;
 use std::thread;
 
 use abscissa_core::clap::Parser;
@@ -13,6 +14,9 @@ use ibc_relayer::chain::requests::{
 use ibc_relayer::config::Config;
 use ibc_relayer::event::IbcEventWithHeight;
 use ibc_relayer::foreign_client::{CreateOptions, ForeignClient};
+use ibc_relayer::recover_client::{
+    build_recover_client_proposal, ProposalKind, RecoverClientOptions,
+};
 use ibc_relayer::{chain::handle::ChainHandle, config::GenesisRestart};
 use ibc_relayer_types::core::ics02_client::client_state::ClientState;
 use ibc_relayer_types::core::ics24_host::identifier::{ChainId, ClientId};
@@ -24,7 +28,9 @@ use tendermint_rpc::Url;
 use tracing::debug;
 
 use crate::application::app_config;
-use crate::cli_utils::{spawn_chain_runtime, spawn_chain_runtime_generic, ChainHandlePair};
+use crate::cli_utils::{
+    spawn_chain_runtime, spawn_chain_runtime_generic, unsigned_tx_json, ChainHandlePair,
+};
 use crate::conclude::{exit_with_unrecoverable_error, Output};
 use crate::error::Error;
 
@@ -266,6 +272,20 @@ pub struct TxUpgradeClientCmd {
         help = "The height at which the reference chain halts for the client upgrade"
     )]
     reference_upgrade_height: u64,
+
+    #[clap(
+        long = "generate-only",
+        help = "Output the unsigned transaction in the JSON format of the Cosmos SDK, to be signed offline, instead of submitting it. With `--json`, the transaction is the `result` of the output"
+    )]
+    generate_only: bool,
+
+    #[clap(
+        long = "signer",
+        value_name = "ADDRESS",
+        requires = "generate-only",
+        help = "Address of the account signing the generated transaction, eg. a multisig account (default: the relayer's account)"
+    )]
+    signer: Option<String>,
 }
 
 impl Runnable for TxUpgradeClientCmd {
@@ -347,6 +367,20 @@ impl Runnable for TxUpgradeClientCmd {
         // condition with the chain
         thread::sleep(Duration::from_millis(6000));
 
+        if self.generate_only {
+            let tx = client
+                .build_upgrade(reference_upgrade_height)
+                .map_err(Error::foreign_client)
+                .and_then(|msgs| {
+                    unsigned_tx_json(&config, &self.chain_id, &msgs, self.signer.as_deref())
+                });
+
+            match tx {
+                Ok(tx) => Output::success(tx).exit(),
+                Err(e) => Output::error(e).exit(),
+            }
+        }
+
         let outcome = client.upgrade(reference_upgrade_height);
 
         match outcome {
@@ -504,6 +538,141 @@ impl TxUpgradeClientsCmd {
     }
 }
 
+/// Prepare the recovery of an expired or frozen client through governance.
+///
+/// Unless a substitute client is given, a new client with the same parameters as the
+/// subject client is created. The governance proposal replacing the state of the subject
+/// client with the state of the substitute client is then written to a file, to be submitted
+/// with `<chain binary> tx gov submit-proposal <file>`.
+///
+/// `tx recover-client --chain <CHAIN_ID> --subject <SUBJECT_CLIENT_ID> [--substitute <SUBSTITUTE_CLIENT_ID>]`
+#[derive(Clone, Command, Debug, Parser, PartialEq, Eq)]
+pub struct TxRecoverClientCmd {
+    #[clap(
+        long = "chain",
+        required = true,
+        value_name = "CHAIN_ID",
+        help_heading = "REQUIRED",
+        help = "Identifier of the chain that hosts the client"
+    )]
+    chain_id: ChainId,
+
+    #[clap(
+        long = "subject",
+        required = true,
+        value_name = "SUBJECT_CLIENT_ID",
+        help_heading = "REQUIRED",
+        help = "Identifier of the expired or frozen client to recover"
+    )]
+    subject_client_id: ClientId,
+
+    #[clap(
+        long = "substitute",
+        value_name = "SUBSTITUTE_CLIENT_ID",
+        help = "Identifier of an active client with the same parameters as the subject client (default: a new client is created)"
+    )]
+    substitute_client_id: Option<ClientId>,
+
+    #[clap(
+        long = "legacy",
+        help = "Generate a legacy ClientUpdateProposal instead of a MsgRecoverClient, for chains using ibc-go v7 or earlier"
+    )]
+    legacy: bool,
+
+    #[clap(
+        long = "authority",
+        value_name = "AUTHORITY",
+        help = "Address of the authority of the client module (default: the governance module account)"
+    )]
+    authority: Option<String>,
+
+    #[clap(
+        long = "deposit",
+        value_name = "DEPOSIT",
+        help = "Initial deposit of the proposal, eg. 10000000stake (default: none)"
+    )]
+    deposit: Option<String>,
+
+    #[clap(
+        long = "title",
+        value_name = "TITLE",
+        help = "Title of the proposal (default: 'Recover client <SUBJECT_CLIENT_ID>')"
+    )]
+    title: Option<String>,
+
+    #[clap(
+        long = "output",
+        value_name = "PROPOSAL_FILE",
+        default_value = "recover-client-proposal.json",
+        help = "Path of the file to write the proposal to"
+    )]
+    output: PathBuf,
+}
+
+impl Runnable for TxRecoverClientCmd {
+    fn run(&self) {
+        match self.execute() {
+            Ok(result) => Output::success(result).exit(),
+            Err(e) => Output::error(e).exit(),
+        }
+    }
+}
+
+impl TxRecoverClientCmd {
+    fn execute(&self) -> Result<serde_json::Value, Error> {
+        let config = app_config();
+
+        let host_chain = spawn_chain_runtime(&config, &self.chain_id)?;
+
+        // The substitute client tracks the same chain as the subject client,
+        // unless this chain changed its identifier when restarting.
+        let reference_client_id = self
+            .substitute_client_id
+            .as_ref()
+            .unwrap_or(&self.subject_client_id);
+
+        let (reference_client_state, _) = host_chain
+            .query_client_state(
+                QueryClientStateRequest {
+                    client_id: reference_client_id.clone(),
+                    height: QueryHeight::Latest,
+                },
+                IncludeProof::No,
+            )
+            .map_err(Error::relayer)?;
+
+        let reference_chain = spawn_chain_runtime(&config, &reference_client_state.chain_id())?;
+
+        let opts = RecoverClientOptions {
+            subject_client_id: self.subject_client_id.clone(),
+            substitute_client_id: self.substitute_client_id.clone(),
+            kind: if self.legacy {
+                ProposalKind::ClientUpdate
+            } else {
+                ProposalKind::RecoverClient
+            },
+            authority: self.authority.clone(),
+            deposit: self.deposit.clone().unwrap_or_default(),
+            title: self.title.clone(),
+        };
+
+        let recovery = build_recover_client_proposal(host_chain, reference_chain, &opts)
+            .map_err(Error::recover_client)?;
+
+        let proposal = serde_json::to_string_pretty(&recovery.proposal)
+            .map_err(|e| Error::cli_arg(format!("failed to encode the proposal: {e}")))?;
+
+        std::fs::write(&self.output, proposal).map_err(Error::io)?;
+
+        Ok(serde_json::json!({
+            "subject_client_id": recovery.subject_client_id,
+            "substitute_client_id": recovery.substitute_client_id,
+            "created_substitute": recovery.created_substitute,
+            "proposal_file": self.output,
+        }))
+    }
+}
+
 fn parse_trust_threshold(input: &str) -> Result<TrustThreshold, Error> {
     let (num_part, denom_part) = input.split_once('/').ok_or_else(|| {
         Error::cli_arg("expected a fractional argument, two numbers separated by '/'".into())
@@ -596,10 +765,11 @@ impl OutputBuffer {
 #[cfg(test)]
 mod tests {
     use super::{
-        parse_trust_threshold, TxCreateClientCmd, TxUpdateClientCmd, TxUpgradeClientCmd,
-        TxUpgradeClientsCmd,
+        parse_trust_threshold, TxCreateClientCmd, TxRecoverClientCmd, TxUpdateClientCmd,
+        TxUpgradeClientCmd, TxUpgradeClientsCmd,
     };
 
+    use std::path::PathBuf;
     use std::str::FromStr;
 
     use abscissa_core::clap::Parser;
@@ -1002,6 +1172,8 @@ mod tests {
                 chain_id: ChainId::from_string("chain_id"),
                 client_id: ClientId::from_str("client_to_upgrade").unwrap(),
                 reference_upgrade_height: 42,
+                generate_only: false,
+                signer: None,
             },
             TxUpgradeClientCmd::parse_from([
                 "test",
@@ -1015,6 +1187,47 @@ mod tests {
         )
     }
 
+    #[test]
+    fn test_upgrade_client_generate_only() {
+        assert_eq!(
+            TxUpgradeClientCmd {
+                chain_id: ChainId::from_string("chain_id"),
+                client_id: ClientId::from_str("client_to_upgrade").unwrap(),
+                reference_upgrade_height: 42,
+                generate_only: true,
+                signer: Some("cosmos1multisig".to_owned()),
+            },
+            TxUpgradeClientCmd::parse_from([
+                "test",
+                "--host-chain",
+                "chain_id",
+                "--client",
+                "client_to_upgrade",
+                "--upgrade-height",
+                "42",
+                "--generate-only",
+                "--signer",
+                "cosmos1multisig"
+            ])
+        )
+    }
+
+    #[test]
+    fn test_upgrade_client_signer_without_generate_only() {
+        assert!(TxUpgradeClientCmd::try_parse_from([
+            "test",
+            "--host-chain",
+            "chain_id",
+            "--client",
+            "client_to_upgrade",
+            "--upgrade-height",
+            "42",
+            "--signer",
+            "cosmos1multisig"
+        ])
+        .is_err())
+    }
+
     #[test]
     fn test_upgrade_client_no_chain() {
         assert!(TxUpgradeClientCmd::try_parse_from([
@@ -1101,4 +1314,62 @@ mod tests {
     fn test_upgrade_clients_no_chain() {
         assert!(TxUpgradeClientsCmd::try_parse_from(["test", "--upgrade-height", "42"]).is_err())
     }
+
+    #[test]
+    fn test_recover_client_required_only() {
+        assert_eq!(
+            TxRecoverClientCmd {
+                chain_id: ChainId::from_string("chain_host_id"),
+                subject_client_id: ClientId::from_str("07-tendermint-0").unwrap(),
+                substitute_client_id: None,
+                legacy: false,
+                authority: None,
+                deposit: None,
+                title: None,
+                output: PathBuf::from("recover-client-proposal.json"),
+            },
+            TxRecoverClientCmd::parse_from([
+                "test",
+                "--chain",
+                "chain_host_id",
+                "--subject",
+                "07-tendermint-0"
+            ])
+        )
+    }
+
+    #[test]
+    fn test_recover_client_substitute() {
+        assert_eq!(
+            TxRecoverClientCmd {
+                chain_id: ChainId::from_string("chain_host_id"),
+                subject_client_id: ClientId::from_str("07-tendermint-0").unwrap(),
+                substitute_client_id: Some(ClientId::from_str("07-tendermint-1").unwrap()),
+                legacy: true,
+                authority: None,
+                deposit: Some("10000000stake".to_string()),
+                title: None,
+                output: PathBuf::from("proposal.json"),
+            },
+            TxRecoverClientCmd::parse_from([
+                "test",
+                "--chain",
+                "chain_host_id",
+                "--subject",
+                "07-tendermint-0",
+                "--substitute",
+                "07-tendermint-1",
+                "--legacy",
+                "--deposit",
+                "10000000stake",
+                "--output",
+                "proposal.json"
+            ])
+        )
+    }
+
+    #[test]
+    fn test_recover_client_no_subject() {
+        assert!(TxRecoverClientCmd::try_parse_from(["test", "--chain", "chain_host_id"]).is_err())
+    }
 }
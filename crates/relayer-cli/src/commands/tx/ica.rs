@@ -0,0 +1,310 @@
+use core::time::Duration;
+use std::path::PathBuf;
+
+use abscissa_core::clap::Parser;
+use abscissa_core::{config::Override, Command, FrameworkErrorKind, Runnable};
+
+use ibc_relayer::chain::handle::ChainHandle;
+use ibc_relayer::chain::tracking::TrackedMsgs;
+use ibc_relayer::config::Config;
+use ibc_relayer::event::IbcEventWithHeight;
+use ibc_relayer_types::applications::ics27_ica::msgs::register::MsgRegisterInterchainAccount;
+use ibc_relayer_types::applications::ics27_ica::msgs::send_tx::MsgSendTx;
+use ibc_relayer_types::applications::ics27_ica::packet_data::{
+    CosmosTx, InterchainAccountPacketData,
+};
+use ibc_relayer_types::core::ics24_host::identifier::{ChainId, ConnectionId};
+use ibc_relayer_types::tx_msg::Msg;
+
+use crate::application::app_config;
+use crate::cli_utils::spawn_chain_runtime;
+use crate::conclude::{exit_with_unrecoverable_error, Output};
+use crate::error::Error;
+
+/// Overrides the signing key of the given controller chain.
+fn override_key_name(
+    mut config: Config,
+    chain_id: &ChainId,
+    key_name: &Option<String>,
+) -> Result<Config, abscissa_core::FrameworkError> {
+    let chain_config = config.find_chain_mut(chain_id).ok_or_else(|| {
+        FrameworkErrorKind::ComponentError
+            .context(format!("missing configuration for chain '{chain_id}'"))
+    })?;
+
+    if let Some(ref key_name) = key_name {
+        chain_config.key_name = key_name.to_string();
+        // Only submit transactions with the given key
+        chain_config.key_names.clear();
+    }
+
+    Ok(config)
+}
+
+/// Register an interchain account on the host chain at the other end of the given connection.
+///
+/// The account is owned by the signing key of the controller chain.
+///
+/// `tx ica-register --chain <CHAIN_ID> --connection <CONNECTION_ID>`
+#[derive(Clone, Command, Debug, Parser, PartialEq, Eq)]
+pub struct TxIcaRegisterCmd {
+    #[clap(
+        long = "chain",
+        required = true,
+        value_name = "CHAIN_ID",
+        help_heading = "REQUIRED",
+        help = "Identifier of the controller chain"
+    )]
+    chain_id: ChainId,
+
+    #[clap(
+        long = "connection",
+        visible_alias = "conn",
+        required = true,
+        value_name = "CONNECTION_ID",
+        help_heading = "REQUIRED",
+        help = "Identifier of the connection to the host chain"
+    )]
+    connection_id: ConnectionId,
+
+    #[clap(
+        long = "version",
+        value_name = "VERSION",
+        help = "Version of the interchain account channel (default: negotiated by the controller chain)"
+    )]
+    version: Option<String>,
+
+    #[clap(
+        long = "key-name",
+        value_name = "KEY_NAME",
+        help = "Use the given signing key name (default: `key_name` config)"
+    )]
+    key_name: Option<String>,
+}
+
+impl Override<Config> for TxIcaRegisterCmd {
+    fn override_config(&self, config: Config) -> Result<Config, abscissa_core::FrameworkError> {
+        override_key_name(config, &self.chain_id, &self.key_name)
+    }
+}
+
+impl Runnable for TxIcaRegisterCmd {
+    fn run(&self) {
+        match run_ica_register_command(&self.chain_id, &self.connection_id, &self.version) {
+            Ok(events) => Output::success(events).exit(),
+            Err(e) => Output::error(e).exit(),
+        }
+    }
+}
+
+fn run_ica_register_command(
+    chain_id: &ChainId,
+    connection_id: &ConnectionId,
+    version: &Option<String>,
+) -> Result<Vec<IbcEventWithHeight>, Error> {
+    let config = app_config();
+
+    let chain_handle = spawn_chain_runtime(&config, chain_id)?;
+
+    let owner = chain_handle.get_signer().map_err(Error::relayer)?;
+
+    let message = MsgRegisterInterchainAccount {
+        owner,
+        connection_id: connection_id.clone(),
+        version: version.clone().unwrap_or_default(),
+    };
+
+    let messages = TrackedMsgs::new_static(vec![message.to_any()], "cli");
+
+    chain_handle
+        .send_messages_and_wait_commit(messages)
+        .map_err(Error::relayer)
+}
+
+/// Execute a list of messages with an interchain account on the host chain
+/// at the other end of the given connection.
+///
+/// The messages are read from a JSON file holding a list of messages, each
+/// with its type URL in an `@type` field.
+///
+/// `tx ica-send --chain <CHAIN_ID> --connection <CONNECTION_ID> --messages <MESSAGES_FILE>`
+#[derive(Clone, Command, Debug, Parser, PartialEq, Eq)]
+pub struct TxIcaSendCmd {
+    #[clap(
+        long = "chain",
+        required = true,
+        value_name = "CHAIN_ID",
+        help_heading = "REQUIRED",
+        help = "Identifier of the controller chain"
+    )]
+    chain_id: ChainId,
+
+    #[clap(
+        long = "connection",
+        visible_alias = "conn",
+        required = true,
+        value_name = "CONNECTION_ID",
+        help_heading = "REQUIRED",
+        help = "Identifier of the connection to the host chain"
+    )]
+    connection_id: ConnectionId,
+
+    #[clap(
+        long = "messages",
+        required = true,
+        value_name = "MESSAGES_FILE",
+        help_heading = "REQUIRED",
+        help = "Path to a JSON file holding the list of messages to execute"
+    )]
+    messages: PathBuf,
+
+    #[clap(
+        long = "memo",
+        value_name = "MEMO",
+        help = "Optional memo included in the packet"
+    )]
+    memo: Option<String>,
+
+    #[clap(
+        long = "timeout-seconds",
+        default_value = "600",
+        value_name = "TIMEOUT_SECONDS",
+        help = "Timeout of the packet in seconds, relative to the latest block of the controller chain"
+    )]
+    timeout_seconds: u64,
+
+    #[clap(
+        long = "key-name",
+        value_name = "KEY_NAME",
+        help = "Use the given signing key name (default: `key_name` config)"
+    )]
+    key_name: Option<String>,
+}
+
+impl Override<Config> for TxIcaSendCmd {
+    fn override_config(&self, config: Config) -> Result<Config, abscissa_core::FrameworkError> {
+        override_key_name(config, &self.chain_id, &self.key_name)
+    }
+}
+
+impl Runnable for TxIcaSendCmd {
+    fn run(&self) {
+        let tx = std::fs::read_to_string(&self.messages)
+            .map_err(Error::io)
+            .and_then(|json| CosmosTx::from_json(&json).map_err(Error::ica))
+            .unwrap_or_else(exit_with_unrecoverable_error);
+
+        let packet_data =
+            InterchainAccountPacketData::new(tx, self.memo.clone().unwrap_or_default());
+
+        match run_ica_send_command(
+            &self.chain_id,
+            &self.connection_id,
+            packet_data,
+            Duration::from_secs(self.timeout_seconds),
+        ) {
+            Ok(events) => Output::success(events).exit(),
+            Err(e) => Output::error(e).exit(),
+        }
+    }
+}
+
+fn run_ica_send_command(
+    chain_id: &ChainId,
+    connection_id: &ConnectionId,
+    packet_data: InterchainAccountPacketData,
+    relative_timeout: Duration,
+) -> Result<Vec<IbcEventWithHeight>, Error> {
+    let config = app_config();
+
+    let chain_handle = spawn_chain_runtime(&config, chain_id)?;
+
+    let owner = chain_handle.get_signer().map_err(Error::relayer)?;
+
+    let message = MsgSendTx {
+        owner,
+        connection_id: connection_id.clone(),
+        packet_data,
+        relative_timeout,
+    };
+
+    let messages = TrackedMsgs::new_static(vec![message.to_any()], "cli");
+
+    chain_handle
+        .send_messages_and_wait_commit(messages)
+        .map_err(Error::relayer)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{TxIcaRegisterCmd, TxIcaSendCmd};
+
+    use abscissa_core::clap::Parser;
+    use std::path::PathBuf;
+    use std::str::FromStr;
+
+    use ibc_relayer_types::core::ics24_host::identifier::{ChainId, ConnectionId};
+
+    #[test]
+    fn test_ica_register() {
+        assert_eq!(
+            TxIcaRegisterCmd {
+                chain_id: ChainId::from_string("chain_a"),
+                connection_id: ConnectionId::from_str("connection-0").unwrap(),
+                version: None,
+                key_name: None,
+            },
+            TxIcaRegisterCmd::parse_from([
+                "test",
+                "--chain",
+                "chain_a",
+                "--connection",
+                "connection-0"
+            ])
+        )
+    }
+
+    #[test]
+    fn test_ica_register_no_connection() {
+        assert!(TxIcaRegisterCmd::try_parse_from(["test", "--chain", "chain_a"]).is_err())
+    }
+
+    #[test]
+    fn test_ica_send() {
+        assert_eq!(
+            TxIcaSendCmd {
+                chain_id: ChainId::from_string("chain_a"),
+                connection_id: ConnectionId::from_str("connection-0").unwrap(),
+                messages: PathBuf::from("msgs.json"),
+                memo: Some("memo".to_owned()),
+                timeout_seconds: 600,
+                key_name: Some("wallet".to_owned()),
+            },
+            TxIcaSendCmd::parse_from([
+                "test",
+                "--chain",
+                "chain_a",
+                "--conn",
+                "connection-0",
+                "--messages",
+                "msgs.json",
+                "--memo",
+                "memo",
+                "--key-name",
+                "wallet"
+            ])
+        )
+    }
+
+    #[test]
+    fn test_ica_send_no_messages() {
+        assert!(TxIcaSendCmd::try_parse_from([
+            "test",
+            "--chain",
+            "chain_a",
+            "--connection",
+            "connection-0"
+        ])
+        .is_err())
+    }
+}
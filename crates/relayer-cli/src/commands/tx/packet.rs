@@ -56,6 +56,12 @@ pub struct TxPacketRecvCmd {
         help = "Exact height at which the packet data is queried via block_results RPC"
     )]
     packet_data_query_height: Option<u64>,
+
+    #[clap(
+        long = "dry-run",
+        help = "Simulate the transactions to estimate their fees, without submitting them"
+    )]
+    dry_run: bool,
 }
 
 impl Runnable for TxPacketRecvCmd {
@@ -72,7 +78,7 @@ impl Runnable for TxPacketRecvCmd {
             src_channel_id: self.src_channel_id.clone(),
         };
         let link = match Link::new_from_opts(chains.src, chains.dst, opts, false, false) {
-            Ok(link) => link,
+            Ok(link) => link.with_dry_run(self.dry_run),
             Err(e) => Output::error(e).exit(),
         };
 
@@ -87,6 +93,7 @@ impl Runnable for TxPacketRecvCmd {
             .map_err(Error::link);
 
         match res {
+            Ok(_) if self.dry_run => Output::success(link.a_to_b.take_simulated_txs()).exit(),
             Ok(ev) => Output::success(ev).exit(),
             Err(e) => Output::error(e).exit(),
         }
@@ -189,7 +196,8 @@ mod tests {
                 src_chain_id: ChainId::from_string("chain_sender"),
                 src_port_id: PortId::from_str("port_sender").unwrap(),
                 src_channel_id: ChannelId::from_str("channel_sender").unwrap(),
-                packet_data_query_height: None
+                packet_data_query_height: None,
+                dry_run: false,
             },
             TxPacketRecvCmd::parse_from([
                 "test",
@@ -213,7 +221,8 @@ mod tests {
                 src_chain_id: ChainId::from_string("chain_sender"),
                 src_port_id: PortId::from_str("port_sender").unwrap(),
                 src_channel_id: ChannelId::from_str("channel_sender").unwrap(),
-                packet_data_query_height: None
+                packet_data_query_height: None,
+                dry_run: false,
             },
             TxPacketRecvCmd::parse_from([
                 "test",
@@ -237,6 +246,7 @@ mod tests {
                 src_port_id: PortId::from_str("port_sender").unwrap(),
                 src_channel_id: ChannelId::from_str("channel_sender").unwrap(),
                 packet_data_query_height: Some(5),
+                dry_run: false,
             },
             TxPacketRecvCmd::parse_from([
                 "test",
@@ -254,6 +264,32 @@ mod tests {
         )
     }
 
+    #[test]
+    fn test_packet_recv_dry_run() {
+        assert_eq!(
+            TxPacketRecvCmd {
+                dst_chain_id: ChainId::from_string("chain_receiver"),
+                src_chain_id: ChainId::from_string("chain_sender"),
+                src_port_id: PortId::from_str("port_sender").unwrap(),
+                src_channel_id: ChannelId::from_str("channel_sender").unwrap(),
+                packet_data_query_height: None,
+                dry_run: true,
+            },
+            TxPacketRecvCmd::parse_from([
+                "test",
+                "--dst-chain",
+                "chain_receiver",
+                "--src-chain",
+                "chain_sender",
+                "--src-port",
+                "port_sender",
+                "--src-channel",
+                "channel_sender",
+                "--dry-run"
+            ])
+        )
+    }
+
     #[test]
     fn test_packet_recv_no_sender_channel() {
         assert!(TxPacketRecvCmd::try_parse_from([
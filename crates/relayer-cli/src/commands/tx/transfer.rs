@@ -8,14 +8,14 @@ use ibc_relayer::{
     chain::handle::ChainHandle,
     config::Config,
     event::IbcEventWithHeight,
-    transfer::{build_and_send_transfer_messages, TransferOptions},
+    transfer::{build_and_send_transfer_messages, build_transfer_messages, TransferOptions},
 };
 use ibc_relayer_types::{
     applications::transfer::Amount,
     core::ics24_host::identifier::{ChainId, ChannelId, PortId},
 };
 
-use crate::cli_utils::{check_can_send_on_channel, ChainHandlePair};
+use crate::cli_utils::{check_can_send_on_channel, unsigned_tx_json, ChainHandlePair};
 use crate::conclude::{exit_with_unrecoverable_error, Output};
 use crate::error::Error;
 use crate::prelude::*;
@@ -119,6 +119,20 @@ pub struct TxIcs20MsgTransferCmd {
         help = "Optional memo included in the transfer"
     )]
     memo: Option<String>,
+
+    #[clap(
+        long = "generate-only",
+        help = "Output the unsigned transaction in the JSON format of the Cosmos SDK, to be signed offline, instead of submitting it. With `--json`, the transaction is the `result` of the output"
+    )]
+    generate_only: bool,
+
+    #[clap(
+        long = "signer",
+        value_name = "ADDRESS",
+        requires = "generate-only",
+        help = "Address of the account sending the tokens and signing the generated transaction, eg. a multisig account (default: the relayer's account)"
+    )]
+    signer: Option<String>,
 }
 
 impl Override<Config> for TxIcs20MsgTransferCmd {
@@ -132,6 +146,8 @@ impl Override<Config> for TxIcs20MsgTransferCmd {
 
         if let Some(ref key_name) = self.key_name {
             src_chain_config.key_name = key_name.to_string();
+            // Only submit transactions with the given key
+            src_chain_config.key_names.clear();
         }
 
         Ok(config)
@@ -197,6 +213,19 @@ impl Runnable for TxIcs20MsgTransferCmd {
         )
         .unwrap_or_else(exit_with_unrecoverable_error);
 
+        if self.generate_only {
+            let tx = build_transfer_messages(&chains.src, &chains.dst, &opts)
+                .map_err(Error::transfer)
+                .and_then(|msgs| {
+                    unsigned_tx_json(&config, &self.src_chain_id, &msgs, self.signer.as_deref())
+                });
+
+            match tx {
+                Ok(tx) => Output::success(tx).exit(),
+                Err(e) => Output::error(e).exit(),
+            }
+        }
+
         // Checks pass, build and send the tx
         let res: Result<Vec<IbcEventWithHeight>, Error> =
             build_and_send_transfer_messages(&chains.src, &chains.dst, &opts)
@@ -237,6 +266,8 @@ mod tests {
                 number_msgs: None,
                 key_name: None,
                 memo: None,
+                generate_only: false,
+                signer: None,
             },
             TxIcs20MsgTransferCmd::parse_from([
                 "test",
@@ -270,6 +301,8 @@ mod tests {
                 number_msgs: None,
                 key_name: None,
                 memo: None,
+                generate_only: false,
+                signer: None,
             },
             TxIcs20MsgTransferCmd::parse_from([
                 "test",
@@ -303,6 +336,8 @@ mod tests {
                 number_msgs: None,
                 key_name: None,
                 memo: None,
+                generate_only: false,
+                signer: None,
             },
             TxIcs20MsgTransferCmd::parse_from([
                 "test",
@@ -338,6 +373,8 @@ mod tests {
                 number_msgs: None,
                 key_name: Some("key_name".to_owned()),
                 memo: None,
+                generate_only: false,
+                signer: None,
             },
             TxIcs20MsgTransferCmd::parse_from([
                 "test",
@@ -373,6 +410,8 @@ mod tests {
                 number_msgs: Some(21),
                 key_name: None,
                 memo: None,
+                generate_only: false,
+                signer: None,
             },
             TxIcs20MsgTransferCmd::parse_from([
                 "test",
@@ -408,6 +447,8 @@ mod tests {
                 number_msgs: None,
                 key_name: None,
                 memo: None,
+                generate_only: false,
+                signer: None,
             },
             TxIcs20MsgTransferCmd::parse_from([
                 "test",
@@ -443,6 +484,8 @@ mod tests {
                 number_msgs: None,
                 key_name: None,
                 memo: None,
+                generate_only: false,
+                signer: None,
             },
             TxIcs20MsgTransferCmd::parse_from([
                 "test",
@@ -478,6 +521,8 @@ mod tests {
                 number_msgs: None,
                 key_name: None,
                 memo: None,
+                generate_only: false,
+                signer: None,
             },
             TxIcs20MsgTransferCmd::parse_from([
                 "test",
@@ -513,6 +558,8 @@ mod tests {
                 number_msgs: None,
                 key_name: None,
                 memo: Some("test memo".to_owned()),
+                generate_only: false,
+                signer: None,
             },
             TxIcs20MsgTransferCmd::parse_from([
                 "test",
@@ -532,6 +579,44 @@ mod tests {
         )
     }
 
+    #[test]
+    fn test_ft_transfer_generate_only() {
+        assert_eq!(
+            TxIcs20MsgTransferCmd {
+                dst_chain_id: ChainId::from_string("chain_receiver"),
+                src_chain_id: ChainId::from_string("chain_sender"),
+                src_port_id: PortId::from_str("port_sender").unwrap(),
+                src_channel_id: ChannelId::from_str("channel_sender").unwrap(),
+                amount: Amount::from(42u64),
+                timeout_height_offset: 0,
+                timeout_seconds: 0,
+                receiver: None,
+                denom: "samoleans".to_owned(),
+                number_msgs: None,
+                key_name: None,
+                memo: None,
+                generate_only: true,
+                signer: Some("cosmos1multisig".to_owned()),
+            },
+            TxIcs20MsgTransferCmd::parse_from([
+                "test",
+                "--dst-chain",
+                "chain_receiver",
+                "--src-chain",
+                "chain_sender",
+                "--src-port",
+                "port_sender",
+                "--src-channel",
+                "channel_sender",
+                "--amount",
+                "42",
+                "--generate-only",
+                "--signer",
+                "cosmos1multisig"
+            ])
+        )
+    }
+
     #[test]
     fn test_ft_transfer_no_amount() {
         assert!(TxIcs20MsgTransferCmd::try_parse_from([
@@ -8,7 +8,9 @@ use alloc::collections::BTreeSet;
 use std::path::PathBuf;
 
 use flex_error::{define_error, TraceError};
-use ibc_relayer::config::{ChainConfig, Config, ModeConfig};
+use ibc_relayer::config::paths::PathConfig;
+use ibc_relayer::config::{ChainConfig, Config, ModeConfig, PacketFilter};
+use ibc_relayer::keyring::Store;
 use ibc_relayer_types::core::ics24_host::identifier::ChainId;
 use tendermint_light_client_verifier::types::TrustThreshold;
 use tracing_subscriber::filter::ParseError;
@@ -73,6 +75,34 @@ define_error! {
                     e.chain_id, e.gas_adjustment, e.gas_multiplier
                 )
             },
+
+        InvalidPath
+            {
+                a_chain: ChainId,
+                b_chain: ChainId,
+                reason: String,
+            }
+            |e| {
+                format!("config file specifies an invalid path between the chains '{0}' and '{1}', caused by: {2}",
+                    e.a_chain, e.b_chain, e.reason)
+            },
+
+        InvalidProfitabilityPolicy
+            {
+                chain_id: ChainId,
+                reason: String,
+            }
+            |e| {
+                format!("config file specifies an invalid `profitability` fee policy for the chain '{0}', caused by: {1}",
+                    e.chain_id, e.reason)
+            },
+
+        MissingRemoteSigner
+            { chain_id: ChainId }
+            |e| {
+                format!("config file specifies `key_store_type = 'Remote'` for the chain '{0}' but does not specify its `remote_signer`",
+                    e.chain_id)
+            },
     }
 }
 
@@ -96,6 +126,21 @@ pub fn validate_config(config: &Config) -> Result<(), Diagnostic<Error>> {
 
         // Validate gas-related settings
         validate_gas_settings(&c.id, c)?;
+
+        // Validate the profitability fee policies
+        validate_profitability_policies(&c.id, &c.packet_filter)?;
+
+        // Validate the remote signer settings
+        if c.key_store_type == Store::Remote && c.remote_signer.is_none() {
+            return Err(Diagnostic::Error(Error::missing_remote_signer(
+                c.id.clone(),
+            )));
+        }
+    }
+
+    // Check that the paths are between two different chains of the config
+    for path in config.paths.iter() {
+        validate_path(config, path)?;
     }
 
     // Check for invalid mode config
@@ -104,6 +149,68 @@ pub fn validate_config(config: &Config) -> Result<(), Diagnostic<Error>> {
     Ok(())
 }
 
+fn validate_path(config: &Config, path: &PathConfig) -> Result<(), Diagnostic<Error>> {
+    let invalid_path = |reason: String| {
+        Diagnostic::Error(Error::invalid_path(
+            path.a_chain.clone(),
+            path.b_chain.clone(),
+            reason,
+        ))
+    };
+
+    if path.a_chain == path.b_chain {
+        return Err(invalid_path(
+            "a path must be between two different chains".to_string(),
+        ));
+    }
+
+    for chain_id in [&path.a_chain, &path.b_chain] {
+        if !config.has_chain(chain_id) {
+            return Err(invalid_path(format!(
+                "chain '{chain_id}' is not present in the config"
+            )));
+        }
+    }
+
+    Ok(())
+}
+
+/// Check that the minimum margin and the prices of the profitability
+/// fee policies are neither negative, infinite nor NaN.
+fn validate_profitability_policies(
+    id: &ChainId,
+    packet_filter: &PacketFilter,
+) -> Result<(), Diagnostic<Error>> {
+    let invalid_policy =
+        |reason: String| Diagnostic::Error(Error::invalid_profitability_policy(id.clone(), reason));
+
+    let is_valid = |value: f64| value.is_finite() && value >= 0.0;
+
+    let policies = packet_filter
+        .min_fees
+        .values()
+        .filter_map(|fee_policy| fee_policy.profitability());
+
+    for policy in policies {
+        if !is_valid(policy.min_margin()) {
+            return Err(invalid_policy(format!(
+                "`min_margin` must be a non-negative number, got {}",
+                policy.min_margin()
+            )));
+        }
+
+        for (denom, price) in policy.prices() {
+            if !is_valid(*price) {
+                return Err(invalid_policy(format!(
+                    "the price of '{denom}' must be a non-negative number, got {price}"
+                )));
+            }
+        }
+    }
+
+    Ok(())
+}
+
 fn validate_mode(mode: &ModeConfig) -> Result<(), Diagnostic<Error>> {
     if mode.all_disabled() {
         return Err(Diagnostic::Warning(Error::invalid_mode(
@@ -170,3 +277,49 @@ fn validate_gas_settings(id: &ChainId, config: &ChainConfig) -> Result<(), Diagn
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::collections::HashMap;
+
+    use ibc_relayer::config::filter::{
+        ChannelFilterMatch, ChannelPolicy, FeePolicy, ProfitabilityPolicy,
+    };
+    use ibc_relayer_types::core::ics24_host::identifier::ChannelId;
+
+    fn packet_filter(min_margin: f64, price: f64) -> PacketFilter {
+        let profitability =
+            ProfitabilityPolicy::new(min_margin, HashMap::from([("stake".to_string(), price)]));
+
+        PacketFilter::new(
+            ChannelPolicy::AllowAll,
+            HashMap::from([(
+                ChannelFilterMatch::Exact(ChannelId::new(0)),
+                FeePolicy::new(vec![]).with_profitability(profitability),
+            )]),
+        )
+    }
+
+    #[test]
+    fn validate_profitability_policy() {
+        let id = ChainId::from_string("ibc-0");
+
+        assert!(validate_profitability_policies(&id, &packet_filter(0.2, 1.0)).is_ok());
+        assert!(validate_profitability_policies(&id, &packet_filter(0.0, 0.0)).is_ok());
+
+        for (min_margin, price) in [
+            (-0.2, 1.0),
+            (f64::NAN, 1.0),
+            (f64::INFINITY, 1.0),
+            (0.2, -1.0),
+            (0.2, f64::NAN),
+        ] {
+            assert!(matches!(
+                validate_profitability_policies(&id, &packet_filter(min_margin, price)),
+                Err(Diagnostic::Error(_))
+            ));
+        }
+    }
+}
@@ -5,6 +5,7 @@ use std::io::Error as IoError;
 
 use tendermint::Error as TendermintError;
 
+use ibc_relayer_types::applications::ics27_ica::error::Error as IcaError;
 use ibc_relayer_types::applications::ics29_fee::error::Error as FeeError;
 use ibc_relayer_types::core::ics04_channel::channel::IdentifiedChannelEnd;
 use ibc_relayer_types::core::ics24_host::identifier::ChainId;
@@ -12,10 +13,12 @@ use ibc_relayer_types::signer::SignerError;
 
 use ibc_relayer::channel::ChannelError;
 use ibc_relayer::connection::ConnectionError;
+use ibc_relayer::economics::EconomicsError;
 use ibc_relayer::error::Error as RelayerError;
 use ibc_relayer::foreign_client::ForeignClientError;
 use ibc_relayer::keyring::errors::Error as KeyRingError;
 use ibc_relayer::link::error::LinkError;
+use ibc_relayer::recover_client::RecoverClientError;
 use ibc_relayer::spawn::SpawnError;
 use ibc_relayer::supervisor::Error as SupervisorError;
 use ibc_relayer::transfer::TransferError;
@@ -88,6 +91,10 @@ define_error! {
             [ FeeError ]
             |_| { "fee error" },
 
+        Ica
+            [ IcaError ]
+            |_| { "interchain account error" },
+
         Transfer
             [ TransferError ]
             |_| { "transfer error" },
@@ -112,6 +119,14 @@ define_error! {
             [ UpgradeChainError ]
             |_| { "upgrade chain error" },
 
+        RecoverClient
+            [ RecoverClientError ]
+            |_| { "recover client error" },
+
+        Economics
+            [ EconomicsError ]
+            |_| { "relayer economics error" },
+
         Signer
             [ SignerError ]
             |_| { "signer error" },
@@ -4,15 +4,19 @@ use tracing::error;
 
 use crossbeam_channel as channel;
 
+use ibc_relayer::chain::counterparty::PendingPackets;
 use ibc_relayer::supervisor::dump_state::SupervisorState;
 use ibc_relayer::{
     config::ChainConfig,
     rest::{
-        request::{reply_channel, ReplySender, Request, VersionInfo},
+        request::{reply_channel, ClearPacketsOutcome, ReplySender, Request, VersionInfo},
         RestApiError,
     },
 };
-use ibc_relayer_types::core::ics24_host::identifier::ChainId;
+use ibc_relayer_types::core::ics24_host::{
+    error::ValidationError,
+    identifier::{ChainId, ChannelId, PortId},
+};
 
 pub const NAME: &str = env!(
     "CARGO_PKG_NAME",
@@ -64,6 +68,54 @@ pub fn supervisor_state(
     submit_request(sender, |reply_to| Request::State { reply_to })
 }
 
+fn parse_channel(port_id: &str, channel_id: &str) -> Result<(PortId, ChannelId), RestApiError> {
+    let port = port_id
+        .parse()
+        .map_err(|e: ValidationError| RestApiError::InvalidPortId(port_id.to_string(), e.0))?;
+
+    let channel = channel_id.parse().map_err(|e: ValidationError| {
+        RestApiError::InvalidChannelId(channel_id.to_string(), e.0)
+    })?;
+
+    Ok((port, channel))
+}
+
+pub fn pending_packets(
+    sender: &channel::Sender<Request>,
+    chain_id: &str,
+    port_id: &str,
+    channel_id: &str,
+) -> Result<PendingPackets, RestApiError> {
+    let (port_id, channel_id) = parse_channel(port_id, channel_id)?;
+
+    submit_request(sender, |reply_to| Request::GetPendingPackets {
+        chain_id: ChainId::from_string(chain_id),
+        port_id,
+        channel_id,
+        reply_to,
+    })
+}
+
+pub fn clear_packets(
+    sender: &channel::Sender<Request>,
+    chain_id: &str,
+    port_id: &str,
+    channel_id: &str,
+) -> Result<ClearPacketsOutcome, RestApiError> {
+    let (port_id, channel_id) = parse_channel(port_id, channel_id)?;
+
+    submit_request(sender, |reply_to| Request::ClearPackets {
+        chain_id: ChainId::from_string(chain_id),
+        port_id,
+        channel_id,
+        reply_to,
+    })
+}
+
+pub fn reload_config(sender: &channel::Sender<Request>) -> Result<(), RestApiError> {
+    submit_request(sender, |reply_to| Request::ReloadConfig { reply_to })
+}
+
 pub fn assemble_version_info(sender: &channel::Sender<Request>) -> Vec<VersionInfo> {
     // Fetch the relayer library version
     let lib_version = submit_request(sender, |reply_to| Request::Version { reply_to })
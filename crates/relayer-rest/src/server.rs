@@ -3,7 +3,12 @@ use std::{
     net::{SocketAddr, ToSocketAddrs},
 };
 
-use axum::{extract::Path, response::IntoResponse, routing::get, Extension, Json, Router, Server};
+use axum::{
+    extract::Path,
+    response::IntoResponse,
+    routing::{get, post},
+    Extension, Json, Router, Server,
+};
 use crossbeam_channel as channel;
 use serde::{Deserialize, Serialize};
 use tokio::task::JoinHandle;
@@ -13,7 +18,10 @@ use ibc_relayer::{
     supervisor::dump_state::SupervisorState,
 };
 
-use crate::handle::{all_chain_ids, assemble_version_info, chain_config, supervisor_state};
+use crate::handle::{
+    all_chain_ids, assemble_version_info, chain_config, clear_packets, pending_packets,
+    reload_config, supervisor_state,
+};
 
 pub type BoxError = Box<dyn Error + Send + Sync>;
 
@@ -68,6 +76,27 @@ async fn get_state(
     Json(JsonResult::from(state))
 }
 
+async fn get_pending_packets(
+    Path((chain_id, port_id, channel_id)): Path<(String, String, String)>,
+    Extension(sender): Extension<Sender>,
+) -> impl IntoResponse {
+    let pending = pending_packets(&sender, &chain_id, &port_id, &channel_id);
+    Json(JsonResult::from(pending))
+}
+
+async fn post_clear_packets(
+    Path((chain_id, port_id, channel_id)): Path<(String, String, String)>,
+    Extension(sender): Extension<Sender>,
+) -> impl IntoResponse {
+    let outcome = clear_packets(&sender, &chain_id, &port_id, &channel_id);
+    Json(JsonResult::from(outcome))
+}
+
+async fn post_reload_config(Extension(sender): Extension<Sender>) -> impl IntoResponse {
+    let result = reload_config(&sender);
+    Json(JsonResult::from(result))
+}
+
 type Sender = channel::Sender<Request>;
 
 async fn run(addr: SocketAddr, sender: Sender) {
@@ -76,6 +105,15 @@ async fn run(addr: SocketAddr, sender: Sender) {
         .route("/chains", get(get_chains))
         .route("/chain/:id", get(get_chain))
         .route("/state", get(get_state))
+        .route(
+            "/pending_packets/:chain/:port/:channel",
+            get(get_pending_packets),
+        )
+        .route(
+            "/clear_packets/:chain/:port/:channel",
+            post(post_clear_packets),
+        )
+        .route("/reload_config", post(post_reload_config))
         .layer(Extension(sender));
 
     Server::bind(&addr)
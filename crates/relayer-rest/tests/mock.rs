@@ -3,11 +3,17 @@ use std::{fmt::Debug, str::FromStr, time::Duration};
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
 
 use ibc_relayer::{
+    chain::counterparty::PendingPackets,
     config::ChainConfig,
-    rest::request::{Request, VersionInfo},
-    supervisor::dump_state::SupervisorState,
+    object::{Object, Packet},
+    rest::request::{ClearPacketsOutcome, Request, VersionInfo},
+    supervisor::dump_state::{SupervisorState, WorkerDesc},
+    worker::WorkerId,
+};
+use ibc_relayer_types::core::{
+    ics04_channel::packet::Sequence,
+    ics24_host::identifier::{ChainId, ChannelId, PortId},
 };
-use ibc_relayer_types::core::ics24_host::identifier::ChainId;
 
 use ibc_relayer_rest::spawn;
 
@@ -28,6 +34,19 @@ async fn run_test<R, F>(port: u16, path: &str, expected: R, handler: F)
 where
     R: Serialize + DeserializeOwned + Debug + PartialEq,
     F: FnOnce(Request) -> TestResult + Send + 'static,
+{
+    run_test_with_method(reqwest::Method::GET, port, path, expected, handler).await
+}
+
+async fn run_test_with_method<R, F>(
+    method: reqwest::Method,
+    port: u16,
+    path: &str,
+    expected: R,
+    handler: F,
+) where
+    R: Serialize + DeserializeOwned + Debug + PartialEq,
+    F: FnOnce(Request) -> TestResult + Send + 'static,
 {
     let (tx, rx) = crossbeam_channel::unbounded();
 
@@ -43,7 +62,9 @@ where
 
     tokio::time::sleep(Duration::from_millis(500)).await;
 
-    let response = reqwest::get(&format!("http://127.0.0.1:{port}{path}"))
+    let response = reqwest::Client::new()
+        .request(method, format!("http://127.0.0.1:{port}{path}"))
+        .send()
         .await
         .unwrap()
         .json::<R>()
@@ -142,3 +163,93 @@ async fn state() {
     })
     .await;
 }
+
+#[tokio::test]
+async fn pending_packets() {
+    let pending = PendingPackets {
+        unreceived_packets: vec![Sequence::from(1), Sequence::from(2)],
+        unreceived_acks: vec![Sequence::from(3)],
+        excluded: vec![],
+    };
+    let result: JsonResult<_, ()> = JsonResult::Success(pending.clone());
+
+    run_test(
+        19105,
+        "/pending_packets/mock-0/transfer/channel-0",
+        result,
+        |req| match req {
+            Request::GetPendingPackets {
+                chain_id,
+                port_id,
+                channel_id,
+                reply_to,
+            } if chain_id.as_str() == "mock-0"
+                && port_id == PortId::transfer()
+                && channel_id == ChannelId::new(0) =>
+            {
+                reply_to.send(Ok(pending)).unwrap();
+                TestResult::Success
+            }
+            req => TestResult::WrongRequest(req),
+        },
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn clear_packets() {
+    let object = Object::Packet(Packet {
+        dst_chain_id: ChainId::from_str("mock-1").unwrap(),
+        src_chain_id: ChainId::from_str("mock-0").unwrap(),
+        src_channel_id: ChannelId::new(0),
+        src_port_id: PortId::transfer(),
+    });
+
+    let outcome = ClearPacketsOutcome {
+        worker: WorkerDesc::new(WorkerId::new(1), object, None),
+    };
+    let result: JsonResult<_, ()> = JsonResult::Success(outcome.clone());
+
+    run_test_with_method(
+        reqwest::Method::POST,
+        19106,
+        "/clear_packets/mock-0/transfer/channel-0",
+        result,
+        |req| match req {
+            Request::ClearPackets {
+                chain_id,
+                port_id,
+                channel_id,
+                reply_to,
+            } if chain_id.as_str() == "mock-0"
+                && port_id == PortId::transfer()
+                && channel_id == ChannelId::new(0) =>
+            {
+                reply_to.send(Ok(outcome)).unwrap();
+                TestResult::Success
+            }
+            req => TestResult::WrongRequest(req),
+        },
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn reload_config() {
+    let result: JsonResult<(), ()> = JsonResult::Success(());
+
+    run_test_with_method(
+        reqwest::Method::POST,
+        19107,
+        "/reload_config",
+        result,
+        |req| match req {
+            Request::ReloadConfig { reply_to } => {
+                reply_to.send(Ok(())).unwrap();
+                TestResult::Success
+            }
+            req => TestResult::WrongRequest(req),
+        },
+    )
+    .await;
+}
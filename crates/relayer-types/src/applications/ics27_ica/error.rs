@@ -0,0 +1,37 @@
+use flex_error::{define_error, TraceError};
+
+use crate::core::ics24_host::error::ValidationError;
+use crate::prelude::*;
+use crate::signer::SignerError;
+
+define_error! {
+    #[derive(Debug, PartialEq, Eq)]
+    Error {
+        Signer
+            [ SignerError ]
+            | _ | { "failed to parse signer" },
+
+        Ics24
+            [ ValidationError ]
+            | _ | { "ics24 error" },
+
+        InvalidMessages
+            { reason: String }
+            | e | { format_args!("invalid interchain account messages: {}", e.reason) },
+
+        UnsupportedMessage
+            { type_url: String }
+            | e | {
+                format_args!("unsupported message type {}, messages of this type must be \
+                    given as base64-encoded protobuf in a `value` field", e.type_url)
+            },
+
+        InvalidPacketData
+            { reason: String }
+            | e | { format_args!("invalid interchain account packet data: {}", e.reason) },
+
+        Decode
+            [ TraceError<prost::DecodeError> ]
+            | _ | { "protobuf decode error" },
+    }
+}
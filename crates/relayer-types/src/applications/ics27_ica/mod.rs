@@ -0,0 +1,27 @@
+//! The implementation of the ICS 27 interchain accounts [spec](https://github.com/cosmos/ibc/blob/main/spec/app/ics-027-interchain-accounts/README.md).
+//! Enables an account on a controller chain to register and control an account on a host
+//! chain, by sending it packets holding the transactions to execute on the host chain.
+
+pub mod error;
+pub mod msgs;
+pub mod packet_data;
+pub mod raw;
+
+use crate::prelude::*;
+
+use crate::applications::ics27_ica::error::Error;
+use crate::core::ics24_host::identifier::PortId;
+use crate::signer::Signer;
+
+/// The prefix of the ports bound by interchain account controllers.
+pub const CONTROLLER_PORT_PREFIX: &str = "icacontroller-";
+
+/// The port bound by the interchain account host module.
+pub const HOST_PORT_ID: &str = "icahost";
+
+/// Returns the controller port of the interchain accounts owned by the given account.
+pub fn controller_port_id(owner: &Signer) -> Result<PortId, Error> {
+    format!("{CONTROLLER_PORT_PREFIX}{owner}")
+        .parse()
+        .map_err(Error::ics24)
+}
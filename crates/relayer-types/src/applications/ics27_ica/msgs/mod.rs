@@ -0,0 +1,2 @@
+pub mod register;
+pub mod send_tx;
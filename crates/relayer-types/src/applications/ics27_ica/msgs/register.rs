@@ -0,0 +1,92 @@
+//! Message used by an account of a controller chain to register an interchain
+//! account on a host chain, by opening a channel over the given connection.
+
+use crate::prelude::*;
+
+use ibc_proto::protobuf::Protobuf;
+
+use crate::applications::ics27_ica::error::Error;
+use crate::applications::ics27_ica::raw::MsgRegisterInterchainAccount as RawMsgRegisterInterchainAccount;
+use crate::core::ics24_host::identifier::ConnectionId;
+use crate::signer::Signer;
+use crate::tx_msg::Msg;
+
+pub const TYPE_URL: &str =
+    "/ibc.applications.interchain_accounts.controller.v1.MsgRegisterInterchainAccount";
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MsgRegisterInterchainAccount {
+    /// the account owning the interchain account, which signs the message
+    pub owner: Signer,
+    /// the connection to the host chain
+    pub connection_id: ConnectionId,
+    /// the version of the interchain account channel, which is
+    /// negotiated by the controller chain when left empty
+    pub version: String,
+}
+
+impl Msg for MsgRegisterInterchainAccount {
+    type ValidationError = Error;
+    type Raw = RawMsgRegisterInterchainAccount;
+
+    fn route(&self) -> String {
+        crate::keys::ROUTER_KEY.to_string()
+    }
+
+    fn type_url(&self) -> String {
+        TYPE_URL.to_string()
+    }
+}
+
+impl Protobuf<RawMsgRegisterInterchainAccount> for MsgRegisterInterchainAccount {}
+
+impl TryFrom<RawMsgRegisterInterchainAccount> for MsgRegisterInterchainAccount {
+    type Error = Error;
+
+    fn try_from(raw_msg: RawMsgRegisterInterchainAccount) -> Result<Self, Self::Error> {
+        Ok(MsgRegisterInterchainAccount {
+            owner: raw_msg.owner.parse().map_err(Error::signer)?,
+            connection_id: raw_msg.connection_id.parse().map_err(Error::ics24)?,
+            version: raw_msg.version,
+        })
+    }
+}
+
+impl From<MsgRegisterInterchainAccount> for RawMsgRegisterInterchainAccount {
+    fn from(domain_msg: MsgRegisterInterchainAccount) -> Self {
+        RawMsgRegisterInterchainAccount {
+            owner: domain_msg.owner.to_string(),
+            connection_id: domain_msg.connection_id.to_string(),
+            version: domain_msg.version,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ibc_proto::protobuf::Protobuf;
+    use test_log::test;
+
+    use super::MsgRegisterInterchainAccount;
+    use crate::applications::ics27_ica::raw::MsgRegisterInterchainAccount as RawMsgRegisterInterchainAccount;
+    use crate::core::ics24_host::identifier::ConnectionId;
+    use crate::prelude::*;
+    use crate::test_utils::get_dummy_account_id;
+
+    #[test]
+    fn msg_to_and_from_raw() {
+        let msg = MsgRegisterInterchainAccount {
+            owner: get_dummy_account_id(),
+            connection_id: ConnectionId::new(0),
+            version: String::new(),
+        };
+
+        let raw = RawMsgRegisterInterchainAccount::from(msg.clone());
+        assert_eq!(raw.connection_id, "connection-0");
+        assert_eq!(MsgRegisterInterchainAccount::try_from(raw).unwrap(), msg);
+
+        let encoded = Protobuf::<RawMsgRegisterInterchainAccount>::encode_vec(&msg);
+        let decoded = Protobuf::<RawMsgRegisterInterchainAccount>::decode_vec(&encoded).unwrap();
+        assert_eq!(msg, decoded);
+    }
+}
@@ -0,0 +1,112 @@
+//! Message used by the owner of an interchain account to execute a transaction
+//! with the account on the host chain, by sending it a packet over the channel
+//! of the interchain account.
+
+use crate::prelude::*;
+
+use core::time::Duration;
+
+use ibc_proto::protobuf::Protobuf;
+
+use crate::applications::ics27_ica::error::Error;
+use crate::applications::ics27_ica::packet_data::InterchainAccountPacketData;
+use crate::applications::ics27_ica::raw::MsgSendTx as RawMsgSendTx;
+use crate::core::ics24_host::identifier::ConnectionId;
+use crate::signer::Signer;
+use crate::tx_msg::Msg;
+
+pub const TYPE_URL: &str = "/ibc.applications.interchain_accounts.controller.v1.MsgSendTx";
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MsgSendTx {
+    /// the account owning the interchain account, which signs the message
+    pub owner: Signer,
+    /// the connection to the host chain
+    pub connection_id: ConnectionId,
+    /// the transaction to execute on the host chain
+    pub packet_data: InterchainAccountPacketData,
+    /// Timeout of the packet, relative to the current block timestamp
+    pub relative_timeout: Duration,
+}
+
+impl Msg for MsgSendTx {
+    type ValidationError = Error;
+    type Raw = RawMsgSendTx;
+
+    fn route(&self) -> String {
+        crate::keys::ROUTER_KEY.to_string()
+    }
+
+    fn type_url(&self) -> String {
+        TYPE_URL.to_string()
+    }
+}
+
+impl Protobuf<RawMsgSendTx> for MsgSendTx {}
+
+impl TryFrom<RawMsgSendTx> for MsgSendTx {
+    type Error = Error;
+
+    fn try_from(raw_msg: RawMsgSendTx) -> Result<Self, Self::Error> {
+        let packet_data = raw_msg
+            .packet_data
+            .ok_or_else(|| Error::invalid_packet_data("missing packet data".into()))?
+            .try_into()?;
+
+        Ok(MsgSendTx {
+            owner: raw_msg.owner.parse().map_err(Error::signer)?,
+            connection_id: raw_msg.connection_id.parse().map_err(Error::ics24)?,
+            packet_data,
+            relative_timeout: Duration::from_nanos(raw_msg.relative_timeout),
+        })
+    }
+}
+
+impl From<MsgSendTx> for RawMsgSendTx {
+    fn from(domain_msg: MsgSendTx) -> Self {
+        RawMsgSendTx {
+            owner: domain_msg.owner.to_string(),
+            connection_id: domain_msg.connection_id.to_string(),
+            packet_data: Some(domain_msg.packet_data.into()),
+            relative_timeout: domain_msg.relative_timeout.as_nanos() as u64,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::time::Duration;
+
+    use ibc_proto::google::protobuf::Any;
+    use ibc_proto::protobuf::Protobuf;
+    use test_log::test;
+
+    use super::MsgSendTx;
+    use crate::applications::ics27_ica::packet_data::{CosmosTx, InterchainAccountPacketData};
+    use crate::applications::ics27_ica::raw::MsgSendTx as RawMsgSendTx;
+    use crate::core::ics24_host::identifier::ConnectionId;
+    use crate::prelude::*;
+    use crate::test_utils::get_dummy_account_id;
+
+    #[test]
+    fn msg_to_and_from_raw() {
+        let tx = CosmosTx::new(vec![Any {
+            type_url: "/cosmos.bank.v1beta1.MsgSend".to_string(),
+            value: vec![1, 2, 3],
+        }]);
+
+        let msg = MsgSendTx {
+            owner: get_dummy_account_id(),
+            connection_id: ConnectionId::new(1),
+            packet_data: InterchainAccountPacketData::new(tx, String::new()),
+            relative_timeout: Duration::from_secs(600),
+        };
+
+        let raw = RawMsgSendTx::from(msg.clone());
+        assert_eq!(raw.relative_timeout, 600_000_000_000);
+
+        let encoded = Protobuf::<RawMsgSendTx>::encode_vec(&msg);
+        let decoded = Protobuf::<RawMsgSendTx>::decode_vec(&encoded).unwrap();
+        assert_eq!(msg, decoded);
+    }
+}
@@ -0,0 +1,231 @@
+use crate::prelude::*;
+
+use ibc_proto::cosmos::bank::v1beta1::MsgSend;
+use ibc_proto::google::protobuf::Any;
+use ibc_proto::ibc::applications::interchain_accounts::v1::{
+    CosmosTx as RawCosmosTx, InterchainAccountPacketData as RawInterchainAccountPacketData, Type,
+};
+use ibc_proto::ibc::applications::transfer::v1::MsgTransfer;
+use ibc_proto::protobuf::Protobuf;
+use prost::Message;
+use serde::de::DeserializeOwned;
+use serde_json::{Map, Value};
+use subtle_encoding::base64;
+
+use crate::applications::ics27_ica::error::Error;
+use crate::applications::transfer::msgs::transfer::TYPE_URL as MSG_TRANSFER_TYPE_URL;
+
+pub const MSG_SEND_TYPE_URL: &str = "/cosmos.bank.v1beta1.MsgSend";
+
+/// The messages executed by an interchain account on the host chain.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct CosmosTx {
+    pub messages: Vec<Any>,
+}
+
+impl CosmosTx {
+    pub fn new(messages: Vec<Any>) -> Self {
+        Self { messages }
+    }
+
+    /// Parses a JSON list of messages, each holding its type URL in an `@type` field.
+    ///
+    /// Bank `MsgSend` and ICS20 `MsgTransfer` messages are given by their fields, eg.
+    /// `{"@type": "/cosmos.bank.v1beta1.MsgSend", "from_address": ..., "to_address": ...,
+    /// "amount": [{"denom": ..., "amount": ...}]}`. Messages of any other type are given
+    /// as base64-encoded protobuf, eg. `{"@type": ..., "value": ...}`.
+    pub fn from_json(json: &str) -> Result<Self, Error> {
+        let messages: Vec<Value> = serde_json::from_str(json)
+            .map_err(|e| Error::invalid_messages(format!("expected a list of messages: {e}")))?;
+
+        if messages.is_empty() {
+            return Err(Error::invalid_messages("no messages to send".into()));
+        }
+
+        let messages = messages
+            .into_iter()
+            .map(message_from_json)
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self { messages })
+    }
+}
+
+fn message_from_json(message: Value) -> Result<Any, Error> {
+    let mut fields = match message {
+        Value::Object(fields) => fields,
+        _ => {
+            return Err(Error::invalid_messages(format!(
+                "expected an object, found {message}"
+            )))
+        }
+    };
+
+    let type_url = match fields.remove("@type") {
+        Some(Value::String(type_url)) => type_url,
+        _ => {
+            return Err(Error::invalid_messages(
+                "missing `@type` field in message".into(),
+            ))
+        }
+    };
+
+    match type_url.as_str() {
+        MSG_SEND_TYPE_URL => encode_message::<MsgSend>(type_url, fields),
+        MSG_TRANSFER_TYPE_URL => encode_message::<MsgTransfer>(type_url, fields),
+        _ => match fields.remove("value") {
+            Some(Value::String(value)) if fields.is_empty() => {
+                let value = base64::decode(value).map_err(|e| {
+                    Error::invalid_messages(format!("invalid base64 value of {type_url}: {e}"))
+                })?;
+
+                Ok(Any { type_url, value })
+            }
+            _ => Err(Error::unsupported_message(type_url)),
+        },
+    }
+}
+
+fn encode_message<M>(type_url: String, fields: Map<String, Value>) -> Result<Any, Error>
+where
+    M: Message + DeserializeOwned,
+{
+    let message: M = serde_json::from_value(Value::Object(fields))
+        .map_err(|e| Error::invalid_messages(format!("invalid {type_url}: {e}")))?;
+
+    Ok(Any {
+        type_url,
+        value: message.encode_to_vec(),
+    })
+}
+
+impl Protobuf<RawCosmosTx> for CosmosTx {}
+
+impl From<RawCosmosTx> for CosmosTx {
+    fn from(raw: RawCosmosTx) -> Self {
+        Self {
+            messages: raw.messages,
+        }
+    }
+}
+
+impl From<CosmosTx> for RawCosmosTx {
+    fn from(value: CosmosTx) -> Self {
+        RawCosmosTx {
+            messages: value.messages,
+        }
+    }
+}
+
+/// The data of the packets sent by interchain account controllers,
+/// holding the transaction to execute on the host chain.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct InterchainAccountPacketData {
+    pub tx: CosmosTx,
+    pub memo: String,
+}
+
+impl InterchainAccountPacketData {
+    pub fn new(tx: CosmosTx, memo: String) -> Self {
+        Self { tx, memo }
+    }
+}
+
+impl Protobuf<RawInterchainAccountPacketData> for InterchainAccountPacketData {}
+
+impl TryFrom<RawInterchainAccountPacketData> for InterchainAccountPacketData {
+    type Error = Error;
+
+    fn try_from(raw: RawInterchainAccountPacketData) -> Result<Self, Self::Error> {
+        if raw.r#type != Type::ExecuteTx as i32 {
+            return Err(Error::invalid_packet_data(format!(
+                "unsupported packet type {}",
+                raw.r#type
+            )));
+        }
+
+        let tx = Protobuf::<RawCosmosTx>::decode_vec(&raw.data)
+            .map_err(|e| Error::invalid_packet_data(e.to_string()))?;
+
+        Ok(Self { tx, memo: raw.memo })
+    }
+}
+
+impl From<InterchainAccountPacketData> for RawInterchainAccountPacketData {
+    fn from(value: InterchainAccountPacketData) -> Self {
+        RawInterchainAccountPacketData {
+            r#type: Type::ExecuteTx as i32,
+            data: Protobuf::<RawCosmosTx>::encode_vec(&value.tx),
+            memo: value.memo,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_log::test;
+
+    use crate::applications::ics27_ica::error::ErrorDetail;
+
+    #[test]
+    fn msg_send_from_json() {
+        let json = r#"[{
+            "@type": "/cosmos.bank.v1beta1.MsgSend",
+            "from_address": "cosmos1host",
+            "to_address": "cosmos1receiver",
+            "amount": [{ "denom": "stake", "amount": "1000" }]
+        }]"#;
+
+        let tx = CosmosTx::from_json(json).unwrap();
+        assert_eq!(tx.messages.len(), 1);
+        assert_eq!(tx.messages[0].type_url, MSG_SEND_TYPE_URL);
+
+        let msg = MsgSend::decode(tx.messages[0].value.as_slice()).unwrap();
+        assert_eq!(msg.to_address, "cosmos1receiver");
+        assert_eq!(msg.amount[0].amount, "1000");
+    }
+
+    #[test]
+    fn encoded_message_from_json() {
+        let json = r#"[{ "@type": "/cosmos.staking.v1beta1.MsgDelegate", "value": "CgNmb28=" }]"#;
+
+        let tx = CosmosTx::from_json(json).unwrap();
+        assert_eq!(tx.messages[0].value, b"\n\x03foo".to_vec());
+    }
+
+    #[test]
+    fn unsupported_message_from_json() {
+        let json = r#"[{ "@type": "/cosmos.staking.v1beta1.MsgDelegate", "amount": "1" }]"#;
+
+        match CosmosTx::from_json(json).unwrap_err().into_detail() {
+            ErrorDetail::UnsupportedMessage(e) => {
+                assert_eq!(e.type_url, "/cosmos.staking.v1beta1.MsgDelegate")
+            }
+            e => panic!("expected an unsupported message error, got {e}"),
+        }
+    }
+
+    #[test]
+    fn invalid_messages_from_json() {
+        assert!(CosmosTx::from_json("[]").is_err());
+        assert!(CosmosTx::from_json(r#"[{ "from_address": "cosmos1host" }]"#).is_err());
+        assert!(CosmosTx::from_json(r#"[{ "@type": "/cosmos.bank.v1beta1.MsgSend" }]"#).is_err());
+    }
+
+    #[test]
+    fn packet_data_to_and_from_raw() {
+        let tx = CosmosTx::new(vec![Any {
+            type_url: MSG_SEND_TYPE_URL.to_string(),
+            value: vec![1, 2, 3],
+        }]);
+        let packet_data = InterchainAccountPacketData::new(tx, "memo".to_string());
+
+        let raw = RawInterchainAccountPacketData::from(packet_data.clone());
+        assert_eq!(raw.r#type, Type::ExecuteTx as i32);
+        assert_eq!(
+            InterchainAccountPacketData::try_from(raw).unwrap(),
+            packet_data
+        );
+    }
+}
@@ -0,0 +1,32 @@
+//! Protobuf definitions of the `ibc.applications.interchain_accounts.controller.v1`
+//! messages, as used by ibc-go v6 and later.
+//!
+//! These messages are not part of the `ibc-proto` release used by the relayer, so they
+//! are defined here by hand, following the field numbering of the ibc-go proto files.
+
+use crate::prelude::*;
+
+use ibc_proto::ibc::applications::interchain_accounts::v1::InterchainAccountPacketData;
+
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct MsgRegisterInterchainAccount {
+    #[prost(string, tag = "1")]
+    pub owner: String,
+    #[prost(string, tag = "2")]
+    pub connection_id: String,
+    #[prost(string, tag = "3")]
+    pub version: String,
+}
+
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct MsgSendTx {
+    #[prost(string, tag = "1")]
+    pub owner: String,
+    #[prost(string, tag = "2")]
+    pub connection_id: String,
+    #[prost(message, optional, tag = "3")]
+    pub packet_data: Option<InterchainAccountPacketData>,
+    /// Relative timeout of the packet, in nanoseconds
+    #[prost(uint64, tag = "4")]
+    pub relative_timeout: u64,
+}
@@ -0,0 +1,216 @@
+use crate::prelude::*;
+
+use core::time::Duration;
+
+use ibc_proto::google::protobuf::Any;
+use ibc_proto::protobuf::Protobuf;
+use prost::Message;
+use serde::{Deserialize, Serialize};
+
+use crate::clients::ics06_solomachine::consensus_state::ConsensusState;
+use crate::clients::ics06_solomachine::error::Error;
+use crate::clients::ics06_solomachine::raw::ClientState as RawClientState;
+use crate::core::ics02_client::client_state::{
+    ClientState as Ics2ClientState, UpgradeOptions as CoreUpgradeOptions,
+};
+use crate::core::ics02_client::client_type::ClientType;
+use crate::core::ics02_client::error::Error as Ics02Error;
+use crate::core::ics24_host::identifier::ChainId;
+use crate::Height;
+
+pub const SOLOMACHINE_CLIENT_STATE_TYPE_URL: &str = "/ibc.lightclients.solomachine.v3.ClientState";
+
+/// The client state of a solo machine.
+///
+/// The height of a solo machine client is `0-{sequence}`, where `sequence` is the
+/// sequence at which the solo machine must produce its next signature. Every header
+/// and proof verified by the client increments it.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ClientState {
+    pub sequence: u64,
+    pub is_frozen: bool,
+    pub consensus_state: ConsensusState,
+}
+
+impl ClientState {
+    pub fn new(sequence: u64, consensus_state: ConsensusState) -> Result<Self, Error> {
+        if sequence == 0 {
+            return Err(Error::invalid_raw_client_state(
+                "sequence cannot be zero".into(),
+            ));
+        }
+
+        Ok(Self {
+            sequence,
+            is_frozen: false,
+            consensus_state,
+        })
+    }
+
+    pub fn latest_height(&self) -> Height {
+        Height::new(0, self.sequence).expect("the sequence of a solo machine is never zero")
+    }
+}
+
+impl Ics2ClientState for ClientState {
+    /// Solo machines have no chain identifier, by convention the relayer
+    /// uses the diversifier of the solo machine as its chain identifier.
+    fn chain_id(&self) -> ChainId {
+        ChainId::from_string(&self.consensus_state.diversifier)
+    }
+
+    fn client_type(&self) -> ClientType {
+        ClientType::Solomachine
+    }
+
+    fn latest_height(&self) -> Height {
+        self.latest_height()
+    }
+
+    fn frozen_height(&self) -> Option<Height> {
+        // ibc-go reports a fixed height of `0-1` for frozen solo machine clients
+        self.is_frozen
+            .then(|| Height::new(0, 1).expect("height 0-1 is a valid height"))
+    }
+
+    fn expired(&self, _elapsed: Duration) -> bool {
+        false
+    }
+
+    fn upgrade(
+        &mut self,
+        _upgrade_height: Height,
+        _upgrade_options: &dyn CoreUpgradeOptions,
+        _chain_id: ChainId,
+    ) {
+        // Solo machine clients cannot be upgraded, their public key is
+        // rotated with a regular client update instead.
+    }
+}
+
+impl Protobuf<RawClientState> for ClientState {}
+
+impl TryFrom<RawClientState> for ClientState {
+    type Error = Error;
+
+    fn try_from(raw: RawClientState) -> Result<Self, Self::Error> {
+        let consensus_state = raw
+            .consensus_state
+            .ok_or_else(|| Error::invalid_raw_client_state("missing consensus state".into()))?
+            .try_into()?;
+
+        let client_state = Self::new(raw.sequence, consensus_state)?;
+
+        Ok(Self {
+            is_frozen: raw.is_frozen,
+            ..client_state
+        })
+    }
+}
+
+impl From<ClientState> for RawClientState {
+    fn from(value: ClientState) -> Self {
+        RawClientState {
+            sequence: value.sequence,
+            is_frozen: value.is_frozen,
+            consensus_state: Some(value.consensus_state.into()),
+        }
+    }
+}
+
+impl Protobuf<Any> for ClientState {}
+
+impl TryFrom<Any> for ClientState {
+    type Error = Ics02Error;
+
+    fn try_from(raw: Any) -> Result<Self, Self::Error> {
+        fn decode_client_state(buf: &[u8]) -> Result<ClientState, Error> {
+            RawClientState::decode(buf)
+                .map_err(Error::decode)?
+                .try_into()
+        }
+
+        match raw.type_url.as_str() {
+            SOLOMACHINE_CLIENT_STATE_TYPE_URL => {
+                decode_client_state(&raw.value).map_err(Into::into)
+            }
+            _ => Err(Ics02Error::unknown_client_state_type(raw.type_url)),
+        }
+    }
+}
+
+impl From<ClientState> for Any {
+    fn from(client_state: ClientState) -> Self {
+        Any {
+            type_url: SOLOMACHINE_CLIENT_STATE_TYPE_URL.to_string(),
+            value: Protobuf::<RawClientState>::encode_vec(&client_state),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    use ibc_proto::google::protobuf::Any;
+    use test_log::test;
+
+    use super::ClientState;
+    use crate::clients::ics06_solomachine::consensus_state::test_util::get_dummy_raw_consensus_state;
+    use crate::clients::ics06_solomachine::raw::ClientState as RawClientState;
+    use crate::core::ics02_client::client_state::ClientState as _;
+    use crate::core::ics24_host::identifier::ChainId;
+    use crate::Height;
+
+    fn get_dummy_raw_client_state() -> RawClientState {
+        RawClientState {
+            sequence: 5,
+            is_frozen: false,
+            consensus_state: Some(get_dummy_raw_consensus_state()),
+        }
+    }
+
+    #[test]
+    fn client_state_to_and_from() {
+        let raw = get_dummy_raw_client_state();
+        let client_state = ClientState::try_from(raw.clone()).unwrap();
+        assert_eq!(raw, RawClientState::from(client_state.clone()));
+
+        let any = Any::from(client_state.clone());
+        assert_eq!(ClientState::try_from(any).unwrap(), client_state);
+    }
+
+    #[test]
+    fn client_state_height_and_chain_id() {
+        let client_state = ClientState::try_from(get_dummy_raw_client_state()).unwrap();
+
+        assert_eq!(client_state.latest_height(), Height::new(0, 5).unwrap());
+        assert_eq!(client_state.chain_id(), ChainId::from_string("solo-0"));
+        assert_eq!(client_state.frozen_height(), None);
+    }
+
+    #[test]
+    fn frozen_client_state() {
+        let raw = RawClientState {
+            is_frozen: true,
+            ..get_dummy_raw_client_state()
+        };
+        let client_state = ClientState::try_from(raw).unwrap();
+
+        assert!(client_state.is_frozen());
+        assert_eq!(
+            client_state.frozen_height(),
+            Some(Height::new(0, 1).unwrap())
+        );
+    }
+
+    #[test]
+    fn client_state_with_zero_sequence() {
+        let raw = RawClientState {
+            sequence: 0,
+            ..get_dummy_raw_client_state()
+        };
+
+        assert!(ClientState::try_from(raw).is_err());
+    }
+}
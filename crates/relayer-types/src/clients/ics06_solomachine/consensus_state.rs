@@ -0,0 +1,219 @@
+use crate::prelude::*;
+
+use ibc_proto::google::protobuf::Any;
+use ibc_proto::protobuf::Protobuf;
+use prost::Message;
+use serde::{Deserialize, Serialize};
+
+use crate::clients::ics06_solomachine::error::Error;
+use crate::clients::ics06_solomachine::raw::{ConsensusState as RawConsensusState, PubKey};
+use crate::core::ics02_client::client_type::ClientType;
+use crate::core::ics02_client::error::Error as Ics02Error;
+use crate::core::ics23_commitment::commitment::CommitmentRoot;
+use crate::timestamp::Timestamp;
+
+pub const SOLOMACHINE_CONSENSUS_STATE_TYPE_URL: &str =
+    "/ibc.lightclients.solomachine.v3.ConsensusState";
+
+pub const SECP256K1_PUBLIC_KEY_TYPE_URL: &str = "/cosmos.crypto.secp256k1.PubKey";
+
+/// Solo machines do not commit to their state in a Merkle tree,
+/// so their consensus states all share the same, empty, root.
+static EMPTY_ROOT: CommitmentRoot = CommitmentRoot::empty();
+
+/// The compressed secp256k1 public key of a solo machine.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PublicKey(pub Vec<u8>);
+
+impl PublicKey {
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl TryFrom<Any> for PublicKey {
+    type Error = Error;
+
+    fn try_from(raw: Any) -> Result<Self, Self::Error> {
+        if raw.type_url != SECP256K1_PUBLIC_KEY_TYPE_URL {
+            return Err(Error::unsupported_public_key(raw.type_url));
+        }
+
+        let pub_key = PubKey::decode(raw.value.as_slice()).map_err(Error::decode)?;
+
+        Ok(Self(pub_key.key))
+    }
+}
+
+impl From<PublicKey> for Any {
+    fn from(value: PublicKey) -> Self {
+        Any {
+            type_url: SECP256K1_PUBLIC_KEY_TYPE_URL.to_string(),
+            value: PubKey { key: value.0 }.encode_to_vec(),
+        }
+    }
+}
+
+/// The consensus state of a solo machine: the public key it signs with,
+/// the diversifier it includes in its signatures, and the timestamp of its
+/// latest signature.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ConsensusState {
+    pub public_key: PublicKey,
+    pub diversifier: String,
+    pub timestamp: Timestamp,
+}
+
+impl ConsensusState {
+    pub fn new(public_key: PublicKey, diversifier: String, timestamp: Timestamp) -> Self {
+        Self {
+            public_key,
+            diversifier,
+            timestamp,
+        }
+    }
+}
+
+impl crate::core::ics02_client::consensus_state::ConsensusState for ConsensusState {
+    fn client_type(&self) -> ClientType {
+        ClientType::Solomachine
+    }
+
+    fn root(&self) -> &CommitmentRoot {
+        &EMPTY_ROOT
+    }
+
+    fn timestamp(&self) -> Timestamp {
+        self.timestamp
+    }
+}
+
+impl Protobuf<RawConsensusState> for ConsensusState {}
+
+impl TryFrom<RawConsensusState> for ConsensusState {
+    type Error = Error;
+
+    fn try_from(raw: RawConsensusState) -> Result<Self, Self::Error> {
+        let public_key = raw
+            .public_key
+            .ok_or_else(|| Error::invalid_raw_consensus_state("missing public key".into()))?
+            .try_into()?;
+
+        if raw.timestamp == 0 {
+            return Err(Error::invalid_raw_consensus_state(
+                "timestamp cannot be zero".into(),
+            ));
+        }
+
+        Ok(Self {
+            public_key,
+            diversifier: raw.diversifier,
+            timestamp: Timestamp::from_nanoseconds(raw.timestamp)
+                .map_err(Error::invalid_timestamp)?,
+        })
+    }
+}
+
+impl From<ConsensusState> for RawConsensusState {
+    fn from(value: ConsensusState) -> Self {
+        RawConsensusState {
+            public_key: Some(value.public_key.into()),
+            diversifier: value.diversifier,
+            timestamp: value.timestamp.nanoseconds(),
+        }
+    }
+}
+
+impl Protobuf<Any> for ConsensusState {}
+
+impl TryFrom<Any> for ConsensusState {
+    type Error = Ics02Error;
+
+    fn try_from(raw: Any) -> Result<Self, Self::Error> {
+        fn decode_consensus_state(buf: &[u8]) -> Result<ConsensusState, Error> {
+            RawConsensusState::decode(buf)
+                .map_err(Error::decode)?
+                .try_into()
+        }
+
+        match raw.type_url.as_str() {
+            SOLOMACHINE_CONSENSUS_STATE_TYPE_URL => {
+                decode_consensus_state(&raw.value).map_err(Into::into)
+            }
+            _ => Err(Ics02Error::unknown_consensus_state_type(raw.type_url)),
+        }
+    }
+}
+
+impl From<ConsensusState> for Any {
+    fn from(consensus_state: ConsensusState) -> Self {
+        Any {
+            type_url: SOLOMACHINE_CONSENSUS_STATE_TYPE_URL.to_string(),
+            value: Protobuf::<RawConsensusState>::encode_vec(&consensus_state),
+        }
+    }
+}
+
+#[cfg(any(test, feature = "mocks"))]
+pub mod test_util {
+    use crate::prelude::*;
+
+    use ibc_proto::google::protobuf::Any;
+
+    use super::SECP256K1_PUBLIC_KEY_TYPE_URL;
+    use crate::clients::ics06_solomachine::raw::{ConsensusState as RawConsensusState, PubKey};
+
+    /// Returns a dummy `RawConsensusState`, for testing only!
+    pub fn get_dummy_raw_consensus_state() -> RawConsensusState {
+        RawConsensusState {
+            public_key: Some(Any {
+                type_url: SECP256K1_PUBLIC_KEY_TYPE_URL.to_string(),
+                value: prost::Message::encode_to_vec(&PubKey { key: vec![2; 33] }),
+            }),
+            diversifier: "solo-0".to_string(),
+            timestamp: 1_700_000_000_000_000_000,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    use ibc_proto::google::protobuf::Any;
+    use test_log::test;
+
+    use super::test_util::get_dummy_raw_consensus_state;
+    use super::ConsensusState;
+    use crate::clients::ics06_solomachine::raw::ConsensusState as RawConsensusState;
+
+    #[test]
+    fn consensus_state_to_and_from() {
+        let raw = get_dummy_raw_consensus_state();
+        let consensus_state = ConsensusState::try_from(raw.clone()).unwrap();
+        assert_eq!(raw, RawConsensusState::from(consensus_state.clone()));
+
+        let any = Any::from(consensus_state.clone());
+        assert_eq!(ConsensusState::try_from(any).unwrap(), consensus_state);
+    }
+
+    #[test]
+    fn consensus_state_with_unsupported_key() {
+        let mut raw = get_dummy_raw_consensus_state();
+        if let Some(public_key) = raw.public_key.as_mut() {
+            public_key.type_url = "/cosmos.crypto.ed25519.PubKey".to_string();
+        }
+
+        assert!(ConsensusState::try_from(raw).is_err());
+    }
+
+    #[test]
+    fn consensus_state_without_timestamp() {
+        let raw = RawConsensusState {
+            timestamp: 0,
+            ..get_dummy_raw_consensus_state()
+        };
+
+        assert!(ConsensusState::try_from(raw).is_err());
+    }
+}
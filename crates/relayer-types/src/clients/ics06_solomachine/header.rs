@@ -0,0 +1,180 @@
+use crate::prelude::*;
+
+use core::fmt::{Display, Error as FmtError, Formatter};
+
+use ibc_proto::google::protobuf::Any;
+use ibc_proto::protobuf::Protobuf;
+use prost::Message;
+use serde::{Deserialize, Serialize};
+
+use crate::clients::ics06_solomachine::consensus_state::PublicKey;
+use crate::clients::ics06_solomachine::error::Error;
+use crate::clients::ics06_solomachine::raw::Header as RawHeader;
+use crate::core::ics02_client::client_type::ClientType;
+use crate::core::ics02_client::error::Error as Ics02Error;
+use crate::timestamp::Timestamp;
+use crate::Height;
+
+pub const SOLOMACHINE_HEADER_TYPE_URL: &str = "/ibc.lightclients.solomachine.v3.Header";
+
+/// A solo machine header, which updates the timestamp of a solo machine client
+/// and possibly rotates its public key and diversifier.
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Header {
+    /// The sequence the header was signed at. It is not part of the header sent
+    /// to the chain hosting the client, which uses its own sequence instead, and
+    /// is therefore zero for decoded headers.
+    pub sequence: u64,
+    pub timestamp: Timestamp,
+    /// The encoded `SignatureDescriptor.Data` holding the signature over the header
+    pub signature: Vec<u8>,
+    pub new_public_key: PublicKey,
+    pub new_diversifier: String,
+}
+
+impl core::fmt::Debug for Header {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), FmtError> {
+        write!(f, " Header {{...}}")
+    }
+}
+
+impl Display for Header {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), FmtError> {
+        write!(
+            f,
+            "Header {{ sequence: {}, timestamp: {}, new_diversifier: {} }}",
+            self.sequence, self.timestamp, self.new_diversifier
+        )
+    }
+}
+
+impl Header {
+    /// The height of the client once this header is applied to it.
+    pub fn height(&self) -> Height {
+        Height::new(0, self.sequence + 1).expect("the height of a header is never zero")
+    }
+}
+
+impl crate::core::ics02_client::header::Header for Header {
+    fn client_type(&self) -> ClientType {
+        ClientType::Solomachine
+    }
+
+    fn height(&self) -> Height {
+        self.height()
+    }
+
+    fn timestamp(&self) -> Timestamp {
+        self.timestamp
+    }
+}
+
+impl Protobuf<RawHeader> for Header {}
+
+impl TryFrom<RawHeader> for Header {
+    type Error = Error;
+
+    fn try_from(raw: RawHeader) -> Result<Self, Self::Error> {
+        if raw.timestamp == 0 {
+            return Err(Error::invalid_raw_header("timestamp cannot be zero".into()));
+        }
+
+        if raw.signature.is_empty() {
+            return Err(Error::invalid_raw_header("missing signature".into()));
+        }
+
+        let new_public_key = raw
+            .new_public_key
+            .ok_or_else(|| Error::invalid_raw_header("missing new public key".into()))?
+            .try_into()?;
+
+        Ok(Self {
+            sequence: 0,
+            timestamp: Timestamp::from_nanoseconds(raw.timestamp)
+                .map_err(Error::invalid_timestamp)?,
+            signature: raw.signature,
+            new_public_key,
+            new_diversifier: raw.new_diversifier,
+        })
+    }
+}
+
+impl From<Header> for RawHeader {
+    fn from(value: Header) -> Self {
+        RawHeader {
+            timestamp: value.timestamp.nanoseconds(),
+            signature: value.signature,
+            new_public_key: Some(value.new_public_key.into()),
+            new_diversifier: value.new_diversifier,
+        }
+    }
+}
+
+impl Protobuf<Any> for Header {}
+
+impl TryFrom<Any> for Header {
+    type Error = Ics02Error;
+
+    fn try_from(raw: Any) -> Result<Self, Self::Error> {
+        match raw.type_url.as_str() {
+            SOLOMACHINE_HEADER_TYPE_URL => decode_header(&raw.value).map_err(Into::into),
+            _ => Err(Ics02Error::unknown_header_type(raw.type_url)),
+        }
+    }
+}
+
+impl From<Header> for Any {
+    fn from(header: Header) -> Self {
+        Any {
+            type_url: SOLOMACHINE_HEADER_TYPE_URL.to_string(),
+            value: Protobuf::<RawHeader>::encode_vec(&header),
+        }
+    }
+}
+
+pub fn decode_header(buf: &[u8]) -> Result<Header, Error> {
+    RawHeader::decode(buf).map_err(Error::decode)?.try_into()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    use ibc_proto::google::protobuf::Any;
+    use test_log::test;
+
+    use super::Header;
+    use crate::clients::ics06_solomachine::consensus_state::PublicKey;
+    use crate::clients::ics06_solomachine::raw::Header as RawHeader;
+    use crate::timestamp::Timestamp;
+    use crate::Height;
+
+    fn get_dummy_header() -> Header {
+        Header {
+            sequence: 0,
+            timestamp: Timestamp::from_nanoseconds(1_700_000_000_000_000_000).unwrap(),
+            signature: vec![1; 70],
+            new_public_key: PublicKey(vec![3; 33]),
+            new_diversifier: "solo-0".to_string(),
+        }
+    }
+
+    #[test]
+    fn header_to_and_from() {
+        let header = get_dummy_header();
+
+        let any = Any::from(header.clone());
+        assert_eq!(Header::try_from(any).unwrap(), header);
+        assert_eq!(header.height(), Height::new(0, 1).unwrap());
+    }
+
+    #[test]
+    fn header_without_signature() {
+        let raw = RawHeader {
+            signature: vec![],
+            ..RawHeader::from(get_dummy_header())
+        };
+
+        assert!(Header::try_from(raw).is_err());
+    }
+}
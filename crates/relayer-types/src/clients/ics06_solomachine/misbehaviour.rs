@@ -0,0 +1,124 @@
+use crate::prelude::*;
+
+use ibc_proto::protobuf::Protobuf;
+use serde::{Deserialize, Serialize};
+
+use crate::clients::ics06_solomachine::error::Error;
+use crate::clients::ics06_solomachine::raw::{
+    Misbehaviour as RawMisbehaviour, SignatureAndData as RawSignatureAndData,
+};
+use crate::core::ics24_host::identifier::ClientId;
+use crate::timestamp::Timestamp;
+use crate::Height;
+
+pub const SOLOMACHINE_MISBEHAVIOUR_TYPE_URL: &str = "/ibc.lightclients.solomachine.v3.Misbehaviour";
+
+/// A signature over some data, along with the path and timestamp it was signed with.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SignatureAndData {
+    /// The encoded `SignatureDescriptor.Data` holding the signature
+    pub signature: Vec<u8>,
+    pub path: Vec<u8>,
+    pub data: Vec<u8>,
+    pub timestamp: Timestamp,
+}
+
+impl Protobuf<RawSignatureAndData> for SignatureAndData {}
+
+impl TryFrom<RawSignatureAndData> for SignatureAndData {
+    type Error = Error;
+
+    fn try_from(raw: RawSignatureAndData) -> Result<Self, Self::Error> {
+        if raw.signature.is_empty() {
+            return Err(Error::invalid_raw_misbehaviour("missing signature".into()));
+        }
+
+        Ok(Self {
+            signature: raw.signature,
+            path: raw.path,
+            data: raw.data,
+            timestamp: Timestamp::from_nanoseconds(raw.timestamp)
+                .map_err(Error::invalid_timestamp)?,
+        })
+    }
+}
+
+impl From<SignatureAndData> for RawSignatureAndData {
+    fn from(value: SignatureAndData) -> Self {
+        RawSignatureAndData {
+            signature: value.signature,
+            path: value.path,
+            data: value.data,
+            timestamp: value.timestamp.nanoseconds(),
+        }
+    }
+}
+
+/// Evidence that a solo machine signed two different messages at the same sequence.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Misbehaviour {
+    pub client_id: ClientId,
+    pub sequence: u64,
+    pub signature_one: SignatureAndData,
+    pub signature_two: SignatureAndData,
+}
+
+impl crate::core::ics02_client::misbehaviour::Misbehaviour for Misbehaviour {
+    fn client_id(&self) -> &ClientId {
+        &self.client_id
+    }
+
+    fn height(&self) -> Height {
+        Height::new(0, self.sequence).expect("the sequence of a misbehaviour is never zero")
+    }
+}
+
+impl Protobuf<RawMisbehaviour> for Misbehaviour {}
+
+impl TryFrom<RawMisbehaviour> for Misbehaviour {
+    type Error = Error;
+
+    fn try_from(raw: RawMisbehaviour) -> Result<Self, Self::Error> {
+        if raw.sequence == 0 {
+            return Err(Error::invalid_raw_misbehaviour(
+                "sequence cannot be zero".into(),
+            ));
+        }
+
+        Ok(Self {
+            client_id: Default::default(),
+            sequence: raw.sequence,
+            signature_one: raw
+                .signature_one
+                .ok_or_else(|| Error::invalid_raw_misbehaviour("missing signature one".into()))?
+                .try_into()?,
+            signature_two: raw
+                .signature_two
+                .ok_or_else(|| Error::invalid_raw_misbehaviour("missing signature two".into()))?
+                .try_into()?,
+        })
+    }
+}
+
+impl From<Misbehaviour> for RawMisbehaviour {
+    fn from(value: Misbehaviour) -> Self {
+        RawMisbehaviour {
+            sequence: value.sequence,
+            signature_one: Some(value.signature_one.into()),
+            signature_two: Some(value.signature_two.into()),
+        }
+    }
+}
+
+impl core::fmt::Display for Misbehaviour {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> Result<(), core::fmt::Error> {
+        write!(
+            f,
+            "{} sequence: {} t1: {} t2: {}",
+            self.client_id,
+            self.sequence,
+            self.signature_one.timestamp,
+            self.signature_two.timestamp,
+        )
+    }
+}
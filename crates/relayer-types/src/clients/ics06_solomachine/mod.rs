@@ -0,0 +1,11 @@
+//! ICS 06: Solo Machine Client implements a client verification algorithm for machines
+//! which cannot maintain a consensus of their own, such as phones or custody services,
+//! and instead prove their state with the signature of a single key.
+
+pub mod client_state;
+pub mod consensus_state;
+pub mod error;
+pub mod header;
+pub mod misbehaviour;
+pub mod raw;
+pub mod sign_bytes;
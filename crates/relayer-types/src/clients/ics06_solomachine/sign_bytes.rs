@@ -0,0 +1,233 @@
+//! The data signed by a solo machine to update its client or to prove its state,
+//! along with the encoding of the resulting signatures.
+
+use crate::prelude::*;
+
+use ibc_proto::cosmos::tx::signing::v1beta1::signature_descriptor::{
+    data::{Single, Sum},
+    Data as RawSignatureData,
+};
+use ibc_proto::cosmos::tx::signing::v1beta1::SignMode;
+use ibc_proto::ibc::core::commitment::v1::MerklePath;
+use prost::Message;
+
+use crate::clients::ics06_solomachine::consensus_state::PublicKey;
+use crate::clients::ics06_solomachine::error::Error;
+use crate::clients::ics06_solomachine::raw::{
+    HeaderData as RawHeaderData, SignBytes as RawSignBytes,
+    TimestampedSignatureData as RawTimestampedSignatureData,
+};
+use crate::timestamp::Timestamp;
+
+/// The path signed over by a solo machine when it produces a header.
+pub const SENTINEL_HEADER_PATH: &str = "solomachine:header";
+
+/// The bytes a solo machine signs, either to update its client with a new
+/// header, or to prove the value stored at some path of its state.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SignBytes {
+    pub sequence: u64,
+    pub timestamp: Timestamp,
+    pub diversifier: String,
+    pub path: Vec<u8>,
+    pub data: Vec<u8>,
+}
+
+impl SignBytes {
+    /// The bytes to sign to update a client at the given `sequence` with a new
+    /// public key and diversifier.
+    pub fn header(
+        sequence: u64,
+        timestamp: Timestamp,
+        diversifier: String,
+        new_public_key: PublicKey,
+        new_diversifier: String,
+    ) -> Self {
+        let header_data = RawHeaderData {
+            new_pub_key: Some(new_public_key.into()),
+            new_diversifier,
+        };
+
+        Self {
+            sequence,
+            timestamp,
+            diversifier,
+            path: SENTINEL_HEADER_PATH.as_bytes().to_vec(),
+            data: header_data.encode_to_vec(),
+        }
+    }
+
+    /// The bytes to sign to prove that `value` is stored at the given commitment
+    /// path, as produced by [`apply_prefix`](crate::core::ics23_commitment::merkle::apply_prefix).
+    pub fn membership(
+        sequence: u64,
+        timestamp: Timestamp,
+        diversifier: String,
+        path: &MerklePath,
+        value: Vec<u8>,
+    ) -> Self {
+        Self {
+            sequence,
+            timestamp,
+            diversifier,
+            path: merkle_path_bytes(path),
+            data: value,
+        }
+    }
+
+    pub fn encode_vec(self) -> Vec<u8> {
+        RawSignBytes {
+            sequence: self.sequence,
+            timestamp: self.timestamp.nanoseconds(),
+            diversifier: self.diversifier,
+            path: self.path,
+            data: self.data,
+        }
+        .encode_to_vec()
+    }
+}
+
+/// Encodes a merkle path the way ibc-go does before including it in the sign bytes,
+/// ie. as the concatenation of its URL-escaped keys, each preceded by a `/`.
+pub fn merkle_path_bytes(path: &MerklePath) -> Vec<u8> {
+    path.key_path
+        .iter()
+        .map(|key| format!("/{}", path_escape(key)))
+        .collect::<String>()
+        .into_bytes()
+}
+
+/// Escapes a path segment, following Go's `url.PathEscape`.
+fn path_escape(segment: &str) -> String {
+    segment
+        .bytes()
+        .map(|byte| match byte {
+            b'A'..=b'Z'
+            | b'a'..=b'z'
+            | b'0'..=b'9'
+            | b'-'
+            | b'_'
+            | b'.'
+            | b'~'
+            | b'$'
+            | b'&'
+            | b'+'
+            | b','
+            | b';'
+            | b'='
+            | b':'
+            | b'@' => char::from(byte).to_string(),
+            _ => format!("%{byte:02X}"),
+        })
+        .collect()
+}
+
+/// Wraps a raw signature into the `SignatureDescriptor.Data` expected by solo machine clients.
+pub fn encode_signature_data(signature: Vec<u8>) -> Vec<u8> {
+    RawSignatureData {
+        sum: Some(Sum::Single(Single {
+            mode: SignMode::Direct as i32,
+            signature,
+        })),
+    }
+    .encode_to_vec()
+}
+
+/// Extracts the raw signature out of an encoded `SignatureDescriptor.Data`.
+pub fn decode_signature_data(signature_data: &[u8]) -> Result<Vec<u8>, Error> {
+    match RawSignatureData::decode(signature_data)
+        .map_err(Error::decode)?
+        .sum
+    {
+        Some(Sum::Single(single)) => Ok(single.signature),
+        _ => Err(Error::invalid_raw_header(
+            "expected the signature of a single signer".into(),
+        )),
+    }
+}
+
+/// A signature along with the timestamp it was produced at,
+/// which solo machines use as commitment proofs.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TimestampedSignatureData {
+    /// The raw signature over the sign bytes
+    pub signature: Vec<u8>,
+    pub timestamp: Timestamp,
+}
+
+impl TimestampedSignatureData {
+    pub fn encode_vec(self) -> Vec<u8> {
+        RawTimestampedSignatureData {
+            signature_data: encode_signature_data(self.signature),
+            timestamp: self.timestamp.nanoseconds(),
+        }
+        .encode_to_vec()
+    }
+
+    pub fn decode_vec(bytes: &[u8]) -> Result<Self, Error> {
+        let raw = RawTimestampedSignatureData::decode(bytes).map_err(Error::decode)?;
+
+        Ok(Self {
+            signature: decode_signature_data(&raw.signature_data)?,
+            timestamp: Timestamp::from_nanoseconds(raw.timestamp)
+                .map_err(Error::invalid_timestamp)?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    use test_log::test;
+
+    use super::{decode_signature_data, encode_signature_data, merkle_path_bytes};
+    use super::{SignBytes, TimestampedSignatureData, SENTINEL_HEADER_PATH};
+    use crate::clients::ics06_solomachine::consensus_state::PublicKey;
+    use crate::core::ics23_commitment::commitment::CommitmentPrefix;
+    use crate::core::ics23_commitment::merkle::apply_prefix;
+    use crate::timestamp::Timestamp;
+
+    #[test]
+    fn merkle_path_is_escaped() {
+        let prefix = CommitmentPrefix::try_from(b"ibc".to_vec()).unwrap();
+        let path = apply_prefix(&prefix, vec!["connections/connection-0".to_string()]);
+
+        assert_eq!(
+            merkle_path_bytes(&path),
+            b"/ibc/connections%2Fconnection-0".to_vec()
+        );
+    }
+
+    fn header_sign_bytes(sequence: u64) -> SignBytes {
+        SignBytes::header(
+            sequence,
+            Timestamp::from_nanoseconds(1).unwrap(),
+            "solo-0".to_string(),
+            PublicKey(vec![2; 33]),
+            "solo-0".to_string(),
+        )
+    }
+
+    #[test]
+    fn header_sign_bytes_depend_on_sequence() {
+        let sign_bytes = header_sign_bytes(3);
+        assert_eq!(sign_bytes.path, SENTINEL_HEADER_PATH.as_bytes());
+
+        assert_ne!(sign_bytes.encode_vec(), header_sign_bytes(4).encode_vec());
+    }
+
+    #[test]
+    fn signature_data_to_and_from() {
+        let signature = vec![7; 64];
+        let encoded = encode_signature_data(signature.clone());
+        assert_eq!(decode_signature_data(&encoded).unwrap(), signature);
+
+        let timestamped = TimestampedSignatureData {
+            signature,
+            timestamp: Timestamp::from_nanoseconds(42).unwrap(),
+        };
+        let decoded = TimestampedSignatureData::decode_vec(&timestamped.clone().encode_vec());
+        assert_eq!(decoded.unwrap(), timestamped);
+    }
+}
@@ -0,0 +1,91 @@
+use crate::prelude::*;
+
+use ibc_proto::google::protobuf::Any;
+use ibc_proto::protobuf::Protobuf;
+use prost::Message;
+
+use crate::clients::ics08_wasm::error::Error;
+use crate::clients::ics08_wasm::raw::ClientMessage as RawClientMessage;
+use crate::core::ics02_client::error::Error as Ics02Error;
+
+pub const WASM_CLIENT_MESSAGE_TYPE_URL: &str = "/ibc.lightclients.wasm.v1.ClientMessage";
+
+/// A header or misbehaviour submitted to an `08-wasm` client, which passes
+/// the encoded message it wraps to the Wasm contract of the client.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ClientMessage {
+    pub data: Vec<u8>,
+}
+
+impl ClientMessage {
+    /// Wraps the given encoded header or misbehaviour.
+    pub fn wrap(message: Any) -> Self {
+        Self {
+            data: message.encode_to_vec(),
+        }
+    }
+
+    /// Returns the header or misbehaviour wrapped in this message,
+    /// if it was encoded as an `Any`.
+    pub fn inner(&self) -> Result<Any, Error> {
+        Any::decode(self.data.as_slice()).map_err(Error::decode)
+    }
+}
+
+impl Protobuf<RawClientMessage> for ClientMessage {}
+
+impl From<RawClientMessage> for ClientMessage {
+    fn from(raw: RawClientMessage) -> Self {
+        Self { data: raw.data }
+    }
+}
+
+impl From<ClientMessage> for RawClientMessage {
+    fn from(value: ClientMessage) -> Self {
+        RawClientMessage { data: value.data }
+    }
+}
+
+impl Protobuf<Any> for ClientMessage {}
+
+impl TryFrom<Any> for ClientMessage {
+    type Error = Ics02Error;
+
+    fn try_from(raw: Any) -> Result<Self, Self::Error> {
+        match raw.type_url.as_str() {
+            WASM_CLIENT_MESSAGE_TYPE_URL => RawClientMessage::decode(raw.value.as_slice())
+                .map(Into::into)
+                .map_err(|e| Error::decode(e).into()),
+            _ => Err(Ics02Error::unknown_header_type(raw.type_url)),
+        }
+    }
+}
+
+impl From<ClientMessage> for Any {
+    fn from(message: ClientMessage) -> Self {
+        Any {
+            type_url: WASM_CLIENT_MESSAGE_TYPE_URL.to_string(),
+            value: Protobuf::<RawClientMessage>::encode_vec(&message),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    use ibc_proto::google::protobuf::Any;
+    use test_log::test;
+
+    use super::ClientMessage;
+    use crate::clients::ics07_tendermint::header::test_util::get_dummy_ics07_header;
+
+    #[test]
+    fn wrap_and_unwrap_header() {
+        let header = Any::from(get_dummy_ics07_header());
+
+        let message =
+            ClientMessage::try_from(Any::from(ClientMessage::wrap(header.clone()))).unwrap();
+        assert_eq!(message.inner().unwrap(), header);
+    }
+}
@@ -0,0 +1,247 @@
+use crate::prelude::*;
+
+use core::time::Duration;
+
+use ibc_proto::google::protobuf::Any;
+use ibc_proto::protobuf::Protobuf;
+use prost::Message;
+use serde::{Deserialize, Serialize};
+
+use crate::clients::ics07_tendermint::client_state::{
+    ClientState as TmClientState, TENDERMINT_CLIENT_STATE_TYPE_URL,
+};
+use crate::clients::ics08_wasm::error::Error;
+use crate::clients::ics08_wasm::raw::ClientState as RawClientState;
+use crate::core::ics02_client::client_state::{
+    ClientState as Ics2ClientState, UpgradeOptions as CoreUpgradeOptions,
+};
+use crate::core::ics02_client::client_type::ClientType;
+use crate::core::ics02_client::error::Error as Ics02Error;
+use crate::core::ics24_host::identifier::ChainId;
+use crate::Height;
+
+pub const WASM_CLIENT_STATE_TYPE_URL: &str = "/ibc.lightclients.wasm.v1.ClientState";
+
+/// The client state of an `08-wasm` client.
+///
+/// The `data` of the client state is the state of the Wasm contract identified by
+/// `checksum`. When the contract is a build of the Tendermint light client, `data`
+/// holds a Tendermint client state, which is decoded in `tendermint`.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ClientState {
+    #[serde(serialize_with = "crate::serializers::ser_hex_upper")]
+    pub data: Vec<u8>,
+    #[serde(serialize_with = "crate::serializers::ser_hex_upper")]
+    pub checksum: Vec<u8>,
+    pub latest_height: Height,
+    pub tendermint: Option<TmClientState>,
+}
+
+impl ClientState {
+    pub fn new(data: Vec<u8>, checksum: Vec<u8>, latest_height: Height) -> Self {
+        let tendermint = decode_tendermint_client_state(&data);
+
+        Self {
+            data,
+            checksum,
+            latest_height,
+            tendermint,
+        }
+    }
+
+    /// Wraps a Tendermint client state in the client state of the
+    /// Wasm contract with the given checksum.
+    pub fn from_tendermint(checksum: Vec<u8>, client_state: TmClientState) -> Self {
+        Self {
+            data: Any::from(client_state.clone()).encode_to_vec(),
+            checksum,
+            latest_height: client_state.latest_height(),
+            tendermint: Some(client_state),
+        }
+    }
+
+    pub fn latest_height(&self) -> Height {
+        self.latest_height
+    }
+}
+
+/// Decodes the Tendermint client state held by the state of a Wasm contract, if any.
+fn decode_tendermint_client_state(data: &[u8]) -> Option<TmClientState> {
+    let any = Any::decode(data).ok()?;
+
+    if any.type_url != TENDERMINT_CLIENT_STATE_TYPE_URL {
+        return None;
+    }
+
+    TmClientState::try_from(any).ok()
+}
+
+impl Ics2ClientState for ClientState {
+    /// The chain identifier of a Wasm client is only known when it wraps a
+    /// Tendermint client state, the default identifier is returned otherwise.
+    fn chain_id(&self) -> ChainId {
+        self.tendermint
+            .as_ref()
+            .map(|client_state| client_state.chain_id())
+            .unwrap_or_default()
+    }
+
+    fn client_type(&self) -> ClientType {
+        ClientType::Wasm
+    }
+
+    fn latest_height(&self) -> Height {
+        self.latest_height
+    }
+
+    fn frozen_height(&self) -> Option<Height> {
+        self.tendermint
+            .as_ref()
+            .and_then(|client_state| client_state.frozen_height())
+    }
+
+    fn expired(&self, elapsed: Duration) -> bool {
+        self.tendermint
+            .as_ref()
+            .map_or(false, |client_state| client_state.expired(elapsed))
+    }
+
+    fn upgrade(
+        &mut self,
+        upgrade_height: Height,
+        upgrade_options: &dyn CoreUpgradeOptions,
+        chain_id: ChainId,
+    ) {
+        // Only the Tendermint client state wrapped by the contract is known to the relayer
+        if let Some(mut client_state) = self.tendermint.take() {
+            client_state.upgrade(upgrade_height, upgrade_options, chain_id);
+            *self = Self::from_tendermint(core::mem::take(&mut self.checksum), client_state);
+        }
+    }
+}
+
+impl Protobuf<RawClientState> for ClientState {}
+
+impl TryFrom<RawClientState> for ClientState {
+    type Error = Error;
+
+    fn try_from(raw: RawClientState) -> Result<Self, Self::Error> {
+        let latest_height = raw
+            .latest_height
+            .ok_or_else(|| Error::invalid_raw_client_state("missing latest height".into()))?
+            .try_into()
+            .map_err(|_| Error::invalid_raw_client_state("invalid latest height".into()))?;
+
+        if raw.checksum.is_empty() {
+            return Err(Error::invalid_raw_client_state("empty checksum".into()));
+        }
+
+        Ok(Self::new(raw.data, raw.checksum, latest_height))
+    }
+}
+
+impl From<ClientState> for RawClientState {
+    fn from(value: ClientState) -> Self {
+        RawClientState {
+            data: value.data,
+            checksum: value.checksum,
+            latest_height: Some(value.latest_height.into()),
+        }
+    }
+}
+
+impl Protobuf<Any> for ClientState {}
+
+impl TryFrom<Any> for ClientState {
+    type Error = Ics02Error;
+
+    fn try_from(raw: Any) -> Result<Self, Self::Error> {
+        fn decode_client_state(buf: &[u8]) -> Result<ClientState, Error> {
+            RawClientState::decode(buf)
+                .map_err(Error::decode)?
+                .try_into()
+        }
+
+        match raw.type_url.as_str() {
+            WASM_CLIENT_STATE_TYPE_URL => decode_client_state(&raw.value).map_err(Into::into),
+            _ => Err(Ics02Error::unknown_client_state_type(raw.type_url)),
+        }
+    }
+}
+
+impl From<ClientState> for Any {
+    fn from(client_state: ClientState) -> Self {
+        Any {
+            type_url: WASM_CLIENT_STATE_TYPE_URL.to_string(),
+            value: Protobuf::<RawClientState>::encode_vec(&client_state),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    use ibc_proto::google::protobuf::Any;
+    use test_log::test;
+
+    use super::ClientState;
+    use crate::clients::ics07_tendermint::client_state::test_util::get_dummy_tendermint_client_state;
+    use crate::clients::ics07_tendermint::header::test_util::get_dummy_tendermint_header;
+    use crate::clients::ics08_wasm::raw::ClientState as RawClientState;
+    use crate::core::ics02_client::client_state::ClientState as _;
+    use crate::core::ics24_host::identifier::ChainId;
+    use crate::Height;
+
+    #[test]
+    fn wrapped_tendermint_client_state() {
+        let tm_client_state = get_dummy_tendermint_client_state(get_dummy_tendermint_header());
+        let client_state = ClientState::from_tendermint(vec![1; 32], tm_client_state.clone());
+
+        let decoded = ClientState::try_from(Any::from(client_state.clone())).unwrap();
+        assert_eq!(decoded, client_state);
+
+        assert_eq!(decoded.checksum, vec![1; 32]);
+        assert_eq!(decoded.latest_height(), tm_client_state.latest_height());
+        assert_eq!(decoded.chain_id(), tm_client_state.chain_id());
+        assert_eq!(decoded.tendermint, Some(tm_client_state));
+        assert!(!decoded.is_frozen());
+    }
+
+    #[test]
+    fn frozen_wrapped_tendermint_client_state() {
+        let tm_client_state = get_dummy_tendermint_client_state(get_dummy_tendermint_header())
+            .with_frozen_height(Height::new(0, 1).unwrap())
+            .unwrap();
+        let client_state = ClientState::from_tendermint(vec![1; 32], tm_client_state);
+
+        assert!(client_state.is_frozen());
+    }
+
+    #[test]
+    fn opaque_client_state() {
+        let raw = RawClientState {
+            data: b"opaque contract state".to_vec(),
+            checksum: vec![2; 32],
+            latest_height: Some(Height::new(1, 42).unwrap().into()),
+        };
+        let client_state = ClientState::try_from(raw.clone()).unwrap();
+        assert_eq!(RawClientState::from(client_state.clone()), raw);
+
+        assert_eq!(client_state.tendermint, None);
+        assert_eq!(client_state.latest_height(), Height::new(1, 42).unwrap());
+        assert_eq!(client_state.chain_id(), ChainId::default());
+        assert!(!client_state.is_frozen());
+    }
+
+    #[test]
+    fn client_state_without_checksum() {
+        let raw = RawClientState {
+            data: vec![],
+            checksum: vec![],
+            latest_height: Some(Height::new(1, 42).unwrap().into()),
+        };
+
+        assert!(ClientState::try_from(raw).is_err());
+    }
+}
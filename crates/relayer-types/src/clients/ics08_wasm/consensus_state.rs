@@ -0,0 +1,158 @@
+use crate::prelude::*;
+
+use ibc_proto::google::protobuf::Any;
+use ibc_proto::protobuf::Protobuf;
+use prost::Message;
+use serde::{Deserialize, Serialize};
+
+use crate::clients::ics07_tendermint::consensus_state::{
+    ConsensusState as TmConsensusState, TENDERMINT_CONSENSUS_STATE_TYPE_URL,
+};
+use crate::clients::ics08_wasm::error::Error;
+use crate::clients::ics08_wasm::raw::ConsensusState as RawConsensusState;
+use crate::core::ics02_client::client_type::ClientType;
+use crate::core::ics02_client::consensus_state::ConsensusState as Ics02ConsensusState;
+use crate::core::ics02_client::error::Error as Ics02Error;
+use crate::core::ics23_commitment::commitment::CommitmentRoot;
+use crate::timestamp::Timestamp;
+
+pub const WASM_CONSENSUS_STATE_TYPE_URL: &str = "/ibc.lightclients.wasm.v1.ConsensusState";
+
+/// The root of consensus states whose contents are opaque to the relayer.
+static EMPTY_ROOT: CommitmentRoot = CommitmentRoot::empty();
+
+/// The consensus state of an `08-wasm` client.
+///
+/// As for the client state, `data` is only decoded when it holds a
+/// Tendermint consensus state.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ConsensusState {
+    #[serde(serialize_with = "crate::serializers::ser_hex_upper")]
+    pub data: Vec<u8>,
+    pub tendermint: Option<TmConsensusState>,
+}
+
+impl ConsensusState {
+    pub fn new(data: Vec<u8>) -> Self {
+        let tendermint = decode_tendermint_consensus_state(&data);
+
+        Self { data, tendermint }
+    }
+
+    pub fn from_tendermint(consensus_state: TmConsensusState) -> Self {
+        Self {
+            data: Any::from(consensus_state.clone()).encode_to_vec(),
+            tendermint: Some(consensus_state),
+        }
+    }
+}
+
+/// Decodes the Tendermint consensus state held by the state of a Wasm contract, if any.
+fn decode_tendermint_consensus_state(data: &[u8]) -> Option<TmConsensusState> {
+    let any = Any::decode(data).ok()?;
+
+    if any.type_url != TENDERMINT_CONSENSUS_STATE_TYPE_URL {
+        return None;
+    }
+
+    TmConsensusState::try_from(any).ok()
+}
+
+impl Ics02ConsensusState for ConsensusState {
+    fn client_type(&self) -> ClientType {
+        ClientType::Wasm
+    }
+
+    fn root(&self) -> &CommitmentRoot {
+        self.tendermint
+            .as_ref()
+            .map_or(&EMPTY_ROOT, |consensus_state| consensus_state.root())
+    }
+
+    fn timestamp(&self) -> Timestamp {
+        self.tendermint
+            .as_ref()
+            .map_or_else(Timestamp::none, |consensus_state| {
+                consensus_state.timestamp()
+            })
+    }
+}
+
+impl Protobuf<RawConsensusState> for ConsensusState {}
+
+impl TryFrom<RawConsensusState> for ConsensusState {
+    type Error = Error;
+
+    fn try_from(raw: RawConsensusState) -> Result<Self, Self::Error> {
+        Ok(Self::new(raw.data))
+    }
+}
+
+impl From<ConsensusState> for RawConsensusState {
+    fn from(value: ConsensusState) -> Self {
+        RawConsensusState { data: value.data }
+    }
+}
+
+impl Protobuf<Any> for ConsensusState {}
+
+impl TryFrom<Any> for ConsensusState {
+    type Error = Ics02Error;
+
+    fn try_from(raw: Any) -> Result<Self, Self::Error> {
+        fn decode_consensus_state(buf: &[u8]) -> Result<ConsensusState, Error> {
+            RawConsensusState::decode(buf)
+                .map_err(Error::decode)?
+                .try_into()
+        }
+
+        match raw.type_url.as_str() {
+            WASM_CONSENSUS_STATE_TYPE_URL => decode_consensus_state(&raw.value).map_err(Into::into),
+            _ => Err(Ics02Error::unknown_consensus_state_type(raw.type_url)),
+        }
+    }
+}
+
+impl From<ConsensusState> for Any {
+    fn from(consensus_state: ConsensusState) -> Self {
+        Any {
+            type_url: WASM_CONSENSUS_STATE_TYPE_URL.to_string(),
+            value: Protobuf::<RawConsensusState>::encode_vec(&consensus_state),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    use ibc_proto::google::protobuf::Any;
+    use test_log::test;
+
+    use super::ConsensusState;
+    use crate::clients::ics07_tendermint::consensus_state::ConsensusState as TmConsensusState;
+    use crate::clients::ics07_tendermint::header::test_util::get_dummy_tendermint_header;
+    use crate::core::ics02_client::consensus_state::ConsensusState as _;
+    use crate::timestamp::Timestamp;
+
+    #[test]
+    fn wrapped_tendermint_consensus_state() {
+        let tm_consensus_state = TmConsensusState::from(get_dummy_tendermint_header());
+        let consensus_state = ConsensusState::from_tendermint(tm_consensus_state.clone());
+
+        let decoded = ConsensusState::try_from(Any::from(consensus_state.clone())).unwrap();
+        assert_eq!(decoded, consensus_state);
+
+        assert_eq!(decoded.timestamp(), tm_consensus_state.timestamp());
+        assert_eq!(decoded.root(), tm_consensus_state.root());
+    }
+
+    #[test]
+    fn opaque_consensus_state() {
+        let consensus_state = ConsensusState::new(b"opaque contract state".to_vec());
+
+        assert_eq!(consensus_state.tendermint, None);
+        assert_eq!(consensus_state.timestamp(), Timestamp::none());
+        assert!(consensus_state.root().as_bytes().is_empty());
+    }
+}
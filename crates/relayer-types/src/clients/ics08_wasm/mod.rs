@@ -0,0 +1,12 @@
+//! ICS 08: Wasm Client wraps light clients implemented as Wasm contracts stored on the
+//! host chain, such as the clients of chains which do not run Tendermint.
+//!
+//! The state of these clients is opaque to the host chain. The relayer only decodes it
+//! when it wraps a Tendermint client state, consensus state or header, and otherwise only
+//! exposes the checksum of the contract and the latest height of the client.
+
+pub mod client_message;
+pub mod client_state;
+pub mod consensus_state;
+pub mod error;
+pub mod raw;
@@ -0,0 +1,31 @@
+//! Protobuf definitions of the `ibc.lightclients.wasm.v1` types, as used by the `08-wasm`
+//! module of ibc-go.
+//!
+//! These types are not part of the `ibc-proto` release used by the relayer, so they are
+//! defined here by hand, following the field numbering of the ibc-go proto files.
+
+use crate::prelude::*;
+
+use ibc_proto::ibc::core::client::v1::Height;
+
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ClientState {
+    #[prost(bytes = "vec", tag = "1")]
+    pub data: Vec<u8>,
+    #[prost(bytes = "vec", tag = "2")]
+    pub checksum: Vec<u8>,
+    #[prost(message, optional, tag = "3")]
+    pub latest_height: Option<Height>,
+}
+
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ConsensusState {
+    #[prost(bytes = "vec", tag = "1")]
+    pub data: Vec<u8>,
+}
+
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ClientMessage {
+    #[prost(bytes = "vec", tag = "1")]
+    pub data: Vec<u8>,
+}
@@ -8,6 +8,8 @@ use super::error::Error;
 #[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
 pub enum ClientType {
     Tendermint = 1,
+    Solomachine = 6,
+    Wasm = 8,
 
     #[cfg(any(test, feature = "mocks"))]
     Mock = 9999,
@@ -15,6 +17,8 @@ pub enum ClientType {
 
 impl ClientType {
     const TENDERMINT_STR: &'static str = "07-tendermint";
+    const SOLOMACHINE_STR: &'static str = "06-solomachine";
+    const WASM_STR: &'static str = "08-wasm";
 
     #[cfg_attr(not(test), allow(dead_code))]
     const MOCK_STR: &'static str = "9999-mock";
@@ -23,6 +27,8 @@ impl ClientType {
     pub fn as_str(&self) -> &'static str {
         match self {
             Self::Tendermint => Self::TENDERMINT_STR,
+            Self::Solomachine => Self::SOLOMACHINE_STR,
+            Self::Wasm => Self::WASM_STR,
 
             #[cfg(any(test, feature = "mocks"))]
             Self::Mock => Self::MOCK_STR,
@@ -42,6 +48,8 @@ impl core::str::FromStr for ClientType {
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s {
             Self::TENDERMINT_STR => Ok(Self::Tendermint),
+            Self::SOLOMACHINE_STR => Ok(Self::Solomachine),
+            Self::WASM_STR => Ok(Self::Wasm),
 
             #[cfg(any(test, feature = "mocks"))]
             Self::MOCK_STR => Ok(Self::Mock),
@@ -69,6 +77,26 @@ mod tests {
         }
     }
 
+    #[test]
+    fn parse_solomachine_client_type() {
+        let client_type = ClientType::from_str("06-solomachine");
+
+        match client_type {
+            Ok(ClientType::Solomachine) => (),
+            _ => panic!("parse failed"),
+        }
+    }
+
+    #[test]
+    fn parse_wasm_client_type() {
+        let client_type = ClientType::from_str("08-wasm");
+
+        match client_type {
+            Ok(ClientType::Wasm) => (),
+            _ => panic!("parse failed"),
+        }
+    }
+
     #[test]
     fn parse_mock_client_type() {
         let client_type = ClientType::from_str("9999-mock");
@@ -0,0 +1,111 @@
+//! Definition of domain type message `MsgRecoverClient`, which replaces the state of an
+//! expired or frozen client (the subject) by the state of an active client (the substitute).
+//!
+//! This message was introduced in ibc-go v8 and can only be executed by the authority of
+//! the `02-client` module, ie. through a governance proposal.
+
+use crate::prelude::*;
+
+use ibc_proto::protobuf::Protobuf;
+
+use crate::core::ics02_client::error::Error;
+use crate::core::ics24_host::identifier::ClientId;
+use crate::signer::Signer;
+use crate::tx_msg::Msg;
+
+pub const TYPE_URL: &str = "/ibc.core.client.v1.MsgRecoverClient";
+
+/// Protobuf definition of `ibc.core.client.v1.MsgRecoverClient`, which is not part
+/// of the `ibc-proto` release used by the relayer.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RawMsgRecoverClient {
+    #[prost(string, tag = "1")]
+    pub subject_client_id: String,
+    #[prost(string, tag = "2")]
+    pub substitute_client_id: String,
+    #[prost(string, tag = "3")]
+    pub signer: String,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MsgRecoverClient {
+    /// the client to recover
+    pub subject_client_id: ClientId,
+    /// the client whose state replaces the state of the subject client
+    pub substitute_client_id: ClientId,
+    /// the authority of the `02-client` module, usually the governance module account
+    pub signer: Signer,
+}
+
+impl Msg for MsgRecoverClient {
+    type ValidationError = Error;
+    type Raw = RawMsgRecoverClient;
+
+    fn route(&self) -> String {
+        crate::keys::ROUTER_KEY.to_string()
+    }
+
+    fn type_url(&self) -> String {
+        TYPE_URL.to_string()
+    }
+}
+
+impl Protobuf<RawMsgRecoverClient> for MsgRecoverClient {}
+
+impl TryFrom<RawMsgRecoverClient> for MsgRecoverClient {
+    type Error = Error;
+
+    fn try_from(raw: RawMsgRecoverClient) -> Result<Self, Self::Error> {
+        Ok(MsgRecoverClient {
+            subject_client_id: raw
+                .subject_client_id
+                .parse()
+                .map_err(Error::invalid_client_identifier)?,
+            substitute_client_id: raw
+                .substitute_client_id
+                .parse()
+                .map_err(Error::invalid_client_identifier)?,
+            signer: raw.signer.parse().map_err(Error::signer)?,
+        })
+    }
+}
+
+impl From<MsgRecoverClient> for RawMsgRecoverClient {
+    fn from(msg: MsgRecoverClient) -> Self {
+        RawMsgRecoverClient {
+            subject_client_id: msg.subject_client_id.to_string(),
+            substitute_client_id: msg.substitute_client_id.to_string(),
+            signer: msg.signer.to_string(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::str::FromStr;
+
+    use ibc_proto::protobuf::Protobuf;
+    use test_log::test;
+
+    use super::{MsgRecoverClient, RawMsgRecoverClient};
+    use crate::core::ics24_host::identifier::ClientId;
+    use crate::prelude::*;
+    use crate::test_utils::get_dummy_account_id;
+
+    #[test]
+    fn msg_to_and_from_raw() {
+        let msg = MsgRecoverClient {
+            subject_client_id: ClientId::from_str("07-tendermint-0").unwrap(),
+            substitute_client_id: ClientId::from_str("07-tendermint-1").unwrap(),
+            signer: get_dummy_account_id(),
+        };
+
+        let raw = RawMsgRecoverClient::from(msg.clone());
+        let decoded = MsgRecoverClient::try_from(raw).unwrap();
+        assert_eq!(msg, decoded);
+
+        let bytes = Protobuf::<RawMsgRecoverClient>::encode_vec(&msg);
+        let decoded = Protobuf::<RawMsgRecoverClient>::decode_vec(&bytes).unwrap();
+        assert_eq!(msg, decoded);
+    }
+}
@@ -4,7 +4,9 @@ use crate::utils::pretty::PrettySlice;
 use core::fmt::{Display, Error as FmtError, Formatter};
 use core::str::FromStr;
 
-use ibc_proto::protobuf::Protobuf;
+use ibc_proto::protobuf::{Error as ProtoError, Protobuf};
+use prost::bytes::Buf;
+use prost::Message;
 use serde::{Deserialize, Serialize};
 
 use ibc_proto::ibc::core::channel::v1::{
@@ -12,6 +14,7 @@ use ibc_proto::ibc::core::channel::v1::{
     IdentifiedChannel as RawIdentifiedChannel,
 };
 
+use crate::core::ics04_channel::upgrade::raw::Channel as RawUpgradableChannel;
 use crate::core::ics04_channel::{error::Error, version::Version};
 use crate::core::ics24_host::identifier::{ChannelId, ConnectionId, PortId};
 
@@ -80,14 +83,18 @@ pub struct ChannelEnd {
     pub remote: Counterparty,
     pub connection_hops: Vec<ConnectionId>,
     pub version: Version,
+    /// The sequence of the last upgrade of the channel, always `0` for chains
+    /// which do not support channel upgrades
+    #[serde(default)]
+    pub upgrade_sequence: u64,
 }
 
 impl Display for ChannelEnd {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), FmtError> {
         write!(
             f,
-            "ChannelEnd {{ state: {}, ordering: {}, remote: {}, connection_hops: {}, version: {} }}",
-            self.state, self.ordering, self.remote, PrettySlice(&self.connection_hops), self.version
+            "ChannelEnd {{ state: {}, ordering: {}, remote: {}, connection_hops: {}, version: {}, upgrade_sequence: {} }}",
+            self.state, self.ordering, self.remote, PrettySlice(&self.connection_hops), self.version, self.upgrade_sequence
         )
     }
 }
@@ -100,16 +107,44 @@ impl Default for ChannelEnd {
             remote: Counterparty::default(),
             connection_hops: Vec::new(),
             version: Version::default(),
+            upgrade_sequence: 0,
         }
     }
 }
 
-impl Protobuf<RawChannel> for ChannelEnd {}
+impl Protobuf<RawChannel> for ChannelEnd {
+    /// Decodes the channel end including its upgrade sequence, which is not part
+    /// of the `Channel` message of the `ibc-proto` release used by the relayer.
+    fn decode<B: Buf>(buf: B) -> Result<Self, ProtoError>
+    where
+        Self: Sized,
+    {
+        let raw = RawUpgradableChannel::decode(buf).map_err(ProtoError::decode_message)?;
+
+        Self::try_from(raw).map_err(ProtoError::try_from::<RawUpgradableChannel, Self, _>)
+    }
+}
 
 impl TryFrom<RawChannel> for ChannelEnd {
     type Error = Error;
 
     fn try_from(value: RawChannel) -> Result<Self, Self::Error> {
+        RawUpgradableChannel {
+            state: value.state,
+            ordering: value.ordering,
+            counterparty: value.counterparty,
+            connection_hops: value.connection_hops,
+            version: value.version,
+            upgrade_sequence: 0,
+        }
+        .try_into()
+    }
+}
+
+impl TryFrom<RawUpgradableChannel> for ChannelEnd {
+    type Error = Error;
+
+    fn try_from(value: RawUpgradableChannel) -> Result<Self, Self::Error> {
         let chan_state: State = State::from_i32(value.state)?;
 
         if chan_state == State::Uninitialized {
@@ -134,13 +169,26 @@ impl TryFrom<RawChannel> for ChannelEnd {
 
         let version = value.version.into();
 
-        Ok(ChannelEnd::new(
-            chan_state,
-            chan_ordering,
-            remote,
-            connection_hops,
-            version,
-        ))
+        Ok(
+            ChannelEnd::new(chan_state, chan_ordering, remote, connection_hops, version)
+                .with_upgrade_sequence(value.upgrade_sequence),
+        )
+    }
+}
+
+impl From<ChannelEnd> for RawUpgradableChannel {
+    fn from(value: ChannelEnd) -> Self {
+        let upgrade_sequence = value.upgrade_sequence;
+        let raw = RawChannel::from(value);
+
+        RawUpgradableChannel {
+            state: raw.state,
+            ordering: raw.ordering,
+            counterparty: raw.counterparty,
+            connection_hops: raw.connection_hops,
+            version: raw.version,
+            upgrade_sequence,
+        }
     }
 }
 
@@ -175,6 +223,14 @@ impl ChannelEnd {
             remote,
             connection_hops,
             version,
+            upgrade_sequence: 0,
+        }
+    }
+
+    pub fn with_upgrade_sequence(self, upgrade_sequence: u64) -> Self {
+        Self {
+            upgrade_sequence,
+            ..self
         }
     }
 
@@ -216,6 +272,16 @@ impl ChannelEnd {
         &self.version
     }
 
+    pub fn upgrade_sequence(&self) -> u64 {
+        self.upgrade_sequence
+    }
+
+    /// Returns `true` if this `ChannelEnd` is flushing its in-flight packets
+    /// as part of a channel upgrade.
+    pub fn is_upgrading(&self) -> bool {
+        self.state.is_upgrading()
+    }
+
     pub fn validate_basic(&self) -> Result<(), Error> {
         if self.connection_hops.len() != 1 {
             return Err(Error::invalid_connection_hops_length(
@@ -379,6 +445,8 @@ pub enum State {
     TryOpen = 2,
     Open = 3,
     Closed = 4,
+    Flushing = 5,
+    FlushComplete = 6,
 }
 
 impl State {
@@ -390,6 +458,8 @@ impl State {
             Self::TryOpen => "TRYOPEN",
             Self::Open => "OPEN",
             Self::Closed => "CLOSED",
+            Self::Flushing => "FLUSHING",
+            Self::FlushComplete => "FLUSHCOMPLETE",
         }
     }
 
@@ -401,6 +471,8 @@ impl State {
             2 => Ok(Self::TryOpen),
             3 => Ok(Self::Open),
             4 => Ok(Self::Closed),
+            5 => Ok(Self::Flushing),
+            6 => Ok(Self::FlushComplete),
             _ => Err(Error::unknown_state(s)),
         }
     }
@@ -415,6 +487,13 @@ impl State {
         self == State::Closed
     }
 
+    /// Returns whether or not this channel state is one of the states
+    /// an open channel goes through while it is being upgraded,
+    /// ie. `Flushing` or `FlushComplete`.
+    pub fn is_upgrading(self) -> bool {
+        matches!(self, State::Flushing | State::FlushComplete)
+    }
+
     /// Returns whether or not the channel with this state
     /// has progressed less or the same than the argument.
     ///
@@ -424,6 +503,9 @@ impl State {
     /// assert!(State::TryOpen.less_or_equal_progress(State::TryOpen));
     /// assert!(!State::Closed.less_or_equal_progress(State::Open));
     /// ```
+    ///
+    /// Note that the upgrade states `Flushing` and `FlushComplete` are only
+    /// reachable from `Open`, and are ranked after all the other states.
     pub fn less_or_equal_progress(self, other: Self) -> bool {
         self as u32 <= other as u32
     }
@@ -99,6 +99,30 @@ define_error! {
         MissingChannel
             | _ | { "missing channel end" },
 
+        MissingUpgrade
+            | _ | { "missing channel upgrade" },
+
+        MissingUpgradeFields
+            | _ | { "missing channel upgrade fields" },
+
+        MissingUpgradeTimeout
+            | _ | { "missing channel upgrade timeout" },
+
+        MissingErrorReceipt
+            | _ | { "missing channel upgrade error receipt" },
+
+        InvalidUpgradeTimeout
+            | _ | { "invalid channel upgrade timeout: either the timeout height or the timeout timestamp must be set" },
+
+        InvalidUpgradeSequence
+            { value: String }
+            [ TraceError<core::num::ParseIntError> ]
+            | e | {
+                format_args!(
+                    "String {0} cannot be converted to an upgrade sequence",
+                    e.value)
+            },
+
         InvalidVersionLengthConnection
             | _ | { "single version must be negociated on connection before opening channel" },
 
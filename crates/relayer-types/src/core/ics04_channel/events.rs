@@ -19,6 +19,9 @@ pub const PORT_ID_ATTRIBUTE_KEY: &str = "port_id";
 pub const COUNTERPARTY_CHANNEL_ID_ATTRIBUTE_KEY: &str = "counterparty_channel_id";
 pub const COUNTERPARTY_PORT_ID_ATTRIBUTE_KEY: &str = "counterparty_port_id";
 
+/// Channel upgrade event attribute keys
+pub const UPGRADE_SEQUENCE_ATTRIBUTE_KEY: &str = "upgrade_sequence";
+
 /// Packet event attribute keys
 pub const PKT_SEQ_ATTRIBUTE_KEY: &str = "packet_sequence";
 pub const PKT_DATA_ATTRIBUTE_KEY: &str = "packet_data";
@@ -514,6 +517,181 @@ impl_from_ibc_to_abci_event!(
     CloseConfirm
 );
 
+/// The attributes of the events emitted during the channel upgrade handshake.
+#[derive(Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize, Serialize)]
+pub struct UpgradeAttributes {
+    pub port_id: PortId,
+    pub channel_id: ChannelId,
+    pub counterparty_port_id: PortId,
+    pub counterparty_channel_id: Option<ChannelId>,
+    pub upgrade_sequence: u64,
+}
+
+impl UpgradeAttributes {
+    pub fn port_id(&self) -> &PortId {
+        &self.port_id
+    }
+
+    pub fn channel_id(&self) -> &ChannelId {
+        &self.channel_id
+    }
+
+    pub fn counterparty_port_id(&self) -> &PortId {
+        &self.counterparty_port_id
+    }
+
+    pub fn counterparty_channel_id(&self) -> Option<&ChannelId> {
+        self.counterparty_channel_id.as_ref()
+    }
+
+    pub fn upgrade_sequence(&self) -> u64 {
+        self.upgrade_sequence
+    }
+}
+
+impl Display for UpgradeAttributes {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), FmtError> {
+        match &self.counterparty_channel_id {
+            Some(counterparty_channel_id) => write!(f, "UpgradeAttributes {{ port_id: {}, channel_id: {}, counterparty_port_id: {}, counterparty_channel_id: {}, upgrade_sequence: {} }}", self.port_id, self.channel_id, self.counterparty_port_id, counterparty_channel_id, self.upgrade_sequence),
+            None => write!(f, "UpgradeAttributes {{ port_id: {}, channel_id: {}, counterparty_port_id: {}, counterparty_channel_id: None, upgrade_sequence: {} }}", self.port_id, self.channel_id, self.counterparty_port_id, self.upgrade_sequence),
+        }
+    }
+}
+
+/// Convert attributes to Tendermint ABCI tags
+impl From<UpgradeAttributes> for Vec<abci::EventAttribute> {
+    fn from(a: UpgradeAttributes) -> Self {
+        let mut attributes = vec![];
+        let port_id = (PORT_ID_ATTRIBUTE_KEY, a.port_id.as_str()).into();
+        attributes.push(port_id);
+        let channel_id = (CHANNEL_ID_ATTRIBUTE_KEY, a.channel_id.as_str()).into();
+        attributes.push(channel_id);
+        let counterparty_port_id = (
+            COUNTERPARTY_PORT_ID_ATTRIBUTE_KEY,
+            a.counterparty_port_id.as_str(),
+        )
+            .into();
+        attributes.push(counterparty_port_id);
+        if let Some(channel_id) = a.counterparty_channel_id {
+            let channel_id = (COUNTERPARTY_CHANNEL_ID_ATTRIBUTE_KEY, channel_id.as_str()).into();
+            attributes.push(channel_id);
+        }
+        let upgrade_sequence = (
+            UPGRADE_SEQUENCE_ATTRIBUTE_KEY,
+            a.upgrade_sequence.to_string(),
+        )
+            .into();
+        attributes.push(upgrade_sequence);
+        attributes
+    }
+}
+
+/// Emitted when a channel end proposes an upgrade.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct UpgradeInit(pub UpgradeAttributes);
+
+/// Emitted when a channel end accepts the upgrade proposed by its counterparty.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct UpgradeTry(pub UpgradeAttributes);
+
+/// Emitted when the channel end which proposed an upgrade acknowledges the counterparty upgrade.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct UpgradeAck(pub UpgradeAttributes);
+
+/// Emitted when a channel end confirms that the counterparty channel end is flushing.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct UpgradeConfirm(pub UpgradeAttributes);
+
+/// Emitted when a channel end is re-opened with the upgraded parameters.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct UpgradeOpen(pub UpgradeAttributes);
+
+/// Emitted when a channel end aborts an upgrade which has timed out.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct UpgradeTimeout(pub UpgradeAttributes);
+
+/// Emitted when a channel end aborts an upgrade which was cancelled.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct UpgradeCancel(pub UpgradeAttributes);
+
+/// Emitted when a channel end aborts an upgrade and writes an error receipt,
+/// which allows the counterparty channel end to cancel the upgrade as well.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct UpgradeError(pub UpgradeAttributes);
+
+macro_rules! impl_upgrade_event {
+    ($($event:ident => $event_type:ident),+) => {
+        $(impl $event {
+            pub fn attributes(&self) -> &UpgradeAttributes {
+                &self.0
+            }
+
+            pub fn port_id(&self) -> &PortId {
+                &self.0.port_id
+            }
+
+            pub fn channel_id(&self) -> &ChannelId {
+                &self.0.channel_id
+            }
+
+            pub fn upgrade_sequence(&self) -> u64 {
+                self.0.upgrade_sequence
+            }
+        }
+
+        impl Display for $event {
+            fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), FmtError> {
+                write!(f, "{} {{ {} }}", stringify!($event), self.0)
+            }
+        }
+
+        impl From<UpgradeAttributes> for $event {
+            fn from(attrs: UpgradeAttributes) -> Self {
+                Self(attrs)
+            }
+        }
+
+        impl From<$event> for UpgradeAttributes {
+            fn from(ev: $event) -> Self {
+                ev.0
+            }
+        }
+
+        impl From<$event> for IbcEvent {
+            fn from(v: $event) -> Self {
+                IbcEvent::$event_type(v)
+            }
+        }
+
+        impl EventType for $event {
+            fn event_type() -> IbcEventType {
+                IbcEventType::$event_type
+            }
+        }
+
+        impl From<$event> for abci::Event {
+            fn from(v: $event) -> Self {
+                let kind = <$event>::event_type().as_str().to_owned();
+                Self {
+                    kind,
+                    attributes: v.0.into(),
+                }
+            }
+        })+
+    };
+}
+
+impl_upgrade_event!(
+    UpgradeInit => UpgradeInitChannel,
+    UpgradeTry => UpgradeTryChannel,
+    UpgradeAck => UpgradeAckChannel,
+    UpgradeConfirm => UpgradeConfirmChannel,
+    UpgradeOpen => UpgradeOpenChannel,
+    UpgradeTimeout => UpgradeTimeoutChannel,
+    UpgradeCancel => UpgradeCancelChannel,
+    UpgradeError => UpgradeErrorChannel
+);
+
 #[derive(Clone, Debug, PartialEq, Eq, Serialize)]
 pub struct SendPacket {
     pub packet: Packet,
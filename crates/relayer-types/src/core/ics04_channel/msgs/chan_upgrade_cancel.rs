@@ -0,0 +1,184 @@
+use crate::prelude::*;
+
+use ibc_proto::protobuf::Protobuf;
+
+use crate::core::ics04_channel::error::Error;
+use crate::core::ics04_channel::upgrade::raw::MsgChannelUpgradeCancel as RawMsgChannelUpgradeCancel;
+use crate::core::ics04_channel::upgrade::ErrorReceipt;
+use crate::core::ics24_host::identifier::{ChannelId, PortId};
+use crate::proofs::Proofs;
+use crate::signer::Signer;
+use crate::tx_msg::Msg;
+
+pub const TYPE_URL: &str = "/ibc.core.channel.v1.MsgChannelUpgradeCancel";
+
+///
+/// Message definition to abort a channel upgrade given the error receipt written
+/// by the counterparty chain (`ChanUpgradeCancel` datagram).
+///
+/// The object proof is the proof of the error receipt.
+///
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MsgChannelUpgradeCancel {
+    pub port_id: PortId,
+    pub channel_id: ChannelId,
+    pub error_receipt: ErrorReceipt,
+    pub proofs: Proofs,
+    pub signer: Signer,
+}
+
+impl Msg for MsgChannelUpgradeCancel {
+    type ValidationError = Error;
+    type Raw = RawMsgChannelUpgradeCancel;
+
+    fn route(&self) -> String {
+        crate::keys::ROUTER_KEY.to_string()
+    }
+
+    fn type_url(&self) -> String {
+        TYPE_URL.to_string()
+    }
+}
+
+impl Protobuf<RawMsgChannelUpgradeCancel> for MsgChannelUpgradeCancel {}
+
+impl TryFrom<RawMsgChannelUpgradeCancel> for MsgChannelUpgradeCancel {
+    type Error = Error;
+
+    fn try_from(raw_msg: RawMsgChannelUpgradeCancel) -> Result<Self, Self::Error> {
+        let proofs = Proofs::new(
+            raw_msg
+                .proof_error_receipt
+                .try_into()
+                .map_err(Error::invalid_proof)?,
+            None,
+            None,
+            None,
+            raw_msg
+                .proof_height
+                .and_then(|raw_height| raw_height.try_into().ok())
+                .ok_or_else(Error::missing_height)?,
+        )
+        .map_err(Error::invalid_proof)?;
+
+        Ok(MsgChannelUpgradeCancel {
+            port_id: raw_msg.port_id.parse().map_err(Error::identifier)?,
+            channel_id: raw_msg.channel_id.parse().map_err(Error::identifier)?,
+            error_receipt: raw_msg
+                .error_receipt
+                .ok_or_else(Error::missing_error_receipt)?
+                .try_into()?,
+            proofs,
+            signer: raw_msg.signer.parse().map_err(Error::signer)?,
+        })
+    }
+}
+
+impl From<MsgChannelUpgradeCancel> for RawMsgChannelUpgradeCancel {
+    fn from(domain_msg: MsgChannelUpgradeCancel) -> Self {
+        RawMsgChannelUpgradeCancel {
+            port_id: domain_msg.port_id.to_string(),
+            channel_id: domain_msg.channel_id.to_string(),
+            error_receipt: Some(domain_msg.error_receipt.into()),
+            proof_error_receipt: domain_msg.proofs.object_proof().clone().into(),
+            proof_height: Some(domain_msg.proofs.height().into()),
+            signer: domain_msg.signer.to_string(),
+        }
+    }
+}
+
+#[cfg(test)]
+pub mod test_util {
+    use crate::prelude::*;
+
+    use ibc_proto::ibc::core::client::v1::Height;
+
+    use crate::core::ics04_channel::upgrade::raw::MsgChannelUpgradeCancel as RawMsgChannelUpgradeCancel;
+    use crate::core::ics04_channel::upgrade::test_util::get_dummy_raw_error_receipt;
+    use crate::core::ics24_host::identifier::{ChannelId, PortId};
+    use crate::test_utils::{get_dummy_bech32_account, get_dummy_proof};
+
+    /// Returns a dummy `RawMsgChannelUpgradeCancel`, for testing only!
+    pub fn get_dummy_raw_msg_chan_upgrade_cancel(proof_height: u64) -> RawMsgChannelUpgradeCancel {
+        RawMsgChannelUpgradeCancel {
+            port_id: PortId::default().to_string(),
+            channel_id: ChannelId::default().to_string(),
+            error_receipt: Some(get_dummy_raw_error_receipt()),
+            proof_error_receipt: get_dummy_proof(),
+            proof_height: Some(Height {
+                revision_number: 0,
+                revision_height: proof_height,
+            }),
+            signer: get_dummy_bech32_account(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    use test_log::test;
+
+    use crate::core::ics04_channel::msgs::chan_upgrade_cancel::test_util::get_dummy_raw_msg_chan_upgrade_cancel;
+    use crate::core::ics04_channel::msgs::chan_upgrade_cancel::MsgChannelUpgradeCancel;
+    use crate::core::ics04_channel::upgrade::raw::MsgChannelUpgradeCancel as RawMsgChannelUpgradeCancel;
+
+    #[test]
+    fn parse_channel_upgrade_cancel_msg() {
+        struct Test {
+            name: String,
+            raw: RawMsgChannelUpgradeCancel,
+            want_pass: bool,
+        }
+
+        let default_raw_msg = get_dummy_raw_msg_chan_upgrade_cancel(10);
+
+        let tests: Vec<Test> = vec![
+            Test {
+                name: "Good parameters".to_string(),
+                raw: default_raw_msg.clone(),
+                want_pass: true,
+            },
+            Test {
+                name: "Missing error receipt".to_string(),
+                raw: RawMsgChannelUpgradeCancel {
+                    error_receipt: None,
+                    ..default_raw_msg.clone()
+                },
+                want_pass: false,
+            },
+            Test {
+                name: "Missing proof error receipt".to_string(),
+                raw: RawMsgChannelUpgradeCancel {
+                    proof_error_receipt: Vec::new(),
+                    ..default_raw_msg
+                },
+                want_pass: false,
+            },
+        ];
+
+        for test in tests {
+            let res_msg = MsgChannelUpgradeCancel::try_from(test.raw.clone());
+
+            assert_eq!(
+                test.want_pass,
+                res_msg.is_ok(),
+                "MsgChannelUpgradeCancel::try_from failed for test {}, \nraw msg {:?} with error {:?}",
+                test.name,
+                test.raw,
+                res_msg.err(),
+            );
+        }
+    }
+
+    #[test]
+    fn to_and_from() {
+        let raw = get_dummy_raw_msg_chan_upgrade_cancel(10);
+        let msg = MsgChannelUpgradeCancel::try_from(raw.clone()).unwrap();
+        let raw_back = RawMsgChannelUpgradeCancel::from(msg.clone());
+        let msg_back = MsgChannelUpgradeCancel::try_from(raw_back.clone()).unwrap();
+        assert_eq!(raw, raw_back);
+        assert_eq!(msg, msg_back);
+    }
+}
@@ -0,0 +1,210 @@
+use crate::prelude::*;
+
+use ibc_proto::protobuf::Protobuf;
+
+use crate::core::ics04_channel::channel::State;
+use crate::core::ics04_channel::error::Error;
+use crate::core::ics04_channel::upgrade::raw::MsgChannelUpgradeConfirm as RawMsgChannelUpgradeConfirm;
+use crate::core::ics04_channel::upgrade::Upgrade;
+use crate::core::ics24_host::identifier::{ChannelId, PortId};
+use crate::proofs::Proofs;
+use crate::signer::Signer;
+use crate::tx_msg::Msg;
+
+pub const TYPE_URL: &str = "/ibc.core.channel.v1.MsgChannelUpgradeConfirm";
+
+///
+/// Message definition for the fourth step in the channel upgrade handshake (`ChanUpgradeConfirm` datagram).
+///
+/// The object proof is the proof of the counterparty channel end,
+/// and the other proof is the proof of the counterparty upgrade.
+///
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MsgChannelUpgradeConfirm {
+    pub port_id: PortId,
+    pub channel_id: ChannelId,
+    pub counterparty_channel_state: State,
+    pub counterparty_upgrade: Upgrade,
+    pub proofs: Proofs,
+    pub signer: Signer,
+}
+
+impl Msg for MsgChannelUpgradeConfirm {
+    type ValidationError = Error;
+    type Raw = RawMsgChannelUpgradeConfirm;
+
+    fn route(&self) -> String {
+        crate::keys::ROUTER_KEY.to_string()
+    }
+
+    fn type_url(&self) -> String {
+        TYPE_URL.to_string()
+    }
+}
+
+impl Protobuf<RawMsgChannelUpgradeConfirm> for MsgChannelUpgradeConfirm {}
+
+impl TryFrom<RawMsgChannelUpgradeConfirm> for MsgChannelUpgradeConfirm {
+    type Error = Error;
+
+    fn try_from(raw_msg: RawMsgChannelUpgradeConfirm) -> Result<Self, Self::Error> {
+        let proofs = Proofs::new(
+            raw_msg
+                .proof_channel
+                .try_into()
+                .map_err(Error::invalid_proof)?,
+            None,
+            None,
+            Some(
+                raw_msg
+                    .proof_upgrade
+                    .try_into()
+                    .map_err(Error::invalid_proof)?,
+            ),
+            raw_msg
+                .proof_height
+                .and_then(|raw_height| raw_height.try_into().ok())
+                .ok_or_else(Error::missing_height)?,
+        )
+        .map_err(Error::invalid_proof)?;
+
+        Ok(MsgChannelUpgradeConfirm {
+            port_id: raw_msg.port_id.parse().map_err(Error::identifier)?,
+            channel_id: raw_msg.channel_id.parse().map_err(Error::identifier)?,
+            counterparty_channel_state: State::from_i32(raw_msg.counterparty_channel_state)?,
+            counterparty_upgrade: raw_msg
+                .counterparty_upgrade
+                .ok_or_else(Error::missing_upgrade)?
+                .try_into()?,
+            proofs,
+            signer: raw_msg.signer.parse().map_err(Error::signer)?,
+        })
+    }
+}
+
+impl From<MsgChannelUpgradeConfirm> for RawMsgChannelUpgradeConfirm {
+    fn from(domain_msg: MsgChannelUpgradeConfirm) -> Self {
+        RawMsgChannelUpgradeConfirm {
+            port_id: domain_msg.port_id.to_string(),
+            channel_id: domain_msg.channel_id.to_string(),
+            counterparty_channel_state: domain_msg.counterparty_channel_state as i32,
+            counterparty_upgrade: Some(domain_msg.counterparty_upgrade.into()),
+            proof_channel: domain_msg.proofs.object_proof().clone().into(),
+            proof_upgrade: domain_msg
+                .proofs
+                .other_proof()
+                .clone()
+                .map_or_else(Vec::new, |v| v.into()),
+            proof_height: Some(domain_msg.proofs.height().into()),
+            signer: domain_msg.signer.to_string(),
+        }
+    }
+}
+
+#[cfg(test)]
+pub mod test_util {
+    use crate::prelude::*;
+
+    use ibc_proto::ibc::core::client::v1::Height;
+
+    use crate::core::ics04_channel::upgrade::raw::MsgChannelUpgradeConfirm as RawMsgChannelUpgradeConfirm;
+    use crate::core::ics04_channel::upgrade::test_util::get_dummy_raw_upgrade;
+    use crate::core::ics24_host::identifier::{ChannelId, PortId};
+    use crate::test_utils::{get_dummy_bech32_account, get_dummy_proof};
+
+    /// Returns a dummy `RawMsgChannelUpgradeConfirm`, for testing only!
+    pub fn get_dummy_raw_msg_chan_upgrade_confirm(
+        proof_height: u64,
+    ) -> RawMsgChannelUpgradeConfirm {
+        RawMsgChannelUpgradeConfirm {
+            port_id: PortId::default().to_string(),
+            channel_id: ChannelId::default().to_string(),
+            counterparty_channel_state: 5,
+            counterparty_upgrade: Some(get_dummy_raw_upgrade()),
+            proof_channel: get_dummy_proof(),
+            proof_upgrade: get_dummy_proof(),
+            proof_height: Some(Height {
+                revision_number: 0,
+                revision_height: proof_height,
+            }),
+            signer: get_dummy_bech32_account(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    use test_log::test;
+
+    use crate::core::ics04_channel::msgs::chan_upgrade_confirm::test_util::get_dummy_raw_msg_chan_upgrade_confirm;
+    use crate::core::ics04_channel::msgs::chan_upgrade_confirm::MsgChannelUpgradeConfirm;
+    use crate::core::ics04_channel::upgrade::raw::MsgChannelUpgradeConfirm as RawMsgChannelUpgradeConfirm;
+
+    #[test]
+    fn parse_channel_upgrade_confirm_msg() {
+        struct Test {
+            name: String,
+            raw: RawMsgChannelUpgradeConfirm,
+            want_pass: bool,
+        }
+
+        let default_raw_msg = get_dummy_raw_msg_chan_upgrade_confirm(10);
+
+        let tests: Vec<Test> = vec![
+            Test {
+                name: "Good parameters".to_string(),
+                raw: default_raw_msg.clone(),
+                want_pass: true,
+            },
+            Test {
+                name: "Unknown counterparty channel state".to_string(),
+                raw: RawMsgChannelUpgradeConfirm {
+                    counterparty_channel_state: 42,
+                    ..default_raw_msg.clone()
+                },
+                want_pass: false,
+            },
+            Test {
+                name: "Missing counterparty upgrade".to_string(),
+                raw: RawMsgChannelUpgradeConfirm {
+                    counterparty_upgrade: None,
+                    ..default_raw_msg.clone()
+                },
+                want_pass: false,
+            },
+            Test {
+                name: "Missing proof upgrade".to_string(),
+                raw: RawMsgChannelUpgradeConfirm {
+                    proof_upgrade: Vec::new(),
+                    ..default_raw_msg
+                },
+                want_pass: false,
+            },
+        ];
+
+        for test in tests {
+            let res_msg = MsgChannelUpgradeConfirm::try_from(test.raw.clone());
+
+            assert_eq!(
+                test.want_pass,
+                res_msg.is_ok(),
+                "MsgChannelUpgradeConfirm::try_from failed for test {}, \nraw msg {:?} with error {:?}",
+                test.name,
+                test.raw,
+                res_msg.err(),
+            );
+        }
+    }
+
+    #[test]
+    fn to_and_from() {
+        let raw = get_dummy_raw_msg_chan_upgrade_confirm(10);
+        let msg = MsgChannelUpgradeConfirm::try_from(raw.clone()).unwrap();
+        let raw_back = RawMsgChannelUpgradeConfirm::from(msg.clone());
+        let msg_back = MsgChannelUpgradeConfirm::try_from(raw_back.clone()).unwrap();
+        assert_eq!(raw, raw_back);
+        assert_eq!(msg, msg_back);
+    }
+}
@@ -0,0 +1,197 @@
+use crate::prelude::*;
+
+use ibc_proto::protobuf::Protobuf;
+
+use crate::core::ics04_channel::channel::ChannelEnd;
+use crate::core::ics04_channel::error::Error;
+use crate::core::ics04_channel::upgrade::raw::MsgChannelUpgradeTimeout as RawMsgChannelUpgradeTimeout;
+use crate::core::ics24_host::identifier::{ChannelId, PortId};
+use crate::proofs::Proofs;
+use crate::signer::Signer;
+use crate::tx_msg::Msg;
+
+pub const TYPE_URL: &str = "/ibc.core.channel.v1.MsgChannelUpgradeTimeout";
+
+///
+/// Message definition to abort a channel upgrade once the upgrade timeout has elapsed
+/// on the counterparty chain (`ChanUpgradeTimeout` datagram).
+///
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MsgChannelUpgradeTimeout {
+    pub port_id: PortId,
+    pub channel_id: ChannelId,
+    pub counterparty_channel: ChannelEnd,
+    pub proofs: Proofs,
+    pub signer: Signer,
+}
+
+impl Msg for MsgChannelUpgradeTimeout {
+    type ValidationError = Error;
+    type Raw = RawMsgChannelUpgradeTimeout;
+
+    fn route(&self) -> String {
+        crate::keys::ROUTER_KEY.to_string()
+    }
+
+    fn type_url(&self) -> String {
+        TYPE_URL.to_string()
+    }
+}
+
+impl Protobuf<RawMsgChannelUpgradeTimeout> for MsgChannelUpgradeTimeout {}
+
+impl TryFrom<RawMsgChannelUpgradeTimeout> for MsgChannelUpgradeTimeout {
+    type Error = Error;
+
+    fn try_from(raw_msg: RawMsgChannelUpgradeTimeout) -> Result<Self, Self::Error> {
+        let proofs = Proofs::new(
+            raw_msg
+                .proof_channel
+                .try_into()
+                .map_err(Error::invalid_proof)?,
+            None,
+            None,
+            None,
+            raw_msg
+                .proof_height
+                .and_then(|raw_height| raw_height.try_into().ok())
+                .ok_or_else(Error::missing_height)?,
+        )
+        .map_err(Error::invalid_proof)?;
+
+        Ok(MsgChannelUpgradeTimeout {
+            port_id: raw_msg.port_id.parse().map_err(Error::identifier)?,
+            channel_id: raw_msg.channel_id.parse().map_err(Error::identifier)?,
+            counterparty_channel: raw_msg
+                .counterparty_channel
+                .ok_or_else(Error::missing_channel)?
+                .try_into()?,
+            proofs,
+            signer: raw_msg.signer.parse().map_err(Error::signer)?,
+        })
+    }
+}
+
+impl From<MsgChannelUpgradeTimeout> for RawMsgChannelUpgradeTimeout {
+    fn from(domain_msg: MsgChannelUpgradeTimeout) -> Self {
+        RawMsgChannelUpgradeTimeout {
+            port_id: domain_msg.port_id.to_string(),
+            channel_id: domain_msg.channel_id.to_string(),
+            counterparty_channel: Some(domain_msg.counterparty_channel.into()),
+            proof_channel: domain_msg.proofs.object_proof().clone().into(),
+            proof_height: Some(domain_msg.proofs.height().into()),
+            signer: domain_msg.signer.to_string(),
+        }
+    }
+}
+
+#[cfg(test)]
+pub mod test_util {
+    use crate::prelude::*;
+
+    use ibc_proto::ibc::core::client::v1::Height;
+
+    use crate::core::ics04_channel::channel::test_util::get_dummy_raw_channel_end;
+    use crate::core::ics04_channel::upgrade::raw::{
+        Channel as RawUpgradableChannel, MsgChannelUpgradeTimeout as RawMsgChannelUpgradeTimeout,
+    };
+    use crate::core::ics24_host::identifier::{ChannelId, PortId};
+    use crate::test_utils::{get_dummy_bech32_account, get_dummy_proof};
+
+    /// Returns a dummy `RawMsgChannelUpgradeTimeout`, for testing only!
+    pub fn get_dummy_raw_msg_chan_upgrade_timeout(
+        proof_height: u64,
+    ) -> RawMsgChannelUpgradeTimeout {
+        let channel = get_dummy_raw_channel_end();
+
+        RawMsgChannelUpgradeTimeout {
+            port_id: PortId::default().to_string(),
+            channel_id: ChannelId::default().to_string(),
+            counterparty_channel: Some(RawUpgradableChannel {
+                state: 3,
+                ordering: channel.ordering,
+                counterparty: channel.counterparty,
+                connection_hops: channel.connection_hops,
+                version: channel.version,
+                upgrade_sequence: 1,
+            }),
+            proof_channel: get_dummy_proof(),
+            proof_height: Some(Height {
+                revision_number: 0,
+                revision_height: proof_height,
+            }),
+            signer: get_dummy_bech32_account(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    use test_log::test;
+
+    use crate::core::ics04_channel::msgs::chan_upgrade_timeout::test_util::get_dummy_raw_msg_chan_upgrade_timeout;
+    use crate::core::ics04_channel::msgs::chan_upgrade_timeout::MsgChannelUpgradeTimeout;
+    use crate::core::ics04_channel::upgrade::raw::MsgChannelUpgradeTimeout as RawMsgChannelUpgradeTimeout;
+
+    #[test]
+    fn parse_channel_upgrade_timeout_msg() {
+        struct Test {
+            name: String,
+            raw: RawMsgChannelUpgradeTimeout,
+            want_pass: bool,
+        }
+
+        let default_raw_msg = get_dummy_raw_msg_chan_upgrade_timeout(10);
+
+        let tests: Vec<Test> = vec![
+            Test {
+                name: "Good parameters".to_string(),
+                raw: default_raw_msg.clone(),
+                want_pass: true,
+            },
+            Test {
+                name: "Missing counterparty channel".to_string(),
+                raw: RawMsgChannelUpgradeTimeout {
+                    counterparty_channel: None,
+                    ..default_raw_msg.clone()
+                },
+                want_pass: false,
+            },
+            Test {
+                name: "Missing proof height".to_string(),
+                raw: RawMsgChannelUpgradeTimeout {
+                    proof_height: None,
+                    ..default_raw_msg
+                },
+                want_pass: false,
+            },
+        ];
+
+        for test in tests {
+            let res_msg = MsgChannelUpgradeTimeout::try_from(test.raw.clone());
+
+            assert_eq!(
+                test.want_pass,
+                res_msg.is_ok(),
+                "MsgChannelUpgradeTimeout::try_from failed for test {}, \nraw msg {:?} with error {:?}",
+                test.name,
+                test.raw,
+                res_msg.err(),
+            );
+        }
+    }
+
+    #[test]
+    fn to_and_from() {
+        let raw = get_dummy_raw_msg_chan_upgrade_timeout(10);
+        let msg = MsgChannelUpgradeTimeout::try_from(raw.clone()).unwrap();
+        assert_eq!(msg.counterparty_channel.upgrade_sequence(), 1);
+
+        let raw_back = RawMsgChannelUpgradeTimeout::from(msg.clone());
+        let msg_back = MsgChannelUpgradeTimeout::try_from(raw_back.clone()).unwrap();
+        assert_eq!(raw, raw_back);
+        assert_eq!(msg, msg_back);
+    }
+}
@@ -0,0 +1,336 @@
+//! Types of the ICS4 channel upgrade handshake, which allows the ordering, the connection hops
+//! and the version of an open channel to be changed, eg. to add the fee middleware to an existing
+//! ICS20 channel.
+
+use crate::prelude::*;
+
+use core::fmt::{Display, Error as FmtError, Formatter};
+use core::str::FromStr;
+
+use ibc_proto::protobuf::Protobuf;
+use serde::{Deserialize, Serialize};
+
+use crate::core::ics04_channel::channel::Ordering;
+use crate::core::ics04_channel::error::Error;
+use crate::core::ics04_channel::packet::Sequence;
+use crate::core::ics04_channel::timeout::TimeoutHeight;
+use crate::core::ics04_channel::version::Version;
+use crate::core::ics24_host::identifier::ConnectionId;
+use crate::timestamp::{Expiry, Timestamp};
+use crate::utils::pretty::PrettySlice;
+use crate::Height;
+
+pub mod raw;
+
+use raw::{
+    ErrorReceipt as RawErrorReceipt, Timeout as RawTimeout, Upgrade as RawUpgrade,
+    UpgradeFields as RawUpgradeFields,
+};
+
+/// The parameters of a channel which can be changed by an upgrade.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct UpgradeFields {
+    pub ordering: Ordering,
+    pub connection_hops: Vec<ConnectionId>,
+    pub version: Version,
+}
+
+impl UpgradeFields {
+    pub fn new(ordering: Ordering, connection_hops: Vec<ConnectionId>, version: Version) -> Self {
+        Self {
+            ordering,
+            connection_hops,
+            version,
+        }
+    }
+}
+
+impl Display for UpgradeFields {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), FmtError> {
+        write!(
+            f,
+            "UpgradeFields {{ ordering: {}, connection_hops: {}, version: {} }}",
+            self.ordering,
+            PrettySlice(&self.connection_hops),
+            self.version
+        )
+    }
+}
+
+impl Protobuf<RawUpgradeFields> for UpgradeFields {}
+
+impl TryFrom<RawUpgradeFields> for UpgradeFields {
+    type Error = Error;
+
+    fn try_from(value: RawUpgradeFields) -> Result<Self, Self::Error> {
+        let ordering = Ordering::from_i32(value.ordering)?;
+
+        let connection_hops = value
+            .connection_hops
+            .iter()
+            .map(|conn_id| ConnectionId::from_str(conn_id))
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(Error::identifier)?;
+
+        Ok(Self::new(ordering, connection_hops, value.version.into()))
+    }
+}
+
+impl From<UpgradeFields> for RawUpgradeFields {
+    fn from(value: UpgradeFields) -> Self {
+        RawUpgradeFields {
+            ordering: value.ordering as i32,
+            connection_hops: value
+                .connection_hops
+                .iter()
+                .map(|conn_id| conn_id.to_string())
+                .collect(),
+            version: value.version.to_string(),
+        }
+    }
+}
+
+/// The height and/or timestamp on the counterparty chain after which
+/// a channel upgrade can be timed out.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct UpgradeTimeout {
+    pub height: TimeoutHeight,
+    pub timestamp: Timestamp,
+}
+
+impl UpgradeTimeout {
+    pub fn new(height: TimeoutHeight, timestamp: Timestamp) -> Result<Self, Error> {
+        if height == TimeoutHeight::Never && timestamp == Timestamp::none() {
+            return Err(Error::invalid_upgrade_timeout());
+        }
+
+        Ok(Self { height, timestamp })
+    }
+
+    /// Checks whether the upgrade has timed out, given the
+    /// current height and timestamp of the counterparty chain.
+    pub fn has_expired(&self, chain_height: Height, chain_timestamp: &Timestamp) -> bool {
+        let height_timed_out = self.height.has_expired(chain_height);
+
+        let timestamp_timed_out = self.timestamp != Timestamp::none()
+            && chain_timestamp.check_expiry(&self.timestamp) == Expiry::Expired;
+
+        height_timed_out || timestamp_timed_out
+    }
+}
+
+impl Display for UpgradeTimeout {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), FmtError> {
+        write!(
+            f,
+            "UpgradeTimeout {{ height: {}, timestamp: {} }}",
+            self.height, self.timestamp
+        )
+    }
+}
+
+impl Protobuf<RawTimeout> for UpgradeTimeout {}
+
+impl TryFrom<RawTimeout> for UpgradeTimeout {
+    type Error = Error;
+
+    fn try_from(value: RawTimeout) -> Result<Self, Self::Error> {
+        let height = value
+            .height
+            .try_into()
+            .map_err(|_| Error::invalid_timeout_height())?;
+
+        let timestamp = Timestamp::from_nanoseconds(value.timestamp)
+            .map_err(Error::invalid_packet_timestamp)?;
+
+        Self::new(height, timestamp)
+    }
+}
+
+impl From<UpgradeTimeout> for RawTimeout {
+    fn from(value: UpgradeTimeout) -> Self {
+        RawTimeout {
+            height: value.height.into(),
+            timestamp: value.timestamp.nanoseconds(),
+        }
+    }
+}
+
+/// A channel upgrade proposed by one of the channel ends.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Upgrade {
+    pub fields: UpgradeFields,
+    pub timeout: UpgradeTimeout,
+    pub next_sequence_send: Sequence,
+}
+
+impl Display for Upgrade {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), FmtError> {
+        write!(
+            f,
+            "Upgrade {{ fields: {}, timeout: {}, next_sequence_send: {} }}",
+            self.fields, self.timeout, self.next_sequence_send
+        )
+    }
+}
+
+impl Protobuf<RawUpgrade> for Upgrade {}
+
+impl TryFrom<RawUpgrade> for Upgrade {
+    type Error = Error;
+
+    fn try_from(value: RawUpgrade) -> Result<Self, Self::Error> {
+        let fields = value
+            .fields
+            .ok_or_else(Error::missing_upgrade_fields)?
+            .try_into()?;
+
+        let timeout = value
+            .timeout
+            .ok_or_else(Error::missing_upgrade_timeout)?
+            .try_into()?;
+
+        Ok(Self {
+            fields,
+            timeout,
+            next_sequence_send: value.next_sequence_send.into(),
+        })
+    }
+}
+
+impl From<Upgrade> for RawUpgrade {
+    fn from(value: Upgrade) -> Self {
+        RawUpgrade {
+            fields: Some(value.fields.into()),
+            timeout: Some(value.timeout.into()),
+            next_sequence_send: value.next_sequence_send.into(),
+        }
+    }
+}
+
+/// The receipt written by a chain when it aborts the upgrade with the given sequence.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ErrorReceipt {
+    pub sequence: u64,
+    pub message: String,
+}
+
+impl Display for ErrorReceipt {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), FmtError> {
+        write!(
+            f,
+            "ErrorReceipt {{ sequence: {}, message: {} }}",
+            self.sequence, self.message
+        )
+    }
+}
+
+impl Protobuf<RawErrorReceipt> for ErrorReceipt {}
+
+impl TryFrom<RawErrorReceipt> for ErrorReceipt {
+    type Error = Error;
+
+    fn try_from(value: RawErrorReceipt) -> Result<Self, Self::Error> {
+        Ok(Self {
+            sequence: value.sequence,
+            message: value.message,
+        })
+    }
+}
+
+impl From<ErrorReceipt> for RawErrorReceipt {
+    fn from(value: ErrorReceipt) -> Self {
+        RawErrorReceipt {
+            sequence: value.sequence,
+            message: value.message,
+        }
+    }
+}
+
+#[cfg(test)]
+pub mod test_util {
+    use crate::prelude::*;
+
+    use ibc_proto::ibc::core::client::v1::Height as RawHeight;
+
+    use super::raw::{
+        ErrorReceipt as RawErrorReceipt, Timeout as RawTimeout, Upgrade as RawUpgrade,
+        UpgradeFields as RawUpgradeFields,
+    };
+    use crate::core::ics24_host::identifier::ConnectionId;
+
+    /// Returns a dummy `RawUpgradeFields`, for testing only!
+    pub fn get_dummy_raw_upgrade_fields() -> RawUpgradeFields {
+        RawUpgradeFields {
+            ordering: 1,
+            connection_hops: vec![ConnectionId::default().to_string()],
+            version: "ics20".to_string(),
+        }
+    }
+
+    /// Returns a dummy `RawUpgrade`, for testing only!
+    pub fn get_dummy_raw_upgrade() -> RawUpgrade {
+        RawUpgrade {
+            fields: Some(get_dummy_raw_upgrade_fields()),
+            timeout: Some(RawTimeout {
+                height: Some(RawHeight {
+                    revision_number: 0,
+                    revision_height: 100,
+                }),
+                timestamp: 0,
+            }),
+            next_sequence_send: 1,
+        }
+    }
+
+    /// Returns a dummy `RawErrorReceipt`, for testing only!
+    pub fn get_dummy_raw_error_receipt() -> RawErrorReceipt {
+        RawErrorReceipt {
+            sequence: 1,
+            message: "upgrade handshake failed".to_string(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    use test_log::test;
+
+    use super::raw::{Timeout as RawTimeout, Upgrade as RawUpgrade};
+    use super::test_util::get_dummy_raw_upgrade;
+    use super::{Upgrade, UpgradeTimeout};
+    use crate::timestamp::Timestamp;
+    use crate::Height;
+
+    #[test]
+    fn upgrade_to_and_from() {
+        let raw = get_dummy_raw_upgrade();
+        let upgrade = Upgrade::try_from(raw.clone()).unwrap();
+        assert_eq!(raw, RawUpgrade::from(upgrade));
+    }
+
+    #[test]
+    fn upgrade_without_timeout() {
+        let raw = RawUpgrade {
+            timeout: Some(RawTimeout {
+                height: None,
+                timestamp: 0,
+            }),
+            ..get_dummy_raw_upgrade()
+        };
+
+        assert!(Upgrade::try_from(raw).is_err());
+    }
+
+    #[test]
+    fn upgrade_timeout_expiry() {
+        let timeout =
+            UpgradeTimeout::new(Height::new(0, 100).unwrap().into(), Timestamp::none()).unwrap();
+
+        let now = Timestamp::now();
+        assert!(!timeout.has_expired(Height::new(0, 100).unwrap(), &now));
+        assert!(timeout.has_expired(Height::new(0, 101).unwrap(), &now));
+    }
+}
@@ -0,0 +1,185 @@
+//! Protobuf definitions of the channel upgrade types and messages of `ibc.core.channel.v1`,
+//! as introduced in ibc-go v8.
+//!
+//! These are not yet part of the `ibc-proto` release used by the relayer, so they are
+//! defined here by hand, following the field numbering of the ibc-go proto files.
+
+use crate::prelude::*;
+
+use ibc_proto::ibc::core::channel::v1::Counterparty as RawCounterparty;
+use ibc_proto::ibc::core::client::v1::Height as RawHeight;
+
+/// `Channel` as defined in ibc-go v8, which adds the `upgrade_sequence` field.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Channel {
+    #[prost(int32, tag = "1")]
+    pub state: i32,
+    #[prost(int32, tag = "2")]
+    pub ordering: i32,
+    #[prost(message, optional, tag = "3")]
+    pub counterparty: Option<RawCounterparty>,
+    #[prost(string, repeated, tag = "4")]
+    pub connection_hops: Vec<String>,
+    #[prost(string, tag = "5")]
+    pub version: String,
+    #[prost(uint64, tag = "7")]
+    pub upgrade_sequence: u64,
+}
+
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Upgrade {
+    #[prost(message, optional, tag = "1")]
+    pub fields: Option<UpgradeFields>,
+    #[prost(message, optional, tag = "2")]
+    pub timeout: Option<Timeout>,
+    #[prost(uint64, tag = "3")]
+    pub next_sequence_send: u64,
+}
+
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct UpgradeFields {
+    #[prost(int32, tag = "1")]
+    pub ordering: i32,
+    #[prost(string, repeated, tag = "2")]
+    pub connection_hops: Vec<String>,
+    #[prost(string, tag = "3")]
+    pub version: String,
+}
+
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Timeout {
+    #[prost(message, optional, tag = "1")]
+    pub height: Option<RawHeight>,
+    #[prost(uint64, tag = "2")]
+    pub timestamp: u64,
+}
+
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ErrorReceipt {
+    #[prost(uint64, tag = "1")]
+    pub sequence: u64,
+    #[prost(string, tag = "2")]
+    pub message: String,
+}
+
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct MsgChannelUpgradeInit {
+    #[prost(string, tag = "1")]
+    pub port_id: String,
+    #[prost(string, tag = "2")]
+    pub channel_id: String,
+    #[prost(message, optional, tag = "3")]
+    pub fields: Option<UpgradeFields>,
+    #[prost(string, tag = "4")]
+    pub signer: String,
+}
+
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct MsgChannelUpgradeTry {
+    #[prost(string, tag = "1")]
+    pub port_id: String,
+    #[prost(string, tag = "2")]
+    pub channel_id: String,
+    #[prost(string, repeated, tag = "3")]
+    pub proposed_upgrade_connection_hops: Vec<String>,
+    #[prost(message, optional, tag = "4")]
+    pub counterparty_upgrade_fields: Option<UpgradeFields>,
+    #[prost(uint64, tag = "5")]
+    pub counterparty_upgrade_sequence: u64,
+    #[prost(bytes = "vec", tag = "6")]
+    pub proof_channel: Vec<u8>,
+    #[prost(bytes = "vec", tag = "7")]
+    pub proof_upgrade: Vec<u8>,
+    #[prost(message, optional, tag = "8")]
+    pub proof_height: Option<RawHeight>,
+    #[prost(string, tag = "9")]
+    pub signer: String,
+}
+
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct MsgChannelUpgradeAck {
+    #[prost(string, tag = "1")]
+    pub port_id: String,
+    #[prost(string, tag = "2")]
+    pub channel_id: String,
+    #[prost(message, optional, tag = "3")]
+    pub counterparty_upgrade: Option<Upgrade>,
+    #[prost(bytes = "vec", tag = "4")]
+    pub proof_channel: Vec<u8>,
+    #[prost(bytes = "vec", tag = "5")]
+    pub proof_upgrade: Vec<u8>,
+    #[prost(message, optional, tag = "6")]
+    pub proof_height: Option<RawHeight>,
+    #[prost(string, tag = "7")]
+    pub signer: String,
+}
+
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct MsgChannelUpgradeConfirm {
+    #[prost(string, tag = "1")]
+    pub port_id: String,
+    #[prost(string, tag = "2")]
+    pub channel_id: String,
+    #[prost(int32, tag = "3")]
+    pub counterparty_channel_state: i32,
+    #[prost(message, optional, tag = "4")]
+    pub counterparty_upgrade: Option<Upgrade>,
+    #[prost(bytes = "vec", tag = "5")]
+    pub proof_channel: Vec<u8>,
+    #[prost(bytes = "vec", tag = "6")]
+    pub proof_upgrade: Vec<u8>,
+    #[prost(message, optional, tag = "7")]
+    pub proof_height: Option<RawHeight>,
+    #[prost(string, tag = "8")]
+    pub signer: String,
+}
+
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct MsgChannelUpgradeOpen {
+    #[prost(string, tag = "1")]
+    pub port_id: String,
+    #[prost(string, tag = "2")]
+    pub channel_id: String,
+    #[prost(int32, tag = "3")]
+    pub counterparty_channel_state: i32,
+    #[prost(uint64, tag = "4")]
+    pub counterparty_upgrade_sequence: u64,
+    #[prost(bytes = "vec", tag = "5")]
+    pub proof_channel: Vec<u8>,
+    #[prost(message, optional, tag = "6")]
+    pub proof_height: Option<RawHeight>,
+    #[prost(string, tag = "7")]
+    pub signer: String,
+}
+
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct MsgChannelUpgradeTimeout {
+    #[prost(string, tag = "1")]
+    pub port_id: String,
+    #[prost(string, tag = "2")]
+    pub channel_id: String,
+    #[prost(message, optional, tag = "3")]
+    pub counterparty_channel: Option<Channel>,
+    #[prost(bytes = "vec", tag = "4")]
+    pub proof_channel: Vec<u8>,
+    #[prost(message, optional, tag = "5")]
+    pub proof_height: Option<RawHeight>,
+    #[prost(string, tag = "6")]
+    pub signer: String,
+}
+
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct MsgChannelUpgradeCancel {
+    #[prost(string, tag = "1")]
+    pub port_id: String,
+    #[prost(string, tag = "2")]
+    pub channel_id: String,
+    #[prost(message, optional, tag = "3")]
+    pub error_receipt: Option<ErrorReceipt>,
+    #[prost(bytes = "vec", tag = "4")]
+    pub proof_error_receipt: Vec<u8>,
+    #[prost(message, optional, tag = "5")]
+    pub proof_height: Option<RawHeight>,
+    #[prost(string, tag = "6")]
+    pub signer: String,
+}
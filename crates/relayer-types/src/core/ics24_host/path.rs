@@ -37,6 +37,7 @@ pub enum Path {
     Connections(ConnectionsPath),
     Ports(PortsPath),
     ChannelEnds(ChannelEndsPath),
+    ChannelUpgrades(ChannelUpgradePath),
     SeqSends(SeqSendsPath),
     SeqRecvs(SeqRecvsPath),
     SeqAcks(SeqAcksPath),
@@ -78,6 +79,15 @@ pub struct PortsPath(pub PortId);
 #[display(fmt = "channelEnds/ports/{_0}/channels/{_1}")]
 pub struct ChannelEndsPath(pub PortId, pub ChannelId);
 
+/// Paths that are specific for channel upgrades.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Display)]
+pub enum ChannelUpgradePath {
+    #[display(fmt = "channelUpgrades/upgrades/ports/{_0}/channels/{_1}")]
+    Upgrade(PortId, ChannelId),
+    #[display(fmt = "channelUpgrades/upgradeError/ports/{_0}/channels/{_1}")]
+    UpgradeError(PortId, ChannelId),
+}
+
 #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Display)]
 #[display(fmt = "nextSequenceSend/ports/{_0}/channels/{_1}")]
 pub struct SeqSendsPath(pub PortId, pub ChannelId);
@@ -163,6 +173,7 @@ impl FromStr for Path {
             .or_else(|| parse_connections(&components))
             .or_else(|| parse_ports(&components))
             .or_else(|| parse_channel_ends(&components))
+            .or_else(|| parse_channel_upgrades(&components))
             .or_else(|| parse_seqs(&components))
             .or_else(|| parse_commitments(&components))
             .or_else(|| parse_acks(&components))
@@ -374,6 +385,37 @@ fn parse_channel_ends(components: &[&str]) -> Option<Path> {
     Some(ChannelEndsPath(port_id, channel_id).into())
 }
 
+fn parse_channel_upgrades(components: &[&str]) -> Option<Path> {
+    if components.len() != 6 {
+        return None;
+    }
+
+    if components[0] != "channelUpgrades" {
+        return None;
+    }
+
+    let port = parse_ports(&components[2..=3]);
+    let channel = parse_channels(&components[4..=5]);
+
+    let port_id = if let Some(Path::Ports(PortsPath(port_id))) = port {
+        port_id
+    } else {
+        return None;
+    };
+
+    let channel_id = if let Some(SubPath::Channels(channel_id)) = channel {
+        channel_id
+    } else {
+        return None;
+    };
+
+    match components[1] {
+        "upgrades" => Some(ChannelUpgradePath::Upgrade(port_id, channel_id).into()),
+        "upgradeError" => Some(ChannelUpgradePath::UpgradeError(port_id, channel_id).into()),
+        _ => None,
+    }
+}
+
 fn parse_seqs(components: &[&str]) -> Option<Path> {
     if components.len() != 5 {
         return None;
@@ -989,4 +1031,25 @@ mod tests {
             Path::Upgrade(ClientUpgradePath::UpgradedClientConsensusState(0)),
         );
     }
+
+    #[test]
+    fn channel_upgrade_paths_parse() {
+        let path = "channelUpgrades/upgrades/ports/defaultPort/channels/channel-0";
+        assert_eq!(
+            Path::from_str(path).unwrap(),
+            Path::ChannelUpgrades(ChannelUpgradePath::Upgrade(
+                PortId::default(),
+                ChannelId::default()
+            )),
+        );
+
+        let path = "channelUpgrades/upgradeError/ports/defaultPort/channels/channel-0";
+        assert_eq!(
+            Path::from_str(path).unwrap(),
+            Path::ChannelUpgrades(ChannelUpgradePath::UpgradeError(
+                PortId::default(),
+                ChannelId::default()
+            )),
+        );
+    }
 }
@@ -21,6 +21,10 @@ use crate::Height;
 
 pub const MOCK_CLIENT_STATE_TYPE_URL: &str = "/ibc.mock.ClientState";
 
+/// The name of the chains tracked by mock clients, which only know these chains by
+/// the revision number of their height, ie. as the chain `mock-<revision number>`.
+pub const MOCK_CHAIN_NAME: &str = "mock";
+
 /// A mock of an IBC client record as it is stored in a mock context.
 /// For testing ICS02 handlers mostly, cf. `MockClientContext`.
 #[derive(Clone, Debug)]
@@ -117,7 +121,10 @@ impl From<MockClientState> for Any {
 
 impl ClientState for MockClientState {
     fn chain_id(&self) -> ChainId {
-        unimplemented!()
+        ChainId::new(
+            MOCK_CHAIN_NAME.to_string(),
+            self.latest_height().revision_number(),
+        )
     }
 
     fn client_type(&self) -> ClientType {
@@ -0,0 +1,287 @@
+//! Alerts about the expiry of the clients hosted on the scanned chains.
+//!
+//! Alerts are always logged, and additionally POSTed as JSON
+//! to the webhook configured in the `[alerting]` section, if any.
+
+use core::time::Duration;
+
+use flex_error::{define_error, TraceError};
+use serde::Serialize;
+use tendermint_rpc::Url;
+use tracing::{error, warn};
+
+use ibc_relayer_types::core::ics24_host::identifier::{ChainId, ClientId};
+
+use crate::foreign_client::ClientExpiryStatus;
+
+const WEBHOOK_TIMEOUT: Duration = Duration::from_secs(10);
+
+define_error! {
+    AlertError {
+        Webhook
+            { url: String }
+            [ TraceError<reqwest::Error> ]
+            |e| { format_args!("failed to send alert to webhook {}", e.url) },
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AlertKind {
+    /// The time left in the trusting period of the client dropped below a threshold.
+    ClientExpiring,
+    /// The client is expired.
+    ClientExpired,
+    /// The client is frozen.
+    ClientFrozen,
+}
+
+/// An alert about a client hosted on `dst_chain`, which tracks `src_chain`.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct Alert {
+    pub kind: AlertKind,
+    pub client: ClientId,
+    pub src_chain: ChainId,
+    pub dst_chain: ChainId,
+    /// Time left in the trusting period of the client, in seconds.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time_left_secs: Option<u64>,
+    /// The threshold which was crossed, in seconds.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub threshold_secs: Option<u64>,
+    pub message: String,
+}
+
+/// How close a client is to expiry, in increasing order of severity.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+enum ExpiryLevel {
+    /// Index of the smallest threshold which the time left is below of.
+    BelowThreshold(usize),
+    Expired,
+    Frozen,
+}
+
+/// Keeps track of the alerts already raised for a client, in order to
+/// raise a single alert every time the client gets closer to expiry.
+///
+/// Once the time left goes back up (eg. after the client was refreshed),
+/// the thresholds which are not crossed anymore are re-armed.
+#[derive(Clone, Debug)]
+pub struct ExpiryAlerts {
+    client: ClientId,
+    src_chain: ChainId,
+    dst_chain: ChainId,
+    /// The thresholds, in decreasing order.
+    thresholds: Vec<Duration>,
+    level: Option<ExpiryLevel>,
+}
+
+impl ExpiryAlerts {
+    pub fn new(
+        client: ClientId,
+        src_chain: ChainId,
+        dst_chain: ChainId,
+        mut thresholds: Vec<Duration>,
+    ) -> Self {
+        thresholds.sort_unstable_by(|a, b| b.cmp(a));
+        thresholds.dedup();
+
+        Self {
+            client,
+            src_chain,
+            dst_chain,
+            thresholds,
+            level: None,
+        }
+    }
+
+    /// Records the latest status of the client, returning an alert
+    /// if the client got closer to expiry since the last update.
+    pub fn update(&mut self, status: ClientExpiryStatus) -> Option<Alert> {
+        let level = match status {
+            ClientExpiryStatus::Frozen => Some(ExpiryLevel::Frozen),
+            ClientExpiryStatus::Expired { .. } => Some(ExpiryLevel::Expired),
+            ClientExpiryStatus::Active { time_left, .. } => time_left.and_then(|time_left| {
+                self.thresholds
+                    .iter()
+                    .rposition(|threshold| time_left <= *threshold)
+                    .map(ExpiryLevel::BelowThreshold)
+            }),
+        };
+
+        let previous = core::mem::replace(&mut self.level, level);
+
+        if level <= previous {
+            return None;
+        }
+
+        let time_left = status.time_left();
+
+        let (kind, threshold, message) = match level? {
+            ExpiryLevel::Frozen => (
+                AlertKind::ClientFrozen,
+                None,
+                "client is frozen".to_string(),
+            ),
+            ExpiryLevel::Expired => (
+                AlertKind::ClientExpired,
+                None,
+                "client is expired".to_string(),
+            ),
+            ExpiryLevel::BelowThreshold(index) => {
+                let threshold = self.thresholds[index];
+
+                (
+                    AlertKind::ClientExpiring,
+                    Some(threshold),
+                    format!(
+                        "client expires in {}, which is less than {}",
+                        humantime::format_duration(time_left.unwrap_or_default()),
+                        humantime::format_duration(threshold)
+                    ),
+                )
+            }
+        };
+
+        Some(Alert {
+            kind,
+            client: self.client.clone(),
+            src_chain: self.src_chain.clone(),
+            dst_chain: self.dst_chain.clone(),
+            time_left_secs: time_left.map(|time_left| time_left.as_secs()),
+            threshold_secs: threshold.map(|threshold| threshold.as_secs()),
+            message,
+        })
+    }
+}
+
+/// Logs alerts and sends them to the configured webhook, if any.
+#[derive(Clone, Debug)]
+pub struct AlertNotifier {
+    webhook_url: Option<Url>,
+}
+
+impl AlertNotifier {
+    pub fn new(webhook_url: Option<Url>) -> Self {
+        Self { webhook_url }
+    }
+
+    pub fn notify(&self, alert: &Alert) {
+        match alert.kind {
+            AlertKind::ClientExpiring => warn!(
+                client = %alert.client,
+                src_chain = %alert.src_chain,
+                dst_chain = %alert.dst_chain,
+                "{}",
+                alert.message
+            ),
+            AlertKind::ClientExpired | AlertKind::ClientFrozen => error!(
+                client = %alert.client,
+                src_chain = %alert.src_chain,
+                dst_chain = %alert.dst_chain,
+                "{}",
+                alert.message
+            ),
+        }
+
+        if let Some(webhook_url) = &self.webhook_url {
+            if let Err(e) = send_to_webhook(webhook_url, alert) {
+                error!("{e}");
+            }
+        }
+    }
+}
+
+fn send_to_webhook(webhook_url: &Url, alert: &Alert) -> Result<(), AlertError> {
+    let url = webhook_url.to_string();
+
+    // Alerts are rare, so there is no point in keeping a client around
+    reqwest::blocking::Client::builder()
+        .timeout(WEBHOOK_TIMEOUT)
+        .build()
+        .and_then(|client| client.post(&url).json(alert).send())
+        .and_then(|response| response.error_for_status())
+        .map_err(|e| AlertError::webhook(url, e))?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use test_log::test;
+
+    const HOUR: Duration = Duration::from_secs(60 * 60);
+
+    fn active(time_left: Duration) -> ClientExpiryStatus {
+        ClientExpiryStatus::Active {
+            elapsed: 14 * 24 * HOUR - time_left,
+            time_left: Some(time_left),
+        }
+    }
+
+    fn alerts() -> ExpiryAlerts {
+        ExpiryAlerts::new(
+            ClientId::default(),
+            ChainId::from_string("chain-a"),
+            ChainId::from_string("chain-b"),
+            vec![6 * HOUR, 72 * HOUR, 24 * HOUR],
+        )
+    }
+
+    #[test]
+    fn alerts_once_per_threshold() {
+        let mut alerts = alerts();
+
+        assert_eq!(alerts.update(active(100 * HOUR)), None);
+
+        let alert = alerts.update(active(48 * HOUR)).unwrap();
+        assert_eq!(alert.kind, AlertKind::ClientExpiring);
+        assert_eq!(alert.threshold_secs, Some(72 * 60 * 60));
+        assert_eq!(alert.time_left_secs, Some(48 * 60 * 60));
+        assert_eq!(alerts.update(active(47 * HOUR)), None);
+
+        // Crossing several thresholds at once only alerts about the smallest one
+        let alert = alerts.update(active(5 * HOUR)).unwrap();
+        assert_eq!(alert.threshold_secs, Some(6 * 60 * 60));
+        assert_eq!(alerts.update(active(4 * HOUR)), None);
+
+        let alert = alerts
+            .update(ClientExpiryStatus::Expired {
+                elapsed: 15 * 24 * HOUR,
+            })
+            .unwrap();
+        assert_eq!(alert.kind, AlertKind::ClientExpired);
+        assert_eq!(alert.time_left_secs, Some(0));
+
+        let alert = alerts.update(ClientExpiryStatus::Frozen).unwrap();
+        assert_eq!(alert.kind, AlertKind::ClientFrozen);
+        assert_eq!(alerts.update(ClientExpiryStatus::Frozen), None);
+    }
+
+    #[test]
+    fn rearms_after_refresh() {
+        let mut alerts = alerts();
+
+        assert!(alerts.update(active(20 * HOUR)).is_some());
+
+        // The client was refreshed
+        assert_eq!(alerts.update(active(200 * HOUR)), None);
+
+        let alert = alerts.update(active(20 * HOUR)).unwrap();
+        assert_eq!(alert.threshold_secs, Some(24 * 60 * 60));
+    }
+
+    #[test]
+    fn no_alerts_without_trusting_period() {
+        let mut alerts = alerts();
+
+        let status = ClientExpiryStatus::Active {
+            elapsed: 1000 * HOUR,
+            time_left: None,
+        };
+
+        assert_eq!(alerts.update(status), None);
+    }
+}
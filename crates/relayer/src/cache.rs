@@ -14,15 +14,18 @@ use ibc_relayer_types::core::ics04_channel::channel::ChannelEnd;
 use ibc_relayer_types::core::ics24_host::identifier::{ClientId, ConnectionId, PortChannelId};
 
 use crate::client_state::AnyClientState;
+use crate::consensus_state::AnyConsensusState;
 
 const CHANNEL_CACHE_TTL: Duration = Duration::from_secs(60);
 const CONNECTION_CACHE_TTL: Duration = Duration::from_secs(10 * 60);
 const CLIENT_STATE_CACHE_TTL: Duration = Duration::from_millis(500);
 const LATEST_HEIGHT_CACHE_TTL: Duration = Duration::from_millis(200);
+const HOST_CONSENSUS_STATE_CACHE_TTL: Duration = Duration::from_secs(10 * 60);
 
 const CHANNEL_CACHE_CAPACITY: u64 = 10_000;
 const CONNECTION_CACHE_CAPACITY: u64 = 10_000;
 const CLIENT_STATE_CACHE_CAPACITY: u64 = 10_000;
+const HOST_CONSENSUS_STATE_CACHE_CAPACITY: u64 = 1_000;
 
 /// Whether or not a result was in cache (ie. a cache hit)
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
@@ -48,6 +51,8 @@ pub struct Cache {
     client_states: MokaCache<ClientId, AnyClientState>,
     /// The latest `Height` associated with the chain runtime this `Cache` is associated with.
     latest_height: MokaCache<(), Height>,
+    /// Cache storing the [`AnyConsensusState`]s of the chain itself keyed by their [`Height`]s.
+    host_consensus_states: MokaCache<Height, AnyConsensusState>,
 }
 
 impl Default for Cache {
@@ -79,11 +84,17 @@ impl Cache {
             .max_capacity(1)
             .build();
 
+        let host_consensus_states = MokaCache::builder()
+            .time_to_live(HOST_CONSENSUS_STATE_CACHE_TTL)
+            .max_capacity(HOST_CONSENSUS_STATE_CACHE_CAPACITY)
+            .build();
+
         Cache {
             channels,
             connections,
             client_states,
             latest_height,
+            host_consensus_states,
         }
     }
 
@@ -175,6 +186,27 @@ impl Cache {
             Ok((height, CacheStatus::Miss))
         }
     }
+
+    /// Return the cached [`AnyConsensusState`] of the chain at the given [`Height`] if it
+    /// exists in the cache. Otherwise, attempts to fetch it via the supplied fetcher
+    /// function `F`. If `F` returns successfully with the consensus state, a copy of it
+    /// is stored in the cache before it is returned.
+    pub fn get_or_try_insert_host_consensus_state_with<F, E>(
+        &self,
+        height: &Height,
+        f: F,
+    ) -> CacheResult<AnyConsensusState, E>
+    where
+        F: FnOnce() -> Result<AnyConsensusState, E>,
+    {
+        if let Some(state) = self.host_consensus_states.get(height) {
+            Ok((state, CacheStatus::Hit))
+        } else {
+            let state = f()?;
+            self.host_consensus_states.insert(*height, state.clone());
+            Ok((state, CacheStatus::Miss))
+        }
+    }
 }
 
 impl fmt::Debug for Cache {
@@ -3,8 +3,10 @@ pub mod cosmos;
 pub mod counterparty;
 pub mod endpoint;
 pub mod handle;
+pub mod mock;
 pub mod requests;
 pub mod runtime;
+pub mod solomachine;
 pub mod tracking;
 
 use serde::{de::Error, Deserialize, Serialize};
@@ -18,6 +20,12 @@ use serde::{de::Error, Deserialize, Serialize};
 pub enum ChainType {
     /// Chains based on the Cosmos SDK
     CosmosSdk,
+
+    /// In-memory mock chains, for testing
+    Mock,
+
+    /// Solo machines, whose key lives in the relayer keyring
+    SoloMachine,
 }
 
 impl<'de> Deserialize<'de> for ChainType {
@@ -30,9 +38,14 @@ impl<'de> Deserialize<'de> for ChainType {
 
         match s.as_str() {
             "cosmossdk" => Ok(Self::CosmosSdk),
+            "mock" => Ok(Self::Mock),
+            "solomachine" => Ok(Self::SoloMachine),
 
             // NOTE(new): Add a case here
-            _ => Err(D::Error::unknown_variant(&original, &["cosmos-sdk"])), // NOTE(new): mention the new variant here
+            _ => Err(D::Error::unknown_variant(
+                &original,
+                &["cosmos-sdk", "mock", "solo-machine"],
+            )), // NOTE(new): mention the new variant here
         }
     }
 }
@@ -58,6 +71,13 @@ mod tests {
         assert!(matches!(parse("cosmossdk"), Ok(CosmosSdk)));
         assert!(matches!(parse("cosmos-sdk"), Ok(CosmosSdk)));
 
+        assert!(matches!(parse("Mock"), Ok(Mock)));
+        assert!(matches!(parse("mock"), Ok(Mock)));
+
+        assert!(matches!(parse("SoloMachine"), Ok(SoloMachine)));
+        assert!(matches!(parse("solomachine"), Ok(SoloMachine)));
+        assert!(matches!(parse("solo-machine"), Ok(SoloMachine)));
+
         // NOTE(new): Add tests here
 
         assert!(matches!(parse("hello-world"), Err(_)));
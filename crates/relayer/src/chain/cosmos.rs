@@ -8,15 +8,21 @@ use core::{
 };
 use futures::future::join_all;
 use num_bigint::BigInt;
-use std::{cmp::Ordering, thread};
+use std::{
+    cmp::Ordering,
+    sync::{Mutex, RwLock},
+    thread,
+    time::Instant,
+};
 
 use tokio::runtime::Runtime as TokioRuntime;
 use tonic::codegen::http::Uri;
 use tonic::metadata::AsciiMetadataValue;
-use tracing::{error, instrument, trace, warn};
+use tracing::{debug, error, instrument, trace, warn};
 
 use ibc_proto::cosmos::{
     base::node::v1beta1::ConfigResponse, staking::v1beta1::Params as StakingParams,
+    tx::v1beta1::Fee,
 };
 
 use ibc_proto::interchain_security::ccv::consumer::v1::Params as CcvConsumerParams;
@@ -39,14 +45,15 @@ use ibc_relayer_types::core::ics03_connection::connection::{
 };
 use ibc_relayer_types::core::ics04_channel::channel::{ChannelEnd, IdentifiedChannelEnd};
 use ibc_relayer_types::core::ics04_channel::packet::Sequence;
+use ibc_relayer_types::core::ics04_channel::upgrade::{ErrorReceipt, Upgrade};
 use ibc_relayer_types::core::ics23_commitment::commitment::CommitmentPrefix;
 use ibc_relayer_types::core::ics23_commitment::merkle::MerkleProof;
 use ibc_relayer_types::core::ics24_host::identifier::{
     ChainId, ChannelId, ClientId, ConnectionId, PortId,
 };
 use ibc_relayer_types::core::ics24_host::path::{
-    AcksPath, ChannelEndsPath, ClientConsensusStatePath, ClientStatePath, CommitmentsPath,
-    ConnectionsPath, ReceiptsPath, SeqRecvsPath,
+    AcksPath, ChannelEndsPath, ChannelUpgradePath, ClientConsensusStatePath, ClientStatePath,
+    CommitmentsPath, ConnectionsPath, ReceiptsPath, SeqRecvsPath,
 };
 use ibc_relayer_types::core::ics24_host::{
     ClientUpgradePath, Path, IBC_QUERY_PATH, SDK_UPGRADE_QUERY_PATH,
@@ -57,11 +64,12 @@ use ibc_relayer_types::Height as ICSHeight;
 use tendermint::block::Height as TmHeight;
 use tendermint::node::{self, info::TxIndexStatus};
 use tendermint::time::Time as TmTime;
+use tendermint::Hash as TxHash;
 use tendermint_light_client::verifier::types::LightBlock as TmLightBlock;
 use tendermint_rpc::client::CompatMode;
 use tendermint_rpc::endpoint::broadcast::tx_sync::Response;
 use tendermint_rpc::endpoint::status;
-use tendermint_rpc::{Client, HttpClient, Order};
+use tendermint_rpc::{Client, HttpClient, Order, WebSocketClientUrl};
 
 use crate::account::Balance;
 use crate::chain::client::ClientSettings;
@@ -70,6 +78,8 @@ use crate::chain::cosmos::batch::{
     sequential_send_batched_messages_and_wait_commit,
 };
 use crate::chain::cosmos::encode::key_pair_to_signer;
+use crate::chain::cosmos::endpoints::{monitor_endpoints_health, EndpointPool};
+use crate::chain::cosmos::estimate::estimate_tx_fees;
 use crate::chain::cosmos::fee::maybe_register_counterparty_payee;
 use crate::chain::cosmos::gas::{calculate_fee, mul_ceil};
 use crate::chain::cosmos::query::account::get_or_fetch_account;
@@ -80,7 +90,8 @@ use crate::chain::cosmos::query::denom_trace::query_denom_trace;
 use crate::chain::cosmos::query::fee::query_incentivized_packet;
 use crate::chain::cosmos::query::status::query_status;
 use crate::chain::cosmos::query::tx::{
-    filter_matching_event, query_packets_from_block, query_packets_from_txs, query_txs,
+    all_ibc_events_from_tx_search_response, filter_matching_event, query_packets_from_block,
+    query_packets_from_txs, query_tx_response, query_txs,
 };
 use crate::chain::cosmos::query::{abci_query, fetch_version_specs, packet_query, QueryResponse};
 use crate::chain::cosmos::types::account::Account;
@@ -88,21 +99,27 @@ use crate::chain::cosmos::types::config::TxConfig;
 use crate::chain::cosmos::types::gas::{
     default_gas_from_config, gas_multiplier_from_config, max_gas_from_config,
 };
+use crate::chain::cosmos::wallet_pool::WalletPool;
 use crate::chain::endpoint::{ChainEndpoint, ChainStatus, HealthCheck};
 use crate::chain::handle::Subscription;
 use crate::chain::requests::*;
 use crate::chain::tracking::TrackedMsgs;
 use crate::client_state::{AnyClientState, IdentifiedAnyClientState};
-use crate::config::{parse_gas_prices, ChainConfig, GasPrice};
+use crate::config::{parse_gas_prices, ChainConfig, EventSourceMode, GasPrice, NodeEndpoints};
 use crate::consensus_state::AnyConsensusState;
 use crate::denom::DenomTrace;
+use crate::economics::{self, SubmittedTxs};
 use crate::error::Error;
-use crate::event::monitor::{EventMonitor, TxMonitorCmd};
+use crate::event::monitor::{
+    merged::MergedEventMonitor, pull::PullEventMonitor, Error as EventMonitorError, EventMonitor,
+    TxMonitorCmd,
+};
 use crate::event::IbcEventWithHeight;
 use crate::keyring::{KeyRing, Secp256k1KeyPair, SigningKeyPair};
 use crate::light_client::tendermint::LightClient as TmLightClient;
 use crate::light_client::{LightClient, Verified};
 use crate::misbehaviour::MisbehaviourEvidence;
+use crate::util::lock::LockExt;
 use crate::util::pretty::{
     PrettyIdentifiedChannel, PrettyIdentifiedClientState, PrettyIdentifiedConnection,
 };
@@ -111,6 +128,7 @@ pub mod batch;
 pub mod client;
 pub mod compatibility;
 pub mod encode;
+pub mod endpoints;
 pub mod estimate;
 pub mod fee;
 pub mod gas;
@@ -118,9 +136,11 @@ pub mod query;
 pub mod retry;
 pub mod simulate;
 pub mod tx;
+pub mod tx_json;
 pub mod types;
 pub mod version;
 pub mod wait;
+pub mod wallet_pool;
 
 /// Defines an upper limit on how large any transaction can be.
 /// This upper limit is defined as a fraction relative to the block's
@@ -143,6 +163,12 @@ pub struct CosmosSdkChain {
     rpc_client: HttpClient,
     compat_mode: CompatMode,
     grpc_addr: Uri,
+
+    /// The endpoints of the nodes of the chain, the active one being mirrored in
+    /// `config`, `rpc_client` and `grpc_addr`, and their health as last checked
+    /// by the task spawned by [`monitor_endpoints_health`]
+    endpoints: Arc<RwLock<EndpointPool>>,
+
     light_client: TmLightClient,
     rt: Arc<TokioRuntime>,
     keybase: KeyRing<Secp256k1KeyPair>,
@@ -150,6 +176,14 @@ pub struct CosmosSdkChain {
     /// A cached copy of the account information
     account: Option<Account>,
 
+    /// The wallets used to submit transactions when several keys are configured,
+    /// each with its key and a cached copy of its account information
+    wallets: Option<WalletPool>,
+
+    /// The transactions submitted without waiting for their commit, whose fees are
+    /// accounted for once they are found to be committed, see [`economics`]
+    submitted_txs: Mutex<SubmittedTxs>,
+
     tx_monitor_cmd: Option<TxMonitorCmd>,
 }
 
@@ -170,6 +204,38 @@ impl CosmosSdkChain {
             .map_err(Error::key_base)
     }
 
+    /// Find the wallet which must submit messages signed by the given signer.
+    ///
+    /// Returns the index of the wallet in the pool, when several keys are
+    /// configured, and its key.
+    fn signing_wallet(
+        &self,
+        signer: Option<&Signer>,
+    ) -> Result<(Option<usize>, Secp256k1KeyPair), Error> {
+        match &self.wallets {
+            Some(wallets) => {
+                let wallet = wallets.signing_wallet(signer);
+
+                debug!(
+                    key_name = wallets.key_name(wallet),
+                    account = %wallets.address(wallet),
+                    "submitting messages with wallet"
+                );
+
+                Ok((Some(wallet), wallets.key_pair(wallet).clone()))
+            }
+            None => Ok((None, self.key()?)),
+        }
+    }
+
+    /// Record until when the transaction submitted by the given wallet is expected
+    /// to be in flight, or that the wallet is idle again if `None`.
+    fn set_in_flight(&mut self, wallet: Option<usize>, until: Option<Instant>) {
+        if let (Some(wallets), Some(wallet)) = (&mut self.wallets, wallet) {
+            wallets.set_in_flight(wallet, until);
+        }
+    }
+
     /// Fetches the trusting period as a `Duration` from the chain config.
     /// If no trusting period exists in the config, the trusting period is calculated
     /// as two-thirds of the `unbonding_period`.
@@ -294,22 +360,217 @@ impl CosmosSdkChain {
             }
         );
 
+        let backup_endpoints = self.endpoints.acquire_read().backups();
+
+        let monitor_tx = match self.config.event_source {
+            EventSourceMode::Push { nodes } => {
+                let ws_urls = core::iter::once(self.config.websocket_addr.clone())
+                    .chain(
+                        backup_endpoints
+                            .iter()
+                            .map(|node| node.websocket_addr.clone()),
+                    )
+                    .collect::<Vec<_>>();
+
+                // Subscribe through the first `nodes` WebSocket endpoints which respond,
+                // starting with the one of the active node. The other endpoints serve
+                // as backups to fail over to.
+                let mut monitors = vec![];
+                let mut backup_urls = vec![];
+                let mut errors = vec![];
+
+                for ws_url in ws_urls {
+                    if monitors.len() >= nodes.max(1) {
+                        backup_urls.push(ws_url);
+                        continue;
+                    }
+
+                    match self.connect_event_monitor(ws_url.clone()) {
+                        Ok(monitor) => monitors.push(monitor),
+                        Err(e) => {
+                            warn!(
+                                "failed to subscribe to events of chain '{}' at {}: {}",
+                                self.config.id, ws_url, e
+                            );
+                            backup_urls.push(ws_url);
+                            errors.push(e);
+                        }
+                    }
+                }
+
+                if monitors.is_empty() {
+                    return Err(Error::event_monitor(errors.remove(0)));
+                }
+
+                let monitor_txs = monitors
+                    .into_iter()
+                    .map(|(mut event_monitor, monitor_tx)| {
+                        event_monitor.set_backup_urls(backup_urls.clone());
+                        thread::spawn(move || event_monitor.run());
+                        monitor_tx
+                    })
+                    .collect::<Vec<_>>();
+
+                if monitor_txs.len() == 1 {
+                    monitor_txs.into_iter().next().unwrap()
+                } else {
+                    let (event_monitor, monitor_tx) = MergedEventMonitor::new(
+                        self.config.id.clone(),
+                        monitor_txs,
+                        self.config.batch_delay,
+                    )
+                    .map_err(Error::event_monitor)?;
+
+                    thread::spawn(move || event_monitor.run());
+
+                    monitor_tx
+                }
+            }
+            EventSourceMode::Pull { interval } => {
+                let (mut event_monitor, monitor_tx) = PullEventMonitor::new(
+                    self.config.id.clone(),
+                    self.rpc_client.clone(),
+                    interval,
+                    self.rt.clone(),
+                )
+                .map_err(Error::event_monitor)?;
+
+                let backup_rpc_clients = backup_endpoints
+                    .iter()
+                    .map(|node| {
+                        let mut rpc_client = HttpClient::new(node.rpc_addr.clone())
+                            .map_err(|e| Error::rpc(node.rpc_addr.clone(), e))?;
+                        rpc_client.set_compat_mode(self.compat_mode);
+                        Ok(rpc_client)
+                    })
+                    .collect::<Result<_, Error>>()?;
+
+                event_monitor.set_backup_rpc_clients(backup_rpc_clients);
+
+                thread::spawn(move || event_monitor.run());
+
+                monitor_tx
+            }
+        };
+
+        Ok(monitor_tx)
+    }
+
+    /// Connect to the WebSocket endpoint at the given address, and subscribe to the events.
+    fn connect_event_monitor(
+        &self,
+        ws_url: WebSocketClientUrl,
+    ) -> Result<(EventMonitor, TxMonitorCmd), EventMonitorError> {
         let (mut event_monitor, monitor_tx) = EventMonitor::new(
             self.config.id.clone(),
-            self.config.websocket_addr.clone(),
+            ws_url,
             self.compat_mode,
             self.config.batch_delay,
             self.rt.clone(),
-        )
-        .map_err(Error::event_monitor)?;
+        )?;
+
+        event_monitor.init_subscriptions()?;
 
-        event_monitor
-            .init_subscriptions()
-            .map_err(Error::event_monitor)?;
+        Ok((event_monitor, monitor_tx))
+    }
 
-        thread::spawn(move || event_monitor.run());
+    /// Fail over to the healthy node with the best score if the active node
+    /// is unhealthy, as of the last health check of the nodes.
+    fn do_refresh_endpoints(&mut self) -> Result<(), Error> {
+        let failover = {
+            let endpoints = self.endpoints.acquire_read();
 
-        Ok(monitor_tx)
+            endpoints.failover().and_then(|index| {
+                endpoints
+                    .health(index)
+                    .map(|health| (index, health.node_id))
+            })
+        };
+
+        let Some((index, node_id)) = failover else {
+            return Ok(());
+        };
+
+        warn!(
+            "node at {} of chain '{}' is unhealthy",
+            self.config.rpc_addr, self.config.id
+        );
+
+        self.switch_endpoints(index, node_id)
+    }
+
+    /// Switch to the healthy node with the best score other than the active node,
+    /// after a request to the active node failed. Returns `false` if there is none.
+    fn do_fail_over(&mut self) -> Result<bool, Error> {
+        let next = {
+            let endpoints = self.endpoints.acquire_read();
+
+            endpoints.next_healthy().and_then(|index| {
+                endpoints
+                    .health(index)
+                    .map(|health| (index, health.node_id))
+            })
+        };
+
+        let Some((index, node_id)) = next else {
+            return Ok(false);
+        };
+
+        self.switch_endpoints(index, node_id)?;
+
+        Ok(true)
+    }
+
+    /// Use the node at the given index of the endpoint pool
+    /// for the queries, transactions and event subscriptions.
+    fn switch_endpoints(&mut self, index: usize, node_id: node::Id) -> Result<(), Error> {
+        let node = self.endpoints.acquire_read().endpoints()[index].clone();
+
+        let mut config = self.config.clone();
+        use_node_endpoints(&mut config, &node);
+
+        let mut rpc_client = HttpClient::new(node.rpc_addr.clone())
+            .map_err(|e| Error::rpc(node.rpc_addr.clone(), e))?;
+        rpc_client.set_compat_mode(self.compat_mode);
+
+        let grpc_addr = Uri::from_str(&node.grpc_addr.to_string())
+            .map_err(|e| Error::invalid_uri(node.grpc_addr.to_string(), e))?;
+
+        let light_client = TmLightClient::from_config(&config, node_id)?;
+
+        warn!(
+            "failing over from node at {} of chain '{}' to node at {}",
+            self.config.rpc_addr, self.config.id, node.rpc_addr
+        );
+
+        self.tx_config.rpc_address = node.rpc_addr.clone();
+        self.tx_config.grpc_address = grpc_addr.clone();
+        self.config = config;
+        self.rpc_client = rpc_client;
+        self.grpc_addr = grpc_addr;
+        self.light_client = light_client;
+        self.endpoints.acquire_write().set_active(index);
+
+        self.record_active_endpoint();
+
+        Ok(())
+    }
+
+    /// Record which of the nodes of the chain is in use.
+    fn record_active_endpoint(&self) {
+        crate::telemetry!({
+            let endpoints = self.endpoints.acquire_read();
+
+            for (index, node) in endpoints.endpoints().iter().enumerate() {
+                ibc_telemetry::global().active_endpoint(
+                    &self.config.id,
+                    &node.rpc_addr.to_string(),
+                    &node.websocket_addr.to_string(),
+                    &node.grpc_addr.to_string(),
+                    index == endpoints.active_index(),
+                );
+            }
+        });
     }
 
     /// Query the chain staking parameters
@@ -648,18 +909,25 @@ impl CosmosSdkChain {
             }
         );
 
+        let (wallet, key_pair) = self.signing_wallet(tracked_msgs.signer.as_ref())?;
         let proto_msgs = tracked_msgs.msgs;
-
-        let key_pair = self.key()?;
         let key_account = key_pair.account();
 
-        let account =
-            get_or_fetch_account(&self.grpc_addr, &key_account, &mut self.account).await?;
+        let account = get_or_fetch_account(
+            &self.grpc_addr,
+            &key_account,
+            wallet_account(&mut self.wallets, &mut self.account, wallet),
+        )
+        .await?;
+
+        let tx_config = self
+            .tx_config
+            .with_batch_limits(tracked_msgs.max_msg_num, tracked_msgs.max_tx_size);
 
-        if self.config.sequential_batch_tx {
+        let result = if self.config.sequential_batch_tx {
             sequential_send_batched_messages_and_wait_commit(
                 &self.rpc_client,
-                &self.tx_config,
+                &tx_config,
                 &key_pair,
                 account,
                 &self.config.memo_prefix,
@@ -669,14 +937,19 @@ impl CosmosSdkChain {
         } else {
             send_batched_messages_and_wait_commit(
                 &self.rpc_client,
-                &self.tx_config,
+                &tx_config,
                 &key_pair,
                 account,
                 &self.config.memo_prefix,
                 proto_msgs,
             )
             .await
-        }
+        };
+
+        // The transactions have been committed, or have failed
+        self.set_in_flight(wallet, None);
+
+        result
     }
 
     #[instrument(
@@ -699,21 +972,86 @@ impl CosmosSdkChain {
             }
         );
 
+        let (wallet, key_pair) = self.signing_wallet(tracked_msgs.signer.as_ref())?;
         let proto_msgs = tracked_msgs.msgs;
+        let key_account = key_pair.account();
 
-        let key_pair = self.key()?;
+        let account = get_or_fetch_account(
+            &self.grpc_addr,
+            &key_account,
+            wallet_account(&mut self.wallets, &mut self.account, wallet),
+        )
+        .await?;
+
+        let tx_config = self
+            .tx_config
+            .with_batch_limits(tracked_msgs.max_msg_num, tracked_msgs.max_tx_size);
+
+        let responses = send_batched_messages_and_wait_check_tx(
+            &self.rpc_client,
+            &tx_config,
+            &key_pair,
+            account,
+            &self.config.memo_prefix,
+            proto_msgs,
+        )
+        .await?;
+
+        // The transactions are not awaited, so they are expected
+        // to be in flight until they are included in a block
+        self.set_in_flight(wallet, Some(Instant::now() + self.config.max_block_time));
+
+        let mut submitted_txs = self.submitted_txs.lock().unwrap();
+        for response in responses.iter().filter(|response| response.code.is_ok()) {
+            submitted_txs.insert(response.hash);
+        }
+        drop(submitted_txs);
+
+        Ok(responses)
+    }
+
+    /// Query the IBC events of a transaction which was submitted without waiting for its
+    /// commit, accounting for its fees once it is committed.
+    async fn query_submitted_tx(&self, tx_hash: TxHash) -> Result<Vec<IbcEventWithHeight>, Error> {
+        let Some(response) =
+            query_tx_response(&self.rpc_client, &self.config.rpc_addr, &tx_hash).await?
+        else {
+            return Ok(vec![]);
+        };
+
+        self.submitted_txs.lock().unwrap().remove(&tx_hash);
+
+        economics::record_tx_response(self.id(), &response);
+
+        Ok(all_ibc_events_from_tx_search_response(self.id(), response))
+    }
+
+    async fn do_estimate_tx_fee(&mut self, tracked_msgs: TrackedMsgs) -> Result<Fee, Error> {
+        crate::time!(
+            "estimate_tx_fee",
+            {
+                "src_chain": self.config().id.to_string(),
+            }
+        );
+
+        let (wallet, key_pair) = self.signing_wallet(tracked_msgs.signer.as_ref())?;
+        let proto_msgs = tracked_msgs.msgs;
         let key_account = key_pair.account();
 
-        let account =
-            get_or_fetch_account(&self.grpc_addr, &key_account, &mut self.account).await?;
+        let account = get_or_fetch_account(
+            &self.grpc_addr,
+            &key_account,
+            wallet_account(&mut self.wallets, &mut self.account, wallet),
+        )
+        .await?;
 
-        send_batched_messages_and_wait_check_tx(
+        estimate_tx_fees(
             &self.rpc_client,
             &self.tx_config,
             &key_pair,
             account,
             &self.config.memo_prefix,
-            proto_msgs,
+            &proto_msgs,
         )
         .await
     }
@@ -838,15 +1176,44 @@ impl ChainEndpoint for CosmosSdkChain {
     type Time = TmTime;
     type SigningKeyPair = Secp256k1KeyPair;
 
-    fn bootstrap(config: ChainConfig, rt: Arc<TokioRuntime>) -> Result<Self, Error> {
-        let mut rpc_client = HttpClient::new(config.rpc_addr.clone())
-            .map_err(|e| Error::rpc(config.rpc_addr.clone(), e))?;
+    fn bootstrap(mut config: ChainConfig, rt: Arc<TokioRuntime>) -> Result<Self, Error> {
+        let mut endpoints = EndpointPool::new(config.node_endpoints());
+
+        // Connect to the first node which responds, starting with the primary node
+        let mut index = 0;
+        let (rpc_client, compat_mode, node_info) = loop {
+            use_node_endpoints(&mut config, &endpoints.endpoints()[index]);
+
+            match rt.block_on(connect_rpc_client(&config)) {
+                Ok(connected) => break connected,
+                Err(e) if index + 1 < endpoints.endpoints().len() => {
+                    warn!(
+                        "failed to connect to chain '{}' at {}, failing over to {}: {}",
+                        config.id,
+                        config.rpc_addr,
+                        endpoints.endpoints()[index + 1].rpc_addr,
+                        e
+                    );
+                    index += 1;
+                }
+                Err(e) => return Err(e),
+            }
+        };
+
+        endpoints.set_active(index);
 
-        let node_info = rt.block_on(fetch_node_info(&rpc_client, &config))?;
+        let endpoints = Arc::new(RwLock::new(endpoints));
 
-        let compat_mode = CompatMode::from_version(node_info.version)
-            .map_err(|e| Error::rpc(config.rpc_addr.clone(), e))?;
-        rpc_client.set_compat_mode(compat_mode);
+        // Check the health of the nodes in the background, for the chain to fail over to
+        // another node when the active one is unhealthy, see `refresh_endpoints`
+        if !config.backup_endpoints.is_empty() {
+            rt.spawn(monitor_endpoints_health(
+                Arc::downgrade(&endpoints),
+                compat_mode,
+                config.rpc_timeout,
+                config.endpoint_health_check_interval,
+            ));
+        }
 
         let light_client = TmLightClient::from_config(&config, node_info.id)?;
 
@@ -856,6 +1223,7 @@ impl ChainEndpoint for CosmosSdkChain {
             &config.account_prefix,
             &config.id,
             &config.key_store_folder,
+            &config.remote_signer,
         )
         .map_err(Error::key_base)?;
 
@@ -864,6 +1232,25 @@ impl ChainEndpoint for CosmosSdkChain {
 
         let tx_config = TxConfig::try_from(&config)?;
 
+        // Load the keys of the wallets once, if several keys are configured
+        let wallets = if config.key_names.is_empty() {
+            None
+        } else {
+            let keys = config
+                .wallet_key_names()
+                .into_iter()
+                .map(|key_name| {
+                    let key_pair = keybase
+                        .get_key(&key_name)
+                        .map_err(|e| Error::key_not_found(key_name.clone(), e))?;
+
+                    Ok((key_name, key_pair))
+                })
+                .collect::<Result<Vec<_>, Error>>()?;
+
+            Some(WalletPool::new(keys))
+        };
+
         // Retrieve the version specification of this chain
 
         let chain = Self {
@@ -871,14 +1258,19 @@ impl ChainEndpoint for CosmosSdkChain {
             rpc_client,
             compat_mode,
             grpc_addr,
+            endpoints,
             light_client,
             rt,
             keybase,
             tx_config,
             account: None,
+            wallets,
+            submitted_txs: Mutex::new(SubmittedTxs::default()),
             tx_monitor_cmd: None,
         };
 
+        chain.record_active_endpoint();
+
         Ok(chain)
     }
 
@@ -912,6 +1304,22 @@ impl ChainEndpoint for CosmosSdkChain {
         Ok(subscription)
     }
 
+    fn refresh_endpoints(&mut self) -> Result<(), Error> {
+        if !self.endpoints.acquire_read().has_backups() {
+            return Ok(());
+        }
+
+        self.do_refresh_endpoints()
+    }
+
+    fn fail_over(&mut self) -> Result<bool, Error> {
+        if !self.endpoints.acquire_read().has_backups() {
+            return Ok(false);
+        }
+
+        self.do_fail_over()
+    }
+
     /// Does multiple RPC calls to the full node, to check for
     /// reachability and some basic APIs are available.
     ///
@@ -1011,6 +1419,12 @@ impl ChainEndpoint for CosmosSdkChain {
         runtime.block_on(self.do_send_messages_and_wait_check_tx(tracked_msgs))
     }
 
+    fn estimate_tx_fee(&mut self, tracked_msgs: TrackedMsgs) -> Result<Fee, Error> {
+        let runtime = self.rt.clone();
+
+        runtime.block_on(self.do_estimate_tx_fee(tracked_msgs))
+    }
+
     /// Get the account for the signer
     fn get_signer(&self) -> Result<Signer, Error> {
         // Get the key from key seed file
@@ -1021,6 +1435,26 @@ impl ChainEndpoint for CosmosSdkChain {
         Ok(signer)
     }
 
+    fn select_signer(&mut self) -> Result<Signer, Error> {
+        let Some(wallets) = &mut self.wallets else {
+            return self.get_signer();
+        };
+
+        let wallet = wallets.select_idle(Instant::now());
+
+        key_pair_to_signer(wallets.key_pair(wallet))
+    }
+
+    fn get_wallet_keys(&mut self) -> Result<Vec<(String, Secp256k1KeyPair)>, Error> {
+        match &self.wallets {
+            Some(wallets) => Ok(wallets
+                .keys()
+                .map(|(key_name, key_pair)| (key_name.to_string(), key_pair.clone()))
+                .collect()),
+            None => Ok(vec![(self.config.key_name.clone(), self.key()?)]),
+        }
+    }
+
     /// Get the chain configuration
     fn config(&self) -> &ChainConfig {
         &self.config
@@ -1287,7 +1721,10 @@ impl ChainEndpoint for CosmosSdkChain {
 
         let consensus_state = AnyConsensusState::decode_vec(&res.value).map_err(Error::decode)?;
 
-        if !matches!(consensus_state, AnyConsensusState::Tendermint(_)) {
+        if !matches!(
+            consensus_state,
+            AnyConsensusState::Tendermint(_) | AnyConsensusState::Wasm(_)
+        ) {
             return Err(Error::consensus_state_type_mismatch(
                 ClientType::Tendermint,
                 consensus_state.client_type(),
@@ -1611,6 +2048,80 @@ impl ChainEndpoint for CosmosSdkChain {
         }
     }
 
+    fn query_upgrade(
+        &self,
+        request: QueryUpgradeRequest,
+        include_proof: IncludeProof,
+    ) -> Result<(Upgrade, Option<MerkleProof>), Error> {
+        crate::time!(
+            "query_upgrade",
+            {
+                "src_chain": self.config().id.to_string(),
+            }
+        );
+        crate::telemetry!(query, self.id(), "query_upgrade");
+
+        let res = self.query(
+            ChannelUpgradePath::Upgrade(request.port_id.clone(), request.channel_id.clone()),
+            request.height,
+            matches!(include_proof, IncludeProof::Yes),
+        )?;
+
+        if res.value.is_empty() {
+            return Err(Error::upgrade_not_found(
+                request.port_id,
+                request.channel_id,
+            ));
+        }
+
+        let upgrade = Upgrade::decode_vec(&res.value).map_err(Error::decode)?;
+
+        match include_proof {
+            IncludeProof::Yes => {
+                let proof = res.proof.ok_or_else(Error::empty_response_proof)?;
+                Ok((upgrade, Some(proof)))
+            }
+            IncludeProof::No => Ok((upgrade, None)),
+        }
+    }
+
+    fn query_upgrade_error(
+        &self,
+        request: QueryUpgradeErrorRequest,
+        include_proof: IncludeProof,
+    ) -> Result<(ErrorReceipt, Option<MerkleProof>), Error> {
+        crate::time!(
+            "query_upgrade_error",
+            {
+                "src_chain": self.config().id.to_string(),
+            }
+        );
+        crate::telemetry!(query, self.id(), "query_upgrade_error");
+
+        let res = self.query(
+            ChannelUpgradePath::UpgradeError(request.port_id.clone(), request.channel_id.clone()),
+            request.height,
+            matches!(include_proof, IncludeProof::Yes),
+        )?;
+
+        if res.value.is_empty() {
+            return Err(Error::upgrade_error_not_found(
+                request.port_id,
+                request.channel_id,
+            ));
+        }
+
+        let error_receipt = ErrorReceipt::decode_vec(&res.value).map_err(Error::decode)?;
+
+        match include_proof {
+            IncludeProof::Yes => {
+                let proof = res.proof.ok_or_else(Error::empty_response_proof)?;
+                Ok((error_receipt, Some(proof)))
+            }
+            IncludeProof::No => Ok((error_receipt, None)),
+        }
+    }
+
     fn query_channel_client_state(
         &self,
         request: QueryChannelClientStateRequest,
@@ -1963,6 +2474,12 @@ impl ChainEndpoint for CosmosSdkChain {
         });
         crate::telemetry!(query, self.id(), "query_txs");
 
+        if let QueryTxRequest::Transaction(QueryTxHash(tx_hash)) = &request {
+            if self.submitted_txs.lock().unwrap().contains(tx_hash) {
+                return self.block_on(self.query_submitted_tx(*tx_hash));
+            }
+        }
+
         self.block_on(query_txs(
             self.id(),
             &self.rpc_client,
@@ -2165,7 +2682,7 @@ impl ChainEndpoint for CosmosSdkChain {
             &self.rpc_client,
             &self.tx_config,
             &key_pair,
-            &mut self.account,
+            wallet_account(&mut self.wallets, &mut self.account, Some(0)),
             &self.config.memo_prefix,
             channel_id,
             port_id,
@@ -2203,6 +2720,19 @@ impl ChainEndpoint for CosmosSdkChain {
     }
 }
 
+/// The cached account of the given wallet of the pool, if several keys are configured,
+/// or the cached account of the single wallet otherwise.
+fn wallet_account<'a>(
+    wallets: &'a mut Option<WalletPool>,
+    account: &'a mut Option<Account>,
+    wallet: Option<usize>,
+) -> &'a mut Option<Account> {
+    match (wallets, wallet) {
+        (Some(wallets), Some(wallet)) => wallets.account_mut(wallet),
+        _ => account,
+    }
+}
+
 fn sort_events_by_sequence(events: &mut [IbcEventWithHeight]) {
     events.sort_by(|a, b| {
         a.event
@@ -2213,6 +2743,30 @@ fn sort_events_by_sequence(events: &mut [IbcEventWithHeight]) {
     });
 }
 
+/// Connect to the RPC endpoint at `config.rpc_addr`, with the
+/// compatibility mode matching the version of the node.
+async fn connect_rpc_client(
+    config: &ChainConfig,
+) -> Result<(HttpClient, CompatMode, node::Info), Error> {
+    let mut rpc_client = HttpClient::new(config.rpc_addr.clone())
+        .map_err(|e| Error::rpc(config.rpc_addr.clone(), e))?;
+
+    let node_info = fetch_node_info(&rpc_client, config).await?;
+
+    let compat_mode = CompatMode::from_version(node_info.version.clone())
+        .map_err(|e| Error::rpc(config.rpc_addr.clone(), e))?;
+    rpc_client.set_compat_mode(compat_mode);
+
+    Ok((rpc_client, compat_mode, node_info))
+}
+
+/// Point the RPC, WebSocket and gRPC addresses of the configuration to the given node.
+fn use_node_endpoints(config: &mut ChainConfig, node: &NodeEndpoints) {
+    config.rpc_addr = node.rpc_addr.clone();
+    config.websocket_addr = node.websocket_addr.clone();
+    config.grpc_addr = node.grpc_addr.clone();
+}
+
 async fn fetch_node_info(
     rpc_client: &HttpClient,
     config: &ChainConfig,
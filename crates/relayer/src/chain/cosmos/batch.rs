@@ -17,6 +17,7 @@ use crate::chain::cosmos::types::config::TxConfig;
 use crate::chain::cosmos::types::tx::{TxStatus, TxSyncResult};
 use crate::chain::cosmos::wait::wait_for_block_commits;
 use crate::config::types::Memo;
+use crate::economics;
 use crate::error::Error;
 use crate::event::IbcEventWithHeight;
 use crate::keyring::Secp256k1KeyPair;
@@ -43,14 +44,18 @@ pub async fn send_batched_messages_and_wait_commit(
     let mut tx_sync_results =
         send_messages_as_batches(rpc_client, config, key_pair, account, tx_memo, messages).await?;
 
-    wait_for_block_commits(
+    let result = wait_for_block_commits(
         &config.chain_id,
         rpc_client,
         &config.rpc_address,
         &config.rpc_timeout,
         &mut tx_sync_results,
     )
-    .await?;
+    .await;
+
+    record_committed_txs(&config.chain_id, &mut tx_sync_results);
+
+    result?;
 
     let events = tx_sync_results
         .into_iter()
@@ -196,19 +201,32 @@ async fn sequential_send_messages_as_batches(
 
         tx_sync_results.push(tx_sync_result);
 
-        wait_for_block_commits(
+        let result = wait_for_block_commits(
             &config.chain_id,
             rpc_client,
             &config.rpc_address,
             &config.rpc_timeout,
             &mut tx_sync_results,
         )
-        .await?;
+        .await;
+
+        record_committed_txs(&config.chain_id, &mut tx_sync_results);
+
+        result?;
     }
 
     Ok(tx_sync_results)
 }
 
+/// Accounts for the fees of the transactions which were committed since the last call.
+fn record_committed_txs(chain_id: &ChainId, tx_sync_results: &mut [TxSyncResult]) {
+    for res in tx_sync_results.iter_mut() {
+        if let Some(committed) = res.committed.take() {
+            economics::record_tx_response(chain_id, &committed);
+        }
+    }
+}
+
 fn response_to_tx_sync_result(
     chain_id: &ChainId,
     message_count: usize,
@@ -228,12 +246,14 @@ fn response_to_tx_sync_result(
             response,
             events: events_per_tx,
             status: TxStatus::ReceivedResponse,
+            committed: None,
         }
     } else {
         TxSyncResult {
             response,
             events: Vec::new(),
             status: TxStatus::Pending { message_count },
+            committed: None,
         }
     }
 }
@@ -344,6 +364,7 @@ mod tests {
             "cosmos",
             &chain_id,
             &chain_config.key_store_folder,
+            &chain_config.remote_signer,
         )
         .unwrap();
         let hd_path = COSMOS_HD_PATH.parse().unwrap();
@@ -36,6 +36,25 @@ pub fn sign_and_encode_tx(
     encode_tx_raw(tx_raw)
 }
 
+/// Encode a transaction without any signer information nor signature,
+/// to be signed offline, eg. by the members of a multisig account.
+pub fn encode_unsigned_tx(
+    config: &TxConfig,
+    tx_memo: &Memo,
+    messages: &[Any],
+    fee: &Fee,
+) -> Result<TxRaw, Error> {
+    let (_, body_bytes) = tx_body_and_bytes(messages, tx_memo, config.extension_options.clone())?;
+
+    let (_, auth_info_bytes) = auth_info_and_bytes(vec![], fee.clone())?;
+
+    Ok(TxRaw {
+        body_bytes,
+        auth_info_bytes,
+        signatures: vec![],
+    })
+}
+
 /// Length information for an encoded transaction.
 pub struct EncodedTxMetrics {
     /// Length of the encoded message, excluding the `body_bytes` field.
@@ -89,7 +108,7 @@ pub fn sign_tx(
     let (body, body_bytes) =
         tx_body_and_bytes(messages, tx_memo, config.extension_options.clone())?;
 
-    let (auth_info, auth_info_bytes) = auth_info_and_bytes(signer, fee.clone())?;
+    let (auth_info, auth_info_bytes) = auth_info_and_bytes(vec![signer], fee.clone())?;
 
     let signed_doc = encode_sign_doc(
         &config.chain_id,
@@ -166,7 +185,7 @@ fn encode_signer_info(
     Ok(signer_info)
 }
 
-fn encode_tx_raw(tx_raw: TxRaw) -> Result<Vec<u8>, Error> {
+pub fn encode_tx_raw(tx_raw: TxRaw) -> Result<Vec<u8>, Error> {
     let mut tx_bytes = Vec::new();
     prost::Message::encode(&tx_raw, &mut tx_bytes)
         .map_err(|e| Error::protobuf_encode("Transaction".to_string(), e))?;
@@ -184,9 +203,12 @@ pub fn encode_to_bech32(address: &str, account_prefix: &str) -> Result<String, E
     Ok(encoded)
 }
 
-fn auth_info_and_bytes(signer_info: SignerInfo, fee: Fee) -> Result<(AuthInfo, Vec<u8>), Error> {
+fn auth_info_and_bytes(
+    signer_infos: Vec<SignerInfo>,
+    fee: Fee,
+) -> Result<(AuthInfo, Vec<u8>), Error> {
     let auth_info = AuthInfo {
-        signer_infos: vec![signer_info],
+        signer_infos,
         fee: Some(fee),
 
         // Since Cosmos SDK v0.46.0
@@ -202,7 +224,7 @@ fn auth_info_and_bytes(signer_info: SignerInfo, fee: Fee) -> Result<(AuthInfo, V
     Ok((auth_info, auth_buf))
 }
 
-fn tx_body_and_bytes(
+pub(crate) fn tx_body_and_bytes(
     proto_msgs: &[Any],
     memo: &Memo,
     extension_options: Vec<Any>,
@@ -0,0 +1,362 @@
+//! Health scoring of the endpoints of the full nodes of a chain,
+//! used by [`CosmosSdkChain`](super::CosmosSdkChain) to fail over
+//! to the `backup_endpoints` when the active node is unhealthy.
+
+use alloc::sync::Weak;
+use core::str::FromStr;
+use core::time::Duration;
+use std::sync::RwLock;
+use std::time::Instant;
+
+use futures::future::join_all;
+use tendermint::node;
+use tendermint_rpc::client::CompatMode;
+use tendermint_rpc::{Client, Error as TendermintRpcError, HttpClient};
+use tonic::codegen::http::Uri;
+use tonic::transport::Endpoint;
+use tracing::debug;
+
+use crate::config::NodeEndpoints;
+use crate::error::Error;
+
+/// The maximum number of blocks a node can lag behind the most
+/// up-to-date node of the chain while still being considered healthy.
+pub const MAX_HEIGHT_LAG: u64 = 10;
+
+/// The health of a full node, as observed by the last health check of its endpoints.
+#[derive(Clone, Debug)]
+pub struct EndpointHealth {
+    /// The identifier of the node
+    pub node_id: node::Id,
+    /// The latest block height reported by the node
+    pub latest_height: u64,
+    /// Whether the node reported that it is still catching up with the chain
+    pub catching_up: bool,
+    /// The round-trip time of the status query to the RPC endpoint
+    pub latency: Duration,
+}
+
+impl EndpointHealth {
+    /// The health score of the node, from 0 for an unhealthy node up to 100.
+    ///
+    /// A node is unhealthy if it is catching up, or if it lags more than
+    /// [`MAX_HEIGHT_LAG`] blocks behind the node at `best_height`.
+    /// Otherwise, the score decreases with the lag and the latency of the node.
+    pub fn score(&self, best_height: u64) -> u64 {
+        let lag = best_height.saturating_sub(self.latest_height);
+
+        if self.catching_up || lag > MAX_HEIGHT_LAG {
+            return 0;
+        }
+
+        let latency_penalty = (self.latency.as_millis() / 100).min(40) as u64;
+
+        100 - lag * 5 - latency_penalty
+    }
+}
+
+/// The endpoints of the full nodes of a chain, along with their health,
+/// and which of them are currently in use.
+#[derive(Clone, Debug)]
+pub struct EndpointPool {
+    endpoints: Vec<NodeEndpoints>,
+    health: Vec<Option<EndpointHealth>>,
+    active: usize,
+}
+
+impl EndpointPool {
+    /// Creates a pool with the given endpoints, the first of which are in use.
+    pub fn new(endpoints: Vec<NodeEndpoints>) -> Self {
+        assert!(!endpoints.is_empty(), "a chain has at least one node");
+
+        Self {
+            health: vec![None; endpoints.len()],
+            endpoints,
+            active: 0,
+        }
+    }
+
+    pub fn endpoints(&self) -> &[NodeEndpoints] {
+        &self.endpoints
+    }
+
+    /// Whether there are backup endpoints to fail over to
+    pub fn has_backups(&self) -> bool {
+        self.endpoints.len() > 1
+    }
+
+    pub fn active_index(&self) -> usize {
+        self.active
+    }
+
+    pub fn active(&self) -> &NodeEndpoints {
+        &self.endpoints[self.active]
+    }
+
+    /// The endpoints of the nodes other than the active one, in order.
+    pub fn backups(&self) -> Vec<NodeEndpoints> {
+        self.endpoints
+            .iter()
+            .enumerate()
+            .filter(|(index, _)| *index != self.active)
+            .map(|(_, node)| node.clone())
+            .collect()
+    }
+
+    /// The health of the node at the given index, as of the last call to [`EndpointPool::update`],
+    /// or `None` if the node was unreachable.
+    pub fn health(&self, index: usize) -> Option<&EndpointHealth> {
+        self.health.get(index).and_then(Option::as_ref)
+    }
+
+    /// The health score of each node, as of the last call to [`EndpointPool::update`].
+    pub fn scores(&self) -> Vec<u64> {
+        let best_height = self
+            .health
+            .iter()
+            .flatten()
+            .map(|health| health.latest_height)
+            .max()
+            .unwrap_or_default();
+
+        self.health
+            .iter()
+            .map(|health| {
+                health
+                    .as_ref()
+                    .map_or(0, |health| health.score(best_height))
+            })
+            .collect()
+    }
+
+    /// Records the health of each node, where `None` stands for an unreachable node,
+    /// and returns the index of the node to fail over to, see [`EndpointPool::failover`].
+    pub fn update(&mut self, health: Vec<Option<EndpointHealth>>) -> Option<usize> {
+        self.record_health(health);
+        self.failover()
+    }
+
+    /// Records the health of each node, where `None` stands for an unreachable node.
+    pub fn record_health(&mut self, health: Vec<Option<EndpointHealth>>) {
+        assert_eq!(health.len(), self.endpoints.len());
+
+        self.health = health;
+    }
+
+    /// Returns the index of the node to fail over to if the active node is unhealthy,
+    /// namely the healthy node with the best score, preferring the nodes listed first.
+    /// Returns `None` if the active node is healthy, or if none of the nodes is.
+    pub fn failover(&self) -> Option<usize> {
+        if self.scores()[self.active] > 0 {
+            return None;
+        }
+
+        self.best_other_node()
+    }
+
+    /// Returns the index of the healthy node with the best score other than the active one,
+    /// to retry a request on after it failed on the active node, if there is such a node.
+    pub fn next_healthy(&self) -> Option<usize> {
+        self.best_other_node()
+    }
+
+    fn best_other_node(&self) -> Option<usize> {
+        self.scores()
+            .iter()
+            .enumerate()
+            .filter(|(index, score)| *index != self.active && **score > 0)
+            .fold(
+                None,
+                |best: Option<(usize, u64)>, (index, score)| match best {
+                    Some((_, best_score)) if best_score >= *score => best,
+                    _ => Some((index, *score)),
+                },
+            )
+            .map(|(index, _)| index)
+    }
+
+    /// Marks the node at the given index as the one in use.
+    pub fn set_active(&mut self, index: usize) {
+        assert!(index < self.endpoints.len());
+
+        self.active = index;
+    }
+}
+
+/// Checks the health of the nodes of the given pool every `interval`, and records it in the pool,
+/// until the pool is dropped. The chain using the pool then fails over to another node if needed.
+///
+/// The checks run in their own task, so that they do not hold up the requests to the chain.
+pub async fn monitor_endpoints_health(
+    pool: Weak<RwLock<EndpointPool>>,
+    compat_mode: CompatMode,
+    timeout: Duration,
+    interval: Duration,
+) {
+    let mut interval = tokio::time::interval(interval);
+
+    loop {
+        interval.tick().await;
+
+        let endpoints = match pool.upgrade() {
+            Some(pool) => pool.read().unwrap().endpoints().to_vec(),
+            None => return,
+        };
+
+        let health = join_all(
+            endpoints
+                .iter()
+                .map(|node| check_endpoint_health(node, compat_mode, timeout)),
+        )
+        .await;
+
+        let health = health
+            .into_iter()
+            .zip(&endpoints)
+            .map(|(result, node)| {
+                result
+                    .map_err(|e| debug!("node at {} is unreachable: {}", node.rpc_addr, e))
+                    .ok()
+            })
+            .collect();
+
+        let Some(pool) = pool.upgrade() else { return };
+
+        pool.write().unwrap().record_health(health);
+    }
+}
+
+/// Checks that the RPC and gRPC endpoints of a node respond within the given timeout,
+/// and retrieves the status of the node.
+pub async fn check_endpoint_health(
+    endpoints: &NodeEndpoints,
+    compat_mode: CompatMode,
+    timeout: Duration,
+) -> Result<EndpointHealth, Error> {
+    let rpc_addr = endpoints.rpc_addr.clone();
+
+    let mut rpc_client =
+        HttpClient::new(rpc_addr.clone()).map_err(|e| Error::rpc(rpc_addr.clone(), e))?;
+    rpc_client.set_compat_mode(compat_mode);
+
+    let start = Instant::now();
+
+    let status = tokio::time::timeout(timeout, rpc_client.status())
+        .await
+        .map_err(|_| Error::rpc(rpc_addr.clone(), TendermintRpcError::timeout(timeout)))?
+        .map_err(|e| Error::rpc(rpc_addr.clone(), e))?;
+
+    let latency = start.elapsed();
+
+    let grpc_addr = Uri::from_str(&endpoints.grpc_addr.to_string())
+        .map_err(|e| Error::invalid_uri(endpoints.grpc_addr.to_string(), e))?;
+
+    Endpoint::from(grpc_addr)
+        .connect_timeout(timeout)
+        .connect()
+        .await
+        .map_err(Error::grpc_transport)?;
+
+    Ok(EndpointHealth {
+        node_id: status.node_info.id,
+        latest_height: status.sync_info.latest_block_height.value(),
+        catching_up: status.sync_info.catching_up,
+        latency,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use core::str::FromStr;
+    use core::time::Duration;
+
+    use tendermint::node;
+    use test_log::test;
+
+    use super::{EndpointHealth, EndpointPool};
+    use crate::config::NodeEndpoints;
+
+    fn endpoints(count: usize) -> Vec<NodeEndpoints> {
+        (0..count)
+            .map(|i| NodeEndpoints {
+                rpc_addr: format!("http://node-{i}:26657").parse().unwrap(),
+                websocket_addr: format!("ws://node-{i}:26657/websocket").parse().unwrap(),
+                grpc_addr: format!("http://node-{i}:9090").parse().unwrap(),
+            })
+            .collect()
+    }
+
+    fn healthy(latest_height: u64, latency_ms: u64) -> Option<EndpointHealth> {
+        Some(EndpointHealth {
+            node_id: node::Id::from_str("a8cf7e1ebd2b7fc2ad1a8f3fba3c11c9bcb88cf6").unwrap(),
+            latest_height,
+            catching_up: false,
+            latency: Duration::from_millis(latency_ms),
+        })
+    }
+
+    #[test]
+    fn score_endpoints() {
+        let mut pool = EndpointPool::new(endpoints(4));
+
+        let mut catching_up = healthy(100, 10);
+        catching_up.as_mut().unwrap().catching_up = true;
+
+        pool.update(vec![healthy(100, 10), healthy(95, 250), catching_up, None]);
+
+        assert_eq!(pool.scores(), vec![100, 73, 0, 0]);
+
+        pool.update(vec![
+            healthy(100, 10),
+            healthy(89, 10),
+            None,
+            healthy(100, 60_000),
+        ]);
+
+        assert_eq!(pool.scores(), vec![100, 0, 0, 60]);
+    }
+
+    #[test]
+    fn fail_over_to_best_healthy_endpoint() {
+        let mut pool = EndpointPool::new(endpoints(3));
+
+        // The active node is healthy
+        assert_eq!(
+            pool.update(vec![healthy(100, 500), healthy(100, 10), healthy(100, 10)]),
+            None
+        );
+
+        // The active node is down, the nodes with the best score are preferred in order
+        assert_eq!(
+            pool.update(vec![None, healthy(100, 10), healthy(100, 10)]),
+            Some(1)
+        );
+        pool.set_active(1);
+        assert_eq!(pool.active(), &endpoints(3)[1]);
+
+        // The active node lags behind
+        assert_eq!(
+            pool.update(vec![healthy(100, 10), healthy(50, 10), healthy(100, 5)]),
+            Some(0)
+        );
+
+        // None of the nodes is healthy
+        assert_eq!(pool.update(vec![None, None, None]), None);
+        assert_eq!(pool.active_index(), 1);
+    }
+
+    #[test]
+    fn retry_on_next_healthy_endpoint() {
+        let mut pool = EndpointPool::new(endpoints(3));
+
+        // No health check has completed yet
+        assert_eq!(pool.next_healthy(), None);
+
+        // The active node is never picked, even if it is the healthiest
+        pool.record_health(vec![healthy(100, 10), healthy(100, 900), healthy(100, 500)]);
+        assert_eq!(pool.next_healthy(), Some(2));
+
+        pool.record_health(vec![healthy(100, 10), None, None]);
+        assert_eq!(pool.next_healthy(), None);
+    }
+}
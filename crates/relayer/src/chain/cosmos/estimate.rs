@@ -1,21 +1,26 @@
 use ibc_proto::cosmos::tx::v1beta1::{Fee, Tx};
 use ibc_proto::google::protobuf::Any;
 use ibc_relayer_types::core::ics24_host::identifier::ChainId;
+use tendermint_rpc::HttpClient;
 use tonic::codegen::http::Uri;
 use tracing::{debug, error, span, warn, Level};
 
 use crate::chain::cosmos::encode::sign_tx;
 use crate::chain::cosmos::gas::gas_amount_to_fee;
+use crate::chain::cosmos::query::eip_base_fee::query_eip_base_fee;
 use crate::chain::cosmos::simulate::send_tx_simulate;
 use crate::chain::cosmos::types::account::Account;
 use crate::chain::cosmos::types::config::TxConfig;
 use crate::chain::cosmos::types::gas::GasConfig;
 use crate::config::types::Memo;
+use crate::config::GasPrice;
 use crate::error::Error;
 use crate::keyring::Secp256k1KeyPair;
+use crate::telemetry;
 use crate::util::pretty::PrettyFee;
 
 pub async fn estimate_tx_fees(
+    rpc_client: &HttpClient,
     config: &TxConfig,
     key_pair: &Secp256k1KeyPair,
     account: &Account,
@@ -44,12 +49,64 @@ pub async fn estimate_tx_fees(
         signatures: signed_tx.signatures,
     };
 
+    let gas_config = GasConfig {
+        gas_price: gas_price_for_tx(rpc_client, config).await,
+        ..gas_config.clone()
+    };
+
     let estimated_fee =
-        estimate_fee_with_tx(gas_config, &config.grpc_address, &config.chain_id, tx).await?;
+        estimate_fee_with_tx(&gas_config, &config.grpc_address, &config.chain_id, tx).await?;
 
     Ok(estimated_fee)
 }
 
+/// Determine the gas price to pay for the next transaction.
+///
+/// If the dynamic gas price is enabled, this queries the current base gas price
+/// of the chain and adjusts it as per the configuration, falling back on the
+/// static gas price if the query fails. Otherwise, the static gas price is used.
+async fn gas_price_for_tx(rpc_client: &HttpClient, config: &TxConfig) -> GasPrice {
+    let gas_config = &config.gas_config;
+    let static_price = &gas_config.gas_price;
+
+    if !gas_config.dynamic_gas_price.enabled {
+        return static_price.clone();
+    }
+
+    match query_eip_base_fee(rpc_client, &config.rpc_address).await {
+        Ok(base_price) => {
+            let price = gas_config
+                .dynamic_gas_price
+                .apply(base_price, static_price.price);
+
+            debug!(
+                id = %config.chain_id,
+                "using dynamic gas price {price}{} (base gas price: {base_price})",
+                static_price.denom,
+            );
+
+            telemetry!(
+                dynamic_gas_price,
+                &config.chain_id,
+                price,
+                &static_price.denom
+            );
+
+            GasPrice::new(price, static_price.denom.clone())
+        }
+
+        Err(e) => {
+            warn!(
+                id = %config.chain_id,
+                "failed to query base gas price, falling back on static gas price {static_price}: {}",
+                e.detail()
+            );
+
+            static_price.clone()
+        }
+    }
+}
+
 async fn estimate_fee_with_tx(
     gas_config: &GasConfig,
     grpc_address: &Uri,
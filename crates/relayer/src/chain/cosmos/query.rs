@@ -19,7 +19,9 @@ pub mod balance;
 pub mod consensus_state;
 pub mod custom;
 pub mod denom_trace;
+pub mod eip_base_fee;
 pub mod fee;
+pub mod ica;
 pub mod status;
 pub mod tx;
 
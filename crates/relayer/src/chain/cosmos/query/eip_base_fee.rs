@@ -0,0 +1,75 @@
+use prost::Message;
+use tendermint::block::Height;
+use tendermint_rpc::{HttpClient, Url};
+use tracing::debug;
+
+use crate::chain::cosmos::query::abci_query;
+use crate::error::Error;
+
+/// Path of the ABCI query returning the current base fee of
+/// the EIP-1559 fee market implemented by Osmosis' `txfees` module.
+const EIP_BASE_FEE_QUERY_PATH: &str = "/osmosis.txfees.v1beta1.Query/GetEipBaseFee";
+
+/// Number of decimal places of a Cosmos SDK `Dec`.
+const DEC_PRECISION: i32 = 18;
+
+// protobuf message: https://github.com/osmosis-labs/osmosis/blob/main/proto/osmosis/txfees/v1beta1/query.proto
+#[derive(Clone, PartialEq, Eq, Message)]
+struct QueryEipBaseFeeResponse {
+    #[prost(string, tag = "1")]
+    pub base_fee: ::prost::alloc::string::String,
+}
+
+/// Query the current base gas price of a chain running an EIP-1559-style fee market.
+pub async fn query_eip_base_fee(rpc_client: &HttpClient, rpc_address: &Url) -> Result<f64, Error> {
+    debug!("querying EIP-1559 base fee at {rpc_address}");
+
+    let response = abci_query(
+        rpc_client,
+        rpc_address,
+        EIP_BASE_FEE_QUERY_PATH.to_string(),
+        String::new(),
+        Height::from(0u32),
+        false,
+    )
+    .await?;
+
+    let response = QueryEipBaseFeeResponse::decode(response.value.as_slice())
+        .map_err(|e| Error::protobuf_decode("QueryEipBaseFeeResponse".to_string(), e))?;
+
+    parse_dec(&response.base_fee).ok_or_else(|| Error::invalid_base_fee(response.base_fee))
+}
+
+/// Parse a Cosmos SDK `Dec`, which is either encoded as a decimal number,
+/// or as the integer obtained by shifting the decimal point by 18 places.
+fn parse_dec(dec: &str) -> Option<f64> {
+    let value = if dec.contains('.') {
+        dec.parse::<f64>().ok()?
+    } else {
+        dec.parse::<u128>().ok()? as f64 / 10f64.powi(DEC_PRECISION)
+    };
+
+    (value.is_finite() && value >= 0.0).then_some(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::parse_dec;
+
+    #[test]
+    fn parse_dec_with_decimal_point() {
+        assert_eq!(parse_dec("0.0025"), Some(0.0025));
+    }
+
+    #[test]
+    fn parse_dec_without_decimal_point() {
+        assert_eq!(parse_dec("2500000000000000"), Some(0.0025));
+    }
+
+    #[test]
+    fn parse_invalid_dec() {
+        assert_eq!(parse_dec(""), None);
+        assert_eq!(parse_dec("abc"), None);
+        assert_eq!(parse_dec("-1"), None);
+    }
+}
@@ -0,0 +1,38 @@
+use http::uri::Uri;
+use tonic::Code;
+
+use ibc_proto::ibc::applications::interchain_accounts::controller::v1::{
+    query_client::QueryClient, QueryInterchainAccountRequest,
+};
+use ibc_relayer_types::core::ics24_host::identifier::ConnectionId;
+use ibc_relayer_types::signer::Signer;
+
+use crate::config::default::max_grpc_decoding_size;
+use crate::error::Error;
+
+/// Uses the GRPC client to retrieve the address of the interchain account
+/// owned by the given account of the controller chain, over the given connection.
+///
+/// Returns `None` if no interchain account is registered for this owner and connection.
+pub async fn query_interchain_account(
+    grpc_address: &Uri,
+    owner: &Signer,
+    connection_id: &ConnectionId,
+) -> Result<Option<String>, Error> {
+    let mut client = QueryClient::connect(grpc_address.clone())
+        .await
+        .map_err(Error::grpc_transport)?;
+
+    client = client.max_decoding_message_size(max_grpc_decoding_size().get_bytes() as usize);
+
+    let request = tonic::Request::new(QueryInterchainAccountRequest {
+        owner: owner.to_string(),
+        connection_id: connection_id.to_string(),
+    });
+
+    match client.interchain_account(request).await {
+        Ok(response) => Ok(Some(response.into_inner().address)),
+        Err(e) if e.code() == Code::NotFound => Ok(None),
+        Err(e) => Err(Error::grpc_status(e, "query_interchain_account".to_owned())),
+    }
+}
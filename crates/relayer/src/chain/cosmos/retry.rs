@@ -36,12 +36,17 @@ const INCORRECT_ACCOUNT_SEQUENCE_ERR: u32 = 32;
 ///
 /// We treat both cases by re-fetching the account sequence number
 /// from the full node and retrying once with the new account s.n.
+///
+/// When the chain has several wallets, the given `account` is the one of
+/// the wallet selected to submit the messages, so that each wallet tracks
+/// its own account sequence.
 #[instrument(
     name = "send_tx_with_account_sequence_retry",
     level = "error",
     skip_all,
     fields(
         chain = %config.chain_id,
+        account.address = %key_pair.account(),
         account.sequence = %account.sequence,
     ),
 )]
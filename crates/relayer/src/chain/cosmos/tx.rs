@@ -1,10 +1,10 @@
-use ibc_proto::cosmos::tx::v1beta1::Fee;
+use ibc_proto::cosmos::tx::v1beta1::{Fee, TxRaw};
 use ibc_proto::google::protobuf::Any;
 use ibc_relayer_types::events::IbcEvent;
 use tendermint_rpc::endpoint::broadcast::tx_sync::Response;
 use tendermint_rpc::{Client, HttpClient, Url};
 
-use crate::chain::cosmos::encode::sign_and_encode_tx;
+use crate::chain::cosmos::encode::{encode_tx_raw, sign_and_encode_tx};
 use crate::chain::cosmos::estimate::estimate_tx_fees;
 use crate::chain::cosmos::query::account::query_account;
 use crate::chain::cosmos::query::tx::all_ibc_events_from_tx_search_response;
@@ -26,7 +26,7 @@ pub async fn estimate_fee_and_send_tx(
     tx_memo: &Memo,
     messages: &[Any],
 ) -> Result<Response, Error> {
-    let fee = estimate_tx_fees(config, key_pair, account, tx_memo, messages).await?;
+    let fee = estimate_tx_fees(rpc_client, config, key_pair, account, tx_memo, messages).await?;
 
     send_tx_with_fee(
         rpc_client, config, key_pair, account, tx_memo, messages, &fee,
@@ -114,6 +114,34 @@ pub async fn simple_send_tx(
     Ok(events)
 }
 
+/// Broadcast a transaction which was signed outside of Hermes, eg. by the members
+/// of a multisig account, wait for it to be committed and return its IBC events.
+pub async fn broadcast_signed_tx(
+    rpc_client: &HttpClient,
+    config: &TxConfig,
+    tx_raw: TxRaw,
+) -> Result<Vec<IbcEventWithHeight>, Error> {
+    let tx_bytes = encode_tx_raw(tx_raw)?;
+
+    let response = broadcast_tx_sync(rpc_client, &config.rpc_address, tx_bytes).await?;
+
+    if response.code.is_err() {
+        return Err(Error::check_tx(response));
+    }
+
+    let response = wait_tx_succeed(
+        rpc_client,
+        &config.rpc_address,
+        &config.rpc_timeout,
+        &response.hash,
+    )
+    .await?;
+
+    let events = all_ibc_events_from_tx_search_response(&config.chain_id, response);
+
+    Ok(events)
+}
+
 pub async fn batched_send_tx(
     rpc_client: &HttpClient,
     config: &TxConfig,
@@ -0,0 +1,1402 @@
+//! Conversion of transactions to and from the JSON format used by the Cosmos SDK
+//! for offline signing, ie. the format produced by `<chain binary> tx ... --generate-only`
+//! and consumed by `<chain binary> tx sign` and `<chain binary> tx multisign`.
+//!
+//! The conversion is driven by schemas describing the protobuf fields of the transaction
+//! envelope, of the public keys, and of the messages which Hermes can generate for offline
+//! signing. A transaction containing a message of any other type is rejected instead of
+//! being partially converted, since a lossy conversion would invalidate the signatures.
+
+use ibc_proto::cosmos::tx::v1beta1::TxRaw;
+use prost::bytes::Buf;
+use prost::encoding::{decode_key, decode_varint, encode_key, encode_varint, WireType};
+use serde_json::{Map, Value};
+use subtle_encoding::base64;
+use tendermint::Time;
+
+use crate::error::Error;
+
+/// Converts a transaction, signed or not, into the JSON format of the Cosmos SDK.
+pub fn tx_to_json(tx: &TxRaw) -> Result<Value, Error> {
+    let body = decode_message(tx_body(), &tx.body_bytes)?;
+    let auth_info = decode_message(auth_info(), &tx.auth_info_bytes)?;
+    let signatures = tx
+        .signatures
+        .iter()
+        .map(|signature| bytes_to_json(signature))
+        .collect::<Result<_, _>>()?;
+
+    let mut json = Map::new();
+    json.insert("body".to_string(), Value::Object(body));
+    json.insert("auth_info".to_string(), Value::Object(auth_info));
+    json.insert("signatures".to_string(), Value::Array(signatures));
+
+    Ok(Value::Object(json))
+}
+
+/// Converts a transaction in the JSON format of the Cosmos SDK back into its protobuf
+/// encoding, eg. to broadcast a transaction which was signed offline.
+pub fn tx_from_json(json: &Value) -> Result<TxRaw, Error> {
+    let json = as_object(json, "transaction")?;
+
+    for key in json.keys() {
+        if !["body", "auth_info", "authInfo", "signatures"].contains(&key.as_str()) {
+            return Err(unknown_field("Tx", key));
+        }
+    }
+
+    let body = json
+        .get("body")
+        .ok_or_else(|| missing_field("Tx", "body"))?;
+    let auth_info_json = json
+        .get("auth_info")
+        .or_else(|| json.get("authInfo"))
+        .ok_or_else(|| missing_field("Tx", "auth_info"))?;
+
+    let mut body_bytes = Vec::new();
+    encode_message(tx_body(), as_object(body, "body")?, &mut body_bytes)?;
+
+    let mut auth_info_bytes = Vec::new();
+    encode_message(
+        auth_info(),
+        as_object(auth_info_json, "auth_info")?,
+        &mut auth_info_bytes,
+    )?;
+
+    let signatures = match json.get("signatures") {
+        None | Some(Value::Null) => Vec::new(),
+        Some(Value::Array(signatures)) => signatures
+            .iter()
+            .map(bytes_from_json)
+            .collect::<Result<_, _>>()?,
+        Some(_) => return Err(invalid_value("signatures", "expected an array")),
+    };
+
+    Ok(TxRaw {
+        body_bytes,
+        auth_info_bytes,
+        signatures,
+    })
+}
+
+/// Replaces the signer of every message in the body of the given transaction in JSON,
+/// ie. the `signer` field of IBC messages and the `sender` field of ICS20 transfers.
+///
+/// Used when the transaction must be signed by another account than the one
+/// configured for Hermes, for instance a multisig account.
+pub fn set_messages_signer(tx: &mut Value, signer: &str) {
+    let messages = tx
+        .get_mut("body")
+        .and_then(|body| body.get_mut("messages"))
+        .and_then(Value::as_array_mut);
+
+    for message in messages.into_iter().flatten() {
+        if let Some(message) = message.as_object_mut() {
+            for key in ["signer", "sender"] {
+                if let Some(value) = message.get_mut(key) {
+                    *value = Value::String(signer.to_string());
+                }
+            }
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+enum Kind {
+    String,
+    Bytes,
+    Bool,
+    Int32,
+    Uint32,
+    Int64,
+    Uint64,
+    Enum(&'static [(i32, &'static str)]),
+    Message(fn() -> &'static [Field]),
+    Any,
+    Timestamp,
+    Duration,
+}
+
+impl Kind {
+    fn is_varint(&self) -> bool {
+        matches!(
+            self,
+            Self::Bool | Self::Int32 | Self::Uint32 | Self::Int64 | Self::Uint64 | Self::Enum(_)
+        )
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Label {
+    Singular,
+    Repeated,
+    /// Member of a `oneof`, whose value is encoded even if it is the default one.
+    OneOf,
+}
+
+#[derive(Clone, Copy)]
+struct Field {
+    tag: u32,
+    name: &'static str,
+    kind: Kind,
+    label: Label,
+}
+
+/// Defines a function returning the fields of a message, so that the schemas of
+/// recursive messages can refer to each other.
+macro_rules! schema {
+    ($name:ident = [$($field:expr),* $(,)?]) => {
+        fn $name() -> &'static [Field] {
+            const FIELDS: &[Field] = &[$($field),*];
+            FIELDS
+        }
+    };
+}
+
+const fn field(tag: u32, name: &'static str, kind: Kind) -> Field {
+    Field {
+        tag,
+        name,
+        kind,
+        label: Label::Singular,
+    }
+}
+
+const fn repeated(tag: u32, name: &'static str, kind: Kind) -> Field {
+    Field {
+        tag,
+        name,
+        kind,
+        label: Label::Repeated,
+    }
+}
+
+const fn one_of(tag: u32, name: &'static str, kind: Kind) -> Field {
+    Field {
+        tag,
+        name,
+        kind,
+        label: Label::OneOf,
+    }
+}
+
+// Transaction envelope, see `cosmos/tx/v1beta1/tx.proto`.
+
+schema! {
+    tx_body = [
+        repeated(1, "messages", Kind::Any),
+        field(2, "memo", Kind::String),
+        field(3, "timeout_height", Kind::Uint64),
+        repeated(1023, "extension_options", Kind::Any),
+        repeated(2047, "non_critical_extension_options", Kind::Any),
+    ]
+}
+
+schema! {
+    auth_info = [
+        repeated(1, "signer_infos", Kind::Message(signer_info)),
+        field(2, "fee", Kind::Message(fee)),
+        field(3, "tip", Kind::Message(tip)),
+    ]
+}
+
+schema! {
+    signer_info = [
+        field(1, "public_key", Kind::Any),
+        field(2, "mode_info", Kind::Message(mode_info)),
+        field(3, "sequence", Kind::Uint64),
+    ]
+}
+
+schema! {
+    mode_info = [
+        one_of(1, "single", Kind::Message(mode_info_single)),
+        one_of(2, "multi", Kind::Message(mode_info_multi)),
+    ]
+}
+
+schema! {
+    mode_info_single = [
+        field(1, "mode", Kind::Enum(SIGN_MODE)),
+    ]
+}
+
+schema! {
+    mode_info_multi = [
+        field(1, "bitarray", Kind::Message(compact_bit_array)),
+        repeated(2, "mode_infos", Kind::Message(mode_info)),
+    ]
+}
+
+schema! {
+    compact_bit_array = [
+        field(1, "extra_bits_stored", Kind::Uint32),
+        field(2, "elems", Kind::Bytes),
+    ]
+}
+
+const SIGN_MODE: &[(i32, &str)] = &[
+    (0, "SIGN_MODE_UNSPECIFIED"),
+    (1, "SIGN_MODE_DIRECT"),
+    (2, "SIGN_MODE_TEXTUAL"),
+    (3, "SIGN_MODE_DIRECT_AUX"),
+    (127, "SIGN_MODE_LEGACY_AMINO_JSON"),
+    (191, "SIGN_MODE_EIP_191"),
+];
+
+schema! {
+    fee = [
+        repeated(1, "amount", Kind::Message(coin)),
+        field(2, "gas_limit", Kind::Uint64),
+        field(3, "payer", Kind::String),
+        field(4, "granter", Kind::String),
+    ]
+}
+
+schema! {
+    tip = [
+        repeated(1, "amount", Kind::Message(coin)),
+        field(2, "tipper", Kind::String),
+    ]
+}
+
+schema! {
+    coin = [
+        field(1, "denom", Kind::String),
+        field(2, "amount", Kind::String),
+    ]
+}
+
+// Public keys and extension options.
+
+schema! {
+    pub_key = [
+        field(1, "key", Kind::Bytes),
+    ]
+}
+
+schema! {
+    legacy_amino_pub_key = [
+        field(1, "threshold", Kind::Uint32),
+        repeated(2, "public_keys", Kind::Any),
+    ]
+}
+
+schema! {
+    extension_option_dynamic_fee_tx = [
+        field(1, "max_priority_price", Kind::String),
+    ]
+}
+
+// IBC messages which can be generated for offline signing.
+
+schema! {
+    msg_transfer = [
+        field(1, "source_port", Kind::String),
+        field(2, "source_channel", Kind::String),
+        field(3, "token", Kind::Message(coin)),
+        field(4, "sender", Kind::String),
+        field(5, "receiver", Kind::String),
+        field(6, "timeout_height", Kind::Message(height)),
+        field(7, "timeout_timestamp", Kind::Uint64),
+        field(8, "memo", Kind::String),
+    ]
+}
+
+schema! {
+    height = [
+        field(1, "revision_number", Kind::Uint64),
+        field(2, "revision_height", Kind::Uint64),
+    ]
+}
+
+schema! {
+    msg_channel_close_init = [
+        field(1, "port_id", Kind::String),
+        field(2, "channel_id", Kind::String),
+        field(3, "signer", Kind::String),
+    ]
+}
+
+schema! {
+    msg_channel_upgrade_init = [
+        field(1, "port_id", Kind::String),
+        field(2, "channel_id", Kind::String),
+        field(3, "fields", Kind::Message(upgrade_fields)),
+        field(4, "signer", Kind::String),
+    ]
+}
+
+schema! {
+    upgrade_fields = [
+        field(1, "ordering", Kind::Enum(ORDER)),
+        repeated(2, "connection_hops", Kind::String),
+        field(3, "version", Kind::String),
+    ]
+}
+
+const ORDER: &[(i32, &str)] = &[
+    (0, "ORDER_NONE_UNSPECIFIED"),
+    (1, "ORDER_UNORDERED"),
+    (2, "ORDER_ORDERED"),
+];
+
+schema! {
+    msg_update_client = [
+        field(1, "client_id", Kind::String),
+        field(2, "header", Kind::Any),
+        field(3, "signer", Kind::String),
+    ]
+}
+
+schema! {
+    msg_upgrade_client = [
+        field(1, "client_id", Kind::String),
+        field(2, "client_state", Kind::Any),
+        field(3, "consensus_state", Kind::Any),
+        field(4, "proof_upgrade_client", Kind::Bytes),
+        field(5, "proof_upgrade_consensus_state", Kind::Bytes),
+        field(6, "signer", Kind::String),
+    ]
+}
+
+// Tendermint light client, see `ibc/lightclients/tendermint/v1/tendermint.proto`.
+
+schema! {
+    tm_client_state = [
+        field(1, "chain_id", Kind::String),
+        field(2, "trust_level", Kind::Message(fraction)),
+        field(3, "trusting_period", Kind::Duration),
+        field(4, "unbonding_period", Kind::Duration),
+        field(5, "max_clock_drift", Kind::Duration),
+        field(6, "frozen_height", Kind::Message(height)),
+        field(7, "latest_height", Kind::Message(height)),
+        repeated(8, "proof_specs", Kind::Message(proof_spec)),
+        repeated(9, "upgrade_path", Kind::String),
+        field(10, "allow_update_after_expiry", Kind::Bool),
+        field(11, "allow_update_after_misbehaviour", Kind::Bool),
+    ]
+}
+
+schema! {
+    fraction = [
+        field(1, "numerator", Kind::Uint64),
+        field(2, "denominator", Kind::Uint64),
+    ]
+}
+
+schema! {
+    tm_consensus_state = [
+        field(1, "timestamp", Kind::Timestamp),
+        field(2, "root", Kind::Message(merkle_root)),
+        field(3, "next_validators_hash", Kind::Bytes),
+    ]
+}
+
+schema! {
+    merkle_root = [
+        field(1, "hash", Kind::Bytes),
+    ]
+}
+
+schema! {
+    tm_header = [
+        field(1, "signed_header", Kind::Message(signed_header)),
+        field(2, "validator_set", Kind::Message(validator_set)),
+        field(3, "trusted_height", Kind::Message(height)),
+        field(4, "trusted_validators", Kind::Message(validator_set)),
+    ]
+}
+
+schema! {
+    proof_spec = [
+        field(1, "leaf_spec", Kind::Message(leaf_op)),
+        field(2, "inner_spec", Kind::Message(inner_spec)),
+        field(3, "max_depth", Kind::Int32),
+        field(4, "min_depth", Kind::Int32),
+        field(5, "prehash_key_before_comparison", Kind::Bool),
+    ]
+}
+
+schema! {
+    leaf_op = [
+        field(1, "hash", Kind::Enum(HASH_OP)),
+        field(2, "prehash_key", Kind::Enum(HASH_OP)),
+        field(3, "prehash_value", Kind::Enum(HASH_OP)),
+        field(4, "length", Kind::Enum(LENGTH_OP)),
+        field(5, "prefix", Kind::Bytes),
+    ]
+}
+
+schema! {
+    inner_spec = [
+        repeated(1, "child_order", Kind::Int32),
+        field(2, "child_size", Kind::Int32),
+        field(3, "min_prefix_length", Kind::Int32),
+        field(4, "max_prefix_length", Kind::Int32),
+        field(5, "empty_child", Kind::Bytes),
+        field(6, "hash", Kind::Enum(HASH_OP)),
+    ]
+}
+
+const HASH_OP: &[(i32, &str)] = &[
+    (0, "NO_HASH"),
+    (1, "SHA256"),
+    (2, "SHA512"),
+    (3, "KECCAK"),
+    (4, "RIPEMD160"),
+    (5, "BITCOIN"),
+    (6, "SHA512_256"),
+];
+
+const LENGTH_OP: &[(i32, &str)] = &[
+    (0, "NO_PREFIX"),
+    (1, "VAR_PROTO"),
+    (2, "VAR_RLP"),
+    (3, "FIXED32_BIG"),
+    (4, "FIXED32_LITTLE"),
+    (5, "FIXED64_BIG"),
+    (6, "FIXED64_LITTLE"),
+    (7, "REQUIRE_32_BYTES"),
+    (8, "REQUIRE_64_BYTES"),
+];
+
+// Tendermint types, see `tendermint/types/types.proto` and `tendermint/types/validator.proto`.
+
+schema! {
+    signed_header = [
+        field(1, "header", Kind::Message(block_header)),
+        field(2, "commit", Kind::Message(commit)),
+    ]
+}
+
+schema! {
+    block_header = [
+        field(1, "version", Kind::Message(consensus_version)),
+        field(2, "chain_id", Kind::String),
+        field(3, "height", Kind::Int64),
+        field(4, "time", Kind::Timestamp),
+        field(5, "last_block_id", Kind::Message(block_id)),
+        field(6, "last_commit_hash", Kind::Bytes),
+        field(7, "data_hash", Kind::Bytes),
+        field(8, "validators_hash", Kind::Bytes),
+        field(9, "next_validators_hash", Kind::Bytes),
+        field(10, "consensus_hash", Kind::Bytes),
+        field(11, "app_hash", Kind::Bytes),
+        field(12, "last_results_hash", Kind::Bytes),
+        field(13, "evidence_hash", Kind::Bytes),
+        field(14, "proposer_address", Kind::Bytes),
+    ]
+}
+
+schema! {
+    consensus_version = [
+        field(1, "block", Kind::Uint64),
+        field(2, "app", Kind::Uint64),
+    ]
+}
+
+schema! {
+    block_id = [
+        field(1, "hash", Kind::Bytes),
+        field(2, "part_set_header", Kind::Message(part_set_header)),
+    ]
+}
+
+schema! {
+    part_set_header = [
+        field(1, "total", Kind::Uint32),
+        field(2, "hash", Kind::Bytes),
+    ]
+}
+
+schema! {
+    commit = [
+        field(1, "height", Kind::Int64),
+        field(2, "round", Kind::Int32),
+        field(3, "block_id", Kind::Message(block_id)),
+        repeated(4, "signatures", Kind::Message(commit_sig)),
+    ]
+}
+
+schema! {
+    commit_sig = [
+        field(1, "block_id_flag", Kind::Enum(BLOCK_ID_FLAG)),
+        field(2, "validator_address", Kind::Bytes),
+        field(3, "timestamp", Kind::Timestamp),
+        field(4, "signature", Kind::Bytes),
+    ]
+}
+
+const BLOCK_ID_FLAG: &[(i32, &str)] = &[
+    (0, "BLOCK_ID_FLAG_UNKNOWN"),
+    (1, "BLOCK_ID_FLAG_ABSENT"),
+    (2, "BLOCK_ID_FLAG_COMMIT"),
+    (3, "BLOCK_ID_FLAG_NIL"),
+];
+
+schema! {
+    validator_set = [
+        repeated(1, "validators", Kind::Message(validator)),
+        field(2, "proposer", Kind::Message(validator)),
+        field(3, "total_voting_power", Kind::Int64),
+    ]
+}
+
+schema! {
+    validator = [
+        field(1, "address", Kind::Bytes),
+        field(2, "pub_key", Kind::Message(public_key)),
+        field(3, "voting_power", Kind::Int64),
+        field(4, "proposer_priority", Kind::Int64),
+    ]
+}
+
+schema! {
+    public_key = [
+        one_of(1, "ed25519", Kind::Bytes),
+        one_of(2, "secp256k1", Kind::Bytes),
+    ]
+}
+
+// Well-known types, converted to JSON strings.
+
+schema! {
+    timestamp = [
+        field(1, "seconds", Kind::Int64),
+        field(2, "nanos", Kind::Int32),
+    ]
+}
+
+/// Returns the schema of the message with the given type URL, if it is supported.
+fn any_schema(type_url: &str) -> Option<&'static [Field]> {
+    let schema = match type_url {
+        "/cosmos.crypto.multisig.LegacyAminoPubKey" => legacy_amino_pub_key(),
+        "/ethermint.types.v1.ExtensionOptionDynamicFeeTx" => extension_option_dynamic_fee_tx(),
+        "/ibc.applications.transfer.v1.MsgTransfer" => msg_transfer(),
+        "/ibc.core.channel.v1.MsgChannelCloseInit" => msg_channel_close_init(),
+        "/ibc.core.channel.v1.MsgChannelUpgradeInit" => msg_channel_upgrade_init(),
+        "/ibc.core.client.v1.MsgUpdateClient" => msg_update_client(),
+        "/ibc.core.client.v1.MsgUpgradeClient" => msg_upgrade_client(),
+        "/ibc.lightclients.tendermint.v1.ClientState" => tm_client_state(),
+        "/ibc.lightclients.tendermint.v1.ConsensusState" => tm_consensus_state(),
+        "/ibc.lightclients.tendermint.v1.Header" => tm_header(),
+        // Secp256k1, Ed25519 and Ethermint public keys all share the same encoding
+        url if url.ends_with(".PubKey") => pub_key(),
+        _ => return None,
+    };
+
+    Some(schema)
+}
+
+/// A field value read from the wire, before its conversion to JSON.
+enum WireValue<'a> {
+    Varint(u64),
+    Bytes(&'a [u8]),
+}
+
+fn decode_message(schema: &[Field], mut buf: &[u8]) -> Result<Map<String, Value>, Error> {
+    let mut values: Vec<Vec<WireValue<'_>>> = schema.iter().map(|_| Vec::new()).collect();
+
+    while buf.has_remaining() {
+        let (tag, wire_type) = decode_key(&mut buf).map_err(decode_error)?;
+
+        let index = schema
+            .iter()
+            .position(|field| field.tag == tag)
+            .ok_or_else(|| Error::tx_json(format!("unsupported field with tag {tag}")))?;
+
+        let field = &schema[index];
+
+        match wire_type {
+            WireType::Varint if field.kind.is_varint() => {
+                values[index].push(WireValue::Varint(
+                    decode_varint(&mut buf).map_err(decode_error)?,
+                ));
+            }
+            WireType::LengthDelimited => {
+                let len = decode_varint(&mut buf).map_err(decode_error)? as usize;
+                if len > buf.len() {
+                    return Err(Error::tx_json("truncated length-delimited field".into()));
+                }
+
+                let (bytes, rest) = buf.split_at(len);
+                buf = rest;
+
+                if field.kind.is_varint() {
+                    // Packed repeated scalar field
+                    let mut packed = bytes;
+                    while packed.has_remaining() {
+                        values[index].push(WireValue::Varint(
+                            decode_varint(&mut packed).map_err(decode_error)?,
+                        ));
+                    }
+                } else {
+                    values[index].push(WireValue::Bytes(bytes));
+                }
+            }
+            _ => {
+                return Err(Error::tx_json(format!(
+                    "unexpected wire type {:?} for field `{}`",
+                    wire_type, field.name
+                )))
+            }
+        }
+    }
+
+    let mut json = Map::new();
+
+    for (field, values) in schema.iter().zip(values) {
+        match field.label {
+            Label::Repeated => {
+                let values = values
+                    .into_iter()
+                    .map(|value| value_to_json(field, value))
+                    .collect::<Result<_, _>>()?;
+
+                json.insert(field.name.to_string(), Value::Array(values));
+            }
+            Label::Singular | Label::OneOf => match values.into_iter().last() {
+                Some(value) => {
+                    json.insert(field.name.to_string(), value_to_json(field, value)?);
+                }
+                None if field.label == Label::OneOf => {}
+                None => {
+                    json.insert(field.name.to_string(), default_json(field.kind));
+                }
+            },
+        }
+    }
+
+    Ok(json)
+}
+
+fn default_json(kind: Kind) -> Value {
+    match kind {
+        Kind::String | Kind::Bytes => Value::String(String::new()),
+        Kind::Bool => Value::Bool(false),
+        Kind::Int32 | Kind::Uint32 => Value::from(0),
+        Kind::Int64 | Kind::Uint64 => Value::String("0".to_string()),
+        Kind::Enum(names) => enum_to_json(names, 0),
+        Kind::Message(_) | Kind::Any | Kind::Timestamp | Kind::Duration => Value::Null,
+    }
+}
+
+fn enum_to_json(names: &[(i32, &str)], value: i32) -> Value {
+    match names.iter().find(|(number, _)| *number == value) {
+        Some((_, name)) => Value::String(name.to_string()),
+        None => Value::from(value),
+    }
+}
+
+fn value_to_json(field: &Field, value: WireValue<'_>) -> Result<Value, Error> {
+    let json = match (field.kind, value) {
+        (Kind::Bool, WireValue::Varint(v)) => Value::Bool(v != 0),
+        (Kind::Int32, WireValue::Varint(v)) => Value::from(v as i32),
+        (Kind::Uint32, WireValue::Varint(v)) => Value::from(v as u32),
+        (Kind::Int64, WireValue::Varint(v)) => Value::String((v as i64).to_string()),
+        (Kind::Uint64, WireValue::Varint(v)) => Value::String(v.to_string()),
+        (Kind::Enum(names), WireValue::Varint(v)) => enum_to_json(names, v as i32),
+        (Kind::String, WireValue::Bytes(bytes)) => {
+            let string = core::str::from_utf8(bytes).map_err(|_| {
+                Error::tx_json(format!("field `{}` is not valid UTF-8", field.name))
+            })?;
+
+            Value::String(string.to_string())
+        }
+        (Kind::Bytes, WireValue::Bytes(bytes)) => bytes_to_json(bytes)?,
+        (Kind::Message(schema), WireValue::Bytes(bytes)) => {
+            Value::Object(decode_message(schema(), bytes)?)
+        }
+        (Kind::Any, WireValue::Bytes(bytes)) => any_to_json(bytes)?,
+        (Kind::Timestamp, WireValue::Bytes(bytes)) => {
+            let (seconds, nanos) = decode_seconds_and_nanos(bytes)?;
+
+            let time = Time::from_unix_timestamp(seconds, nanos as u32).map_err(|e| {
+                Error::tx_json(format!("invalid timestamp in field `{}`: {e}", field.name))
+            })?;
+
+            Value::String(time.to_rfc3339())
+        }
+        (Kind::Duration, WireValue::Bytes(bytes)) => {
+            let (seconds, nanos) = decode_seconds_and_nanos(bytes)?;
+            Value::String(format_duration(seconds, nanos))
+        }
+        _ => {
+            return Err(Error::tx_json(format!(
+                "unexpected encoding for field `{}`",
+                field.name
+            )))
+        }
+    };
+
+    Ok(json)
+}
+
+fn any_to_json(bytes: &[u8]) -> Result<Value, Error> {
+    const ANY: &[Field] = &[
+        field(1, "type_url", Kind::String),
+        field(2, "value", Kind::Bytes),
+    ];
+
+    let any = decode_message(ANY, bytes)?;
+    let type_url = any["type_url"].as_str().unwrap_or_default();
+
+    let schema = any_schema(type_url)
+        .ok_or_else(|| Error::tx_json(format!("unsupported message type `{type_url}`")))?;
+
+    let value = bytes_from_json(&any["value"])?;
+
+    let mut json = Map::new();
+    json.insert("@type".to_string(), Value::String(type_url.to_string()));
+    json.extend(decode_message(schema, &value)?);
+
+    Ok(Value::Object(json))
+}
+
+fn decode_seconds_and_nanos(bytes: &[u8]) -> Result<(i64, i32), Error> {
+    let json = decode_message(timestamp(), bytes)?;
+
+    let seconds = json["seconds"]
+        .as_str()
+        .and_then(|seconds| seconds.parse().ok())
+        .unwrap_or_default();
+    let nanos = json["nanos"].as_i64().unwrap_or_default() as i32;
+
+    Ok((seconds, nanos))
+}
+
+/// Formats a duration the way the Cosmos SDK does, eg. `1209600s` or `0.5s`.
+fn format_duration(seconds: i64, nanos: i32) -> String {
+    let sign = if seconds < 0 || nanos < 0 { "-" } else { "" };
+    let (seconds, nanos) = (seconds.unsigned_abs(), nanos.unsigned_abs());
+
+    if nanos == 0 {
+        format!("{sign}{seconds}s")
+    } else {
+        let fraction = format!("{nanos:09}");
+        format!("{sign}{seconds}.{}s", fraction.trim_end_matches('0'))
+    }
+}
+
+fn parse_duration(value: &str) -> Option<(i64, i32)> {
+    let (negative, value) = match value.strip_prefix('-') {
+        Some(value) => (true, value),
+        None => (false, value),
+    };
+
+    let (seconds, fraction) = match value.strip_suffix('s')?.split_once('.') {
+        Some((seconds, fraction)) => (seconds, fraction),
+        None => (value.strip_suffix('s')?, ""),
+    };
+
+    if fraction.len() > 9 || !fraction.chars().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+
+    let seconds: i64 = seconds.parse().ok()?;
+    let nanos: i32 = if fraction.is_empty() {
+        0
+    } else {
+        format!("{fraction:0<9}").parse().ok()?
+    };
+
+    if negative {
+        Some((-seconds, -nanos))
+    } else {
+        Some((seconds, nanos))
+    }
+}
+
+fn encode_message(
+    schema: &[Field],
+    json: &Map<String, Value>,
+    buf: &mut Vec<u8>,
+) -> Result<(), Error> {
+    let mut values: Vec<Option<&Value>> = schema.iter().map(|_| None).collect();
+
+    for (key, value) in json {
+        let index = schema
+            .iter()
+            .position(|field| field.name == key || to_camel_case(field.name) == *key)
+            .ok_or_else(|| Error::tx_json(format!("unsupported field `{key}`")))?;
+
+        values[index] = Some(value);
+    }
+
+    for (field, value) in schema.iter().zip(values) {
+        let value = match value {
+            None | Some(Value::Null) => continue,
+            Some(value) => value,
+        };
+
+        match field.label {
+            Label::Repeated => {
+                let values = value.as_array().ok_or_else(|| {
+                    invalid_value(field.name, "expected an array for a repeated field")
+                })?;
+
+                if field.kind.is_varint() {
+                    if values.is_empty() {
+                        continue;
+                    }
+
+                    let mut packed = Vec::new();
+                    for value in values {
+                        encode_varint(varint_from_json(field, value)?, &mut packed);
+                    }
+
+                    encode_key(field.tag, WireType::LengthDelimited, buf);
+                    encode_varint(packed.len() as u64, buf);
+                    buf.extend(packed);
+                } else {
+                    for value in values {
+                        encode_value(field, value, buf)?;
+                    }
+                }
+            }
+            Label::Singular => {
+                if !is_default(field, value)? {
+                    encode_value(field, value, buf)?;
+                }
+            }
+            Label::OneOf => encode_value(field, value, buf)?,
+        }
+    }
+
+    Ok(())
+}
+
+/// Whether the value of a singular field is the default one, and must therefore be omitted.
+/// Messages which are present are always encoded, even if all their fields are unset.
+fn is_default(field: &Field, value: &Value) -> Result<bool, Error> {
+    let is_default = match field.kind {
+        Kind::String => value.as_str() == Some(""),
+        Kind::Bytes => bytes_from_json(value)?.is_empty(),
+        kind if kind.is_varint() => varint_from_json(field, value)? == 0,
+        _ => false,
+    };
+
+    Ok(is_default)
+}
+
+fn encode_value(field: &Field, value: &Value, buf: &mut Vec<u8>) -> Result<(), Error> {
+    if field.kind.is_varint() {
+        encode_key(field.tag, WireType::Varint, buf);
+        encode_varint(varint_from_json(field, value)?, buf);
+        return Ok(());
+    }
+
+    let bytes = match field.kind {
+        Kind::String => value
+            .as_str()
+            .ok_or_else(|| invalid_value(field.name, "expected a string"))?
+            .as_bytes()
+            .to_vec(),
+        Kind::Bytes => bytes_from_json(value)?,
+        Kind::Message(schema) => {
+            let mut bytes = Vec::new();
+            encode_message(schema(), as_object(value, field.name)?, &mut bytes)?;
+            bytes
+        }
+        Kind::Any => any_from_json(value)?,
+        Kind::Timestamp => {
+            let time = value
+                .as_str()
+                .and_then(|time| Time::parse_from_rfc3339(time).ok())
+                .ok_or_else(|| invalid_value(field.name, "expected an RFC 3339 timestamp"))?;
+
+            let seconds = time.unix_timestamp();
+            let nanos = time.unix_timestamp_nanos() - i128::from(seconds) * 1_000_000_000;
+
+            encode_seconds_and_nanos(seconds, nanos as i32)
+        }
+        Kind::Duration => {
+            let (seconds, nanos) = value
+                .as_str()
+                .and_then(parse_duration)
+                .ok_or_else(|| invalid_value(field.name, "expected a duration such as `1.5s`"))?;
+
+            encode_seconds_and_nanos(seconds, nanos)
+        }
+        _ => unreachable!("varint fields are encoded above"),
+    };
+
+    encode_key(field.tag, WireType::LengthDelimited, buf);
+    encode_varint(bytes.len() as u64, buf);
+    buf.extend(bytes);
+
+    Ok(())
+}
+
+fn encode_seconds_and_nanos(seconds: i64, nanos: i32) -> Vec<u8> {
+    let mut buf = Vec::new();
+
+    if seconds != 0 {
+        encode_key(1, WireType::Varint, &mut buf);
+        encode_varint(seconds as u64, &mut buf);
+    }
+
+    if nanos != 0 {
+        encode_key(2, WireType::Varint, &mut buf);
+        encode_varint(nanos as i64 as u64, &mut buf);
+    }
+
+    buf
+}
+
+fn any_from_json(value: &Value) -> Result<Vec<u8>, Error> {
+    let json = as_object(value, "Any")?;
+
+    let type_url = json
+        .get("@type")
+        .and_then(Value::as_str)
+        .ok_or_else(|| missing_field("Any", "@type"))?;
+
+    let schema = any_schema(type_url)
+        .ok_or_else(|| Error::tx_json(format!("unsupported message type `{type_url}`")))?;
+
+    let fields = json
+        .iter()
+        .filter(|(key, _)| key.as_str() != "@type")
+        .map(|(key, value)| (key.clone(), value.clone()))
+        .collect();
+
+    let mut value = Vec::new();
+    encode_message(schema, &fields, &mut value)?;
+
+    let mut buf = Vec::new();
+
+    encode_key(1, WireType::LengthDelimited, &mut buf);
+    encode_varint(type_url.len() as u64, &mut buf);
+    buf.extend(type_url.as_bytes());
+
+    if !value.is_empty() {
+        encode_key(2, WireType::LengthDelimited, &mut buf);
+        encode_varint(value.len() as u64, &mut buf);
+        buf.extend(value);
+    }
+
+    Ok(buf)
+}
+
+/// Reads the value of a varint field, given either as a JSON number or as a string,
+/// as 64-bit integers are represented by strings in the JSON format of the Cosmos SDK.
+fn varint_from_json(field: &Field, value: &Value) -> Result<u64, Error> {
+    let invalid = || invalid_value(field.name, "expected an integer in range");
+
+    let signed = |value: &Value| -> Option<i64> {
+        match value {
+            Value::Number(number) => number.as_i64(),
+            Value::String(string) => string.parse().ok(),
+            _ => None,
+        }
+    };
+
+    let unsigned = |value: &Value| -> Option<u64> {
+        match value {
+            Value::Number(number) => number.as_u64(),
+            Value::String(string) => string.parse().ok(),
+            _ => None,
+        }
+    };
+
+    let varint = match field.kind {
+        Kind::Bool => u64::from(value.as_bool().ok_or_else(invalid)?),
+        Kind::Int32 => {
+            i32::try_from(signed(value).ok_or_else(invalid)?).map_err(|_| invalid())? as i64 as u64
+        }
+        Kind::Uint32 => {
+            u32::try_from(unsigned(value).ok_or_else(invalid)?).map_err(|_| invalid())? as u64
+        }
+        Kind::Int64 => signed(value).ok_or_else(invalid)? as u64,
+        Kind::Uint64 => unsigned(value).ok_or_else(invalid)?,
+        Kind::Enum(names) => {
+            let number = match value {
+                Value::String(name) => names
+                    .iter()
+                    .find(|(_, n)| n == name)
+                    .map(|(number, _)| *number),
+                value => signed(value).and_then(|number| i32::try_from(number).ok()),
+            };
+
+            number.ok_or_else(invalid)? as i64 as u64
+        }
+        _ => return Err(invalid()),
+    };
+
+    Ok(varint)
+}
+
+fn bytes_to_json(bytes: &[u8]) -> Result<Value, Error> {
+    let encoded = String::from_utf8(base64::encode(bytes))
+        .map_err(|e| Error::tx_json(format!("failed to encode bytes in base64: {e}")))?;
+
+    Ok(Value::String(encoded))
+}
+
+fn bytes_from_json(value: &Value) -> Result<Vec<u8>, Error> {
+    let encoded = value
+        .as_str()
+        .ok_or_else(|| Error::tx_json("expected a base64-encoded string".into()))?;
+
+    base64::decode(encoded)
+        .map_err(|e| Error::tx_json(format!("invalid base64-encoded string `{encoded}`: {e}")))
+}
+
+fn as_object<'a>(value: &'a Value, name: &str) -> Result<&'a Map<String, Value>, Error> {
+    value
+        .as_object()
+        .ok_or_else(|| invalid_value(name, "expected an object"))
+}
+
+fn to_camel_case(name: &str) -> String {
+    let mut camel_case = String::with_capacity(name.len());
+    let mut upper = false;
+
+    for c in name.chars() {
+        if c == '_' {
+            upper = true;
+        } else if upper {
+            camel_case.push(c.to_ascii_uppercase());
+            upper = false;
+        } else {
+            camel_case.push(c);
+        }
+    }
+
+    camel_case
+}
+
+fn decode_error(e: prost::DecodeError) -> Error {
+    Error::tx_json(format!("invalid protobuf encoding: {e}"))
+}
+
+fn invalid_value(name: &str, reason: &str) -> Error {
+    Error::tx_json(format!("invalid value for field `{name}`: {reason}"))
+}
+
+fn missing_field(message: &str, name: &str) -> Error {
+    Error::tx_json(format!("missing field `{name}` in {message}"))
+}
+
+fn unknown_field(message: &str, name: &str) -> Error {
+    Error::tx_json(format!("unsupported field `{name}` in {message}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use ibc_proto::cosmos::base::v1beta1::Coin;
+    use ibc_proto::cosmos::tx::v1beta1::mode_info::{Single, Sum};
+    use ibc_proto::cosmos::tx::v1beta1::{AuthInfo, Fee, ModeInfo, SignerInfo, TxBody};
+    use ibc_proto::google::protobuf::Any;
+    use ibc_proto::ibc::applications::transfer::v1::MsgTransfer;
+    use ibc_proto::ibc::core::channel::v1::MsgChannelCloseInit;
+    use ibc_proto::ibc::core::client::v1::{Height, MsgUpdateClient, MsgUpgradeClient};
+    use ibc_relayer_types::clients::ics07_tendermint::client_state::test_util::get_dummy_tendermint_client_state;
+    use ibc_relayer_types::clients::ics07_tendermint::consensus_state::ConsensusState as TmConsensusState;
+    use ibc_relayer_types::clients::ics07_tendermint::header::test_util::{
+        get_dummy_ics07_header, get_dummy_tendermint_header,
+    };
+    use ibc_relayer_types::core::ics04_channel::upgrade::raw::{
+        MsgChannelUpgradeInit, UpgradeFields,
+    };
+    use prost::Message;
+    use serde_json::json;
+
+    use super::*;
+    use crate::chain::cosmos::encode::tx_body_and_bytes;
+    use crate::config::types::Memo;
+    use crate::extension_options::ExtensionOptionDynamicFeeTx;
+
+    #[derive(Clone, PartialEq, Message)]
+    struct LegacyAminoPubKey {
+        #[prost(uint32, tag = "1")]
+        threshold: u32,
+        #[prost(message, repeated, tag = "2")]
+        public_keys: Vec<Any>,
+    }
+
+    fn to_any<M: Message>(type_url: &str, msg: &M) -> Any {
+        Any {
+            type_url: type_url.to_string(),
+            value: msg.encode_to_vec(),
+        }
+    }
+
+    fn sample_tx() -> TxRaw {
+        let transfer = MsgTransfer {
+            source_port: "transfer".to_string(),
+            source_channel: "channel-0".to_string(),
+            token: Some(Coin {
+                denom: "stake".to_string(),
+                amount: "1000".to_string(),
+            }),
+            sender: "cosmos1sender".to_string(),
+            receiver: "cosmos1receiver".to_string(),
+            timeout_height: Some(Height {
+                revision_number: 0,
+                revision_height: 42,
+            }),
+            timeout_timestamp: 0,
+            memo: String::new(),
+        };
+
+        let close_init = MsgChannelCloseInit {
+            port_id: "transfer".to_string(),
+            channel_id: "channel-1".to_string(),
+            signer: "cosmos1signer".to_string(),
+        };
+
+        let body = TxBody {
+            messages: vec![
+                to_any("/ibc.applications.transfer.v1.MsgTransfer", &transfer),
+                to_any("/ibc.core.channel.v1.MsgChannelCloseInit", &close_init),
+            ],
+            memo: "generated by hermes".to_string(),
+            timeout_height: 0,
+            extension_options: vec![],
+            non_critical_extension_options: vec![],
+        };
+
+        let auth_info = AuthInfo {
+            signer_infos: vec![SignerInfo {
+                public_key: Some(to_any("/cosmos.crypto.secp256k1.PubKey", &vec![2u8; 33])),
+                mode_info: Some(ModeInfo {
+                    sum: Some(Sum::Single(Single { mode: 1 })),
+                }),
+                sequence: 7,
+            }],
+            fee: Some(Fee {
+                amount: vec![Coin {
+                    denom: "stake".to_string(),
+                    amount: "2500".to_string(),
+                }],
+                gas_limit: 100_000,
+                payer: String::new(),
+                granter: String::new(),
+            }),
+            tip: None,
+        };
+
+        TxRaw {
+            body_bytes: body.encode_to_vec(),
+            auth_info_bytes: auth_info.encode_to_vec(),
+            signatures: vec![vec![1, 2, 3]],
+        }
+    }
+
+    #[test]
+    fn tx_json_round_trip() {
+        let tx = sample_tx();
+
+        let json = tx_to_json(&tx).unwrap();
+        assert_eq!(tx_from_json(&json).unwrap(), tx);
+    }
+
+    /// A sample of every message supported by `any_schema`, with non-default
+    /// values in all its fields.
+    fn supported_messages() -> Vec<Any> {
+        let tm_header = get_dummy_ics07_header();
+        let client_state: Any =
+            get_dummy_tendermint_client_state(get_dummy_tendermint_header()).into();
+        let consensus_state: Any = TmConsensusState::from(get_dummy_tendermint_header()).into();
+
+        let transfer = MsgTransfer {
+            source_port: "transfer".to_string(),
+            source_channel: "channel-0".to_string(),
+            token: Some(Coin {
+                denom: "stake".to_string(),
+                amount: "1000".to_string(),
+            }),
+            sender: "cosmos1sender".to_string(),
+            receiver: "cosmos1receiver".to_string(),
+            timeout_height: Some(Height {
+                revision_number: 1,
+                revision_height: 42,
+            }),
+            timeout_timestamp: 1_700_000_000_000_000_000,
+            memo: "memo".to_string(),
+        };
+
+        let close_init = MsgChannelCloseInit {
+            port_id: "transfer".to_string(),
+            channel_id: "channel-1".to_string(),
+            signer: "cosmos1signer".to_string(),
+        };
+
+        let upgrade_init = MsgChannelUpgradeInit {
+            port_id: "transfer".to_string(),
+            channel_id: "channel-1".to_string(),
+            fields: Some(UpgradeFields {
+                ordering: 2,
+                connection_hops: vec!["connection-1".to_string()],
+                version: "ics20-1".to_string(),
+            }),
+            signer: "cosmos1authority".to_string(),
+        };
+
+        let update_client = MsgUpdateClient {
+            client_id: "07-tendermint-0".to_string(),
+            header: Some(tm_header.clone().into()),
+            signer: "cosmos1signer".to_string(),
+        };
+
+        let upgrade_client = MsgUpgradeClient {
+            client_id: "07-tendermint-0".to_string(),
+            client_state: Some(client_state.clone()),
+            consensus_state: Some(consensus_state.clone()),
+            proof_upgrade_client: vec![1, 2, 3],
+            proof_upgrade_consensus_state: vec![4, 5, 6],
+            signer: "cosmos1signer".to_string(),
+        };
+
+        let secp256k1 = to_any("/cosmos.crypto.secp256k1.PubKey", &vec![2u8; 33]);
+        let multisig = LegacyAminoPubKey {
+            threshold: 2,
+            public_keys: vec![
+                secp256k1.clone(),
+                to_any("/cosmos.crypto.secp256k1.PubKey", &vec![3u8; 33]),
+            ],
+        };
+
+        vec![
+            to_any("/cosmos.crypto.multisig.LegacyAminoPubKey", &multisig),
+            ExtensionOptionDynamicFeeTx {
+                max_priority_price: "1000".to_string(),
+            }
+            .to_any()
+            .unwrap(),
+            to_any("/ibc.applications.transfer.v1.MsgTransfer", &transfer),
+            to_any("/ibc.core.channel.v1.MsgChannelCloseInit", &close_init),
+            to_any("/ibc.core.channel.v1.MsgChannelUpgradeInit", &upgrade_init),
+            to_any("/ibc.core.client.v1.MsgUpdateClient", &update_client),
+            to_any("/ibc.core.client.v1.MsgUpgradeClient", &upgrade_client),
+            client_state,
+            consensus_state,
+            tm_header.into(),
+            secp256k1,
+            to_any("/cosmos.crypto.ed25519.PubKey", &vec![4u8; 32]),
+            to_any("/ethermint.crypto.v1.ethsecp256k1.PubKey", &vec![5u8; 33]),
+        ]
+    }
+
+    #[test]
+    fn supported_messages_round_trip() {
+        let memo = Memo::new("generated by hermes").unwrap();
+
+        for msg in supported_messages() {
+            assert!(any_schema(&msg.type_url).is_some(), "{}", msg.type_url);
+
+            // Extension options are carried in their own field of the body
+            let (messages, extension_options) = if msg
+                .type_url
+                .starts_with("/ethermint.types.v1.ExtensionOption")
+            {
+                (vec![], vec![msg.clone()])
+            } else {
+                (vec![msg.clone()], vec![])
+            };
+
+            let (_, body_bytes) = tx_body_and_bytes(&messages, &memo, extension_options).unwrap();
+
+            let tx = TxRaw {
+                body_bytes,
+                auth_info_bytes: vec![],
+                signatures: vec![],
+            };
+
+            let json = tx_to_json(&tx).unwrap();
+            assert_eq!(tx_from_json(&json).unwrap(), tx, "{}", msg.type_url);
+        }
+    }
+
+    #[test]
+    fn tx_to_json_uses_sdk_format() {
+        let json = tx_to_json(&sample_tx()).unwrap();
+
+        let transfer = &json["body"]["messages"][0];
+        assert_eq!(
+            transfer["@type"],
+            json!("/ibc.applications.transfer.v1.MsgTransfer")
+        );
+        assert_eq!(
+            transfer["timeout_height"],
+            json!({ "revision_number": "0", "revision_height": "42" })
+        );
+        assert_eq!(transfer["timeout_timestamp"], json!("0"));
+
+        let signer_info = &json["auth_info"]["signer_infos"][0];
+        assert_eq!(
+            signer_info["mode_info"],
+            json!({ "single": { "mode": "SIGN_MODE_DIRECT" } })
+        );
+        assert_eq!(signer_info["sequence"], json!("7"));
+        assert_eq!(json["auth_info"]["tip"], Value::Null);
+        assert_eq!(json["signatures"], json!(["AQID"]));
+    }
+
+    #[test]
+    fn tx_from_json_accepts_camel_case() {
+        let json = json!({
+            "body": {
+                "messages": [{
+                    "@type": "/ibc.core.channel.v1.MsgChannelCloseInit",
+                    "portId": "transfer",
+                    "channelId": "channel-1",
+                    "signer": "cosmos1signer"
+                }]
+            },
+            "authInfo": {
+                "signerInfos": [],
+                "fee": { "amount": [], "gasLimit": "200000" }
+            },
+            "signatures": []
+        });
+
+        let tx = tx_from_json(&json).unwrap();
+        let body = TxBody::decode(tx.body_bytes.as_slice()).unwrap();
+        let auth_info = AuthInfo::decode(tx.auth_info_bytes.as_slice()).unwrap();
+
+        let msg = MsgChannelCloseInit::decode(body.messages[0].value.as_slice()).unwrap();
+        assert_eq!(msg.channel_id, "channel-1");
+        assert_eq!(auth_info.fee.unwrap().gas_limit, 200_000);
+    }
+
+    #[test]
+    fn unsupported_message_is_rejected() {
+        let mut tx = sample_tx();
+
+        let body = TxBody {
+            messages: vec![to_any("/cosmos.bank.v1beta1.MsgSend", &vec![1u8])],
+            ..Default::default()
+        };
+        tx.body_bytes = body.encode_to_vec();
+
+        assert!(tx_to_json(&tx).is_err());
+    }
+
+    #[test]
+    fn messages_signer_is_replaced() {
+        let mut json = tx_to_json(&sample_tx()).unwrap();
+        set_messages_signer(&mut json, "cosmos1multisig");
+
+        let messages = &json["body"]["messages"];
+        assert_eq!(messages[0]["sender"], json!("cosmos1multisig"));
+        assert_eq!(messages[0]["receiver"], json!("cosmos1receiver"));
+        assert_eq!(messages[1]["signer"], json!("cosmos1multisig"));
+    }
+
+    #[test]
+    fn durations_round_trip() {
+        for (seconds, nanos, formatted) in [
+            (1_209_600, 0, "1209600s"),
+            (0, 500_000_000, "0.5s"),
+            (3, 1, "3.000000001s"),
+            (-2, -250_000_000, "-2.25s"),
+        ] {
+            assert_eq!(format_duration(seconds, nanos), formatted);
+            assert_eq!(parse_duration(formatted), Some((seconds, nanos)));
+        }
+    }
+}
@@ -1,3 +1,4 @@
+use alloc::borrow::Cow;
 use core::str::FromStr;
 use core::time::Duration;
 use http::Uri;
@@ -51,3 +52,23 @@ impl<'a> TryFrom<&'a ChainConfig> for TxConfig {
         })
     }
 }
+
+impl TxConfig {
+    /// This configuration, with the limits on the number of messages per transaction
+    /// and on the size of each transaction replaced by the given ones, if any.
+    pub fn with_batch_limits(
+        &self,
+        max_msg_num: Option<MaxMsgNum>,
+        max_tx_size: Option<MaxTxSize>,
+    ) -> Cow<'_, Self> {
+        if max_msg_num.is_none() && max_tx_size.is_none() {
+            return Cow::Borrowed(self);
+        }
+
+        let mut config = self.clone();
+        config.max_msg_num = max_msg_num.unwrap_or(self.max_msg_num);
+        config.max_tx_size = max_tx_size.unwrap_or(self.max_tx_size);
+
+        Cow::Owned(config)
+    }
+}
@@ -1,6 +1,7 @@
 use ibc_proto::cosmos::tx::v1beta1::Fee;
 
 use crate::chain::cosmos::calculate_fee;
+use crate::config::dynamic_gas::DynamicGasPrice;
 use crate::config::{ChainConfig, GasPrice};
 
 /// Default gas limit when submitting a transaction.
@@ -14,6 +15,7 @@ pub struct GasConfig {
     pub max_gas: u64,
     pub gas_multiplier: f64,
     pub gas_price: GasPrice,
+    pub dynamic_gas_price: DynamicGasPrice,
     pub max_fee: Fee,
     pub fee_granter: String,
 }
@@ -25,6 +27,7 @@ impl<'a> From<&'a ChainConfig> for GasConfig {
             max_gas: max_gas_from_config(config),
             gas_multiplier: gas_multiplier_from_config(config),
             gas_price: config.gas_price.clone(),
+            dynamic_gas_price: config.dynamic_gas_price,
             max_fee: max_fee_from_config(config),
             fee_granter: fee_granter_from_config(config),
         }
@@ -1,5 +1,6 @@
 use ibc_proto::cosmos::tx::v1beta1::{AuthInfo, TxBody};
 use tendermint_rpc::endpoint::broadcast::tx_sync::Response;
+use tendermint_rpc::endpoint::tx::Response as TxResponse;
 
 use crate::event::IbcEventWithHeight;
 
@@ -22,4 +23,6 @@ pub struct TxSyncResult {
     // the events generated by a Tx once executed
     pub events: Vec<IbcEventWithHeight>,
     pub status: TxStatus,
+    // the response of the query for the Tx once committed, until its fees are accounted for
+    pub committed: Option<TxResponse>,
 }
@@ -111,6 +111,8 @@ async fn update_tx_sync_result(
                     .flat_map(|event| from_tx_response_event(height, event))
                     .collect::<Vec<_>>();
             }
+
+            tx_sync_result.committed = Some(response);
         }
     }
 
@@ -0,0 +1,203 @@
+//! Pool of wallets used to submit transactions to a chain.
+//!
+//! When a chain is configured with several keys, transactions are spread across
+//! the accounts of these keys, so that the relayer does not serialize all its
+//! transactions on the sequence number of a single account. The keys of the
+//! wallets are loaded once, when the pool is created, and each wallet caches
+//! its own account information, including its sequence number.
+//!
+//! The wallet submitting some messages is chosen when the messages are built,
+//! by using the address of an idle wallet as their signer, see [`WalletPool::select_idle`].
+//! This signer is recorded along with the messages in their
+//! [`TrackedMsgs`](crate::chain::tracking::TrackedMsgs), which are then submitted
+//! by the wallet with this address, see [`WalletPool::signing_wallet`].
+
+use std::time::Instant;
+
+use ibc_relayer_types::signer::Signer;
+
+use crate::chain::cosmos::types::account::Account;
+use crate::keyring::{Secp256k1KeyPair, SigningKeyPair};
+
+#[derive(Debug)]
+struct Wallet {
+    key_name: String,
+    key_pair: Secp256k1KeyPair,
+    address: String,
+    /// A cached copy of the account information
+    account: Option<Account>,
+    /// Until when the last transaction submitted by the wallet is expected
+    /// to be in flight, ie. not yet included in a block
+    in_flight_until: Option<Instant>,
+}
+
+impl Wallet {
+    fn is_idle(&self, now: Instant) -> bool {
+        self.in_flight_until.map_or(true, |until| until <= now)
+    }
+}
+
+#[derive(Debug)]
+pub struct WalletPool {
+    wallets: Vec<Wallet>,
+    next: usize,
+}
+
+impl WalletPool {
+    /// Create a pool with the given keys, indexed by key name, the first one being
+    /// the primary wallet.
+    ///
+    /// ## Panics
+    /// If no key is given.
+    pub fn new(keys: Vec<(String, Secp256k1KeyPair)>) -> Self {
+        assert!(!keys.is_empty(), "a wallet pool needs at least one key");
+
+        Self {
+            wallets: keys
+                .into_iter()
+                .map(|(key_name, key_pair)| Wallet {
+                    key_name,
+                    address: key_pair.account(),
+                    key_pair,
+                    account: None,
+                    in_flight_until: None,
+                })
+                .collect(),
+            next: 0,
+        }
+    }
+
+    /// The keys of the wallets, indexed by key name.
+    pub fn keys(&self) -> impl Iterator<Item = (&str, &Secp256k1KeyPair)> {
+        self.wallets
+            .iter()
+            .map(|wallet| (wallet.key_name.as_str(), &wallet.key_pair))
+    }
+
+    pub fn key_name(&self, index: usize) -> &str {
+        &self.wallets[index].key_name
+    }
+
+    pub fn key_pair(&self, index: usize) -> &Secp256k1KeyPair {
+        &self.wallets[index].key_pair
+    }
+
+    pub fn address(&self, index: usize) -> &str {
+        &self.wallets[index].address
+    }
+
+    /// The cached account of the wallet at the given index.
+    pub fn account_mut(&mut self, index: usize) -> &mut Option<Account> {
+        &mut self.wallets[index].account
+    }
+
+    /// Select the wallet to sign the next messages with.
+    ///
+    /// The idle wallets are selected in turn. If no wallet is idle, the wallet
+    /// whose transaction is expected to be included in a block first is selected.
+    pub fn select_idle(&mut self, now: Instant) -> usize {
+        let len = self.wallets.len();
+
+        let index = (0..len)
+            .map(|offset| (self.next + offset) % len)
+            .find(|&index| self.wallets[index].is_idle(now))
+            .unwrap_or_else(|| {
+                (0..len)
+                    .min_by_key(|&index| self.wallets[index].in_flight_until)
+                    .unwrap_or(0)
+            });
+
+        self.next = (index + 1) % len;
+        index
+    }
+
+    /// Record that the wallet at the given index has a transaction in flight
+    /// until the given instant, or that it is idle if `None`.
+    pub fn set_in_flight(&mut self, index: usize, until: Option<Instant>) {
+        self.wallets[index].in_flight_until = until;
+    }
+
+    /// Find the index of the wallet which must submit messages signed by the
+    /// given signer, or the primary wallet if the signer is unknown or is not
+    /// the address of any wallet of the pool.
+    pub fn signing_wallet(&self, signer: Option<&Signer>) -> usize {
+        signer
+            .and_then(|signer| {
+                self.wallets
+                    .iter()
+                    .position(|wallet| wallet.address == signer.as_ref())
+            })
+            .unwrap_or(0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use core::str::FromStr;
+    use core::time::Duration;
+
+    use hdpath::StandardHDPath;
+
+    use crate::config::AddressType;
+
+    const MNEMONIC: &str = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+
+    fn pool(len: u32) -> WalletPool {
+        let keys = (0..len)
+            .map(|index| {
+                let hd_path =
+                    StandardHDPath::from_str(&format!("m/44'/118'/0'/0/{index}")).unwrap();
+                let key_pair = Secp256k1KeyPair::from_mnemonic(
+                    MNEMONIC,
+                    &hd_path,
+                    &AddressType::Cosmos,
+                    "cosmos",
+                )
+                .unwrap();
+
+                (format!("key-{index}"), key_pair)
+            })
+            .collect();
+
+        WalletPool::new(keys)
+    }
+
+    #[test]
+    fn select_idle_wallets_in_turn() {
+        let mut pool = pool(3);
+        let now = Instant::now();
+
+        assert_eq!(pool.select_idle(now), 0);
+        pool.set_in_flight(0, Some(now + Duration::from_secs(10)));
+        pool.set_in_flight(2, Some(now + Duration::from_secs(5)));
+
+        // Only the second wallet is idle
+        assert_eq!(pool.select_idle(now), 1);
+        assert_eq!(pool.select_idle(now), 1);
+
+        // No wallet is idle, the third one is expected to be idle first
+        pool.set_in_flight(1, Some(now + Duration::from_secs(20)));
+        assert_eq!(pool.select_idle(now), 2);
+
+        // The third wallet is idle again
+        assert_eq!(pool.select_idle(now + Duration::from_secs(5)), 2);
+        assert_eq!(pool.select_idle(now + Duration::from_secs(10)), 0);
+    }
+
+    #[test]
+    fn messages_are_submitted_by_their_signer() {
+        let pool = pool(2);
+
+        let signer = |address: &str| Signer::from_str(address).unwrap();
+
+        assert_eq!(pool.signing_wallet(Some(&signer(pool.address(1)))), 1);
+        assert_eq!(pool.signing_wallet(Some(&signer(pool.address(0)))), 0);
+
+        // Messages whose signer is unknown or is none of the wallets are submitted
+        // by the primary wallet
+        assert_eq!(pool.signing_wallet(None), 0);
+        assert_eq!(pool.signing_wallet(Some(&signer("cosmos1other"))), 0);
+    }
+}
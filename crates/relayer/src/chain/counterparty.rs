@@ -595,13 +595,16 @@ pub fn unreceived_acknowledgements(
 
 /// A structure to display pending packet commitment IDs
 /// at one end of a channel.
-#[derive(Debug, Serialize)]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub struct PendingPackets {
     /// Not yet received on the counterparty chain.
     pub unreceived_packets: Vec<Sequence>,
     /// Received on the counterparty chain,
     /// but the acknowledgement is not yet received on the local chain.
     pub unreceived_acks: Vec<Sequence>,
+    /// Pending packets, either unreceived or unacknowledged, which will not be
+    /// relayed because their sequence is excluded in the local chain configuration.
+    pub excluded: Vec<Sequence>,
 }
 
 pub fn pending_packet_summary(
@@ -643,8 +646,21 @@ pub fn pending_packet_summary(
         Vec::new()
     };
 
+    let excluded_sequences = chain.config().map_err(Error::relayer)?.excluded_sequences;
+
+    let mut excluded: Vec<_> = unreceived
+        .iter()
+        .chain(pending_acks.iter())
+        .copied()
+        .filter(|seq| excluded_sequences.is_excluded(&channel.channel_id, *seq))
+        .collect();
+
+    excluded.sort_unstable();
+    excluded.dedup();
+
     Ok(PendingPackets {
         unreceived_packets: unreceived,
         unreceived_acks: pending_acks,
+        excluded,
     })
 }
@@ -3,6 +3,7 @@ use core::convert::TryFrom;
 
 use tokio::runtime::Runtime as TokioRuntime;
 
+use ibc_proto::cosmos::tx::v1beta1::Fee;
 use ibc_proto::ibc::apps::fee::v1::{
     QueryIncentivizedPacketRequest, QueryIncentivizedPacketResponse,
 };
@@ -17,6 +18,7 @@ use ibc_relayer_types::core::ics03_connection::connection::{
 use ibc_relayer_types::core::ics03_connection::version::{get_compatible_versions, Version};
 use ibc_relayer_types::core::ics04_channel::channel::{ChannelEnd, IdentifiedChannelEnd};
 use ibc_relayer_types::core::ics04_channel::packet::{PacketMsgType, Sequence};
+use ibc_relayer_types::core::ics04_channel::upgrade::{ErrorReceipt, Upgrade};
 use ibc_relayer_types::core::ics23_commitment::commitment::{
     CommitmentPrefix, CommitmentProofBytes,
 };
@@ -100,6 +102,21 @@ pub trait ChainEndpoint: Sized {
     /// Perform a health check
     fn health_check(&self) -> Result<HealthCheck, Error>;
 
+    /// Fail over to another node of the chain if the one in use was found unhealthy
+    /// by the last health check of the nodes, which must not block. Only relevant for
+    /// chains configured with `backup_endpoints`, and called by the chain runtime
+    /// every `endpoint_health_check_interval`.
+    fn refresh_endpoints(&mut self) -> Result<(), Error> {
+        Ok(())
+    }
+
+    /// Switch to another healthy node of the chain after a request to the node in use
+    /// failed, for the chain runtime to retry the request if it is a query, or to send
+    /// the next requests to. Returns `false` if there is no other healthy node to switch to.
+    fn fail_over(&mut self) -> Result<bool, Error> {
+        Ok(false)
+    }
+
     // Events
     fn subscribe(&mut self) -> Result<Subscription, Error>;
 
@@ -113,6 +130,12 @@ pub trait ChainEndpoint: Sized {
 
     fn get_signer(&self) -> Result<Signer, Error>;
 
+    /// Select the signer of the next messages to submit, which may differ from
+    /// the one returned by `get_signer` when transactions are submitted from several wallets
+    fn select_signer(&mut self) -> Result<Signer, Error> {
+        self.get_signer()
+    }
+
     /// Get the signing key pair
     fn get_key(&mut self) -> Result<Self::SigningKeyPair, Error> {
         // Get the key from key seed file
@@ -124,6 +147,23 @@ pub trait ChainEndpoint: Sized {
         Ok(key_pair)
     }
 
+    /// Get the signing key pairs of the wallets used to submit transactions,
+    /// indexed by key name, starting with the key given by `key_name`
+    fn get_wallet_keys(&mut self) -> Result<Vec<(String, Self::SigningKeyPair)>, Error> {
+        self.config()
+            .wallet_key_names()
+            .into_iter()
+            .map(|key_name| {
+                let key_pair = self
+                    .keybase()
+                    .get_key(&key_name)
+                    .map_err(|e| Error::key_not_found(key_name.clone(), e))?;
+
+                Ok((key_name, key_pair))
+            })
+            .collect()
+    }
+
     fn add_key(&mut self, key_name: &str, key_pair: Self::SigningKeyPair) -> Result<(), Error> {
         self.keybase_mut()
             .add_key(key_name, key_pair)
@@ -153,6 +193,10 @@ pub trait ChainEndpoint: Sized {
         tracked_msgs: TrackedMsgs,
     ) -> Result<Vec<TxResponse>, Error>;
 
+    /// Estimates the fee to pay for a transaction with `msgs`, by simulating it.
+    /// Nothing is submitted to the chain.
+    fn estimate_tx_fee(&mut self, tracked_msgs: TrackedMsgs) -> Result<Fee, Error>;
+
     /// Fetch a header from the chain at the given height and verify it.
     fn verify_header(
         &mut self,
@@ -271,6 +315,21 @@ pub trait ChainEndpoint: Sized {
         include_proof: IncludeProof,
     ) -> Result<(ChannelEnd, Option<MerkleProof>), Error>;
 
+    /// Performs a query to retrieve the upgrade proposed by the given channel end, if any.
+    fn query_upgrade(
+        &self,
+        request: QueryUpgradeRequest,
+        include_proof: IncludeProof,
+    ) -> Result<(Upgrade, Option<MerkleProof>), Error>;
+
+    /// Performs a query to retrieve the error receipt of the last upgrade
+    /// aborted by the given channel end, if any.
+    fn query_upgrade_error(
+        &self,
+        request: QueryUpgradeErrorRequest,
+        include_proof: IncludeProof,
+    ) -> Result<(ErrorReceipt, Option<MerkleProof>), Error>;
+
     /// Performs a query to retrieve the client state for the channel associated
     /// with a given channel identifier.
     fn query_channel_client_state(
@@ -527,6 +586,83 @@ pub trait ChainEndpoint: Sized {
             .map_err(Error::malformed_proof)
     }
 
+    /// Builds the proofs for the channel upgrade handshake messages, ie. the proof of
+    /// the channel end and the proof of the upgrade proposed by the channel end.
+    fn build_channel_upgrade_proofs(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        height: ICSHeight,
+    ) -> Result<Proofs, Error> {
+        let (_, maybe_channel_proof) = self.query_channel(
+            QueryChannelRequest {
+                port_id: port_id.clone(),
+                channel_id: channel_id.clone(),
+                height: QueryHeight::Specific(height),
+            },
+            IncludeProof::Yes,
+        )?;
+
+        let Some(channel_proof) = maybe_channel_proof else {
+            return Err(Error::queried_proof_not_found());
+        };
+
+        let (_, maybe_upgrade_proof) = self.query_upgrade(
+            QueryUpgradeRequest {
+                port_id: port_id.clone(),
+                channel_id: channel_id.clone(),
+                height: QueryHeight::Specific(height),
+            },
+            IncludeProof::Yes,
+        )?;
+
+        let Some(upgrade_proof) = maybe_upgrade_proof else {
+            return Err(Error::queried_proof_not_found());
+        };
+
+        Proofs::new(
+            CommitmentProofBytes::try_from(channel_proof).map_err(Error::malformed_proof)?,
+            None,
+            None,
+            Some(CommitmentProofBytes::try_from(upgrade_proof).map_err(Error::malformed_proof)?),
+            height.increment(),
+        )
+        .map_err(Error::malformed_proof)
+    }
+
+    /// Builds the proof of the error receipt of the last upgrade aborted by
+    /// the given channel end, for the channel upgrade cancel message.
+    fn build_channel_upgrade_error_proofs(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        height: ICSHeight,
+    ) -> Result<(ErrorReceipt, Proofs), Error> {
+        let (error_receipt, maybe_error_receipt_proof) = self.query_upgrade_error(
+            QueryUpgradeErrorRequest {
+                port_id: port_id.clone(),
+                channel_id: channel_id.clone(),
+                height: QueryHeight::Specific(height),
+            },
+            IncludeProof::Yes,
+        )?;
+
+        let Some(error_receipt_proof) = maybe_error_receipt_proof else {
+            return Err(Error::queried_proof_not_found());
+        };
+
+        let proofs = Proofs::new(
+            CommitmentProofBytes::try_from(error_receipt_proof).map_err(Error::malformed_proof)?,
+            None,
+            None,
+            None,
+            height.increment(),
+        )
+        .map_err(Error::malformed_proof)?;
+
+        Ok((error_receipt, proofs))
+    }
+
     /// Builds the proof for packet messages.
     fn build_packet_proofs(
         &self,
@@ -4,6 +4,7 @@ use core::fmt::{self, Debug, Display};
 use crossbeam_channel as channel;
 use tracing::Span;
 
+use ibc_proto::cosmos::tx::v1beta1::Fee;
 use ibc_proto::ibc::apps::fee::v1::{
     QueryIncentivizedPacketRequest, QueryIncentivizedPacketResponse,
 };
@@ -18,6 +19,7 @@ use ibc_relayer_types::{
         ics04_channel::{
             channel::{ChannelEnd, IdentifiedChannelEnd},
             packet::{PacketMsgType, Sequence},
+            upgrade::{ErrorReceipt, Upgrade},
         },
         ics23_commitment::{commitment::CommitmentPrefix, merkle::MerkleProof},
         ics24_host::identifier::{ChainId, ChannelId, ClientId, ConnectionId, PortId},
@@ -123,6 +125,11 @@ pub enum ChainRequest {
         reply_to: ReplyTo<Vec<tendermint_rpc::endpoint::broadcast::tx_sync::Response>>,
     },
 
+    EstimateTxFee {
+        tracked_msgs: TrackedMsgs,
+        reply_to: ReplyTo<Fee>,
+    },
+
     Config {
         reply_to: ReplyTo<ChainConfig>,
     },
@@ -131,10 +138,18 @@ pub enum ChainRequest {
         reply_to: ReplyTo<Signer>,
     },
 
+    SelectSigner {
+        reply_to: ReplyTo<Signer>,
+    },
+
     GetKey {
         reply_to: ReplyTo<AnySigningKeyPair>,
     },
 
+    GetWalletKeys {
+        reply_to: ReplyTo<Vec<(String, AnySigningKeyPair)>>,
+    },
+
     AddKey {
         key_name: String,
         key: AnySigningKeyPair,
@@ -271,6 +286,18 @@ pub enum ChainRequest {
         reply_to: ReplyTo<(ChannelEnd, Option<MerkleProof>)>,
     },
 
+    QueryUpgrade {
+        request: QueryUpgradeRequest,
+        include_proof: IncludeProof,
+        reply_to: ReplyTo<(Upgrade, Option<MerkleProof>)>,
+    },
+
+    QueryUpgradeError {
+        request: QueryUpgradeErrorRequest,
+        include_proof: IncludeProof,
+        reply_to: ReplyTo<(ErrorReceipt, Option<MerkleProof>)>,
+    },
+
     QueryChannelClientState {
         request: QueryChannelClientStateRequest,
         reply_to: ReplyTo<Option<IdentifiedAnyClientState>>,
@@ -289,6 +316,20 @@ pub enum ChainRequest {
         reply_to: ReplyTo<Proofs>,
     },
 
+    BuildChannelUpgradeProofs {
+        port_id: PortId,
+        channel_id: ChannelId,
+        height: Height,
+        reply_to: ReplyTo<Proofs>,
+    },
+
+    BuildChannelUpgradeErrorProofs {
+        port_id: PortId,
+        channel_id: ChannelId,
+        height: Height,
+        reply_to: ReplyTo<(ErrorReceipt, Proofs)>,
+    },
+
     BuildPacketProofs {
         packet_type: PacketMsgType,
         port_id: PortId,
@@ -400,12 +441,23 @@ pub trait ChainHandle: Clone + Display + Send + Sync + Debug + 'static {
         tracked_msgs: TrackedMsgs,
     ) -> Result<Vec<tendermint_rpc::endpoint::broadcast::tx_sync::Response>, Error>;
 
+    /// Estimate the fee to pay for a transaction with the given `msgs`,
+    /// by simulating it, without submitting it.
+    fn estimate_tx_fee(&self, tracked_msgs: TrackedMsgs) -> Result<Fee, Error>;
+
     fn get_signer(&self) -> Result<Signer, Error>;
 
+    /// Select the signer of the next messages to submit, ie. the address of an idle
+    /// wallet when transactions are submitted from several wallets.
+    fn select_signer(&self) -> Result<Signer, Error>;
+
     fn config(&self) -> Result<ChainConfig, Error>;
 
     fn get_key(&self) -> Result<AnySigningKeyPair, Error>;
 
+    /// Return the keys of the wallets used to submit transactions, indexed by key name.
+    fn get_wallet_keys(&self) -> Result<Vec<(String, AnySigningKeyPair)>, Error>;
+
     fn add_key(&self, key_name: String, key: AnySigningKeyPair) -> Result<(), Error>;
 
     /// Return the version of the IBC protocol that this chain is running, if known.
@@ -525,6 +577,21 @@ pub trait ChainHandle: Clone + Display + Send + Sync + Debug + 'static {
         include_proof: IncludeProof,
     ) -> Result<(ChannelEnd, Option<MerkleProof>), Error>;
 
+    /// Performs a query to retrieve the upgrade proposed by the given channel end, if any.
+    fn query_upgrade(
+        &self,
+        request: QueryUpgradeRequest,
+        include_proof: IncludeProof,
+    ) -> Result<(Upgrade, Option<MerkleProof>), Error>;
+
+    /// Performs a query to retrieve the error receipt of the last upgrade
+    /// aborted by the given channel end, if any.
+    fn query_upgrade_error(
+        &self,
+        request: QueryUpgradeErrorRequest,
+        include_proof: IncludeProof,
+    ) -> Result<(ErrorReceipt, Option<MerkleProof>), Error>;
+
     /// Performs a query to retrieve the client state for the channel associated
     /// with a given channel identifier.
     fn query_channel_client_state(
@@ -575,6 +642,20 @@ pub trait ChainHandle: Clone + Display + Send + Sync + Debug + 'static {
         height: Height,
     ) -> Result<Proofs, Error>;
 
+    fn build_channel_upgrade_proofs(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        height: Height,
+    ) -> Result<Proofs, Error>;
+
+    fn build_channel_upgrade_error_proofs(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        height: Height,
+    ) -> Result<(ErrorReceipt, Proofs), Error>;
+
     fn build_packet_proofs(
         &self,
         packet_type: PacketMsgType,
@@ -3,6 +3,7 @@ use core::fmt::{Debug, Display, Error as FmtError, Formatter};
 use crossbeam_channel as channel;
 use tracing::Span;
 
+use ibc_proto::cosmos::tx::v1beta1::Fee;
 use ibc_proto::ibc::apps::fee::v1::{
     QueryIncentivizedPacketRequest, QueryIncentivizedPacketResponse,
 };
@@ -14,6 +15,7 @@ use ibc_relayer_types::{
         ics03_connection::version::Version,
         ics04_channel::channel::{ChannelEnd, IdentifiedChannelEnd},
         ics04_channel::packet::{PacketMsgType, Sequence},
+        ics04_channel::upgrade::{ErrorReceipt, Upgrade},
         ics23_commitment::{commitment::CommitmentPrefix, merkle::MerkleProof},
         ics24_host::identifier::ChainId,
         ics24_host::identifier::ChannelId,
@@ -125,10 +127,21 @@ impl ChainHandle for BaseChainHandle {
         })
     }
 
+    fn estimate_tx_fee(&self, tracked_msgs: TrackedMsgs) -> Result<Fee, Error> {
+        self.send(|reply_to| ChainRequest::EstimateTxFee {
+            tracked_msgs,
+            reply_to,
+        })
+    }
+
     fn get_signer(&self) -> Result<Signer, Error> {
         self.send(|reply_to| ChainRequest::Signer { reply_to })
     }
 
+    fn select_signer(&self) -> Result<Signer, Error> {
+        self.send(|reply_to| ChainRequest::SelectSigner { reply_to })
+    }
+
     fn config(&self) -> Result<ChainConfig, Error> {
         self.send(|reply_to| ChainRequest::Config { reply_to })
     }
@@ -137,6 +150,10 @@ impl ChainHandle for BaseChainHandle {
         self.send(|reply_to| ChainRequest::GetKey { reply_to })
     }
 
+    fn get_wallet_keys(&self) -> Result<Vec<(String, AnySigningKeyPair)>, Error> {
+        self.send(|reply_to| ChainRequest::GetWalletKeys { reply_to })
+    }
+
     fn add_key(&self, key_name: String, key: AnySigningKeyPair) -> Result<(), Error> {
         self.send(|reply_to| ChainRequest::AddKey {
             key_name,
@@ -297,6 +314,30 @@ impl ChainHandle for BaseChainHandle {
         })
     }
 
+    fn query_upgrade(
+        &self,
+        request: QueryUpgradeRequest,
+        include_proof: IncludeProof,
+    ) -> Result<(Upgrade, Option<MerkleProof>), Error> {
+        self.send(|reply_to| ChainRequest::QueryUpgrade {
+            request,
+            include_proof,
+            reply_to,
+        })
+    }
+
+    fn query_upgrade_error(
+        &self,
+        request: QueryUpgradeErrorRequest,
+        include_proof: IncludeProof,
+    ) -> Result<(ErrorReceipt, Option<MerkleProof>), Error> {
+        self.send(|reply_to| ChainRequest::QueryUpgradeError {
+            request,
+            include_proof,
+            reply_to,
+        })
+    }
+
     fn query_channel_client_state(
         &self,
         request: QueryChannelClientStateRequest,
@@ -388,6 +429,34 @@ impl ChainHandle for BaseChainHandle {
         })
     }
 
+    fn build_channel_upgrade_proofs(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        height: Height,
+    ) -> Result<Proofs, Error> {
+        self.send(|reply_to| ChainRequest::BuildChannelUpgradeProofs {
+            port_id: port_id.clone(),
+            channel_id: channel_id.clone(),
+            height,
+            reply_to,
+        })
+    }
+
+    fn build_channel_upgrade_error_proofs(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        height: Height,
+    ) -> Result<(ErrorReceipt, Proofs), Error> {
+        self.send(|reply_to| ChainRequest::BuildChannelUpgradeErrorProofs {
+            port_id: port_id.clone(),
+            channel_id: channel_id.clone(),
+            height,
+            reply_to,
+        })
+    }
+
     fn build_packet_proofs(
         &self,
         packet_type: PacketMsgType,
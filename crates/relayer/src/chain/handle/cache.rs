@@ -2,6 +2,7 @@ use core::fmt::{Display, Error as FmtError, Formatter};
 use crossbeam_channel as channel;
 use tracing::Span;
 
+use ibc_proto::cosmos::tx::v1beta1::Fee;
 use ibc_proto::ibc::apps::fee::v1::QueryIncentivizedPacketRequest;
 use ibc_proto::ibc::apps::fee::v1::QueryIncentivizedPacketResponse;
 use ibc_relayer_types::applications::ics31_icq::response::CrossChainQueryResponse;
@@ -12,6 +13,7 @@ use ibc_relayer_types::core::ics03_connection::version::Version;
 use ibc_relayer_types::core::ics04_channel::channel::ChannelEnd;
 use ibc_relayer_types::core::ics04_channel::channel::IdentifiedChannelEnd;
 use ibc_relayer_types::core::ics04_channel::packet::{PacketMsgType, Sequence};
+use ibc_relayer_types::core::ics04_channel::upgrade::{ErrorReceipt, Upgrade};
 use ibc_relayer_types::core::ics23_commitment::commitment::CommitmentPrefix;
 use ibc_relayer_types::core::ics23_commitment::merkle::MerkleProof;
 use ibc_relayer_types::core::ics24_host::identifier::{
@@ -106,10 +108,18 @@ impl<Handle: ChainHandle> ChainHandle for CachingChainHandle<Handle> {
         self.inner().send_messages_and_wait_check_tx(tracked_msgs)
     }
 
+    fn estimate_tx_fee(&self, tracked_msgs: TrackedMsgs) -> Result<Fee, Error> {
+        self.inner().estimate_tx_fee(tracked_msgs)
+    }
+
     fn get_signer(&self) -> Result<Signer, Error> {
         self.inner().get_signer()
     }
 
+    fn select_signer(&self) -> Result<Signer, Error> {
+        self.inner().select_signer()
+    }
+
     fn config(&self) -> Result<ChainConfig, Error> {
         self.inner().config()
     }
@@ -118,6 +128,10 @@ impl<Handle: ChainHandle> ChainHandle for CachingChainHandle<Handle> {
         self.inner().get_key()
     }
 
+    fn get_wallet_keys(&self) -> Result<Vec<(String, AnySigningKeyPair)>, Error> {
+        self.inner().get_wallet_keys()
+    }
+
     fn add_key(&self, key_name: String, key: AnySigningKeyPair) -> Result<(), Error> {
         self.inner().add_key(key_name, key)
     }
@@ -334,6 +348,22 @@ impl<Handle: ChainHandle> ChainHandle for CachingChainHandle<Handle> {
         }
     }
 
+    fn query_upgrade(
+        &self,
+        request: QueryUpgradeRequest,
+        include_proof: IncludeProof,
+    ) -> Result<(Upgrade, Option<MerkleProof>), Error> {
+        self.inner().query_upgrade(request, include_proof)
+    }
+
+    fn query_upgrade_error(
+        &self,
+        request: QueryUpgradeErrorRequest,
+        include_proof: IncludeProof,
+    ) -> Result<(ErrorReceipt, Option<MerkleProof>), Error> {
+        self.inner().query_upgrade_error(request, include_proof)
+    }
+
     fn query_channel_client_state(
         &self,
         request: QueryChannelClientStateRequest,
@@ -404,6 +434,26 @@ impl<Handle: ChainHandle> ChainHandle for CachingChainHandle<Handle> {
             .build_channel_proofs(port_id, channel_id, height)
     }
 
+    fn build_channel_upgrade_proofs(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        height: Height,
+    ) -> Result<Proofs, Error> {
+        self.inner()
+            .build_channel_upgrade_proofs(port_id, channel_id, height)
+    }
+
+    fn build_channel_upgrade_error_proofs(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        height: Height,
+    ) -> Result<(ErrorReceipt, Proofs), Error> {
+        self.inner()
+            .build_channel_upgrade_error_proofs(port_id, channel_id, height)
+    }
+
     fn build_packet_proofs(
         &self,
         packet_type: PacketMsgType,
@@ -484,7 +534,22 @@ impl<Handle: ChainHandle> ChainHandle for CachingChainHandle<Handle> {
         &self,
         request: QueryHostConsensusStateRequest,
     ) -> Result<AnyConsensusState, Error> {
-        self.inner.query_host_consensus_state(request)
+        let handle = self.inner();
+        if let QueryHeight::Specific(height) = request.height {
+            let (result, in_cache) = self
+                .cache
+                .get_or_try_insert_host_consensus_state_with(&height, || {
+                    handle.query_host_consensus_state(request)
+                })?;
+
+            if in_cache == CacheStatus::Hit {
+                telemetry!(queries_cache_hits, &self.id(), "query_host_consensus_state");
+            }
+
+            Ok(result)
+        } else {
+            handle.query_host_consensus_state(request)
+        }
     }
 
     fn maybe_register_counterparty_payee(
@@ -5,6 +5,7 @@ use std::sync::{Arc, RwLock, RwLockReadGuard};
 use crossbeam_channel as channel;
 use tracing::{debug, Span};
 
+use ibc_proto::cosmos::tx::v1beta1::Fee;
 use ibc_proto::ibc::apps::fee::v1::{
     QueryIncentivizedPacketRequest, QueryIncentivizedPacketResponse,
 };
@@ -16,6 +17,7 @@ use ibc_relayer_types::core::ics03_connection::version::Version;
 use ibc_relayer_types::core::ics04_channel::channel::ChannelEnd;
 use ibc_relayer_types::core::ics04_channel::channel::IdentifiedChannelEnd;
 use ibc_relayer_types::core::ics04_channel::packet::{PacketMsgType, Sequence};
+use ibc_relayer_types::core::ics04_channel::upgrade::{ErrorReceipt, Upgrade};
 use ibc_relayer_types::core::ics23_commitment::commitment::CommitmentPrefix;
 use ibc_relayer_types::core::ics23_commitment::merkle::MerkleProof;
 use ibc_relayer_types::core::ics24_host::identifier::{
@@ -130,11 +132,21 @@ impl<Handle: ChainHandle> ChainHandle for CountingChainHandle<Handle> {
         self.inner().send_messages_and_wait_check_tx(tracked_msgs)
     }
 
+    fn estimate_tx_fee(&self, tracked_msgs: TrackedMsgs) -> Result<Fee, Error> {
+        self.inc_metric("estimate_tx_fee");
+        self.inner().estimate_tx_fee(tracked_msgs)
+    }
+
     fn get_signer(&self) -> Result<Signer, Error> {
         self.inc_metric("get_signer");
         self.inner().get_signer()
     }
 
+    fn select_signer(&self) -> Result<Signer, Error> {
+        self.inc_metric("select_signer");
+        self.inner().select_signer()
+    }
+
     fn config(&self) -> Result<ChainConfig, Error> {
         self.inc_metric("config");
         self.inner().config()
@@ -145,6 +157,11 @@ impl<Handle: ChainHandle> ChainHandle for CountingChainHandle<Handle> {
         self.inner().get_key()
     }
 
+    fn get_wallet_keys(&self) -> Result<Vec<(String, AnySigningKeyPair)>, Error> {
+        self.inc_metric("get_wallet_keys");
+        self.inner().get_wallet_keys()
+    }
+
     fn add_key(&self, key_name: String, key: AnySigningKeyPair) -> Result<(), Error> {
         self.inc_metric("add_key");
         self.inner().add_key(key_name, key)
@@ -306,6 +323,24 @@ impl<Handle: ChainHandle> ChainHandle for CountingChainHandle<Handle> {
         self.inner().query_channel(request, include_proof)
     }
 
+    fn query_upgrade(
+        &self,
+        request: QueryUpgradeRequest,
+        include_proof: IncludeProof,
+    ) -> Result<(Upgrade, Option<MerkleProof>), Error> {
+        self.inc_metric("query_upgrade");
+        self.inner().query_upgrade(request, include_proof)
+    }
+
+    fn query_upgrade_error(
+        &self,
+        request: QueryUpgradeErrorRequest,
+        include_proof: IncludeProof,
+    ) -> Result<(ErrorReceipt, Option<MerkleProof>), Error> {
+        self.inc_metric("query_upgrade_error");
+        self.inner().query_upgrade_error(request, include_proof)
+    }
+
     fn query_channel_client_state(
         &self,
         request: QueryChannelClientStateRequest,
@@ -383,6 +418,28 @@ impl<Handle: ChainHandle> ChainHandle for CountingChainHandle<Handle> {
             .build_channel_proofs(port_id, channel_id, height)
     }
 
+    fn build_channel_upgrade_proofs(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        height: Height,
+    ) -> Result<Proofs, Error> {
+        self.inc_metric("build_channel_upgrade_proofs");
+        self.inner()
+            .build_channel_upgrade_proofs(port_id, channel_id, height)
+    }
+
+    fn build_channel_upgrade_error_proofs(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        height: Height,
+    ) -> Result<(ErrorReceipt, Proofs), Error> {
+        self.inc_metric("build_channel_upgrade_error_proofs");
+        self.inner()
+            .build_channel_upgrade_error_proofs(port_id, channel_id, height)
+    }
+
     fn build_packet_proofs(
         &self,
         packet_type: PacketMsgType,
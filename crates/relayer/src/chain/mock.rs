@@ -0,0 +1,19 @@
+//! An in-memory chain, backed by the mock client, consensus state and header types,
+//! which allows testing the relayer end-to-end without running any full node.
+//!
+//! The in-memory IBC store of the mock chain, and its message handlers, are also used
+//! by the solo machine, while the mock chain endpoint itself, along with the mock client,
+//! consensus state and misbehaviour types, are only available with the `mocks` feature.
+
+pub mod error;
+pub mod handler;
+pub mod store;
+
+#[cfg(any(test, feature = "mocks"))]
+mod endpoint;
+
+#[cfg(any(test, feature = "mocks"))]
+pub use endpoint::{MockBlock, MockChain, MockHost};
+
+#[cfg(test)]
+pub mod test_utils;
@@ -0,0 +1,1172 @@
+//! The endpoint of the mock chain.
+//!
+//! The chain produces a new block at a fixed interval, as well as whenever a
+//! transaction is submitted to it. Every block holds a snapshot of the IBC store
+//! of the chain, which allows querying the state of the chain, along with its
+//! proofs, at any past height.
+//!
+//! Mock clients only know the chain they track by its revision number, hence the
+//! identifier of a mock chain must be `mock-<revision number>`, eg. `mock-1`.
+
+use alloc::collections::BTreeSet;
+use alloc::sync::Arc;
+use core::convert::Infallible;
+use core::time::Duration;
+use std::sync::RwLock;
+
+use ibc_proto::cosmos::tx::v1beta1::Fee;
+use ibc_proto::google::protobuf::Any;
+use ibc_proto::ibc::apps::fee::v1::{
+    QueryIncentivizedPacketRequest, QueryIncentivizedPacketResponse,
+};
+use sha2::{Digest, Sha256};
+use tendermint::abci::Code;
+use tendermint_rpc::endpoint::broadcast::tx_sync::Response as TxResponse;
+use tokio::runtime::Runtime as TokioRuntime;
+use tracing::{debug, error_span};
+
+use ibc_relayer_types::applications::ics31_icq::response::CrossChainQueryResponse;
+use ibc_relayer_types::core::ics02_client::error::Error as ClientError;
+use ibc_relayer_types::core::ics02_client::events::{NewBlock, UpdateClient};
+use ibc_relayer_types::core::ics03_connection::connection::{
+    ConnectionEnd, IdentifiedConnectionEnd,
+};
+use ibc_relayer_types::core::ics04_channel::channel::{ChannelEnd, IdentifiedChannelEnd, Ordering};
+use ibc_relayer_types::core::ics04_channel::packet::Sequence;
+use ibc_relayer_types::core::ics04_channel::upgrade::{ErrorReceipt, Upgrade};
+use ibc_relayer_types::core::ics23_commitment::commitment::CommitmentPrefix;
+use ibc_relayer_types::core::ics23_commitment::merkle::MerkleProof;
+use ibc_relayer_types::core::ics24_host::identifier::{ChainId, ChannelId, ConnectionId, PortId};
+use ibc_relayer_types::events::{IbcEvent, WithBlockDataType};
+use ibc_relayer_types::mock::client_state::{MockClientState, MOCK_CHAIN_NAME};
+use ibc_relayer_types::mock::consensus_state::MockConsensusState;
+use ibc_relayer_types::mock::header::MockHeader;
+use ibc_relayer_types::signer::Signer;
+use ibc_relayer_types::timestamp::Timestamp;
+use ibc_relayer_types::Height as ICSHeight;
+
+use crate::account::Balance;
+use crate::chain::client::ClientSettings;
+use crate::chain::endpoint::{ChainEndpoint, ChainStatus, HealthCheck};
+use crate::chain::handle::Subscription;
+use crate::chain::requests::*;
+use crate::chain::tracking::{TrackedMsgs, TrackingId};
+use crate::client_state::{AnyClientState, IdentifiedAnyClientState};
+use crate::config::ChainConfig;
+use crate::consensus_state::AnyConsensusState;
+use crate::denom::DenomTrace;
+use crate::error::Error;
+use crate::event::bus::EventBus;
+use crate::event::monitor::{EventBatch, Result as MonitorResult};
+use crate::event::IbcEventWithHeight;
+use crate::keyring::{KeyRing, Secp256k1KeyPair};
+use crate::misbehaviour::MisbehaviourEvidence;
+use crate::util::lock::LockExt;
+use crate::util::task::{spawn_background_task, Next, TaskError, TaskHandle};
+
+use super::error::Error as MockError;
+use super::store::IbcStore;
+
+/// The interval at which the mock chain produces empty blocks.
+const BLOCK_INTERVAL: Duration = Duration::from_millis(100);
+
+/// A block of the mock chain.
+#[derive(Clone, Debug)]
+pub struct MockBlock {
+    pub header: MockHeader,
+    /// The state of the IBC store after the execution of the block
+    pub store: Arc<IbcStore>,
+    /// The events emitted by the transactions of the block
+    pub events: Vec<IbcEvent>,
+}
+
+/// The state of a mock chain, shared between its endpoint and the task producing its blocks.
+pub struct MockHost {
+    chain_id: ChainId,
+    blocks: Vec<MockBlock>,
+    event_bus: EventBus<Arc<MonitorResult<EventBatch>>>,
+}
+
+impl MockHost {
+    /// Creates a mock chain with a single genesis block, at height 1.
+    pub fn new(chain_id: ChainId) -> Self {
+        let header = MockHeader::new(
+            ICSHeight::new(chain_id.version(), 1).expect("height 1 is a valid height"),
+        );
+
+        Self {
+            chain_id,
+            blocks: vec![MockBlock {
+                header,
+                store: Default::default(),
+                events: vec![],
+            }],
+            event_bus: EventBus::new(),
+        }
+    }
+
+    pub fn latest_block(&self) -> &MockBlock {
+        self.blocks.last().expect("the chain has a genesis block")
+    }
+
+    pub fn block(&self, height: ICSHeight) -> Result<&MockBlock, MockError> {
+        if height.revision_number() != self.chain_id.version() {
+            return Err(MockError::block_not_found(height));
+        }
+
+        usize::try_from(height.revision_height() - 1)
+            .ok()
+            .and_then(|index| self.blocks.get(index))
+            .ok_or_else(|| MockError::block_not_found(height))
+    }
+
+    pub fn block_at(&self, height: QueryHeight) -> Result<&MockBlock, MockError> {
+        match height {
+            QueryHeight::Latest => Ok(self.latest_block()),
+            QueryHeight::Specific(height) => self.block(height),
+        }
+    }
+
+    /// Applies the given messages atomically, in a new block, and returns the events
+    /// emitted by the messages along with the height of that block.
+    ///
+    /// If any message fails, the state of the chain is left untouched and no block
+    /// is produced.
+    pub fn deliver(&mut self, msgs: &[Any]) -> Result<(ICSHeight, Vec<IbcEvent>), MockError> {
+        let header = self.next_header();
+        let mut store = IbcStore::clone(&self.latest_block().store);
+
+        let mut events = vec![];
+        for msg in msgs {
+            events.extend(store.deliver(msg, header.height, header.timestamp)?);
+        }
+
+        self.commit(header, store, events.clone());
+
+        Ok((header.height, events))
+    }
+
+    /// Produces an empty block.
+    pub fn produce_block(&mut self) {
+        let header = self.next_header();
+        let store = IbcStore::clone(&self.latest_block().store);
+
+        self.commit(header, store, vec![]);
+    }
+
+    pub fn subscribe(&mut self) -> Subscription {
+        self.event_bus.subscribe()
+    }
+
+    fn next_header(&self) -> MockHeader {
+        let latest = self.latest_block().header;
+
+        // Ensure that timestamps are strictly increasing, whatever the resolution of the clock
+        let now = Timestamp::now();
+        let timestamp = if now > latest.timestamp {
+            now
+        } else {
+            (latest.timestamp + Duration::from_nanos(1)).unwrap_or(now)
+        };
+
+        MockHeader {
+            height: latest.height.increment(),
+            timestamp,
+        }
+    }
+
+    fn commit(&mut self, header: MockHeader, store: IbcStore, events: Vec<IbcEvent>) {
+        let height = header.height;
+
+        let batch = EventBatch {
+            chain_id: self.chain_id.clone(),
+            tracking_id: TrackingId::new_uuid(),
+            height,
+            events: core::iter::once(IbcEvent::NewBlock(NewBlock::new(height)))
+                .chain(events.iter().cloned())
+                .map(|event| IbcEventWithHeight::new(event, height))
+                .collect(),
+        };
+
+        self.blocks.push(MockBlock {
+            header,
+            store: Arc::new(store),
+            events,
+        });
+
+        self.event_bus.broadcast(Arc::new(Ok(batch)));
+    }
+}
+
+/// A chain endpoint for an in-memory mock chain.
+///
+/// The chain hosts mock clients only, and the clients of its counterparties must be
+/// mock clients as well, which means that the counterparties must be mock chains too.
+/// It does not perform any authentication: the signer of its messages is the
+/// configured `key_name`.
+pub struct MockChain {
+    config: ChainConfig,
+    keybase: KeyRing<Secp256k1KeyPair>,
+    host: Arc<RwLock<MockHost>>,
+    block_producer: TaskHandle,
+}
+
+impl MockChain {
+    fn block_at<T>(
+        &self,
+        height: QueryHeight,
+        f: impl FnOnce(&MockBlock) -> Result<T, MockError>,
+    ) -> Result<T, Error> {
+        let host = self.host.acquire_read();
+        let block = host.block_at(height).map_err(Error::mock)?;
+
+        f(block).map_err(Error::mock)
+    }
+
+    fn latest_store(&self) -> Arc<IbcStore> {
+        self.host.acquire_read().latest_block().store.clone()
+    }
+
+    fn header(&self, height: ICSHeight) -> Result<MockHeader, Error> {
+        self.block_at(QueryHeight::Specific(height), |block| Ok(block.header))
+    }
+
+    /// The events of all blocks up to the given height, along with the height of their block.
+    fn events_up_to(&self, height: QueryHeight) -> Result<Vec<IbcEventWithHeight>, Error> {
+        let host = self.host.acquire_read();
+        let max_height = host.block_at(height).map_err(Error::mock)?.header.height;
+
+        Ok(host
+            .blocks
+            .iter()
+            .take_while(|block| block.header.height <= max_height)
+            .flat_map(|block| {
+                block
+                    .events
+                    .iter()
+                    .map(|event| IbcEventWithHeight::new(event.clone(), block.header.height))
+            })
+            .collect())
+    }
+}
+
+fn with_proof<T>(
+    value: T,
+    include_proof: IncludeProof,
+    proof: impl FnOnce() -> Result<MerkleProof, MockError>,
+) -> Result<(T, Option<MerkleProof>), MockError> {
+    match include_proof {
+        IncludeProof::Yes => Ok((value, Some(proof()?))),
+        IncludeProof::No => Ok((value, None)),
+    }
+}
+
+impl ChainEndpoint for MockChain {
+    type LightBlock = MockHeader;
+    type Header = MockHeader;
+    type ConsensusState = MockConsensusState;
+    type ClientState = MockClientState;
+    type Time = Timestamp;
+    type SigningKeyPair = Secp256k1KeyPair;
+
+    fn config(&self) -> &ChainConfig {
+        &self.config
+    }
+
+    fn bootstrap(config: ChainConfig, _rt: Arc<TokioRuntime>) -> Result<Self, Error> {
+        if config.id != ChainId::new(MOCK_CHAIN_NAME.to_string(), config.id.version()) {
+            return Err(Error::mock(MockError::invalid_chain_id(config.id)));
+        }
+
+        let keybase = KeyRing::new_secp256k1(
+            config.key_store_type,
+            &config.account_prefix,
+            &config.id,
+            &config.key_store_folder,
+            &config.remote_signer,
+        )
+        .map_err(Error::key_base)?;
+
+        let host = Arc::new(RwLock::new(MockHost::new(config.id.clone())));
+
+        let block_producer = {
+            let host = host.clone();
+
+            spawn_background_task(
+                error_span!("mock.block_producer", chain = %config.id),
+                Some(BLOCK_INTERVAL),
+                move || -> Result<Next, TaskError<Infallible>> {
+                    host.acquire_write().produce_block();
+                    Ok(Next::Continue)
+                },
+            )
+        };
+
+        Ok(Self {
+            config,
+            keybase,
+            host,
+            block_producer,
+        })
+    }
+
+    fn shutdown(self) -> Result<(), Error> {
+        self.block_producer.shutdown_and_wait();
+        Ok(())
+    }
+
+    fn health_check(&self) -> Result<HealthCheck, Error> {
+        Ok(HealthCheck::Healthy)
+    }
+
+    fn subscribe(&mut self) -> Result<Subscription, Error> {
+        Ok(self.host.acquire_write().subscribe())
+    }
+
+    fn keybase(&self) -> &KeyRing<Self::SigningKeyPair> {
+        &self.keybase
+    }
+
+    fn keybase_mut(&mut self) -> &mut KeyRing<Self::SigningKeyPair> {
+        &mut self.keybase
+    }
+
+    fn get_signer(&self) -> Result<Signer, Error> {
+        self.config
+            .key_name
+            .parse()
+            .map_err(|e| Error::mock(MockError::signer(e)))
+    }
+
+    fn ibc_version(&self) -> Result<Option<semver::Version>, Error> {
+        Ok(None)
+    }
+
+    fn send_messages_and_wait_commit(
+        &mut self,
+        tracked_msgs: TrackedMsgs,
+    ) -> Result<Vec<IbcEventWithHeight>, Error> {
+        let result = self.host.acquire_write().deliver(tracked_msgs.messages());
+
+        match result {
+            Ok((height, events)) => Ok(events
+                .into_iter()
+                .map(|event| IbcEventWithHeight::new(event, height))
+                .collect()),
+            Err(e) => {
+                debug!(
+                    "transaction {} failed on mock chain {}: {e}",
+                    tracked_msgs.tracking_id(),
+                    self.id()
+                );
+
+                let height = self.host.acquire_read().latest_block().header.height;
+
+                Ok(vec![IbcEventWithHeight::new(
+                    IbcEvent::ChainError(e.to_string()),
+                    height,
+                )])
+            }
+        }
+    }
+
+    fn send_messages_and_wait_check_tx(
+        &mut self,
+        tracked_msgs: TrackedMsgs,
+    ) -> Result<Vec<TxResponse>, Error> {
+        let mut hasher = Sha256::new();
+        for msg in tracked_msgs.messages() {
+            hasher.update(&msg.value);
+        }
+        let hash = tendermint::Hash::Sha256(hasher.finalize().into());
+
+        let (code, log) = match self.host.acquire_write().deliver(tracked_msgs.messages()) {
+            Ok(_) => (Code::Ok, String::new()),
+            Err(e) => (Code::from(1), e.to_string()),
+        };
+
+        Ok(vec![TxResponse {
+            code,
+            data: Default::default(),
+            log,
+            hash,
+        }])
+    }
+
+    fn estimate_tx_fee(&mut self, _tracked_msgs: TrackedMsgs) -> Result<Fee, Error> {
+        // The mock chain does not charge any fee
+        Ok(Fee::default())
+    }
+
+    fn verify_header(
+        &mut self,
+        _trusted: ICSHeight,
+        target: ICSHeight,
+        _client_state: &AnyClientState,
+    ) -> Result<Self::LightBlock, Error> {
+        self.header(target)
+    }
+
+    fn check_misbehaviour(
+        &mut self,
+        _update: &UpdateClient,
+        _client_state: &AnyClientState,
+    ) -> Result<Option<MisbehaviourEvidence>, Error> {
+        Ok(None)
+    }
+
+    fn query_balance(
+        &self,
+        _key_name: Option<&str>,
+        denom: Option<&str>,
+    ) -> Result<Balance, Error> {
+        Ok(Balance {
+            amount: "0".to_string(),
+            denom: denom.unwrap_or(&self.config.gas_price.denom).to_string(),
+        })
+    }
+
+    fn query_all_balances(&self, key_name: Option<&str>) -> Result<Vec<Balance>, Error> {
+        Ok(vec![self.query_balance(key_name, None)?])
+    }
+
+    fn query_denom_trace(&self, _hash: String) -> Result<DenomTrace, Error> {
+        Err(Error::mock(MockError::unsupported_query(
+            "denom trace".to_string(),
+        )))
+    }
+
+    fn query_commitment_prefix(&self) -> Result<CommitmentPrefix, Error> {
+        CommitmentPrefix::try_from(self.config.store_prefix.as_bytes().to_vec())
+            .map_err(|_| Error::ics02(ClientError::empty_prefix()))
+    }
+
+    fn query_application_status(&self) -> Result<ChainStatus, Error> {
+        let header = self.host.acquire_read().latest_block().header;
+
+        Ok(ChainStatus {
+            height: header.height,
+            timestamp: header.timestamp,
+        })
+    }
+
+    fn query_clients(
+        &self,
+        _request: QueryClientStatesRequest,
+    ) -> Result<Vec<IdentifiedAnyClientState>, Error> {
+        Ok(self
+            .latest_store()
+            .clients
+            .iter()
+            .map(|(client_id, client)| {
+                IdentifiedAnyClientState::new(client_id.clone(), client.client_state.clone())
+            })
+            .collect())
+    }
+
+    fn query_client_state(
+        &self,
+        request: QueryClientStateRequest,
+        include_proof: IncludeProof,
+    ) -> Result<(AnyClientState, Option<MerkleProof>), Error> {
+        self.block_at(request.height, |block| {
+            let client_state = block.store.client(&request.client_id)?.client_state.clone();
+
+            with_proof(client_state, include_proof, || {
+                block.store.client_state_proof(&request.client_id)
+            })
+        })
+    }
+
+    fn query_consensus_state(
+        &self,
+        request: QueryConsensusStateRequest,
+        include_proof: IncludeProof,
+    ) -> Result<(AnyConsensusState, Option<MerkleProof>), Error> {
+        self.block_at(request.query_height, |block| {
+            let consensus_state = block
+                .store
+                .client(&request.client_id)?
+                .consensus_states
+                .get(&request.consensus_height)
+                .cloned()
+                .ok_or_else(|| {
+                    MockError::consensus_state_not_found(
+                        request.client_id.clone(),
+                        request.consensus_height,
+                    )
+                })?;
+
+            with_proof(consensus_state, include_proof, || {
+                block
+                    .store
+                    .consensus_state_proof(&request.client_id, request.consensus_height)
+            })
+        })
+    }
+
+    fn query_consensus_state_heights(
+        &self,
+        request: QueryConsensusStateHeightsRequest,
+    ) -> Result<Vec<ICSHeight>, Error> {
+        let store = self.latest_store();
+        let client = store.client(&request.client_id).map_err(Error::mock)?;
+
+        Ok(client.consensus_states.keys().copied().collect())
+    }
+
+    fn query_upgraded_client_state(
+        &self,
+        _request: QueryUpgradedClientStateRequest,
+    ) -> Result<(AnyClientState, MerkleProof), Error> {
+        Err(Error::mock(MockError::unsupported_query(
+            "upgraded client state".to_string(),
+        )))
+    }
+
+    fn query_upgraded_consensus_state(
+        &self,
+        _request: QueryUpgradedConsensusStateRequest,
+    ) -> Result<(AnyConsensusState, MerkleProof), Error> {
+        Err(Error::mock(MockError::unsupported_query(
+            "upgraded consensus state".to_string(),
+        )))
+    }
+
+    fn query_connections(
+        &self,
+        _request: QueryConnectionsRequest,
+    ) -> Result<Vec<IdentifiedConnectionEnd>, Error> {
+        Ok(self
+            .latest_store()
+            .connections
+            .iter()
+            .map(|(connection_id, connection_end)| {
+                IdentifiedConnectionEnd::new(connection_id.clone(), connection_end.clone())
+            })
+            .collect())
+    }
+
+    fn query_client_connections(
+        &self,
+        request: QueryClientConnectionsRequest,
+    ) -> Result<Vec<ConnectionId>, Error> {
+        Ok(self
+            .latest_store()
+            .connections
+            .iter()
+            .filter(|(_, connection_end)| connection_end.client_id() == &request.client_id)
+            .map(|(connection_id, _)| connection_id.clone())
+            .collect())
+    }
+
+    fn query_connection(
+        &self,
+        request: QueryConnectionRequest,
+        include_proof: IncludeProof,
+    ) -> Result<(ConnectionEnd, Option<MerkleProof>), Error> {
+        self.block_at(request.height, |block| {
+            let connection_end = block.store.connection(&request.connection_id)?.clone();
+
+            with_proof(connection_end, include_proof, || {
+                block.store.connection_proof(&request.connection_id)
+            })
+        })
+    }
+
+    fn query_connection_channels(
+        &self,
+        request: QueryConnectionChannelsRequest,
+    ) -> Result<Vec<IdentifiedChannelEnd>, Error> {
+        Ok(self
+            .latest_store()
+            .channels
+            .iter()
+            .filter(|(_, channel)| {
+                channel.channel_end.connection_hops().first() == Some(&request.connection_id)
+            })
+            .map(|((port_id, channel_id), channel)| {
+                IdentifiedChannelEnd::new(
+                    port_id.clone(),
+                    channel_id.clone(),
+                    channel.channel_end.clone(),
+                )
+            })
+            .collect())
+    }
+
+    fn query_channels(
+        &self,
+        _request: QueryChannelsRequest,
+    ) -> Result<Vec<IdentifiedChannelEnd>, Error> {
+        Ok(self
+            .latest_store()
+            .channels
+            .iter()
+            .map(|((port_id, channel_id), channel)| {
+                IdentifiedChannelEnd::new(
+                    port_id.clone(),
+                    channel_id.clone(),
+                    channel.channel_end.clone(),
+                )
+            })
+            .collect())
+    }
+
+    fn query_channel(
+        &self,
+        request: QueryChannelRequest,
+        include_proof: IncludeProof,
+    ) -> Result<(ChannelEnd, Option<MerkleProof>), Error> {
+        self.block_at(request.height, |block| {
+            // Like on Cosmos SDK chains, a missing channel is reported as an uninitialized channel
+            let channel_end = block
+                .store
+                .channel(&request.port_id, &request.channel_id)
+                .map(|channel| channel.channel_end.clone())
+                .unwrap_or_default();
+
+            with_proof(channel_end, include_proof, || {
+                Ok(block
+                    .store
+                    .channel_proof(&request.port_id, &request.channel_id))
+            })
+        })
+    }
+
+    fn query_upgrade(
+        &self,
+        request: QueryUpgradeRequest,
+        _include_proof: IncludeProof,
+    ) -> Result<(Upgrade, Option<MerkleProof>), Error> {
+        Err(Error::mock(MockError::upgrade_not_found(
+            request.port_id,
+            request.channel_id,
+        )))
+    }
+
+    fn query_upgrade_error(
+        &self,
+        request: QueryUpgradeErrorRequest,
+        _include_proof: IncludeProof,
+    ) -> Result<(ErrorReceipt, Option<MerkleProof>), Error> {
+        Err(Error::mock(MockError::upgrade_not_found(
+            request.port_id,
+            request.channel_id,
+        )))
+    }
+
+    fn query_channel_client_state(
+        &self,
+        request: QueryChannelClientStateRequest,
+    ) -> Result<Option<IdentifiedAnyClientState>, Error> {
+        let store = self.latest_store();
+
+        let client_state = store
+            .channel(&request.port_id, &request.channel_id)
+            .ok()
+            .and_then(|channel| channel.channel_end.connection_hops().first().cloned())
+            .and_then(|connection_id| store.connection(&connection_id).ok())
+            .and_then(|connection_end| {
+                let client_id = connection_end.client_id();
+
+                store.client(client_id).ok().map(|client| {
+                    IdentifiedAnyClientState::new(client_id.clone(), client.client_state.clone())
+                })
+            });
+
+        Ok(client_state)
+    }
+
+    fn query_packet_commitment(
+        &self,
+        request: QueryPacketCommitmentRequest,
+        include_proof: IncludeProof,
+    ) -> Result<(Vec<u8>, Option<MerkleProof>), Error> {
+        let key = (request.port_id, request.channel_id, request.sequence);
+
+        self.block_at(request.height, |block| {
+            let commitment = block
+                .store
+                .packet_commitments
+                .get(&key)
+                .cloned()
+                .unwrap_or_default();
+
+            with_proof(commitment, include_proof, || {
+                Ok(block.store.packet_commitment_proof(&key))
+            })
+        })
+    }
+
+    fn query_packet_commitments(
+        &self,
+        request: QueryPacketCommitmentsRequest,
+    ) -> Result<(Vec<Sequence>, ICSHeight), Error> {
+        let host = self.host.acquire_read();
+        let block = host.latest_block();
+
+        let sequences = block
+            .store
+            .packet_commitments
+            .keys()
+            .filter(|(port_id, channel_id, _)| {
+                port_id == &request.port_id && channel_id == &request.channel_id
+            })
+            .map(|(_, _, sequence)| *sequence)
+            .collect();
+
+        Ok((sequences, block.header.height))
+    }
+
+    fn query_packet_receipt(
+        &self,
+        request: QueryPacketReceiptRequest,
+        include_proof: IncludeProof,
+    ) -> Result<(Vec<u8>, Option<MerkleProof>), Error> {
+        let key = (request.port_id, request.channel_id, request.sequence);
+
+        self.block_at(request.height, |block| {
+            let receipt = if block.store.packet_receipts.contains(&key) {
+                vec![1]
+            } else {
+                vec![]
+            };
+
+            with_proof(receipt, include_proof, || {
+                Ok(block.store.packet_receipt_proof(&key))
+            })
+        })
+    }
+
+    fn query_unreceived_packets(
+        &self,
+        request: QueryUnreceivedPacketsRequest,
+    ) -> Result<Vec<Sequence>, Error> {
+        let store = self.latest_store();
+        let channel = store
+            .channel(&request.port_id, &request.channel_id)
+            .map_err(Error::mock)?;
+
+        Ok(request
+            .packet_commitment_sequences
+            .into_iter()
+            .filter(|sequence| match channel.channel_end.ordering {
+                Ordering::Ordered => *sequence >= channel.next_sequence_recv,
+                _ => !store.packet_receipts.contains(&(
+                    request.port_id.clone(),
+                    request.channel_id.clone(),
+                    *sequence,
+                )),
+            })
+            .collect())
+    }
+
+    fn query_packet_acknowledgement(
+        &self,
+        request: QueryPacketAcknowledgementRequest,
+        include_proof: IncludeProof,
+    ) -> Result<(Vec<u8>, Option<MerkleProof>), Error> {
+        let key = (request.port_id, request.channel_id, request.sequence);
+
+        self.block_at(request.height, |block| {
+            let ack = block
+                .store
+                .packet_acknowledgements
+                .get(&key)
+                .cloned()
+                .unwrap_or_default();
+
+            with_proof(ack, include_proof, || {
+                Ok(block.store.packet_acknowledgement_proof(&key))
+            })
+        })
+    }
+
+    fn query_packet_acknowledgements(
+        &self,
+        request: QueryPacketAcknowledgementsRequest,
+    ) -> Result<(Vec<Sequence>, ICSHeight), Error> {
+        let host = self.host.acquire_read();
+        let block = host.latest_block();
+
+        let sequences = block
+            .store
+            .packet_acknowledgements
+            .keys()
+            .filter(|(port_id, channel_id, sequence)| {
+                port_id == &request.port_id
+                    && channel_id == &request.channel_id
+                    && request.packet_commitment_sequences.contains(sequence)
+            })
+            .map(|(_, _, sequence)| *sequence)
+            .collect();
+
+        Ok((sequences, block.header.height))
+    }
+
+    fn query_unreceived_acknowledgements(
+        &self,
+        request: QueryUnreceivedAcksRequest,
+    ) -> Result<Vec<Sequence>, Error> {
+        let store = self.latest_store();
+
+        Ok(request
+            .packet_ack_sequences
+            .into_iter()
+            .filter(|sequence| {
+                store.packet_commitments.contains_key(&(
+                    request.port_id.clone(),
+                    request.channel_id.clone(),
+                    *sequence,
+                ))
+            })
+            .collect())
+    }
+
+    fn query_next_sequence_receive(
+        &self,
+        request: QueryNextSequenceReceiveRequest,
+        include_proof: IncludeProof,
+    ) -> Result<(Sequence, Option<MerkleProof>), Error> {
+        self.block_at(request.height, |block| {
+            let next_sequence_recv = block
+                .store
+                .channel(&request.port_id, &request.channel_id)?
+                .next_sequence_recv;
+
+            with_proof(next_sequence_recv, include_proof, || {
+                block
+                    .store
+                    .next_sequence_recv_proof(&request.port_id, &request.channel_id)
+            })
+        })
+    }
+
+    fn query_txs(&self, request: QueryTxRequest) -> Result<Vec<IbcEventWithHeight>, Error> {
+        match request {
+            QueryTxRequest::Client(request) => {
+                let event = self
+                    .events_up_to(request.query_height)?
+                    .into_iter()
+                    .rev()
+                    .find(|event| match (&request.event_id, &event.event) {
+                        (WithBlockDataType::CreateClient, IbcEvent::CreateClient(e)) => {
+                            e.client_id() == &request.client_id
+                        }
+                        (WithBlockDataType::UpdateClient, IbcEvent::UpdateClient(e)) => {
+                            e.client_id() == &request.client_id
+                                && e.consensus_height() == request.consensus_height
+                        }
+                        _ => false,
+                    });
+
+                Ok(event.into_iter().collect())
+            }
+
+            // Transactions are committed as soon as they are submitted, and have no hash
+            QueryTxRequest::Transaction(_) => Ok(vec![]),
+        }
+    }
+
+    fn query_packet_events(
+        &self,
+        request: QueryPacketEventDataRequest,
+    ) -> Result<Vec<IbcEventWithHeight>, Error> {
+        let (query_height, exact) = match request.height {
+            Qualified::SmallerEqual(height) => (height, false),
+            Qualified::Equal(height) => (height, true),
+        };
+
+        let max_height = self.block_at(query_height, |block| Ok(block.header.height))?;
+        let sequences: BTreeSet<_> = request.sequences.iter().copied().collect();
+
+        let mut events: Vec<_> = self
+            .events_up_to(query_height)?
+            .into_iter()
+            .filter(|event| !exact || event.height == max_height)
+            .filter(|event| {
+                let packet = match (&request.event_id, &event.event) {
+                    (WithBlockDataType::SendPacket, IbcEvent::SendPacket(e)) => &e.packet,
+                    (WithBlockDataType::WriteAck, IbcEvent::WriteAcknowledgement(e)) => &e.packet,
+                    _ => return false,
+                };
+
+                packet.source_port == request.source_port_id
+                    && packet.source_channel == request.source_channel_id
+                    && packet.destination_port == request.destination_port_id
+                    && packet.destination_channel == request.destination_channel_id
+                    && sequences.contains(&packet.sequence)
+            })
+            .collect();
+
+        events.sort_by_key(|event| event.event.packet().map(|packet| packet.sequence));
+
+        Ok(events)
+    }
+
+    fn query_host_consensus_state(
+        &self,
+        request: QueryHostConsensusStateRequest,
+    ) -> Result<Self::ConsensusState, Error> {
+        self.block_at(request.height, |block| {
+            Ok(MockConsensusState::new(block.header))
+        })
+    }
+
+    fn build_client_state(
+        &self,
+        height: ICSHeight,
+        _settings: ClientSettings,
+    ) -> Result<Self::ClientState, Error> {
+        let header = self.header(height)?;
+
+        Ok(MockClientState::new(header))
+    }
+
+    fn build_consensus_state(
+        &self,
+        light_block: Self::LightBlock,
+    ) -> Result<Self::ConsensusState, Error> {
+        Ok(MockConsensusState::new(light_block))
+    }
+
+    fn build_header(
+        &mut self,
+        _trusted_height: ICSHeight,
+        target_height: ICSHeight,
+        _client_state: &AnyClientState,
+    ) -> Result<(Self::Header, Vec<Self::Header>), Error> {
+        Ok((self.header(target_height)?, vec![]))
+    }
+
+    fn maybe_register_counterparty_payee(
+        &mut self,
+        _channel_id: &ChannelId,
+        _port_id: &PortId,
+        _counterparty_payee: &Signer,
+    ) -> Result<(), Error> {
+        Ok(())
+    }
+
+    fn cross_chain_query(
+        &self,
+        _requests: Vec<CrossChainQueryRequest>,
+    ) -> Result<Vec<CrossChainQueryResponse>, Error> {
+        Err(Error::mock(MockError::unsupported_query(
+            "cross-chain".to_string(),
+        )))
+    }
+
+    fn query_incentivized_packet(
+        &self,
+        _request: QueryIncentivizedPacketRequest,
+    ) -> Result<QueryIncentivizedPacketResponse, Error> {
+        Err(Error::mock(MockError::unsupported_query(
+            "incentivized packet".to_string(),
+        )))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_log::test;
+
+    use ibc_proto::cosmos::base::v1beta1::Coin;
+    use ibc_relayer_types::applications::transfer::msgs::transfer::MsgTransfer;
+    use ibc_relayer_types::core::ics04_channel::timeout::TimeoutHeight;
+    use ibc_relayer_types::tx_msg::Msg;
+
+    use crate::chain::handle::{BaseChainHandle, ChainHandle};
+    use crate::chain::mock::test_utils::{
+        config, link, open_channel, packet_commitments, spawn_chain, transfer,
+    };
+    use crate::chain::runtime::ChainRuntime;
+
+    #[test]
+    fn relay_packet_and_acknowledgement() {
+        let rt = Arc::new(TokioRuntime::new().unwrap());
+        let a = spawn_chain("mock-1", &rt);
+        let b = spawn_chain("mock-2", &rt);
+
+        let channel = open_channel(&a, &b, Ordering::Unordered);
+        let (a_port, a_channel) = (
+            channel.src_port_id().clone(),
+            channel.src_channel_id().unwrap().clone(),
+        );
+        let (b_port, b_channel) = (
+            channel.dst_port_id().clone(),
+            channel.dst_channel_id().unwrap().clone(),
+        );
+
+        for chain in [&a, &b] {
+            let channels = chain.query_channels(QueryChannelsRequest { pagination: None });
+            assert!(channels.unwrap()[0].channel_end.is_open());
+        }
+
+        let events = transfer(&channel, TimeoutHeight::Never);
+        assert!(matches!(events[0].event, IbcEvent::SendPacket(_)));
+        assert_eq!(
+            packet_commitments(&a, &a_port, &a_channel),
+            vec![Sequence::from(1)]
+        );
+
+        let link = link(&channel);
+        let events = link.relay_recv_packet_and_timeout_messages().unwrap();
+        assert!(events
+            .iter()
+            .any(|event| matches!(event, IbcEvent::WriteAcknowledgement(_))));
+
+        let unreceived = b
+            .query_unreceived_packets(QueryUnreceivedPacketsRequest {
+                port_id: b_port,
+                channel_id: b_channel,
+                packet_commitment_sequences: vec![Sequence::from(1)],
+            })
+            .unwrap();
+        assert!(unreceived.is_empty());
+
+        // Relaying the same packet again is a no-op
+        assert!(link
+            .relay_recv_packet_and_timeout_messages()
+            .unwrap()
+            .is_empty());
+
+        let events = link
+            .reverse(false, false)
+            .unwrap()
+            .relay_ack_packet_messages()
+            .unwrap();
+        assert!(events
+            .iter()
+            .any(|event| matches!(event, IbcEvent::AcknowledgePacket(_))));
+
+        assert!(packet_commitments(&a, &a_port, &a_channel).is_empty());
+
+        a.shutdown().unwrap();
+        b.shutdown().unwrap();
+    }
+
+    #[test]
+    fn skip_excluded_packet_events() {
+        let rt = Arc::new(TokioRuntime::new().unwrap());
+
+        let mut a_config = config("mock-1");
+        a_config.excluded_sequences = toml::from_str("'channel-0' = [1]").unwrap();
+        let a = ChainRuntime::<MockChain>::spawn::<BaseChainHandle>(a_config, rt.clone()).unwrap();
+        let b = spawn_chain("mock-2", &rt);
+
+        let channel = open_channel(&a, &b, Ordering::Unordered);
+        assert_eq!(channel.src_channel_id(), Some(&ChannelId::new(0)));
+
+        let mut events = transfer(&channel, TimeoutHeight::Never);
+        events.extend(transfer(&channel, TimeoutHeight::Never));
+
+        // Both packets are sent in the same batch of events
+        let height = events[1].height;
+        let events = events
+            .into_iter()
+            .map(|event| event.with_height(height))
+            .collect();
+
+        let mut link = link(&channel);
+        link.a_to_b
+            .update_schedule(EventBatch {
+                chain_id: a.id(),
+                tracking_id: TrackingId::new_uuid(),
+                height,
+                events,
+            })
+            .unwrap();
+        link.a_to_b.execute_schedule().unwrap();
+
+        // Only the packet whose sequence is not excluded was relayed
+        let unreceived = b
+            .query_unreceived_packets(QueryUnreceivedPacketsRequest {
+                port_id: channel.dst_port_id().clone(),
+                channel_id: channel.dst_channel_id().unwrap().clone(),
+                packet_commitment_sequences: vec![Sequence::from(1), Sequence::from(2)],
+            })
+            .unwrap();
+        assert_eq!(unreceived, vec![Sequence::from(1)]);
+
+        a.shutdown().unwrap();
+        b.shutdown().unwrap();
+    }
+
+    #[test]
+    fn timeout_packet() {
+        let rt = Arc::new(TokioRuntime::new().unwrap());
+        let a = spawn_chain("mock-1", &rt);
+        let b = spawn_chain("mock-2", &rt);
+
+        let channel = open_channel(&a, &b, Ordering::Ordered);
+        let (a_port, a_channel) = (
+            channel.src_port_id().clone(),
+            channel.src_channel_id().unwrap().clone(),
+        );
+
+        let b_height = b.query_latest_height().unwrap();
+        transfer(&channel, TimeoutHeight::At(b_height.increment()));
+
+        // Wait for the destination chain to go past the timeout height
+        while b.query_latest_height().unwrap() <= b_height.increment() {
+            std::thread::sleep(BLOCK_INTERVAL);
+        }
+
+        let events = link(&channel)
+            .relay_recv_packet_and_timeout_messages()
+            .unwrap();
+        assert!(events
+            .iter()
+            .any(|event| matches!(event, IbcEvent::TimeoutPacket(_))));
+
+        assert!(packet_commitments(&a, &a_port, &a_channel).is_empty());
+
+        // Timing out a packet closes an ordered channel
+        let (channel_end, _) = a
+            .query_channel(
+                QueryChannelRequest {
+                    port_id: a_port,
+                    channel_id: a_channel,
+                    height: QueryHeight::Latest,
+                },
+                IncludeProof::No,
+            )
+            .unwrap();
+        assert!(!channel_end.is_open());
+
+        a.shutdown().unwrap();
+        b.shutdown().unwrap();
+    }
+
+    #[test]
+    fn failed_transaction() {
+        let rt = Arc::new(TokioRuntime::new().unwrap());
+        let a = spawn_chain("mock-1", &rt);
+
+        // There is no channel to send the packet on
+        let msg = MsgTransfer {
+            source_port: PortId::transfer(),
+            source_channel: ChannelId::new(0),
+            token: Coin {
+                denom: "stake".to_string(),
+                amount: "100".to_string(),
+            },
+            sender: a.get_signer().unwrap(),
+            receiver: a.get_signer().unwrap(),
+            timeout_height: TimeoutHeight::Never,
+            timeout_timestamp: Timestamp::none(),
+            memo: None,
+        };
+
+        let events = a
+            .send_messages_and_wait_commit(TrackedMsgs::new_static(vec![msg.to_any()], "transfer"))
+            .unwrap();
+
+        assert!(matches!(events[0].event, IbcEvent::ChainError(_)));
+
+        a.shutdown().unwrap();
+    }
+}
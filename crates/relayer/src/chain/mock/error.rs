@@ -0,0 +1,108 @@
+//! Errors raised by the in-memory IBC store of the mock chain.
+
+use flex_error::{define_error, DisplayOnly};
+
+use ibc_relayer_types::core::ics02_client::error as client_error;
+use ibc_relayer_types::core::ics03_connection::error as connection_error;
+use ibc_relayer_types::core::ics04_channel::error as channel_error;
+use ibc_relayer_types::core::ics04_channel::packet::Sequence;
+use ibc_relayer_types::core::ics24_host::identifier::{
+    ChainId, ChannelId, ClientId, ConnectionId, PortId,
+};
+use ibc_relayer_types::signer::SignerError;
+use ibc_relayer_types::Height;
+
+use crate::chain::solomachine::error as solomachine;
+
+define_error! {
+    Error {
+        UnsupportedMessage
+            { type_url: String }
+            |e| { format_args!("the mock chain does not support messages of type {}", e.type_url) },
+
+        UnsupportedQuery
+            { query: String }
+            |e| { format_args!("the mock chain does not support the {} query", e.query) },
+
+        DecodeMessage
+            { type_url: String }
+            [ DisplayOnly<ibc_proto::protobuf::Error> ]
+            |e| { format_args!("failed to decode message of type {}", e.type_url) },
+
+        Signer
+            [ SignerError ]
+            |_| { "invalid signer" },
+
+        Client
+            [ client_error::Error ]
+            |_| { "ICS02 client error" },
+
+        Connection
+            [ connection_error::Error ]
+            |_| { "ICS03 connection error" },
+
+        Channel
+            [ channel_error::Error ]
+            |_| { "ICS04 channel error" },
+
+        UnsupportedClient
+            { client_type: String }
+            |e| { format_args!("the mock chain only hosts mock and solo machine clients, not clients of type {}", e.client_type) },
+
+        SoloMachine
+            [ solomachine::Error ]
+            |_| { "invalid solo machine header" },
+
+        ClientNotFound
+            { client_id: ClientId }
+            |e| { format_args!("client {} not found", e.client_id) },
+
+        ClientFrozen
+            { client_id: ClientId }
+            |e| { format_args!("client {} is frozen", e.client_id) },
+
+        ConsensusStateNotFound
+            { client_id: ClientId, height: Height }
+            |e| { format_args!("consensus state of client {} at height {} not found", e.client_id, e.height) },
+
+        ConnectionNotFound
+            { connection_id: ConnectionId }
+            |e| { format_args!("connection {} not found", e.connection_id) },
+
+        ChannelNotFound
+            { port_id: PortId, channel_id: ChannelId }
+            |e| { format_args!("channel {}/{} not found", e.port_id, e.channel_id) },
+
+        UpgradeNotFound
+            { port_id: PortId, channel_id: ChannelId }
+            |e| { format_args!("the mock chain does not support upgrades of channel {}/{}", e.port_id, e.channel_id) },
+
+        InvalidState
+            { reason: String }
+            |e| { format_args!("invalid state: {}", e.reason) },
+
+        InvalidProof
+            { path: String, reason: String }
+            |e| { format_args!("invalid proof for path {}: {}", e.path, e.reason) },
+
+        PacketTimedOut
+            { sequence: Sequence }
+            |e| { format_args!("packet with sequence {} has timed out", e.sequence) },
+
+        PacketNotTimedOut
+            { sequence: Sequence }
+            |e| { format_args!("packet with sequence {} has not timed out yet", e.sequence) },
+
+        InvalidPacketSequence
+            { sequence: Sequence, expected: Sequence }
+            |e| { format_args!("invalid packet sequence {}, expected {}", e.sequence, e.expected) },
+
+        InvalidChainId
+            { chain_id: ChainId }
+            |e| { format_args!("invalid mock chain identifier {}, expected mock-<revision number>", e.chain_id) },
+
+        BlockNotFound
+            { height: Height }
+            |e| { format_args!("block at height {} not found", e.height) },
+    }
+}
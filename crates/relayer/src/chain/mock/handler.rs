@@ -0,0 +1,1055 @@
+//! Handlers of the messages submitted to the mock chain.
+//!
+//! The handlers follow the ICS02, ICS03, ICS04 and ICS20 specifications closely enough
+//! to exercise the relayer, but do not implement any application logic: in particular,
+//! token transfers only send a packet, and every received packet is acknowledged with
+//! a successful acknowledgement.
+
+use ibc_proto::google::protobuf::Any;
+use ibc_proto::ibc::applications::transfer::v2::FungibleTokenPacketData as RawPacketData;
+use ibc_proto::protobuf::Protobuf;
+
+use ibc_relayer_types::applications::transfer::acknowledgement::Acknowledgement as TransferAck;
+use ibc_relayer_types::applications::transfer::msgs::transfer::{
+    MsgTransfer, TYPE_URL as TRANSFER_TYPE_URL,
+};
+use ibc_relayer_types::core::ics02_client::client_state::ClientState;
+use ibc_relayer_types::core::ics02_client::error::Error as ClientError;
+use ibc_relayer_types::core::ics02_client::events::{
+    Attributes as ClientAttributes, CreateClient, UpdateClient,
+};
+use ibc_relayer_types::core::ics02_client::header::Header;
+use ibc_relayer_types::core::ics02_client::msgs::create_client::{
+    MsgCreateClient, TYPE_URL as CREATE_CLIENT_TYPE_URL,
+};
+use ibc_relayer_types::core::ics02_client::msgs::update_client::{
+    MsgUpdateClient, TYPE_URL as UPDATE_CLIENT_TYPE_URL,
+};
+use ibc_relayer_types::core::ics03_connection::connection::{
+    ConnectionEnd, Counterparty as ConnectionCounterparty, State as ConnectionState,
+};
+use ibc_relayer_types::core::ics03_connection::events::{
+    Attributes as ConnectionAttributes, OpenAck as ConnectionOpenAck,
+    OpenConfirm as ConnectionOpenConfirm, OpenInit as ConnectionOpenInit,
+    OpenTry as ConnectionOpenTry,
+};
+use ibc_relayer_types::core::ics03_connection::msgs::conn_open_ack::{
+    MsgConnectionOpenAck, TYPE_URL as CONN_OPEN_ACK_TYPE_URL,
+};
+use ibc_relayer_types::core::ics03_connection::msgs::conn_open_confirm::{
+    MsgConnectionOpenConfirm, TYPE_URL as CONN_OPEN_CONFIRM_TYPE_URL,
+};
+use ibc_relayer_types::core::ics03_connection::msgs::conn_open_init::{
+    MsgConnectionOpenInit, TYPE_URL as CONN_OPEN_INIT_TYPE_URL,
+};
+use ibc_relayer_types::core::ics03_connection::msgs::conn_open_try::{
+    MsgConnectionOpenTry, TYPE_URL as CONN_OPEN_TRY_TYPE_URL,
+};
+use ibc_relayer_types::core::ics03_connection::version::{get_compatible_versions, pick_version};
+use ibc_relayer_types::core::ics04_channel::channel::{
+    ChannelEnd, Counterparty as ChannelCounterparty, Ordering, State as ChannelState,
+};
+use ibc_relayer_types::core::ics04_channel::events::{
+    AcknowledgePacket, CloseConfirm, CloseInit, OpenAck as ChannelOpenAck,
+    OpenConfirm as ChannelOpenConfirm, OpenInit as ChannelOpenInit, OpenTry as ChannelOpenTry,
+    ReceivePacket, SendPacket, TimeoutOnClosePacket, TimeoutPacket, WriteAcknowledgement,
+};
+use ibc_relayer_types::core::ics04_channel::msgs::acknowledgement::{
+    MsgAcknowledgement, TYPE_URL as ACKNOWLEDGEMENT_TYPE_URL,
+};
+use ibc_relayer_types::core::ics04_channel::msgs::chan_close_confirm::{
+    MsgChannelCloseConfirm, TYPE_URL as CHAN_CLOSE_CONFIRM_TYPE_URL,
+};
+use ibc_relayer_types::core::ics04_channel::msgs::chan_close_init::{
+    MsgChannelCloseInit, TYPE_URL as CHAN_CLOSE_INIT_TYPE_URL,
+};
+use ibc_relayer_types::core::ics04_channel::msgs::chan_open_ack::{
+    MsgChannelOpenAck, TYPE_URL as CHAN_OPEN_ACK_TYPE_URL,
+};
+use ibc_relayer_types::core::ics04_channel::msgs::chan_open_confirm::{
+    MsgChannelOpenConfirm, TYPE_URL as CHAN_OPEN_CONFIRM_TYPE_URL,
+};
+use ibc_relayer_types::core::ics04_channel::msgs::chan_open_init::{
+    MsgChannelOpenInit, TYPE_URL as CHAN_OPEN_INIT_TYPE_URL,
+};
+use ibc_relayer_types::core::ics04_channel::msgs::chan_open_try::{
+    MsgChannelOpenTry, TYPE_URL as CHAN_OPEN_TRY_TYPE_URL,
+};
+use ibc_relayer_types::core::ics04_channel::msgs::recv_packet::{
+    MsgRecvPacket, TYPE_URL as RECV_PACKET_TYPE_URL,
+};
+use ibc_relayer_types::core::ics04_channel::msgs::timeout::{
+    MsgTimeout, TYPE_URL as TIMEOUT_TYPE_URL,
+};
+use ibc_relayer_types::core::ics04_channel::msgs::timeout_on_close::{
+    MsgTimeoutOnClose, TYPE_URL as TIMEOUT_ON_CLOSE_TYPE_URL,
+};
+use ibc_relayer_types::core::ics04_channel::packet::{Packet, Sequence};
+use ibc_relayer_types::core::ics23_commitment::commitment::CommitmentProofBytes;
+use ibc_relayer_types::core::ics24_host::identifier::{ChannelId, ClientId, ConnectionId};
+use ibc_relayer_types::core::ics24_host::path::{
+    ChannelEndsPath, ClientStatePath, ConnectionsPath, SeqRecvsPath,
+};
+use ibc_relayer_types::events::IbcEvent;
+#[cfg(any(test, feature = "mocks"))]
+use ibc_relayer_types::mock::client_state::MockClientState;
+#[cfg(any(test, feature = "mocks"))]
+use ibc_relayer_types::mock::consensus_state::MockConsensusState;
+use ibc_relayer_types::proofs::Proofs;
+use ibc_relayer_types::timestamp::Timestamp;
+use ibc_relayer_types::Height;
+
+use super::error::Error;
+use super::store::{
+    acknowledgement_commitment, acks_path, commitments_path, consensus_state_path,
+    packet_commitment, receipts_path, verify_membership, verify_non_membership, ChannelRecord,
+    ClientRecord, IbcStore, PacketKey,
+};
+use crate::chain::solomachine::update_client_state;
+use crate::client_state::AnyClientState;
+use crate::consensus_state::AnyConsensusState;
+use crate::light_client::AnyHeader;
+
+/// Decode the message carried by the given `Any`, whose type URL has already been matched.
+macro_rules! decode {
+    ($msg:expr) => {
+        Protobuf::decode_vec(&$msg.value)
+            .map_err(|e| Error::decode_message($msg.type_url.clone(), e))?
+    };
+}
+
+impl IbcStore {
+    /// Apply the given message to the store, as part of the block at the given height
+    /// and timestamp, and return the events emitted by the message.
+    pub fn deliver(
+        &mut self,
+        msg: &Any,
+        height: Height,
+        timestamp: Timestamp,
+    ) -> Result<Vec<IbcEvent>, Error> {
+        match msg.type_url.as_str() {
+            CREATE_CLIENT_TYPE_URL => self.create_client(decode!(msg)),
+            UPDATE_CLIENT_TYPE_URL => self.update_client(decode!(msg)),
+
+            CONN_OPEN_INIT_TYPE_URL => self.conn_open_init(decode!(msg)),
+            CONN_OPEN_TRY_TYPE_URL => self.conn_open_try(decode!(msg)),
+            CONN_OPEN_ACK_TYPE_URL => self.conn_open_ack(decode!(msg)),
+            CONN_OPEN_CONFIRM_TYPE_URL => self.conn_open_confirm(decode!(msg)),
+
+            CHAN_OPEN_INIT_TYPE_URL => self.chan_open_init(decode!(msg)),
+            CHAN_OPEN_TRY_TYPE_URL => self.chan_open_try(decode!(msg)),
+            CHAN_OPEN_ACK_TYPE_URL => self.chan_open_ack(decode!(msg)),
+            CHAN_OPEN_CONFIRM_TYPE_URL => self.chan_open_confirm(decode!(msg)),
+            CHAN_CLOSE_INIT_TYPE_URL => self.chan_close_init(decode!(msg)),
+            CHAN_CLOSE_CONFIRM_TYPE_URL => self.chan_close_confirm(decode!(msg)),
+
+            TRANSFER_TYPE_URL => self.transfer(decode!(msg)),
+            RECV_PACKET_TYPE_URL => self.recv_packet(decode!(msg), height, timestamp),
+            ACKNOWLEDGEMENT_TYPE_URL => self.acknowledgement(decode!(msg)),
+            TIMEOUT_TYPE_URL => self.timeout(decode!(msg)),
+            TIMEOUT_ON_CLOSE_TYPE_URL => self.timeout_on_close(decode!(msg)),
+
+            _ => Err(Error::unsupported_message(msg.type_url.clone())),
+        }
+    }
+
+    fn create_client(&mut self, msg: MsgCreateClient) -> Result<Vec<IbcEvent>, Error> {
+        let client_state = AnyClientState::try_from(msg.client_state).map_err(Error::client)?;
+        let consensus_state =
+            AnyConsensusState::try_from(msg.consensus_state).map_err(Error::client)?;
+
+        let client_type = client_state.client_type();
+        match client_state {
+            #[cfg(any(test, feature = "mocks"))]
+            AnyClientState::Mock(_) => {}
+            AnyClientState::Solomachine(_) => {}
+            _ => return Err(Error::unsupported_client(client_type.to_string())),
+        }
+
+        let client_id = ClientId::new(client_type, self.client_counter)
+            .map_err(|e| Error::client(ClientError::invalid_client_identifier(e)))?;
+        self.client_counter += 1;
+
+        let consensus_height = client_state.latest_height();
+
+        // Like ibc-go, only keep the consensus state of a solo machine within its client state
+        let consensus_states = match client_state {
+            AnyClientState::Solomachine(_) => Default::default(),
+            _ => [(consensus_height, consensus_state)].into(),
+        };
+
+        self.clients.insert(
+            client_id.clone(),
+            ClientRecord {
+                client_state,
+                consensus_states,
+            },
+        );
+
+        Ok(vec![CreateClient::from(ClientAttributes {
+            client_id,
+            client_type,
+            consensus_height,
+        })
+        .into()])
+    }
+
+    fn update_client(&mut self, msg: MsgUpdateClient) -> Result<Vec<IbcEvent>, Error> {
+        let header = AnyHeader::try_from(msg.header).map_err(Error::client)?;
+
+        let client = self
+            .clients
+            .get_mut(&msg.client_id)
+            .ok_or_else(|| Error::client_not_found(msg.client_id.clone()))?;
+
+        if client.client_state.is_frozen() {
+            return Err(Error::client_frozen(msg.client_id));
+        }
+
+        let consensus_height = match (&client.client_state, &header) {
+            #[cfg(any(test, feature = "mocks"))]
+            (AnyClientState::Mock(client_state), AnyHeader::Mock(header)) => {
+                if header.height() > client_state.latest_height() {
+                    client.client_state = MockClientState::new(*header).into();
+                }
+
+                client
+                    .consensus_states
+                    .insert(header.height(), MockConsensusState::new(*header).into());
+
+                header.height()
+            }
+            (AnyClientState::Solomachine(client_state), AnyHeader::Solomachine(header)) => {
+                let client_state =
+                    update_client_state(client_state, header).map_err(Error::solo_machine)?;
+                let consensus_height = client_state.latest_height();
+                client.client_state = client_state.into();
+
+                consensus_height
+            }
+            (_, header) => return Err(Error::unsupported_client(header.client_type().to_string())),
+        };
+
+        Ok(vec![UpdateClient {
+            common: ClientAttributes {
+                client_id: msg.client_id,
+                client_type: header.client_type(),
+                consensus_height,
+            },
+            header: Some(Box::new(header)),
+        }
+        .into()])
+    }
+
+    fn conn_open_init(&mut self, msg: MsgConnectionOpenInit) -> Result<Vec<IbcEvent>, Error> {
+        self.client(&msg.client_id)?;
+
+        let versions = match msg.version {
+            Some(version) => vec![version],
+            None => get_compatible_versions(),
+        };
+
+        let connection_id = ConnectionId::new(self.connection_counter);
+        self.connection_counter += 1;
+
+        let attributes = ConnectionAttributes {
+            connection_id: Some(connection_id.clone()),
+            client_id: msg.client_id.clone(),
+            counterparty_connection_id: None,
+            counterparty_client_id: msg.counterparty.client_id().clone(),
+        };
+
+        self.connections.insert(
+            connection_id,
+            ConnectionEnd::new(
+                ConnectionState::Init,
+                msg.client_id,
+                msg.counterparty,
+                versions,
+                msg.delay_period,
+            ),
+        );
+
+        Ok(vec![ConnectionOpenInit::from(attributes).into()])
+    }
+
+    fn conn_open_try(&mut self, msg: MsgConnectionOpenTry) -> Result<Vec<IbcEvent>, Error> {
+        let counterparty_connection_id =
+            msg.counterparty.connection_id().cloned().ok_or_else(|| {
+                Error::invalid_state("missing counterparty connection".to_string())
+            })?;
+
+        self.verify_connection_proofs(
+            &msg.client_id,
+            &msg.proofs,
+            &counterparty_connection_id,
+            msg.counterparty.client_id(),
+        )?;
+
+        let version = pick_version(get_compatible_versions(), msg.counterparty_versions)
+            .map_err(Error::connection)?;
+
+        let connection_id = ConnectionId::new(self.connection_counter);
+        self.connection_counter += 1;
+
+        let attributes = ConnectionAttributes {
+            connection_id: Some(connection_id.clone()),
+            client_id: msg.client_id.clone(),
+            counterparty_connection_id: Some(counterparty_connection_id),
+            counterparty_client_id: msg.counterparty.client_id().clone(),
+        };
+
+        self.connections.insert(
+            connection_id,
+            ConnectionEnd::new(
+                ConnectionState::TryOpen,
+                msg.client_id,
+                msg.counterparty,
+                vec![version],
+                msg.delay_period,
+            ),
+        );
+
+        Ok(vec![ConnectionOpenTry::from(attributes).into()])
+    }
+
+    fn conn_open_ack(&mut self, msg: MsgConnectionOpenAck) -> Result<Vec<IbcEvent>, Error> {
+        let mut connection = self.connection(&msg.connection_id)?.clone();
+
+        if !connection.state_matches(&ConnectionState::Init) {
+            return Err(Error::invalid_state(format!(
+                "connection {} is not in state Init",
+                msg.connection_id
+            )));
+        }
+
+        self.verify_connection_proofs(
+            connection.client_id(),
+            &msg.proofs,
+            &msg.counterparty_connection_id,
+            connection.counterparty().client_id(),
+        )?;
+
+        connection.set_state(ConnectionState::Open);
+        connection.set_version(msg.version);
+        connection.set_counterparty(ConnectionCounterparty::new(
+            connection.counterparty().client_id().clone(),
+            Some(msg.counterparty_connection_id.clone()),
+            connection.counterparty().prefix().clone(),
+        ));
+
+        let attributes = ConnectionAttributes {
+            connection_id: Some(msg.connection_id.clone()),
+            client_id: connection.client_id().clone(),
+            counterparty_connection_id: Some(msg.counterparty_connection_id),
+            counterparty_client_id: connection.counterparty().client_id().clone(),
+        };
+
+        self.connections.insert(msg.connection_id, connection);
+
+        Ok(vec![ConnectionOpenAck::from(attributes).into()])
+    }
+
+    fn conn_open_confirm(&mut self, msg: MsgConnectionOpenConfirm) -> Result<Vec<IbcEvent>, Error> {
+        let mut connection = self.connection(&msg.connection_id)?.clone();
+
+        if !connection.state_matches(&ConnectionState::TryOpen) {
+            return Err(Error::invalid_state(format!(
+                "connection {} is not in state TryOpen",
+                msg.connection_id
+            )));
+        }
+
+        let counterparty_connection_id = connection
+            .counterparty()
+            .connection_id()
+            .cloned()
+            .ok_or_else(|| Error::invalid_state("missing counterparty connection".to_string()))?;
+
+        self.verify_membership(
+            connection.client_id(),
+            &msg.proofs,
+            msg.proofs.object_proof(),
+            ConnectionsPath(counterparty_connection_id.clone()),
+            None,
+        )?;
+
+        connection.set_state(ConnectionState::Open);
+
+        let attributes = ConnectionAttributes {
+            connection_id: Some(msg.connection_id.clone()),
+            client_id: connection.client_id().clone(),
+            counterparty_connection_id: Some(counterparty_connection_id),
+            counterparty_client_id: connection.counterparty().client_id().clone(),
+        };
+
+        self.connections.insert(msg.connection_id, connection);
+
+        Ok(vec![ConnectionOpenConfirm::from(attributes).into()])
+    }
+
+    fn chan_open_init(&mut self, msg: MsgChannelOpenInit) -> Result<Vec<IbcEvent>, Error> {
+        let connection_id = self.channel_connection(&msg.channel)?;
+
+        let channel_id = ChannelId::new(self.channel_counter);
+        self.channel_counter += 1;
+
+        let mut channel_end = msg.channel;
+        channel_end.set_state(ChannelState::Init);
+
+        let event = ChannelOpenInit {
+            port_id: msg.port_id.clone(),
+            channel_id: Some(channel_id.clone()),
+            connection_id,
+            counterparty_port_id: channel_end.counterparty().port_id().clone(),
+            counterparty_channel_id: None,
+        };
+
+        self.channels
+            .insert((msg.port_id, channel_id), ChannelRecord::new(channel_end));
+
+        Ok(vec![event.into()])
+    }
+
+    fn chan_open_try(&mut self, msg: MsgChannelOpenTry) -> Result<Vec<IbcEvent>, Error> {
+        let connection_id = self.channel_connection(&msg.channel)?;
+        let client_id = self.connection(&connection_id)?.client_id().clone();
+
+        let counterparty = msg.channel.counterparty().clone();
+        let counterparty_channel_id = counterparty
+            .channel_id()
+            .cloned()
+            .ok_or_else(|| Error::invalid_state("missing counterparty channel".to_string()))?;
+
+        self.verify_membership(
+            &client_id,
+            &msg.proofs,
+            msg.proofs.object_proof(),
+            ChannelEndsPath(
+                counterparty.port_id().clone(),
+                counterparty_channel_id.clone(),
+            ),
+            None,
+        )?;
+
+        let channel_id = ChannelId::new(self.channel_counter);
+        self.channel_counter += 1;
+
+        let mut channel_end = msg.channel;
+        channel_end.set_state(ChannelState::TryOpen);
+        if channel_end.version().to_string().is_empty() {
+            channel_end.set_version(msg.counterparty_version);
+        }
+
+        let event = ChannelOpenTry {
+            port_id: msg.port_id.clone(),
+            channel_id: Some(channel_id.clone()),
+            connection_id,
+            counterparty_port_id: counterparty.port_id().clone(),
+            counterparty_channel_id: Some(counterparty_channel_id),
+        };
+
+        self.channels
+            .insert((msg.port_id, channel_id), ChannelRecord::new(channel_end));
+
+        Ok(vec![event.into()])
+    }
+
+    fn chan_open_ack(&mut self, msg: MsgChannelOpenAck) -> Result<Vec<IbcEvent>, Error> {
+        let mut channel_end = self
+            .channel(&msg.port_id, &msg.channel_id)?
+            .channel_end
+            .clone();
+
+        if !channel_end.state_matches(&ChannelState::Init) {
+            return Err(Error::invalid_state(format!(
+                "channel {}/{} is not in state Init",
+                msg.port_id, msg.channel_id
+            )));
+        }
+
+        let connection_id = self.channel_connection(&channel_end)?;
+        let client_id = self.connection(&connection_id)?.client_id().clone();
+        let counterparty_port_id = channel_end.counterparty().port_id().clone();
+
+        self.verify_membership(
+            &client_id,
+            &msg.proofs,
+            msg.proofs.object_proof(),
+            ChannelEndsPath(
+                counterparty_port_id.clone(),
+                msg.counterparty_channel_id.clone(),
+            ),
+            None,
+        )?;
+
+        channel_end.set_state(ChannelState::Open);
+        channel_end.set_version(msg.counterparty_version);
+        channel_end.set_counterparty_channel_id(msg.counterparty_channel_id.clone());
+
+        self.channel_mut(&msg.port_id, &msg.channel_id)?.channel_end = channel_end;
+
+        Ok(vec![ChannelOpenAck {
+            port_id: msg.port_id,
+            channel_id: Some(msg.channel_id),
+            counterparty_channel_id: Some(msg.counterparty_channel_id),
+            connection_id,
+            counterparty_port_id,
+        }
+        .into()])
+    }
+
+    fn chan_open_confirm(&mut self, msg: MsgChannelOpenConfirm) -> Result<Vec<IbcEvent>, Error> {
+        let mut channel_end = self
+            .channel(&msg.port_id, &msg.channel_id)?
+            .channel_end
+            .clone();
+
+        if !channel_end.state_matches(&ChannelState::TryOpen) {
+            return Err(Error::invalid_state(format!(
+                "channel {}/{} is not in state TryOpen",
+                msg.port_id, msg.channel_id
+            )));
+        }
+
+        let connection_id = self.channel_connection(&channel_end)?;
+        let client_id = self.connection(&connection_id)?.client_id().clone();
+        let counterparty = channel_end.counterparty().clone();
+
+        self.verify_membership(
+            &client_id,
+            &msg.proofs,
+            msg.proofs.object_proof(),
+            counterparty_channel_path(&counterparty)?,
+            None,
+        )?;
+
+        channel_end.set_state(ChannelState::Open);
+
+        self.channel_mut(&msg.port_id, &msg.channel_id)?.channel_end = channel_end;
+
+        Ok(vec![ChannelOpenConfirm {
+            port_id: msg.port_id,
+            channel_id: Some(msg.channel_id),
+            connection_id,
+            counterparty_port_id: counterparty.port_id,
+            counterparty_channel_id: counterparty.channel_id,
+        }
+        .into()])
+    }
+
+    fn chan_close_init(&mut self, msg: MsgChannelCloseInit) -> Result<Vec<IbcEvent>, Error> {
+        let channel = self.channel_mut(&msg.port_id, &msg.channel_id)?;
+
+        if channel.channel_end.state_matches(&ChannelState::Closed) {
+            return Err(Error::invalid_state(format!(
+                "channel {}/{} is already closed",
+                msg.port_id, msg.channel_id
+            )));
+        }
+
+        channel.channel_end.set_state(ChannelState::Closed);
+
+        let channel_end = channel.channel_end.clone();
+        let connection_id = self.channel_connection(&channel_end)?;
+
+        Ok(vec![CloseInit {
+            port_id: msg.port_id,
+            channel_id: msg.channel_id,
+            connection_id,
+            counterparty_port_id: channel_end.remote.port_id,
+            counterparty_channel_id: channel_end.remote.channel_id,
+        }
+        .into()])
+    }
+
+    fn chan_close_confirm(&mut self, msg: MsgChannelCloseConfirm) -> Result<Vec<IbcEvent>, Error> {
+        let mut channel_end = self
+            .channel(&msg.port_id, &msg.channel_id)?
+            .channel_end
+            .clone();
+
+        if channel_end.state_matches(&ChannelState::Closed) {
+            return Err(Error::invalid_state(format!(
+                "channel {}/{} is already closed",
+                msg.port_id, msg.channel_id
+            )));
+        }
+
+        let connection_id = self.channel_connection(&channel_end)?;
+        let client_id = self.connection(&connection_id)?.client_id().clone();
+        let counterparty = channel_end.counterparty().clone();
+
+        self.verify_membership(
+            &client_id,
+            &msg.proofs,
+            msg.proofs.object_proof(),
+            counterparty_channel_path(&counterparty)?,
+            None,
+        )?;
+
+        channel_end.set_state(ChannelState::Closed);
+
+        self.channel_mut(&msg.port_id, &msg.channel_id)?.channel_end = channel_end;
+
+        Ok(vec![CloseConfirm {
+            channel_id: Some(msg.channel_id),
+            port_id: msg.port_id,
+            connection_id,
+            counterparty_port_id: counterparty.port_id,
+            counterparty_channel_id: counterparty.channel_id,
+        }
+        .into()])
+    }
+
+    fn transfer(&mut self, msg: MsgTransfer) -> Result<Vec<IbcEvent>, Error> {
+        let channel = self.channel_mut(&msg.source_port, &msg.source_channel)?;
+
+        if !channel.channel_end.is_open() {
+            return Err(Error::invalid_state(format!(
+                "channel {}/{} is not open",
+                msg.source_port, msg.source_channel
+            )));
+        }
+
+        let counterparty = channel.channel_end.counterparty().clone();
+        let destination_channel = counterparty
+            .channel_id()
+            .cloned()
+            .ok_or_else(|| Error::invalid_state("missing counterparty channel".to_string()))?;
+
+        let data = RawPacketData {
+            denom: msg.token.denom,
+            amount: msg.token.amount,
+            sender: msg.sender.to_string(),
+            receiver: msg.receiver.to_string(),
+            memo: msg.memo.unwrap_or_default(),
+        };
+
+        let sequence = channel.next_sequence_send;
+        channel.next_sequence_send = sequence.increment();
+
+        let packet = Packet {
+            sequence,
+            source_port: msg.source_port,
+            source_channel: msg.source_channel,
+            destination_port: counterparty.port_id,
+            destination_channel,
+            data: serde_json::to_vec(&data).expect("packet data can be serialized"),
+            timeout_height: msg.timeout_height,
+            timeout_timestamp: msg.timeout_timestamp,
+        };
+
+        self.packet_commitments
+            .insert(packet_key(&packet), packet_commitment(&packet));
+
+        Ok(vec![SendPacket { packet }.into()])
+    }
+
+    fn recv_packet(
+        &mut self,
+        msg: MsgRecvPacket,
+        height: Height,
+        timestamp: Timestamp,
+    ) -> Result<Vec<IbcEvent>, Error> {
+        let packet = msg.packet;
+        let channel = self
+            .channel(&packet.destination_port, &packet.destination_channel)?
+            .clone();
+
+        self.check_packet_channel(&channel.channel_end, &packet, true)?;
+
+        if packet.timed_out(&timestamp, height) {
+            return Err(Error::packet_timed_out(packet.sequence));
+        }
+
+        let client_id = self.channel_client(&channel.channel_end)?;
+        let src_key = (
+            packet.source_port.clone(),
+            packet.source_channel.clone(),
+            packet.sequence,
+        );
+
+        self.verify_membership(
+            &client_id,
+            &msg.proofs,
+            msg.proofs.object_proof(),
+            commitments_path(&src_key),
+            Some(&packet_commitment(&packet)),
+        )?;
+
+        let key = dst_packet_key(&packet);
+
+        match channel.channel_end.ordering {
+            Ordering::Ordered => {
+                if packet.sequence < channel.next_sequence_recv {
+                    // The packet was already received, nothing to do
+                    return Ok(vec![]);
+                }
+
+                if packet.sequence != channel.next_sequence_recv {
+                    return Err(Error::invalid_packet_sequence(
+                        packet.sequence,
+                        channel.next_sequence_recv,
+                    ));
+                }
+
+                self.channel_mut(&packet.destination_port, &packet.destination_channel)?
+                    .next_sequence_recv = packet.sequence.increment();
+            }
+            _ => {
+                if !self.packet_receipts.insert(key.clone()) {
+                    // The packet was already received, nothing to do
+                    return Ok(vec![]);
+                }
+            }
+        }
+
+        let ack =
+            serde_json::to_vec(&TransferAck::success()).expect("acknowledgement can be serialized");
+
+        self.packet_acknowledgements
+            .insert(key, acknowledgement_commitment(&ack));
+
+        Ok(vec![
+            ReceivePacket {
+                packet: packet.clone(),
+            }
+            .into(),
+            WriteAcknowledgement { packet, ack }.into(),
+        ])
+    }
+
+    fn acknowledgement(&mut self, msg: MsgAcknowledgement) -> Result<Vec<IbcEvent>, Error> {
+        let packet = msg.packet;
+        let channel = self
+            .channel(&packet.source_port, &packet.source_channel)?
+            .clone();
+
+        self.check_packet_channel(&channel.channel_end, &packet, false)?;
+
+        let key = packet_key(&packet);
+
+        if !self.packet_commitments.contains_key(&key) {
+            // The packet was already acknowledged or timed out, nothing to do
+            return Ok(vec![]);
+        }
+
+        let client_id = self.channel_client(&channel.channel_end)?;
+
+        self.verify_membership(
+            &client_id,
+            &msg.proofs,
+            msg.proofs.object_proof(),
+            acks_path(&dst_packet_key(&packet)),
+            Some(&acknowledgement_commitment(msg.acknowledgement.as_ref())),
+        )?;
+
+        if channel.channel_end.ordering == Ordering::Ordered {
+            if packet.sequence != channel.next_sequence_ack {
+                return Err(Error::invalid_packet_sequence(
+                    packet.sequence,
+                    channel.next_sequence_ack,
+                ));
+            }
+
+            self.channel_mut(&packet.source_port, &packet.source_channel)?
+                .next_sequence_ack = packet.sequence.increment();
+        }
+
+        self.packet_commitments.remove(&key);
+
+        Ok(vec![AcknowledgePacket { packet }.into()])
+    }
+
+    fn timeout(&mut self, msg: MsgTimeout) -> Result<Vec<IbcEvent>, Error> {
+        let packet = msg.packet;
+        let channel = self
+            .channel(&packet.source_port, &packet.source_channel)?
+            .clone();
+
+        self.check_packet_channel(&channel.channel_end, &packet, false)?;
+
+        let key = packet_key(&packet);
+
+        if !self.packet_commitments.contains_key(&key) {
+            // The packet was already acknowledged or timed out, nothing to do
+            return Ok(vec![]);
+        }
+
+        let client_id = self.channel_client(&channel.channel_end)?;
+        self.check_packet_timed_out(&client_id, &packet, msg.proofs.height())?;
+
+        self.verify_packet_not_received(
+            &client_id,
+            &channel.channel_end,
+            &packet,
+            msg.next_sequence_recv,
+            &msg.proofs,
+        )?;
+
+        self.packet_commitments.remove(&key);
+
+        if channel.channel_end.ordering == Ordering::Ordered {
+            self.channel_mut(&packet.source_port, &packet.source_channel)?
+                .channel_end
+                .set_state(ChannelState::Closed);
+        }
+
+        Ok(vec![TimeoutPacket { packet }.into()])
+    }
+
+    fn timeout_on_close(&mut self, msg: MsgTimeoutOnClose) -> Result<Vec<IbcEvent>, Error> {
+        let packet = msg.packet;
+        let channel = self
+            .channel(&packet.source_port, &packet.source_channel)?
+            .clone();
+
+        self.check_packet_channel(&channel.channel_end, &packet, false)?;
+
+        let key = packet_key(&packet);
+
+        if !self.packet_commitments.contains_key(&key) {
+            // The packet was already acknowledged or timed out, nothing to do
+            return Ok(vec![]);
+        }
+
+        let client_id = self.channel_client(&channel.channel_end)?;
+
+        let channel_proof =
+            msg.proofs.other_proof().as_ref().ok_or_else(|| {
+                Error::invalid_state("missing proof of the closed channel".into())
+            })?;
+
+        self.verify_membership(
+            &client_id,
+            &msg.proofs,
+            channel_proof,
+            ChannelEndsPath(
+                packet.destination_port.clone(),
+                packet.destination_channel.clone(),
+            ),
+            None,
+        )?;
+
+        self.verify_packet_not_received(
+            &client_id,
+            &channel.channel_end,
+            &packet,
+            msg.next_sequence_recv,
+            &msg.proofs,
+        )?;
+
+        self.packet_commitments.remove(&key);
+
+        Ok(vec![TimeoutOnClosePacket { packet }.into()])
+    }
+
+    /// The connection of the given channel end, which must exist on this chain.
+    fn channel_connection(&self, channel_end: &ChannelEnd) -> Result<ConnectionId, Error> {
+        let connection_id = channel_end
+            .connection_hops()
+            .first()
+            .cloned()
+            .ok_or_else(|| Error::invalid_state("channel without connection hops".to_string()))?;
+
+        self.connection(&connection_id)?;
+
+        Ok(connection_id)
+    }
+
+    /// The client of the connection of the given channel end.
+    fn channel_client(&self, channel_end: &ChannelEnd) -> Result<ClientId, Error> {
+        let connection_id = self.channel_connection(channel_end)?;
+
+        Ok(self.connection(&connection_id)?.client_id().clone())
+    }
+
+    /// Check that the packet was sent on the given channel end and its counterparty.
+    fn check_packet_channel(
+        &self,
+        channel_end: &ChannelEnd,
+        packet: &Packet,
+        is_destination: bool,
+    ) -> Result<(), Error> {
+        if is_destination && !channel_end.is_open() {
+            return Err(Error::invalid_state(format!(
+                "channel {}/{} is not open",
+                packet.destination_port, packet.destination_channel
+            )));
+        }
+
+        let (port_id, channel_id) = if is_destination {
+            (&packet.source_port, &packet.source_channel)
+        } else {
+            (&packet.destination_port, &packet.destination_channel)
+        };
+
+        let counterparty = channel_end.counterparty();
+
+        if counterparty.port_id() != port_id || counterparty.channel_id() != Some(channel_id) {
+            return Err(Error::invalid_state(format!(
+                "packet counterparty {port_id}/{channel_id} does not match the channel counterparty"
+            )));
+        }
+
+        Ok(())
+    }
+
+    /// Check that the packet had timed out on the counterparty chain at the given height.
+    fn check_packet_timed_out(
+        &self,
+        client_id: &ClientId,
+        packet: &Packet,
+        proof_height: Height,
+    ) -> Result<(), Error> {
+        let consensus_state = self
+            .client(client_id)?
+            .consensus_states
+            .get(&proof_height)
+            .ok_or_else(|| Error::consensus_state_not_found(client_id.clone(), proof_height))?;
+
+        if !packet.timed_out(&consensus_state.timestamp(), proof_height) {
+            return Err(Error::packet_not_timed_out(packet.sequence));
+        }
+
+        Ok(())
+    }
+
+    /// Verify the proof that the packet was not received on the counterparty chain.
+    fn verify_packet_not_received(
+        &self,
+        client_id: &ClientId,
+        channel_end: &ChannelEnd,
+        packet: &Packet,
+        next_sequence_recv: Sequence,
+        proofs: &Proofs,
+    ) -> Result<(), Error> {
+        match channel_end.ordering {
+            Ordering::Ordered => {
+                if packet.sequence < next_sequence_recv {
+                    return Err(Error::invalid_state(format!(
+                        "packet with sequence {} was received",
+                        packet.sequence
+                    )));
+                }
+
+                self.verify_membership(
+                    client_id,
+                    proofs,
+                    proofs.object_proof(),
+                    SeqRecvsPath(
+                        packet.destination_port.clone(),
+                        packet.destination_channel.clone(),
+                    ),
+                    Some(&u64::from(next_sequence_recv).to_be_bytes()),
+                )
+            }
+            _ => {
+                self.check_proof_height(client_id, proofs.height())?;
+
+                verify_non_membership(
+                    proofs.object_proof(),
+                    receipts_path(&dst_packet_key(packet)),
+                )
+            }
+        }
+    }
+
+    /// Verify the proofs of a connection handshake message, ie. the proof of the
+    /// counterparty connection end and, if any, the proofs of the client state
+    /// and the consensus state of the counterparty client.
+    fn verify_connection_proofs(
+        &self,
+        client_id: &ClientId,
+        proofs: &Proofs,
+        counterparty_connection_id: &ConnectionId,
+        counterparty_client_id: &ClientId,
+    ) -> Result<(), Error> {
+        self.verify_membership(
+            client_id,
+            proofs,
+            proofs.object_proof(),
+            ConnectionsPath(counterparty_connection_id.clone()),
+            None,
+        )?;
+
+        if let Some(client_proof) = proofs.client_proof() {
+            self.verify_membership(
+                client_id,
+                proofs,
+                client_proof,
+                ClientStatePath(counterparty_client_id.clone()),
+                None,
+            )?;
+        }
+
+        if let Some(consensus_proof) = proofs.consensus_proof() {
+            self.verify_membership(
+                client_id,
+                proofs,
+                consensus_proof.proof(),
+                consensus_state_path(counterparty_client_id, consensus_proof.height()),
+                None,
+            )?;
+        }
+
+        Ok(())
+    }
+
+    /// Verify the given proof of the counterparty state at `proofs.height()`,
+    /// which must be the height of a consensus state of the client.
+    fn verify_membership(
+        &self,
+        client_id: &ClientId,
+        proofs: &Proofs,
+        proof: &CommitmentProofBytes,
+        path: impl core::fmt::Display,
+        value: Option<&[u8]>,
+    ) -> Result<(), Error> {
+        self.check_proof_height(client_id, proofs.height())?;
+
+        verify_membership(proof, path, value)
+    }
+
+    fn check_proof_height(&self, client_id: &ClientId, height: Height) -> Result<(), Error> {
+        let client = self.client(client_id)?;
+
+        if client.client_state.is_frozen() {
+            return Err(Error::client_frozen(client_id.clone()));
+        }
+
+        if !client.consensus_states.contains_key(&height) {
+            return Err(Error::consensus_state_not_found(client_id.clone(), height));
+        }
+
+        Ok(())
+    }
+}
+
+fn counterparty_channel_path(counterparty: &ChannelCounterparty) -> Result<ChannelEndsPath, Error> {
+    let channel_id = counterparty
+        .channel_id()
+        .cloned()
+        .ok_or_else(|| Error::invalid_state("missing counterparty channel".to_string()))?;
+
+    Ok(ChannelEndsPath(counterparty.port_id().clone(), channel_id))
+}
+
+/// The key of the packet commitment on the source chain.
+fn packet_key(packet: &Packet) -> PacketKey {
+    (
+        packet.source_port.clone(),
+        packet.source_channel.clone(),
+        packet.sequence,
+    )
+}
+
+/// The key of the packet receipt and acknowledgement on the destination chain.
+fn dst_packet_key(packet: &Packet) -> PacketKey {
+    (
+        packet.destination_port.clone(),
+        packet.destination_channel.clone(),
+        packet.sequence,
+    )
+}
@@ -0,0 +1,357 @@
+//! The in-memory IBC store of the mock chain, ie. the state of its IBC
+//! module at a given height, along with the proofs of its entries.
+//!
+//! The proofs produced by the store are not Merkle proofs: each of them holds a
+//! single ICS23 existence (or non-existence) proof whose key is the ICS24 path of
+//! the entry and whose value is the encoded entry. This is enough for the mock
+//! chain on the other end of a path to check that the relayer submitted the proof
+//! of the entry expected by the message, at a height known to the mock client.
+
+use alloc::collections::{BTreeMap, BTreeSet};
+use core::fmt::Display;
+
+use ibc_proto::google::protobuf::Any;
+use ibc_proto::ibc::core::commitment::v1::MerkleProof as RawMerkleProof;
+use ibc_proto::ics23::commitment_proof::Proof;
+use ibc_proto::ics23::{CommitmentProof, ExistenceProof, NonExistenceProof};
+use ibc_proto::protobuf::Protobuf;
+use prost::Message;
+use sha2::{Digest, Sha256};
+
+use ibc_relayer_types::core::ics03_connection::connection::ConnectionEnd;
+use ibc_relayer_types::core::ics04_channel::channel::ChannelEnd;
+use ibc_relayer_types::core::ics04_channel::packet::{Packet, Sequence};
+use ibc_relayer_types::core::ics23_commitment::commitment::CommitmentProofBytes;
+use ibc_relayer_types::core::ics23_commitment::merkle::MerkleProof;
+use ibc_relayer_types::core::ics24_host::identifier::{ChannelId, ClientId, ConnectionId, PortId};
+use ibc_relayer_types::core::ics24_host::path::{
+    AcksPath, ChannelEndsPath, ClientConsensusStatePath, ClientStatePath, CommitmentsPath,
+    ConnectionsPath, ReceiptsPath, SeqRecvsPath,
+};
+use ibc_relayer_types::Height;
+
+use super::error::Error;
+use crate::client_state::AnyClientState;
+use crate::consensus_state::AnyConsensusState;
+
+/// A client hosted by the mock chain, along with its consensus states.
+#[derive(Clone, Debug)]
+pub struct ClientRecord {
+    pub client_state: AnyClientState,
+    pub consensus_states: BTreeMap<Height, AnyConsensusState>,
+}
+
+/// A channel end of the mock chain, along with its sequence numbers.
+#[derive(Clone, Debug)]
+pub struct ChannelRecord {
+    pub channel_end: ChannelEnd,
+    pub next_sequence_send: Sequence,
+    pub next_sequence_recv: Sequence,
+    pub next_sequence_ack: Sequence,
+}
+
+impl ChannelRecord {
+    pub fn new(channel_end: ChannelEnd) -> Self {
+        Self {
+            channel_end,
+            next_sequence_send: Sequence::from(1),
+            next_sequence_recv: Sequence::from(1),
+            next_sequence_ack: Sequence::from(1),
+        }
+    }
+}
+
+pub type PacketKey = (PortId, ChannelId, Sequence);
+
+#[derive(Clone, Debug, Default)]
+pub struct IbcStore {
+    pub clients: BTreeMap<ClientId, ClientRecord>,
+    pub client_counter: u64,
+
+    pub connections: BTreeMap<ConnectionId, ConnectionEnd>,
+    pub connection_counter: u64,
+
+    pub channels: BTreeMap<(PortId, ChannelId), ChannelRecord>,
+    pub channel_counter: u64,
+
+    pub packet_commitments: BTreeMap<PacketKey, Vec<u8>>,
+    pub packet_receipts: BTreeSet<PacketKey>,
+    pub packet_acknowledgements: BTreeMap<PacketKey, Vec<u8>>,
+}
+
+impl IbcStore {
+    pub fn client(&self, client_id: &ClientId) -> Result<&ClientRecord, Error> {
+        self.clients
+            .get(client_id)
+            .ok_or_else(|| Error::client_not_found(client_id.clone()))
+    }
+
+    pub fn connection(&self, connection_id: &ConnectionId) -> Result<&ConnectionEnd, Error> {
+        self.connections
+            .get(connection_id)
+            .ok_or_else(|| Error::connection_not_found(connection_id.clone()))
+    }
+
+    pub fn channel(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<&ChannelRecord, Error> {
+        self.channels
+            .get(&(port_id.clone(), channel_id.clone()))
+            .ok_or_else(|| Error::channel_not_found(port_id.clone(), channel_id.clone()))
+    }
+
+    pub fn channel_mut(
+        &mut self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<&mut ChannelRecord, Error> {
+        self.channels
+            .get_mut(&(port_id.clone(), channel_id.clone()))
+            .ok_or_else(|| Error::channel_not_found(port_id.clone(), channel_id.clone()))
+    }
+
+    pub fn client_state_proof(&self, client_id: &ClientId) -> Result<MerkleProof, Error> {
+        let client = self.client(client_id)?;
+        let value = Any::from(client.client_state.clone()).encode_to_vec();
+
+        Ok(proof(ClientStatePath(client_id.clone()), Some(value)))
+    }
+
+    pub fn consensus_state_proof(
+        &self,
+        client_id: &ClientId,
+        height: Height,
+    ) -> Result<MerkleProof, Error> {
+        let consensus_state = self
+            .client(client_id)?
+            .consensus_states
+            .get(&height)
+            .ok_or_else(|| Error::consensus_state_not_found(client_id.clone(), height))?;
+
+        let value = Any::from(consensus_state.clone()).encode_to_vec();
+
+        Ok(proof(consensus_state_path(client_id, height), Some(value)))
+    }
+
+    pub fn connection_proof(&self, connection_id: &ConnectionId) -> Result<MerkleProof, Error> {
+        let value = self.connection(connection_id)?.encode_vec();
+
+        Ok(proof(ConnectionsPath(connection_id.clone()), Some(value)))
+    }
+
+    pub fn channel_proof(&self, port_id: &PortId, channel_id: &ChannelId) -> MerkleProof {
+        let value = self
+            .channels
+            .get(&(port_id.clone(), channel_id.clone()))
+            .map(|channel| channel.channel_end.encode_vec());
+
+        proof(ChannelEndsPath(port_id.clone(), channel_id.clone()), value)
+    }
+
+    pub fn packet_commitment_proof(&self, key: &PacketKey) -> MerkleProof {
+        proof(
+            commitments_path(key),
+            self.packet_commitments.get(key).cloned(),
+        )
+    }
+
+    pub fn packet_receipt_proof(&self, key: &PacketKey) -> MerkleProof {
+        let value = self.packet_receipts.contains(key).then(|| vec![1]);
+
+        proof(receipts_path(key), value)
+    }
+
+    pub fn packet_acknowledgement_proof(&self, key: &PacketKey) -> MerkleProof {
+        proof(
+            acks_path(key),
+            self.packet_acknowledgements.get(key).cloned(),
+        )
+    }
+
+    pub fn next_sequence_recv_proof(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<MerkleProof, Error> {
+        let next_sequence_recv = self.channel(port_id, channel_id)?.next_sequence_recv;
+        let value = u64::from(next_sequence_recv).to_be_bytes().to_vec();
+
+        Ok(proof(
+            SeqRecvsPath(port_id.clone(), channel_id.clone()),
+            Some(value),
+        ))
+    }
+}
+
+pub fn consensus_state_path(client_id: &ClientId, height: Height) -> ClientConsensusStatePath {
+    ClientConsensusStatePath {
+        client_id: client_id.clone(),
+        epoch: height.revision_number(),
+        height: height.revision_height(),
+    }
+}
+
+pub fn commitments_path((port_id, channel_id, sequence): &PacketKey) -> CommitmentsPath {
+    CommitmentsPath {
+        port_id: port_id.clone(),
+        channel_id: channel_id.clone(),
+        sequence: *sequence,
+    }
+}
+
+pub fn receipts_path((port_id, channel_id, sequence): &PacketKey) -> ReceiptsPath {
+    ReceiptsPath {
+        port_id: port_id.clone(),
+        channel_id: channel_id.clone(),
+        sequence: *sequence,
+    }
+}
+
+pub fn acks_path((port_id, channel_id, sequence): &PacketKey) -> AcksPath {
+    AcksPath {
+        port_id: port_id.clone(),
+        channel_id: channel_id.clone(),
+        sequence: *sequence,
+    }
+}
+
+/// The commitment to a packet, as computed by ibc-go.
+pub fn packet_commitment(packet: &Packet) -> Vec<u8> {
+    let mut buf = Vec::new();
+    buf.extend(packet.timeout_timestamp.nanoseconds().to_be_bytes());
+    buf.extend(
+        packet
+            .timeout_height
+            .commitment_revision_number()
+            .to_be_bytes(),
+    );
+    buf.extend(
+        packet
+            .timeout_height
+            .commitment_revision_height()
+            .to_be_bytes(),
+    );
+    buf.extend(Sha256::digest(&packet.data));
+
+    Sha256::digest(buf).to_vec()
+}
+
+/// The commitment to a packet acknowledgement, as computed by ibc-go.
+pub fn acknowledgement_commitment(ack: &[u8]) -> Vec<u8> {
+    Sha256::digest(ack).to_vec()
+}
+
+/// Build the proof of the entry at `path`, or of its absence if there is no `value`.
+fn proof(path: impl Display, value: Option<Vec<u8>>) -> MerkleProof {
+    let key = path.to_string().into_bytes();
+
+    let proof = match value {
+        Some(value) => Proof::Exist(ExistenceProof {
+            key,
+            value,
+            leaf: None,
+            path: vec![],
+        }),
+        None => Proof::Nonexist(NonExistenceProof {
+            key,
+            left: None,
+            right: None,
+        }),
+    };
+
+    MerkleProof {
+        proofs: vec![CommitmentProof { proof: Some(proof) }],
+    }
+}
+
+/// Check that the given proof was built by [`proof`] for the entry at `path`,
+/// and, if a value is given, that the proven entry holds this value.
+pub fn verify_membership(
+    proof: &CommitmentProofBytes,
+    path: impl Display,
+    value: Option<&[u8]>,
+) -> Result<(), Error> {
+    let path = path.to_string();
+
+    match decode_proof(proof, &path)? {
+        Proof::Exist(proof) if proof.key == path.as_bytes() => match value {
+            Some(value) if proof.value != value => Err(Error::invalid_proof(
+                path,
+                "the proven value differs from the expected one".to_string(),
+            )),
+            _ => Ok(()),
+        },
+        Proof::Exist(_) => Err(Error::invalid_proof(
+            path,
+            "proof of another entry".to_string(),
+        )),
+        _ => Err(Error::invalid_proof(
+            path,
+            "expected a proof of existence".to_string(),
+        )),
+    }
+}
+
+/// Check that the given proof was built by [`proof`] for the absence of the entry at `path`.
+pub fn verify_non_membership(
+    proof: &CommitmentProofBytes,
+    path: impl Display,
+) -> Result<(), Error> {
+    let path = path.to_string();
+
+    match decode_proof(proof, &path)? {
+        Proof::Nonexist(proof) if proof.key == path.as_bytes() => Ok(()),
+        Proof::Nonexist(_) => Err(Error::invalid_proof(
+            path,
+            "proof of another entry".to_string(),
+        )),
+        _ => Err(Error::invalid_proof(
+            path,
+            "expected a proof of absence".to_string(),
+        )),
+    }
+}
+
+fn decode_proof(proof: &CommitmentProofBytes, path: &str) -> Result<Proof, Error> {
+    let raw = RawMerkleProof::try_from(proof.clone())
+        .map_err(|_| Error::invalid_proof(path.to_string(), "malformed proof".to_string()))?;
+
+    raw.proofs
+        .into_iter()
+        .next()
+        .and_then(|proof| proof.proof)
+        .ok_or_else(|| Error::invalid_proof(path.to_string(), "empty proof".to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use test_log::test;
+
+    #[test]
+    fn verify_proofs() {
+        let store = IbcStore::default();
+        let key = (PortId::transfer(), ChannelId::new(0), Sequence::from(1));
+
+        let proof = CommitmentProofBytes::try_from(store.packet_receipt_proof(&key)).unwrap();
+        assert!(verify_non_membership(&proof, receipts_path(&key)).is_ok());
+        assert!(verify_membership(&proof, receipts_path(&key), None).is_err());
+
+        let other_key = (PortId::transfer(), ChannelId::new(0), Sequence::from(2));
+        assert!(verify_non_membership(&proof, receipts_path(&other_key)).is_err());
+
+        let mut store = store;
+        store
+            .packet_acknowledgements
+            .insert(key.clone(), vec![1, 2, 3]);
+
+        let proof =
+            CommitmentProofBytes::try_from(store.packet_acknowledgement_proof(&key)).unwrap();
+        assert!(verify_membership(&proof, acks_path(&key), Some(&[1, 2, 3])).is_ok());
+        assert!(verify_membership(&proof, acks_path(&key), Some(&[1, 2])).is_err());
+        assert!(verify_membership(&proof, commitments_path(&key), None).is_err());
+    }
+}
@@ -0,0 +1,133 @@
+//! Helpers to test the relayer against mock chains.
+
+use alloc::sync::Arc;
+use core::time::Duration;
+
+use ibc_proto::cosmos::base::v1beta1::Coin;
+use tokio::runtime::Runtime as TokioRuntime;
+
+use ibc_relayer_types::applications::transfer::msgs::transfer::MsgTransfer;
+use ibc_relayer_types::core::ics04_channel::channel::Ordering;
+use ibc_relayer_types::core::ics04_channel::packet::Sequence;
+use ibc_relayer_types::core::ics04_channel::timeout::TimeoutHeight;
+use ibc_relayer_types::core::ics04_channel::version::Version;
+use ibc_relayer_types::core::ics24_host::identifier::{ChannelId, PortId};
+use ibc_relayer_types::timestamp::Timestamp;
+use ibc_relayer_types::tx_msg::Msg;
+
+use crate::chain::handle::{BaseChainHandle, ChainHandle};
+use crate::chain::requests::QueryPacketCommitmentsRequest;
+use crate::chain::runtime::ChainRuntime;
+use crate::chain::tracking::TrackedMsgs;
+use crate::channel::Channel;
+use crate::config::ChainConfig;
+use crate::connection::Connection;
+use crate::event::IbcEventWithHeight;
+use crate::foreign_client::ForeignClient;
+use crate::link::{Link, LinkParameters};
+
+use super::MockChain;
+
+/// The configuration of the mock chain with the given identifier.
+pub fn config(chain_id: &str) -> ChainConfig {
+    toml::from_str(&format!(
+        r#"
+        id = '{chain_id}'
+        type = 'Mock'
+        rpc_addr = 'http://127.0.0.1:26657'
+        grpc_addr = 'http://127.0.0.1:9090'
+        websocket_addr = 'ws://127.0.0.1:26657/websocket'
+        account_prefix = 'mock'
+        key_name = 'relayer'
+        key_store_type = 'Memory'
+        store_prefix = 'ibc'
+        gas_price = {{ price = 0.0, denom = 'stake' }}
+        "#
+    ))
+    .unwrap()
+}
+
+/// Spawns the runtime of the mock chain with the given identifier.
+pub fn spawn_chain(chain_id: &str, rt: &Arc<TokioRuntime>) -> BaseChainHandle {
+    ChainRuntime::<MockChain>::spawn(config(chain_id), rt.clone()).unwrap()
+}
+
+/// Creates the clients, the connection and the channel between the two chains.
+pub fn open_channel(
+    a: &BaseChainHandle,
+    b: &BaseChainHandle,
+    ordering: Ordering,
+) -> Channel<BaseChainHandle, BaseChainHandle> {
+    let client_on_a = ForeignClient::new(a.clone(), b.clone()).unwrap();
+    let client_on_b = ForeignClient::new(b.clone(), a.clone()).unwrap();
+
+    let connection = Connection::new(client_on_a, client_on_b, Duration::ZERO).unwrap();
+
+    Channel::new(
+        connection,
+        ordering,
+        PortId::transfer(),
+        PortId::transfer(),
+        Some(Version::ics20()),
+    )
+    .unwrap()
+}
+
+/// The link relaying packets sent on the given channel.
+pub fn link(
+    channel: &Channel<BaseChainHandle, BaseChainHandle>,
+) -> Link<BaseChainHandle, BaseChainHandle> {
+    Link::new_from_opts(
+        channel.src_chain().clone(),
+        channel.dst_chain().clone(),
+        LinkParameters {
+            src_port_id: channel.src_port_id().clone(),
+            src_channel_id: channel.src_channel_id().unwrap().clone(),
+        },
+        false,
+        false,
+    )
+    .unwrap()
+}
+
+/// Sends a packet on the given channel, returning the events of the transaction.
+pub fn transfer(
+    channel: &Channel<BaseChainHandle, BaseChainHandle>,
+    timeout_height: TimeoutHeight,
+) -> Vec<IbcEventWithHeight> {
+    let chain = channel.src_chain();
+
+    let msg = MsgTransfer {
+        source_port: channel.src_port_id().clone(),
+        source_channel: channel.src_channel_id().unwrap().clone(),
+        token: Coin {
+            denom: "stake".to_string(),
+            amount: "100".to_string(),
+        },
+        sender: chain.get_signer().unwrap(),
+        receiver: channel.dst_chain().get_signer().unwrap(),
+        timeout_height,
+        timeout_timestamp: Timestamp::none(),
+        memo: None,
+    };
+
+    chain
+        .send_messages_and_wait_commit(TrackedMsgs::new_static(vec![msg.to_any()], "transfer"))
+        .unwrap()
+}
+
+/// The sequences of the packets with a commitment on the given channel end.
+pub fn packet_commitments(
+    chain: &impl ChainHandle,
+    port_id: &PortId,
+    channel_id: &ChannelId,
+) -> Vec<Sequence> {
+    chain
+        .query_packet_commitments(QueryPacketCommitmentsRequest {
+            port_id: port_id.clone(),
+            channel_id: channel_id.clone(),
+            pagination: None,
+        })
+        .unwrap()
+        .0
+}
@@ -263,6 +263,20 @@ pub struct QueryChannelRequest {
     pub height: QueryHeight,
 }
 
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct QueryUpgradeRequest {
+    pub port_id: PortId,
+    pub channel_id: ChannelId,
+    pub height: QueryHeight,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct QueryUpgradeErrorRequest {
+    pub port_id: PortId,
+    pub channel_id: ChannelId,
+    pub height: QueryHeight,
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct QueryChannelClientStateRequest {
     pub port_id: PortId,
@@ -3,8 +3,9 @@ use std::thread;
 
 use crossbeam_channel as channel;
 use tokio::runtime::Runtime as TokioRuntime;
-use tracing::{error, Span};
+use tracing::{error, warn, Span};
 
+use ibc_proto::cosmos::tx::v1beta1::Fee;
 use ibc_proto::ibc::apps::fee::v1::{
     QueryIncentivizedPacketRequest, QueryIncentivizedPacketResponse,
 };
@@ -19,6 +20,7 @@ use ibc_relayer_types::{
         ics04_channel::{
             channel::{ChannelEnd, IdentifiedChannelEnd},
             packet::{PacketMsgType, Sequence},
+            upgrade::{ErrorReceipt, Upgrade},
         },
         ics23_commitment::{commitment::CommitmentPrefix, merkle::MerkleProof},
         ics24_host::identifier::{ChannelId, ClientId, ConnectionId, PortId},
@@ -131,8 +133,22 @@ where
     }
 
     fn run(mut self) -> Result<(), Error> {
+        let config = self.chain.config();
+
+        let endpoints_tick = if config.backup_endpoints.is_empty() {
+            channel::never()
+        } else {
+            channel::tick(config.endpoint_health_check_interval)
+        };
+
         loop {
             channel::select! {
+                recv(endpoints_tick) -> _ => {
+                    if let Err(e) = self.chain.refresh_endpoints() {
+                        error!("failed to check the health of the endpoints: {}", e);
+                    }
+                },
+
                 recv(self.request_receiver) -> event => {
                     let (span, event) = match event {
                         Ok((span, event)) => (span, event),
@@ -170,10 +186,18 @@ where
                             self.send_messages_and_wait_check_tx(tracked_msgs, reply_to)?
                         },
 
+                        ChainRequest::EstimateTxFee { tracked_msgs, reply_to } => {
+                            self.estimate_tx_fee(tracked_msgs, reply_to)?
+                        },
+
                         ChainRequest::Signer { reply_to } => {
                             self.get_signer(reply_to)?
                         },
 
+                        ChainRequest::SelectSigner { reply_to } => {
+                            self.select_signer(reply_to)?
+                        },
+
                         ChainRequest::Config { reply_to } => {
                             self.get_config(reply_to)?
                         },
@@ -182,6 +206,10 @@ where
                             self.get_key(reply_to)?
                         },
 
+                        ChainRequest::GetWalletKeys { reply_to } => {
+                            self.get_wallet_keys(reply_to)?
+                        },
+
                         ChainRequest::AddKey { key_name, key, reply_to } => {
                             self.add_key(key_name, key, reply_to)?
                         },
@@ -214,6 +242,14 @@ where
                             self.build_channel_proofs(port_id, channel_id, height, reply_to)?
                         },
 
+                        ChainRequest::BuildChannelUpgradeProofs { port_id, channel_id, height, reply_to } => {
+                            self.build_channel_upgrade_proofs(port_id, channel_id, height, reply_to)?
+                        },
+
+                        ChainRequest::BuildChannelUpgradeErrorProofs { port_id, channel_id, height, reply_to } => {
+                            self.build_channel_upgrade_error_proofs(port_id, channel_id, height, reply_to)?
+                        },
+
                         ChainRequest::QueryBalance { key_name, denom, reply_to } => {
                             self.query_balance(key_name, denom, reply_to)?
                         },
@@ -286,6 +322,14 @@ where
                             self.query_channel(request, include_proof, reply_to)?
                         },
 
+                        ChainRequest::QueryUpgrade { request, include_proof, reply_to } => {
+                            self.query_upgrade(request, include_proof, reply_to)?
+                        },
+
+                        ChainRequest::QueryUpgradeError { request, include_proof, reply_to } => {
+                            self.query_upgrade_error(request, include_proof, reply_to)?
+                        },
+
                         ChainRequest::QueryChannelClientState { request, reply_to } => {
                             self.query_channel_client_state(request, reply_to)?
                         },
@@ -357,6 +401,62 @@ where
         Ok(())
     }
 
+    /// Run the given request against the chain, and retry it once on another healthy node
+    /// of the chain if it failed because the node in use is unreachable or unresponsive.
+    /// Only to be used for idempotent requests, ie. queries.
+    fn with_failover<T>(
+        &mut self,
+        mut request: impl FnMut(&mut Endpoint) -> Result<T, Error>,
+    ) -> Result<T, Error> {
+        match request(&mut self.chain) {
+            Err(e) if e.is_endpoint_error() => match self.chain.fail_over() {
+                Ok(true) => {
+                    warn!(
+                        "retrying request on another node of the chain after error: {}",
+                        e
+                    );
+                    request(&mut self.chain)
+                }
+                Ok(false) => Err(e),
+                Err(fail_over_error) => {
+                    error!("failed to fail over to another node: {}", fail_over_error);
+                    Err(e)
+                }
+            },
+            result => result,
+        }
+    }
+
+    /// Run the given request against the chain, and fail over to another healthy node
+    /// of the chain for the next requests if it failed because the node in use is
+    /// unreachable or unresponsive.
+    ///
+    /// Unlike [`Self::with_failover`], the request is not retried, as it is not
+    /// idempotent: eg. the transaction it broadcasts may have reached the node.
+    fn fail_over_on_error<T>(
+        &mut self,
+        request: impl FnOnce(&mut Endpoint) -> Result<T, Error>,
+    ) -> Result<T, Error> {
+        let result = request(&mut self.chain);
+
+        if let Err(e) = &result {
+            if e.is_endpoint_error() {
+                match self.chain.fail_over() {
+                    Ok(true) => warn!(
+                        "switched to another node of the chain for the next requests after error: {}",
+                        e
+                    ),
+                    Ok(false) => {}
+                    Err(fail_over_error) => {
+                        error!("failed to fail over to another node: {}", fail_over_error)
+                    }
+                }
+            }
+        }
+
+        result
+    }
+
     fn health_check(&mut self, reply_to: ReplyTo<HealthCheck>) -> Result<(), Error> {
         let result = self.chain.health_check();
         reply_to.send(result).map_err(Error::send)
@@ -372,7 +472,8 @@ where
         tracked_msgs: TrackedMsgs,
         reply_to: ReplyTo<Vec<IbcEventWithHeight>>,
     ) -> Result<(), Error> {
-        let result = self.chain.send_messages_and_wait_commit(tracked_msgs);
+        let result =
+            self.fail_over_on_error(|chain| chain.send_messages_and_wait_commit(tracked_msgs));
         reply_to.send(result).map_err(Error::send)
     }
 
@@ -381,39 +482,52 @@ where
         tracked_msgs: TrackedMsgs,
         reply_to: ReplyTo<Vec<tendermint_rpc::endpoint::broadcast::tx_sync::Response>>,
     ) -> Result<(), Error> {
-        let result = self.chain.send_messages_and_wait_check_tx(tracked_msgs);
+        let result =
+            self.fail_over_on_error(|chain| chain.send_messages_and_wait_check_tx(tracked_msgs));
+        reply_to.send(result).map_err(Error::send)
+    }
+
+    fn estimate_tx_fee(
+        &mut self,
+        tracked_msgs: TrackedMsgs,
+        reply_to: ReplyTo<Fee>,
+    ) -> Result<(), Error> {
+        let result = self.with_failover(|chain| chain.estimate_tx_fee(tracked_msgs.clone()));
         reply_to.send(result).map_err(Error::send)
     }
 
     fn query_balance(
-        &self,
+        &mut self,
         key_name: Option<String>,
         denom: Option<String>,
         reply_to: ReplyTo<Balance>,
     ) -> Result<(), Error> {
-        let balance = self
-            .chain
-            .query_balance(key_name.as_deref(), denom.as_deref());
+        let balance =
+            self.with_failover(|chain| chain.query_balance(key_name.as_deref(), denom.as_deref()));
 
         reply_to.send(balance).map_err(Error::send)
     }
 
     fn query_all_balances(
-        &self,
+        &mut self,
         key_name: Option<String>,
         reply_to: ReplyTo<Vec<Balance>>,
     ) -> Result<(), Error> {
-        let balances = self.chain.query_all_balances(key_name.as_deref());
+        let balances = self.with_failover(|chain| chain.query_all_balances(key_name.as_deref()));
         reply_to.send(balances).map_err(Error::send)
     }
 
-    fn query_denom_trace(&self, hash: String, reply_to: ReplyTo<DenomTrace>) -> Result<(), Error> {
-        let denom_trace = self.chain.query_denom_trace(hash);
+    fn query_denom_trace(
+        &mut self,
+        hash: String,
+        reply_to: ReplyTo<DenomTrace>,
+    ) -> Result<(), Error> {
+        let denom_trace = self.with_failover(|chain| chain.query_denom_trace(hash.clone()));
         reply_to.send(denom_trace).map_err(Error::send)
     }
 
-    fn query_application_status(&self, reply_to: ReplyTo<ChainStatus>) -> Result<(), Error> {
-        let latest_timestamp = self.chain.query_application_status();
+    fn query_application_status(&mut self, reply_to: ReplyTo<ChainStatus>) -> Result<(), Error> {
+        let latest_timestamp = self.with_failover(|chain| chain.query_application_status());
         reply_to.send(latest_timestamp).map_err(Error::send)
     }
 
@@ -422,6 +536,11 @@ where
         reply_to.send(result).map_err(Error::send)
     }
 
+    fn select_signer(&mut self, reply_to: ReplyTo<Signer>) -> Result<(), Error> {
+        let result = self.chain.select_signer();
+        reply_to.send(result).map_err(Error::send)
+    }
+
     fn get_config(&self, reply_to: ReplyTo<ChainConfig>) -> Result<(), Error> {
         let result = Ok(self.chain.config().clone());
         reply_to.send(result).map_err(Error::send)
@@ -432,6 +551,19 @@ where
         reply_to.send(result).map_err(Error::send)
     }
 
+    fn get_wallet_keys(
+        &mut self,
+        reply_to: ReplyTo<Vec<(String, AnySigningKeyPair)>>,
+    ) -> Result<(), Error> {
+        let result = self.chain.get_wallet_keys().map(|keys| {
+            keys.into_iter()
+                .map(|(key_name, key)| (key_name, key.into()))
+                .collect()
+        });
+
+        reply_to.send(result).map_err(Error::send)
+    }
+
     fn add_key(
         &mut self,
         key_name: String,
@@ -446,7 +578,7 @@ where
     }
 
     fn ibc_version(&mut self, reply_to: ReplyTo<Option<semver::Version>>) -> Result<(), Error> {
-        let result = self.chain.ibc_version();
+        let result = self.with_failover(|chain| chain.ibc_version());
         reply_to.send(result).map_err(Error::send)
     }
 
@@ -458,8 +590,7 @@ where
         reply_to: ReplyTo<(AnyHeader, Vec<AnyHeader>)>,
     ) -> Result<(), Error> {
         let result = self
-            .chain
-            .build_header(trusted_height, target_height, &client_state)
+            .with_failover(|chain| chain.build_header(trusted_height, target_height, &client_state))
             .map(|(header, support)| {
                 let header = header.into();
                 let support = support.into_iter().map(|h| h.into()).collect();
@@ -471,14 +602,13 @@ where
 
     /// Constructs a client state for the given height
     fn build_client_state(
-        &self,
+        &mut self,
         height: Height,
         settings: ClientSettings,
         reply_to: ReplyTo<AnyClientState>,
     ) -> Result<(), Error> {
         let client_state = self
-            .chain
-            .build_client_state(height, settings)
+            .with_failover(|chain| chain.build_client_state(height, settings.clone()))
             .map(|cs| cs.into());
 
         reply_to.send(client_state).map_err(Error::send)
@@ -492,7 +622,8 @@ where
         client_state: AnyClientState,
         reply_to: ReplyTo<AnyConsensusState>,
     ) -> Result<(), Error> {
-        let verified = self.chain.verify_header(trusted, target, &client_state)?;
+        let verified =
+            self.with_failover(|chain| chain.verify_header(trusted, target, &client_state))?;
 
         let consensus_state = self
             .chain
@@ -509,94 +640,101 @@ where
         client_state: AnyClientState,
         reply_to: ReplyTo<Option<MisbehaviourEvidence>>,
     ) -> Result<(), Error> {
-        let misbehaviour = self.chain.check_misbehaviour(&update_event, &client_state);
+        let misbehaviour =
+            self.with_failover(|chain| chain.check_misbehaviour(&update_event, &client_state));
 
         reply_to.send(misbehaviour).map_err(Error::send)
     }
 
     fn build_connection_proofs_and_client_state(
-        &self,
+        &mut self,
         message_type: ConnectionMsgType,
         connection_id: ConnectionId,
         client_id: ClientId,
         height: Height,
         reply_to: ReplyTo<(Option<AnyClientState>, Proofs)>,
     ) -> Result<(), Error> {
-        let result = self.chain.build_connection_proofs_and_client_state(
-            message_type,
-            &connection_id,
-            &client_id,
-            height,
-        );
+        let result = self.with_failover(|chain| {
+            chain.build_connection_proofs_and_client_state(
+                message_type.clone(),
+                &connection_id,
+                &client_id,
+                height,
+            )
+        });
 
         reply_to.send(result).map_err(Error::send)
     }
 
     fn query_clients(
-        &self,
+        &mut self,
         request: QueryClientStatesRequest,
         reply_to: ReplyTo<Vec<IdentifiedAnyClientState>>,
     ) -> Result<(), Error> {
-        let result = self.chain.query_clients(request);
+        let result = self.with_failover(|chain| chain.query_clients(request.clone()));
         reply_to.send(result).map_err(Error::send)
     }
 
     fn query_client_connections(
-        &self,
+        &mut self,
         request: QueryClientConnectionsRequest,
         reply_to: ReplyTo<Vec<ConnectionId>>,
     ) -> Result<(), Error> {
-        let result = self.chain.query_client_connections(request);
+        let result = self.with_failover(|chain| chain.query_client_connections(request.clone()));
         reply_to.send(result).map_err(Error::send)
     }
 
     fn query_client_state(
-        &self,
+        &mut self,
         request: QueryClientStateRequest,
         include_proof: IncludeProof,
         reply_to: ReplyTo<(AnyClientState, Option<MerkleProof>)>,
     ) -> Result<(), Error> {
-        let res = self.chain.query_client_state(request, include_proof);
+        let res =
+            self.with_failover(|chain| chain.query_client_state(request.clone(), include_proof));
 
         reply_to.send(res).map_err(Error::send)
     }
 
     fn query_upgraded_client_state(
-        &self,
+        &mut self,
         request: QueryUpgradedClientStateRequest,
         reply_to: ReplyTo<(AnyClientState, MerkleProof)>,
     ) -> Result<(), Error> {
-        let result = self.chain.query_upgraded_client_state(request);
+        let result = self.with_failover(|chain| chain.query_upgraded_client_state(request.clone()));
 
         reply_to.send(result).map_err(Error::send)
     }
 
     fn query_consensus_state_heights(
-        &self,
+        &mut self,
         request: QueryConsensusStateHeightsRequest,
         reply_to: ReplyTo<Vec<Height>>,
     ) -> Result<(), Error> {
-        let heights = self.chain.query_consensus_state_heights(request);
+        let heights =
+            self.with_failover(|chain| chain.query_consensus_state_heights(request.clone()));
         reply_to.send(heights).map_err(Error::send)
     }
 
     fn query_consensus_state(
-        &self,
+        &mut self,
         request: QueryConsensusStateRequest,
         include_proof: IncludeProof,
         reply_to: ReplyTo<(AnyConsensusState, Option<MerkleProof>)>,
     ) -> Result<(), Error> {
-        let res = self.chain.query_consensus_state(request, include_proof);
+        let res =
+            self.with_failover(|chain| chain.query_consensus_state(request.clone(), include_proof));
 
         reply_to.send(res).map_err(Error::send)
     }
 
     fn query_upgraded_consensus_state(
-        &self,
+        &mut self,
         request: QueryUpgradedConsensusStateRequest,
         reply_to: ReplyTo<(AnyConsensusState, MerkleProof)>,
     ) -> Result<(), Error> {
-        let result = self.chain.query_upgraded_consensus_state(request);
+        let result =
+            self.with_failover(|chain| chain.query_upgraded_consensus_state(request.clone()));
 
         reply_to.send(result).map_err(Error::send)
     }
@@ -606,83 +744,134 @@ where
         reply_to.send(prefix).map_err(Error::send)
     }
 
-    fn query_compatible_versions(&self, reply_to: ReplyTo<Vec<Version>>) -> Result<(), Error> {
-        let versions = self.chain.query_compatible_versions();
+    fn query_compatible_versions(&mut self, reply_to: ReplyTo<Vec<Version>>) -> Result<(), Error> {
+        let versions = self.with_failover(|chain| chain.query_compatible_versions());
         reply_to.send(versions).map_err(Error::send)
     }
 
     fn query_connection(
-        &self,
+        &mut self,
         request: QueryConnectionRequest,
         include_proof: IncludeProof,
         reply_to: ReplyTo<(ConnectionEnd, Option<MerkleProof>)>,
     ) -> Result<(), Error> {
-        let connection_end = self.chain.query_connection(request, include_proof);
+        let connection_end =
+            self.with_failover(|chain| chain.query_connection(request.clone(), include_proof));
         reply_to.send(connection_end).map_err(Error::send)
     }
 
     fn query_connections(
-        &self,
+        &mut self,
         request: QueryConnectionsRequest,
         reply_to: ReplyTo<Vec<IdentifiedConnectionEnd>>,
     ) -> Result<(), Error> {
-        let result = self.chain.query_connections(request);
+        let result = self.with_failover(|chain| chain.query_connections(request.clone()));
         reply_to.send(result).map_err(Error::send)
     }
 
     fn query_connection_channels(
-        &self,
+        &mut self,
         request: QueryConnectionChannelsRequest,
         reply_to: ReplyTo<Vec<IdentifiedChannelEnd>>,
     ) -> Result<(), Error> {
-        let result = self.chain.query_connection_channels(request);
+        let result = self.with_failover(|chain| chain.query_connection_channels(request.clone()));
         reply_to.send(result).map_err(Error::send)
     }
 
     fn query_channels(
-        &self,
+        &mut self,
         request: QueryChannelsRequest,
         reply_to: ReplyTo<Vec<IdentifiedChannelEnd>>,
     ) -> Result<(), Error> {
-        let result = self.chain.query_channels(request);
+        let result = self.with_failover(|chain| chain.query_channels(request.clone()));
         reply_to.send(result).map_err(Error::send)
     }
 
     fn query_channel(
-        &self,
+        &mut self,
         request: QueryChannelRequest,
         include_proof: IncludeProof,
         reply_to: ReplyTo<(ChannelEnd, Option<MerkleProof>)>,
     ) -> Result<(), Error> {
-        let result = self.chain.query_channel(request, include_proof);
+        let result =
+            self.with_failover(|chain| chain.query_channel(request.clone(), include_proof));
+        reply_to.send(result).map_err(Error::send)
+    }
+
+    fn query_upgrade(
+        &mut self,
+        request: QueryUpgradeRequest,
+        include_proof: IncludeProof,
+        reply_to: ReplyTo<(Upgrade, Option<MerkleProof>)>,
+    ) -> Result<(), Error> {
+        let result =
+            self.with_failover(|chain| chain.query_upgrade(request.clone(), include_proof));
+        reply_to.send(result).map_err(Error::send)
+    }
+
+    fn query_upgrade_error(
+        &mut self,
+        request: QueryUpgradeErrorRequest,
+        include_proof: IncludeProof,
+        reply_to: ReplyTo<(ErrorReceipt, Option<MerkleProof>)>,
+    ) -> Result<(), Error> {
+        let result =
+            self.with_failover(|chain| chain.query_upgrade_error(request.clone(), include_proof));
         reply_to.send(result).map_err(Error::send)
     }
 
     fn query_channel_client_state(
-        &self,
+        &mut self,
         request: QueryChannelClientStateRequest,
         reply_to: ReplyTo<Option<IdentifiedAnyClientState>>,
     ) -> Result<(), Error> {
-        let result = self.chain.query_channel_client_state(request);
+        let result = self.with_failover(|chain| chain.query_channel_client_state(request.clone()));
         reply_to.send(result).map_err(Error::send)
     }
 
     fn build_channel_proofs(
-        &self,
+        &mut self,
         port_id: PortId,
         channel_id: ChannelId,
         height: Height,
         reply_to: ReplyTo<Proofs>,
     ) -> Result<(), Error> {
-        let result = self
-            .chain
-            .build_channel_proofs(&port_id, &channel_id, height);
+        let result =
+            self.with_failover(|chain| chain.build_channel_proofs(&port_id, &channel_id, height));
+
+        reply_to.send(result).map_err(Error::send)
+    }
+
+    fn build_channel_upgrade_proofs(
+        &mut self,
+        port_id: PortId,
+        channel_id: ChannelId,
+        height: Height,
+        reply_to: ReplyTo<Proofs>,
+    ) -> Result<(), Error> {
+        let result = self.with_failover(|chain| {
+            chain.build_channel_upgrade_proofs(&port_id, &channel_id, height)
+        });
+
+        reply_to.send(result).map_err(Error::send)
+    }
+
+    fn build_channel_upgrade_error_proofs(
+        &mut self,
+        port_id: PortId,
+        channel_id: ChannelId,
+        height: Height,
+        reply_to: ReplyTo<(ErrorReceipt, Proofs)>,
+    ) -> Result<(), Error> {
+        let result = self.with_failover(|chain| {
+            chain.build_channel_upgrade_error_proofs(&port_id, &channel_id, height)
+        });
 
         reply_to.send(result).map_err(Error::send)
     }
 
     fn build_packet_proofs(
-        &self,
+        &mut self,
         packet_type: PacketMsgType,
         port_id: PortId,
         channel_id: ChannelId,
@@ -690,108 +879,118 @@ where
         height: Height,
         reply_to: ReplyTo<Proofs>,
     ) -> Result<(), Error> {
-        let result =
-            self.chain
-                .build_packet_proofs(packet_type, port_id, channel_id, sequence, height);
+        let result = self.with_failover(|chain| {
+            chain.build_packet_proofs(
+                packet_type.clone(),
+                port_id.clone(),
+                channel_id.clone(),
+                sequence,
+                height,
+            )
+        });
 
         reply_to.send(result).map_err(Error::send)
     }
 
     fn query_packet_commitment(
-        &self,
+        &mut self,
         request: QueryPacketCommitmentRequest,
         include_proof: IncludeProof,
         reply_to: ReplyTo<(Vec<u8>, Option<MerkleProof>)>,
     ) -> Result<(), Error> {
-        let result = self.chain.query_packet_commitment(request, include_proof);
+        let result = self
+            .with_failover(|chain| chain.query_packet_commitment(request.clone(), include_proof));
         reply_to.send(result).map_err(Error::send)
     }
 
     fn query_packet_commitments(
-        &self,
+        &mut self,
         request: QueryPacketCommitmentsRequest,
         reply_to: ReplyTo<(Vec<Sequence>, Height)>,
     ) -> Result<(), Error> {
-        let result = self.chain.query_packet_commitments(request);
+        let result = self.with_failover(|chain| chain.query_packet_commitments(request.clone()));
         reply_to.send(result).map_err(Error::send)
     }
 
     fn query_packet_receipt(
-        &self,
+        &mut self,
         request: QueryPacketReceiptRequest,
         include_proof: IncludeProof,
         reply_to: ReplyTo<(Vec<u8>, Option<MerkleProof>)>,
     ) -> Result<(), Error> {
-        let result = self.chain.query_packet_receipt(request, include_proof);
+        let result =
+            self.with_failover(|chain| chain.query_packet_receipt(request.clone(), include_proof));
         reply_to.send(result).map_err(Error::send)
     }
 
     fn query_unreceived_packets(
-        &self,
+        &mut self,
         request: QueryUnreceivedPacketsRequest,
         reply_to: ReplyTo<Vec<Sequence>>,
     ) -> Result<(), Error> {
-        let result = self.chain.query_unreceived_packets(request);
+        let result = self.with_failover(|chain| chain.query_unreceived_packets(request.clone()));
         reply_to.send(result).map_err(Error::send)
     }
 
     fn query_packet_acknowledgement(
-        &self,
+        &mut self,
         request: QueryPacketAcknowledgementRequest,
         include_proof: IncludeProof,
         reply_to: ReplyTo<(Vec<u8>, Option<MerkleProof>)>,
     ) -> Result<(), Error> {
-        let result = self
-            .chain
-            .query_packet_acknowledgement(request, include_proof);
+        let result = self.with_failover(|chain| {
+            chain.query_packet_acknowledgement(request.clone(), include_proof)
+        });
         reply_to.send(result).map_err(Error::send)
     }
 
     fn query_packet_acknowledgements(
-        &self,
+        &mut self,
         request: QueryPacketAcknowledgementsRequest,
         reply_to: ReplyTo<(Vec<Sequence>, Height)>,
     ) -> Result<(), Error> {
-        let result = self.chain.query_packet_acknowledgements(request);
+        let result =
+            self.with_failover(|chain| chain.query_packet_acknowledgements(request.clone()));
         reply_to.send(result).map_err(Error::send)
     }
 
     fn query_unreceived_acknowledgement(
-        &self,
+        &mut self,
         request: QueryUnreceivedAcksRequest,
         reply_to: ReplyTo<Vec<Sequence>>,
     ) -> Result<(), Error> {
-        let result = self.chain.query_unreceived_acknowledgements(request);
+        let result =
+            self.with_failover(|chain| chain.query_unreceived_acknowledgements(request.clone()));
         reply_to.send(result).map_err(Error::send)
     }
 
     fn query_next_sequence_receive(
-        &self,
+        &mut self,
         request: QueryNextSequenceReceiveRequest,
         include_proof: IncludeProof,
         reply_to: ReplyTo<(Sequence, Option<MerkleProof>)>,
     ) -> Result<(), Error> {
-        let result = self
-            .chain
-            .query_next_sequence_receive(request, include_proof);
+        let result = self.with_failover(|chain| {
+            chain.query_next_sequence_receive(request.clone(), include_proof)
+        });
         reply_to.send(result).map_err(Error::send)
     }
 
     fn query_txs(
-        &self,
+        &mut self,
         request: QueryTxRequest,
         reply_to: ReplyTo<Vec<IbcEventWithHeight>>,
     ) -> Result<(), Error> {
-        let result = self.chain.query_txs(request);
+        let result = self.with_failover(|chain| chain.query_txs(request.clone()));
         reply_to.send(result).map_err(Error::send)
     }
 
     fn query_packet_events(
-        &self,
+        &mut self,
         request: QueryPacketEventDataRequest,
         reply_to: ReplyTo<Vec<IbcEventWithHeight>>,
     ) -> Result<(), Error> {
-        let result = self.chain.query_packet_events(request);
+        let result = self.with_failover(|chain| chain.query_packet_events(request.clone()));
 
         reply_to.send(result).map_err(Error::send)?;
 
@@ -799,13 +998,12 @@ where
     }
 
     fn query_host_consensus_state(
-        &self,
+        &mut self,
         request: QueryHostConsensusStateRequest,
         reply_to: ReplyTo<AnyConsensusState>,
     ) -> Result<(), Error> {
         let result = self
-            .chain
-            .query_host_consensus_state(request)
+            .with_failover(|chain| chain.query_host_consensus_state(request.clone()))
             .map(|h| h.into());
 
         reply_to.send(result).map_err(Error::send)?;
@@ -820,9 +1018,9 @@ where
         counterparty_payee: &Signer,
         reply_to: ReplyTo<()>,
     ) -> Result<(), Error> {
-        let result =
-            self.chain
-                .maybe_register_counterparty_payee(channel_id, port_id, counterparty_payee);
+        let result = self.fail_over_on_error(|chain| {
+            chain.maybe_register_counterparty_payee(channel_id, port_id, counterparty_payee)
+        });
 
         reply_to.send(result).map_err(Error::send)?;
 
@@ -830,22 +1028,22 @@ where
     }
 
     fn cross_chain_query(
-        &self,
+        &mut self,
         request: Vec<CrossChainQueryRequest>,
         reply_to: ReplyTo<Vec<CrossChainQueryResponse>>,
     ) -> Result<(), Error> {
-        let result = self.chain.cross_chain_query(request);
+        let result = self.with_failover(|chain| chain.cross_chain_query(request.clone()));
         reply_to.send(result).map_err(Error::send)?;
 
         Ok(())
     }
 
     fn query_incentivized_packet(
-        &self,
+        &mut self,
         request: QueryIncentivizedPacketRequest,
         reply_to: ReplyTo<QueryIncentivizedPacketResponse>,
     ) -> Result<(), Error> {
-        let result = self.chain.query_incentivized_packet(request);
+        let result = self.with_failover(|chain| chain.query_incentivized_packet(request.clone()));
         reply_to.send(result).map_err(Error::send)?;
 
         Ok(())
@@ -0,0 +1,1394 @@
+//! A solo machine "chain", ie. a single key held in the relayer keyring, which the
+//! relayer uses to create and update ICS06 solo machine clients on other chains, and
+//! to sign the proofs of the state of the solo machine.
+//!
+//! The IBC state of the solo machine, ie. its clients, connections, channels and packets,
+//! is held in memory by the relayer, which applies the messages submitted to the solo
+//! machine the way the mock chain does. The solo machine has no history: every query
+//! is answered from its current state, and the entries of that state are proven by
+//! signing them instead of with Merkle proofs.
+//!
+//! The height of the solo machine is the sequence at which it will produce its next
+//! signature, and its diversifier is its chain identifier. Every header and proof signed
+//! at a given sequence moves the solo machine past that sequence, as the client verifying
+//! it on the counterparty chain does. The sequence is persisted next to the keys of the
+//! chain, unless these are only kept in memory.
+
+use alloc::collections::BTreeSet;
+use alloc::sync::Arc;
+use std::sync::Mutex;
+
+use crossbeam_channel as channel;
+use ibc_proto::cosmos::tx::v1beta1::Fee;
+use ibc_proto::google::protobuf::Any;
+use ibc_proto::ibc::apps::fee::v1::{
+    QueryIncentivizedPacketRequest, QueryIncentivizedPacketResponse,
+};
+use ibc_proto::protobuf::Protobuf;
+use prost::Message;
+use secp256k1::{
+    ecdsa::Signature, Message as Secp256k1Message, PublicKey as Secp256k1PublicKey, Secp256k1,
+};
+use sha2::{Digest, Sha256};
+use tendermint::abci::Code;
+use tendermint_rpc::endpoint::broadcast::tx_sync::Response as TxResponse;
+use tokio::runtime::Runtime as TokioRuntime;
+use tracing::debug;
+
+use ibc_relayer_types::applications::ics31_icq::response::CrossChainQueryResponse;
+use ibc_relayer_types::clients::ics06_solomachine::client_state::ClientState as SmClientState;
+use ibc_relayer_types::clients::ics06_solomachine::consensus_state::{
+    ConsensusState as SmConsensusState, PublicKey,
+};
+use ibc_relayer_types::clients::ics06_solomachine::header::Header as SmHeader;
+use ibc_relayer_types::clients::ics06_solomachine::sign_bytes::{
+    decode_signature_data, encode_signature_data, SignBytes, TimestampedSignatureData,
+};
+use ibc_relayer_types::core::ics02_client::error::Error as ClientError;
+use ibc_relayer_types::core::ics02_client::events::UpdateClient;
+use ibc_relayer_types::core::ics03_connection::connection::{
+    ConnectionEnd, IdentifiedConnectionEnd, State as ConnectionState,
+};
+use ibc_relayer_types::core::ics04_channel::channel::{ChannelEnd, IdentifiedChannelEnd, Ordering};
+use ibc_relayer_types::core::ics04_channel::packet::{PacketMsgType, Sequence};
+use ibc_relayer_types::core::ics04_channel::upgrade::{ErrorReceipt, Upgrade};
+use ibc_relayer_types::core::ics23_commitment::commitment::{
+    CommitmentPrefix, CommitmentProofBytes,
+};
+use ibc_relayer_types::core::ics23_commitment::merkle::{apply_prefix, MerkleProof};
+use ibc_relayer_types::core::ics24_host::identifier::{ChannelId, ClientId, ConnectionId, PortId};
+use ibc_relayer_types::core::ics24_host::path::{
+    ChannelEndsPath, ClientStatePath, ConnectionsPath, Path, SeqRecvsPath,
+};
+use ibc_relayer_types::events::{IbcEvent, WithBlockDataType};
+use ibc_relayer_types::proofs::{ConsensusProof, Proofs};
+use ibc_relayer_types::signer::Signer;
+use ibc_relayer_types::timestamp::Timestamp;
+use ibc_relayer_types::Height as ICSHeight;
+
+use crate::account::Balance;
+use crate::chain::client::ClientSettings;
+use crate::chain::endpoint::{ChainEndpoint, ChainStatus, HealthCheck};
+use crate::chain::handle::Subscription;
+use crate::chain::mock::error::Error as StateError;
+use crate::chain::mock::store::{
+    acks_path, commitments_path, consensus_state_path, receipts_path, IbcStore,
+};
+use crate::chain::requests::*;
+use crate::chain::tracking::TrackedMsgs;
+use crate::client_state::{AnyClientState, IdentifiedAnyClientState};
+use crate::config::ChainConfig;
+use crate::connection::ConnectionMsgType;
+use crate::consensus_state::AnyConsensusState;
+use crate::denom::DenomTrace;
+use crate::error::Error;
+use crate::event::IbcEventWithHeight;
+use crate::keyring::{chain_folder, KeyRing, Secp256k1KeyPair, SigningKeyPair, Store};
+use crate::misbehaviour::MisbehaviourEvidence;
+
+use self::error::Error as SoloMachineError;
+use self::sequence::{SequenceStore, SEQUENCE_FILE_NAME};
+
+pub mod error;
+pub mod sequence;
+
+/// Verifies the signature of a solo machine over the given sign bytes.
+///
+/// The signature is the encoded `SignatureDescriptor.Data` found in solo machine
+/// headers and proofs, holding a compact secp256k1 signature over the SHA-256 digest
+/// of the sign bytes.
+pub fn verify_signature(
+    public_key: &PublicKey,
+    sign_bytes: SignBytes,
+    signature_data: &[u8],
+) -> Result<(), SoloMachineError> {
+    let public_key = Secp256k1PublicKey::from_slice(public_key.as_bytes())
+        .map_err(|_| SoloMachineError::invalid_public_key())?;
+
+    let signature = decode_signature_data(signature_data).map_err(SoloMachineError::client)?;
+    let signature = Signature::from_compact(&signature)
+        .map_err(|e| SoloMachineError::invalid_signature(e.to_string()))?;
+
+    let digest = Secp256k1Message::from_slice(&Sha256::digest(sign_bytes.encode_vec()))
+        .expect("SHA-256 digests are 32 bytes long");
+
+    Secp256k1::verification_only()
+        .verify_ecdsa(&digest, &signature, &public_key)
+        .map_err(|e| SoloMachineError::invalid_signature(e.to_string()))
+}
+
+/// Verifies a header against the client state of a solo machine, the way ibc-go does,
+/// and returns the client state resulting from applying the header.
+pub fn update_client_state(
+    client_state: &SmClientState,
+    header: &SmHeader,
+) -> Result<SmClientState, SoloMachineError> {
+    let consensus_state = &client_state.consensus_state;
+
+    if header.timestamp < consensus_state.timestamp {
+        return Err(SoloMachineError::outdated_timestamp());
+    }
+
+    let sign_bytes = SignBytes::header(
+        client_state.sequence,
+        header.timestamp,
+        consensus_state.diversifier.clone(),
+        header.new_public_key.clone(),
+        header.new_diversifier.clone(),
+    );
+
+    verify_signature(&consensus_state.public_key, sign_bytes, &header.signature)?;
+
+    Ok(SmClientState {
+        sequence: client_state.sequence + 1,
+        is_frozen: client_state.is_frozen,
+        consensus_state: SmConsensusState::new(
+            header.new_public_key.clone(),
+            header.new_diversifier.clone(),
+            header.timestamp,
+        ),
+    })
+}
+
+fn unsupported_query<T>(query: &str) -> Result<T, Error> {
+    Err(Error::solo_machine(SoloMachineError::unsupported_query(
+        query.to_string(),
+    )))
+}
+
+pub struct SoloMachineChain {
+    config: ChainConfig,
+    keybase: KeyRing<Secp256k1KeyPair>,
+    /// The sequence at which the solo machine will produce its next signature
+    sequence: Mutex<SequenceStore>,
+    /// The IBC state of the solo machine
+    store: IbcStore,
+    /// The events emitted by the messages applied to the state of the solo machine
+    events: Vec<IbcEventWithHeight>,
+}
+
+impl SoloMachineChain {
+    fn key_pair(&self) -> Result<Secp256k1KeyPair, Error> {
+        self.keybase
+            .get_key(&self.config.key_name)
+            .map_err(Error::key_base)
+    }
+
+    /// The diversifier included in all the signatures of the solo machine.
+    pub fn diversifier(&self) -> String {
+        self.config.id.to_string()
+    }
+
+    /// The public key of the solo machine, ie. of the configured key.
+    pub fn public_key(&self) -> Result<PublicKey, Error> {
+        Ok(PublicKey(self.key_pair()?.public_key.serialize().to_vec()))
+    }
+
+    /// The sequence at which the solo machine will produce its next signature.
+    pub fn sequence(&self) -> u64 {
+        self.sequence.lock().unwrap().current()
+    }
+
+    fn height(&self) -> Result<ICSHeight, Error> {
+        ICSHeight::new(0, self.sequence()).map_err(Error::ics02)
+    }
+
+    /// Returns the timestamp to sign at, which cannot be older than the
+    /// timestamp of the client verifying the signature.
+    fn signature_timestamp(client_state: &SmClientState) -> Timestamp {
+        Timestamp::now().max(client_state.consensus_state.timestamp)
+    }
+
+    /// Signs the given bytes with the key of the solo machine, once the
+    /// solo machine has moved past the sequence they are signed at.
+    fn sign(&self, sign_bytes: SignBytes) -> Result<Vec<u8>, Error> {
+        let key_pair = self.key_pair()?;
+
+        self.sequence
+            .lock()
+            .unwrap()
+            .advance_past(sign_bytes.sequence)
+            .map_err(Error::solo_machine)?;
+
+        let signature = key_pair
+            .sign(&sign_bytes.encode_vec())
+            .map_err(Error::key_base)?;
+
+        Ok(encode_signature_data(signature))
+    }
+
+    /// Signs a header which updates the given client, keeping the current
+    /// key and diversifier of the solo machine.
+    pub fn sign_header(&self, client_state: &SmClientState) -> Result<SmHeader, Error> {
+        let timestamp = Self::signature_timestamp(client_state);
+        let new_public_key = self.public_key()?;
+        let new_diversifier = self.diversifier();
+
+        let sign_bytes = SignBytes::header(
+            client_state.sequence,
+            timestamp,
+            client_state.consensus_state.diversifier.clone(),
+            new_public_key.clone(),
+            new_diversifier.clone(),
+        );
+
+        Ok(SmHeader {
+            sequence: client_state.sequence,
+            timestamp,
+            signature: self.sign(sign_bytes)?,
+            new_public_key,
+            new_diversifier,
+        })
+    }
+
+    /// Signs a proof that `value` is stored at `path` in the state of the solo machine,
+    /// at the current sequence of the solo machine, or a proof that there is no entry
+    /// at `path` if `value` is empty.
+    ///
+    /// The proof is only accepted by a client whose sequence is the current sequence
+    /// of the solo machine. As the client moves past its sequence whenever it verifies
+    /// a proof, the proofs must be submitted in the order in which they were signed.
+    pub fn sign_membership_proof(
+        &self,
+        path: impl Into<Path>,
+        value: Vec<u8>,
+    ) -> Result<CommitmentProofBytes, Error> {
+        let timestamp = Timestamp::now();
+        let merkle_path = apply_prefix(
+            &self.query_commitment_prefix()?,
+            vec![path.into().to_string()],
+        );
+
+        let sign_bytes = SignBytes::membership(
+            self.sequence(),
+            timestamp,
+            self.diversifier(),
+            &merkle_path,
+            value,
+        );
+
+        let signature = self.sign(sign_bytes)?;
+        let signature = decode_signature_data(&signature)
+            .map_err(|e| Error::solo_machine(SoloMachineError::client(e)))?;
+
+        TimestampedSignatureData {
+            signature,
+            timestamp,
+        }
+        .encode_vec()
+        .try_into()
+        .map_err(Error::malformed_proof)
+    }
+
+    fn sign_channel_proof(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<CommitmentProofBytes, Error> {
+        let channel = self
+            .store
+            .channel(port_id, channel_id)
+            .map_err(state_error)?;
+
+        self.sign_membership_proof(
+            ChannelEndsPath(port_id.clone(), channel_id.clone()),
+            channel.channel_end.encode_vec(),
+        )
+    }
+
+    /// Applies the given messages atomically to the state of the solo machine, and
+    /// returns the events emitted by the messages along with the current height.
+    fn deliver(&mut self, msgs: &[Any]) -> Result<Vec<IbcEventWithHeight>, Error> {
+        let height = self.height()?;
+        let timestamp = Timestamp::now();
+        let mut store = self.store.clone();
+
+        let mut events = vec![];
+        for msg in msgs {
+            let msg_events = store.deliver(msg, height, timestamp).map_err(state_error)?;
+
+            events.extend(
+                msg_events
+                    .into_iter()
+                    .map(|event| IbcEventWithHeight::new(event, height)),
+            );
+        }
+
+        self.store = store;
+        self.events.extend(events.iter().cloned());
+
+        Ok(events)
+    }
+}
+
+fn state_error(e: StateError) -> Error {
+    Error::solo_machine(SoloMachineError::state(e))
+}
+
+impl ChainEndpoint for SoloMachineChain {
+    type LightBlock = SmConsensusState;
+    type Header = SmHeader;
+    type ConsensusState = SmConsensusState;
+    type ClientState = SmClientState;
+    type Time = Timestamp;
+    type SigningKeyPair = Secp256k1KeyPair;
+
+    fn config(&self) -> &ChainConfig {
+        &self.config
+    }
+
+    fn bootstrap(config: ChainConfig, _rt: Arc<TokioRuntime>) -> Result<Self, Error> {
+        let keybase = KeyRing::new_secp256k1(
+            config.key_store_type,
+            &config.account_prefix,
+            &config.id,
+            &config.key_store_folder,
+            &config.remote_signer,
+        )
+        .map_err(Error::key_base)?;
+
+        let sequence = match config.key_store_type {
+            Store::Memory => SequenceStore::in_memory(),
+            _ => {
+                let folder = chain_folder(config.id.as_str(), &config.key_store_folder)
+                    .map_err(Error::key_base)?;
+
+                SequenceStore::open(folder.join(SEQUENCE_FILE_NAME)).map_err(Error::solo_machine)?
+            }
+        };
+
+        Ok(Self {
+            config,
+            keybase,
+            sequence: Mutex::new(sequence),
+            store: IbcStore::default(),
+            events: vec![],
+        })
+    }
+
+    fn shutdown(self) -> Result<(), Error> {
+        Ok(())
+    }
+
+    fn health_check(&self) -> Result<HealthCheck, Error> {
+        Ok(HealthCheck::Healthy)
+    }
+
+    fn subscribe(&mut self) -> Result<Subscription, Error> {
+        // A solo machine does not emit any event
+        Ok(channel::never())
+    }
+
+    fn keybase(&self) -> &KeyRing<Self::SigningKeyPair> {
+        &self.keybase
+    }
+
+    fn keybase_mut(&mut self) -> &mut KeyRing<Self::SigningKeyPair> {
+        &mut self.keybase
+    }
+
+    fn get_signer(&self) -> Result<Signer, Error> {
+        self.key_pair()?
+            .account()
+            .parse()
+            .map_err(|e| Error::ics02(ClientError::signer(e)))
+    }
+
+    fn ibc_version(&self) -> Result<Option<semver::Version>, Error> {
+        Ok(None)
+    }
+
+    fn send_messages_and_wait_commit(
+        &mut self,
+        tracked_msgs: TrackedMsgs,
+    ) -> Result<Vec<IbcEventWithHeight>, Error> {
+        match self.deliver(tracked_msgs.messages()) {
+            Ok(events) => Ok(events),
+            Err(e) => {
+                debug!(
+                    "messages {} failed on solo machine {}: {e}",
+                    tracked_msgs.tracking_id(),
+                    self.id()
+                );
+
+                Ok(vec![IbcEventWithHeight::new(
+                    IbcEvent::ChainError(e.to_string()),
+                    self.height()?,
+                )])
+            }
+        }
+    }
+
+    fn send_messages_and_wait_check_tx(
+        &mut self,
+        tracked_msgs: TrackedMsgs,
+    ) -> Result<Vec<TxResponse>, Error> {
+        let mut hasher = Sha256::new();
+        for msg in tracked_msgs.messages() {
+            hasher.update(&msg.value);
+        }
+        let hash = tendermint::Hash::Sha256(hasher.finalize().into());
+
+        let (code, log) = match self.deliver(tracked_msgs.messages()) {
+            Ok(_) => (Code::Ok, String::new()),
+            Err(e) => (Code::from(1), e.to_string()),
+        };
+
+        Ok(vec![TxResponse {
+            code,
+            data: Default::default(),
+            log,
+            hash,
+        }])
+    }
+
+    fn estimate_tx_fee(&mut self, _tracked_msgs: TrackedMsgs) -> Result<Fee, Error> {
+        // The messages are applied by the relayer itself, without any fee
+        Ok(Fee::default())
+    }
+
+    fn verify_header(
+        &mut self,
+        _trusted: ICSHeight,
+        _target: ICSHeight,
+        client_state: &AnyClientState,
+    ) -> Result<Self::LightBlock, Error> {
+        match client_state {
+            AnyClientState::Solomachine(client_state) => Ok(client_state.consensus_state.clone()),
+            client_state => Err(Error::solo_machine(SoloMachineError::unsupported_client(
+                client_state.client_type().to_string(),
+            ))),
+        }
+    }
+
+    fn check_misbehaviour(
+        &mut self,
+        _update: &UpdateClient,
+        _client_state: &AnyClientState,
+    ) -> Result<Option<MisbehaviourEvidence>, Error> {
+        // The relayer never signs twice at the same sequence
+        Ok(None)
+    }
+
+    fn query_balance(
+        &self,
+        _key_name: Option<&str>,
+        _denom: Option<&str>,
+    ) -> Result<Balance, Error> {
+        unsupported_query("balance")
+    }
+
+    fn query_all_balances(&self, _key_name: Option<&str>) -> Result<Vec<Balance>, Error> {
+        unsupported_query("balance")
+    }
+
+    fn query_denom_trace(&self, _hash: String) -> Result<DenomTrace, Error> {
+        unsupported_query("denom trace")
+    }
+
+    fn query_commitment_prefix(&self) -> Result<CommitmentPrefix, Error> {
+        CommitmentPrefix::try_from(self.config.store_prefix.as_bytes().to_vec())
+            .map_err(|_| Error::ics02(ClientError::empty_prefix()))
+    }
+
+    fn query_application_status(&self) -> Result<ChainStatus, Error> {
+        Ok(ChainStatus {
+            height: self.height()?,
+            timestamp: Timestamp::now(),
+        })
+    }
+
+    fn query_clients(
+        &self,
+        _request: QueryClientStatesRequest,
+    ) -> Result<Vec<IdentifiedAnyClientState>, Error> {
+        Ok(self
+            .store
+            .clients
+            .iter()
+            .map(|(client_id, client)| {
+                IdentifiedAnyClientState::new(client_id.clone(), client.client_state.clone())
+            })
+            .collect())
+    }
+
+    fn query_client_state(
+        &self,
+        request: QueryClientStateRequest,
+        _include_proof: IncludeProof,
+    ) -> Result<(AnyClientState, Option<MerkleProof>), Error> {
+        let client = self.store.client(&request.client_id).map_err(state_error)?;
+
+        Ok((client.client_state.clone(), None))
+    }
+
+    fn query_consensus_state(
+        &self,
+        request: QueryConsensusStateRequest,
+        _include_proof: IncludeProof,
+    ) -> Result<(AnyConsensusState, Option<MerkleProof>), Error> {
+        let consensus_state = self
+            .store
+            .client(&request.client_id)
+            .map_err(state_error)?
+            .consensus_states
+            .get(&request.consensus_height)
+            .cloned()
+            .ok_or_else(|| {
+                state_error(StateError::consensus_state_not_found(
+                    request.client_id.clone(),
+                    request.consensus_height,
+                ))
+            })?;
+
+        Ok((consensus_state, None))
+    }
+
+    fn query_consensus_state_heights(
+        &self,
+        request: QueryConsensusStateHeightsRequest,
+    ) -> Result<Vec<ICSHeight>, Error> {
+        let client = self.store.client(&request.client_id).map_err(state_error)?;
+
+        Ok(client.consensus_states.keys().copied().collect())
+    }
+
+    fn query_upgraded_client_state(
+        &self,
+        _request: QueryUpgradedClientStateRequest,
+    ) -> Result<(AnyClientState, MerkleProof), Error> {
+        unsupported_query("upgraded client state")
+    }
+
+    fn query_upgraded_consensus_state(
+        &self,
+        _request: QueryUpgradedConsensusStateRequest,
+    ) -> Result<(AnyConsensusState, MerkleProof), Error> {
+        unsupported_query("upgraded consensus state")
+    }
+
+    fn query_connections(
+        &self,
+        _request: QueryConnectionsRequest,
+    ) -> Result<Vec<IdentifiedConnectionEnd>, Error> {
+        Ok(self
+            .store
+            .connections
+            .iter()
+            .map(|(connection_id, connection_end)| {
+                IdentifiedConnectionEnd::new(connection_id.clone(), connection_end.clone())
+            })
+            .collect())
+    }
+
+    fn query_client_connections(
+        &self,
+        request: QueryClientConnectionsRequest,
+    ) -> Result<Vec<ConnectionId>, Error> {
+        Ok(self
+            .store
+            .connections
+            .iter()
+            .filter(|(_, connection_end)| connection_end.client_id() == &request.client_id)
+            .map(|(connection_id, _)| connection_id.clone())
+            .collect())
+    }
+
+    fn query_connection(
+        &self,
+        request: QueryConnectionRequest,
+        _include_proof: IncludeProof,
+    ) -> Result<(ConnectionEnd, Option<MerkleProof>), Error> {
+        let connection_end = self
+            .store
+            .connection(&request.connection_id)
+            .map_err(state_error)?;
+
+        Ok((connection_end.clone(), None))
+    }
+
+    fn query_connection_channels(
+        &self,
+        request: QueryConnectionChannelsRequest,
+    ) -> Result<Vec<IdentifiedChannelEnd>, Error> {
+        Ok(self
+            .store
+            .channels
+            .iter()
+            .filter(|(_, channel)| {
+                channel.channel_end.connection_hops().first() == Some(&request.connection_id)
+            })
+            .map(|((port_id, channel_id), channel)| {
+                IdentifiedChannelEnd::new(
+                    port_id.clone(),
+                    channel_id.clone(),
+                    channel.channel_end.clone(),
+                )
+            })
+            .collect())
+    }
+
+    fn query_channels(
+        &self,
+        _request: QueryChannelsRequest,
+    ) -> Result<Vec<IdentifiedChannelEnd>, Error> {
+        Ok(self
+            .store
+            .channels
+            .iter()
+            .map(|((port_id, channel_id), channel)| {
+                IdentifiedChannelEnd::new(
+                    port_id.clone(),
+                    channel_id.clone(),
+                    channel.channel_end.clone(),
+                )
+            })
+            .collect())
+    }
+
+    fn query_channel(
+        &self,
+        request: QueryChannelRequest,
+        _include_proof: IncludeProof,
+    ) -> Result<(ChannelEnd, Option<MerkleProof>), Error> {
+        // Like on Cosmos SDK chains, a missing channel is reported as an uninitialized channel
+        let channel_end = self
+            .store
+            .channel(&request.port_id, &request.channel_id)
+            .map(|channel| channel.channel_end.clone())
+            .unwrap_or_default();
+
+        Ok((channel_end, None))
+    }
+
+    fn query_upgrade(
+        &self,
+        request: QueryUpgradeRequest,
+        _include_proof: IncludeProof,
+    ) -> Result<(Upgrade, Option<MerkleProof>), Error> {
+        Err(Error::upgrade_not_found(
+            request.port_id,
+            request.channel_id,
+        ))
+    }
+
+    fn query_upgrade_error(
+        &self,
+        request: QueryUpgradeErrorRequest,
+        _include_proof: IncludeProof,
+    ) -> Result<(ErrorReceipt, Option<MerkleProof>), Error> {
+        Err(Error::upgrade_error_not_found(
+            request.port_id,
+            request.channel_id,
+        ))
+    }
+
+    fn query_channel_client_state(
+        &self,
+        request: QueryChannelClientStateRequest,
+    ) -> Result<Option<IdentifiedAnyClientState>, Error> {
+        let store = &self.store;
+
+        let client_state = store
+            .channel(&request.port_id, &request.channel_id)
+            .ok()
+            .and_then(|channel| channel.channel_end.connection_hops().first().cloned())
+            .and_then(|connection_id| store.connection(&connection_id).ok())
+            .and_then(|connection_end| {
+                let client_id = connection_end.client_id();
+
+                store.client(client_id).ok().map(|client| {
+                    IdentifiedAnyClientState::new(client_id.clone(), client.client_state.clone())
+                })
+            });
+
+        Ok(client_state)
+    }
+
+    fn query_packet_commitment(
+        &self,
+        request: QueryPacketCommitmentRequest,
+        _include_proof: IncludeProof,
+    ) -> Result<(Vec<u8>, Option<MerkleProof>), Error> {
+        let key = (request.port_id, request.channel_id, request.sequence);
+        let commitment = self
+            .store
+            .packet_commitments
+            .get(&key)
+            .cloned()
+            .unwrap_or_default();
+
+        Ok((commitment, None))
+    }
+
+    fn query_packet_commitments(
+        &self,
+        request: QueryPacketCommitmentsRequest,
+    ) -> Result<(Vec<Sequence>, ICSHeight), Error> {
+        let sequences = self
+            .store
+            .packet_commitments
+            .keys()
+            .filter(|(port_id, channel_id, _)| {
+                port_id == &request.port_id && channel_id == &request.channel_id
+            })
+            .map(|(_, _, sequence)| *sequence)
+            .collect();
+
+        Ok((sequences, self.height()?))
+    }
+
+    fn query_packet_receipt(
+        &self,
+        request: QueryPacketReceiptRequest,
+        _include_proof: IncludeProof,
+    ) -> Result<(Vec<u8>, Option<MerkleProof>), Error> {
+        let key = (request.port_id, request.channel_id, request.sequence);
+        let receipt = if self.store.packet_receipts.contains(&key) {
+            vec![1]
+        } else {
+            vec![]
+        };
+
+        Ok((receipt, None))
+    }
+
+    fn query_unreceived_packets(
+        &self,
+        request: QueryUnreceivedPacketsRequest,
+    ) -> Result<Vec<Sequence>, Error> {
+        let channel = self
+            .store
+            .channel(&request.port_id, &request.channel_id)
+            .map_err(state_error)?;
+
+        Ok(request
+            .packet_commitment_sequences
+            .into_iter()
+            .filter(|sequence| match channel.channel_end.ordering {
+                Ordering::Ordered => *sequence >= channel.next_sequence_recv,
+                _ => !self.store.packet_receipts.contains(&(
+                    request.port_id.clone(),
+                    request.channel_id.clone(),
+                    *sequence,
+                )),
+            })
+            .collect())
+    }
+
+    fn query_packet_acknowledgement(
+        &self,
+        request: QueryPacketAcknowledgementRequest,
+        _include_proof: IncludeProof,
+    ) -> Result<(Vec<u8>, Option<MerkleProof>), Error> {
+        let key = (request.port_id, request.channel_id, request.sequence);
+        let ack = self
+            .store
+            .packet_acknowledgements
+            .get(&key)
+            .cloned()
+            .unwrap_or_default();
+
+        Ok((ack, None))
+    }
+
+    fn query_packet_acknowledgements(
+        &self,
+        request: QueryPacketAcknowledgementsRequest,
+    ) -> Result<(Vec<Sequence>, ICSHeight), Error> {
+        let sequences = self
+            .store
+            .packet_acknowledgements
+            .keys()
+            .filter(|(port_id, channel_id, sequence)| {
+                port_id == &request.port_id
+                    && channel_id == &request.channel_id
+                    && request.packet_commitment_sequences.contains(sequence)
+            })
+            .map(|(_, _, sequence)| *sequence)
+            .collect();
+
+        Ok((sequences, self.height()?))
+    }
+
+    fn query_unreceived_acknowledgements(
+        &self,
+        request: QueryUnreceivedAcksRequest,
+    ) -> Result<Vec<Sequence>, Error> {
+        Ok(request
+            .packet_ack_sequences
+            .into_iter()
+            .filter(|sequence| {
+                self.store.packet_commitments.contains_key(&(
+                    request.port_id.clone(),
+                    request.channel_id.clone(),
+                    *sequence,
+                ))
+            })
+            .collect())
+    }
+
+    fn query_next_sequence_receive(
+        &self,
+        request: QueryNextSequenceReceiveRequest,
+        _include_proof: IncludeProof,
+    ) -> Result<(Sequence, Option<MerkleProof>), Error> {
+        let channel = self
+            .store
+            .channel(&request.port_id, &request.channel_id)
+            .map_err(state_error)?;
+
+        Ok((channel.next_sequence_recv, None))
+    }
+
+    fn query_txs(&self, request: QueryTxRequest) -> Result<Vec<IbcEventWithHeight>, Error> {
+        match request {
+            QueryTxRequest::Client(request) => {
+                let event = self.events.iter().rev().find(|event| {
+                    match (&request.event_id, &event.event) {
+                        (WithBlockDataType::CreateClient, IbcEvent::CreateClient(e)) => {
+                            e.client_id() == &request.client_id
+                        }
+                        (WithBlockDataType::UpdateClient, IbcEvent::UpdateClient(e)) => {
+                            e.client_id() == &request.client_id
+                                && e.consensus_height() == request.consensus_height
+                        }
+                        _ => false,
+                    }
+                });
+
+                Ok(event.cloned().into_iter().collect())
+            }
+
+            // The messages are not submitted in transactions, which have no hash
+            QueryTxRequest::Transaction(_) => Ok(vec![]),
+        }
+    }
+
+    fn query_packet_events(
+        &self,
+        request: QueryPacketEventDataRequest,
+    ) -> Result<Vec<IbcEventWithHeight>, Error> {
+        let sequences: BTreeSet<_> = request.sequences.iter().copied().collect();
+
+        let mut events: Vec<_> = self
+            .events
+            .iter()
+            .filter(|event| {
+                let packet = match (&request.event_id, &event.event) {
+                    (WithBlockDataType::SendPacket, IbcEvent::SendPacket(e)) => &e.packet,
+                    (WithBlockDataType::WriteAck, IbcEvent::WriteAcknowledgement(e)) => &e.packet,
+                    _ => return false,
+                };
+
+                packet.source_port == request.source_port_id
+                    && packet.source_channel == request.source_channel_id
+                    && packet.destination_port == request.destination_port_id
+                    && packet.destination_channel == request.destination_channel_id
+                    && sequences.contains(&packet.sequence)
+            })
+            .cloned()
+            .collect();
+
+        events.sort_by_key(|event| event.event.packet().map(|packet| packet.sequence));
+
+        Ok(events)
+    }
+
+    fn query_host_consensus_state(
+        &self,
+        _request: QueryHostConsensusStateRequest,
+    ) -> Result<Self::ConsensusState, Error> {
+        Ok(SmConsensusState::new(
+            self.public_key()?,
+            self.diversifier(),
+            Timestamp::now(),
+        ))
+    }
+
+    fn build_client_state(
+        &self,
+        height: ICSHeight,
+        _settings: ClientSettings,
+    ) -> Result<Self::ClientState, Error> {
+        let consensus_state = self.query_host_consensus_state(QueryHostConsensusStateRequest {
+            height: QueryHeight::Specific(height),
+        })?;
+
+        SmClientState::new(height.revision_height(), consensus_state)
+            .map_err(|e| Error::solo_machine(SoloMachineError::client(e)))
+    }
+
+    fn build_consensus_state(
+        &self,
+        light_block: Self::LightBlock,
+    ) -> Result<Self::ConsensusState, Error> {
+        Ok(light_block)
+    }
+
+    fn build_header(
+        &mut self,
+        _trusted_height: ICSHeight,
+        _target_height: ICSHeight,
+        client_state: &AnyClientState,
+    ) -> Result<(Self::Header, Vec<Self::Header>), Error> {
+        match client_state {
+            AnyClientState::Solomachine(client_state) => {
+                Ok((self.sign_header(client_state)?, vec![]))
+            }
+            client_state => Err(Error::solo_machine(SoloMachineError::unsupported_client(
+                client_state.client_type().to_string(),
+            ))),
+        }
+    }
+
+    /// Signs the proofs of the connection end and, if required, of the client state and
+    /// consensus state of its client, in the order in which ibc-go verifies them.
+    ///
+    /// The proofs of a solo machine are verified against the public key of the client at its
+    /// current sequence, rather than against a consensus state at the proof height, hence the
+    /// proof height is the height of the solo machine they were built at.
+    fn build_connection_proofs_and_client_state(
+        &self,
+        message_type: ConnectionMsgType,
+        connection_id: &ConnectionId,
+        client_id: &ClientId,
+        height: ICSHeight,
+    ) -> Result<(Option<AnyClientState>, Proofs), Error> {
+        let connection_end = self.store.connection(connection_id).map_err(state_error)?;
+
+        // Check that the connection state is compatible with the message
+        let expected_states: &[ConnectionState] = match message_type {
+            ConnectionMsgType::OpenTry => &[ConnectionState::Init, ConnectionState::TryOpen],
+            ConnectionMsgType::OpenAck => &[ConnectionState::TryOpen, ConnectionState::Open],
+            ConnectionMsgType::OpenConfirm => &[ConnectionState::Open],
+        };
+
+        if !expected_states
+            .iter()
+            .any(|state| connection_end.state_matches(state))
+        {
+            return Err(Error::bad_connection_state());
+        }
+
+        let connection_proof = self.sign_membership_proof(
+            ConnectionsPath(connection_id.clone()),
+            connection_end.encode_vec(),
+        )?;
+
+        let (client_state, client_proof, consensus_proof) = match message_type {
+            ConnectionMsgType::OpenTry | ConnectionMsgType::OpenAck => {
+                let client = self.store.client(client_id).map_err(state_error)?;
+                let client_state = client.client_state.clone();
+                let consensus_height = client_state.latest_height();
+
+                let consensus_state = client
+                    .consensus_states
+                    .get(&consensus_height)
+                    .cloned()
+                    .ok_or_else(|| {
+                        state_error(StateError::consensus_state_not_found(
+                            client_id.clone(),
+                            consensus_height,
+                        ))
+                    })?;
+
+                let client_proof = self.sign_membership_proof(
+                    ClientStatePath(client_id.clone()),
+                    Any::from(client_state.clone()).encode_to_vec(),
+                )?;
+
+                let consensus_proof = self.sign_membership_proof(
+                    consensus_state_path(client_id, consensus_height),
+                    Any::from(consensus_state).encode_to_vec(),
+                )?;
+
+                let consensus_proof = ConsensusProof::new(consensus_proof, consensus_height)
+                    .map_err(Error::consensus_proof)?;
+
+                (
+                    Some(client_state),
+                    Some(client_proof),
+                    Some(consensus_proof),
+                )
+            }
+            ConnectionMsgType::OpenConfirm => (None, None, None),
+        };
+
+        let proofs = Proofs::new(
+            connection_proof,
+            client_proof,
+            consensus_proof,
+            None,
+            height,
+        )
+        .map_err(Error::malformed_proof)?;
+
+        Ok((client_state, proofs))
+    }
+
+    fn build_channel_proofs(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        height: ICSHeight,
+    ) -> Result<Proofs, Error> {
+        let channel_proof = self.sign_channel_proof(port_id, channel_id)?;
+
+        Proofs::new(channel_proof, None, None, None, height).map_err(Error::malformed_proof)
+    }
+
+    /// Signs the proof of the packet entry required by the message and, for
+    /// the timeouts on close, the proof of the channel end verified before it.
+    fn build_packet_proofs(
+        &self,
+        packet_type: PacketMsgType,
+        port_id: PortId,
+        channel_id: ChannelId,
+        sequence: Sequence,
+        height: ICSHeight,
+    ) -> Result<Proofs, Error> {
+        let channel_proof = match packet_type {
+            PacketMsgType::TimeoutOnCloseUnordered | PacketMsgType::TimeoutOnCloseOrdered => {
+                Some(self.sign_channel_proof(&port_id, &channel_id)?)
+            }
+            _ => None,
+        };
+
+        let key = (port_id, channel_id, sequence);
+
+        let packet_proof = match packet_type {
+            PacketMsgType::Recv => {
+                let commitment = self.store.packet_commitments.get(&key).cloned();
+                let commitment = commitment.ok_or_else(Error::queried_proof_not_found)?;
+
+                self.sign_membership_proof(commitments_path(&key), commitment)?
+            }
+            PacketMsgType::Ack => {
+                let ack = self.store.packet_acknowledgements.get(&key).cloned();
+                let ack = ack.ok_or_else(Error::queried_proof_not_found)?;
+
+                self.sign_membership_proof(acks_path(&key), ack)?
+            }
+            PacketMsgType::TimeoutUnordered | PacketMsgType::TimeoutOnCloseUnordered => {
+                if self.store.packet_receipts.contains(&key) {
+                    return Err(Error::queried_proof_not_found());
+                }
+
+                self.sign_membership_proof(receipts_path(&key), vec![])?
+            }
+            PacketMsgType::TimeoutOrdered | PacketMsgType::TimeoutOnCloseOrdered => {
+                let (port_id, channel_id, _) = key;
+                let channel = self
+                    .store
+                    .channel(&port_id, &channel_id)
+                    .map_err(state_error)?;
+
+                self.sign_membership_proof(
+                    SeqRecvsPath(port_id, channel_id),
+                    u64::from(channel.next_sequence_recv).to_be_bytes().to_vec(),
+                )?
+            }
+        };
+
+        Proofs::new(packet_proof, None, None, channel_proof, height).map_err(Error::malformed_proof)
+    }
+
+    fn maybe_register_counterparty_payee(
+        &mut self,
+        _channel_id: &ChannelId,
+        _port_id: &PortId,
+        _counterparty_payee: &Signer,
+    ) -> Result<(), Error> {
+        Ok(())
+    }
+
+    fn cross_chain_query(
+        &self,
+        _requests: Vec<CrossChainQueryRequest>,
+    ) -> Result<Vec<CrossChainQueryResponse>, Error> {
+        unsupported_query("cross-chain")
+    }
+
+    fn query_incentivized_packet(
+        &self,
+        _request: QueryIncentivizedPacketRequest,
+    ) -> Result<QueryIncentivizedPacketResponse, Error> {
+        unsupported_query("incentivized packet")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_log::test;
+
+    use core::str::FromStr;
+    use core::time::Duration;
+
+    use hdpath::StandardHDPath;
+    use ibc_relayer_types::core::ics02_client::client_state::ClientState;
+    use ibc_relayer_types::core::ics02_client::trust_threshold::TrustThreshold;
+
+    use crate::chain::cosmos::client::Settings;
+    use crate::chain::handle::{BaseChainHandle, ChainHandle};
+    use crate::chain::mock::store::ChannelRecord;
+    use crate::chain::mock::MockChain;
+    use crate::chain::runtime::ChainRuntime;
+    use crate::config::AddressType;
+    use crate::foreign_client::ForeignClient;
+    use ibc_relayer_types::tx_msg::Msg;
+
+    const MNEMONIC: &str = "such walnut usual noble image raise cabin suspect combine key absurd detail present bless yard grief amazing slam brown donate fabric opera desk minor";
+
+    fn config(chain_id: &str, chain_type: &str) -> ChainConfig {
+        toml::from_str(&format!(
+            r#"
+            id = '{chain_id}'
+            type = '{chain_type}'
+            rpc_addr = 'http://127.0.0.1:26657'
+            grpc_addr = 'http://127.0.0.1:9090'
+            websocket_addr = 'ws://127.0.0.1:26657/websocket'
+            account_prefix = 'cosmos'
+            key_name = 'relayer'
+            key_store_type = 'Memory'
+            store_prefix = 'ibc'
+            gas_price = {{ price = 0.0, denom = 'stake' }}
+            "#
+        ))
+        .unwrap()
+    }
+
+    fn key_pair(account: u32) -> Secp256k1KeyPair {
+        Secp256k1KeyPair::from_mnemonic(
+            MNEMONIC,
+            &StandardHDPath::from_str(&format!("m/44'/118'/{account}'/0/0")).unwrap(),
+            &AddressType::Cosmos,
+            "cosmos",
+        )
+        .unwrap()
+    }
+
+    fn solo_machine(rt: &Arc<TokioRuntime>) -> SoloMachineChain {
+        let mut chain =
+            SoloMachineChain::bootstrap(config("solo-0", "SoloMachine"), rt.clone()).unwrap();
+        chain.add_key("relayer", key_pair(0)).unwrap();
+        chain
+    }
+
+    fn client_state(chain: &SoloMachineChain) -> SmClientState {
+        let height = chain.query_application_status().unwrap().height;
+        let settings = ClientSettings::Tendermint(Settings {
+            max_clock_drift: Duration::from_secs(5),
+            trusting_period: None,
+            trust_threshold: TrustThreshold::default(),
+        });
+
+        chain.build_client_state(height, settings).unwrap()
+    }
+
+    /// Checks that the given proof was signed by the solo machine of the given client,
+    /// at the given sequence, over the given value stored at the given path.
+    fn verify_proof(
+        chain: &SoloMachineChain,
+        client_state: &SmClientState,
+        proof: &CommitmentProofBytes,
+        sequence: u64,
+        path: Path,
+        value: Vec<u8>,
+    ) -> Result<(), SoloMachineError> {
+        let proof = TimestampedSignatureData::decode_vec(&Vec::<u8>::from(proof.clone())).unwrap();
+        let merkle_path = apply_prefix(
+            &chain.query_commitment_prefix().unwrap(),
+            vec![path.to_string()],
+        );
+        let sign_bytes = SignBytes::membership(
+            sequence,
+            proof.timestamp,
+            client_state.consensus_state.diversifier.clone(),
+            &merkle_path,
+            value,
+        );
+
+        verify_signature(
+            &client_state.consensus_state.public_key,
+            sign_bytes,
+            &encode_signature_data(proof.signature),
+        )
+    }
+
+    #[test]
+    fn sign_membership_proof() {
+        let rt = Arc::new(TokioRuntime::new().unwrap());
+        let chain = solo_machine(&rt);
+
+        let client_state = client_state(&chain);
+        assert_eq!(client_state.sequence, 1);
+
+        let path = Path::Connections(ConnectionsPath(ConnectionId::default()));
+        let proof = chain
+            .sign_membership_proof(path.clone(), b"connection".to_vec())
+            .unwrap();
+
+        // The solo machine moved past the sequence the proof was signed at
+        assert_eq!(
+            chain.query_application_status().unwrap().height,
+            ICSHeight::new(0, 2).unwrap()
+        );
+
+        let connection = b"connection".to_vec();
+        verify_proof(&chain, &client_state, &proof, 1, path.clone(), connection).unwrap();
+
+        let forged = b"forged".to_vec();
+        assert!(verify_proof(&chain, &client_state, &proof, 1, path, forged).is_err());
+    }
+
+    #[test]
+    fn sign_packet_proofs() {
+        let rt = Arc::new(TokioRuntime::new().unwrap());
+        let mut chain = solo_machine(&rt);
+
+        let client_state = client_state(&chain);
+        let height = chain.query_application_status().unwrap().height;
+
+        let key = (PortId::transfer(), ChannelId::new(0), Sequence::from(1));
+        let channel_end = ChannelEnd::default();
+        chain.store.channels.insert(
+            (key.0.clone(), key.1.clone()),
+            ChannelRecord::new(channel_end.clone()),
+        );
+
+        let proofs = chain
+            .build_packet_proofs(
+                PacketMsgType::TimeoutOnCloseUnordered,
+                key.0.clone(),
+                key.1.clone(),
+                key.2,
+                height,
+            )
+            .unwrap();
+        assert_eq!(proofs.height(), height);
+
+        // The proof of the channel end is verified first, then the proof of the absence of a receipt
+        let channel_path = ChannelEndsPath(key.0.clone(), key.1.clone()).into();
+        let channel_proof = proofs.other_proof().as_ref().unwrap();
+        let channel_end = channel_end.encode_vec();
+        verify_proof(
+            &chain,
+            &client_state,
+            channel_proof,
+            1,
+            channel_path,
+            channel_end,
+        )
+        .unwrap();
+
+        let receipt_path = receipts_path(&key).into();
+        let receipt_proof = proofs.object_proof();
+        verify_proof(
+            &chain,
+            &client_state,
+            receipt_proof,
+            2,
+            receipt_path,
+            vec![],
+        )
+        .unwrap();
+
+        assert_eq!(chain.sequence(), 3);
+
+        // There is no proof of the absence of a receipt once the packet is received
+        chain.store.packet_receipts.insert(key.clone());
+        assert!(chain
+            .build_packet_proofs(PacketMsgType::TimeoutUnordered, key.0, key.1, key.2, height)
+            .is_err());
+    }
+
+    #[test]
+    fn persist_sequence() {
+        let rt = Arc::new(TokioRuntime::new().unwrap());
+        let folder =
+            std::env::temp_dir().join(format!("hermes-solomachine-{}", uuid::Uuid::new_v4()));
+
+        let mut config = config("solo-0", "SoloMachine");
+        config.key_store_type = Store::Test;
+        config.key_store_folder = Some(folder.clone());
+
+        let mut chain = SoloMachineChain::bootstrap(config.clone(), rt.clone()).unwrap();
+        chain.add_key("relayer", key_pair(0)).unwrap();
+        assert_eq!(chain.sequence(), 1);
+
+        let client_state = client_state(&chain);
+        chain.sign_header(&client_state).unwrap();
+        assert_eq!(chain.sequence(), 2);
+
+        // The solo machine carries on from the sequence it had reached
+        let chain = SoloMachineChain::bootstrap(config, rt).unwrap();
+        assert_eq!(chain.sequence(), 2);
+
+        std::fs::remove_dir_all(folder).unwrap();
+    }
+
+    #[test]
+    fn update_with_header() {
+        let rt = Arc::new(TokioRuntime::new().unwrap());
+        let chain = solo_machine(&rt);
+
+        let client_state = client_state(&chain);
+        let header = chain.sign_header(&client_state).unwrap();
+
+        let updated = update_client_state(&client_state, &header).unwrap();
+        assert_eq!(updated.sequence, 2);
+        assert_eq!(updated.consensus_state.timestamp, header.timestamp);
+
+        // The header was signed for the previous sequence of the client
+        assert!(update_client_state(&updated, &header).is_err());
+
+        // A header signed with another key is rejected
+        let mut other = SoloMachineChain::bootstrap(config("solo-0", "SoloMachine"), rt).unwrap();
+        other.add_key("relayer", key_pair(1)).unwrap();
+        let header = other.sign_header(&client_state).unwrap();
+        assert!(update_client_state(&client_state, &header).is_err());
+    }
+
+    #[test]
+    fn create_and_update_client() {
+        let rt = Arc::new(TokioRuntime::new().unwrap());
+
+        let mock = ChainRuntime::<MockChain>::spawn::<BaseChainHandle>(
+            config("mock-0", "Mock"),
+            rt.clone(),
+        )
+        .unwrap();
+        let solo = ChainRuntime::<SoloMachineChain>::spawn::<BaseChainHandle>(
+            config("solo-0", "SoloMachine"),
+            rt,
+        )
+        .unwrap();
+        solo.add_key("relayer".to_string(), key_pair(0).into())
+            .unwrap();
+
+        let client = ForeignClient::new(mock.clone(), solo).unwrap();
+        assert_eq!(
+            client.id(),
+            &ClientId::from_str("06-solomachine-0").unwrap()
+        );
+
+        let msgs = client
+            .build_update_client_with_trusted(ICSHeight::new(0, 2).unwrap(), None)
+            .unwrap();
+        let msgs = msgs.into_iter().map(|msg| msg.to_any()).collect();
+
+        let events = mock
+            .send_messages_and_wait_commit(TrackedMsgs::new_static(msgs, "update client"))
+            .unwrap();
+        assert!(matches!(
+            events[0].event,
+            ibc_relayer_types::events::IbcEvent::UpdateClient(_)
+        ));
+
+        let (client_state, _) = client.validated_client_state().unwrap();
+        assert_eq!(client_state.latest_height(), ICSHeight::new(0, 2).unwrap());
+        assert_eq!(client_state.chain_id().as_str(), "solo-0");
+    }
+}
@@ -0,0 +1,54 @@
+//! Errors raised by the solo machine chain endpoint.
+
+use std::io;
+use std::path::PathBuf;
+
+use flex_error::{define_error, DisplayOnly, TraceError};
+
+use ibc_relayer_types::clients::ics06_solomachine::error::Error as SoloMachineClientError;
+
+use crate::chain::mock::error::Error as StateError;
+
+define_error! {
+    Error {
+        UnsupportedQuery
+            { query: String }
+            |e| { format_args!("a solo machine does not support the {} query", e.query) },
+
+        State
+            [ DisplayOnly<StateError> ]
+            |_| { "error in the IBC state of the solo machine" },
+
+        UnsupportedClient
+            { client_type: String }
+            |e| { format_args!("expected a solo machine client, got a client of type {}", e.client_type) },
+
+        Client
+            [ SoloMachineClientError ]
+            |_| { "ICS06 client error" },
+
+        InvalidPublicKey
+            |_| { "invalid secp256k1 public key" },
+
+        InvalidSignature
+            { reason: String }
+            |e| { format_args!("invalid solo machine signature: {}", e.reason) },
+
+        OutdatedTimestamp
+            |_| { "the timestamp of the signature is older than the timestamp of the client" },
+
+        SequenceIo
+            { path: PathBuf }
+            [ TraceError<io::Error> ]
+            |e| { format!("I/O error on solo machine sequence file {}", e.path.display()) },
+
+        DecodeSequence
+            { path: PathBuf }
+            [ TraceError<serde_json::Error> ]
+            |e| { format!("failed to decode solo machine sequence file {}", e.path.display()) },
+
+        EncodeSequence
+            [ TraceError<serde_json::Error> ]
+            |_| { "failed to encode the solo machine sequence" },
+    }
+}
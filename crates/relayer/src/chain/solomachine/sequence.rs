@@ -0,0 +1,104 @@
+//! The sequence of a solo machine, persisted next to its keys.
+//!
+//! The clients of a solo machine only accept signatures produced at their current
+//! sequence, hence the solo machine must carry on signing from the sequence it had
+//! reached when the relayer stopped, instead of starting over at sequence 1.
+
+use std::fs;
+use std::io;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use super::error::Error;
+
+/// The name of the file holding the sequence, within the key store folder of the chain.
+pub const SEQUENCE_FILE_NAME: &str = "solomachine_sequence.json";
+
+#[derive(Debug, Serialize, Deserialize)]
+struct PersistedSequence {
+    sequence: u64,
+}
+
+/// The sequence at which the solo machine will produce its next signature.
+#[derive(Debug)]
+pub struct SequenceStore {
+    /// The file the sequence is persisted to, if any
+    path: Option<PathBuf>,
+    sequence: u64,
+}
+
+impl SequenceStore {
+    /// A sequence which is only kept in memory, for solo machines whose keys are too.
+    pub fn in_memory() -> Self {
+        Self {
+            path: None,
+            sequence: 1,
+        }
+    }
+
+    /// Opens the sequence persisted at the given path, starting at sequence 1 if there is none.
+    pub fn open(path: PathBuf) -> Result<Self, Error> {
+        let sequence = match fs::read(&path) {
+            Ok(bytes) => {
+                let persisted: PersistedSequence = serde_json::from_slice(&bytes)
+                    .map_err(|e| Error::decode_sequence(path.clone(), e))?;
+
+                persisted.sequence
+            }
+            Err(e) if e.kind() == io::ErrorKind::NotFound => 1,
+            Err(e) => return Err(Error::sequence_io(path, e)),
+        };
+
+        Ok(Self {
+            path: Some(path),
+            sequence,
+        })
+    }
+
+    pub fn current(&self) -> u64 {
+        self.sequence
+    }
+
+    /// Moves past the given sequence, if it is not already.
+    ///
+    /// The new sequence is persisted before returning, so that the solo machine
+    /// never signs twice at the same sequence, even across restarts.
+    pub fn advance_past(&mut self, sequence: u64) -> Result<(), Error> {
+        let next = self.sequence.max(sequence + 1);
+
+        if next == self.sequence {
+            return Ok(());
+        }
+
+        if let Some(path) = &self.path {
+            write_sequence(path, next)?;
+        }
+
+        self.sequence = next;
+
+        Ok(())
+    }
+}
+
+/// Writes the sequence to a temporary file which then replaces the sequence file,
+/// so that the sequence file is never left partially written.
+fn write_sequence(path: &Path, sequence: u64) -> Result<(), Error> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).map_err(|e| Error::sequence_io(parent.to_path_buf(), e))?;
+    }
+
+    let bytes =
+        serde_json::to_vec(&PersistedSequence { sequence }).map_err(Error::encode_sequence)?;
+    let tmp_path = path.with_extension("tmp");
+
+    let mut file =
+        fs::File::create(&tmp_path).map_err(|e| Error::sequence_io(tmp_path.clone(), e))?;
+
+    file.write_all(&bytes)
+        .and_then(|()| file.sync_all())
+        .map_err(|e| Error::sequence_io(tmp_path.clone(), e))?;
+
+    fs::rename(&tmp_path, path).map_err(|e| Error::sequence_io(path.to_path_buf(), e))
+}
@@ -1,8 +1,11 @@
 use core::fmt::{Display, Error as FmtError, Formatter};
 
 use ibc_proto::google::protobuf::Any;
+use ibc_relayer_types::signer::Signer;
 use uuid::Uuid;
 
+use crate::config::types::{MaxMsgNum, MaxTxSize};
+
 /// Identifier used to track an `EventBatch` along
 /// the relaying pipeline until the corresponding
 /// transactions are submitted and/or confirmed.
@@ -61,21 +64,40 @@ impl Display for TrackingId {
 /// A [`TrackedMsgs`] correlates with a
 /// [`TrackedEvents`](crate::link::operational_data::TrackedEvents)
 /// by sharing the same `tracking_id`.
+///
+/// The messages are split into transactions according to the
+/// `max_msg_num` and `max_tx_size` of the target chain, unless
+/// they are overridden for the channel the messages relate to.
+///
+/// The messages are submitted by the wallet of their signer, if it is known, or
+/// by the wallet of the chain's `key_name` otherwise.
 #[derive(Debug, Clone)]
 pub struct TrackedMsgs {
     pub msgs: Vec<Any>,
     pub tracking_id: TrackingId,
+    pub max_msg_num: Option<MaxMsgNum>,
+    pub max_tx_size: Option<MaxTxSize>,
+    pub signer: Option<Signer>,
 }
 
 impl TrackedMsgs {
     pub fn new(msgs: Vec<Any>, tracking_id: TrackingId) -> Self {
-        Self { msgs, tracking_id }
+        Self {
+            msgs,
+            tracking_id,
+            max_msg_num: None,
+            max_tx_size: None,
+            signer: None,
+        }
     }
 
     pub fn new_static(msgs: Vec<Any>, tracking_id: &'static str) -> Self {
         Self {
             msgs,
             tracking_id: TrackingId::Static(tracking_id),
+            max_msg_num: None,
+            max_tx_size: None,
+            signer: None,
         }
     }
 
@@ -83,6 +105,9 @@ impl TrackedMsgs {
         Self {
             msgs,
             tracking_id: TrackingId::Uuid(tracking_id),
+            max_msg_num: None,
+            max_tx_size: None,
+            signer: None,
         }
     }
 
@@ -90,6 +115,9 @@ impl TrackedMsgs {
         Self {
             msgs: vec![msg],
             tracking_id: TrackingId::Static(tracking_id),
+            max_msg_num: None,
+            max_tx_size: None,
+            signer: None,
         }
     }
 
@@ -97,9 +125,31 @@ impl TrackedMsgs {
         Self {
             msgs: vec![msg],
             tracking_id: TrackingId::Uuid(tracking_id),
+            max_msg_num: None,
+            max_tx_size: None,
+            signer: None,
         }
     }
 
+    /// Overrides the limits of the target chain on the
+    /// number of messages and the size of each transaction.
+    pub fn with_batch_limits(
+        mut self,
+        max_msg_num: Option<MaxMsgNum>,
+        max_tx_size: Option<MaxTxSize>,
+    ) -> Self {
+        self.max_msg_num = max_msg_num;
+        self.max_tx_size = max_tx_size;
+        self
+    }
+
+    /// Sets the signer of the messages, ie. the address of the wallet
+    /// selected to submit them when they were built.
+    pub fn with_signer(mut self, signer: Signer) -> Self {
+        self.signer = Some(signer);
+        self
+    }
+
     pub fn messages(&self) -> &Vec<Any> {
         &self.msgs
     }
@@ -15,10 +15,19 @@ use ibc_relayer_types::core::ics04_channel::msgs::chan_open_ack::MsgChannelOpenA
 use ibc_relayer_types::core::ics04_channel::msgs::chan_open_confirm::MsgChannelOpenConfirm;
 use ibc_relayer_types::core::ics04_channel::msgs::chan_open_init::MsgChannelOpenInit;
 use ibc_relayer_types::core::ics04_channel::msgs::chan_open_try::MsgChannelOpenTry;
+use ibc_relayer_types::core::ics04_channel::msgs::chan_upgrade_ack::MsgChannelUpgradeAck;
+use ibc_relayer_types::core::ics04_channel::msgs::chan_upgrade_cancel::MsgChannelUpgradeCancel;
+use ibc_relayer_types::core::ics04_channel::msgs::chan_upgrade_confirm::MsgChannelUpgradeConfirm;
+use ibc_relayer_types::core::ics04_channel::msgs::chan_upgrade_init::MsgChannelUpgradeInit;
+use ibc_relayer_types::core::ics04_channel::msgs::chan_upgrade_open::MsgChannelUpgradeOpen;
+use ibc_relayer_types::core::ics04_channel::msgs::chan_upgrade_timeout::MsgChannelUpgradeTimeout;
+use ibc_relayer_types::core::ics04_channel::msgs::chan_upgrade_try::MsgChannelUpgradeTry;
+use ibc_relayer_types::core::ics04_channel::upgrade::{Upgrade, UpgradeFields};
 use ibc_relayer_types::core::ics24_host::identifier::{
-    ChainId, ChannelId, ClientId, ConnectionId, PortId,
+    ChainId, ChannelId, ClientId, ConnectionId, PortChannelId, PortId,
 };
-use ibc_relayer_types::events::IbcEvent;
+use ibc_relayer_types::events::{IbcEvent, IbcEventType};
+use ibc_relayer_types::signer::Signer;
 use ibc_relayer_types::tx_msg::Msg;
 use ibc_relayer_types::Height;
 
@@ -26,7 +35,7 @@ use crate::chain::counterparty::{channel_connection_client, channel_state_on_des
 use crate::chain::handle::ChainHandle;
 use crate::chain::requests::{
     IncludeProof, PageRequest, QueryChannelRequest, QueryConnectionChannelsRequest,
-    QueryConnectionRequest, QueryHeight,
+    QueryConnectionRequest, QueryHeight, QueryUpgradeErrorRequest, QueryUpgradeRequest,
 };
 use crate::chain::tracking::TrackedMsgs;
 use crate::connection::Connection;
@@ -757,7 +766,17 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> Channel<ChainA, ChainB> {
             (State::TryOpen, State::Init) => Some(self.build_chan_open_ack_and_send()?),
             (State::TryOpen, State::TryOpen) => Some(self.build_chan_open_ack_and_send()?),
             (State::Open, State::TryOpen) => Some(self.build_chan_open_confirm_and_send()?),
+
+            // Upgrade handshake steps, once both channel ends are open
+            (State::Open, State::Open) if self.src_upgrade()?.is_some() => {
+                return self.upgrade_handshake_step()
+            }
             (State::Open, State::Open) => return Ok((None, Next::Abort)),
+            (state, counterparty_state)
+                if state.is_upgrading() || counterparty_state.is_upgrading() =>
+            {
+                return self.upgrade_handshake_step()
+            }
 
             // If the counterparty state is already Open but current state is TryOpen,
             // return anyway as the final step is to be done by the counterparty worker.
@@ -803,6 +822,10 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> Channel<ChainA, ChainB> {
     }
 
     pub fn step_event(&mut self, event: &IbcEvent, index: u64) -> RetryResult<Next, u64> {
+        if event.channel_upgrade_attributes().is_some() {
+            return self.step_upgrade_event(event, index);
+        }
+
         let state = match event {
             IbcEvent::OpenInitChannel(_) => State::Init,
             IbcEvent::OpenTryChannel(_) => State::TryOpen,
@@ -815,6 +838,39 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> Channel<ChainA, ChainB> {
         self.step_state(state, index)
     }
 
+    /// Executes the step of the upgrade handshake following the given upgrade event.
+    pub fn step_upgrade_event(&mut self, event: &IbcEvent, index: u64) -> RetryResult<Next, u64> {
+        let result = match event {
+            IbcEvent::UpgradeInitChannel(_) => self
+                .build_chan_upgrade_try_and_send()
+                .map(|ev| (Some(ev), Next::Continue)),
+            IbcEvent::UpgradeTryChannel(_) => self
+                .build_chan_upgrade_ack_and_send()
+                .map(|ev| (Some(ev), Next::Continue)),
+            IbcEvent::UpgradeAckChannel(_) => self
+                .build_chan_upgrade_confirm_and_send()
+                .map(|ev| (Some(ev), Next::Continue)),
+            _ => self.upgrade_handshake_step(),
+        };
+
+        match result {
+            Err(e) => {
+                if e.is_expired_or_frozen_error() {
+                    error!("failed to upgrade channel on frozen client: {}", e);
+                    RetryResult::Err(index)
+                } else {
+                    error!("failed channel upgrade step after {}: {}", event, e);
+                    RetryResult::Retry(index)
+                }
+            }
+            Ok((Some(ev), next)) => {
+                info!("channel upgrade step completed with events: {}", ev);
+                RetryResult::Ok(next)
+            }
+            Ok((None, next)) => RetryResult::Ok(next),
+        }
+    }
+
     pub fn build_update_client_on_dst(&self, height: Height) -> Result<Vec<Any>, ChannelError> {
         let client = ForeignClient::restore(
             self.dst_client_id().clone(),
@@ -1465,6 +1521,566 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> Channel<ChainA, ChainB> {
         }
     }
 
+    /// Queries the channel end and its counterparty channel end, on the source
+    /// and the destination chain respectively, at their latest height.
+    fn query_channel_ends(&self) -> Result<(ChannelEnd, ChannelEnd), ChannelError> {
+        let src_channel_id = self
+            .src_channel_id()
+            .ok_or_else(ChannelError::missing_local_channel_id)?;
+        let dst_channel_id = self
+            .dst_channel_id()
+            .ok_or_else(ChannelError::missing_counterparty_channel_id)?;
+
+        let (src_channel, _) = self
+            .src_chain()
+            .query_channel(
+                QueryChannelRequest {
+                    port_id: self.src_port_id().clone(),
+                    channel_id: src_channel_id.clone(),
+                    height: QueryHeight::Latest,
+                },
+                IncludeProof::No,
+            )
+            .map_err(|e| ChannelError::query(self.src_chain().id(), e))?;
+
+        let (dst_channel, _) = self
+            .dst_chain()
+            .query_channel(
+                QueryChannelRequest {
+                    port_id: self.dst_port_id().clone(),
+                    channel_id: dst_channel_id.clone(),
+                    height: QueryHeight::Latest,
+                },
+                IncludeProof::No,
+            )
+            .map_err(|e| ChannelError::query(self.dst_chain().id(), e))?;
+
+        Ok((src_channel, dst_channel))
+    }
+
+    /// Executes the next step of the channel upgrade handshake, given the current
+    /// state of the channel ends, by sending a message to the destination chain.
+    ///
+    /// Note that the `ChanUpgradeConfirm` step is only driven by the `ChanUpgradeAck`
+    /// event, as the state of the channel ends does not tell which one of them
+    /// proposed the upgrade once both of them are flushing.
+    pub fn upgrade_handshake_step(&mut self) -> Result<(Option<IbcEvent>, Next), ChannelError> {
+        let (src_channel, dst_channel) = self.query_channel_ends()?;
+
+        let src_sequence = src_channel.upgrade_sequence();
+        let dst_sequence = dst_channel.upgrade_sequence();
+
+        let event = match (*src_channel.state(), *dst_channel.state()) {
+            (State::Open, State::Open) if src_sequence > dst_sequence => {
+                Some(self.build_chan_upgrade_try_and_send()?)
+            }
+            (State::Open, State::Open) => return Ok((None, Next::Abort)),
+
+            // The upgrade was aborted on the source chain, cancel it on the destination chain
+            (_, dst_state)
+                if dst_state.is_upgrading() && self.src_upgrade_aborted(dst_sequence)? =>
+            {
+                Some(self.build_chan_upgrade_cancel_and_send()?)
+            }
+
+            // The upgrade has timed out on the source chain, abort it on the destination chain
+            (src_state, dst_state)
+                if dst_state.is_upgrading()
+                    && src_state != State::FlushComplete
+                    && self.src_upgrade_timed_out()? =>
+            {
+                Some(self.build_chan_upgrade_timeout_and_send()?)
+            }
+
+            (State::Flushing, State::Open) => Some(self.build_chan_upgrade_ack_and_send()?),
+            (State::FlushComplete, State::FlushComplete) | (State::Open, State::FlushComplete)
+                if src_sequence == dst_sequence =>
+            {
+                Some(self.build_chan_upgrade_open_and_send()?)
+            }
+
+            _ => None,
+        };
+
+        // Abort once the upgrade is complete or aborted on the destination chain,
+        // as the remaining steps, if any, are to be done by the counterparty worker
+        match event {
+            Some(IbcEvent::UpgradeOpenChannel(_))
+            | Some(IbcEvent::UpgradeTimeoutChannel(_))
+            | Some(IbcEvent::UpgradeCancelChannel(_)) => Ok((event, Next::Abort)),
+            _ => Ok((event, Next::Continue)),
+        }
+    }
+
+    /// Whether the source chain wrote an error receipt for the upgrade
+    /// with the given sequence, or for a later one.
+    fn src_upgrade_aborted(&self, upgrade_sequence: u64) -> Result<bool, ChannelError> {
+        let src_channel_id = self
+            .src_channel_id()
+            .ok_or_else(ChannelError::missing_local_channel_id)?;
+
+        match self.src_chain().query_upgrade_error(
+            QueryUpgradeErrorRequest {
+                port_id: self.src_port_id().clone(),
+                channel_id: src_channel_id.clone(),
+                height: QueryHeight::Latest,
+            },
+            IncludeProof::No,
+        ) {
+            Ok((error_receipt, _)) => Ok(error_receipt.sequence >= upgrade_sequence),
+            Err(e) if e.is_upgrade_not_found_error() => Ok(false),
+            Err(e) => Err(ChannelError::query(self.src_chain().id(), e)),
+        }
+    }
+
+    /// Queries the upgrade proposed by the source channel end, if any.
+    fn src_upgrade(&self) -> Result<Option<Upgrade>, ChannelError> {
+        let src_channel_id = self
+            .src_channel_id()
+            .ok_or_else(ChannelError::missing_local_channel_id)?;
+
+        match self.src_chain().query_upgrade(
+            QueryUpgradeRequest {
+                port_id: self.src_port_id().clone(),
+                channel_id: src_channel_id.clone(),
+                height: QueryHeight::Latest,
+            },
+            IncludeProof::No,
+        ) {
+            Ok((upgrade, _)) => Ok(Some(upgrade)),
+            Err(e) if e.is_upgrade_not_found_error() => Ok(None),
+            Err(e) => Err(ChannelError::query(self.src_chain().id(), e)),
+        }
+    }
+
+    /// Whether the upgrade proposed by the source channel end has timed out.
+    /// Returns `false` if there is no upgrade in progress on the source chain.
+    fn src_upgrade_timed_out(&self) -> Result<bool, ChannelError> {
+        let Some(upgrade) = self.src_upgrade()? else {
+            return Ok(false);
+        };
+
+        let status = self
+            .src_chain()
+            .query_application_status()
+            .map_err(|e| ChannelError::query(self.src_chain().id(), e))?;
+
+        Ok(upgrade
+            .timeout
+            .has_expired(status.height, &status.timestamp))
+    }
+
+    /// Queries the upgrade proposed by the source channel end at the given height.
+    fn query_src_upgrade(&self, height: Height) -> Result<Upgrade, ChannelError> {
+        let src_channel_id = self
+            .src_channel_id()
+            .ok_or_else(ChannelError::missing_local_channel_id)?;
+
+        self.src_chain()
+            .query_upgrade(
+                QueryUpgradeRequest {
+                    port_id: self.src_port_id().clone(),
+                    channel_id: src_channel_id.clone(),
+                    height: QueryHeight::Specific(height),
+                },
+                IncludeProof::No,
+            )
+            .map(|(upgrade, _)| upgrade)
+            .map_err(|e| {
+                ChannelError::missing_upgrade(
+                    self.src_chain().id(),
+                    PortChannelId::new(src_channel_id.clone(), self.src_port_id().clone()),
+                    e,
+                )
+            })
+    }
+
+    /// Queries the source channel end at the given height.
+    fn query_src_channel(&self, height: Height) -> Result<ChannelEnd, ChannelError> {
+        let src_channel_id = self
+            .src_channel_id()
+            .ok_or_else(ChannelError::missing_local_channel_id)?;
+
+        self.src_chain()
+            .query_channel(
+                QueryChannelRequest {
+                    port_id: self.src_port_id().clone(),
+                    channel_id: src_channel_id.clone(),
+                    height: QueryHeight::Specific(height),
+                },
+                IncludeProof::No,
+            )
+            .map(|(channel, _)| channel)
+            .map_err(|e| ChannelError::query(self.src_chain().id(), e))
+    }
+
+    /// Sends the given messages of the upgrade handshake to the destination chain,
+    /// and returns the event of the given type emitted by the handshake step.
+    fn send_chan_upgrade_msgs(
+        &self,
+        msgs: Vec<Any>,
+        tracking_id: &'static str,
+        event_type: IbcEventType,
+    ) -> Result<IbcEvent, ChannelError> {
+        let tm = TrackedMsgs::new_static(msgs, tracking_id);
+
+        let events = self
+            .dst_chain()
+            .send_messages_and_wait_commit(tm)
+            .map_err(|e| ChannelError::submit(self.dst_chain().id(), e))?;
+
+        let result = events
+            .into_iter()
+            .find(|event_with_height| {
+                event_with_height.event.event_type() == event_type
+                    || matches!(event_with_height.event, IbcEvent::ChainError(_))
+            })
+            .ok_or_else(|| {
+                ChannelError::missing_event(format!(
+                    "no {} event was in the response",
+                    event_type.as_str()
+                ))
+            })?;
+
+        match &result.event {
+            IbcEvent::ChainError(e) => Err(ChannelError::tx_response(e.clone())),
+            _ => {
+                info!("🔧 {} => {}", self.dst_chain().id(), result);
+                Ok(result.event)
+            }
+        }
+    }
+
+    /// Builds the message to propose an upgrade of the destination channel end.
+    ///
+    /// The message cannot be signed by the relayer, as its signer must be the authority
+    /// allowed to upgrade the channels of the destination chain, usually its governance
+    /// module, so the message is meant to be submitted in a governance proposal.
+    pub fn build_chan_upgrade_init(
+        &self,
+        fields: UpgradeFields,
+        authority: Signer,
+    ) -> Result<Vec<Any>, ChannelError> {
+        // Destination channel ID must be specified
+        let dst_channel_id = self
+            .dst_channel_id()
+            .ok_or_else(ChannelError::missing_counterparty_channel_id)?;
+
+        // Channel must exist on destination
+        self.dst_chain()
+            .query_channel(
+                QueryChannelRequest {
+                    port_id: self.dst_port_id().clone(),
+                    channel_id: dst_channel_id.clone(),
+                    height: QueryHeight::Latest,
+                },
+                IncludeProof::No,
+            )
+            .map_err(|e| ChannelError::query(self.dst_chain().id(), e))?;
+
+        // Build the domain type message
+        let new_msg = MsgChannelUpgradeInit::new(
+            self.dst_port_id().clone(),
+            dst_channel_id.clone(),
+            fields,
+            authority,
+        );
+
+        Ok(vec![new_msg.to_any()])
+    }
+
+    /// Builds the message of a step of the upgrade handshake relayed to the destination
+    /// chain, preceded by the messages to update the client of the source chain on the
+    /// destination chain to the height of the proofs of the message.
+    ///
+    /// The given closure builds the message, and returns it along with the height of
+    /// its proofs.
+    fn build_chan_upgrade_msgs<M: Msg>(
+        &self,
+        build_msg: impl FnOnce(UpgradeStep<'_>) -> Result<(M, Height), ChannelError>,
+    ) -> Result<Vec<Any>, ChannelError> {
+        // Source and destination channel IDs must be specified
+        let src_channel_id = self
+            .src_channel_id()
+            .ok_or_else(ChannelError::missing_local_channel_id)?;
+        let dst_channel_id = self
+            .dst_channel_id()
+            .ok_or_else(ChannelError::missing_counterparty_channel_id)?;
+
+        let query_height = self
+            .src_chain()
+            .query_latest_height()
+            .map_err(|e| ChannelError::query(self.src_chain().id(), e))?;
+
+        // Get signer
+        let signer = self
+            .dst_chain()
+            .get_signer()
+            .map_err(|e| ChannelError::fetch_signer(self.dst_chain().id(), e))?;
+
+        let (new_msg, proofs_height) = build_msg(UpgradeStep {
+            src_channel_id,
+            dst_channel_id,
+            query_height,
+            signer,
+        })?;
+
+        // Build message(s) to update client on destination
+        let mut msgs = self.build_update_client_on_dst(proofs_height)?;
+
+        msgs.push(new_msg.to_any());
+        Ok(msgs)
+    }
+
+    pub fn build_chan_upgrade_try(&self) -> Result<Vec<Any>, ChannelError> {
+        self.build_chan_upgrade_msgs(|step| {
+            let src_channel = self.query_src_channel(step.query_height)?;
+            let src_upgrade = self.query_src_upgrade(step.query_height)?;
+
+            // The destination channel end proposes the connection whose counterparty
+            // is the connection proposed by the source channel end.
+            let src_connection_id =
+                src_upgrade.fields.connection_hops.first().ok_or_else(|| {
+                    ChannelError::invalid_channel("the upgrade has no connection hops".to_string())
+                })?;
+
+            let (src_connection, _) = self
+                .src_chain()
+                .query_connection(
+                    QueryConnectionRequest {
+                        connection_id: src_connection_id.clone(),
+                        height: QueryHeight::Specific(step.query_height),
+                    },
+                    IncludeProof::No,
+                )
+                .map_err(|e| ChannelError::query(self.src_chain().id(), e))?;
+
+            let dst_connection_id = src_connection
+                .counterparty()
+                .connection_id()
+                .cloned()
+                .ok_or_else(ChannelError::missing_counterparty_connection)?;
+
+            let proofs = self
+                .src_chain()
+                .build_channel_upgrade_proofs(
+                    self.src_port_id(),
+                    step.src_channel_id,
+                    step.query_height,
+                )
+                .map_err(ChannelError::upgrade_proof)?;
+
+            let proofs_height = proofs.height();
+
+            // Build the domain type message
+            let new_msg = MsgChannelUpgradeTry {
+                port_id: self.dst_port_id().clone(),
+                channel_id: step.dst_channel_id.clone(),
+                proposed_upgrade_connection_hops: vec![dst_connection_id],
+                counterparty_upgrade_fields: src_upgrade.fields,
+                counterparty_upgrade_sequence: src_channel.upgrade_sequence(),
+                proofs,
+                signer: step.signer,
+            };
+
+            Ok((new_msg, proofs_height))
+        })
+    }
+
+    pub fn build_chan_upgrade_try_and_send(&self) -> Result<IbcEvent, ChannelError> {
+        let dst_msgs = self.build_chan_upgrade_try()?;
+
+        self.send_chan_upgrade_msgs(
+            dst_msgs,
+            "ChannelUpgradeTry",
+            IbcEventType::UpgradeTryChannel,
+        )
+    }
+
+    pub fn build_chan_upgrade_ack(&self) -> Result<Vec<Any>, ChannelError> {
+        self.build_chan_upgrade_msgs(|step| {
+            let src_upgrade = self.query_src_upgrade(step.query_height)?;
+
+            let proofs = self
+                .src_chain()
+                .build_channel_upgrade_proofs(
+                    self.src_port_id(),
+                    step.src_channel_id,
+                    step.query_height,
+                )
+                .map_err(ChannelError::upgrade_proof)?;
+
+            let proofs_height = proofs.height();
+
+            // Build the domain type message
+            let new_msg = MsgChannelUpgradeAck {
+                port_id: self.dst_port_id().clone(),
+                channel_id: step.dst_channel_id.clone(),
+                counterparty_upgrade: src_upgrade,
+                proofs,
+                signer: step.signer,
+            };
+
+            Ok((new_msg, proofs_height))
+        })
+    }
+
+    pub fn build_chan_upgrade_ack_and_send(&self) -> Result<IbcEvent, ChannelError> {
+        let dst_msgs = self.build_chan_upgrade_ack()?;
+
+        self.send_chan_upgrade_msgs(
+            dst_msgs,
+            "ChannelUpgradeAck",
+            IbcEventType::UpgradeAckChannel,
+        )
+    }
+
+    pub fn build_chan_upgrade_confirm(&self) -> Result<Vec<Any>, ChannelError> {
+        self.build_chan_upgrade_msgs(|step| {
+            let src_channel = self.query_src_channel(step.query_height)?;
+            let src_upgrade = self.query_src_upgrade(step.query_height)?;
+
+            let proofs = self
+                .src_chain()
+                .build_channel_upgrade_proofs(
+                    self.src_port_id(),
+                    step.src_channel_id,
+                    step.query_height,
+                )
+                .map_err(ChannelError::upgrade_proof)?;
+
+            let proofs_height = proofs.height();
+
+            // Build the domain type message
+            let new_msg = MsgChannelUpgradeConfirm {
+                port_id: self.dst_port_id().clone(),
+                channel_id: step.dst_channel_id.clone(),
+                counterparty_channel_state: *src_channel.state(),
+                counterparty_upgrade: src_upgrade,
+                proofs,
+                signer: step.signer,
+            };
+
+            Ok((new_msg, proofs_height))
+        })
+    }
+
+    pub fn build_chan_upgrade_confirm_and_send(&self) -> Result<IbcEvent, ChannelError> {
+        let dst_msgs = self.build_chan_upgrade_confirm()?;
+
+        self.send_chan_upgrade_msgs(
+            dst_msgs,
+            "ChannelUpgradeConfirm",
+            IbcEventType::UpgradeConfirmChannel,
+        )
+    }
+
+    pub fn build_chan_upgrade_open(&self) -> Result<Vec<Any>, ChannelError> {
+        self.build_chan_upgrade_msgs(|step| {
+            let src_channel = self.query_src_channel(step.query_height)?;
+
+            let proofs = self
+                .src_chain()
+                .build_channel_proofs(self.src_port_id(), step.src_channel_id, step.query_height)
+                .map_err(ChannelError::channel_proof)?;
+
+            let proofs_height = proofs.height();
+
+            // Build the domain type message
+            let new_msg = MsgChannelUpgradeOpen {
+                port_id: self.dst_port_id().clone(),
+                channel_id: step.dst_channel_id.clone(),
+                counterparty_channel_state: *src_channel.state(),
+                counterparty_upgrade_sequence: src_channel.upgrade_sequence(),
+                proofs,
+                signer: step.signer,
+            };
+
+            Ok((new_msg, proofs_height))
+        })
+    }
+
+    pub fn build_chan_upgrade_open_and_send(&self) -> Result<IbcEvent, ChannelError> {
+        let dst_msgs = self.build_chan_upgrade_open()?;
+
+        self.send_chan_upgrade_msgs(
+            dst_msgs,
+            "ChannelUpgradeOpen",
+            IbcEventType::UpgradeOpenChannel,
+        )
+    }
+
+    pub fn build_chan_upgrade_timeout(&self) -> Result<Vec<Any>, ChannelError> {
+        self.build_chan_upgrade_msgs(|step| {
+            let src_channel = self.query_src_channel(step.query_height)?;
+
+            let proofs = self
+                .src_chain()
+                .build_channel_proofs(self.src_port_id(), step.src_channel_id, step.query_height)
+                .map_err(ChannelError::channel_proof)?;
+
+            let proofs_height = proofs.height();
+
+            // Build the domain type message
+            let new_msg = MsgChannelUpgradeTimeout {
+                port_id: self.dst_port_id().clone(),
+                channel_id: step.dst_channel_id.clone(),
+                counterparty_channel: src_channel,
+                proofs,
+                signer: step.signer,
+            };
+
+            Ok((new_msg, proofs_height))
+        })
+    }
+
+    pub fn build_chan_upgrade_timeout_and_send(&self) -> Result<IbcEvent, ChannelError> {
+        let dst_msgs = self.build_chan_upgrade_timeout()?;
+
+        self.send_chan_upgrade_msgs(
+            dst_msgs,
+            "ChannelUpgradeTimeout",
+            IbcEventType::UpgradeTimeoutChannel,
+        )
+    }
+
+    pub fn build_chan_upgrade_cancel(&self) -> Result<Vec<Any>, ChannelError> {
+        self.build_chan_upgrade_msgs(|step| {
+            // The error receipt of the aborted upgrade must exist on source
+            let (error_receipt, proofs) = self
+                .src_chain()
+                .build_channel_upgrade_error_proofs(
+                    self.src_port_id(),
+                    step.src_channel_id,
+                    step.query_height,
+                )
+                .map_err(ChannelError::upgrade_proof)?;
+
+            let proofs_height = proofs.height();
+
+            // Build the domain type message
+            let new_msg = MsgChannelUpgradeCancel {
+                port_id: self.dst_port_id().clone(),
+                channel_id: step.dst_channel_id.clone(),
+                error_receipt,
+                proofs,
+                signer: step.signer,
+            };
+
+            Ok((new_msg, proofs_height))
+        })
+    }
+
+    pub fn build_chan_upgrade_cancel_and_send(&self) -> Result<IbcEvent, ChannelError> {
+        let dst_msgs = self.build_chan_upgrade_cancel()?;
+
+        self.send_chan_upgrade_msgs(
+            dst_msgs,
+            "ChannelUpgradeCancel",
+            IbcEventType::UpgradeCancelChannel,
+        )
+    }
+
     pub fn map_chain<ChainC: ChainHandle, ChainD: ChainHandle>(
         self,
         mapper_a: impl Fn(ChainA) -> ChainC,
@@ -1479,6 +2095,16 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> Channel<ChainA, ChainB> {
     }
 }
 
+/// The parameters of a step of the upgrade handshake relayed to the destination chain.
+struct UpgradeStep<'a> {
+    src_channel_id: &'a ChannelId,
+    dst_channel_id: &'a ChannelId,
+    /// The height at which the source chain is queried, ie. its latest height.
+    query_height: Height,
+    /// The signer of the message on the destination chain.
+    signer: Signer,
+}
+
 pub fn extract_channel_id(event: &IbcEvent) -> Result<&ChannelId, ChannelError> {
     match event {
         IbcEvent::OpenInitChannel(ev) => ev.channel_id(),
@@ -57,6 +57,21 @@ define_error! {
             [ RelayerError ]
             |_| { "failed to build channel proofs" },
 
+        UpgradeProof
+            [ RelayerError ]
+            |_| { "failed to build channel upgrade proofs" },
+
+        MissingUpgrade
+            {
+                chain_id: ChainId,
+                port_channel_id: PortChannelId,
+            }
+            [ RelayerError ]
+            | e | {
+                format_args!("channel '{0}' on chain '{1}' has no upgrade in progress",
+                    e.port_channel_id, e.chain_id)
+            },
+
         ClientOperation
             {
                 client_id: ClientId,
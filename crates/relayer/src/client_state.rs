@@ -2,16 +2,24 @@ use core::time::Duration;
 
 use ibc_proto::ibc::core::client::v1::IdentifiedClientState;
 use ibc_proto::ibc::lightclients::tendermint::v1::ClientState as RawClientState;
-#[cfg(test)]
+#[cfg(any(test, feature = "mocks"))]
 use ibc_proto::ibc::mock::ClientState as RawMockClientState;
 use ibc_proto::protobuf::Protobuf;
 use serde::{Deserialize, Serialize};
 
 use ibc_proto::google::protobuf::Any;
+use ibc_relayer_types::clients::ics06_solomachine::client_state::{
+    ClientState as SmClientState, SOLOMACHINE_CLIENT_STATE_TYPE_URL,
+};
+use ibc_relayer_types::clients::ics06_solomachine::raw::ClientState as RawSmClientState;
 use ibc_relayer_types::clients::ics07_tendermint::client_state::{
     ClientState as TmClientState, UpgradeOptions as TmUpgradeOptions,
     TENDERMINT_CLIENT_STATE_TYPE_URL,
 };
+use ibc_relayer_types::clients::ics08_wasm::client_state::{
+    ClientState as WasmClientState, WASM_CLIENT_STATE_TYPE_URL,
+};
+use ibc_relayer_types::clients::ics08_wasm::raw::ClientState as RawWasmClientState;
 use ibc_relayer_types::core::ics02_client::client_state::{
     downcast_client_state, ClientState, UpgradeOptions,
 };
@@ -21,9 +29,9 @@ use ibc_relayer_types::core::ics02_client::trust_threshold::TrustThreshold;
 
 use ibc_relayer_types::core::ics24_host::error::ValidationError;
 use ibc_relayer_types::core::ics24_host::identifier::{ChainId, ClientId};
-#[cfg(test)]
+#[cfg(any(test, feature = "mocks"))]
 use ibc_relayer_types::mock::client_state::MockClientState;
-#[cfg(test)]
+#[cfg(any(test, feature = "mocks"))]
 use ibc_relayer_types::mock::client_state::MOCK_CLIENT_STATE_TYPE_URL;
 use ibc_relayer_types::Height;
 
@@ -53,7 +61,11 @@ impl UpgradeOptions for AnyUpgradeOptions {}
 pub enum AnyClientState {
     Tendermint(TmClientState),
 
-    #[cfg(test)]
+    Solomachine(SmClientState),
+
+    Wasm(WasmClientState),
+
+    #[cfg(any(test, feature = "mocks"))]
     Mock(MockClientState),
 }
 
@@ -62,7 +74,11 @@ impl AnyClientState {
         match self {
             Self::Tendermint(tm_state) => tm_state.latest_height(),
 
-            #[cfg(test)]
+            Self::Solomachine(sm_state) => sm_state.latest_height(),
+
+            Self::Wasm(wasm_state) => wasm_state.latest_height(),
+
+            #[cfg(any(test, feature = "mocks"))]
             Self::Mock(mock_state) => mock_state.latest_height(),
         }
     }
@@ -71,7 +87,11 @@ impl AnyClientState {
         match self {
             Self::Tendermint(tm_state) => tm_state.frozen_height(),
 
-            #[cfg(test)]
+            Self::Solomachine(sm_state) => sm_state.frozen_height(),
+
+            Self::Wasm(wasm_state) => wasm_state.frozen_height(),
+
+            #[cfg(any(test, feature = "mocks"))]
             Self::Mock(mock_state) => mock_state.frozen_height(),
         }
     }
@@ -80,7 +100,14 @@ impl AnyClientState {
         match self {
             AnyClientState::Tendermint(state) => Some(state.trust_threshold),
 
-            #[cfg(test)]
+            AnyClientState::Solomachine(_) => None,
+
+            AnyClientState::Wasm(wasm_state) => wasm_state
+                .tendermint
+                .as_ref()
+                .map(|tm_state| tm_state.trust_threshold),
+
+            #[cfg(any(test, feature = "mocks"))]
             AnyClientState::Mock(_) => None,
         }
     }
@@ -89,7 +116,14 @@ impl AnyClientState {
         match self {
             AnyClientState::Tendermint(state) => state.max_clock_drift,
 
-            #[cfg(test)]
+            AnyClientState::Solomachine(_) => Duration::new(0, 0),
+
+            AnyClientState::Wasm(wasm_state) => wasm_state
+                .tendermint
+                .as_ref()
+                .map_or(Duration::new(0, 0), |tm_state| tm_state.max_clock_drift),
+
+            #[cfg(any(test, feature = "mocks"))]
             AnyClientState::Mock(_) => Duration::new(0, 0),
         }
     }
@@ -98,7 +132,11 @@ impl AnyClientState {
         match self {
             Self::Tendermint(state) => state.client_type(),
 
-            #[cfg(test)]
+            Self::Solomachine(state) => state.client_type(),
+
+            Self::Wasm(state) => state.client_type(),
+
+            #[cfg(any(test, feature = "mocks"))]
             Self::Mock(state) => state.client_type(),
         }
     }
@@ -107,10 +145,35 @@ impl AnyClientState {
         match self {
             AnyClientState::Tendermint(tm_state) => tm_state.refresh_time(),
 
-            #[cfg(test)]
+            AnyClientState::Solomachine(_) => None,
+
+            AnyClientState::Wasm(wasm_state) => wasm_state
+                .tendermint
+                .as_ref()
+                .and_then(|tm_state| tm_state.refresh_time()),
+
+            #[cfg(any(test, feature = "mocks"))]
             AnyClientState::Mock(mock_state) => mock_state.refresh_time(),
         }
     }
+
+    /// Returns the trusting period of the client, if it has one.
+    pub fn trusting_period(&self) -> Option<Duration> {
+        self.as_tendermint()
+            .map(|tm_state| tm_state.trusting_period)
+    }
+
+    /// Returns the state of a Tendermint client, or the Tendermint
+    /// client state wrapped by an `08-wasm` client, if any.
+    pub fn as_tendermint(&self) -> Option<&TmClientState> {
+        match self {
+            AnyClientState::Tendermint(tm_state) => Some(tm_state),
+
+            AnyClientState::Wasm(wasm_state) => wasm_state.tendermint.as_ref(),
+
+            _ => None,
+        }
+    }
 }
 
 impl Protobuf<Any> for AnyClientState {}
@@ -127,7 +190,17 @@ impl TryFrom<Any> for AnyClientState {
                     .map_err(Error::decode_raw_client_state)?,
             )),
 
-            #[cfg(test)]
+            SOLOMACHINE_CLIENT_STATE_TYPE_URL => Ok(AnyClientState::Solomachine(
+                Protobuf::<RawSmClientState>::decode_vec(&raw.value)
+                    .map_err(Error::decode_raw_client_state)?,
+            )),
+
+            WASM_CLIENT_STATE_TYPE_URL => Ok(AnyClientState::Wasm(
+                Protobuf::<RawWasmClientState>::decode_vec(&raw.value)
+                    .map_err(Error::decode_raw_client_state)?,
+            )),
+
+            #[cfg(any(test, feature = "mocks"))]
             MOCK_CLIENT_STATE_TYPE_URL => Ok(AnyClientState::Mock(
                 Protobuf::<RawMockClientState>::decode_vec(&raw.value)
                     .map_err(Error::decode_raw_client_state)?,
@@ -145,7 +218,15 @@ impl From<AnyClientState> for Any {
                 type_url: TENDERMINT_CLIENT_STATE_TYPE_URL.to_string(),
                 value: Protobuf::<RawClientState>::encode_vec(&value),
             },
-            #[cfg(test)]
+            AnyClientState::Solomachine(value) => Any {
+                type_url: SOLOMACHINE_CLIENT_STATE_TYPE_URL.to_string(),
+                value: Protobuf::<RawSmClientState>::encode_vec(&value),
+            },
+            AnyClientState::Wasm(value) => Any {
+                type_url: WASM_CLIENT_STATE_TYPE_URL.to_string(),
+                value: Protobuf::<RawWasmClientState>::encode_vec(&value),
+            },
+            #[cfg(any(test, feature = "mocks"))]
             AnyClientState::Mock(value) => Any {
                 type_url: MOCK_CLIENT_STATE_TYPE_URL.to_string(),
                 value: Protobuf::<RawMockClientState>::encode_vec(&value),
@@ -159,7 +240,11 @@ impl ClientState for AnyClientState {
         match self {
             AnyClientState::Tendermint(tm_state) => tm_state.chain_id(),
 
-            #[cfg(test)]
+            AnyClientState::Solomachine(sm_state) => sm_state.chain_id(),
+
+            AnyClientState::Wasm(wasm_state) => wasm_state.chain_id(),
+
+            #[cfg(any(test, feature = "mocks"))]
             AnyClientState::Mock(mock_state) => mock_state.chain_id(),
         }
     }
@@ -194,7 +279,17 @@ impl ClientState for AnyClientState {
                 chain_id,
             ),
 
-            #[cfg(test)]
+            AnyClientState::Solomachine(sm_state) => {
+                sm_state.upgrade(upgrade_height, upgrade_options, chain_id)
+            }
+
+            AnyClientState::Wasm(wasm_state) => wasm_state.upgrade(
+                upgrade_height,
+                upgrade_options.as_tm_upgrade_options().unwrap(),
+                chain_id,
+            ),
+
+            #[cfg(any(test, feature = "mocks"))]
             AnyClientState::Mock(mock_state) => {
                 mock_state.upgrade(upgrade_height, upgrade_options, chain_id)
             }
@@ -205,7 +300,11 @@ impl ClientState for AnyClientState {
         match self {
             AnyClientState::Tendermint(tm_state) => tm_state.expired(elapsed_since_latest),
 
-            #[cfg(test)]
+            AnyClientState::Solomachine(sm_state) => sm_state.expired(elapsed_since_latest),
+
+            AnyClientState::Wasm(wasm_state) => wasm_state.expired(elapsed_since_latest),
+
+            #[cfg(any(test, feature = "mocks"))]
             AnyClientState::Mock(mock_state) => mock_state.expired(elapsed_since_latest),
         }
     }
@@ -217,7 +316,19 @@ impl From<TmClientState> for AnyClientState {
     }
 }
 
-#[cfg(test)]
+impl From<SmClientState> for AnyClientState {
+    fn from(cs: SmClientState) -> Self {
+        Self::Solomachine(cs)
+    }
+}
+
+impl From<WasmClientState> for AnyClientState {
+    fn from(cs: WasmClientState) -> Self {
+        Self::Wasm(cs)
+    }
+}
+
+#[cfg(any(test, feature = "mocks"))]
 impl From<MockClientState> for AnyClientState {
     fn from(cs: MockClientState) -> Self {
         Self::Mock(cs)
@@ -226,11 +337,19 @@ impl From<MockClientState> for AnyClientState {
 
 impl From<&dyn ClientState> for AnyClientState {
     fn from(client_state: &dyn ClientState) -> Self {
-        #[cfg(test)]
+        #[cfg(any(test, feature = "mocks"))]
         if let Some(cs) = downcast_client_state::<MockClientState>(client_state) {
             return AnyClientState::from(*cs);
         }
 
+        if let Some(cs) = downcast_client_state::<SmClientState>(client_state) {
+            return AnyClientState::from(cs.clone());
+        }
+
+        if let Some(cs) = downcast_client_state::<WasmClientState>(client_state) {
+            return AnyClientState::from(cs.clone());
+        }
+
         if let Some(cs) = downcast_client_state::<TmClientState>(client_state) {
             AnyClientState::from(cs.clone())
         } else {
@@ -287,6 +406,8 @@ mod tests {
     use ibc_proto::google::protobuf::Any;
     use ibc_relayer_types::clients::ics07_tendermint::client_state::test_util::get_dummy_tendermint_client_state;
     use ibc_relayer_types::clients::ics07_tendermint::header::test_util::get_dummy_tendermint_header;
+    use ibc_relayer_types::clients::ics08_wasm::client_state::ClientState as WasmClientState;
+    use ibc_relayer_types::core::ics02_client::client_type::ClientType;
     use test_log::test;
 
     use super::AnyClientState;
@@ -300,4 +421,22 @@ mod tests {
         let tm_client_state_back = AnyClientState::try_from(raw).unwrap();
         assert_eq!(tm_client_state, tm_client_state_back);
     }
+
+    #[test]
+    fn wasm_client_state_wrapping_tendermint() {
+        let tm_client_state = get_dummy_tendermint_client_state(get_dummy_tendermint_header());
+        let wasm_client_state: AnyClientState =
+            WasmClientState::from_tendermint(vec![1; 32], tm_client_state.clone()).into();
+
+        let raw: Any = wasm_client_state.clone().into();
+        let wasm_client_state_back = AnyClientState::try_from(raw).unwrap();
+        assert_eq!(wasm_client_state, wasm_client_state_back);
+
+        assert_eq!(wasm_client_state.client_type(), ClientType::Wasm);
+        assert_eq!(wasm_client_state.as_tendermint(), Some(&tm_client_state));
+        assert_eq!(
+            wasm_client_state.trust_threshold(),
+            Some(tm_client_state.trust_threshold)
+        );
+    }
 }
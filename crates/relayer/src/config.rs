@@ -1,8 +1,13 @@
 //! Relayer configuration
 
+pub mod alerting;
+pub mod channel_overrides;
+pub mod dynamic_gas;
 pub mod error;
+pub mod excluded_sequences;
 pub mod filter;
 pub mod gas_multiplier;
+pub mod paths;
 pub mod proof_specs;
 pub mod types;
 
@@ -31,11 +36,16 @@ use ibc_relayer_types::core::ics24_host::identifier::{ChainId, ChannelId, PortId
 use ibc_relayer_types::timestamp::ZERO_DURATION;
 
 use crate::chain::ChainType;
+use crate::config::alerting::AlertingConfig;
+use crate::config::channel_overrides::ChannelOverrides;
+use crate::config::dynamic_gas::DynamicGasPrice;
+use crate::config::excluded_sequences::ExcludedSequences;
 use crate::config::gas_multiplier::GasMultiplier;
+use crate::config::paths::PathConfig;
 use crate::config::types::{MaxMsgNum, MaxTxSize, Memo};
 use crate::error::Error as RelayerError;
 use crate::extension_options::ExtensionOptionDynamicFeeTx;
-use crate::keyring::Store;
+use crate::keyring::{remote::RemoteSignerConfig, Store};
 
 pub use crate::config::Error as ConfigError;
 pub use error::Error;
@@ -177,6 +187,18 @@ pub mod default {
         Duration::from_millis(500)
     }
 
+    pub fn endpoint_health_check_interval() -> Duration {
+        Duration::from_secs(10)
+    }
+
+    pub fn poll_interval() -> Duration {
+        Duration::from_secs(1)
+    }
+
+    pub fn push_nodes() -> usize {
+        1
+    }
+
     pub fn clock_drift() -> Duration {
         Duration::from_secs(5)
     }
@@ -213,8 +235,17 @@ pub struct Config {
     pub rest: RestConfig,
     #[serde(default)]
     pub telemetry: TelemetryConfig,
+    #[serde(default)]
+    pub state: StateConfig,
+    #[serde(default)]
+    pub alerting: AlertingConfig,
+    #[serde(default)]
+    pub economics: EconomicsConfig,
     #[serde(default = "Vec::new", skip_serializing_if = "Vec::is_empty")]
     pub chains: Vec<ChainConfig>,
+    /// The paths which Hermes creates on start, or with `hermes reconcile`, if they do not exist yet
+    #[serde(default = "Vec::new", skip_serializing_if = "Vec::is_empty")]
+    pub paths: Vec<PathConfig>,
 }
 
 impl Config {
@@ -253,7 +284,7 @@ impl Config {
     }
 }
 
-#[derive(Copy, Clone, Debug, Deserialize, Serialize)]
+#[derive(Copy, Clone, Debug, PartialEq, Deserialize, Serialize)]
 #[serde(deny_unknown_fields)]
 pub struct ModeConfig {
     pub clients: Clients,
@@ -290,7 +321,7 @@ impl Default for ModeConfig {
     }
 }
 
-#[derive(Copy, Clone, Debug, Default, Deserialize, Serialize)]
+#[derive(Copy, Clone, Debug, PartialEq, Default, Deserialize, Serialize)]
 #[serde(deny_unknown_fields)]
 pub struct Clients {
     pub enabled: bool,
@@ -300,19 +331,19 @@ pub struct Clients {
     pub misbehaviour: bool,
 }
 
-#[derive(Copy, Clone, Debug, Default, Deserialize, Serialize)]
+#[derive(Copy, Clone, Debug, PartialEq, Default, Deserialize, Serialize)]
 #[serde(deny_unknown_fields)]
 pub struct Connections {
     pub enabled: bool,
 }
 
-#[derive(Copy, Clone, Debug, Default, Deserialize, Serialize)]
+#[derive(Copy, Clone, Debug, PartialEq, Default, Deserialize, Serialize)]
 #[serde(deny_unknown_fields)]
 pub struct Channels {
     pub enabled: bool,
 }
 
-#[derive(Copy, Clone, Debug, Deserialize, Serialize)]
+#[derive(Copy, Clone, Debug, PartialEq, Deserialize, Serialize)]
 #[serde(deny_unknown_fields)]
 pub struct Packets {
     pub enabled: bool,
@@ -369,13 +400,39 @@ impl Display for LogLevel {
     }
 }
 
-#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[derive(Clone, Debug, PartialEq, Default, Deserialize, Serialize)]
 #[serde(default, deny_unknown_fields)]
 pub struct GlobalConfig {
     pub log_level: LogLevel,
 }
 
-#[derive(Clone, Debug, Deserialize, Serialize)]
+/// Configuration of the on-disk store of the relaying state,
+/// see [`StateStore`](crate::state_store::StateStore).
+#[derive(Clone, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct StateConfig {
+    /// Whether to persist the relaying state, in order to resume relaying from it
+    /// on start instead of clearing the pending packets of the persisted paths.
+    pub enabled: bool,
+    /// Path of the state file. Default: `$HOME/.hermes/state.json`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub path: Option<PathBuf>,
+}
+
+/// Configuration of the ledger of the fees paid and the ICS29 incentives earned by Hermes,
+/// see [`economics`](crate::economics).
+#[derive(Clone, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct EconomicsConfig {
+    /// Whether to record the fees paid and the incentives earned in the ledger file,
+    /// which `hermes query relayer-economics` reports on.
+    pub enabled: bool,
+    /// Path of the ledger file. Default: `$HOME/.hermes/economics.jsonl`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub path: Option<PathBuf>,
+}
+
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
 #[serde(deny_unknown_fields)]
 pub struct TelemetryConfig {
     pub enabled: bool,
@@ -396,7 +453,7 @@ impl Default for TelemetryConfig {
     }
 }
 
-#[derive(Clone, Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
 #[serde(deny_unknown_fields)]
 pub struct RestConfig {
     pub enabled: bool,
@@ -443,6 +500,44 @@ impl Display for AddressType {
     }
 }
 
+/// Specifies how Hermes collects the IBC events emitted by a chain.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase", tag = "mode", deny_unknown_fields)]
+pub enum EventSourceMode {
+    /// Subscribe to the events over the WebSocket endpoint at `websocket_addr`,
+    /// and over the WebSocket endpoints of the first `nodes - 1` backup endpoints.
+    /// The events of all these nodes are merged and deduplicated.
+    Push {
+        #[serde(default = "default::push_nodes")]
+        nodes: usize,
+    },
+
+    /// Periodically query the RPC endpoint at `rpc_addr` for the
+    /// `/block_results` of the blocks committed since the last poll.
+    Pull {
+        #[serde(default = "default::poll_interval", with = "humantime_serde")]
+        interval: Duration,
+    },
+}
+
+impl Default for EventSourceMode {
+    fn default() -> Self {
+        Self::Push {
+            nodes: default::push_nodes(),
+        }
+    }
+}
+
+impl Display for EventSourceMode {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), FmtError> {
+        match self {
+            Self::Push { nodes: 1 } => write!(f, "push"),
+            Self::Push { nodes } => write!(f, "push (nodes: {nodes})"),
+            Self::Pull { interval } => write!(f, "pull (interval: {interval:?})"),
+        }
+    }
+}
+
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
 #[serde(deny_unknown_fields)]
 pub struct GenesisRestart {
@@ -450,6 +545,15 @@ pub struct GenesisRestart {
     pub archive_addr: Url,
 }
 
+/// The addresses of the RPC, WebSocket and gRPC endpoints of a full node.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct NodeEndpoints {
+    pub rpc_addr: Url,
+    pub websocket_addr: WebSocketClientUrl,
+    pub grpc_addr: Url,
+}
+
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
 #[serde(deny_unknown_fields)]
 pub struct ChainConfig {
@@ -459,6 +563,14 @@ pub struct ChainConfig {
     pub rpc_addr: Url,
     pub websocket_addr: WebSocketClientUrl,
     pub grpc_addr: Url,
+    /// How often the health of the endpoints of the chain is checked,
+    /// in order to fail over to one of the `backup_endpoints` when the
+    /// active endpoints are unhealthy
+    #[serde(
+        default = "default::endpoint_health_check_interval",
+        with = "humantime_serde"
+    )]
+    pub endpoint_health_check_interval: Duration,
     #[serde(default = "default::rpc_timeout", with = "humantime_serde")]
     pub rpc_timeout: Duration,
     #[serde(default = "default::batch_delay", with = "humantime_serde")]
@@ -467,6 +579,11 @@ pub struct ChainConfig {
     pub trusted_node: bool,
     pub account_prefix: String,
     pub key_name: String,
+    /// Names of additional keys whose wallets are used along with the wallet
+    /// of `key_name` to submit transactions, in order to spread the transactions
+    /// across several accounts
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub key_names: Vec<String>,
     #[serde(default)]
     pub key_store_type: Store,
     pub key_store_folder: Option<PathBuf>,
@@ -540,13 +657,76 @@ pub struct ChainConfig {
 
     pub gas_price: GasPrice,
 
+    #[serde(default)]
+    pub dynamic_gas_price: DynamicGasPrice,
+
+    #[serde(default)]
+    pub event_source: EventSourceMode,
+
     #[serde(default)]
     pub packet_filter: PacketFilter,
 
+    #[serde(default, skip_serializing_if = "ExcludedSequences::is_empty")]
+    pub excluded_sequences: ExcludedSequences,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub remote_signer: Option<RemoteSignerConfig>,
+
     #[serde(default)]
     pub address_type: AddressType,
     #[serde(default = "Vec::new", skip_serializing_if = "Vec::is_empty")]
     pub extension_options: Vec<ExtensionOption>,
+
+    /// The endpoints of other full nodes of the chain, which Hermes fails over to,
+    /// in order, when the node at `rpc_addr`, `websocket_addr` and `grpc_addr` is unhealthy
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub backup_endpoints: Vec<NodeEndpoints>,
+
+    /// Relaying settings for some of the channels of the chain,
+    /// which take precedence over the global and chain settings
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub channel_overrides: Vec<ChannelOverrides>,
+}
+
+impl ChainConfig {
+    /// The names of the keys of the wallets used to submit transactions,
+    /// starting with `key_name`, followed by the additional `key_names`.
+    pub fn wallet_key_names(&self) -> Vec<String> {
+        let mut key_names = vec![self.key_name.clone()];
+
+        for key_name in &self.key_names {
+            if !key_names.contains(key_name) {
+                key_names.push(key_name.clone());
+            }
+        }
+
+        key_names
+    }
+
+    /// The endpoints of the full nodes of the chain, starting with
+    /// the primary node, followed by the `backup_endpoints`.
+    pub fn node_endpoints(&self) -> Vec<NodeEndpoints> {
+        let primary = NodeEndpoints {
+            rpc_addr: self.rpc_addr.clone(),
+            websocket_addr: self.websocket_addr.clone(),
+            grpc_addr: self.grpc_addr.clone(),
+        };
+
+        core::iter::once(primary)
+            .chain(self.backup_endpoints.iter().cloned())
+            .collect()
+    }
+
+    /// The first of the `channel_overrides` which applies to the given channel of the chain, if any.
+    pub fn channel_overrides(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Option<&ChannelOverrides> {
+        self.channel_overrides
+            .iter()
+            .find(|overrides| overrides.matches(port_id, channel_id))
+    }
 }
 
 /// Attempt to load and parse the TOML config file as a `Config`.
@@ -583,7 +763,10 @@ pub(crate) fn store_writer(config: &Config, mut writer: impl Write) -> Result<()
 mod tests {
     use core::str::FromStr;
 
-    use super::{load, parse_gas_prices, store_writer};
+    use ibc_relayer_types::core::ics24_host::identifier::{ChannelId, PortId};
+
+    use super::{load, parse_gas_prices, store_writer, EventSourceMode};
+    use crate::config::types::MaxMsgNum;
     use crate::config::GasPrice;
     use test_log::test;
 
@@ -596,6 +779,33 @@ mod tests {
 
         let config = load(path).expect("could not parse config");
 
+        let node_endpoints = config.chains[0].node_endpoints();
+        assert_eq!(node_endpoints.len(), 2);
+        assert_eq!(
+            node_endpoints[1].rpc_addr.to_string(),
+            "http://127.0.0.1:36657/"
+        );
+        assert_eq!(
+            config.chains[0].event_source,
+            EventSourceMode::Push { nodes: 2 }
+        );
+
+        let ica_overrides = config.chains[0]
+            .channel_overrides(&"icahost".parse().unwrap(), &ChannelId::new(3))
+            .expect("overrides for the ICA channels");
+        assert_eq!(ica_overrides.tx_confirmation, Some(true));
+        assert_eq!(ica_overrides.max_msg_num, Some(MaxMsgNum::new(1).unwrap()));
+        assert!(config.chains[0]
+            .channel_overrides(&PortId::transfer(), &ChannelId::new(0))
+            .is_none());
+
+        assert_eq!(config.paths.len(), 1);
+        assert_eq!(config.paths[0].b_chain.as_str(), "chain_B");
+        assert_eq!(
+            config.paths[0].a_client.trusting_period,
+            Some(core::time::Duration::from_secs(7 * 24 * 3600))
+        );
+
         dbg!(config);
     }
 
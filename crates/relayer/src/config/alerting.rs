@@ -0,0 +1,101 @@
+use core::time::Duration;
+
+use serde_derive::{Deserialize, Serialize};
+use tendermint_rpc::Url;
+
+/// Configuration of the monitor which alerts when clients get close to
+/// the end of their trusting period, or when they become expired or frozen.
+///
+/// # IMPORTANT: Remember to update the default config.toml whenever these values change.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct AlertingConfig {
+    /// Whether to monitor the expiry of the clients hosted on the scanned chains.
+    pub enabled: bool,
+    /// How often to check the time left in the trusting period of each client.
+    #[serde(with = "humantime_serde")]
+    pub interval: Duration,
+    /// Alert when the time left in the trusting period of a client
+    /// drops below each of these durations.
+    #[serde(with = "durations")]
+    pub thresholds: Vec<Duration>,
+    /// URL to which alerts are POSTed as JSON, on top of being logged.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub webhook_url: Option<Url>,
+}
+
+impl Default for AlertingConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            interval: Duration::from_secs(5 * 60),
+            thresholds: vec![
+                Duration::from_secs(3 * 24 * 60 * 60),
+                Duration::from_secs(24 * 60 * 60),
+                Duration::from_secs(6 * 60 * 60),
+            ],
+            webhook_url: None,
+        }
+    }
+}
+
+/// (De)serializes a list of durations in the human-readable format of [`humantime_serde`].
+mod durations {
+    use core::time::Duration;
+
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    pub fn serialize<S>(durations: &[Duration], serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        durations
+            .iter()
+            .map(|duration| humantime::format_duration(*duration).to_string())
+            .collect::<Vec<_>>()
+            .serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Vec<Duration>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let durations = Vec::<humantime_serde::Serde<Duration>>::deserialize(deserializer)?;
+
+        Ok(durations.into_iter().map(|d| d.into_inner()).collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use test_log::test;
+
+    #[test]
+    fn parse_thresholds() {
+        let config: AlertingConfig = toml::from_str(
+            r#"
+            enabled = true
+            thresholds = ['2days', '12h', '30m']
+            webhook_url = 'https://alerts.example.com/hermes'
+            "#,
+        )
+        .unwrap();
+
+        assert!(config.enabled);
+        assert_eq!(config.interval, AlertingConfig::default().interval);
+        assert_eq!(
+            config.thresholds,
+            vec![
+                Duration::from_secs(2 * 24 * 60 * 60),
+                Duration::from_secs(12 * 60 * 60),
+                Duration::from_secs(30 * 60),
+            ]
+        );
+
+        let encoded = toml::to_string(&config).unwrap();
+        let decoded: AlertingConfig = toml::from_str(&encoded).unwrap();
+        assert_eq!(config, decoded);
+    }
+}
@@ -0,0 +1,135 @@
+//! Relaying settings which override, for some channels,
+//! the global `[mode.packets]` settings and the settings of a chain.
+
+use core::time::Duration;
+
+use serde_derive::{Deserialize, Serialize};
+
+use ibc_relayer_types::core::ics24_host::identifier::{ChannelId, PortId};
+
+use crate::config::filter::{ChannelFilterMatch, PortFilterMatch};
+use crate::config::types::{MaxMsgNum, MaxTxSize};
+
+/// Relaying settings for the channels matching the given port and channel,
+/// which take precedence over the global `[mode.packets]` settings and over
+/// the settings of the chain.
+///
+/// The port and channel are matched as in the `packet_filter` of the chain,
+/// ie. either exactly or with a wildcard, eg. `port_id = 'icahost'` and `channel_id = '*'`.
+/// The channel identifiers refer to channels on the chain the overrides are configured for.
+/// When several entries match a channel, the first one is used.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct ChannelOverrides {
+    pub port_id: PortFilterMatch,
+    pub channel_id: ChannelFilterMatch,
+
+    /// Overrides `mode.packets.clear_interval`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub clear_interval: Option<u64>,
+
+    /// Overrides `mode.packets.clear_on_start`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub clear_on_start: Option<bool>,
+
+    /// Overrides `mode.packets.tx_confirmation`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tx_confirmation: Option<bool>,
+
+    /// Overrides the `max_msg_num` of the chain the messages are submitted to
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_msg_num: Option<MaxMsgNum>,
+
+    /// Overrides the `max_tx_size` of the chain the messages are submitted to
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_tx_size: Option<MaxTxSize>,
+
+    /// Overrides the `batch_delay` of the chain the events are received from.
+    /// The events of the channel received within that delay are relayed together,
+    /// even if they were emitted at different heights, with proofs at their height.
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        with = "humantime_serde"
+    )]
+    pub batch_delay: Option<Duration>,
+}
+
+impl ChannelOverrides {
+    /// Whether these overrides apply to the given channel.
+    pub fn matches(&self, port_id: &PortId, channel_id: &ChannelId) -> bool {
+        self.port_id.matches(port_id) && self.channel_id.matches(channel_id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use test_log::test;
+
+    #[derive(Debug, Deserialize)]
+    struct DummyConfig {
+        channel_overrides: Vec<ChannelOverrides>,
+    }
+
+    #[test]
+    fn parse_channel_overrides() {
+        let config = toml::from_str::<DummyConfig>(
+            r#"
+            [[channel_overrides]]
+            port_id = 'transfer'
+            channel_id = 'channel-0'
+            clear_interval = 10
+            max_msg_num = 50
+            batch_delay = '1s'
+
+            [[channel_overrides]]
+            port_id = 'icahost'
+            channel_id = '*'
+            tx_confirmation = true
+            max_msg_num = 1
+            "#,
+        )
+        .unwrap();
+
+        let [transfer, ica] = &config.channel_overrides[..] else {
+            panic!("expected two overrides");
+        };
+
+        assert_eq!(transfer.clear_interval, Some(10));
+        assert_eq!(transfer.clear_on_start, None);
+        assert_eq!(transfer.max_msg_num, Some(MaxMsgNum::new(50).unwrap()));
+        assert_eq!(transfer.batch_delay, Some(Duration::from_secs(1)));
+
+        assert_eq!(ica.tx_confirmation, Some(true));
+        assert_eq!(ica.max_tx_size, None);
+
+        let transfer_port = PortId::transfer();
+        let ica_port = "icahost".parse().unwrap();
+
+        assert!(transfer.matches(&transfer_port, &ChannelId::new(0)));
+        assert!(!transfer.matches(&transfer_port, &ChannelId::new(1)));
+        assert!(ica.matches(&ica_port, &ChannelId::new(42)));
+        assert!(!ica.matches(&transfer_port, &ChannelId::new(42)));
+    }
+
+    #[test]
+    fn serialize_channel_overrides() {
+        let overrides = ChannelOverrides {
+            port_id: PortFilterMatch::Exact(PortId::transfer()),
+            channel_id: ChannelFilterMatch::Wildcard("channel-*".parse().unwrap()),
+            clear_interval: Some(5),
+            clear_on_start: None,
+            tx_confirmation: Some(false),
+            max_msg_num: None,
+            max_tx_size: Some(MaxTxSize::new(100_000).unwrap()),
+            batch_delay: Some(Duration::from_millis(200)),
+        };
+
+        let value = toml::Value::try_from(&overrides).unwrap();
+        let parsed: ChannelOverrides = value.try_into().unwrap();
+
+        assert_eq!(parsed, overrides);
+    }
+}
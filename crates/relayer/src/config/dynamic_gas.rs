@@ -0,0 +1,218 @@
+use serde::de::{Error as _, Unexpected};
+use serde::{Deserializer, Serializer};
+use serde_derive::{Deserialize, Serialize};
+
+flex_error::define_error! {
+    Error {
+        MultiplierTooSmall
+            { value: f64 }
+            |e| {
+                format_args!("`dynamic_gas_price.multiplier` must be greater than or equal to {}, found {}",
+                    DynamicGasPrice::MIN_MULTIPLIER, e.value)
+            },
+
+        InvalidMax
+            { value: f64 }
+            |e| {
+                format_args!("`dynamic_gas_price.max` must be a positive number, found {}",
+                    e.value)
+            },
+    }
+}
+
+/// Configuration of the dynamic gas price, which tracks the base fee
+/// of chains running an EIP-1559-style fee market.
+///
+/// When enabled, the relayer queries the current base gas price of the chain
+/// before submitting each transaction, multiplies it by `multiplier` and
+/// uses the result as the gas price, clamped between the static `gas_price`
+/// of the chain and `max`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DynamicGasPrice {
+    pub enabled: bool,
+    pub multiplier: f64,
+    pub max: f64,
+}
+
+impl DynamicGasPrice {
+    const DEFAULT_MULTIPLIER: f64 = 1.1;
+    const DEFAULT_MAX: f64 = 0.6;
+    const MIN_MULTIPLIER: f64 = 1.0;
+
+    pub fn enabled(multiplier: f64, max: f64) -> Result<Self, Error> {
+        Self::new(true, multiplier, max)
+    }
+
+    pub fn disabled() -> Self {
+        Self {
+            enabled: false,
+            multiplier: Self::DEFAULT_MULTIPLIER,
+            max: Self::DEFAULT_MAX,
+        }
+    }
+
+    pub fn new(enabled: bool, multiplier: f64, max: f64) -> Result<Self, Error> {
+        if multiplier < Self::MIN_MULTIPLIER {
+            return Err(Error::multiplier_too_small(multiplier));
+        }
+
+        if max.is_nan() || max <= 0.0 {
+            return Err(Error::invalid_max(max));
+        }
+
+        Ok(Self {
+            enabled,
+            multiplier,
+            max,
+        })
+    }
+
+    /// Compute the gas price to use given the base gas price queried from the chain,
+    /// ie. `base_price * multiplier`, clamped between `min` and the configured maximum.
+    ///
+    /// If `min` is greater than the configured maximum, `min` is returned.
+    pub fn apply(&self, base_price: f64, min: f64) -> f64 {
+        (base_price * self.multiplier).min(self.max).max(min)
+    }
+}
+
+impl Default for DynamicGasPrice {
+    fn default() -> Self {
+        Self::disabled()
+    }
+}
+
+#[derive(Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+struct RawDynamicGasPrice {
+    #[serde(default)]
+    enabled: bool,
+    #[serde(default = "default_multiplier")]
+    multiplier: f64,
+    #[serde(default = "default_max")]
+    max: f64,
+}
+
+fn default_multiplier() -> f64 {
+    DynamicGasPrice::DEFAULT_MULTIPLIER
+}
+
+fn default_max() -> f64 {
+    DynamicGasPrice::DEFAULT_MAX
+}
+
+impl<'de> serde::Deserialize<'de> for DynamicGasPrice {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let RawDynamicGasPrice {
+            enabled,
+            multiplier,
+            max,
+        } = RawDynamicGasPrice::deserialize(deserializer)?;
+
+        DynamicGasPrice::new(enabled, multiplier, max).map_err(|e| match e.detail() {
+            ErrorDetail::MultiplierTooSmall(_) => D::Error::invalid_value(
+                Unexpected::Float(multiplier),
+                &format!(
+                    "a floating-point value greater than or equal to {} for `multiplier`",
+                    Self::MIN_MULTIPLIER
+                )
+                .as_str(),
+            ),
+            ErrorDetail::InvalidMax(_) => D::Error::invalid_value(
+                Unexpected::Float(max),
+                &"a positive floating-point value for `max`",
+            ),
+        })
+    }
+}
+
+impl serde::Serialize for DynamicGasPrice {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        RawDynamicGasPrice {
+            enabled: self.enabled,
+            multiplier: self.multiplier,
+            max: self.max,
+        }
+        .serialize(serializer)
+    }
+}
+
+#[cfg(test)]
+#[allow(dead_code)] // the field of the struct `DummyConfig` defined below is never accessed
+mod tests {
+    use super::*;
+
+    use serde::Deserialize;
+    use test_log::test;
+
+    #[derive(Debug, Deserialize)]
+    struct DummyConfig {
+        dynamic_gas_price: DynamicGasPrice,
+    }
+
+    #[test]
+    fn parse_dynamic_gas_price() {
+        let config = toml::from_str::<DummyConfig>(
+            "dynamic_gas_price = { enabled = true, multiplier = 1.2, max = 0.8 }",
+        )
+        .unwrap();
+
+        assert_eq!(
+            config.dynamic_gas_price,
+            DynamicGasPrice::enabled(1.2, 0.8).unwrap()
+        );
+    }
+
+    #[test]
+    fn parse_dynamic_gas_price_defaults() {
+        let config =
+            toml::from_str::<DummyConfig>("dynamic_gas_price = { enabled = true }").unwrap();
+
+        assert_eq!(
+            config.dynamic_gas_price,
+            DynamicGasPrice::enabled(
+                DynamicGasPrice::DEFAULT_MULTIPLIER,
+                DynamicGasPrice::DEFAULT_MAX
+            )
+            .unwrap()
+        );
+    }
+
+    #[test]
+    fn parse_invalid_dynamic_gas_price() {
+        let err = toml::from_str::<DummyConfig>(
+            "dynamic_gas_price = { enabled = true, multiplier = 0.9 }",
+        )
+        .unwrap_err()
+        .to_string();
+
+        assert!(err.contains("greater than or equal to 1 for `multiplier`"));
+
+        let err =
+            toml::from_str::<DummyConfig>("dynamic_gas_price = { enabled = true, max = 0.0 }")
+                .unwrap_err()
+                .to_string();
+
+        assert!(err.contains("a positive floating-point value for `max`"));
+    }
+
+    #[test]
+    fn apply_clamps_price() {
+        let dynamic = DynamicGasPrice::enabled(1.5, 0.5).unwrap();
+
+        // Within bounds
+        assert_eq!(dynamic.apply(0.2, 0.1), 0.2 * 1.5);
+
+        // Above the maximum
+        assert_eq!(dynamic.apply(0.4, 0.1), 0.5);
+
+        // Below the static gas price
+        assert_eq!(dynamic.apply(0.01, 0.1), 0.1);
+    }
+}
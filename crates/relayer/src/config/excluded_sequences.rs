@@ -0,0 +1,272 @@
+//! Packet sequences which are excluded from relaying, per channel.
+
+use alloc::collections::BTreeMap;
+use core::fmt;
+use core::str::FromStr;
+
+use serde::de::Visitor;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use ibc_relayer_types::core::ics04_channel::packet::Sequence;
+use ibc_relayer_types::core::ics24_host::identifier::ChannelId;
+
+/// Packet sequences which the relayer must not attempt to relay, per channel.
+///
+/// The channel identifiers refer to channels on the chain the packets are sent from.
+/// Excluded packets are neither received, timed out nor acknowledged, whether they
+/// are relayed from the events of the chain or when clearing packets, which is useful
+/// to stop retrying packets which can never be relayed successfully, eg. because the
+/// application on the destination chain always fails to receive them.
+///
+/// Each channel maps to a list of sequences or inclusive ranges of sequences, eg.
+/// `excluded_sequences = { 'channel-0' = [1, 2, '10-20'] }`.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ExcludedSequences {
+    map: BTreeMap<ChannelId, Vec<SequenceRange>>,
+}
+
+impl ExcludedSequences {
+    pub fn new(map: BTreeMap<ChannelId, Vec<SequenceRange>>) -> Self {
+        Self { map }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.map.is_empty()
+    }
+
+    /// Whether the packet with the given sequence, sent on the given channel, is excluded.
+    pub fn is_excluded(&self, channel_id: &ChannelId, sequence: Sequence) -> bool {
+        self.map
+            .get(channel_id)
+            .map_or(false, |ranges| ranges.iter().any(|r| r.contains(sequence)))
+    }
+
+    /// Split the given sequences of packets sent on the given channel
+    /// into the ones which can be relayed and the ones which are excluded.
+    pub fn partition(
+        &self,
+        channel_id: &ChannelId,
+        sequences: Vec<Sequence>,
+    ) -> (Vec<Sequence>, Vec<Sequence>) {
+        sequences
+            .into_iter()
+            .partition(|seq| !self.is_excluded(channel_id, *seq))
+    }
+}
+
+impl<'de> Deserialize<'de> for ExcludedSequences {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        use serde::de::Error;
+
+        let raw = <BTreeMap<String, Vec<SequenceRange>>>::deserialize(deserializer)?;
+
+        let map = raw
+            .into_iter()
+            .map(|(channel_id, ranges)| {
+                let channel_id = ChannelId::from_str(&channel_id).map_err(D::Error::custom)?;
+                Ok((channel_id, ranges))
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self { map })
+    }
+}
+
+impl Serialize for ExcludedSequences {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_map(self.map.iter().map(|(k, v)| (k.as_str(), v)))
+    }
+}
+
+/// An inclusive range of packet sequences, written either as
+/// a single sequence number, or as a `'<start>-<end>'` string.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct SequenceRange {
+    start: Sequence,
+    end: Sequence,
+}
+
+impl SequenceRange {
+    pub fn new(start: Sequence, end: Sequence) -> Self {
+        Self { start, end }
+    }
+
+    pub fn single(sequence: Sequence) -> Self {
+        Self::new(sequence, sequence)
+    }
+
+    pub fn contains(&self, sequence: Sequence) -> bool {
+        self.start <= sequence && sequence <= self.end
+    }
+}
+
+impl fmt::Display for SequenceRange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.start == self.end {
+            write!(f, "{}", self.start)
+        } else {
+            write!(f, "{}-{}", self.start, self.end)
+        }
+    }
+}
+
+impl FromStr for SequenceRange {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let parse = |seq: &str| {
+            seq.trim()
+                .parse::<u64>()
+                .map(Sequence::from)
+                .map_err(|e| format!("invalid sequence '{seq}': {e}"))
+        };
+
+        match s.split_once('-') {
+            None => parse(s).map(Self::single),
+            Some((start, end)) => {
+                let (start, end) = (parse(start)?, parse(end)?);
+
+                if start > end {
+                    return Err(format!(
+                        "invalid sequence range '{s}': start is greater than end"
+                    ));
+                }
+
+                Ok(Self::new(start, end))
+            }
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for SequenceRange {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct SequenceRangeVisitor;
+
+        impl<'de> Visitor<'de> for SequenceRangeVisitor {
+            type Value = SequenceRange;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("a sequence number or a range of sequence numbers, eg. '1-10'")
+            }
+
+            fn visit_u64<E: serde::de::Error>(self, value: u64) -> Result<Self::Value, E> {
+                Ok(SequenceRange::single(Sequence::from(value)))
+            }
+
+            fn visit_i64<E: serde::de::Error>(self, value: i64) -> Result<Self::Value, E> {
+                u64::try_from(value)
+                    .map(|value| SequenceRange::single(Sequence::from(value)))
+                    .map_err(|_| E::custom(format!("invalid sequence '{value}'")))
+            }
+
+            fn visit_str<E: serde::de::Error>(self, value: &str) -> Result<Self::Value, E> {
+                value.parse().map_err(E::custom)
+            }
+        }
+
+        deserializer.deserialize_any(SequenceRangeVisitor)
+    }
+}
+
+impl Serialize for SequenceRange {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        if self.start == self.end {
+            serializer.serialize_u64(self.start.into())
+        } else {
+            serializer.serialize_str(&self.to_string())
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(dead_code)] // the field of the struct `DummyConfig` defined below is never accessed
+mod tests {
+    use super::*;
+
+    use test_log::test;
+
+    #[derive(Debug, Deserialize)]
+    struct DummyConfig {
+        excluded_sequences: ExcludedSequences,
+    }
+
+    fn channel(id: u64) -> ChannelId {
+        ChannelId::new(id)
+    }
+
+    #[test]
+    fn parse_excluded_sequences() {
+        let config = toml::from_str::<DummyConfig>(
+            "excluded_sequences = { 'channel-0' = [1, 3, '10-12'], 'channel-1' = ['5'] }",
+        )
+        .unwrap();
+
+        let excluded = config.excluded_sequences;
+
+        for seq in [1, 3, 10, 11, 12] {
+            assert!(excluded.is_excluded(&channel(0), seq.into()));
+        }
+
+        for seq in [2, 4, 9, 13] {
+            assert!(!excluded.is_excluded(&channel(0), seq.into()));
+        }
+
+        assert!(excluded.is_excluded(&channel(1), 5.into()));
+        assert!(!excluded.is_excluded(&channel(2), 5.into()));
+    }
+
+    #[test]
+    fn parse_invalid_excluded_sequences() {
+        for invalid in ["[-1]", "['a']", "['5-1']", "['1-a']"] {
+            let toml = format!("excluded_sequences = {{ 'channel-0' = {invalid} }}");
+
+            assert!(
+                toml::from_str::<DummyConfig>(&toml).is_err(),
+                "{invalid} should not parse"
+            );
+        }
+    }
+
+    #[test]
+    fn serialize_excluded_sequences() {
+        let excluded = ExcludedSequences::new(BTreeMap::from([(
+            channel(0),
+            vec![
+                SequenceRange::single(1.into()),
+                SequenceRange::new(3.into(), 7.into()),
+            ],
+        )]));
+
+        let value = toml::Value::try_from(&excluded).unwrap();
+        let parsed: ExcludedSequences = value.try_into().unwrap();
+
+        assert_eq!(parsed, excluded);
+    }
+
+    #[test]
+    fn partition_sequences() {
+        let excluded = ExcludedSequences::new(BTreeMap::from([(
+            channel(0),
+            vec![SequenceRange::new(2.into(), 3.into())],
+        )]));
+
+        let sequences = (1..=4).map(Sequence::from).collect();
+
+        assert_eq!(
+            excluded.partition(&channel(0), sequences),
+            (vec![1.into(), 4.into()], vec![2.into(), 3.into()])
+        );
+    }
+}
@@ -13,7 +13,7 @@ use ibc_relayer_types::core::ics24_host::identifier::{ChannelId, PortId};
 use ibc_relayer_types::events::IbcEventType;
 
 /// Represents all the filtering policies for packets.
-#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct PacketFilter {
     #[serde(flatten)]
     pub channel_policy: ChannelPolicy,
@@ -68,27 +68,108 @@ pub enum ChannelPolicy {
 }
 
 /// Represents the policy used to filter incentivized packets.
-/// Currently only filtering on `recv_fee` is authorized.
-#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+/// Packets can be filtered on their `recv_fee`, and on whether relaying them is profitable.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
 pub struct FeePolicy {
+    #[serde(default)]
     recv: Vec<MinFee>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    profitability: Option<ProfitabilityPolicy>,
 }
 
 impl FeePolicy {
     pub fn new(recv: Vec<MinFee>) -> Self {
-        Self { recv }
+        Self {
+            recv,
+            profitability: None,
+        }
+    }
+
+    pub fn with_profitability(self, profitability: ProfitabilityPolicy) -> Self {
+        Self {
+            profitability: Some(profitability),
+            ..self
+        }
+    }
+
+    pub fn profitability(&self) -> Option<&ProfitabilityPolicy> {
+        self.profitability.as_ref()
     }
 
+    /// Whether the packet should be relayed given its `recv_fee`.
+    /// The profitability of relaying the packet is checked separately,
+    /// see [`ProfitabilityPolicy::is_profitable`].
     pub fn should_relay(&self, event_type: IbcEventType, fees: &[RawCoin]) -> bool {
         match event_type {
-            IbcEventType::SendPacket => fees
-                .iter()
-                .any(|fee| self.recv.iter().any(|e| e.is_enough(fee))),
+            // A profitability policy without any minimum `recv_fee` does not filter on the latter
+            IbcEventType::SendPacket => {
+                (self.recv.is_empty() && self.profitability.is_some())
+                    || fees
+                        .iter()
+                        .any(|fee| self.recv.iter().any(|e| e.is_enough(fee)))
+            }
             _ => true,
         }
     }
 }
 
+/// Represents the policy used to only relay incentivized packets whose incentives,
+/// ie. the sum of their `recv_fee`, `ack_fee` and `timeout_fee`, exceed the estimated
+/// cost of relaying them by a minimum margin.
+///
+/// Amounts in different denoms are compared by converting them using the configured prices.
+/// Incentives in denoms without a price are ignored.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ProfitabilityPolicy {
+    /// Minimum margin of the incentives over the cost, as a fraction of the cost.
+    /// Eg. `0.2` only relays packets whose incentives are worth at least 120% of the cost.
+    /// Like the prices, it must be a non-negative number, which is checked when
+    /// the configuration is validated.
+    #[serde(default)]
+    min_margin: f64,
+    /// Price of one unit of each denom, in a common unit of account.
+    prices: HashMap<String, f64>,
+}
+
+impl ProfitabilityPolicy {
+    pub fn new(min_margin: f64, prices: HashMap<String, f64>) -> Self {
+        Self { min_margin, prices }
+    }
+
+    pub fn min_margin(&self) -> f64 {
+        self.min_margin
+    }
+
+    pub fn prices(&self) -> &HashMap<String, f64> {
+        &self.prices
+    }
+
+    /// Whether the given incentives exceed the given cost by at least the minimum margin.
+    ///
+    /// Returns `None` if the cost cannot be valued, because it is in a denom without a price.
+    pub fn is_profitable(&self, incentives: &[RawCoin], cost: &[RawCoin]) -> Option<bool> {
+        let incentives = incentives
+            .iter()
+            .filter_map(|coin| self.value(coin))
+            .sum::<f64>();
+
+        let cost = cost
+            .iter()
+            .map(|coin| self.value(coin))
+            .sum::<Option<f64>>()?;
+
+        Some(incentives >= cost * (1.0 + self.min_margin))
+    }
+
+    fn value(&self, coin: &RawCoin) -> Option<f64> {
+        let price = self.prices.get(&coin.denom)?;
+        let amount = coin.amount.to_string().parse::<f64>().ok()?;
+
+        Some(amount * price)
+    }
+}
+
 /// Represents the minimum fee authorized when filtering.
 /// If no denom is specified, any denom is allowed.
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
@@ -605,4 +686,39 @@ mod tests {
         let wildcard = "ica*".parse::<Wildcard>().unwrap();
         assert_eq!(wildcard.to_string(), "ica*".to_string());
     }
+
+    #[test]
+    fn deserialize_profitability_policy() {
+        let toml_content = r#"
+            [profitability]
+            min_margin = 0.5
+            prices = { stake = 1.0, uatom = 10.0 }
+            "#;
+
+        let fee_policy: FeePolicy =
+            toml::from_str(toml_content).expect("could not parse fee policy");
+
+        let profitability = fee_policy.profitability().unwrap();
+        let coin = |amount: u64, denom: &str| RawCoin::new(denom.to_string(), amount);
+
+        // Without a minimum `recv_fee`, only profitability is checked
+        assert!(fee_policy.should_relay(IbcEventType::SendPacket, &[]));
+
+        // 10uatom + 50stake = 150, cost = 100stake, margin = 50%
+        let incentives = [coin(10, "uatom"), coin(50, "stake"), coin(1000, "unknown")];
+        assert_eq!(
+            profitability.is_profitable(&incentives, &[coin(100, "stake")]),
+            Some(true)
+        );
+        assert_eq!(
+            profitability.is_profitable(&incentives, &[coin(101, "stake")]),
+            Some(false)
+        );
+
+        // The cost cannot be valued
+        assert_eq!(
+            profitability.is_profitable(&incentives, &[coin(1, "unknown")]),
+            None
+        );
+    }
 }
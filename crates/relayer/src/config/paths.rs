@@ -0,0 +1,182 @@
+//! Paths between chains, ie. channels along with their underlying connection
+//! and clients, which Hermes creates if they do not exist yet.
+
+use core::str::FromStr;
+use core::time::Duration;
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use ibc_relayer_types::core::ics02_client::trust_threshold::TrustThreshold;
+use ibc_relayer_types::core::ics04_channel::channel::Ordering;
+use ibc_relayer_types::core::ics04_channel::version::Version;
+use ibc_relayer_types::core::ics24_host::identifier::{ChainId, PortId};
+
+use crate::config::default;
+use crate::foreign_client::CreateOptions;
+
+/// A channel between ports of two chains, along with the connection and clients
+/// underlying it, as declared in a `[[paths]]` section of the configuration.
+///
+/// The existing channels, connections and clients which match the path are
+/// reused, and only the missing ones are created when reconciling the path.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct PathConfig {
+    pub a_chain: ChainId,
+    pub b_chain: ChainId,
+    pub a_port: PortId,
+    pub b_port: PortId,
+
+    /// The version of the channel, negotiated by the
+    /// applications bound to the ports if unspecified
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub version: Option<Version>,
+
+    #[serde(default, with = "ordering")]
+    pub ordering: Ordering,
+
+    #[serde(default = "default::connection_delay", with = "humantime_serde")]
+    pub connection_delay: Duration,
+
+    /// The settings of the client hosted on chain `a`, which tracks chain `b`
+    #[serde(default)]
+    pub a_client: PathClientSettings,
+
+    /// The settings of the client hosted on chain `b`, which tracks chain `a`
+    #[serde(default)]
+    pub b_client: PathClientSettings,
+}
+
+/// The desired settings of a client, which default to the ones
+/// derived from the configuration of the chains when unspecified.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct PathClientSettings {
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        with = "humantime_serde"
+    )]
+    pub max_clock_drift: Option<Duration>,
+
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        with = "humantime_serde"
+    )]
+    pub trusting_period: Option<Duration>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub trust_threshold: Option<TrustThreshold>,
+}
+
+impl PathClientSettings {
+    pub fn create_options(&self) -> CreateOptions {
+        CreateOptions {
+            max_clock_drift: self.max_clock_drift,
+            trusting_period: self.trusting_period,
+            trust_threshold: self.trust_threshold,
+        }
+    }
+}
+
+/// (De)serializes the ordering of a channel as either `'unordered'` or `'ordered'`.
+mod ordering {
+    use super::*;
+
+    pub fn serialize<S: Serializer>(ordering: &Ordering, serializer: S) -> Result<S::Ok, S::Error> {
+        match ordering {
+            Ordering::Ordered => serializer.serialize_str("ordered"),
+            _ => serializer.serialize_str("unordered"),
+        }
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Ordering, D::Error> {
+        use serde::de::Error;
+
+        let ordering = String::deserialize(deserializer)?;
+
+        match Ordering::from_str(&ordering).map_err(D::Error::custom)? {
+            Ordering::Uninitialized => Err(D::Error::custom(format!(
+                "invalid channel ordering '{ordering}', expected 'unordered' or 'ordered'"
+            ))),
+            ordering => Ok(ordering),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use test_log::test;
+
+    #[derive(Debug, Deserialize)]
+    struct DummyConfig {
+        paths: Vec<PathConfig>,
+    }
+
+    #[test]
+    fn parse_paths() {
+        let config = toml::from_str::<DummyConfig>(
+            r#"
+            [[paths]]
+            a_chain = 'ibc-0'
+            b_chain = 'ibc-1'
+            a_port = 'transfer'
+            b_port = 'transfer'
+
+            [[paths]]
+            a_chain = 'ibc-0'
+            b_chain = 'ibc-2'
+            a_port = 'icacontroller-owner'
+            b_port = 'icahost'
+            version = 'ics27-1'
+            ordering = 'ordered'
+            connection_delay = '1m'
+            a_client = { trusting_period = '14days', trust_threshold = { numerator = 2, denominator = 3 } }
+            "#,
+        )
+        .unwrap();
+
+        let [transfer, ica] = &config.paths[..] else {
+            panic!("expected two paths");
+        };
+
+        assert_eq!(transfer.ordering, Ordering::Unordered);
+        assert_eq!(transfer.version, None);
+        assert_eq!(transfer.connection_delay, Duration::ZERO);
+        assert_eq!(transfer.a_client, PathClientSettings::default());
+
+        assert_eq!(ica.ordering, Ordering::Ordered);
+        assert_eq!(ica.version, Some(Version::new("ics27-1".to_string())));
+        assert_eq!(ica.connection_delay, Duration::from_secs(60));
+        assert_eq!(
+            ica.a_client.trusting_period,
+            Some(Duration::from_secs(14 * 24 * 3600))
+        );
+        assert_eq!(
+            ica.a_client.trust_threshold,
+            Some(TrustThreshold::TWO_THIRDS)
+        );
+        assert_eq!(ica.b_client, PathClientSettings::default());
+
+        let value = toml::Value::try_from(ica).unwrap();
+        let parsed: PathConfig = value.try_into().unwrap();
+        assert_eq!(&parsed, ica);
+    }
+
+    #[test]
+    fn parse_invalid_ordering() {
+        let toml = r#"
+            [[paths]]
+            a_chain = 'ibc-0'
+            b_chain = 'ibc-1'
+            a_port = 'transfer'
+            b_port = 'transfer'
+            ordering = 'uninitialized'
+            "#;
+
+        assert!(toml::from_str::<DummyConfig>(toml).is_err());
+    }
+}
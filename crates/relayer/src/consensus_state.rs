@@ -1,21 +1,29 @@
 use ibc_proto::google::protobuf::Any;
 use ibc_proto::ibc::core::client::v1::ConsensusStateWithHeight;
 use ibc_proto::ibc::lightclients::tendermint::v1::ConsensusState as RawConsensusState;
-#[cfg(test)]
+#[cfg(any(test, feature = "mocks"))]
 use ibc_proto::ibc::mock::ConsensusState as RawMockConsensusState;
 use ibc_proto::protobuf::Protobuf;
+use ibc_relayer_types::clients::ics06_solomachine::consensus_state::{
+    ConsensusState as SmConsensusState, SOLOMACHINE_CONSENSUS_STATE_TYPE_URL,
+};
+use ibc_relayer_types::clients::ics06_solomachine::raw::ConsensusState as RawSmConsensusState;
 use ibc_relayer_types::clients::ics07_tendermint::consensus_state::{
     ConsensusState as TmConsensusState, TENDERMINT_CONSENSUS_STATE_TYPE_URL,
 };
+use ibc_relayer_types::clients::ics08_wasm::consensus_state::{
+    ConsensusState as WasmConsensusState, WASM_CONSENSUS_STATE_TYPE_URL,
+};
+use ibc_relayer_types::clients::ics08_wasm::raw::ConsensusState as RawWasmConsensusState;
 use ibc_relayer_types::core::ics02_client::client_type::ClientType;
 use ibc_relayer_types::core::ics02_client::consensus_state::{
     downcast_consensus_state, ConsensusState,
 };
 use ibc_relayer_types::core::ics02_client::error::Error;
 use ibc_relayer_types::core::ics23_commitment::commitment::CommitmentRoot;
-#[cfg(test)]
+#[cfg(any(test, feature = "mocks"))]
 use ibc_relayer_types::mock::consensus_state::MockConsensusState;
-#[cfg(test)]
+#[cfg(any(test, feature = "mocks"))]
 use ibc_relayer_types::mock::consensus_state::MOCK_CONSENSUS_STATE_TYPE_URL;
 use ibc_relayer_types::timestamp::Timestamp;
 use ibc_relayer_types::Height;
@@ -27,7 +35,11 @@ use serde::{Deserialize, Serialize};
 pub enum AnyConsensusState {
     Tendermint(TmConsensusState),
 
-    #[cfg(test)]
+    Solomachine(SmConsensusState),
+
+    Wasm(WasmConsensusState),
+
+    #[cfg(any(test, feature = "mocks"))]
     Mock(MockConsensusState),
 }
 
@@ -36,7 +48,11 @@ impl AnyConsensusState {
         match self {
             Self::Tendermint(cs_state) => cs_state.timestamp.into(),
 
-            #[cfg(test)]
+            Self::Solomachine(cs_state) => cs_state.timestamp,
+
+            Self::Wasm(cs_state) => cs_state.timestamp(),
+
+            #[cfg(any(test, feature = "mocks"))]
             Self::Mock(mock_state) => mock_state.timestamp(),
         }
     }
@@ -45,7 +61,11 @@ impl AnyConsensusState {
         match self {
             AnyConsensusState::Tendermint(_cs) => ClientType::Tendermint,
 
-            #[cfg(test)]
+            AnyConsensusState::Solomachine(_cs) => ClientType::Solomachine,
+
+            AnyConsensusState::Wasm(_cs) => ClientType::Wasm,
+
+            #[cfg(any(test, feature = "mocks"))]
             AnyConsensusState::Mock(_cs) => ClientType::Mock,
         }
     }
@@ -65,7 +85,17 @@ impl TryFrom<Any> for AnyConsensusState {
                     .map_err(Error::decode_raw_client_state)?,
             )),
 
-            #[cfg(test)]
+            SOLOMACHINE_CONSENSUS_STATE_TYPE_URL => Ok(AnyConsensusState::Solomachine(
+                Protobuf::<RawSmConsensusState>::decode_vec(&value.value)
+                    .map_err(Error::decode_raw_client_state)?,
+            )),
+
+            WASM_CONSENSUS_STATE_TYPE_URL => Ok(AnyConsensusState::Wasm(
+                Protobuf::<RawWasmConsensusState>::decode_vec(&value.value)
+                    .map_err(Error::decode_raw_client_state)?,
+            )),
+
+            #[cfg(any(test, feature = "mocks"))]
             MOCK_CONSENSUS_STATE_TYPE_URL => Ok(AnyConsensusState::Mock(
                 Protobuf::<RawMockConsensusState>::decode_vec(&value.value)
                     .map_err(Error::decode_raw_client_state)?,
@@ -83,7 +113,15 @@ impl From<AnyConsensusState> for Any {
                 type_url: TENDERMINT_CONSENSUS_STATE_TYPE_URL.to_string(),
                 value: Protobuf::<RawConsensusState>::encode_vec(&value),
             },
-            #[cfg(test)]
+            AnyConsensusState::Solomachine(value) => Any {
+                type_url: SOLOMACHINE_CONSENSUS_STATE_TYPE_URL.to_string(),
+                value: Protobuf::<RawSmConsensusState>::encode_vec(&value),
+            },
+            AnyConsensusState::Wasm(value) => Any {
+                type_url: WASM_CONSENSUS_STATE_TYPE_URL.to_string(),
+                value: Protobuf::<RawWasmConsensusState>::encode_vec(&value),
+            },
+            #[cfg(any(test, feature = "mocks"))]
             AnyConsensusState::Mock(value) => Any {
                 type_url: MOCK_CONSENSUS_STATE_TYPE_URL.to_string(),
                 value: Protobuf::<RawMockConsensusState>::encode_vec(&value),
@@ -92,7 +130,7 @@ impl From<AnyConsensusState> for Any {
     }
 }
 
-#[cfg(test)]
+#[cfg(any(test, feature = "mocks"))]
 impl From<MockConsensusState> for AnyConsensusState {
     fn from(cs: MockConsensusState) -> Self {
         Self::Mock(cs)
@@ -105,13 +143,33 @@ impl From<TmConsensusState> for AnyConsensusState {
     }
 }
 
+impl From<SmConsensusState> for AnyConsensusState {
+    fn from(cs: SmConsensusState) -> Self {
+        Self::Solomachine(cs)
+    }
+}
+
+impl From<WasmConsensusState> for AnyConsensusState {
+    fn from(cs: WasmConsensusState) -> Self {
+        Self::Wasm(cs)
+    }
+}
+
 impl From<&dyn ConsensusState> for AnyConsensusState {
     fn from(cs: &dyn ConsensusState) -> Self {
-        #[cfg(test)]
+        #[cfg(any(test, feature = "mocks"))]
         if let Some(cs) = downcast_consensus_state::<MockConsensusState>(cs) {
             return AnyConsensusState::from(cs.clone());
         }
 
+        if let Some(cs) = downcast_consensus_state::<SmConsensusState>(cs) {
+            return AnyConsensusState::from(cs.clone());
+        }
+
+        if let Some(cs) = downcast_consensus_state::<WasmConsensusState>(cs) {
+            return AnyConsensusState::from(cs.clone());
+        }
+
         if let Some(cs) = downcast_consensus_state::<TmConsensusState>(cs) {
             AnyConsensusState::from(cs.clone())
         } else {
@@ -166,7 +224,11 @@ impl ConsensusState for AnyConsensusState {
         match self {
             Self::Tendermint(cs_state) => cs_state.root(),
 
-            #[cfg(test)]
+            Self::Solomachine(cs_state) => cs_state.root(),
+
+            Self::Wasm(cs_state) => cs_state.root(),
+
+            #[cfg(any(test, feature = "mocks"))]
             Self::Mock(mock_state) => mock_state.root(),
         }
     }
@@ -0,0 +1,729 @@
+//! Accounting of the economics of relaying, ie. of the fees paid by Hermes for the
+//! transactions it submits against the ICS29 incentives it earns for the packets it relays.
+//!
+//! Every committed transaction submitted by Hermes is attributed to the channels whose
+//! packets it relays, based on the packet events it emitted:
+//! - the fees paid are taken from the `tx` event emitted by the Cosmos SDK ante handler,
+//!   and split across the channels in proportion to the number of packets relayed on each;
+//! - the incentives earned are the `distribute_fee` rewards paid to the fee payer.
+//!
+//! The amounts are reported as telemetry and, when the `[economics]` section is enabled,
+//! appended as JSON lines to a ledger file, which `hermes query relayer-economics` reports on.
+
+use alloc::collections::btree_map::BTreeMap as HashMap;
+use alloc::collections::VecDeque;
+use std::collections::HashSet;
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, BufRead, BufReader, Write};
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use flex_error::{define_error, TraceError};
+use once_cell::sync::{Lazy, OnceCell};
+use serde::{Deserialize, Serialize};
+use tendermint::abci;
+use tendermint::Hash as TxHash;
+use tendermint_rpc::endpoint::tx::Response as TxResponse;
+use tracing::{debug, error};
+
+use ibc_relayer_types::applications::ics29_fee::events::DistributionType;
+use ibc_relayer_types::applications::transfer::{Amount, RawCoin};
+use ibc_relayer_types::bigint::U256;
+use ibc_relayer_types::core::ics24_host::identifier::{ChainId, ChannelId, PortId};
+use ibc_relayer_types::events::IbcEvent;
+
+use crate::config::EconomicsConfig;
+use crate::event::ibc_event_try_from_abci_event;
+
+pub const LEDGER_DEFAULT_PATH: &str = ".hermes/economics.jsonl";
+
+/// How many transaction hashes to remember in order not to account twice for the same transaction.
+const RECENT_TXS_CAPACITY: usize = 10_000;
+
+/// How many transactions submitted without waiting for their commit to remember, per chain.
+const SUBMITTED_TXS_CAPACITY: usize = 10_000;
+
+static LEDGER: OnceCell<Mutex<File>> = OnceCell::new();
+
+static RECENT_TXS: Lazy<Mutex<RecentTxs>> = Lazy::new(|| Mutex::new(RecentTxs::default()));
+
+define_error! {
+    EconomicsError {
+        HomeLocationUnavailable
+            |_| { "home location is unavailable" },
+
+        Io
+            { path: PathBuf }
+            [ TraceError<io::Error> ]
+            |e| { format!("I/O error on economics ledger {}", e.path.display()) },
+
+        Decode
+            { path: PathBuf, line: usize }
+            [ TraceError<serde_json::Error> ]
+            |e| { format!("failed to decode line {} of economics ledger {}", e.line, e.path.display()) },
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EntryKind {
+    /// Fees paid for a transaction submitted by Hermes.
+    FeePaid,
+    /// ICS29 incentive earned for relaying a packet.
+    IncentiveEarned,
+}
+
+/// An entry of the economics ledger.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LedgerEntry {
+    /// When the transaction was found committed, in seconds since the Unix epoch.
+    pub timestamp: u64,
+    pub chain_id: ChainId,
+    /// The channel to which the amount is attributed, if any.
+    /// Fees paid for transactions which do not relay any packet, eg. client updates,
+    /// are not attributed to any channel.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub port_id: Option<PortId>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub channel_id: Option<ChannelId>,
+    pub tx_hash: String,
+    pub kind: EntryKind,
+    #[serde(flatten)]
+    pub coin: RawCoin,
+}
+
+impl LedgerEntry {
+    pub fn channel(&self) -> Option<(&PortId, &ChannelId)> {
+        self.port_id.as_ref().zip(self.channel_id.as_ref())
+    }
+}
+
+/// Returns the path of the ledger configured in the given `[economics]` section.
+pub fn ledger_path(config: &EconomicsConfig) -> Result<PathBuf, EconomicsError> {
+    match &config.path {
+        Some(path) => Ok(path.clone()),
+        None => {
+            let home =
+                dirs_next::home_dir().ok_or_else(EconomicsError::home_location_unavailable)?;
+            Ok(home.join(LEDGER_DEFAULT_PATH))
+        }
+    }
+}
+
+/// Opens the ledger configured in the given `[economics]` section, if it is enabled,
+/// to which the amounts of all the transactions recorded from then on are appended.
+pub fn open_ledger(config: &EconomicsConfig) -> Result<(), EconomicsError> {
+    if !config.enabled {
+        return Ok(());
+    }
+
+    let path = ledger_path(config)?;
+
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).map_err(|e| EconomicsError::io(path.clone(), e))?;
+    }
+
+    // Transactions recorded before a restart may be queried again when reconciling
+    // the relaying state, make sure they are not accounted for twice.
+    if path.exists() {
+        let entries = read_ledger(&path)?;
+        let mut recent_txs = RECENT_TXS.lock().unwrap();
+
+        for entry in entries {
+            recent_txs.insert(&entry.chain_id, &entry.tx_hash);
+        }
+    }
+
+    let file = OpenOptions::new()
+        .append(true)
+        .create(true)
+        .open(&path)
+        .map_err(|e| EconomicsError::io(path.clone(), e))?;
+
+    match LEDGER.set(Mutex::new(file)) {
+        Ok(()) => debug!("recording relayer economics to {}", path.display()),
+        Err(_) => error!("economics ledger was already opened"),
+    }
+
+    Ok(())
+}
+
+/// Reads all the entries of the ledger at the given path.
+pub fn read_ledger(path: &Path) -> Result<Vec<LedgerEntry>, EconomicsError> {
+    let file = File::open(path).map_err(|e| EconomicsError::io(path.to_path_buf(), e))?;
+
+    let mut entries = Vec::new();
+
+    for (index, line) in BufReader::new(file).lines().enumerate() {
+        let line = line.map_err(|e| EconomicsError::io(path.to_path_buf(), e))?;
+
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        let entry = serde_json::from_str(&line)
+            .map_err(|e| EconomicsError::decode(path.to_path_buf(), index + 1, e))?;
+
+        entries.push(entry);
+    }
+
+    Ok(entries)
+}
+
+/// Accounts for the fees paid and the incentives earned by a committed transaction
+/// submitted by Hermes. Transactions which were already accounted for are ignored.
+pub fn record_tx_response(chain_id: &ChainId, response: &TxResponse) {
+    let tx_hash = response.hash.to_string();
+
+    if !RECENT_TXS.lock().unwrap().insert(chain_id, &tx_hash) {
+        return;
+    }
+
+    let entries = tx_entries(
+        chain_id,
+        &tx_hash,
+        &response.tx_result.events,
+        unix_timestamp(),
+    );
+
+    crate::telemetry!(entries.iter().for_each(record_metrics));
+
+    if let Some(ledger) = LEDGER.get() {
+        if let Err(e) = append_entries(ledger, &entries) {
+            error!("failed to append to economics ledger: {e}");
+        }
+    }
+}
+
+#[cfg(feature = "telemetry")]
+fn record_metrics(entry: &LedgerEntry) {
+    let amount = entry.coin.amount.0;
+    let amount = if amount > u64::MAX.into() {
+        u64::MAX
+    } else {
+        amount.as_u64()
+    };
+
+    let telemetry = ibc_telemetry::global();
+
+    match entry.kind {
+        EntryKind::FeePaid => {
+            telemetry.relayer_fees_paid(&entry.chain_id, entry.channel(), &entry.coin.denom, amount)
+        }
+        EntryKind::IncentiveEarned => telemetry.relayer_incentives_earned(
+            &entry.chain_id,
+            entry.channel(),
+            &entry.coin.denom,
+            amount,
+        ),
+    }
+}
+
+fn append_entries(ledger: &Mutex<File>, entries: &[LedgerEntry]) -> io::Result<()> {
+    let mut file = ledger.lock().unwrap();
+
+    for entry in entries {
+        serde_json::to_writer(&mut *file, entry)?;
+        writeln!(&mut *file)?;
+    }
+
+    Ok(())
+}
+
+fn unix_timestamp() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|elapsed| elapsed.as_secs())
+        .unwrap_or_default()
+}
+
+/// Computes the ledger entries of a committed transaction, given the events it emitted.
+pub fn tx_entries(
+    chain_id: &ChainId,
+    tx_hash: &str,
+    events: &[abci::Event],
+    timestamp: u64,
+) -> Vec<LedgerEntry> {
+    let mut fees = Vec::new();
+    let mut fee_payer = None;
+
+    // The channel of every packet relayed by the transaction, along with the index of its event
+    let mut packets = Vec::new();
+    // The incentives distributed by the transaction, along with the index of their event
+    let mut rewards = Vec::new();
+
+    for (index, event) in events.iter().enumerate() {
+        if event.kind == "tx" {
+            for attribute in &event.attributes {
+                match attribute.key.as_str() {
+                    "fee" if !attribute.value.is_empty() => {
+                        match RawCoin::from_string_list(&attribute.value) {
+                            Ok(coins) => fees.extend(coins),
+                            Err(e) => debug!("ignoring fee `{}`: {e}", attribute.value),
+                        }
+                    }
+                    "fee_payer" => fee_payer = Some(attribute.value.clone()),
+                    _ => {}
+                }
+            }
+
+            continue;
+        }
+
+        match ibc_event_try_from_abci_event(event) {
+            // Packets received are identified by the acknowledgement written for them
+            Ok(IbcEvent::WriteAcknowledgement(ev)) => packets.push((
+                index,
+                (ev.packet.destination_port, ev.packet.destination_channel),
+            )),
+            Ok(IbcEvent::AcknowledgePacket(ev)) => {
+                packets.push((index, (ev.packet.source_port, ev.packet.source_channel)))
+            }
+            Ok(IbcEvent::TimeoutPacket(ev)) => {
+                packets.push((index, (ev.packet.source_port, ev.packet.source_channel)))
+            }
+            Ok(IbcEvent::DistributeFeePacket(ev))
+                if ev.distribution_type == DistributionType::Reward =>
+            {
+                rewards.push((index, ev))
+            }
+            _ => {}
+        }
+    }
+
+    let entry = |channel: Option<&(PortId, ChannelId)>, kind, coin| LedgerEntry {
+        timestamp,
+        chain_id: chain_id.clone(),
+        port_id: channel.map(|(port_id, _)| port_id.clone()),
+        channel_id: channel.map(|(_, channel_id)| channel_id.clone()),
+        tx_hash: tx_hash.to_string(),
+        kind,
+        coin,
+    };
+
+    let mut entries = Vec::new();
+
+    // Split the fees across channels in proportion to the number of packets relayed on each,
+    // the remainder of the division going to the first channel.
+    let mut packets_per_channel = HashMap::new();
+    for (_, channel) in &packets {
+        *packets_per_channel.entry(channel).or_insert(0u64) += 1;
+    }
+
+    for fee in fees {
+        if packets_per_channel.is_empty() {
+            entries.push(entry(None, EntryKind::FeePaid, fee));
+            continue;
+        }
+
+        let total = U256::from(packets.len());
+        let mut remainder = fee.amount.0;
+
+        let mut shares = packets_per_channel
+            .iter()
+            .map(|(channel, count)| {
+                let share = fee.amount.0 * U256::from(*count) / total;
+                remainder -= share;
+                (*channel, share)
+            })
+            .collect::<Vec<_>>();
+
+        shares[0].1 += remainder;
+
+        for (channel, share) in shares {
+            let coin = RawCoin::new(fee.denom.clone(), Amount(share));
+            entries.push(entry(Some(channel), EntryKind::FeePaid, coin));
+        }
+    }
+
+    // Incentives are distributed right after the packet they pay for is handled, hence
+    // attribute each one to the channel of the packet event preceding it, if any.
+    for (index, reward) in rewards {
+        if fee_payer.as_deref() != Some(reward.receiver.as_ref()) {
+            continue;
+        }
+
+        let channel = packets
+            .iter()
+            .rev()
+            .find(|(packet_index, _)| *packet_index < index)
+            .or_else(|| packets.first())
+            .map(|(_, channel)| channel);
+
+        entries.push(entry(channel, EntryKind::IncentiveEarned, reward.fee));
+    }
+
+    entries
+}
+
+/// The fees paid and the incentives earned on a channel, in a given denom.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct ChannelEconomics {
+    pub chain_id: ChainId,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub port_id: Option<PortId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub channel_id: Option<ChannelId>,
+    pub denom: String,
+    #[serde(with = "amount_string")]
+    pub fees_paid: Amount,
+    #[serde(with = "amount_string")]
+    pub incentives_earned: Amount,
+    /// The incentives earned minus the fees paid, which is negative if relaying is a net cost.
+    pub net: String,
+}
+
+/// Aggregates the given ledger entries per chain, channel and denom,
+/// only keeping the entries recorded at or after `since` and on `chain_id`, if given.
+pub fn aggregate(
+    entries: &[LedgerEntry],
+    since: Option<u64>,
+    chain_id: Option<&ChainId>,
+) -> Vec<ChannelEconomics> {
+    let zero = Amount(U256::zero());
+
+    let mut totals = HashMap::new();
+
+    for entry in entries {
+        if since.map_or(false, |since| entry.timestamp < since)
+            || chain_id.map_or(false, |chain_id| &entry.chain_id != chain_id)
+        {
+            continue;
+        }
+
+        let key = (
+            entry.chain_id.clone(),
+            entry.port_id.clone(),
+            entry.channel_id.clone(),
+            entry.coin.denom.clone(),
+        );
+
+        let (paid, earned) = totals.entry(key).or_insert((zero, zero));
+
+        let total = match entry.kind {
+            EntryKind::FeePaid => paid,
+            EntryKind::IncentiveEarned => earned,
+        };
+
+        *total = total.checked_add(entry.coin.amount).unwrap_or(*total);
+    }
+
+    totals
+        .into_iter()
+        .map(
+            |((chain_id, port_id, channel_id, denom), (fees_paid, incentives_earned))| {
+                let net = match incentives_earned.checked_sub(fees_paid) {
+                    Some(profit) => profit.to_string(),
+                    None => format!("-{}", fees_paid.0 - incentives_earned.0),
+                };
+
+                ChannelEconomics {
+                    chain_id,
+                    port_id,
+                    channel_id,
+                    denom,
+                    fees_paid,
+                    incentives_earned,
+                    net,
+                }
+            },
+        )
+        .collect()
+}
+
+/// Serializes an [`Amount`] as a decimal string.
+mod amount_string {
+    use ibc_relayer_types::applications::transfer::Amount;
+    use serde::Serializer;
+
+    pub fn serialize<S>(amount: &Amount, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_str(amount)
+    }
+}
+
+/// The most recently recorded transactions, per chain.
+#[derive(Debug, Default)]
+struct RecentTxs {
+    order: VecDeque<(ChainId, String)>,
+    txs: HashSet<(ChainId, String)>,
+}
+
+impl RecentTxs {
+    /// Remembers the given transaction, returning whether it was not recorded yet.
+    fn insert(&mut self, chain_id: &ChainId, tx_hash: &str) -> bool {
+        let key = (chain_id.clone(), tx_hash.to_string());
+
+        if !self.txs.insert(key.clone()) {
+            return false;
+        }
+
+        self.order.push_back(key);
+
+        if self.order.len() > RECENT_TXS_CAPACITY {
+            if let Some(oldest) = self.order.pop_front() {
+                self.txs.remove(&oldest);
+            }
+        }
+
+        true
+    }
+}
+
+/// The transactions submitted by Hermes to a chain without waiting for their commit,
+/// which are accounted for once they are found to be committed.
+#[derive(Debug, Default)]
+pub struct SubmittedTxs {
+    order: VecDeque<TxHash>,
+    txs: HashSet<TxHash>,
+}
+
+impl SubmittedTxs {
+    /// Remembers the given transaction, forgetting the oldest one beyond capacity.
+    pub fn insert(&mut self, tx_hash: TxHash) {
+        if !self.txs.insert(tx_hash) {
+            return;
+        }
+
+        self.order.push_back(tx_hash);
+
+        if self.order.len() > SUBMITTED_TXS_CAPACITY {
+            if let Some(oldest) = self.order.pop_front() {
+                self.txs.remove(&oldest);
+            }
+        }
+    }
+
+    pub fn contains(&self, tx_hash: &TxHash) -> bool {
+        self.txs.contains(tx_hash)
+    }
+
+    /// Forgets the given transaction, once it has been accounted for.
+    pub fn remove(&mut self, tx_hash: &TxHash) {
+        if self.txs.remove(tx_hash) {
+            self.order.retain(|hash| hash != tx_hash);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use sha2::{Digest, Sha256};
+    use test_log::test;
+
+    const RELAYER: &str = "cosmos1relayer";
+
+    fn event(kind: &str, attributes: &[(&str, &str)]) -> abci::Event {
+        abci::Event::new(
+            kind,
+            attributes
+                .iter()
+                .map(|(key, value)| abci::EventAttribute::from((*key, *value))),
+        )
+    }
+
+    fn tx_event(fee: &str) -> abci::Event {
+        event("tx", &[("fee", fee), ("fee_payer", RELAYER)])
+    }
+
+    fn packet_event(
+        kind: &str,
+        sequence: &str,
+        src_channel: &str,
+        dst_channel: &str,
+    ) -> abci::Event {
+        event(
+            kind,
+            &[
+                ("packet_data", "data"),
+                ("packet_timeout_height", "0-100"),
+                ("packet_timeout_timestamp", "0"),
+                ("packet_sequence", sequence),
+                ("packet_src_port", "transfer"),
+                ("packet_src_channel", src_channel),
+                ("packet_dst_port", "transfer"),
+                ("packet_dst_channel", dst_channel),
+                ("packet_channel_ordering", "ORDER_UNORDERED"),
+                ("packet_connection", "connection-0"),
+            ],
+        )
+    }
+
+    fn reward_event(receiver: &str, fee: &str) -> abci::Event {
+        event(
+            "distribute_fee",
+            &[
+                ("receiver", receiver),
+                ("fee", fee),
+                ("distribution_type", "reward"),
+            ],
+        )
+    }
+
+    fn coin(amount: u64) -> RawCoin {
+        RawCoin::new("stake".to_string(), amount)
+    }
+
+    fn channel(entry: &LedgerEntry) -> Option<String> {
+        entry.channel_id.as_ref().map(ToString::to_string)
+    }
+
+    #[test]
+    fn splits_fees_and_attributes_incentives() {
+        let events = vec![
+            tx_event("1001stake"),
+            event("update_client", &[("client_id", "07-tendermint-0")]),
+            // Acknowledgments of packets sent on channel-0 and channel-1
+            packet_event("acknowledge_packet", "1", "channel-0", "channel-5"),
+            reward_event(RELAYER, "50stake"),
+            reward_event("cosmos1other", "20stake"),
+            packet_event("acknowledge_packet", "2", "channel-0", "channel-5"),
+            reward_event(RELAYER, "50stake"),
+            packet_event("acknowledge_packet", "1", "channel-1", "channel-6"),
+            reward_event(RELAYER, "30stake"),
+        ];
+
+        let entries = tx_entries(&ChainId::from_string("ibc-0"), "ABCD", &events, 42);
+
+        let paid = entries
+            .iter()
+            .filter(|entry| entry.kind == EntryKind::FeePaid)
+            .map(|entry| (channel(entry), entry.coin.clone()))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            paid,
+            vec![
+                (Some("channel-0".to_string()), coin(668)),
+                (Some("channel-1".to_string()), coin(333)),
+            ]
+        );
+
+        let earned = entries
+            .iter()
+            .filter(|entry| entry.kind == EntryKind::IncentiveEarned)
+            .map(|entry| (channel(entry), entry.coin.clone()))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            earned,
+            vec![
+                (Some("channel-0".to_string()), coin(50)),
+                (Some("channel-0".to_string()), coin(50)),
+                (Some("channel-1".to_string()), coin(30)),
+            ]
+        );
+    }
+
+    #[test]
+    fn recv_packet_is_attributed_to_destination_channel() {
+        let mut write_ack = packet_event("write_acknowledgement", "1", "channel-0", "channel-5");
+        write_ack
+            .attributes
+            .push(abci::EventAttribute::from(("packet_ack", "ack")));
+
+        let events = vec![
+            tx_event("100stake"),
+            packet_event("recv_packet", "1", "channel-0", "channel-5"),
+            write_ack,
+        ];
+
+        let entries = tx_entries(&ChainId::from_string("ibc-1"), "ABCD", &events, 42);
+
+        assert_eq!(entries.len(), 1);
+        assert_eq!(channel(&entries[0]), Some("channel-5".to_string()));
+    }
+
+    #[test]
+    fn fees_without_packets_are_not_attributed() {
+        let events = vec![
+            tx_event("100stake"),
+            event("update_client", &[("client_id", "07-tendermint-0")]),
+        ];
+
+        let entries = tx_entries(&ChainId::from_string("ibc-0"), "ABCD", &events, 42);
+
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].kind, EntryKind::FeePaid);
+        assert_eq!(entries[0].channel(), None);
+        assert_eq!(entries[0].coin, coin(100));
+    }
+
+    #[test]
+    fn aggregates_entries_in_window() {
+        let chain_id = ChainId::from_string("ibc-0");
+
+        let events = vec![
+            tx_event("100stake"),
+            packet_event("acknowledge_packet", "1", "channel-0", "channel-5"),
+            reward_event(RELAYER, "30stake"),
+        ];
+
+        let mut entries = tx_entries(&chain_id, "OLD", &events, 10);
+        entries.extend(tx_entries(&chain_id, "NEW1", &events, 100));
+        entries.extend(tx_entries(&chain_id, "NEW2", &events, 200));
+
+        // Entries survive a round-trip through the ledger format
+        let encoded = entries
+            .iter()
+            .map(|entry| serde_json::to_string(entry).unwrap())
+            .collect::<Vec<_>>();
+        let decoded = encoded
+            .iter()
+            .map(|line| serde_json::from_str::<LedgerEntry>(line).unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(entries, decoded);
+
+        let report = aggregate(&decoded, Some(100), None);
+
+        assert_eq!(report.len(), 1);
+        assert_eq!(report[0].fees_paid, Amount::from(200u64));
+        assert_eq!(report[0].incentives_earned, Amount::from(60u64));
+        assert_eq!(report[0].net, "-140");
+
+        assert!(aggregate(&decoded, None, Some(&ChainId::from_string("ibc-1"))).is_empty());
+    }
+
+    #[test]
+    fn recent_txs_are_bounded() {
+        let chain_id = ChainId::from_string("ibc-0");
+        let mut recent_txs = RecentTxs::default();
+
+        assert!(recent_txs.insert(&chain_id, "0"));
+        assert!(!recent_txs.insert(&chain_id, "0"));
+        assert!(recent_txs.insert(&ChainId::from_string("ibc-1"), "0"));
+
+        for i in 1..=RECENT_TXS_CAPACITY {
+            recent_txs.insert(&chain_id, &i.to_string());
+        }
+
+        assert_eq!(recent_txs.txs.len(), RECENT_TXS_CAPACITY);
+        assert!(recent_txs.insert(&chain_id, "0"));
+    }
+
+    #[test]
+    fn submitted_txs_are_bounded() {
+        let tx_hash = |i: usize| TxHash::Sha256(Sha256::digest(i.to_be_bytes()).into());
+        let mut submitted_txs = SubmittedTxs::default();
+
+        submitted_txs.insert(tx_hash(0));
+        submitted_txs.insert(tx_hash(1));
+        assert!(submitted_txs.contains(&tx_hash(0)));
+
+        submitted_txs.remove(&tx_hash(0));
+        assert!(!submitted_txs.contains(&tx_hash(0)));
+        assert_eq!(submitted_txs.order.len(), 1);
+
+        for i in 2..=SUBMITTED_TXS_CAPACITY + 1 {
+            submitted_txs.insert(tx_hash(i));
+        }
+
+        assert_eq!(submitted_txs.txs.len(), SUBMITTED_TXS_CAPACITY);
+        assert!(!submitted_txs.contains(&tx_hash(1)));
+    }
+}
@@ -32,7 +32,7 @@ use ibc_relayer_types::{
         ics02_client::{client_type::ClientType, error as client_error},
         ics03_connection::error as connection_error,
         ics23_commitment::error as commitment_error,
-        ics24_host::identifier::{ChainId, ChannelId, ConnectionId},
+        ics24_host::identifier::{ChainId, ChannelId, ConnectionId, PortId},
     },
     proofs::ProofError,
     relayer::ics18_relayer::error as relayer_error,
@@ -40,6 +40,8 @@ use ibc_relayer_types::{
 
 use crate::chain::cosmos::version;
 use crate::chain::cosmos::BLOCK_MAX_BYTES_MAX_FRACTION;
+use crate::chain::mock::error as mock;
+use crate::chain::solomachine::error as solomachine;
 use crate::config::Error as ConfigError;
 use crate::event::monitor;
 use crate::keyring::{errors::Error as KeyringError, KeyType};
@@ -91,6 +93,14 @@ define_error! {
             [ monitor::Error ]
             |_| { "event monitor error" },
 
+        Mock
+            [ mock::Error ]
+            |_| { "mock chain error" },
+
+        SoloMachine
+            [ solomachine::Error ]
+            |_| { "solo machine error" },
+
         Grpc
             |_| { "gRPC error" },
 
@@ -198,6 +208,14 @@ define_error! {
         BadConnectionState
             |_| { "bad connection state" },
 
+        UpgradeNotFound
+            { port_id: PortId, channel_id: ChannelId }
+            |e| { format!("no upgrade found for channel {0}/{1}", e.port_id, e.channel_id) },
+
+        UpgradeErrorNotFound
+            { port_id: PortId, channel_id: ChannelId }
+            |e| { format!("no upgrade error receipt found for channel {0}/{1}", e.port_id, e.channel_id) },
+
         ConnOpen
             { connection_id: ConnectionId, reason: String }
             |e| {
@@ -354,6 +372,10 @@ define_error! {
             [ TraceError<DecodeError> ]
             |e| { format!("error decoding protocol buffer for {}", e.payload_type) },
 
+        InvalidBaseFee
+            { base_fee: String }
+            |e| { format!("invalid base fee returned by the fee market: '{}'", e.base_fee) },
+
         ProtobufEncode
             { payload_type: String }
             [ TraceError<EncodeError> ]
@@ -591,6 +613,10 @@ define_error! {
             { address: String }
             [ TendermintRpcError ]
             |e| { format!("invalid archive node address {}", e.address) },
+
+        TxJson
+            { reason: String }
+            |e| { format!("failed to convert transaction to or from JSON: {}", e.reason) },
     }
 }
 
@@ -599,6 +625,38 @@ impl Error {
         Error::channel_send()
     }
 
+    /// Whether the error is caused by the node in use being unreachable or failing to
+    /// respond, in which case the request can be retried on another node of the chain.
+    pub fn is_endpoint_error(&self) -> bool {
+        use tendermint_rpc::error::ErrorDetail as RpcErrorDetail;
+
+        match self.detail() {
+            ErrorDetail::Rpc(e) => matches!(
+                e.source,
+                RpcErrorDetail::Io(_)
+                    | RpcErrorDetail::Http(_)
+                    | RpcErrorDetail::Hyper(_)
+                    | RpcErrorDetail::Server(_)
+                    | RpcErrorDetail::Timeout(_)
+            ),
+            ErrorDetail::GrpcStatus(e) => matches!(
+                e.status.code(),
+                tonic::Code::Unavailable | tonic::Code::DeadlineExceeded
+            ),
+            ErrorDetail::GrpcTransport(_) | ErrorDetail::LightClientIo(_) => true,
+            _ => false,
+        }
+    }
+
+    /// Whether the error is caused by the queried channel end having no upgrade
+    /// in progress, or no upgrade error receipt.
+    pub fn is_upgrade_not_found_error(&self) -> bool {
+        matches!(
+            self.detail(),
+            ErrorDetail::UpgradeNotFound(_) | ErrorDetail::UpgradeErrorNotFound(_)
+        )
+    }
+
     pub fn is_trusted_state_outside_trusting_period_error(&self) -> bool {
         match self.detail() {
             ErrorDetail::LightClientVerification(e) => matches!(
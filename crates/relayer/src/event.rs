@@ -14,7 +14,10 @@ use ibc_relayer_types::{
     },
     core::ics04_channel::{
         error::Error as ChannelError,
-        events::{self as channel_events, Attributes as ChannelAttributes},
+        events::{
+            self as channel_events, Attributes as ChannelAttributes,
+            UpgradeAttributes as ChannelUpgradeAttributes,
+        },
         packet::Packet,
         timeout::TimeoutHeight,
     },
@@ -23,6 +26,7 @@ use ibc_relayer_types::{
 };
 use serde::Serialize;
 use tendermint::abci::Event as AbciEvent;
+use tendermint::Hash;
 
 use crate::light_client::decode_header;
 
@@ -34,21 +38,44 @@ pub mod rpc;
 pub struct IbcEventWithHeight {
     pub event: IbcEvent,
     pub height: Height,
+    /// Where the event was emitted at that height, when known
+    #[serde(skip)]
+    pub position: Option<EventPosition>,
 }
 
 impl IbcEventWithHeight {
     pub fn new(event: IbcEvent, height: Height) -> Self {
-        Self { event, height }
+        Self {
+            event,
+            height,
+            position: None,
+        }
     }
 
     pub fn with_height(self, height: Height) -> Self {
+        Self { height, ..self }
+    }
+
+    pub fn with_position(self, position: EventPosition) -> Self {
         Self {
-            event: self.event,
-            height,
+            position: Some(position),
+            ..self
         }
     }
 }
 
+/// The position of an event within a block, which tells apart
+/// events emitted at the same height with the same content.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct EventPosition {
+    /// The hash of the transaction which emitted the event,
+    /// or `None` for the events emitted outside of a transaction
+    pub tx_hash: Option<Hash>,
+    /// The index of the event among the events of that transaction,
+    /// or among the block events
+    pub index: usize,
+}
+
 impl Display for IbcEventWithHeight {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), FmtError> {
         write!(f, "{} at height {}", self.event, self.height)
@@ -109,6 +136,46 @@ pub fn ibc_event_try_from_abci_event(abci_event: &AbciEvent) -> Result<IbcEvent,
             channel_close_confirm_try_from_abci_event(abci_event)
                 .map_err(IbcEventError::channel)?,
         )),
+        Ok(IbcEventType::UpgradeInitChannel) => Ok(IbcEvent::UpgradeInitChannel(
+            channel_upgrade_extract_attributes_from_tx(abci_event)
+                .map_err(IbcEventError::channel)?
+                .into(),
+        )),
+        Ok(IbcEventType::UpgradeTryChannel) => Ok(IbcEvent::UpgradeTryChannel(
+            channel_upgrade_extract_attributes_from_tx(abci_event)
+                .map_err(IbcEventError::channel)?
+                .into(),
+        )),
+        Ok(IbcEventType::UpgradeAckChannel) => Ok(IbcEvent::UpgradeAckChannel(
+            channel_upgrade_extract_attributes_from_tx(abci_event)
+                .map_err(IbcEventError::channel)?
+                .into(),
+        )),
+        Ok(IbcEventType::UpgradeConfirmChannel) => Ok(IbcEvent::UpgradeConfirmChannel(
+            channel_upgrade_extract_attributes_from_tx(abci_event)
+                .map_err(IbcEventError::channel)?
+                .into(),
+        )),
+        Ok(IbcEventType::UpgradeOpenChannel) => Ok(IbcEvent::UpgradeOpenChannel(
+            channel_upgrade_extract_attributes_from_tx(abci_event)
+                .map_err(IbcEventError::channel)?
+                .into(),
+        )),
+        Ok(IbcEventType::UpgradeTimeoutChannel) => Ok(IbcEvent::UpgradeTimeoutChannel(
+            channel_upgrade_extract_attributes_from_tx(abci_event)
+                .map_err(IbcEventError::channel)?
+                .into(),
+        )),
+        Ok(IbcEventType::UpgradeCancelChannel) => Ok(IbcEvent::UpgradeCancelChannel(
+            channel_upgrade_extract_attributes_from_tx(abci_event)
+                .map_err(IbcEventError::channel)?
+                .into(),
+        )),
+        Ok(IbcEventType::UpgradeErrorChannel) => Ok(IbcEvent::UpgradeErrorChannel(
+            channel_upgrade_extract_attributes_from_tx(abci_event)
+                .map_err(IbcEventError::channel)?
+                .into(),
+        )),
         Ok(IbcEventType::SendPacket) => Ok(IbcEvent::SendPacket(
             send_packet_try_from_abci_event(abci_event).map_err(IbcEventError::channel)?,
         )),
@@ -397,6 +464,39 @@ fn channel_extract_attributes_from_tx(
     Ok(attr)
 }
 
+fn channel_upgrade_extract_attributes_from_tx(
+    event: &AbciEvent,
+) -> Result<ChannelUpgradeAttributes, ChannelError> {
+    let mut attr = ChannelUpgradeAttributes::default();
+
+    for tag in &event.attributes {
+        let key = tag.key.as_str();
+        let value = tag.value.as_str();
+        match key {
+            channel_events::PORT_ID_ATTRIBUTE_KEY => {
+                attr.port_id = value.parse().map_err(ChannelError::identifier)?
+            }
+            channel_events::CHANNEL_ID_ATTRIBUTE_KEY => {
+                attr.channel_id = value.parse().map_err(ChannelError::identifier)?;
+            }
+            channel_events::COUNTERPARTY_PORT_ID_ATTRIBUTE_KEY => {
+                attr.counterparty_port_id = value.parse().map_err(ChannelError::identifier)?;
+            }
+            channel_events::COUNTERPARTY_CHANNEL_ID_ATTRIBUTE_KEY => {
+                attr.counterparty_channel_id = value.parse().ok();
+            }
+            channel_events::UPGRADE_SEQUENCE_ATTRIBUTE_KEY => {
+                attr.upgrade_sequence = value
+                    .parse()
+                    .map_err(|e| ChannelError::invalid_upgrade_sequence(value.to_string(), e))?;
+            }
+            _ => {}
+        }
+    }
+
+    Ok(attr)
+}
+
 pub fn extract_packet_and_write_ack_from_tx(
     event: &AbciEvent,
 ) -> Result<(Packet, Vec<u8>), ChannelError> {
@@ -465,6 +565,7 @@ mod tests {
     use ibc_proto::protobuf::Protobuf;
     use ibc_relayer_types::clients::ics07_tendermint::header::test_util::get_dummy_ics07_header;
     use ibc_relayer_types::clients::ics07_tendermint::header::Header as TmHeader;
+    use ibc_relayer_types::clients::ics08_wasm::client_message::ClientMessage as WasmClientMessage;
     use ibc_relayer_types::core::ics02_client::header::downcast_header;
     use ibc_relayer_types::core::ics04_channel::packet::Sequence;
     use ibc_relayer_types::timestamp::Timestamp;
@@ -481,6 +582,19 @@ mod tests {
         assert_eq!(&header, decoded_tm_header);
     }
 
+    #[test]
+    fn extract_wasm_wrapped_header() {
+        let header = get_dummy_ics07_header();
+        let message = WasmClientMessage::wrap(header.clone().into());
+        let mut header_bytes = Vec::new();
+        Protobuf::<Any>::encode(&message, &mut header_bytes).unwrap();
+
+        let decoded_dyn_header = decode_header(&header_bytes).unwrap();
+        let decoded_tm_header: &TmHeader = downcast_header(decoded_dyn_header.as_ref()).unwrap();
+
+        assert_eq!(&header, decoded_tm_header);
+    }
+
     #[test]
     fn connection_event_to_abci_event() {
         let attributes = ConnectionAttributes {
@@ -564,6 +678,36 @@ mod tests {
         }
     }
 
+    #[test]
+    fn channel_upgrade_event_to_abci_event() {
+        let attributes = ChannelUpgradeAttributes {
+            port_id: "test_port".parse().unwrap(),
+            channel_id: "channel-0".parse().unwrap(),
+            counterparty_port_id: "counterparty_test_port".parse().unwrap(),
+            counterparty_channel_id: Some("channel-1".parse().unwrap()),
+            upgrade_sequence: 3,
+        };
+
+        let events: Vec<IbcEvent> = vec![
+            channel_events::UpgradeInit::from(attributes.clone()).into(),
+            channel_events::UpgradeTry::from(attributes.clone()).into(),
+            channel_events::UpgradeAck::from(attributes.clone()).into(),
+            channel_events::UpgradeConfirm::from(attributes.clone()).into(),
+            channel_events::UpgradeOpen::from(attributes.clone()).into(),
+            channel_events::UpgradeTimeout::from(attributes.clone()).into(),
+            channel_events::UpgradeCancel::from(attributes.clone()).into(),
+            channel_events::UpgradeError::from(attributes.clone()).into(),
+        ];
+
+        for event in events {
+            let abci_event = AbciEvent::try_from(event.clone()).unwrap();
+            let ibc_event = ibc_event_try_from_abci_event(&abci_event).unwrap();
+
+            assert_eq!(ibc_event.event_type(), event.event_type());
+            assert_eq!(ibc_event.channel_upgrade_attributes(), Some(&attributes));
+        }
+    }
+
     #[test]
     fn packet_event_to_abci_event() {
         let packet = Packet {
@@ -10,7 +10,7 @@ use futures::{
 };
 use tokio::task::JoinHandle;
 use tokio::{runtime::Runtime as TokioRuntime, sync::mpsc};
-use tracing::{debug, error, info, instrument, trace};
+use tracing::{debug, error, info, instrument, trace, warn};
 
 use tendermint_rpc::{
     client::CompatMode, event::Event as RpcEvent, query::Query, Error as RpcError,
@@ -33,6 +33,9 @@ use crate::{
 mod error;
 pub use error::*;
 
+pub mod merged;
+pub mod pull;
+
 use super::{bus::EventBus, IbcEventWithHeight};
 
 pub type Result<T> = core::result::Result<T, Error>;
@@ -120,6 +123,8 @@ pub struct EventMonitor {
     rx_cmd: channel::Receiver<MonitorCmd>,
     /// Node Address
     ws_url: WebSocketClientUrl,
+    /// Addresses of other nodes of the chain to fail over to, in turn
+    backup_urls: Vec<WebSocketClientUrl>,
     /// RPC compatibility mode
     rpc_compat: CompatMode,
     /// Queries
@@ -210,6 +215,7 @@ impl EventMonitor {
             tx_err,
             rx_cmd,
             ws_url,
+            backup_urls: vec![],
             rpc_compat,
             subscriptions: Box::new(futures::stream::empty()),
         };
@@ -217,6 +223,30 @@ impl EventMonitor {
         Ok((monitor, TxMonitorCmd(tx_cmd)))
     }
 
+    /// Set the WebSocket endpoints of other nodes of the chain, which the monitor
+    /// fails over to, in turn, when it cannot reconnect to the current endpoint.
+    pub fn set_backup_urls(&mut self, backup_urls: Vec<WebSocketClientUrl>) {
+        self.backup_urls = backup_urls;
+    }
+
+    /// Switch to the next backup endpoint, if any, moving the
+    /// current endpoint to the end of the backup endpoints.
+    fn fail_over(&mut self) {
+        if self.backup_urls.is_empty() {
+            return;
+        }
+
+        let next_url = self.backup_urls.remove(0);
+        let previous_url = core::mem::replace(&mut self.ws_url, next_url);
+
+        warn!(
+            "failing over from WebSocket endpoint {} to {}",
+            previous_url, self.ws_url
+        );
+
+        self.backup_urls.push(previous_url);
+    }
+
     /// The list of [`Query`] that this event monitor is subscribing for.
     pub fn queries(&self) -> &[Query] {
         &self.event_queries
@@ -311,6 +341,7 @@ impl EventMonitor {
             // Try to reconnect
             if let Err(e) = self.try_reconnect() {
                 trace!("error when reconnecting: {}", e);
+                self.fail_over();
                 return RetryResult::Retry(());
             }
 
@@ -0,0 +1,499 @@
+use alloc::collections::BTreeMap;
+use alloc::sync::Arc;
+use core::time::Duration;
+use std::collections::HashSet;
+use std::time::Instant;
+
+use crossbeam_channel::{self as channel, Select};
+use tracing::{debug, error, instrument, trace, warn};
+
+use ibc_relayer_types::core::{ics02_client::height::Height, ics24_host::identifier::ChainId};
+
+use crate::{
+    chain::handle::Subscription,
+    event::{bus::EventBus, EventPosition, IbcEventWithHeight},
+};
+
+use super::{EventBatch, MonitorCmd, Result, TxMonitorCmd};
+
+/// Number of published heights for which the identity of the published
+/// events is retained, in order to deduplicate events received late.
+const PUBLISHED_HEIGHTS_RETAINED: usize = 100;
+
+/// How long to wait for a batch or command when no batch is pending.
+const IDLE_TIMEOUT: Duration = Duration::from_secs(1);
+
+/// Merge the event batches of several event monitors, each subscribed to
+/// a different node of the same chain, into a single stream of batches.
+///
+/// The batches received from the nodes for the same height are merged into
+/// one [`EventBatch`], deduplicated by event position, which is published
+/// once `merge_window` has elapsed since the first batch for that height
+/// was received. Events received for a height which has already been
+/// published, eg. from a node which reconnected, are published in an
+/// additional batch, unless they were published already.
+///
+/// Errors are only propagated to the subscribers when all the nodes have
+/// failed, since the events are still collected as long as one node is healthy.
+pub struct MergedEventMonitor {
+    chain_id: ChainId,
+    /// The event monitors of the nodes
+    sources: Vec<Source>,
+    /// Merges the batches received from the nodes
+    merger: BatchMerger,
+    /// Event bus for broadcasting the merged batches
+    event_bus: EventBus<Arc<Result<EventBatch>>>,
+    /// Channel where to receive commands
+    rx_cmd: channel::Receiver<MonitorCmd>,
+}
+
+struct Source {
+    monitor_cmd: TxMonitorCmd,
+    subscription: Subscription,
+    healthy: bool,
+    closed: bool,
+}
+
+enum Received {
+    Timeout,
+    Cmd(core::result::Result<MonitorCmd, channel::RecvError>),
+    Batch(
+        usize,
+        core::result::Result<Arc<Result<EventBatch>>, channel::RecvError>,
+    ),
+}
+
+impl MergedEventMonitor {
+    /// Create a monitor merging the event batches of the given event monitors,
+    /// which must already be running.
+    pub fn new(
+        chain_id: ChainId,
+        monitors: Vec<TxMonitorCmd>,
+        merge_window: Duration,
+    ) -> Result<(Self, TxMonitorCmd)> {
+        let sources = monitors
+            .into_iter()
+            .map(|monitor_cmd| {
+                let subscription = monitor_cmd.subscribe()?;
+
+                Ok(Source {
+                    monitor_cmd,
+                    subscription,
+                    healthy: true,
+                    closed: false,
+                })
+            })
+            .collect::<Result<_>>()?;
+
+        let (tx_cmd, rx_cmd) = channel::unbounded();
+
+        let monitor = Self {
+            chain_id,
+            sources,
+            merger: BatchMerger::new(merge_window),
+            event_bus: EventBus::new(),
+            rx_cmd,
+        };
+
+        Ok((monitor, TxMonitorCmd(tx_cmd)))
+    }
+
+    /// Event monitor loop
+    #[instrument(
+        name = "merged_event_monitor",
+        level = "error",
+        skip_all,
+        fields(chain = %self.chain_id)
+    )]
+    pub fn run(mut self) {
+        debug!(
+            "starting event monitor merging the events of {} nodes",
+            self.sources.len()
+        );
+
+        loop {
+            match self.receive() {
+                Received::Timeout => {}
+                Received::Cmd(Ok(MonitorCmd::Shutdown)) | Received::Cmd(Err(_)) => break,
+                Received::Cmd(Ok(MonitorCmd::Subscribe(tx))) => {
+                    if let Err(e) = tx.send(self.event_bus.subscribe()) {
+                        error!("failed to send back subscription: {e}");
+                    }
+                }
+                Received::Batch(index, Ok(result)) => self.process_result(index, result),
+                Received::Batch(index, Err(_)) => {
+                    warn!("event monitor of node #{index} has shut down");
+
+                    self.sources[index].closed = true;
+                    self.sources[index].healthy = false;
+
+                    if self.sources.iter().all(|source| source.closed) {
+                        error!("the event monitors of all nodes have shut down");
+                        break;
+                    }
+                }
+            }
+
+            for batch in self.merger.flush(Instant::now()) {
+                self.publish(batch);
+            }
+        }
+
+        debug!("merged event monitor is shutting down");
+
+        for source in &self.sources {
+            let _ = source.monitor_cmd.shutdown();
+        }
+
+        trace!("merged event monitor has successfully shut down");
+    }
+
+    /// Wait for a command, a batch from one of the nodes, or
+    /// for the merge window of the next pending batch to elapse.
+    fn receive(&self) -> Received {
+        let mut select = Select::new();
+
+        let cmd_index = select.recv(&self.rx_cmd);
+
+        let source_indices = self
+            .sources
+            .iter()
+            .enumerate()
+            .filter(|(_, source)| !source.closed)
+            .map(|(index, source)| (select.recv(&source.subscription), index))
+            .collect::<Vec<_>>();
+
+        let timeout = self
+            .merger
+            .next_deadline()
+            .map_or(IDLE_TIMEOUT, |deadline| {
+                deadline.saturating_duration_since(Instant::now())
+            });
+
+        let Ok(oper) = select.select_timeout(timeout) else {
+            return Received::Timeout;
+        };
+
+        if oper.index() == cmd_index {
+            return Received::Cmd(oper.recv(&self.rx_cmd));
+        }
+
+        let index = source_indices
+            .iter()
+            .find(|(oper_index, _)| *oper_index == oper.index())
+            .map(|(_, index)| *index)
+            .expect("the selected operation is a subscription to a node");
+
+        Received::Batch(index, oper.recv(&self.sources[index].subscription))
+    }
+
+    fn process_result(&mut self, index: usize, result: Arc<Result<EventBatch>>) {
+        match result.as_ref() {
+            Ok(batch) => {
+                self.sources[index].healthy = true;
+
+                if let Some(late_batch) = self.merger.insert(batch.clone(), Instant::now()) {
+                    debug!(
+                        height = %late_batch.height,
+                        len = %late_batch.events.len(),
+                        "received events from node #{index} for a height already published"
+                    );
+
+                    self.publish(late_batch);
+                }
+            }
+            Err(e) => {
+                warn!("event monitor of node #{index} failed: {e}");
+
+                self.sources[index].healthy = false;
+
+                // Only let the subscribers know about the failure, so that
+                // they can catch up on the missed events, if no node is healthy
+                if self.sources.iter().all(|source| !source.healthy) {
+                    self.event_bus.broadcast(result);
+                }
+            }
+        }
+    }
+
+    fn publish(&mut self, batch: EventBatch) {
+        debug!(height = %batch.height, len = %batch.events.len(), "emitting merged batch");
+
+        self.event_bus.broadcast(Arc::new(Ok(batch)));
+    }
+}
+
+/// A batch received for a height which has not been published yet.
+struct PendingBatch {
+    /// When the first batch for that height was received
+    received_at: Instant,
+    batch: EventBatch,
+    keys: HashSet<EventKey>,
+}
+
+/// Merges the event batches received from several nodes into one
+/// batch per height, deduplicated by event position.
+pub struct BatchMerger {
+    merge_window: Duration,
+    pending: BTreeMap<Height, PendingBatch>,
+    /// The identity of the events published for the most recent heights
+    published: BTreeMap<Height, HashSet<EventKey>>,
+}
+
+impl BatchMerger {
+    pub fn new(merge_window: Duration) -> Self {
+        Self {
+            merge_window,
+            pending: BTreeMap::new(),
+            published: BTreeMap::new(),
+        }
+    }
+
+    /// Merge the given batch with the batches received for the same height.
+    ///
+    /// If that height has already been published, returns the events of the
+    /// batch which have not been published yet, if any, as a separate batch.
+    pub fn insert(&mut self, mut batch: EventBatch, now: Instant) -> Option<EventBatch> {
+        let last_published = self.published.keys().next_back().copied();
+
+        if last_published.map_or(false, |last| batch.height <= last) {
+            // Events received for a height older than the retained ones are dropped,
+            // as they were most likely published already
+            let oldest_retained = self.published.keys().next().copied();
+
+            if oldest_retained.map_or(false, |oldest| batch.height < oldest) {
+                return None;
+            }
+
+            let keys = self.published.entry(batch.height).or_default();
+
+            batch.events.retain(|event| keys.insert(event_key(event)));
+
+            return (!batch.events.is_empty()).then_some(batch);
+        }
+
+        match self.pending.get_mut(&batch.height) {
+            Some(pending) => {
+                let keys = &mut pending.keys;
+
+                pending.batch.events.extend(
+                    batch
+                        .events
+                        .into_iter()
+                        .filter(|event| keys.insert(event_key(event))),
+                );
+            }
+            None => {
+                let mut keys = HashSet::new();
+                batch.events.retain(|event| keys.insert(event_key(event)));
+
+                self.pending.insert(
+                    batch.height,
+                    PendingBatch {
+                        received_at: now,
+                        batch,
+                        keys,
+                    },
+                );
+            }
+        }
+
+        None
+    }
+
+    /// When the merge window of the lowest pending height elapses, if any.
+    pub fn next_deadline(&self) -> Option<Instant> {
+        self.pending
+            .values()
+            .next()
+            .map(|pending| pending.received_at + self.merge_window)
+    }
+
+    /// Return the merged batches whose merge window has elapsed, by increasing height.
+    pub fn flush(&mut self, now: Instant) -> Vec<EventBatch> {
+        let mut batches = vec![];
+
+        while let Some((&height, pending)) = self.pending.iter().next() {
+            if pending.received_at + self.merge_window > now {
+                break;
+            }
+
+            if let Some(pending) = self.pending.remove(&height) {
+                self.published.insert(height, pending.keys);
+                batches.push(pending.batch);
+            }
+        }
+
+        while self.published.len() > PUBLISHED_HEIGHTS_RETAINED {
+            if let Some(&height) = self.published.keys().next() {
+                self.published.remove(&height);
+            }
+        }
+
+        batches
+    }
+}
+
+/// The identity of an event, as used to deduplicate the events received from several nodes.
+#[derive(PartialEq, Eq, Hash)]
+enum EventKey {
+    /// The transaction hash and index of the event, which tell apart identical
+    /// events emitted by distinct transactions or messages at the same height
+    Position(EventPosition),
+    /// The content of the event, for the events whose position is unknown
+    Content(String),
+}
+
+fn event_key(event: &IbcEventWithHeight) -> EventKey {
+    match event.position {
+        Some(position) => EventKey::Position(position),
+        None => EventKey::Content(format!("{:?}", event.event)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::time::Duration;
+    use std::time::Instant;
+
+    use ibc_relayer_types::core::ics02_client::events::NewBlock;
+    use ibc_relayer_types::core::ics02_client::height::Height;
+    use ibc_relayer_types::core::ics24_host::identifier::ChainId;
+    use ibc_relayer_types::events::IbcEvent;
+    use tendermint::Hash;
+    use test_log::test;
+
+    use super::BatchMerger;
+    use crate::chain::tracking::TrackingId;
+    use crate::event::monitor::EventBatch;
+    use crate::event::{EventPosition, IbcEventWithHeight};
+
+    const WINDOW: Duration = Duration::from_millis(500);
+
+    /// A batch with a block event followed by the given events,
+    /// each emitted by the transaction whose hash starts with its first byte.
+    fn batch(height: u64, events: &[&str]) -> EventBatch {
+        let height = Height::new(0, height).unwrap();
+
+        let block_event = IbcEventWithHeight::new(NewBlock::new(height).into(), height)
+            .with_position(EventPosition {
+                tx_hash: None,
+                index: 0,
+            });
+
+        let events = core::iter::once(block_event)
+            .chain(events.iter().map(|e| {
+                IbcEventWithHeight::new(IbcEvent::ChainError(e.to_string()), height).with_position(
+                    EventPosition {
+                        tx_hash: Some(tx_hash(e.as_bytes()[0])),
+                        index: 0,
+                    },
+                )
+            }))
+            .collect();
+
+        EventBatch {
+            chain_id: ChainId::from_string("chain-0"),
+            tracking_id: TrackingId::new_uuid(),
+            height,
+            events,
+        }
+    }
+
+    fn tx_hash(byte: u8) -> Hash {
+        Hash::Sha256([byte; 32])
+    }
+
+    fn events(batch: &EventBatch) -> Vec<String> {
+        batch
+            .events
+            .iter()
+            .map(|event| event.event.to_string())
+            .collect()
+    }
+
+    #[test]
+    fn merge_batches_per_height() {
+        let mut merger = BatchMerger::new(WINDOW);
+        let start = Instant::now();
+
+        assert!(merger.insert(batch(10, &["a", "b"]), start).is_none());
+        assert!(merger.insert(batch(11, &["c"]), start).is_none());
+        assert!(merger.insert(batch(10, &["b", "d"]), start).is_none());
+
+        // Nothing is published before the merge window elapses
+        assert!(merger.flush(start + WINDOW / 2).is_empty());
+        assert_eq!(merger.next_deadline(), Some(start + WINDOW));
+
+        let batches = merger.flush(start + WINDOW);
+        assert_eq!(batches.len(), 2);
+        assert_eq!(
+            events(&batches[0]),
+            vec![
+                "NewBlock(0-10)",
+                "ChainError(a)",
+                "ChainError(b)",
+                "ChainError(d)"
+            ]
+        );
+        assert_eq!(events(&batches[1]), vec!["NewBlock(0-11)", "ChainError(c)"]);
+        assert_eq!(merger.next_deadline(), None);
+    }
+
+    #[test]
+    fn publish_late_events_once() {
+        let mut merger = BatchMerger::new(WINDOW);
+        let start = Instant::now();
+
+        merger.insert(batch(10, &["a"]), start);
+        assert_eq!(merger.flush(start + WINDOW).len(), 1);
+
+        // A node which lagged behind delivers the same events
+        assert!(merger.insert(batch(10, &["a"]), start + WINDOW).is_none());
+
+        // A node delivers an event missed by the other nodes
+        let late = merger
+            .insert(batch(10, &["a", "e"]), start + WINDOW)
+            .unwrap();
+        assert_eq!(events(&late), vec!["ChainError(e)"]);
+
+        assert!(merger.insert(batch(10, &["e"]), start + WINDOW).is_none());
+    }
+
+    #[test]
+    fn keep_identical_events_emitted_at_distinct_positions() {
+        let mut merger = BatchMerger::new(WINDOW);
+        let start = Instant::now();
+
+        let mut from_node = batch(10, &["a"]);
+        let mut event = from_node.events[1].clone();
+
+        // The same event emitted by another message of the same transaction
+        event.position = event.position.map(|position| EventPosition {
+            index: 1,
+            ..position
+        });
+        from_node.events.push(event.clone());
+
+        // The same event emitted by another transaction
+        event.position = Some(EventPosition {
+            tx_hash: Some(tx_hash(b'z')),
+            index: 0,
+        });
+        from_node.events.push(event);
+
+        merger.insert(from_node.clone(), start);
+        merger.insert(from_node, start);
+
+        let batches = merger.flush(start + WINDOW);
+        assert_eq!(
+            events(&batches[0]),
+            vec![
+                "NewBlock(0-10)",
+                "ChainError(a)",
+                "ChainError(a)",
+                "ChainError(a)"
+            ]
+        );
+    }
+}
@@ -0,0 +1,561 @@
+use alloc::sync::Arc;
+use core::time::Duration;
+
+use crossbeam_channel::{self as channel, RecvTimeoutError};
+use tokio::runtime::Runtime as TokioRuntime;
+use tracing::{debug, error, instrument, trace, warn};
+
+use tendermint::abci::Event as AbciEvent;
+use tendermint::block::Height as BlockHeight;
+use tendermint_rpc::{Client, HttpClient};
+
+use ibc_relayer_types::{
+    applications::ics29_fee::events::DistributionType,
+    core::ics02_client::{events::NewBlock, height::Height},
+    core::ics24_host::identifier::ChainId,
+    events::IbcEvent,
+};
+
+use crate::{
+    chain::tracking::TrackingId,
+    event::{bus::EventBus, ibc_event_try_from_abci_event, IbcEventWithHeight},
+    telemetry,
+};
+
+use super::{Error, EventBatch, MonitorCmd, Result, TxMonitorCmd};
+
+/// Periodically query a Tendermint node for the results of the blocks
+/// committed since the last poll, and broadcast the IBC events found
+/// in these blocks as [`EventBatch`]es, one per block.
+///
+/// This is an alternative to the WebSocket-based [`EventMonitor`](super::EventMonitor)
+/// for nodes which do not expose a WebSocket endpoint, or which limit the
+/// number of subscriptions or drop the WebSocket connections.
+///
+/// Because it keeps track of the last block it has processed, this monitor does not
+/// miss any event when the node is temporarily unreachable: it catches up on the
+/// blocks committed in the meantime once the node is reachable again.
+pub struct PullEventMonitor {
+    chain_id: ChainId,
+    /// RPC client to poll the node with
+    rpc_client: HttpClient,
+    /// RPC clients of other nodes of the chain to fail over to, in turn
+    backup_rpc_clients: Vec<HttpClient>,
+    /// Delay between two polls
+    poll_interval: Duration,
+    /// Event bus for broadcasting events
+    event_bus: EventBus<Arc<Result<EventBatch>>>,
+    /// Channel where to receive commands
+    rx_cmd: channel::Receiver<MonitorCmd>,
+    /// Height of the last block whose events have been broadcast
+    last_fetched_height: Option<BlockHeight>,
+    /// Tokio runtime
+    rt: Arc<TokioRuntime>,
+}
+
+impl PullEventMonitor {
+    /// Create a pull-based event monitor which polls the node
+    /// behind the given RPC client every `poll_interval`.
+    pub fn new(
+        chain_id: ChainId,
+        rpc_client: HttpClient,
+        poll_interval: Duration,
+        rt: Arc<TokioRuntime>,
+    ) -> Result<(Self, TxMonitorCmd)> {
+        let event_bus = EventBus::new();
+        let (tx_cmd, rx_cmd) = channel::unbounded();
+
+        let monitor = Self {
+            chain_id,
+            rpc_client,
+            backup_rpc_clients: vec![],
+            poll_interval,
+            event_bus,
+            rx_cmd,
+            last_fetched_height: None,
+            rt,
+        };
+
+        Ok((monitor, TxMonitorCmd(tx_cmd)))
+    }
+
+    /// Set the RPC clients of other nodes of the chain, which the monitor
+    /// fails over to, in turn, when polling the current node fails.
+    pub fn set_backup_rpc_clients(&mut self, backup_rpc_clients: Vec<HttpClient>) {
+        self.backup_rpc_clients = backup_rpc_clients;
+    }
+
+    /// Switch to the next backup node, if any, moving the
+    /// current node to the end of the backup nodes.
+    fn fail_over(&mut self) {
+        if self.backup_rpc_clients.is_empty() {
+            return;
+        }
+
+        let next_rpc_client = self.backup_rpc_clients.remove(0);
+        let previous_rpc_client = core::mem::replace(&mut self.rpc_client, next_rpc_client);
+
+        warn!("failing over to the next node to poll for events");
+
+        self.backup_rpc_clients.push(previous_rpc_client);
+    }
+
+    /// Event monitor loop
+    #[instrument(
+        name = "pull_event_monitor",
+        level = "error",
+        skip_all,
+        fields(chain = %self.chain_id)
+    )]
+    pub fn run(mut self) {
+        debug!("starting pull-based event monitor");
+
+        loop {
+            match self.rx_cmd.recv_timeout(self.poll_interval) {
+                Ok(MonitorCmd::Shutdown) | Err(RecvTimeoutError::Disconnected) => break,
+                Ok(MonitorCmd::Subscribe(tx)) => {
+                    if let Err(e) = tx.send(self.event_bus.subscribe()) {
+                        error!("failed to send back subscription: {e}");
+                    }
+                }
+                Err(RecvTimeoutError::Timeout) => {
+                    if let Err(e) = self.poll() {
+                        error!("failed to collect events, will retry at next poll: {e}");
+                        self.fail_over();
+                    }
+                }
+            }
+        }
+
+        debug!("pull-based event monitor has successfully shut down");
+    }
+
+    /// Broadcast the events of all the blocks committed since the last poll.
+    ///
+    /// On the very first poll, only the events of the latest block are broadcast.
+    fn poll(&mut self) -> Result<()> {
+        let latest_height = self.query_latest_height()?;
+
+        let start_height = match self.last_fetched_height {
+            Some(last_fetched_height) => last_fetched_height.increment(),
+            None => latest_height,
+        };
+
+        if start_height > latest_height {
+            trace!("no new block since height {latest_height}");
+            return Ok(());
+        }
+
+        let mut height = start_height;
+
+        while height <= latest_height {
+            let batch = self.fetch_batch(height)?;
+
+            debug!(
+                height = %batch.height,
+                len = %batch.events.len(),
+                "emitting batch"
+            );
+
+            self.event_bus.broadcast(Arc::new(Ok(batch)));
+            self.last_fetched_height = Some(height);
+
+            height = height.increment();
+        }
+
+        Ok(())
+    }
+
+    fn query_latest_height(&self) -> Result<BlockHeight> {
+        let abci_info = self
+            .rt
+            .block_on(self.rpc_client.abci_info())
+            .map_err(Error::rpc)?;
+
+        Ok(abci_info.last_block_height)
+    }
+
+    /// Build an [`EventBatch`] out of the IBC events found in the results of the block at
+    /// the given height, starting with a `NewBlock` event, followed by the events emitted
+    /// in `BeginBlock`, by each transaction, and in `EndBlock`.
+    fn fetch_batch(&self, block_height: BlockHeight) -> Result<EventBatch> {
+        let height = Height::new(self.chain_id.version(), block_height.value()).map_err(|e| {
+            Error::collect_events_failed(format!("invalid height {block_height}: {e}"))
+        })?;
+
+        let block_results = self
+            .rt
+            .block_on(self.rpc_client.block_results(block_height))
+            .map_err(Error::rpc)?;
+
+        let tx_events = block_results
+            .txs_results
+            .unwrap_or_default()
+            .into_iter()
+            .flat_map(|tx_result| tx_result.events);
+
+        let abci_events = block_results
+            .begin_block_events
+            .unwrap_or_default()
+            .into_iter()
+            .chain(tx_events)
+            .chain(block_results.end_block_events.unwrap_or_default());
+
+        let mut events = vec![IbcEventWithHeight::new(
+            NewBlock::new(height).into(),
+            height,
+        )];
+        events.extend(collect_events(&self.chain_id, height, abci_events));
+
+        Ok(EventBatch {
+            chain_id: self.chain_id.clone(),
+            tracking_id: TrackingId::new_uuid(),
+            height,
+            events,
+        })
+    }
+}
+
+/// Extract the IBC events from the given ABCI events.
+///
+/// As with the WebSocket-based monitor, fee distribution events are
+/// only recorded in the telemetry and are not part of the batch.
+fn collect_events(
+    _chain_id: &ChainId,
+    height: Height,
+    abci_events: impl Iterator<Item = AbciEvent>,
+) -> Vec<IbcEventWithHeight> {
+    abci_events
+        .filter_map(|abci_event| ibc_event_try_from_abci_event(&abci_event).ok())
+        .filter_map(|ibc_event| match ibc_event {
+            IbcEvent::DistributeFeePacket(dist) => {
+                // Only record rewarded fees
+                if let DistributionType::Reward = dist.distribution_type {
+                    telemetry!(fees_amount, _chain_id, &dist.receiver, dist.fee);
+                }
+
+                None
+            }
+            ibc_event => Some(IbcEventWithHeight::new(ibc_event, height)),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::collections::HashSet;
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::net::{TcpListener, TcpStream};
+    use std::sync::Mutex;
+    use std::thread;
+    use std::time::Instant;
+
+    use serde_json::{json, Value};
+
+    use crate::chain::handle::Subscription;
+
+    const POLL_INTERVAL: Duration = Duration::from_millis(200);
+    const TIMEOUT: Duration = Duration::from_secs(10);
+
+    /// State of a fake Tendermint node, answering the
+    /// `abci_info` and `block_results` JSON-RPC requests.
+    #[derive(Default)]
+    struct Node {
+        latest_height: u64,
+        /// Heights at which the next `block_results` request fails
+        failing_heights: HashSet<u64>,
+        /// Heights of the `block_results` requests received so far
+        queried_heights: Vec<u64>,
+    }
+
+    impl Node {
+        fn respond(&mut self, request: &Value) -> Value {
+            let id = request["id"].clone();
+
+            let result = match request["method"].as_str() {
+                Some("abci_info") => json!({
+                    "response": {
+                        "app_version": "1",
+                        "data": "",
+                        "last_block_app_hash": "",
+                        "last_block_height": self.latest_height.to_string(),
+                        "version": "1.0.0",
+                    }
+                }),
+                Some("block_results") => {
+                    let height: u64 = request["params"]["height"]
+                        .as_str()
+                        .unwrap()
+                        .parse()
+                        .unwrap();
+
+                    self.queried_heights.push(height);
+
+                    if self.failing_heights.remove(&height) {
+                        return json!({
+                            "jsonrpc": "2.0",
+                            "id": id,
+                            "error": { "code": -32603, "message": "Internal error", "data": "" },
+                        });
+                    }
+
+                    json!({
+                        "height": height.to_string(),
+                        "txs_results": [{
+                            "code": 0,
+                            "data": null,
+                            "log": "",
+                            "info": "",
+                            "gas_wanted": "0",
+                            "gas_used": "0",
+                            "events": [
+                                create_client_event(height),
+                                // Not an IBC event
+                                { "type": "transfer", "attributes": [] },
+                            ],
+                            "codespace": "",
+                        }],
+                        "begin_block_events": null,
+                        "end_block_events": null,
+                        "validator_updates": null,
+                        "consensus_param_updates": null,
+                    })
+                }
+                method => panic!("unexpected request: {method:?}"),
+            };
+
+            json!({ "jsonrpc": "2.0", "id": id, "result": result })
+        }
+    }
+
+    /// A `create_client` event, whose client identifier is derived from the height.
+    fn create_client_event(height: u64) -> Value {
+        json!({
+            "type": "create_client",
+            "attributes": [
+                { "key": "client_id", "value": format!("07-tendermint-{height}"), "index": true },
+                { "key": "client_type", "value": "07-tendermint", "index": true },
+                { "key": "consensus_height", "value": "0-1", "index": true },
+            ]
+        })
+    }
+
+    /// Serve the JSON-RPC requests of the given node over HTTP, and return its address.
+    fn spawn_node(node: Arc<Mutex<Node>>) -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = listener.local_addr().unwrap();
+
+        thread::spawn(move || {
+            for stream in listener.incoming().flatten() {
+                let node = node.clone();
+                thread::spawn(move || serve(stream, &node));
+            }
+        });
+
+        format!("http://{address}")
+    }
+
+    fn serve(mut stream: TcpStream, node: &Mutex<Node>) {
+        let mut reader = BufReader::new(stream.try_clone().unwrap());
+
+        loop {
+            let mut content_length = 0;
+
+            loop {
+                let mut line = String::new();
+                if reader.read_line(&mut line).unwrap_or(0) == 0 {
+                    return;
+                }
+
+                let line = line.trim_end().to_ascii_lowercase();
+                if line.is_empty() {
+                    break;
+                }
+
+                if let Some(value) = line.strip_prefix("content-length:") {
+                    content_length = value.trim().parse().unwrap();
+                }
+            }
+
+            let mut body = vec![0; content_length];
+            reader.read_exact(&mut body).unwrap();
+
+            let request = serde_json::from_slice(&body).unwrap();
+            let response = node.lock().unwrap().respond(&request).to_string();
+
+            let written = write!(
+                stream,
+                "HTTP/1.1 200 OK\r\nContent-Length: {}\r\n\r\n{response}",
+                response.len()
+            );
+
+            if written.is_err() {
+                return;
+            }
+        }
+    }
+
+    fn monitor(node: &Arc<Mutex<Node>>) -> (PullEventMonitor, TxMonitorCmd) {
+        let rpc_client = HttpClient::new(spawn_node(node.clone()).as_str()).unwrap();
+        let rt = Arc::new(TokioRuntime::new().unwrap());
+
+        PullEventMonitor::new(ChainId::from_string("ibc-0"), rpc_client, POLL_INTERVAL, rt).unwrap()
+    }
+
+    fn next_batch(subscription: &Subscription) -> EventBatch {
+        match subscription.recv_timeout(TIMEOUT).unwrap().as_ref() {
+            Ok(batch) => batch.clone(),
+            Err(e) => panic!("unexpected error: {e}"),
+        }
+    }
+
+    fn batch_heights(subscription: &Subscription) -> Vec<u64> {
+        subscription
+            .try_iter()
+            .map(|batch| batch.as_ref().as_ref().unwrap().height.revision_height())
+            .collect()
+    }
+
+    fn set_latest_height(node: &Mutex<Node>, height: u64) {
+        node.lock().unwrap().latest_height = height;
+    }
+
+    #[test]
+    fn one_batch_per_height_starting_with_new_block() {
+        let node = Arc::new(Mutex::new(Node {
+            latest_height: 5,
+            ..Node::default()
+        }));
+
+        let (mut monitor, _) = monitor(&node);
+        let subscription = monitor.event_bus.subscribe();
+
+        monitor.poll().unwrap();
+
+        let batch = next_batch(&subscription);
+        assert_eq!(batch.height, Height::new(0, 5).unwrap());
+        assert_eq!(batch.chain_id, ChainId::from_string("ibc-0"));
+
+        let events: Vec<_> = batch.events.iter().map(|event| &event.event).collect();
+        assert_eq!(events.len(), 2);
+        match events[0] {
+            IbcEvent::NewBlock(new_block) => assert_eq!(new_block.height, batch.height),
+            event => panic!("unexpected event: {event}"),
+        }
+        match events[1] {
+            IbcEvent::CreateClient(create_client) => {
+                assert_eq!(create_client.client_id().as_str(), "07-tendermint-5")
+            }
+            event => panic!("unexpected event: {event}"),
+        }
+        assert!(batch
+            .events
+            .iter()
+            .all(|event| event.height == batch.height));
+    }
+
+    #[test]
+    fn catch_up_on_skipped_heights() {
+        let node = Arc::new(Mutex::new(Node {
+            latest_height: 5,
+            ..Node::default()
+        }));
+
+        let (mut monitor, _) = monitor(&node);
+        let subscription = monitor.event_bus.subscribe();
+
+        // On the first poll, only the latest block is fetched
+        monitor.poll().unwrap();
+        assert_eq!(batch_heights(&subscription), [5]);
+
+        // No new block, no batch
+        monitor.poll().unwrap();
+        assert_eq!(batch_heights(&subscription), Vec::<u64>::new());
+
+        // The blocks committed since the last poll are all fetched, in order
+        set_latest_height(&node, 9);
+        monitor.poll().unwrap();
+        assert_eq!(batch_heights(&subscription), [6, 7, 8, 9]);
+
+        assert_eq!(node.lock().unwrap().queried_heights, [5, 6, 7, 8, 9]);
+    }
+
+    #[test]
+    fn resume_from_last_fetched_height_after_rpc_error() {
+        let node = Arc::new(Mutex::new(Node {
+            latest_height: 5,
+            ..Node::default()
+        }));
+
+        let (mut monitor, _) = monitor(&node);
+        let subscription = monitor.event_bus.subscribe();
+
+        monitor.poll().unwrap();
+        assert_eq!(batch_heights(&subscription), [5]);
+
+        // The batches before the failing height are broadcast,
+        // the ones from the failing height onwards are not
+        {
+            let mut node = node.lock().unwrap();
+            node.latest_height = 9;
+            node.failing_heights.insert(7);
+        }
+
+        assert!(monitor.poll().is_err());
+        assert_eq!(batch_heights(&subscription), [6]);
+
+        // The next poll resumes from the failing height, without skipping nor repeating any block
+        set_latest_height(&node, 10);
+        monitor.poll().unwrap();
+        assert_eq!(batch_heights(&subscription), [7, 8, 9, 10]);
+
+        assert_eq!(node.lock().unwrap().queried_heights, [5, 6, 7, 7, 8, 9, 10]);
+    }
+
+    #[test]
+    fn poll_every_poll_interval() {
+        let node = Arc::new(Mutex::new(Node {
+            latest_height: 5,
+            ..Node::default()
+        }));
+
+        let (monitor, tx_cmd) = monitor(&node);
+
+        let started = Instant::now();
+        let handle = thread::spawn(|| monitor.run());
+        let subscription = tx_cmd.subscribe().unwrap();
+
+        // The node is first polled once the poll interval has elapsed
+        assert_eq!(next_batch(&subscription).height, Height::new(0, 5).unwrap());
+        assert!(started.elapsed() >= POLL_INTERVAL);
+
+        // The blocks committed in the meantime are fetched at the next poll
+        set_latest_height(&node, 7);
+
+        assert_eq!(next_batch(&subscription).height, Height::new(0, 6).unwrap());
+        assert_eq!(next_batch(&subscription).height, Height::new(0, 7).unwrap());
+
+        // A failing poll is retried at the next poll
+        {
+            let mut node = node.lock().unwrap();
+            node.latest_height = 8;
+            node.failing_heights.insert(8);
+        }
+
+        assert_eq!(next_batch(&subscription).height, Height::new(0, 8).unwrap());
+        assert_eq!(
+            node.lock()
+                .unwrap()
+                .queried_heights
+                .iter()
+                .filter(|h| **h == 8)
+                .count(),
+            2
+        );
+
+        tx_cmd.shutdown().unwrap();
+        handle.join().unwrap();
+    }
+}
@@ -2,6 +2,8 @@ use alloc::collections::BTreeMap as HashMap;
 use core::convert::TryFrom;
 use ibc_relayer_types::applications::ics29_fee::events::DistributionType;
 
+use sha2::{Digest, Sha256};
+use tendermint::Hash;
 use tendermint_rpc::{event::Event as RpcEvent, event::EventData as RpcEventData};
 
 use ibc_relayer_types::applications::ics31_icq::events::CrossChainQueryPacket;
@@ -14,7 +16,7 @@ use crate::chain::cosmos::types::events::channel::RawObject;
 use crate::event::monitor::queries;
 use crate::telemetry;
 
-use super::{ibc_event_try_from_abci_event, IbcEventWithHeight};
+use super::{ibc_event_try_from_abci_event, EventPosition, IbcEventWithHeight};
 
 /// Extract IBC events from Tendermint RPC events
 ///
@@ -141,6 +143,17 @@ pub fn get_all_events(
                 height,
             ));
             events_with_height.append(&mut extract_block_events(height, &events));
+
+            events_with_height = events_with_height
+                .into_iter()
+                .enumerate()
+                .map(|(index, event)| {
+                    event.with_position(EventPosition {
+                        tx_hash: None,
+                        index,
+                    })
+                })
+                .collect();
         }
         RpcEventData::Tx { tx_result } => {
             let height = Height::new(
@@ -149,18 +162,29 @@ pub fn get_all_events(
             )
             .map_err(|_| String::from("tx_result.height: invalid header height of 0"))?;
 
-            for abci_event in &tx_result.result.events {
+            let tx_hash = Hash::Sha256(Sha256::digest(&tx_result.tx).into());
+
+            for (index, abci_event) in tx_result.result.events.iter().enumerate() {
                 if let Ok(ibc_event) = ibc_event_try_from_abci_event(abci_event) {
+                    let position = EventPosition {
+                        tx_hash: Some(tx_hash),
+                        index,
+                    };
+
                     if query == queries::ibc_client().to_string()
                         && event_is_type_client(&ibc_event)
                     {
                         tracing::trace!("extracted ibc_client event {}", ibc_event);
-                        events_with_height.push(IbcEventWithHeight::new(ibc_event, height));
+                        events_with_height.push(
+                            IbcEventWithHeight::new(ibc_event, height).with_position(position),
+                        );
                     } else if query == queries::ibc_connection().to_string()
                         && event_is_type_connection(&ibc_event)
                     {
                         tracing::trace!("extracted ibc_connection event {}", ibc_event);
-                        events_with_height.push(IbcEventWithHeight::new(ibc_event, height));
+                        events_with_height.push(
+                            IbcEventWithHeight::new(ibc_event, height).with_position(position),
+                        );
                     } else if query == queries::ibc_channel().to_string()
                         && event_is_type_channel(&ibc_event)
                     {
@@ -175,16 +199,22 @@ pub fn get_all_events(
                             }
                         }
 
-                        events_with_height.push(IbcEventWithHeight::new(ibc_event, height));
+                        events_with_height.push(
+                            IbcEventWithHeight::new(ibc_event, height).with_position(position),
+                        );
                     } else if query == queries::ibc_query().to_string()
                         && event_is_type_cross_chain_query(&ibc_event)
                     {
                         tracing::trace!("extracted cross chain queries {}", ibc_event);
-                        events_with_height.push(IbcEventWithHeight::new(ibc_event, height));
+                        events_with_height.push(
+                            IbcEventWithHeight::new(ibc_event, height).with_position(position),
+                        );
                     } else if query == queries::ibc_channel().to_string()
                         && event_is_type_incentivized(&ibc_event)
                     {
-                        events_with_height.push(IbcEventWithHeight::new(ibc_event, height));
+                        events_with_height.push(
+                            IbcEventWithHeight::new(ibc_event, height).with_position(position),
+                        );
                     } else if query == queries::ibc_channel().to_string()
                         && event_is_type_distribute_fee(&ibc_event)
                     {
@@ -233,6 +263,14 @@ fn event_is_type_channel(ev: &IbcEvent) -> bool {
             | IbcEvent::OpenConfirmChannel(_)
             | IbcEvent::CloseInitChannel(_)
             | IbcEvent::CloseConfirmChannel(_)
+            | IbcEvent::UpgradeInitChannel(_)
+            | IbcEvent::UpgradeTryChannel(_)
+            | IbcEvent::UpgradeAckChannel(_)
+            | IbcEvent::UpgradeConfirmChannel(_)
+            | IbcEvent::UpgradeOpenChannel(_)
+            | IbcEvent::UpgradeTimeoutChannel(_)
+            | IbcEvent::UpgradeCancelChannel(_)
+            | IbcEvent::UpgradeErrorChannel(_)
             | IbcEvent::SendPacket(_)
             | IbcEvent::ReceivePacket(_)
             | IbcEvent::WriteAcknowledgement(_)
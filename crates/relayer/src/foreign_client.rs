@@ -13,6 +13,7 @@ use itertools::Itertools;
 use tracing::{debug, error, info, instrument, trace, warn};
 
 use flex_error::define_error;
+use ibc_relayer_types::clients::ics08_wasm::client_message::ClientMessage as WasmClientMessage;
 use ibc_relayer_types::core::ics02_client::client_state::ClientState;
 use ibc_relayer_types::core::ics02_client::error::Error as ClientError;
 use ibc_relayer_types::core::ics02_client::events::UpdateClient;
@@ -25,6 +26,7 @@ use ibc_relayer_types::core::ics02_client::trust_threshold::TrustThreshold;
 use ibc_relayer_types::core::ics24_host::identifier::{ChainId, ClientId};
 use ibc_relayer_types::downcast;
 use ibc_relayer_types::events::{IbcEvent, IbcEventType, WithBlockDataType};
+use ibc_relayer_types::signer::Signer;
 use ibc_relayer_types::timestamp::{Timestamp, TimestampOverflowError};
 use ibc_relayer_types::tx_msg::Msg;
 use ibc_relayer_types::Height;
@@ -326,6 +328,33 @@ pub enum ConsensusStateTrusted {
     },
 }
 
+/// The status of a client with respect to its trusting period,
+/// as returned by [`ForeignClient::expiry_status`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ClientExpiryStatus {
+    /// The client is active. The time left before it expires is only
+    /// known for clients which have a trusting period.
+    Active {
+        elapsed: Duration,
+        time_left: Option<Duration>,
+    },
+    /// The latest consensus state of the client is outside of its trusting period.
+    Expired { elapsed: Duration },
+    /// The client has been frozen following a misbehaviour.
+    Frozen,
+}
+
+impl ClientExpiryStatus {
+    /// The time left before the client expires, which is zero
+    /// for clients which are already expired or frozen.
+    pub fn time_left(&self) -> Option<Duration> {
+        match self {
+            Self::Active { time_left, .. } => *time_left,
+            Self::Expired { .. } | Self::Frozen => Some(Duration::ZERO),
+        }
+    }
+}
+
 #[derive(Clone, Debug)]
 pub struct ForeignClient<DstChain: ChainHandle, SrcChain: ChainHandle> {
     /// The identifier of this client. The host chain determines this id upon client creation,
@@ -446,6 +475,34 @@ impl<DstChain: ChainHandle, SrcChain: ChainHandle> ForeignClient<DstChain, SrcCh
         fields(client = %self)
     )]
     pub fn upgrade(&self, src_upgrade_height: Height) -> Result<Vec<IbcEvent>, ForeignClientError> {
+        let msgs = self.build_upgrade(src_upgrade_height)?;
+
+        let tm = TrackedMsgs::new_static(msgs, "upgrade client");
+
+        let res = self
+            .dst_chain
+            .send_messages_and_wait_commit(tm)
+            .map_err(|e| {
+                ForeignClientError::client_upgrade(
+                    self.id.clone(),
+                    self.dst_chain.id(),
+                    "failed while sending message to destination chain".to_string(),
+                    e,
+                )
+            })?;
+
+        Ok(res
+            .into_iter()
+            .map(|ev_with_height| ev_with_height.event)
+            .collect())
+    }
+
+    /// Lower-level interface for preparing the messages to perform a client upgrade,
+    /// ie. the client update to the upgrade height followed by the client upgrade itself.
+    pub fn build_upgrade(
+        &self,
+        src_upgrade_height: Height,
+    ) -> Result<Vec<Any>, ForeignClientError> {
         let msgs = self
             .build_update_client_with_trusted(src_upgrade_height, None)
             .map_err(|_| {
@@ -518,24 +575,7 @@ impl<DstChain: ChainHandle, SrcChain: ChainHandle> ForeignClient<DstChain, SrcCh
 
         msgs.push(msg_upgrade);
 
-        let tm = TrackedMsgs::new_static(msgs, "upgrade client");
-
-        let res = self
-            .dst_chain
-            .send_messages_and_wait_commit(tm)
-            .map_err(|e| {
-                ForeignClientError::client_upgrade(
-                    self.id.clone(),
-                    self.dst_chain.id(),
-                    "failed while sending message to destination chain".to_string(),
-                    e,
-                )
-            })?;
-
-        Ok(res
-            .into_iter()
-            .map(|ev_with_height| ev_with_height.event)
-            .collect())
+        Ok(msgs)
     }
 
     /// Returns a handle to the chain hosting this client.
@@ -686,23 +726,7 @@ impl<DstChain: ChainHandle, SrcChain: ChainHandle> ForeignClient<DstChain, SrcCh
     pub fn validated_client_state(
         &self,
     ) -> Result<(AnyClientState, Option<Duration>), ForeignClientError> {
-        let (client_state, _) = {
-            self.dst_chain
-                .query_client_state(
-                    QueryClientStateRequest {
-                        client_id: self.id().clone(),
-                        height: QueryHeight::Latest,
-                    },
-                    IncludeProof::No,
-                )
-                .map_err(|e| {
-                    ForeignClientError::client_refresh(
-                        self.id().clone(),
-                        "failed querying client state on dst chain".to_string(),
-                        e,
-                    )
-                })?
-        };
+        let client_state = self.latest_client_state()?;
 
         if client_state.is_frozen() {
             return Err(ForeignClientError::expired_or_frozen(
@@ -737,6 +761,27 @@ impl<DstChain: ChainHandle, SrcChain: ChainHandle> ForeignClient<DstChain, SrcCh
         }
     }
 
+    fn latest_client_state(&self) -> Result<AnyClientState, ForeignClientError> {
+        let (client_state, _) = self
+            .dst_chain
+            .query_client_state(
+                QueryClientStateRequest {
+                    client_id: self.id().clone(),
+                    height: QueryHeight::Latest,
+                },
+                IncludeProof::No,
+            )
+            .map_err(|e| {
+                ForeignClientError::client_refresh(
+                    self.id().clone(),
+                    "failed querying client state on dst chain".to_string(),
+                    e,
+                )
+            })?;
+
+        Ok(client_state)
+    }
+
     /// Verifies if the consensus state at given [`Height`]
     /// is within or outside of the client's trusting period.
     #[instrument(
@@ -756,7 +801,16 @@ impl<DstChain: ChainHandle, SrcChain: ChainHandle> ForeignClient<DstChain, SrcCh
             client_state.chain_id(), self.src_chain.id());
         }
 
-        let consensus_state_timestamp = self.fetch_consensus_state(*height)?.timestamp();
+        // Solo machine clients only keep track of their latest consensus state,
+        // within their client state, rather than storing it at its height.
+        let consensus_state_timestamp = match client_state {
+            AnyClientState::Solomachine(client_state)
+                if client_state.latest_height() == *height =>
+            {
+                client_state.consensus_state.timestamp
+            }
+            _ => self.fetch_consensus_state(*height)?.timestamp(),
+        };
 
         let current_src_network_time = self
             .src_chain
@@ -793,6 +847,37 @@ impl<DstChain: ChainHandle, SrcChain: ChainHandle> ForeignClient<DstChain, SrcCh
         }
     }
 
+    /// Computes how much of the trusting period of the client is left,
+    /// based on the timestamp of its latest consensus state and on the
+    /// current time of the source chain.
+    #[instrument(
+        name = "foreign_client.expiry_status",
+        level = "error",
+        skip_all,
+        fields(client = %self)
+    )]
+    pub fn expiry_status(&self) -> Result<ClientExpiryStatus, ForeignClientError> {
+        let client_state = self.latest_client_state()?;
+
+        if client_state.is_frozen() {
+            return Ok(ClientExpiryStatus::Frozen);
+        }
+
+        match self
+            .check_consensus_state_trusting_period(&client_state, &client_state.latest_height())?
+        {
+            ConsensusStateTrusted::NotTrusted { elapsed, .. } => {
+                Ok(ClientExpiryStatus::Expired { elapsed })
+            }
+            ConsensusStateTrusted::Trusted { elapsed } => Ok(ClientExpiryStatus::Active {
+                elapsed,
+                time_left: client_state
+                    .trusting_period()
+                    .map(|trusting_period| trusting_period.saturating_sub(elapsed)),
+            }),
+        }
+    }
+
     #[instrument(
         name = "foreign_client.refresh",
         level = "error",
@@ -1069,6 +1154,43 @@ impl<DstChain: ChainHandle, SrcChain: ChainHandle> ForeignClient<DstChain, SrcCh
             }
         );
 
+        self.wait_for_src_height(target_height)?;
+
+        let messages = self.build_update_client_with_trusted(target_height, trusted_height)?;
+
+        let encoded_messages = messages.into_iter().map(Msg::to_any).collect();
+
+        Ok(encoded_messages)
+    }
+
+    /// Same as [`ForeignClient::wait_and_build_update_client`], but the messages
+    /// are signed by the given signer, eg. the address of the wallet selected to
+    /// submit them along with other messages.
+    pub fn wait_and_build_update_client_with_signer(
+        &self,
+        target_height: Height,
+        signer: &Signer,
+    ) -> Result<Vec<Any>, ForeignClientError> {
+        self.wait_for_src_height(target_height)?;
+
+        let messages = self.build_update_client_with_trusted(target_height, None)?;
+
+        let encoded_messages = messages
+            .into_iter()
+            .map(|msg| {
+                MsgUpdateClient {
+                    signer: signer.clone(),
+                    ..msg
+                }
+                .to_any()
+            })
+            .collect();
+
+        Ok(encoded_messages)
+    }
+
+    /// Wait for the source network to produce block(s) & reach `target_height`.
+    fn wait_for_src_height(&self, target_height: Height) -> Result<(), ForeignClientError> {
         let src_application_latest_height = || {
             self.src_chain().query_latest_height().map_err(|e| {
                 ForeignClientError::client_create(
@@ -1087,17 +1209,12 @@ impl<DstChain: ChainHandle, SrcChain: ChainHandle> ForeignClient<DstChain, SrcCh
                     "dst_chain": self.dst_chain().id(),
                 }
             );
-            // Wait for the source network to produce block(s) & reach `target_height`.
             while src_application_latest_height()? < target_height {
                 thread::sleep(Duration::from_millis(100));
             }
         }
 
-        let messages = self.build_update_client_with_trusted(target_height, trusted_height)?;
-
-        let encoded_messages = messages.into_iter().map(Msg::to_any).collect();
-
-        Ok(encoded_messages)
+        Ok(())
     }
 
     #[instrument(
@@ -1185,7 +1302,7 @@ impl<DstChain: ChainHandle, SrcChain: ChainHandle> ForeignClient<DstChain, SrcCh
             );
 
             msgs.push(MsgUpdateClient {
-                header: header.into(),
+                header: client_message(&client_state, header),
                 client_id: self.id.clone(),
                 signer: signer.clone(),
             });
@@ -1198,7 +1315,7 @@ impl<DstChain: ChainHandle, SrcChain: ChainHandle> ForeignClient<DstChain, SrcCh
         );
 
         msgs.push(MsgUpdateClient {
-            header: header.into(),
+            header: client_message(&client_state, header),
             signer,
             client_id: self.id.clone(),
         });
@@ -1787,3 +1904,12 @@ pub fn extract_client_id(event: &IbcEvent) -> Result<&ClientId, ForeignClientErr
         )),
     }
 }
+
+/// Encodes a header for the given client, wrapping it in a Wasm client message
+/// when the client is an `08-wasm` client.
+fn client_message(client_state: &AnyClientState, header: AnyHeader) -> Any {
+    match client_state {
+        AnyClientState::Wasm(_) => WasmClientMessage::wrap(header.into()).into(),
+        _ => header.into(),
+    }
+}
@@ -1,4 +1,6 @@
+pub mod encrypted;
 pub mod errors;
+pub mod remote;
 pub use any_signing_key_pair::AnySigningKeyPair;
 pub use ed25519_key_pair::Ed25519KeyPair;
 pub use key_type::KeyType;
@@ -15,17 +17,20 @@ mod signing_key_pair;
 
 use alloc::collections::btree_map::BTreeMap as HashMap;
 use std::ffi::OsStr;
-use std::fs::{self, File};
+use std::fs;
 use std::path::PathBuf;
 
 use ibc_relayer_types::core::ics24_host::identifier::ChainId;
 use serde::{Deserialize, Serialize};
 
 use crate::{chain::ChainType, config::ChainConfig};
+use encrypted::{EncryptedKeyFile, Passphrase, DEFAULT_KDF_ROUNDS};
 use errors::Error;
+use remote::{RemoteSigner, RemoteSignerClient, RemoteSignerConfig};
 
 pub const KEYSTORE_DEFAULT_FOLDER: &str = ".hermes/keys/";
 pub const KEYSTORE_DISK_BACKEND: &str = "keyring-test";
+pub const KEYSTORE_ENCRYPTED_BACKEND: &str = "keyring-file";
 pub const KEYSTORE_FILE_EXTENSION: &str = "json";
 
 /// JSON key seed file
@@ -120,7 +125,7 @@ impl<S: SigningKeyPairSized> KeyStore<S> for Test {
             return Err(Error::key_file_not_found(format!("{}", key_file.display())));
         }
 
-        let file = File::open(&key_file).map_err(|e| {
+        let file = fs::File::open(&key_file).map_err(|e| {
             Error::key_file_io(
                 key_file.display().to_string(),
                 "failed to open file".to_string(),
@@ -139,7 +144,7 @@ impl<S: SigningKeyPairSized> KeyStore<S> for Test {
         filename.set_extension(KEYSTORE_FILE_EXTENSION);
         let file_path = filename.display().to_string();
 
-        let file = File::create(filename).map_err(|e| {
+        let file = fs::File::create(filename).map_err(|e| {
             Error::key_file_io(file_path.clone(), "failed to create file".to_string(), e)
         })?;
 
@@ -181,10 +186,185 @@ impl<S: SigningKeyPairSized> KeyStore<S> for Test {
     }
 }
 
+/// On-disk key store where each key is encrypted with a passphrase,
+/// see the [`encrypted`] module for details.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct File {
+    account_prefix: String,
+    store: PathBuf,
+    #[serde(skip)]
+    passphrase: Passphrase,
+    #[serde(skip, default = "default_kdf_rounds")]
+    kdf_rounds: u32,
+}
+
+fn default_kdf_rounds() -> u32 {
+    DEFAULT_KDF_ROUNDS
+}
+
+impl File {
+    pub fn new(account_prefix: String, store: PathBuf, passphrase: Passphrase) -> Self {
+        Self {
+            account_prefix,
+            store,
+            passphrase,
+            kdf_rounds: DEFAULT_KDF_ROUNDS,
+        }
+    }
+
+    fn key_file_path(&self, key_name: &str) -> PathBuf {
+        let mut key_file = self.store.join(key_name);
+        key_file.set_extension(KEYSTORE_FILE_EXTENSION);
+        key_file
+    }
+}
+
+impl<S: SigningKeyPairSized> KeyStore<S> for File {
+    fn get_key(&self, key_name: &str) -> Result<S, Error> {
+        let key_file = self.key_file_path(key_name);
+        let file_path = key_file.display().to_string();
+
+        if !key_file.as_path().exists() {
+            return Err(Error::key_file_not_found(file_path));
+        }
+
+        let file = fs::File::open(&key_file).map_err(|e| {
+            Error::key_file_io(file_path.clone(), "failed to open file".to_string(), e)
+        })?;
+
+        let encrypted: EncryptedKeyFile = serde_json::from_reader(file)
+            .map_err(|e| Error::key_file_decode(file_path.clone(), e))?;
+
+        let key_entry = encrypted.decrypt(&file_path, &self.passphrase)?;
+
+        serde_json::from_slice(&key_entry).map_err(|e| Error::key_file_decode(file_path, e))
+    }
+
+    fn add_key(&mut self, key_name: &str, key_entry: S) -> Result<(), Error> {
+        let key_file = self.key_file_path(key_name);
+        let file_path = key_file.display().to_string();
+
+        let plaintext = serde_json::to_vec(&key_entry)
+            .map_err(|e| Error::key_file_encode(file_path.clone(), e))?;
+
+        let encrypted = EncryptedKeyFile::encrypt(&plaintext, &self.passphrase, self.kdf_rounds)?;
+
+        let mut options = fs::OpenOptions::new();
+        options.write(true).create(true).truncate(true);
+
+        // Only the owner of the key file may read it
+        #[cfg(unix)]
+        std::os::unix::fs::OpenOptionsExt::mode(&mut options, 0o600);
+
+        let file = options.open(&key_file).map_err(|e| {
+            Error::key_file_io(file_path.clone(), "failed to create file".to_string(), e)
+        })?;
+
+        serde_json::to_writer_pretty(file, &encrypted)
+            .map_err(|e| Error::key_file_encode(file_path, e))?;
+
+        Ok(())
+    }
+
+    fn remove_key(&mut self, key_name: &str) -> Result<(), Error> {
+        let key_file = self.key_file_path(key_name);
+
+        fs::remove_file(&key_file)
+            .map_err(|e| Error::remove_io_fail(key_file.display().to_string(), e))?;
+
+        Ok(())
+    }
+
+    fn keys(&self) -> Result<Vec<(String, S)>, Error> {
+        let dir = fs::read_dir(&self.store).map_err(|e| {
+            Error::key_file_io(
+                self.store.display().to_string(),
+                "failed to list keys".to_string(),
+                e,
+            )
+        })?;
+
+        let ext = OsStr::new(KEYSTORE_FILE_EXTENSION);
+
+        dir.into_iter()
+            .flatten()
+            .map(|entry| entry.path())
+            .filter(|path| path.extension() == Some(ext))
+            .flat_map(|path| path.file_stem().map(OsStr::to_owned))
+            .flat_map(|stem| stem.to_str().map(ToString::to_string))
+            .map(|name| self.get_key(&name).map(|key| (name, key)))
+            .collect()
+    }
+}
+
+/// Key store backed by a remote signer, which only knows the public part of the keys,
+/// see the [`remote`] module for details.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Remote {
+    account_prefix: String,
+    client: RemoteSignerClient,
+}
+
+impl Remote {
+    pub fn new(account_prefix: String, client: RemoteSignerClient) -> Self {
+        Self {
+            account_prefix,
+            client,
+        }
+    }
+}
+
+impl<S: SigningKeyPairSized> KeyStore<S> for Remote {
+    fn get_key(&self, key_name: &str) -> Result<S, Error> {
+        let (public_key, account) = self.client.get_key(key_name)?;
+        let signer = RemoteSigner::new(self.client.clone(), key_name.to_string());
+
+        S::from_remote_signer(signer, &public_key, &account)
+    }
+
+    fn add_key(&mut self, _key_name: &str, _key_entry: S) -> Result<(), Error> {
+        Err(Error::remote_key_management())
+    }
+
+    fn remove_key(&mut self, _key_name: &str) -> Result<(), Error> {
+        Err(Error::remote_key_management())
+    }
+
+    fn keys(&self) -> Result<Vec<(String, S)>, Error> {
+        self.client
+            .list_keys()?
+            .into_iter()
+            .map(|name| self.get_key(&name).map(|key| (name, key)))
+            .collect()
+    }
+}
+
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum Store {
+    /// Keys are only kept in memory
     Memory,
+    /// Keys are stored on disk, in plaintext
     Test,
+    /// Keys are stored on disk, encrypted with the passphrase given via
+    /// the environment, see [`Passphrase::from_env`]
+    File,
+    /// Keys are held by the remote signer given by the `remote_signer`
+    /// setting of the chain, see [`RemoteSignerConfig`]
+    Remote,
+}
+
+impl Store {
+    /// The store in which keys managed from the CLI are persisted.
+    ///
+    /// Because keys added to the in-memory store would be lost
+    /// when the CLI exits, the plaintext on-disk store is used instead.
+    pub fn persistent(self) -> Self {
+        match self {
+            Self::Memory | Self::Test => Self::Test,
+            Self::File => Self::File,
+            Self::Remote => Self::Remote,
+        }
+    }
 }
 
 impl Default for Store {
@@ -197,6 +377,8 @@ impl Default for Store {
 pub enum KeyRing<S> {
     Memory(Memory<S>),
     Test(Test),
+    File(File),
+    Remote(Remote),
 }
 
 impl<S: SigningKeyPairSized> KeyRing<S> {
@@ -205,6 +387,7 @@ impl<S: SigningKeyPairSized> KeyRing<S> {
         account_prefix: &str,
         chain_id: &ChainId,
         ks_folder: &Option<PathBuf>,
+        remote_signer: &Option<RemoteSignerConfig>,
     ) -> Result<Self, Error> {
         match store {
             Store::Memory => Ok(Self::Memory(Memory::new(account_prefix.to_string()))),
@@ -226,6 +409,37 @@ impl<S: SigningKeyPairSized> KeyRing<S> {
                     keys_folder,
                 )))
             }
+
+            Store::File => {
+                let passphrase = Passphrase::from_env()?;
+                let keys_folder = encrypted_store_path(chain_id.as_str(), ks_folder)?;
+
+                // Create keys folder if it does not exist
+                fs::create_dir_all(&keys_folder).map_err(|e| {
+                    Error::key_file_io(
+                        keys_folder.display().to_string(),
+                        "failed to create keys folder".to_string(),
+                        e,
+                    )
+                })?;
+
+                Ok(Self::File(File::new(
+                    account_prefix.to_string(),
+                    keys_folder,
+                    passphrase,
+                )))
+            }
+
+            Store::Remote => {
+                let config = remote_signer
+                    .clone()
+                    .ok_or_else(Error::remote_signer_not_configured)?;
+
+                Ok(Self::Remote(Remote::new(
+                    account_prefix.to_string(),
+                    RemoteSignerClient::new(config),
+                )))
+            }
         }
     }
 
@@ -233,6 +447,8 @@ impl<S: SigningKeyPairSized> KeyRing<S> {
         match self {
             Self::Memory(m) => m.get_key(key_name),
             Self::Test(d) => d.get_key(key_name),
+            Self::File(f) => f.get_key(key_name),
+            Self::Remote(r) => r.get_key(key_name),
         }
     }
 
@@ -240,6 +456,8 @@ impl<S: SigningKeyPairSized> KeyRing<S> {
         match self {
             Self::Memory(m) => m.add_key(key_name, key_entry),
             Self::Test(d) => d.add_key(key_name, key_entry),
+            Self::File(f) => f.add_key(key_name, key_entry),
+            Self::Remote(r) => r.add_key(key_name, key_entry),
         }
     }
 
@@ -247,6 +465,8 @@ impl<S: SigningKeyPairSized> KeyRing<S> {
         match self {
             Self::Memory(m) => m.remove_key(key_name),
             Self::Test(d) => <Test as KeyStore<S>>::remove_key(d, key_name),
+            Self::File(f) => <File as KeyStore<S>>::remove_key(f, key_name),
+            Self::Remote(r) => <Remote as KeyStore<S>>::remove_key(r, key_name),
         }
     }
 
@@ -254,6 +474,8 @@ impl<S: SigningKeyPairSized> KeyRing<S> {
         match self {
             Self::Memory(m) => m.keys(),
             Self::Test(d) => d.keys(),
+            Self::File(f) => f.keys(),
+            Self::Remote(r) => r.keys(),
         }
     }
 
@@ -261,6 +483,8 @@ impl<S: SigningKeyPairSized> KeyRing<S> {
         match self {
             Self::Memory(m) => &m.account_prefix,
             Self::Test(d) => &d.account_prefix,
+            Self::File(f) => &f.account_prefix,
+            Self::Remote(r) => &r.account_prefix,
         }
     }
 }
@@ -271,8 +495,9 @@ impl KeyRing<Secp256k1KeyPair> {
         account_prefix: &str,
         chain_id: &ChainId,
         ks_folder: &Option<PathBuf>,
+        remote_signer: &Option<RemoteSignerConfig>,
     ) -> Result<Self, Error> {
-        Self::new(store, account_prefix, chain_id, ks_folder)
+        Self::new(store, account_prefix, chain_id, ks_folder, remote_signer)
     }
 }
 
@@ -282,19 +507,21 @@ impl KeyRing<Ed25519KeyPair> {
         account_prefix: &str,
         chain_id: &ChainId,
         ks_folder: &Option<PathBuf>,
+        remote_signer: &Option<RemoteSignerConfig>,
     ) -> Result<Self, Error> {
-        Self::new(store, account_prefix, chain_id, ks_folder)
+        Self::new(store, account_prefix, chain_id, ks_folder, remote_signer)
     }
 }
 
 pub fn list_keys(config: &ChainConfig) -> Result<Vec<(String, AnySigningKeyPair)>, Error> {
     let keys = match config.r#type {
-        ChainType::CosmosSdk => {
+        ChainType::CosmosSdk | ChainType::Mock | ChainType::SoloMachine => {
             let keyring = KeyRing::new_secp256k1(
-                Store::Test,
+                config.key_store_type.persistent(),
                 &config.account_prefix,
                 &config.id,
                 &config.key_store_folder,
+                &config.remote_signer,
             )?;
             keyring
                 .keys()?
@@ -307,6 +534,31 @@ pub fn list_keys(config: &ChainConfig) -> Result<Vec<(String, AnySigningKeyPair)
 }
 
 fn disk_store_path(folder_name: &str, keystore_folder: &Option<PathBuf>) -> Result<PathBuf, Error> {
+    store_path(folder_name, keystore_folder, KEYSTORE_DISK_BACKEND)
+}
+
+fn encrypted_store_path(
+    folder_name: &str,
+    keystore_folder: &Option<PathBuf>,
+) -> Result<PathBuf, Error> {
+    store_path(folder_name, keystore_folder, KEYSTORE_ENCRYPTED_BACKEND)
+}
+
+fn store_path(
+    folder_name: &str,
+    keystore_folder: &Option<PathBuf>,
+    backend: &str,
+) -> Result<PathBuf, Error> {
+    let folder = chain_folder(folder_name, keystore_folder)?.join(backend);
+
+    Ok(folder)
+}
+
+/// The folder holding the key stores of the given chain.
+pub fn chain_folder(
+    folder_name: &str,
+    keystore_folder: &Option<PathBuf>,
+) -> Result<PathBuf, Error> {
     let ks_folder = match keystore_folder {
         Some(folder) => folder.to_owned(),
         None => {
@@ -315,7 +567,126 @@ fn disk_store_path(folder_name: &str, keystore_folder: &Option<PathBuf>) -> Resu
         }
     };
 
-    let folder = ks_folder.join(folder_name).join(KEYSTORE_DISK_BACKEND);
+    Ok(ks_folder.join(folder_name))
+}
 
-    Ok(folder)
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use hdpath::StandardHDPath;
+    use std::str::FromStr;
+
+    use crate::config::AddressType;
+
+    const MNEMONIC: &str = "such walnut usual noble image raise cabin suspect combine key absurd detail present bless yard grief amazing slam brown donate fabric opera desk minor";
+
+    #[test]
+    fn file_store_roundtrip() {
+        let folder = std::env::temp_dir().join(format!("hermes-keyring-{}", uuid::Uuid::new_v4()));
+        fs::create_dir_all(&folder).unwrap();
+
+        let mut store = File::new(
+            "cosmos".to_string(),
+            folder.clone(),
+            Passphrase::new("passphrase".to_string()),
+        );
+        store.kdf_rounds = 1_000;
+
+        let key_pair = Secp256k1KeyPair::from_mnemonic(
+            MNEMONIC,
+            &StandardHDPath::from_str("m/44'/118'/0'/0/0").unwrap(),
+            &AddressType::Cosmos,
+            "cosmos",
+        )
+        .unwrap();
+
+        store.add_key("relayer", key_pair.clone()).unwrap();
+
+        // The key file must not leak the mnemonic
+        let contents = fs::read_to_string(folder.join("relayer.json")).unwrap();
+        assert!(!contents.contains("walnut"));
+
+        let key: Secp256k1KeyPair = store.get_key("relayer").unwrap();
+        assert_eq!(key.account(), key_pair.account());
+
+        let keys: Vec<(String, Secp256k1KeyPair)> = store.keys().unwrap();
+        assert_eq!(keys.len(), 1);
+
+        let wrong = File::new(
+            "cosmos".to_string(),
+            folder.clone(),
+            Passphrase::new("wrong".to_string()),
+        );
+        assert!(KeyStore::<Secp256k1KeyPair>::get_key(&wrong, "relayer").is_err());
+
+        KeyStore::<Secp256k1KeyPair>::remove_key(&mut store, "relayer").unwrap();
+        assert!(KeyStore::<Secp256k1KeyPair>::get_key(&store, "relayer").is_err());
+
+        fs::remove_dir_all(folder).unwrap();
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn remote_store_signs_via_signer() {
+        use std::io::{BufRead, BufReader, Write};
+        use std::os::unix::net::UnixListener;
+
+        use remote::{RemoteSignerRequest, RemoteSignerResponse};
+
+        let socket =
+            std::env::temp_dir().join(format!("hermes-signer-{}.sock", uuid::Uuid::new_v4()));
+        let listener = UnixListener::bind(&socket).unwrap();
+
+        let key_pair = Secp256k1KeyPair::from_mnemonic(
+            MNEMONIC,
+            &StandardHDPath::from_str("m/44'/118'/0'/0/0").unwrap(),
+            &AddressType::Cosmos,
+            "cosmos",
+        )
+        .unwrap();
+
+        // Serve a `get_key` request followed by a `sign` request
+        let signer_key = key_pair.clone();
+        let signer = std::thread::spawn(move || {
+            for stream in listener.incoming().take(2) {
+                let stream = stream.unwrap();
+
+                let mut line = String::new();
+                BufReader::new(&stream).read_line(&mut line).unwrap();
+
+                let response = match serde_json::from_str(&line).unwrap() {
+                    RemoteSignerRequest::GetKey { .. } => RemoteSignerResponse::Key {
+                        public_key: hex::encode(signer_key.public_key.serialize()),
+                        address: signer_key.account(),
+                    },
+                    RemoteSignerRequest::Sign { sign_doc, .. } => RemoteSignerResponse::Signature(
+                        hex::encode(signer_key.sign(&hex::decode(sign_doc).unwrap()).unwrap()),
+                    ),
+                    RemoteSignerRequest::ListKeys => unreachable!(),
+                };
+
+                let mut response = serde_json::to_string(&response).unwrap();
+                response.push('\n');
+                (&stream).write_all(response.as_bytes()).unwrap();
+            }
+        });
+
+        let mut store = Remote::new(
+            "cosmos".to_string(),
+            RemoteSignerClient::new(RemoteSignerConfig::new(socket.clone())),
+        );
+
+        let key: Secp256k1KeyPair = store.get_key("relayer").unwrap();
+        assert_eq!(key.account(), key_pair.account());
+        assert_eq!(
+            key.sign(b"sign doc").unwrap(),
+            key_pair.sign(b"sign doc").unwrap()
+        );
+
+        assert!(store.add_key("relayer", key_pair).is_err());
+
+        signer.join().unwrap();
+        fs::remove_file(socket).unwrap();
+    }
 }
@@ -0,0 +1,232 @@
+//! Passphrase-based encryption of the key files written by the [`File`](super::File) store.
+//!
+//! The encryption key is derived from the passphrase with PBKDF2-HMAC-SHA256,
+//! using a random salt, and the key entry is then encrypted with ChaCha20-Poly1305,
+//! using a random nonce. The KDF and cipher parameters are stored alongside the
+//! ciphertext, so that they can be changed in the future without breaking existing files.
+
+use core::fmt;
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+
+use chacha20poly1305::aead::rand_core::RngCore;
+use chacha20poly1305::aead::{Aead, AeadCore, KeyInit, OsRng};
+use chacha20poly1305::{ChaCha20Poly1305, Key, Nonce};
+use hmac::Hmac;
+use serde::{Deserialize, Serialize};
+use sha2::Sha256;
+
+use super::errors::Error;
+
+/// Environment variable holding the passphrase of the encrypted keyring.
+pub const PASSPHRASE_ENV_VAR: &str = "HERMES_KEYRING_PASSPHRASE";
+
+/// Environment variable holding the path to a file
+/// containing the passphrase of the encrypted keyring.
+pub const PASSPHRASE_FILE_ENV_VAR: &str = "HERMES_KEYRING_PASSPHRASE_FILE";
+
+/// Number of PBKDF2 rounds used when encrypting new key files,
+/// as per the OWASP recommendation for PBKDF2-HMAC-SHA256.
+pub const DEFAULT_KDF_ROUNDS: u32 = 600_000;
+
+const KEY_FILE_VERSION: u8 = 1;
+const KDF_ALGORITHM: &str = "pbkdf2-hmac-sha256";
+const CIPHER_ALGORITHM: &str = "chacha20-poly1305";
+const SALT_LEN: usize = 32;
+
+/// The passphrase used to encrypt and decrypt the keys of the [`File`](super::File) store.
+#[derive(Clone, Default)]
+pub struct Passphrase(String);
+
+impl Passphrase {
+    pub fn new(passphrase: String) -> Self {
+        Self(passphrase)
+    }
+
+    /// Read the passphrase from the [`PASSPHRASE_ENV_VAR`] environment variable if it is set,
+    /// or otherwise from the file whose path is given by [`PASSPHRASE_FILE_ENV_VAR`].
+    ///
+    /// Trailing newlines are stripped from the contents of the passphrase file.
+    pub fn from_env() -> Result<Self, Error> {
+        if let Ok(passphrase) = env::var(PASSPHRASE_ENV_VAR) {
+            if !passphrase.is_empty() {
+                return Ok(Self(passphrase));
+            }
+        }
+
+        if let Some(path) = env::var_os(PASSPHRASE_FILE_ENV_VAR).map(PathBuf::from) {
+            let contents = fs::read_to_string(&path)
+                .map_err(|e| Error::passphrase_file_io(path.display().to_string(), e))?;
+
+            let passphrase = contents.trim_end_matches(['\r', '\n']);
+
+            if !passphrase.is_empty() {
+                return Ok(Self(passphrase.to_string()));
+            }
+        }
+
+        Err(Error::passphrase_unavailable(
+            PASSPHRASE_ENV_VAR.to_string(),
+            PASSPHRASE_FILE_ENV_VAR.to_string(),
+        ))
+    }
+
+    fn as_bytes(&self) -> &[u8] {
+        self.0.as_bytes()
+    }
+}
+
+impl fmt::Debug for Passphrase {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Passphrase(<redacted>)")
+    }
+}
+
+/// Contents of an encrypted key file.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EncryptedKeyFile {
+    version: u8,
+    kdf: KdfParams,
+    cipher: CipherParams,
+    /// Hex-encoded ciphertext, including the authentication tag
+    ciphertext: String,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+struct KdfParams {
+    algorithm: String,
+    rounds: u32,
+    /// Hex-encoded salt
+    salt: String,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+struct CipherParams {
+    algorithm: String,
+    /// Hex-encoded nonce
+    nonce: String,
+}
+
+impl EncryptedKeyFile {
+    /// Encrypt the given plaintext with a key derived from the given passphrase.
+    pub fn encrypt(plaintext: &[u8], passphrase: &Passphrase, rounds: u32) -> Result<Self, Error> {
+        let mut salt = [0u8; SALT_LEN];
+        OsRng.fill_bytes(&mut salt);
+
+        let key = derive_key(passphrase, &salt, rounds);
+        let nonce = ChaCha20Poly1305::generate_nonce(&mut OsRng);
+
+        let ciphertext = ChaCha20Poly1305::new(&key)
+            .encrypt(&nonce, plaintext)
+            .map_err(|_| Error::encryption())?;
+
+        Ok(Self {
+            version: KEY_FILE_VERSION,
+            kdf: KdfParams {
+                algorithm: KDF_ALGORITHM.to_string(),
+                rounds,
+                salt: hex::encode(salt),
+            },
+            cipher: CipherParams {
+                algorithm: CIPHER_ALGORITHM.to_string(),
+                nonce: hex::encode(nonce),
+            },
+            ciphertext: hex::encode(ciphertext),
+        })
+    }
+
+    /// Decrypt the contents of the key file at the given path with the given passphrase.
+    pub fn decrypt(&self, file_path: &str, passphrase: &Passphrase) -> Result<Vec<u8>, Error> {
+        let unsupported =
+            |reason: String| Error::unsupported_key_file(file_path.to_string(), reason);
+
+        if self.version != KEY_FILE_VERSION {
+            return Err(unsupported(format!("unknown version {}", self.version)));
+        }
+
+        if self.kdf.algorithm != KDF_ALGORITHM {
+            return Err(unsupported(format!(
+                "unknown KDF algorithm '{}'",
+                self.kdf.algorithm
+            )));
+        }
+
+        if self.cipher.algorithm != CIPHER_ALGORITHM {
+            return Err(unsupported(format!(
+                "unknown cipher algorithm '{}'",
+                self.cipher.algorithm
+            )));
+        }
+
+        let decode = |field: &str, value: &str| {
+            hex::decode(value).map_err(|e| unsupported(format!("invalid {field}: {e}")))
+        };
+
+        let salt = decode("salt", &self.kdf.salt)?;
+        let nonce = decode("nonce", &self.cipher.nonce)?;
+        let ciphertext = decode("ciphertext", &self.ciphertext)?;
+
+        if nonce.len() != Nonce::default().len() {
+            return Err(unsupported(format!("invalid nonce length {}", nonce.len())));
+        }
+
+        let key = derive_key(passphrase, &salt, self.kdf.rounds);
+
+        ChaCha20Poly1305::new(&key)
+            .decrypt(Nonce::from_slice(&nonce), ciphertext.as_slice())
+            .map_err(|_| Error::decryption(file_path.to_string()))
+    }
+}
+
+fn derive_key(passphrase: &Passphrase, salt: &[u8], rounds: u32) -> Key {
+    let mut key = Key::default();
+    pbkdf2::pbkdf2::<Hmac<Sha256>>(passphrase.as_bytes(), salt, rounds, &mut key);
+    key
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ROUNDS: u32 = 1_000;
+
+    #[test]
+    fn encrypt_decrypt_roundtrip() {
+        let passphrase = Passphrase::new("correct horse battery staple".to_string());
+        let plaintext = b"some secret key material";
+
+        let encrypted = EncryptedKeyFile::encrypt(plaintext, &passphrase, ROUNDS).unwrap();
+        assert!(!encrypted.ciphertext.contains(&hex::encode(plaintext)));
+
+        let decrypted = encrypted.decrypt("key.json", &passphrase).unwrap();
+        assert_eq!(decrypted, plaintext);
+    }
+
+    #[test]
+    fn decrypt_with_wrong_passphrase() {
+        let passphrase = Passphrase::new("correct horse battery staple".to_string());
+        let encrypted = EncryptedKeyFile::encrypt(b"secret", &passphrase, ROUNDS).unwrap();
+
+        let wrong = Passphrase::new("wrong".to_string());
+        assert!(encrypted.decrypt("key.json", &wrong).is_err());
+    }
+
+    #[test]
+    fn decrypt_tampered_ciphertext() {
+        let passphrase = Passphrase::new("correct horse battery staple".to_string());
+        let mut encrypted = EncryptedKeyFile::encrypt(b"secret", &passphrase, ROUNDS).unwrap();
+
+        let mut ciphertext = hex::decode(&encrypted.ciphertext).unwrap();
+        ciphertext[0] ^= 1;
+        encrypted.ciphertext = hex::encode(ciphertext);
+
+        assert!(encrypted.decrypt("key.json", &passphrase).is_err());
+    }
+
+    #[test]
+    fn passphrase_is_redacted() {
+        let passphrase = Passphrase::new("secret".to_string());
+        assert!(!format!("{passphrase:?}").contains("secret"));
+    }
+}
@@ -92,6 +92,92 @@ define_error! {
                     e.file_path)
             },
 
+        PassphraseUnavailable
+            {
+                env_var: String,
+                file_env_var: String,
+            }
+            |e| {
+                format!("the passphrase of the encrypted keyring must be set via the '{}' environment variable, or read from the file given by the '{}' environment variable",
+                    e.env_var, e.file_env_var)
+            },
+
+        PassphraseFileIo
+            { file_path: String }
+            [ TraceError<IoError> ]
+            |e| {
+                format!("I/O error while reading passphrase file at '{}'",
+                    e.file_path)
+            },
+
+        Encryption
+            |_| { "failed to encrypt key" },
+
+        Decryption
+            { file_path: String }
+            |e| {
+                format!("failed to decrypt key file at '{}', either the passphrase is wrong or the file is corrupted",
+                    e.file_path)
+            },
+
+        UnsupportedKeyFile
+            {
+                file_path: String,
+                reason: String,
+            }
+            |e| {
+                format!("unsupported encrypted key file at '{}': {}",
+                    e.file_path, e.reason)
+            },
+
+        RemoteSignerNotConfigured
+            |_| { "the remote key store requires the `remote_signer` setting of the chain to be set" },
+
+        RemoteSignerIo
+            { socket: String }
+            [ TraceError<IoError> ]
+            |e| {
+                format!("I/O error while talking to the remote signer at '{}'",
+                    e.socket)
+            },
+
+        RemoteSigner
+            {
+                socket: String,
+                reason: String,
+            }
+            |e| {
+                format!("remote signer at '{}' failed: {}",
+                    e.socket, e.reason)
+            },
+
+        InvalidRemoteSignature
+            {
+                key_name: String,
+                reason: String,
+            }
+            |e| {
+                format!("invalid signature returned by the remote signer for key '{}': {}",
+                    e.key_name, e.reason)
+            },
+
+        InvalidRemotePublicKey
+            { key_name: String }
+            [ TraceError<secp256k1::Error> ]
+            |e| {
+                format!("invalid public key returned by the remote signer for key '{}'",
+                    e.key_name)
+            },
+
+        RemoteSignerUnsupported
+            { key_type: KeyType }
+            |e| {
+                format!("remote signers are not supported for {} keys", e.key_type)
+            },
+
+        RemoteKeyManagement
+            |_| { "the keys of the remote key store are managed by the remote signer and cannot be added or removed by Hermes" },
+
         HomeLocationUnavailable
             |_| { "home location is unavailable" },
 
@@ -0,0 +1,260 @@
+//! Client for remote signers, ie. external processes which hold the private keys
+//! of the relayer and sign transactions on its behalf, so that the private keys
+//! never live in the relayer process.
+//!
+//! The relayer talks to the signer over a Unix domain socket, using a line-based
+//! JSON protocol: for each request, the relayer opens a new connection, writes a
+//! single [`RemoteSignerRequest`] followed by a newline, and reads back a single
+//! [`RemoteSignerResponse`] followed by a newline. Binary data is hex-encoded.
+//!
+//! For instance, signing the `SignDoc` of a transaction with the key `relayer` looks like:
+//!
+//! ```text
+//! > {"method":"sign","key_name":"relayer","sign_doc":"0a9f010a9c01..."}
+//! < {"signature":"5f0a4e..."}
+//! ```
+//!
+//! The signer is responsible for hashing the `SignDoc` as mandated by the key type,
+//! and must return a 64-byte compact ECDSA signature with a low S value.
+
+use core::time::Duration;
+use std::path::PathBuf;
+
+use serde::{Deserialize, Serialize};
+
+use super::errors::Error;
+
+/// Configuration of the remote signer of a chain.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct RemoteSignerConfig {
+    /// Path to the Unix domain socket the signer listens on
+    pub socket: PathBuf,
+
+    /// Timeout for each request to the signer
+    #[serde(default = "default_timeout", with = "humantime_serde")]
+    pub timeout: Duration,
+}
+
+impl RemoteSignerConfig {
+    pub const DEFAULT_TIMEOUT: Duration = Duration::from_secs(10);
+
+    pub fn new(socket: PathBuf) -> Self {
+        Self {
+            socket,
+            timeout: Self::DEFAULT_TIMEOUT,
+        }
+    }
+}
+
+fn default_timeout() -> Duration {
+    RemoteSignerConfig::DEFAULT_TIMEOUT
+}
+
+/// A request sent by the relayer to the remote signer.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "method", rename_all = "snake_case")]
+pub enum RemoteSignerRequest {
+    /// List the names of the keys held by the signer,
+    /// answered with [`RemoteSignerResponse::KeyNames`]
+    ListKeys,
+
+    /// Get the public key and the address of a key,
+    /// answered with [`RemoteSignerResponse::Key`]
+    GetKey { key_name: String },
+
+    /// Sign the given hex-encoded `SignDoc` bytes with a key,
+    /// answered with [`RemoteSignerResponse::Signature`]
+    Sign { key_name: String, sign_doc: String },
+}
+
+/// A response sent back by the remote signer.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RemoteSignerResponse {
+    KeyNames(Vec<String>),
+
+    Key {
+        /// Hex-encoded compressed public key
+        public_key: String,
+        /// Bech32-encoded address of the account
+        address: String,
+    },
+
+    /// Hex-encoded signature
+    Signature(String),
+
+    Error(String),
+}
+
+/// Client of a remote signer.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RemoteSignerClient {
+    config: RemoteSignerConfig,
+}
+
+impl RemoteSignerClient {
+    pub fn new(config: RemoteSignerConfig) -> Self {
+        Self { config }
+    }
+
+    pub fn socket(&self) -> String {
+        self.config.socket.display().to_string()
+    }
+
+    /// The names of the keys held by the signer.
+    pub fn list_keys(&self) -> Result<Vec<String>, Error> {
+        match self.request(&RemoteSignerRequest::ListKeys)? {
+            RemoteSignerResponse::KeyNames(key_names) => Ok(key_names),
+            response => Err(self.unexpected(response)),
+        }
+    }
+
+    /// The public key bytes and the address of the given key.
+    pub fn get_key(&self, key_name: &str) -> Result<(Vec<u8>, String), Error> {
+        let request = RemoteSignerRequest::GetKey {
+            key_name: key_name.to_string(),
+        };
+
+        match self.request(&request)? {
+            RemoteSignerResponse::Key {
+                public_key,
+                address,
+            } => {
+                let public_key = hex::decode(public_key).map_err(|e| {
+                    Error::remote_signer(self.socket(), format!("invalid public key: {e}"))
+                })?;
+
+                Ok((public_key, address))
+            }
+            response => Err(self.unexpected(response)),
+        }
+    }
+
+    /// Sign the given `SignDoc` bytes with the given key.
+    pub fn sign(&self, key_name: &str, sign_doc: &[u8]) -> Result<Vec<u8>, Error> {
+        let request = RemoteSignerRequest::Sign {
+            key_name: key_name.to_string(),
+            sign_doc: hex::encode(sign_doc),
+        };
+
+        match self.request(&request)? {
+            RemoteSignerResponse::Signature(signature) => hex::decode(signature)
+                .map_err(|e| Error::invalid_remote_signature(key_name.to_string(), e.to_string())),
+            response => Err(self.unexpected(response)),
+        }
+    }
+
+    fn unexpected(&self, response: RemoteSignerResponse) -> Error {
+        match response {
+            RemoteSignerResponse::Error(reason) => Error::remote_signer(self.socket(), reason),
+            response => {
+                Error::remote_signer(self.socket(), format!("unexpected response: {response:?}"))
+            }
+        }
+    }
+
+    #[cfg(unix)]
+    fn request(&self, request: &RemoteSignerRequest) -> Result<RemoteSignerResponse, Error> {
+        use std::io::{BufRead, BufReader, Write};
+        use std::os::unix::net::UnixStream;
+
+        let io_error = |e| Error::remote_signer_io(self.socket(), e);
+
+        let mut stream = UnixStream::connect(&self.config.socket).map_err(io_error)?;
+        stream
+            .set_read_timeout(Some(self.config.timeout))
+            .map_err(io_error)?;
+        stream
+            .set_write_timeout(Some(self.config.timeout))
+            .map_err(io_error)?;
+
+        let mut line = serde_json::to_string(request).map_err(Error::encode)?;
+        line.push('\n');
+        stream.write_all(line.as_bytes()).map_err(io_error)?;
+
+        let mut response = String::new();
+        BufReader::new(stream)
+            .read_line(&mut response)
+            .map_err(io_error)?;
+
+        serde_json::from_str(&response)
+            .map_err(|e| Error::remote_signer(self.socket(), format!("invalid response: {e}")))
+    }
+
+    #[cfg(not(unix))]
+    fn request(&self, _request: &RemoteSignerRequest) -> Result<RemoteSignerResponse, Error> {
+        Err(Error::remote_signer(
+            self.socket(),
+            "remote signers are only supported on Unix platforms".to_string(),
+        ))
+    }
+}
+
+/// Handle to a key held by a remote signer, used by key pairs
+/// which only know their public key to sign messages.
+#[derive(Clone, Debug)]
+pub struct RemoteSigner {
+    client: RemoteSignerClient,
+    key_name: String,
+}
+
+impl RemoteSigner {
+    pub fn new(client: RemoteSignerClient, key_name: String) -> Self {
+        Self { client, key_name }
+    }
+
+    pub fn key_name(&self) -> &str {
+        &self.key_name
+    }
+
+    pub fn sign(&self, sign_doc: &[u8]) -> Result<Vec<u8>, Error> {
+        self.client.sign(&self.key_name, sign_doc)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn request_wire_format() {
+        let request = RemoteSignerRequest::Sign {
+            key_name: "relayer".to_string(),
+            sign_doc: "0a0b".to_string(),
+        };
+
+        assert_eq!(
+            serde_json::to_string(&request).unwrap(),
+            r#"{"method":"sign","key_name":"relayer","sign_doc":"0a0b"}"#
+        );
+
+        assert_eq!(
+            serde_json::to_string(&RemoteSignerRequest::ListKeys).unwrap(),
+            r#"{"method":"list_keys"}"#
+        );
+    }
+
+    #[test]
+    fn response_wire_format() {
+        let response: RemoteSignerResponse =
+            serde_json::from_str(r#"{"key":{"public_key":"02ab","address":"cosmos1xyz"}}"#)
+                .unwrap();
+
+        assert_eq!(
+            response,
+            RemoteSignerResponse::Key {
+                public_key: "02ab".to_string(),
+                address: "cosmos1xyz".to_string(),
+            }
+        );
+
+        let response: RemoteSignerResponse =
+            serde_json::from_str(r#"{"error":"unknown key"}"#).unwrap();
+
+        assert_eq!(
+            response,
+            RemoteSignerResponse::Error("unknown key".to_string())
+        );
+    }
+}
@@ -9,8 +9,8 @@ use digest::Digest;
 use generic_array::{typenum::U32, GenericArray};
 use hdpath::StandardHDPath;
 use ripemd::Ripemd160;
-use secp256k1::{Message, PublicKey, Secp256k1, SecretKey};
-use serde::{Deserialize, Serialize};
+use secp256k1::{ecdsa::Signature, Message, PublicKey, Secp256k1, SecretKey};
+use serde::{Deserialize, Serialize, Serializer};
 use sha2::Sha256;
 use strum::{EnumIter, IntoEnumIterator};
 
@@ -18,6 +18,7 @@ use super::{
     errors::Error,
     key_utils::{decode_bech32, encode_bech32, keccak256_hash},
     pub_key::EncodedPubKey,
+    remote::RemoteSigner,
     KeyFile, KeyType, SigningKeyPair,
 };
 use crate::config::AddressType;
@@ -132,16 +133,52 @@ fn encode_address(account_prefix: &str, address: &[u8]) -> Result<String, Error>
 // This uses `VersionedKeyPair` to allow for backwards-
 // compatible deserialization.
 // /!\ /!\ /!\ /!\ /!\ /!\ /!\ /!\ /!\ /!\ /!\ /!\ /!\ /!\ /!\
-#[derive(Clone, Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Deserialize)]
 #[serde(try_from = "VersionedKeyPair")]
 pub struct Secp256k1KeyPair {
-    private_key: SecretKey,
+    signer: Secp256k1Signer,
     pub public_key: PublicKey,
     address: [u8; 20],
     address_type: Secp256k1AddressType,
     account: String,
 }
 
+/// Holder of the private key of a [`Secp256k1KeyPair`].
+#[derive(Clone, Debug)]
+enum Secp256k1Signer {
+    Local(SecretKey),
+    /// The private key is held by a remote signer, see the [`remote`](super::remote) module.
+    Remote(RemoteSigner),
+}
+
+impl Serialize for Secp256k1KeyPair {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match &self.signer {
+            Secp256k1Signer::Local(private_key) => KeyPairV2Ref {
+                private_key,
+                public_key: &self.public_key,
+                address: &self.address,
+                address_type: &self.address_type,
+                account: &self.account,
+            }
+            .serialize(serializer),
+
+            // Only the public part of keys held by a remote signer is known, so such keys
+            // cannot be stored and are only serialized for display purposes.
+            Secp256k1Signer::Remote(_) => RemoteKeyPairRef {
+                public_key: &self.public_key,
+                address: &self.address,
+                address_type: &self.address_type,
+                account: &self.account,
+            }
+            .serialize(serializer),
+        }
+    }
+}
+
 // The old `KeyEntry` type
 #[derive(Debug, Deserialize)]
 struct KeyPairV1 {
@@ -160,6 +197,23 @@ struct KeyPairV2 {
     account: String,
 }
 
+#[derive(Serialize)]
+struct KeyPairV2Ref<'a> {
+    private_key: &'a SecretKey,
+    public_key: &'a PublicKey,
+    address: &'a [u8; 20],
+    address_type: &'a Secp256k1AddressType,
+    account: &'a str,
+}
+
+#[derive(Serialize)]
+struct RemoteKeyPairRef<'a> {
+    public_key: &'a PublicKey,
+    address: &'a [u8; 20],
+    address_type: &'a Secp256k1AddressType,
+    account: &'a str,
+}
+
 // Note: Since this uses Serde's untagged enums, the serialized formats between
 // versions must be incompatible with each other.
 #[derive(Debug, Deserialize)]
@@ -185,7 +239,7 @@ impl TryFrom<VersionedKeyPair> for Secp256k1KeyPair {
                     .map_err(|address_bytes| Error::invalid_address_length(address_bytes, 20))?;
                 let address_type = Secp256k1AddressType::derive(&public_key.public_key, &address)?;
                 Ok(Self {
-                    private_key: private_key.private_key,
+                    signer: Secp256k1Signer::Local(private_key.private_key),
                     public_key: public_key.public_key,
                     address,
                     address_type,
@@ -199,7 +253,7 @@ impl TryFrom<VersionedKeyPair> for Secp256k1KeyPair {
                 address_type,
                 account,
             }) => Ok(Self {
-                private_key,
+                signer: Secp256k1Signer::Local(private_key),
                 public_key,
                 address,
                 address_type,
@@ -222,7 +276,7 @@ impl Secp256k1KeyPair {
         let account = encode_address(account_prefix, &address)?;
 
         Ok(Self {
-            private_key: private_key.private_key,
+            signer: Secp256k1Signer::Local(private_key.private_key),
             public_key: public_key.public_key,
             address,
             address_type,
@@ -268,7 +322,7 @@ impl SigningKeyPair for Secp256k1KeyPair {
         let address_type = Secp256k1AddressType::derive(&derived_pubkey.public_key, &address)?;
 
         Ok(Self {
-            private_key: private_key.private_key,
+            signer: Secp256k1Signer::Local(private_key.private_key),
             public_key: derived_pubkey.public_key,
             address,
             address_type,
@@ -285,6 +339,28 @@ impl SigningKeyPair for Secp256k1KeyPair {
         Self::from_mnemonic_internal(mnemonic, hd_path, address_type.try_into()?, account_prefix)
     }
 
+    fn from_remote_signer(
+        signer: RemoteSigner,
+        public_key: &[u8],
+        account: &str,
+    ) -> Result<Self, Error> {
+        let public_key = PublicKey::from_slice(public_key)
+            .map_err(|e| Error::invalid_remote_public_key(signer.key_name().to_string(), e))?;
+
+        let address: [u8; 20] = decode_bech32(account)?
+            .try_into()
+            .map_err(|address_bytes| Error::invalid_address_length(address_bytes, 20))?;
+        let address_type = Secp256k1AddressType::derive(&public_key, &address)?;
+
+        Ok(Self {
+            signer: Secp256k1Signer::Remote(signer),
+            public_key,
+            address,
+            address_type,
+            account: account.to_string(),
+        })
+    }
+
     fn account(&self) -> String {
         self.account.to_owned()
     }
@@ -304,12 +380,33 @@ impl SigningKeyPair for Secp256k1KeyPair {
 
         // SAFETY: hashed_message is 32 bytes, as expected in `Message::from_slice`,
         // so `unwrap` is safe.
-        let message = Message::from_slice(&hashed_message).unwrap();
-
-        Ok(Secp256k1::signing_only()
-            .sign_ecdsa(&message, &self.private_key)
-            .serialize_compact()
-            .to_vec())
+        let digest = Message::from_slice(&hashed_message).unwrap();
+
+        match &self.signer {
+            Secp256k1Signer::Local(private_key) => Ok(Secp256k1::signing_only()
+                .sign_ecdsa(&digest, private_key)
+                .serialize_compact()
+                .to_vec()),
+
+            Secp256k1Signer::Remote(remote) => {
+                let signature = remote.sign(message)?;
+
+                // Do not trust the remote signer blindly, and make sure that the signature
+                // is valid for our public key, and has a low S value as required by the SDK.
+                Signature::from_compact(&signature)
+                    .and_then(|sig| {
+                        Secp256k1::verification_only().verify_ecdsa(&digest, &sig, &self.public_key)
+                    })
+                    .map_err(|e| {
+                        Error::invalid_remote_signature(
+                            remote.key_name().to_string(),
+                            e.to_string(),
+                        )
+                    })?;
+
+                Ok(signature)
+            }
+        }
     }
 
     fn as_any(&self) -> &dyn Any {
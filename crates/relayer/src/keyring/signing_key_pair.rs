@@ -3,7 +3,7 @@ use core::any::Any;
 use hdpath::StandardHDPath;
 use serde::{de::DeserializeOwned, Serialize};
 
-use super::{errors::Error, KeyFile, KeyType};
+use super::{errors::Error, remote::RemoteSigner, KeyFile, KeyType};
 use crate::config::AddressType;
 
 pub trait SigningKeyPair {
@@ -30,6 +30,20 @@ pub trait SigningKeyPair {
     where
         Self: Sized;
 
+    /// Build a key pair whose private key is held by a remote signer,
+    /// from the public key and the account address reported by the signer.
+    fn from_remote_signer(
+        signer: RemoteSigner,
+        public_key: &[u8],
+        account: &str,
+    ) -> Result<Self, Error>
+    where
+        Self: Sized,
+    {
+        let _ = (signer, public_key, account);
+        Err(Error::remote_signer_unsupported(Self::KEY_TYPE))
+    }
+
     fn account(&self) -> String;
     fn sign(&self, message: &[u8]) -> Result<Vec<u8>, Error>;
 
@@ -21,6 +21,7 @@
 extern crate alloc;
 
 pub mod account;
+pub mod alerting;
 pub mod cache;
 pub mod chain;
 pub mod channel;
@@ -29,6 +30,7 @@ pub mod config;
 pub mod connection;
 pub mod consensus_state;
 pub mod denom;
+pub mod economics;
 pub mod error;
 pub mod event;
 pub mod extension_options;
@@ -39,10 +41,13 @@ pub mod link;
 pub mod misbehaviour;
 pub mod object;
 pub mod path;
+pub mod reconcile;
+pub mod recover_client;
 pub mod registry;
 pub mod rest;
 pub mod sdk_error;
 pub mod spawn;
+pub mod state_store;
 pub mod supervisor;
 pub mod telemetry;
 pub mod transfer;
@@ -6,13 +6,19 @@ use core::ops::Deref;
 use ibc_proto::google::protobuf::Any;
 use ibc_proto::ibc::lightclients::tendermint::v1::Header as RawTmHeader;
 use ibc_proto::protobuf::Protobuf as ErasedProtobuf;
+use ibc_relayer_types::clients::ics06_solomachine::header::{
+    Header as SmHeader, SOLOMACHINE_HEADER_TYPE_URL,
+};
 use ibc_relayer_types::clients::ics07_tendermint::header::{
     decode_header as tm_decode_header, Header as TendermintHeader, TENDERMINT_HEADER_TYPE_URL,
 };
+use ibc_relayer_types::clients::ics08_wasm::client_message::ClientMessage as WasmClientMessage;
 use ibc_relayer_types::core::ics02_client::client_type::ClientType;
 use ibc_relayer_types::core::ics02_client::error::Error;
 use ibc_relayer_types::core::ics02_client::events::UpdateClient;
 use ibc_relayer_types::core::ics02_client::header::Header;
+#[cfg(any(test, feature = "mocks"))]
+use ibc_relayer_types::mock::header::{MockHeader, MOCK_HEADER_TYPE_URL};
 use ibc_relayer_types::timestamp::Timestamp;
 use ibc_relayer_types::Height;
 use serde::{Deserialize, Serialize};
@@ -73,8 +79,16 @@ pub fn decode_header(header_bytes: &[u8]) -> Result<Box<dyn Header>, Error> {
     // For now, we only have tendermint; however when there is more than one, we
     // can try decoding into all the known types, and return an error only if
     // none work
-    let header: TendermintHeader =
-        ErasedProtobuf::<Any>::decode(header_bytes).map_err(Error::invalid_raw_header)?;
+    //
+    // Headers submitted to `08-wasm` clients are wrapped in a Wasm client message,
+    // which holds the encoded header passed to the contract of the client.
+    let header_bytes = match ErasedProtobuf::<Any>::decode(header_bytes) {
+        Ok(WasmClientMessage { data }) => data,
+        Err(_) => header_bytes.to_vec(),
+    };
+
+    let header: TendermintHeader = ErasedProtobuf::<Any>::decode(header_bytes.as_slice())
+        .map_err(Error::invalid_raw_header)?;
 
     Ok(Box::new(header))
 }
@@ -83,24 +97,36 @@ pub fn decode_header(header_bytes: &[u8]) -> Result<Box<dyn Header>, Error> {
 #[allow(clippy::large_enum_variant)]
 pub enum AnyHeader {
     Tendermint(TendermintHeader),
+    Solomachine(SmHeader),
+    #[cfg(any(test, feature = "mocks"))]
+    Mock(MockHeader),
 }
 
 impl Header for AnyHeader {
     fn client_type(&self) -> ClientType {
         match self {
             Self::Tendermint(header) => header.client_type(),
+            Self::Solomachine(header) => header.client_type(),
+            #[cfg(any(test, feature = "mocks"))]
+            Self::Mock(header) => header.client_type(),
         }
     }
 
     fn height(&self) -> Height {
         match self {
             Self::Tendermint(header) => header.height(),
+            Self::Solomachine(header) => header.height(),
+            #[cfg(any(test, feature = "mocks"))]
+            Self::Mock(header) => header.height(),
         }
     }
 
     fn timestamp(&self) -> Timestamp {
         match self {
             Self::Tendermint(header) => header.timestamp(),
+            Self::Solomachine(header) => header.timestamp(),
+            #[cfg(any(test, feature = "mocks"))]
+            Self::Mock(header) => header.timestamp(),
         }
     }
 }
@@ -118,6 +144,11 @@ impl TryFrom<Any> for AnyHeader {
                 Ok(AnyHeader::Tendermint(val))
             }
 
+            SOLOMACHINE_HEADER_TYPE_URL => Ok(AnyHeader::Solomachine(SmHeader::try_from(raw)?)),
+
+            #[cfg(any(test, feature = "mocks"))]
+            MOCK_HEADER_TYPE_URL => Ok(AnyHeader::Mock(MockHeader::try_from(raw)?)),
+
             _ => Err(Error::unknown_header_type(raw.type_url)),
         }
     }
@@ -130,6 +161,9 @@ impl From<AnyHeader> for Any {
                 type_url: TENDERMINT_HEADER_TYPE_URL.to_string(),
                 value: ErasedProtobuf::<RawTmHeader>::encode_vec(&header),
             },
+            AnyHeader::Solomachine(header) => header.into(),
+            #[cfg(any(test, feature = "mocks"))]
+            AnyHeader::Mock(header) => header.into(),
         }
     }
 }
@@ -139,3 +173,16 @@ impl From<TendermintHeader> for AnyHeader {
         Self::Tendermint(header)
     }
 }
+
+impl From<SmHeader> for AnyHeader {
+    fn from(header: SmHeader) -> Self {
+        Self::Solomachine(header)
+    }
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl From<MockHeader> for AnyHeader {
+    fn from(header: MockHeader) -> Self {
+        Self::Mock(header)
+    }
+}
@@ -28,7 +28,7 @@ use ibc_relayer_types::{
         ics02_client::{client_type::ClientType, events::UpdateClient, header::downcast_header},
         ics24_host::identifier::ChainId,
     },
-    downcast, Height as ICSHeight,
+    Height as ICSHeight,
 };
 
 use crate::{
@@ -154,13 +154,12 @@ impl super::LightClient<CosmosSdkChain> for LightClient {
                 ))
             })?;
 
-        let client_state =
-            downcast!(client_state => AnyClientState::Tendermint).ok_or_else(|| {
-                Error::misbehaviour(format!(
-                    "client type incompatible for chain {}",
-                    self.chain_id
-                ))
-            })?;
+        let client_state = client_state.as_tendermint().ok_or_else(|| {
+            Error::misbehaviour(format!(
+                "client type incompatible for chain {}",
+                self.chain_id
+            ))
+        })?;
 
         let next_validators = self
             .io
@@ -310,10 +309,9 @@ impl LightClient {
         let verifier = ProdVerifier::default();
         let scheduler = components::scheduler::basic_bisecting_schedule;
 
-        let client_state =
-            downcast!(client_state => AnyClientState::Tendermint).ok_or_else(|| {
-                Error::client_type_mismatch(ClientType::Tendermint, client_state.client_type())
-            })?;
+        let client_state = client_state.as_tendermint().ok_or_else(|| {
+            Error::client_type_mismatch(ClientType::Tendermint, client_state.client_type())
+        })?;
 
         Ok(TmLightClient::new(
             self.peer_id,
@@ -9,7 +9,9 @@ use crate::chain::requests::{QueryChannelRequest, QueryHeight};
 use crate::chain::{counterparty::check_channel_counterparty, requests::QueryConnectionRequest};
 use crate::chain::{handle::ChainHandle, requests::IncludeProof};
 use crate::channel::{Channel, ChannelSide};
+use crate::config::channel_overrides::ChannelOverrides;
 use crate::link::error::LinkError;
+use crate::state_store::StateStore;
 
 pub mod cli;
 pub mod error;
@@ -28,6 +30,7 @@ use tx_hashes::TxHashes;
 pub use relay_summary::RelaySummary;
 
 pub use relay_path::{RelayPath, Resubmit};
+pub use relay_sender::SimulatedTx;
 
 #[derive(Clone, Debug)]
 pub struct LinkParameters {
@@ -49,6 +52,29 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> Link<ChainA, ChainB> {
         })
     }
 
+    /// Persist the relaying state of this link in the given store, if any.
+    pub fn with_state_store(mut self, state_store: Option<StateStore>) -> Self {
+        self.a_to_b.state_store = state_store;
+        self
+    }
+
+    /// Simulate the transactions of this link to estimate their fees, instead of
+    /// submitting them, if `dry_run` is set.
+    pub fn with_dry_run(mut self, dry_run: bool) -> Self {
+        self.a_to_b.dry_run = dry_run;
+        self
+    }
+
+    /// Apply the relaying settings configured for the channel of this link, if any.
+    pub fn with_channel_overrides(mut self, overrides: Option<ChannelOverrides>) -> Self {
+        if let Some(tx_confirmation) = overrides.as_ref().and_then(|o| o.tx_confirmation) {
+            self.a_to_b.confirm_txes = tx_confirmation;
+        }
+
+        self.a_to_b.channel_overrides = overrides;
+        self
+    }
+
     pub fn new_from_opts(
         a_chain: ChainA,
         b_chain: ChainB,
@@ -94,6 +94,8 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> Link<ChainA, ChainB> {
         )
         .map_err(LinkError::supervisor)?;
 
+        let sequences = self.a_to_b.remove_excluded_packet_sequences(sequences)?;
+
         if sequences.is_empty() {
             return Ok(vec![]);
         }
@@ -143,6 +145,8 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> Link<ChainA, ChainB> {
         )
         .map_err(LinkError::supervisor)? else { return Ok(vec![]) };
 
+        let sequences = self.a_to_b.remove_excluded_ack_sequences(sequences)?;
+
         if sequences.is_empty() {
             return Ok(vec![]);
         }
@@ -39,6 +39,13 @@ define_error! {
                 format!("failed during query to chain id {0}", e.chain_id)
             },
 
+        FeeEstimation
+            { chain_id: ChainId }
+            [ Error ]
+            |e| {
+                format!("failed to estimate the fee of relaying packets to chain {0}", e.chain_id)
+            },
+
         Channel
             [ ChannelError ]
             |_| { "channel error" },
@@ -6,6 +6,7 @@ use ibc_proto::google::protobuf::Any;
 use tracing::{debug, info};
 
 use ibc_relayer_types::core::ics02_client::client_state::ClientState;
+use ibc_relayer_types::signer::Signer;
 use ibc_relayer_types::Height;
 
 use crate::chain::handle::ChainHandle;
@@ -66,6 +67,11 @@ impl TrackedEvents {
     pub fn len(&self) -> usize {
         self.events.len()
     }
+
+    /// Keep only the events satisfying the given predicate.
+    pub fn retain(&mut self, f: impl FnMut(&IbcEventWithHeight) -> bool) {
+        self.events.retain(f)
+    }
 }
 
 /// A packet message that is prepared for sending
@@ -98,6 +104,9 @@ pub struct OperationalData {
     pub tracking_id: TrackingId,
     /// Stores `Some(ConnectionDelay)` if the delay is non-zero and `None` otherwise
     connection_delay: Option<ConnectionDelay>,
+    /// The signer of all the messages of the batch, including the client update
+    /// message prepended to them, ie. the address of the wallet submitting them
+    pub signer: Signer,
 }
 
 impl OperationalData {
@@ -106,6 +115,7 @@ impl OperationalData {
         target: OperationalDataTarget,
         tracking_id: TrackingId,
         connection_delay: Duration,
+        signer: Signer,
     ) -> Self {
         let connection_delay = if !connection_delay.is_zero() {
             Some(ConnectionDelay::new(connection_delay))
@@ -119,6 +129,7 @@ impl OperationalData {
             target,
             connection_delay,
             tracking_id,
+            signer,
         }
     }
 
@@ -170,10 +181,10 @@ impl OperationalData {
             // for the requested height.
             let mut client_update_opt = match self.target {
                 OperationalDataTarget::Source => {
-                    relay_path.build_update_client_on_src(update_height)?
+                    relay_path.build_update_client_on_src(update_height, &self.signer)?
                 }
                 OperationalDataTarget::Destination => {
-                    relay_path.build_update_client_on_dst(update_height)?
+                    relay_path.build_update_client_on_dst(update_height, &self.signer)?
                 }
             };
 
@@ -215,7 +226,12 @@ impl OperationalData {
             .chain(self.batch.iter().map(|gm| gm.msg.clone()))
             .collect();
 
-        let tm = TrackedMsgs::new(msgs, self.tracking_id);
+        let tm = TrackedMsgs::new(msgs, self.tracking_id).with_signer(self.signer.clone());
+
+        let tm = match relay_path.channel_overrides() {
+            Some(overrides) => tm.with_batch_limits(overrides.max_msg_num, overrides.max_tx_size),
+            None => tm,
+        };
 
         info!("assembled batch of {} message(s)", tm.messages().len());
 
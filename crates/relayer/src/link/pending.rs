@@ -73,8 +73,9 @@ impl<Chain: ChainHandle> PendingTxs<Chain> {
         self.chain.id()
     }
 
-    /// Insert a new pending transaction to the back of the queue.
-    pub fn insert_new_pending_tx(&self, r: AsyncReply, od: OperationalData) {
+    /// Insert a new pending transaction to the back of the queue,
+    /// returning the hashes of the transactions to confirm.
+    pub fn insert_new_pending_tx(&self, r: AsyncReply, od: OperationalData) -> TxHashes {
         let mut tx_hashes = Vec::new();
         let mut error_events = Vec::new();
 
@@ -123,7 +124,11 @@ impl<Chain: ChainHandle> PendingTxs<Chain> {
             error_events,
         };
 
+        let tx_hashes = u.tx_hashes.clone();
+
         self.pending_queue.push_back(u);
+
+        tx_hashes
     }
 
     fn check_tx_events(&self, tx_hashes: &TxHashes) -> Result<Option<Vec<IbcEvent>>, RelayerError> {
@@ -203,7 +208,19 @@ impl<Chain: ChainHandle> PendingTxs<Chain> {
 
                                 match new_od.map(f) {
                                     Some(Ok(reply)) => {
-                                        self.insert_new_pending_tx(reply, pending.original_od);
+                                        relay_path.remove_persisted_tx(tx_hashes);
+
+                                        let tracking_id = pending.tracking_id();
+                                        let target = pending.original_od.target;
+                                        let new_tx_hashes =
+                                            self.insert_new_pending_tx(reply, pending.original_od);
+
+                                        relay_path.persist_pending_tx(
+                                            tracking_id,
+                                            target,
+                                            new_tx_hashes,
+                                        );
+
                                         Ok(None)
                                     }
                                     Some(Err(e)) => {
@@ -212,12 +229,14 @@ impl<Chain: ChainHandle> PendingTxs<Chain> {
                                     }
                                     None => {
                                         // No operational data was regenerated; nothing to resubmit
+                                        relay_path.remove_persisted_tx(tx_hashes);
                                         Ok(None)
                                     }
                                 }
                             }
                             None => {
                                 // `clear_interval != 0` such that resubmission has been disabled
+                                relay_path.remove_persisted_tx(tx_hashes);
                                 Ok(None)
                             }
                         }
@@ -251,6 +270,9 @@ impl<Chain: ChainHandle> PendingTxs<Chain> {
                         &self.counterparty_chain_id
                     );
 
+                    relay_path.untrack_operational_data(&pending.original_od);
+                    relay_path.remove_persisted_tx(tx_hashes);
+
                     // Append the events corresponding to errors from the pending tx.
                     events.extend(pending.error_events);
 
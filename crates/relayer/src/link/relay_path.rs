@@ -1,5 +1,5 @@
 use alloc::collections::BTreeMap as HashMap;
-use alloc::collections::VecDeque;
+use alloc::collections::{BTreeSet, VecDeque};
 use std::ops::Sub;
 use std::time::{Duration, Instant};
 
@@ -7,6 +7,7 @@ use ibc_proto::google::protobuf::Any;
 use itertools::Itertools;
 use tracing::{debug, error, info, span, trace, warn, Level};
 
+use ibc_relayer_types::applications::transfer::RawCoin;
 use ibc_relayer_types::core::ics02_client::events::ClientMisbehaviour as ClientMisbehaviourEvent;
 use ibc_relayer_types::core::ics04_channel::channel::{
     ChannelEnd, Ordering, State as ChannelState,
@@ -16,7 +17,7 @@ use ibc_relayer_types::core::ics04_channel::msgs::{
     acknowledgement::MsgAcknowledgement, chan_close_confirm::MsgChannelCloseConfirm,
     recv_packet::MsgRecvPacket, timeout::MsgTimeout, timeout_on_close::MsgTimeoutOnClose,
 };
-use ibc_relayer_types::core::ics04_channel::packet::{Packet, PacketMsgType};
+use ibc_relayer_types::core::ics04_channel::packet::{Packet, PacketMsgType, Sequence};
 use ibc_relayer_types::core::ics24_host::identifier::{ChannelId, ClientId, ConnectionId, PortId};
 use ibc_relayer_types::events::{IbcEvent, IbcEventType, WithBlockDataType};
 use ibc_relayer_types::signer::Signer;
@@ -34,6 +35,7 @@ use crate::chain::requests::QueryHeight;
 use crate::chain::requests::QueryHostConsensusStateRequest;
 use crate::chain::requests::QueryNextSequenceReceiveRequest;
 use crate::chain::requests::QueryPacketCommitmentRequest;
+use crate::chain::requests::QueryTxHash;
 use crate::chain::requests::QueryTxRequest;
 use crate::chain::requests::QueryUnreceivedAcksRequest;
 use crate::chain::requests::QueryUnreceivedPacketsRequest;
@@ -42,6 +44,7 @@ use crate::chain::tracking::TrackedMsgs;
 use crate::chain::tracking::TrackingId;
 use crate::channel::error::ChannelError;
 use crate::channel::Channel;
+use crate::config::channel_overrides::ChannelOverrides;
 use crate::event::monitor::EventBatch;
 use crate::event::IbcEventWithHeight;
 use crate::foreign_client::{ForeignClient, ForeignClientError};
@@ -53,10 +56,12 @@ use crate::link::packet_events::query_packet_events_with;
 use crate::link::packet_events::query_send_packet_events;
 use crate::link::packet_events::query_write_ack_events;
 use crate::link::pending::PendingTxs;
-use crate::link::relay_sender::{AsyncReply, SubmitReply};
+use crate::link::relay_sender::{AsyncReply, SimulatedTx, SubmitReply};
 use crate::link::relay_summary::RelaySummary;
+use crate::link::TxHashes;
 use crate::link::{pending, relay_sender};
 use crate::path::PathIdentifiers;
+use crate::state_store::{path_key, PendingTx, StateStore};
 use crate::telemetry;
 use crate::util::collate::CollatedIterExt;
 use crate::util::pretty::PrettyEvents;
@@ -64,6 +69,11 @@ use crate::util::queue::Queue;
 
 const MAX_RETRIES: usize = 5;
 
+/// Maximum number of blocks of the source chain scanned for the packets to relay
+/// when resuming relaying from a persisted state, beyond which pending packets
+/// are cleared instead.
+pub const MAX_RESUMED_HEIGHTS: u64 = 1000;
+
 /// Whether or not to resubmit packets when pending transactions
 /// fail to process within the given timeout duration.
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
@@ -101,13 +111,29 @@ pub struct RelayPath<ChainA: ChainHandle, ChainB: ChainHandle> {
     pub dst_operational_data: Queue<OperationalData>,
 
     // Toggle for the transaction confirmation mechanism.
-    confirm_txes: bool,
+    pub(crate) confirm_txes: bool,
 
     // Stores pending (i.e., unconfirmed) operational data.
     // The relaying path periodically tries to confirm these pending
     // transactions if [`confirm_txes`] is true.
     pending_txs_src: PendingTxs<ChainA>,
     pending_txs_dst: PendingTxs<ChainB>,
+
+    // Stores the relaying state on disk, if enabled, so that
+    // it can be reconciled against the chains after a restart.
+    pub(crate) state_store: Option<StateStore>,
+
+    // Relaying settings configured for the channel of this path,
+    // which take precedence over the global and chain settings.
+    pub(crate) channel_overrides: Option<ChannelOverrides>,
+
+    // Toggle for the dry-run mode, in which the transactions are
+    // simulated, to estimate their fees, instead of being submitted.
+    pub(crate) dry_run: bool,
+
+    // Stores the transactions simulated in dry-run mode,
+    // until they are taken to be reported.
+    simulated_txs: Queue<SimulatedTx>,
 }
 
 impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
@@ -152,6 +178,12 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
             confirm_txes: with_tx_confirmation,
             pending_txs_src: PendingTxs::new(src_chain, src_channel_id, src_port_id, dst_chain_id),
             pending_txs_dst: PendingTxs::new(dst_chain, dst_channel_id, dst_port_id, src_chain_id),
+
+            state_store: None,
+            channel_overrides: None,
+
+            dry_run: false,
+            simulated_txs: Queue::new(),
         })
     }
 
@@ -199,6 +231,11 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
         &self.channel
     }
 
+    /// The relaying settings configured for the channel of this path, if any.
+    pub fn channel_overrides(&self) -> Option<&ChannelOverrides> {
+        self.channel_overrides.as_ref()
+    }
+
     fn src_channel(&self, height_query: QueryHeight) -> Result<ChannelEnd, LinkError> {
         self.src_chain()
             .query_channel(
@@ -227,15 +264,23 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
             .map_err(|e| LinkError::channel(ChannelError::query(self.dst_chain().id(), e)))
     }
 
-    fn src_signer(&self) -> Result<Signer, LinkError> {
-        self.src_chain()
+    fn dst_signer(&self) -> Result<Signer, LinkError> {
+        self.dst_chain()
             .get_signer()
+            .map_err(|e| LinkError::signer(self.dst_chain().id(), e))
+    }
+
+    /// Select the signer of a batch of messages to submit to the source chain.
+    fn select_src_signer(&self) -> Result<Signer, LinkError> {
+        self.src_chain()
+            .select_signer()
             .map_err(|e| LinkError::signer(self.src_chain().id(), e))
     }
 
-    fn dst_signer(&self) -> Result<Signer, LinkError> {
+    /// Select the signer of a batch of messages to submit to the destination chain.
+    fn select_dst_signer(&self) -> Result<Signer, LinkError> {
         self.dst_chain()
-            .get_signer()
+            .select_signer()
             .map_err(|e| LinkError::signer(self.dst_chain().id(), e))
     }
 
@@ -313,23 +358,66 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
         self.channel.ordering == Ordering::Ordered
     }
 
-    pub fn build_update_client_on_dst(&self, height: Height) -> Result<Vec<Any>, LinkError> {
+    pub fn build_update_client_on_dst(
+        &self,
+        height: Height,
+        signer: &Signer,
+    ) -> Result<Vec<Any>, LinkError> {
         let client = self.restore_dst_client();
         client
-            .wait_and_build_update_client(height)
+            .wait_and_build_update_client_with_signer(height, signer)
             .map_err(LinkError::client)
     }
 
-    pub fn build_update_client_on_src(&self, height: Height) -> Result<Vec<Any>, LinkError> {
+    pub fn build_update_client_on_src(
+        &self,
+        height: Height,
+        signer: &Signer,
+    ) -> Result<Vec<Any>, LinkError> {
         let client = self.restore_src_client();
         client
-            .wait_and_build_update_client(height)
+            .wait_and_build_update_client_with_signer(height, signer)
             .map_err(LinkError::client)
     }
 
+    /// Estimates the fee to pay on the destination chain for receiving the given packets,
+    /// sent at `height` on the source chain, by simulating the transaction which updates
+    /// the client on the destination chain, if needed, and receives the packets.
+    pub fn estimate_recv_packets_fee(
+        &self,
+        packets: &[Packet],
+        height: Height,
+    ) -> Result<Vec<RawCoin>, LinkError> {
+        let signer = self.dst_signer()?;
+
+        let mut msgs = self
+            .build_update_client_on_dst(height.increment(), &signer)?
+            .pop()
+            .into_iter()
+            .collect::<Vec<_>>();
+
+        for packet in packets {
+            msgs.extend(self.build_recv_packet(packet, height, &signer)?);
+        }
+
+        let fee = self
+            .dst_chain()
+            .estimate_tx_fee(
+                TrackedMsgs::new_static(msgs, "estimate recv packets fee").with_signer(signer),
+            )
+            .map_err(|e| LinkError::fee_estimation(self.dst_chain().id(), e))?;
+
+        Ok(fee
+            .amount
+            .into_iter()
+            .filter_map(|coin| RawCoin::try_from(coin).ok())
+            .collect())
+    }
+
     fn build_chan_close_confirm_from_event(
         &self,
         event: &IbcEventWithHeight,
+        signer: &Signer,
     ) -> Result<Option<Any>, LinkError> {
         // Build the `MsgChannelCloseConfirm` only from `Timeout` or `CloseInitChannel` event types
         if event.event.event_type() != IbcEventType::Timeout
@@ -357,7 +445,7 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
             port_id: self.dst_port_id().clone(),
             channel_id: self.dst_channel_id().clone(),
             proofs,
-            signer: self.dst_signer()?,
+            signer: signer.clone(),
         };
 
         Ok(Some(new_msg.to_any()))
@@ -404,6 +492,15 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
                         result.push(event_with_height);
                     }
                 }
+                // Only used to measure the end-to-end latency of the packets
+                #[cfg(feature = "telemetry")]
+                IbcEvent::AcknowledgePacket(ack_ev) => {
+                    if src_channel_id == ack_ev.src_channel_id()
+                        && self.src_port_id() == ack_ev.src_port_id()
+                    {
+                        result.push(event_with_height);
+                    }
+                }
                 _ => {}
             }
         }
@@ -464,21 +561,22 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
         let events = self.filter_relaying_events(batch.events, batch.tracking_id);
 
         // Update telemetry info
-        telemetry!({
-            for event_with_height in events.events() {
-                self.backlog_update(&event_with_height.event);
-            }
-        });
+        telemetry!(self.backlog_update(events.events()));
 
         // Transform the events into operational data items
         self.events_to_operational_data(events)
     }
 
-    /// Produces and schedules operational data for this relaying path based on the input events.
+    /// Produces and schedules operational data for this relaying path based on the input events,
+    /// skipping the packets whose sequence is excluded in the configuration.
     pub(crate) fn events_to_operational_data(
         &self,
         events: TrackedEvents,
     ) -> Result<(), LinkError> {
+        let events = self.remove_excluded_events(events)?;
+
+        self.track_events(events.events().iter().map(|ev| &ev.event));
+
         // Obtain the operational data for the source chain (mostly timeout packets) and for the
         // destination chain (e.g., receive packet messages).
         let (src_opt, dst_opt) = self.generate_operational_data(events)?;
@@ -513,7 +611,12 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
         .entered();
 
         let input = events.events();
-        let src_height = match input.get(0) {
+
+        // No message is built from the `AcknowledgePacket` events
+        let src_height = match input
+            .iter()
+            .find(|ev| !matches!(ev.event, IbcEvent::AcknowledgePacket(_)))
+        {
             None => return Ok((None, None)),
             Some(ev) => ev.height,
         };
@@ -525,12 +628,18 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
 
         let dst_latest_height = dst_latest_info.height;
 
+        // All the messages of a batch are signed by the same signer,
+        // ie. by the wallet selected to submit that batch
+        let src_signer = self.select_src_signer()?;
+        let dst_signer = self.select_dst_signer()?;
+
         // Operational data targeting the source chain (e.g., Timeout packets)
         let mut src_od = OperationalData::new(
             dst_latest_height,
             OperationalDataTarget::Source,
             events.tracking_id(),
             self.channel.connection_delay,
+            src_signer,
         );
 
         // Operational data targeting the destination chain (e.g., SendPacket messages)
@@ -539,6 +648,7 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
             OperationalDataTarget::Destination,
             events.tracking_id(),
             self.channel.connection_delay,
+            dst_signer,
         );
 
         for event_with_height in input {
@@ -546,7 +656,7 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
 
             let (dst_msg, src_msg) = match &event_with_height.event {
                 IbcEvent::CloseInitChannel(_) => (
-                    self.build_chan_close_confirm_from_event(event_with_height)?,
+                    self.build_chan_close_confirm_from_event(event_with_height, &dst_od.signer)?,
                     None,
                 ),
                 IbcEvent::TimeoutPacket(_) => {
@@ -561,7 +671,10 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
                             .state_matches(&ChannelState::Closed)
                     {
                         (
-                            self.build_chan_close_confirm_from_event(event_with_height)?,
+                            self.build_chan_close_confirm_from_event(
+                                event_with_height,
+                                &dst_od.signer,
+                            )?,
                             None,
                         )
                     } else {
@@ -578,6 +691,8 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
                             event,
                             &dst_latest_info,
                             event_with_height.height,
+                            &src_od.signer,
+                            &dst_od.signer,
                         )?
                     }
                 }
@@ -596,7 +711,11 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
                         (None, None)
                     } else {
                         (
-                            self.build_ack_from_recv_event(event, event_with_height.height)?,
+                            self.build_ack_from_recv_event(
+                                event,
+                                event_with_height.height,
+                                &dst_od.signer,
+                            )?,
                             None,
                         )
                     }
@@ -644,6 +763,11 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
         // We will operate on potentially different operational data if the initial one fails.
         let _span = span!(Level::INFO, "relay", odata = %initial_od.info()).entered();
 
+        if self.dry_run {
+            self.simulate_operational_data(&initial_od)?;
+            return Ok(S::Reply::empty());
+        }
+
         let mut odata = initial_od;
 
         for i in 0..MAX_RETRIES {
@@ -797,21 +921,321 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
         }
     }
 
+    /// Simulates the transaction which would relay the given [`OperationalData`]
+    /// to the corresponding target chain, without submitting it, and stores it
+    /// along with its estimated fee.
+    fn simulate_operational_data(&self, odata: &OperationalData) -> Result<(), LinkError> {
+        if odata.batch.is_empty() {
+            error!("ignoring empty operational data!");
+            return Ok(());
+        }
+
+        let msgs = odata.assemble_msgs(self)?;
+
+        let simulated_tx = match odata.target {
+            OperationalDataTarget::Source => relay_sender::simulate(self.src_chain(), msgs)?,
+            OperationalDataTarget::Destination => relay_sender::simulate(self.dst_chain(), msgs)?,
+        };
+
+        self.simulated_txs.push_back(simulated_tx);
+
+        Ok(())
+    }
+
+    /// Takes the transactions simulated in dry-run mode since they were last taken.
+    pub fn take_simulated_txs(&self) -> Vec<SimulatedTx> {
+        self.simulated_txs.take().into()
+    }
+
     fn enqueue_pending_tx(&self, reply: AsyncReply, odata: OperationalData) {
+        if self.dry_run {
+            // Nothing was submitted, so there is no transaction to confirm
+            return;
+        }
+
         if !self.confirm_txes {
+            // The transactions are not confirmed, so the packets
+            // are considered relayed as soon as they are submitted
+            self.untrack_operational_data(&odata);
             return;
         }
 
+        let tracking_id = odata.tracking_id;
+
         match odata.target {
             OperationalDataTarget::Source => {
-                self.pending_txs_src.insert_new_pending_tx(reply, odata);
+                let tx_hashes = self.pending_txs_src.insert_new_pending_tx(reply, odata);
+                self.persist_pending_tx(tracking_id, OperationalDataTarget::Source, tx_hashes);
             }
             OperationalDataTarget::Destination => {
-                self.pending_txs_dst.insert_new_pending_tx(reply, odata);
+                let tx_hashes = self.pending_txs_dst.insert_new_pending_tx(reply, odata);
+                self.persist_pending_tx(tracking_id, OperationalDataTarget::Destination, tx_hashes);
             }
         }
     }
 
+    /// The key of the state of this path in the [`StateStore`].
+    fn state_key(&self) -> String {
+        path_key(
+            &self.src_chain().id(),
+            self.src_port_id(),
+            self.src_channel_id(),
+        )
+    }
+
+    /// Whether a relaying state was persisted for this path before the relayer was
+    /// restarted, in which case relaying is resumed from it on start.
+    pub fn has_persisted_state(&self) -> bool {
+        self.state_store.as_ref().map_or(false, |store| {
+            store.has_restored_path_state(&self.state_key())
+        })
+    }
+
+    /// Marks the packets and acknowledgements of the given events as being relayed in the state store.
+    fn track_events<'a>(&self, events: impl Iterator<Item = &'a IbcEvent>) {
+        if let Some(store) = &self.state_store {
+            let (packets, acks) = packet_and_ack_sequences(events);
+            store.track(&self.state_key(), &packets, &acks);
+        }
+    }
+
+    /// Marks the packets and acknowledgements of the given operational data as relayed in the state store.
+    pub(crate) fn untrack_operational_data(&self, odata: &OperationalData) {
+        if let Some(store) = &self.state_store {
+            let (packets, acks) = packet_and_ack_sequences(
+                odata.batch.iter().map(|msg| &msg.event_with_height.event),
+            );
+            store.untrack(&self.state_key(), &packets, &acks);
+        }
+    }
+
+    /// Persists the hashes of the transactions submitted to the target chain which are not confirmed yet.
+    pub(crate) fn persist_pending_tx(
+        &self,
+        tracking_id: TrackingId,
+        target: OperationalDataTarget,
+        tx_hashes: TxHashes,
+    ) {
+        if let Some(store) = &self.state_store {
+            let chain_id = match target {
+                OperationalDataTarget::Source => self.src_chain().id(),
+                OperationalDataTarget::Destination => self.dst_chain().id(),
+            };
+
+            let pending_tx = PendingTx {
+                tracking_id: tracking_id.to_string(),
+                chain_id,
+                tx_hashes: tx_hashes.into(),
+            };
+
+            store.insert_pending_tx(&self.state_key(), pending_tx);
+        }
+    }
+
+    /// Removes the transactions with the given hashes from the persisted pending transactions.
+    pub(crate) fn remove_persisted_tx(&self, tx_hashes: &TxHashes) {
+        if let Some(store) = &self.state_store {
+            store.remove_pending_tx(&self.state_key(), &tx_hashes.0);
+        }
+    }
+
+    /// Resumes relaying from the state persisted for this path before the relayer was restarted,
+    /// instead of clearing all the pending packets. Returns whether relaying was resumed, or
+    /// whether the pending packets must be cleared instead.
+    ///
+    /// The transactions which were pending when the relayer stopped are first reconciled against
+    /// the chains. Then, out of the packets and acknowledgements which are still not received by
+    /// the destination chain, the ones which were tracked as in flight are relayed, as well as the
+    /// ones sent or written on the source chain from the last height handled before the restart
+    /// up to the given height, excluded.
+    ///
+    /// Relaying cannot be resumed on ordered channels, on which the packets must be relayed in
+    /// order, or if no height of the source chain was handled in the same revision, or more than
+    /// [`MAX_RESUMED_HEIGHTS`] blocks ago.
+    pub fn resume_persisted_state(&self, height: Option<Height>) -> Result<bool, LinkError> {
+        let Some(store) = &self.state_store else { return Ok(false) };
+        let key = self.state_key();
+        let Some(path_state) = store.take_restored_path_state(&key) else { return Ok(false) };
+
+        let _span = span!(Level::ERROR, "resume_persisted_state", ?height).entered();
+
+        for pending_tx in &path_state.pending_txs {
+            self.reconcile_pending_tx(pending_tx);
+        }
+
+        if self.ordered_channel() {
+            return Ok(false);
+        }
+
+        let last_handled_height = match store.restored_last_handled_height(&self.src_chain().id()) {
+            Some(height) => height,
+            None => {
+                warn!("no height of the source chain was handled before the restart, relaying cannot be resumed");
+                return Ok(false);
+            }
+        };
+
+        let (packet_sequences, src_response_height) =
+            unreceived_packets(self.dst_chain(), self.src_chain(), &self.path_id)
+                .map_err(LinkError::supervisor)?;
+        let packet_sequences = self.remove_excluded_packet_sequences(packet_sequences)?;
+
+        let ack_sequences =
+            unreceived_acknowledgements(self.dst_chain(), self.src_chain(), &self.path_id)
+                .map_err(LinkError::supervisor)?
+                .map(|(sequences, _)| sequences)
+                .unwrap_or_default();
+        let ack_sequences = self.remove_excluded_ack_sequences(ack_sequences)?;
+
+        // Stop tracking the packets and acknowledgements received while the relayer was stopped
+        store.retain_tracked_packets(&key, &packet_sequences);
+        store.retain_tracked_acks(&key, &ack_sequences);
+
+        let end_height = match height {
+            Some(height) => height
+                .decrement()
+                .map_err(|e| LinkError::decrement_height(height, e))?,
+            None => src_response_height,
+        };
+
+        if last_handled_height.revision_number() != end_height.revision_number()
+            || end_height.revision_height()
+                > last_handled_height.revision_height() + MAX_RESUMED_HEIGHTS
+        {
+            warn!(
+                %last_handled_height,
+                %end_height,
+                "the last height handled before the restart is too old, relaying cannot be resumed"
+            );
+            return Ok(false);
+        }
+
+        let (tracked_packets, untracked_packets): (Vec<_>, Vec<_>) = packet_sequences
+            .into_iter()
+            .partition(|sequence| path_state.tracked_packets.contains(sequence));
+        let (tracked_acks, untracked_acks): (Vec<_>, Vec<_>) = ack_sequences
+            .into_iter()
+            .partition(|sequence| path_state.tracked_acks.contains(sequence));
+
+        info!(
+            %last_handled_height,
+            %end_height,
+            tracked_packets = %tracked_packets.iter().copied().collated().format(", "),
+            tracked_acks = %tracked_acks.iter().copied().collated().format(", "),
+            "resuming relaying from the persisted relaying state",
+        );
+
+        let tracking_id = TrackingId::new_cleared_uuid();
+
+        // Relay the packets and acknowledgements which were in flight when the relayer stopped
+        for events_chunk in query_packet_events_with(
+            &tracked_packets,
+            Qualified::SmallerEqual(end_height),
+            self.src_chain(),
+            &self.path_id,
+            query_send_packet_events,
+        )
+        .chain(query_packet_events_with(
+            &tracked_acks,
+            Qualified::SmallerEqual(end_height),
+            self.src_chain(),
+            &self.path_id,
+            query_write_ack_events,
+        )) {
+            self.events_to_operational_data(TrackedEvents::new(events_chunk, tracking_id))?;
+        }
+
+        if untracked_packets.is_empty() && untracked_acks.is_empty() {
+            return Ok(true);
+        }
+
+        // Relay the packets sent and the acknowledgements written since the last handled height.
+        // The last handled height is scanned again, as its events may not have reached the worker.
+        let mut height = last_handled_height;
+
+        while height <= end_height {
+            let mut events = vec![];
+
+            if !untracked_packets.is_empty() {
+                events.extend(
+                    query_send_packet_events(
+                        self.src_chain(),
+                        &self.path_id,
+                        &untracked_packets,
+                        Qualified::Equal(height),
+                    )
+                    .map_err(LinkError::relayer)?,
+                );
+            }
+
+            if !untracked_acks.is_empty() {
+                events.extend(
+                    query_write_ack_events(
+                        self.src_chain(),
+                        &self.path_id,
+                        &untracked_acks,
+                        Qualified::Equal(height),
+                    )
+                    .map_err(LinkError::relayer)?,
+                );
+            }
+
+            if !events.is_empty() {
+                self.events_to_operational_data(TrackedEvents::new(events, tracking_id))?;
+            }
+
+            height = height.increment();
+        }
+
+        Ok(true)
+    }
+
+    /// Checks whether a transaction which was pending when the relayer stopped was committed since.
+    ///
+    /// Its packets are relayed again, as they are either still tracked or pending, if it was not.
+    fn reconcile_pending_tx(&self, pending_tx: &PendingTx) {
+        let query_tx = |hash| QueryTxRequest::Transaction(QueryTxHash(hash));
+
+        let committed = pending_tx
+            .tx_hashes
+            .iter()
+            .try_fold(true, |committed, hash| {
+                let events = if pending_tx.chain_id == self.src_chain().id() {
+                    self.src_chain().query_txs(query_tx(*hash))
+                } else {
+                    self.dst_chain().query_txs(query_tx(*hash))
+                }?;
+
+                Ok::<_, crate::error::Error>(committed && !events.is_empty())
+            });
+
+        let tx_hashes = TxHashes(pending_tx.tx_hashes.clone());
+
+        match committed {
+            Ok(true) => debug!(
+                chain = %pending_tx.chain_id,
+                tracking_id = %pending_tx.tracking_id,
+                "pending transactions {} were committed while the relayer was stopped",
+                tx_hashes
+            ),
+            Ok(false) => warn!(
+                chain = %pending_tx.chain_id,
+                tracking_id = %pending_tx.tracking_id,
+                "pending transactions {} were not committed, their packets will be relayed again",
+                tx_hashes
+            ),
+            Err(e) => error!(
+                chain = %pending_tx.chain_id,
+                tracking_id = %pending_tx.tracking_id,
+                "failed to query pending transactions {}: {}",
+                tx_hashes,
+                e
+            ),
+        }
+
+        self.remove_persisted_tx(&tx_hashes);
+    }
+
     /// Checks if a sent packet has been received on destination.
     fn send_packet_received_on_dst(&self, packet: &Packet) -> Result<bool, LinkError> {
         let unreceived_packet = self
@@ -974,8 +1398,9 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
     ) -> Result<Height, LinkError> {
         info!( "sending update_client to client hosted on source chain for height {} (retries left: {})", src_chain_height, retries_left );
 
-        let dst_update = self.build_update_client_on_dst(src_chain_height)?;
-        let tm = TrackedMsgs::new(dst_update, tracking_id);
+        let signer = self.select_dst_signer()?;
+        let dst_update = self.build_update_client_on_dst(src_chain_height, &signer)?;
+        let tm = TrackedMsgs::new(dst_update, tracking_id).with_signer(signer);
         let dst_tx_events = self
             .dst_chain()
             .send_messages_and_wait_commit(tm)
@@ -1041,8 +1466,9 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
     ) -> Result<Height, LinkError> {
         info!("sending update_client to client hosted on source chain for height {} (retries left: {})", dst_chain_height, retries_left);
 
-        let src_update = self.build_update_client_on_src(dst_chain_height)?;
-        let tm = TrackedMsgs::new(src_update, tracking_id);
+        let signer = self.select_src_signer()?;
+        let src_update = self.build_update_client_on_src(dst_chain_height, &signer)?;
+        let tm = TrackedMsgs::new(src_update, tracking_id).with_signer(signer);
         let src_tx_events = self
             .src_chain()
             .send_messages_and_wait_commit(tm)
@@ -1108,6 +1534,13 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
             unreceived_packets(self.dst_chain(), self.src_chain(), &self.path_id)
                 .map_err(LinkError::supervisor)?;
 
+        let sequences = self.remove_excluded_packet_sequences(sequences)?;
+
+        // Stop tracking the packets which have been received since they were tracked
+        if let Some(store) = &self.state_store {
+            store.retain_tracked_packets(&self.state_key(), &sequences);
+        }
+
         let query_height = opt_query_height.unwrap_or(src_response_height);
 
         // Skip: no relevant events found.
@@ -1145,6 +1578,64 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
         Ok(())
     }
 
+    /// Remove from the given sequences of packets sent by the source chain
+    /// the ones which are excluded from relaying in its configuration.
+    pub(crate) fn remove_excluded_packet_sequences(
+        &self,
+        sequences: Vec<Sequence>,
+    ) -> Result<Vec<Sequence>, LinkError> {
+        remove_excluded_sequences(
+            self.src_chain(),
+            self.dst_chain(),
+            self.src_port_id(),
+            self.src_channel_id(),
+            sequences,
+        )
+    }
+
+    /// Remove from the given sequences of packets whose acknowledgements are to be relayed,
+    /// ie. of packets sent by the destination chain, the ones which are excluded from relaying
+    /// in the configuration of the destination chain.
+    pub(crate) fn remove_excluded_ack_sequences(
+        &self,
+        sequences: Vec<Sequence>,
+    ) -> Result<Vec<Sequence>, LinkError> {
+        remove_excluded_sequences(
+            self.dst_chain(),
+            self.src_chain(),
+            self.dst_port_id(),
+            self.dst_channel_id(),
+            sequences,
+        )
+    }
+
+    /// Remove from the given events the packets sent and the acknowledgements written
+    /// whose packet sequence is excluded from relaying, see [`Self::remove_excluded_packet_sequences`]
+    /// and [`Self::remove_excluded_ack_sequences`].
+    fn remove_excluded_events(
+        &self,
+        mut events: TrackedEvents,
+    ) -> Result<TrackedEvents, LinkError> {
+        let (packets, acks) = packet_and_ack_sequences(events.events().iter().map(|ev| &ev.event));
+
+        let packets: BTreeSet<_> = self
+            .remove_excluded_packet_sequences(packets)?
+            .into_iter()
+            .collect();
+        let acks: BTreeSet<_> = self
+            .remove_excluded_ack_sequences(acks)?
+            .into_iter()
+            .collect();
+
+        events.retain(|ev| match &ev.event {
+            IbcEvent::SendPacket(send_packet) => packets.contains(&send_packet.packet.sequence),
+            IbcEvent::WriteAcknowledgement(write_ack) => acks.contains(&write_ack.packet.sequence),
+            _ => true,
+        });
+
+        Ok(events)
+    }
+
     /// Schedules the relaying of [`MsgAcknowledgement`] messages.
     ///
     /// The `opt_query_height` parameter allows to optionally use a specific height on the source
@@ -1168,6 +1659,13 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
 
         let Some((sequences, src_response_height)) = sequences_and_height else { return Ok(()) };
 
+        let sequences = self.remove_excluded_ack_sequences(sequences)?;
+
+        // Stop tracking the acknowledgements which have been received since they were tracked
+        if let Some(store) = &self.state_store {
+            store.retain_tracked_acks(&self.state_key(), &sequences);
+        }
+
         let query_height = opt_query_height.unwrap_or(src_response_height);
 
         // Skip: no relevant events found.
@@ -1198,7 +1696,12 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
         Ok(())
     }
 
-    fn build_recv_packet(&self, packet: &Packet, height: Height) -> Result<Option<Any>, LinkError> {
+    fn build_recv_packet(
+        &self,
+        packet: &Packet,
+        height: Height,
+        signer: &Signer,
+    ) -> Result<Option<Any>, LinkError> {
         let proofs = self
             .src_chain()
             .build_packet_proofs(
@@ -1210,7 +1713,7 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
             )
             .map_err(|e| LinkError::packet_proofs_constructor(self.src_chain().id(), e))?;
 
-        let msg = MsgRecvPacket::new(packet.clone(), proofs.clone(), self.dst_signer()?);
+        let msg = MsgRecvPacket::new(packet.clone(), proofs.clone(), signer.clone());
 
         trace!(packet = %packet, height = %proofs.height(), "built recv_packet msg");
 
@@ -1221,6 +1724,7 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
         &self,
         event: &WriteAcknowledgement,
         height: Height,
+        signer: &Signer,
     ) -> Result<Option<Any>, LinkError> {
         let packet = event.packet.clone();
 
@@ -1239,7 +1743,7 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
             packet,
             event.ack.clone().into(),
             proofs.clone(),
-            self.dst_signer()?,
+            signer.clone(),
         );
 
         trace!(packet = %msg.packet, height = %proofs.height(), "built acknowledgment msg");
@@ -1251,6 +1755,7 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
         &self,
         packet: &Packet,
         height: Height,
+        signer: &Signer,
     ) -> Result<Option<Any>, LinkError> {
         let dst_channel_id = self.dst_channel_id();
 
@@ -1289,7 +1794,7 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
             packet.clone(),
             next_sequence_received,
             proofs.clone(),
-            self.src_signer()?,
+            signer.clone(),
         );
 
         trace!(packet = %msg.packet, height = %proofs.height(), "built timeout msg");
@@ -1301,6 +1806,7 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
         &self,
         packet: &Packet,
         height: Height,
+        signer: &Signer,
     ) -> Result<Option<Any>, LinkError> {
         let dst_channel_id = self.dst_channel_id();
 
@@ -1338,7 +1844,7 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
             packet.clone(),
             packet.sequence,
             proofs.clone(),
-            self.src_signer()?,
+            signer.clone(),
         );
 
         trace!(packet = %msg.packet, height = %proofs.height(), "built timeout on close msg");
@@ -1350,15 +1856,16 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
         &self,
         event: &SendPacket,
         dst_info: &ChainStatus,
+        signer: &Signer,
     ) -> Result<Option<Any>, LinkError> {
         let packet = event.packet.clone();
         if self
             .dst_channel(QueryHeight::Specific(dst_info.height))?
             .state_matches(&ChannelState::Closed)
         {
-            Ok(self.build_timeout_on_close_packet(&event.packet, dst_info.height)?)
+            Ok(self.build_timeout_on_close_packet(&event.packet, dst_info.height, signer)?)
         } else if packet.timed_out(&dst_info.timestamp, dst_info.height) {
-            Ok(self.build_timeout_packet(&event.packet, dst_info.height)?)
+            Ok(self.build_timeout_packet(&event.packet, dst_info.height, signer)?)
         } else {
             Ok(None)
         }
@@ -1369,12 +1876,17 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
         event: &SendPacket,
         dst_info: &ChainStatus,
         height: Height,
+        src_signer: &Signer,
+        dst_signer: &Signer,
     ) -> Result<(Option<Any>, Option<Any>), LinkError> {
-        let timeout = self.build_timeout_from_send_packet_event(event, dst_info)?;
+        let timeout = self.build_timeout_from_send_packet_event(event, dst_info, src_signer)?;
         if timeout.is_some() {
             Ok((None, timeout))
         } else {
-            Ok((self.build_recv_packet(&event.packet, height)?, None))
+            Ok((
+                self.build_recv_packet(&event.packet, height, dst_signer)?,
+                None,
+            ))
         }
     }
 
@@ -1573,6 +2085,9 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
 
         let dst_current_height = dst_status.height;
 
+        // The timeout messages are submitted to the source chain in new batches
+        let src_signer = self.select_src_signer()?;
+
         // Intermediary data struct to help better manage the transfer from dst. operational data
         // to source operational data.
         let mut all_dst_odata = self.dst_operational_data.clone_vec();
@@ -1594,9 +2109,11 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
                         // Catch any SendPacket event that timed-out
                         if self.send_packet_event_handled(event)? {
                             debug!(?event, "SendPacket event has already been handled");
-                        } else if let Some(new_msg) =
-                            self.build_timeout_from_send_packet_event(event, &dst_status)?
-                        {
+                        } else if let Some(new_msg) = self.build_timeout_from_send_packet_event(
+                            event,
+                            &dst_status,
+                            &src_signer,
+                        )? {
                             debug!(
                                 "found a timed-out message in the operational data: {}",
                                 odata.info(),
@@ -1610,6 +2127,7 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
                                         OperationalDataTarget::Source,
                                         odata.tracking_id,
                                         self.channel.connection_delay,
+                                        src_signer.clone(),
                                     )
                                 })
                                 .push(TransitMessage {
@@ -1800,37 +2318,82 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
         }
     }
 
+    /// Update the backlog and measure the end-to-end latency of the packets
+    /// of this path, based on the relevant events of the given batch.
     #[cfg(feature = "telemetry")]
-    fn backlog_update(&self, event: &IbcEvent) {
-        match event {
-            IbcEvent::SendPacket(send_packet_ev) => {
-                ibc_telemetry::global().backlog_insert(
-                    send_packet_ev.packet.sequence.into(),
-                    &self.src_chain().id(),
-                    self.src_channel_id(),
-                    self.src_port_id(),
-                    &self.dst_chain().id(),
-                );
-            }
-            IbcEvent::WriteAcknowledgement(write_ack_ev) => {
-                ibc_telemetry::global().backlog_remove(
-                    write_ack_ev.packet.sequence.into(),
-                    &self.dst_chain().id(),
-                    self.dst_channel_id(),
-                    self.dst_port_id(),
-                    &self.src_chain().id(),
-                );
-            }
-            IbcEvent::TimeoutPacket(timeout_packet) => {
-                ibc_telemetry::global().backlog_remove(
-                    timeout_packet.packet.sequence.into(),
-                    &self.src_chain().id(),
-                    self.src_channel_id(),
-                    self.src_port_id(),
-                    &self.dst_chain().id(),
-                );
+    fn backlog_update(&self, events: &[IbcEventWithHeight]) {
+        // The events of the batch were emitted by the source chain, so the latency of the packets
+        // is measured with the timestamps of the blocks of the source chain which include them.
+        // These are queried once per height, and are cached by the chain handle of the supervisor.
+        // If the block timestamp cannot be queried, fall back to the current local time.
+        let mut block_times = HashMap::new();
+        let mut block_time = |height: Height| {
+            *block_times.entry(height).or_insert_with(|| {
+                self.src_chain()
+                    .query_host_consensus_state(QueryHostConsensusStateRequest {
+                        height: QueryHeight::Specific(height),
+                    })
+                    .map(|consensus_state| consensus_state.timestamp())
+                    .unwrap_or_else(|_| Timestamp::now())
+            })
+        };
+
+        for event_with_height in events {
+            match &event_with_height.event {
+                IbcEvent::SendPacket(send_packet_ev) => {
+                    ibc_telemetry::global().backlog_insert(
+                        send_packet_ev.packet.sequence.into(),
+                        &self.src_chain().id(),
+                        self.src_channel_id(),
+                        self.src_port_id(),
+                        &self.dst_chain().id(),
+                        block_time(event_with_height.height),
+                    );
+                }
+                IbcEvent::WriteAcknowledgement(write_ack_ev) => {
+                    ibc_telemetry::global().backlog_remove(
+                        write_ack_ev.packet.sequence.into(),
+                        &self.dst_chain().id(),
+                        self.dst_channel_id(),
+                        self.dst_port_id(),
+                        &self.src_chain().id(),
+                    );
+                    ibc_telemetry::global().packet_latency_recv(
+                        write_ack_ev.packet.sequence.into(),
+                        &self.dst_chain().id(),
+                        self.dst_channel_id(),
+                        self.dst_port_id(),
+                        &self.src_chain().id(),
+                        block_time(event_with_height.height),
+                    );
+                }
+                IbcEvent::TimeoutPacket(timeout_packet) => {
+                    ibc_telemetry::global().backlog_remove(
+                        timeout_packet.packet.sequence.into(),
+                        &self.src_chain().id(),
+                        self.src_channel_id(),
+                        self.src_port_id(),
+                        &self.dst_chain().id(),
+                    );
+                    ibc_telemetry::global().sent_packet_remove(
+                        timeout_packet.packet.sequence.into(),
+                        &self.src_chain().id(),
+                        self.src_channel_id(),
+                        self.src_port_id(),
+                    );
+                }
+                IbcEvent::AcknowledgePacket(ack_packet) => {
+                    ibc_telemetry::global().packet_latency_ack(
+                        ack_packet.packet.sequence.into(),
+                        &self.src_chain().id(),
+                        self.src_channel_id(),
+                        self.src_port_id(),
+                        &self.dst_chain().id(),
+                        block_time(event_with_height.height),
+                    );
+                }
+                _ => {}
             }
-            _ => {}
         }
     }
 
@@ -1875,3 +2438,64 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
         }
     }
 }
+
+/// Remove from the given sequences of packets sent by `chain` on the given channel
+/// the ones which are excluded from relaying in the configuration of `chain`.
+fn remove_excluded_sequences(
+    chain: &impl ChainHandle,
+    _counterparty_chain: &impl ChainHandle,
+    port_id: &PortId,
+    channel_id: &ChannelId,
+    sequences: Vec<Sequence>,
+) -> Result<Vec<Sequence>, LinkError> {
+    if sequences.is_empty() {
+        return Ok(sequences);
+    }
+
+    let excluded_sequences = chain
+        .config()
+        .map_err(LinkError::relayer)?
+        .excluded_sequences;
+
+    let (sequences, excluded) = excluded_sequences.partition(channel_id, sequences);
+
+    if !excluded.is_empty() {
+        debug!(
+            chain = %chain.id(),
+            channel = %channel_id,
+            port = %port_id,
+            total = excluded.len(),
+            sequences = %excluded.iter().copied().collated().format(", "),
+            "skipping packets whose sequence is excluded in the configuration",
+        );
+
+        telemetry!(
+            skipped_excluded_packets,
+            &chain.id(),
+            channel_id,
+            port_id,
+            &_counterparty_chain.id(),
+            excluded.len() as u64
+        );
+    }
+
+    Ok(sequences)
+}
+
+/// The sequences of the packets sent and of the acknowledgements written in the given events.
+fn packet_and_ack_sequences<'a>(
+    events: impl Iterator<Item = &'a IbcEvent>,
+) -> (Vec<Sequence>, Vec<Sequence>) {
+    let mut packets = Vec::new();
+    let mut acks = Vec::new();
+
+    for event in events {
+        match event {
+            IbcEvent::SendPacket(send_packet) => packets.push(send_packet.packet.sequence),
+            IbcEvent::WriteAcknowledgement(write_ack) => acks.push(write_ack.packet.sequence),
+            _ => {}
+        }
+    }
+
+    (packets, acks)
+}
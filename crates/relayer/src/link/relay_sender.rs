@@ -1,15 +1,18 @@
 use core::fmt::{Display, Error as FmtError, Formatter};
 
+use serde::Serialize;
 use tendermint_rpc::endpoint::broadcast::tx_sync;
 use tracing::info;
 
+use ibc_relayer_types::applications::transfer::RawCoin;
+use ibc_relayer_types::core::ics24_host::identifier::ChainId;
 use ibc_relayer_types::events::IbcEvent;
 
 use crate::chain::handle::ChainHandle;
 use crate::chain::tracking::TrackedMsgs;
 use crate::link::error::LinkError;
 use crate::link::RelaySummary;
-use crate::util::pretty::{PrettyCode, PrettyEvents};
+use crate::util::pretty::{PrettyCode, PrettyEvents, PrettySlice};
 
 pub trait SubmitReply {
     /// Creates a new, empty instance, i.e., comprising zero replies.
@@ -117,3 +120,57 @@ impl Display for AsyncReply {
             .try_for_each(|r| write!(f, "; {}:{}", PrettyCode(&r.code), r.hash))
     }
 }
+
+/// A transaction which was simulated in dry-run mode, instead of being submitted.
+#[derive(Clone, Debug, Serialize)]
+pub struct SimulatedTx {
+    /// The chain to which the transaction would have been submitted
+    pub chain_id: ChainId,
+    pub tracking_id: String,
+    /// The type URLs of the messages of the transaction
+    pub messages: Vec<String>,
+    pub gas_limit: u64,
+    pub estimated_fee: Vec<RawCoin>,
+}
+
+impl Display for SimulatedTx {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), FmtError> {
+        write!(
+            f,
+            "{} message(s) to {}: {}, estimated fee: {} (gas limit: {})",
+            self.messages.len(),
+            self.chain_id,
+            PrettySlice(&self.messages),
+            PrettySlice(&self.estimated_fee),
+            self.gas_limit
+        )
+    }
+}
+
+/// Simulates the transaction which would submit the given messages
+/// to the target chain, in order to estimate its fee, without
+/// broadcasting it.
+pub fn simulate(target: &impl ChainHandle, msgs: TrackedMsgs) -> Result<SimulatedTx, LinkError> {
+    let tracking_id = msgs.tracking_id().to_string();
+    let messages = msgs.msgs.iter().map(|msg| msg.type_url.clone()).collect();
+
+    let fee = target
+        .estimate_tx_fee(msgs)
+        .map_err(|e| LinkError::fee_estimation(target.id(), e))?;
+
+    let tx = SimulatedTx {
+        chain_id: target.id(),
+        tracking_id,
+        messages,
+        gas_limit: fee.gas_limit,
+        estimated_fee: fee
+            .amount
+            .into_iter()
+            .filter_map(|coin| RawCoin::try_from(coin).ok())
+            .collect(),
+    };
+
+    info!("[DryRun->{}] simulated {}", target.id(), tx);
+
+    Ok(tx)
+}
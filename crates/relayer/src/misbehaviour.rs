@@ -1,4 +1,7 @@
 use ibc_proto::{google::protobuf::Any, protobuf::Protobuf};
+use ibc_relayer_types::clients::ics06_solomachine::misbehaviour::{
+    Misbehaviour as SmMisbehaviour, SOLOMACHINE_MISBEHAVIOUR_TYPE_URL,
+};
 use ibc_relayer_types::clients::ics07_tendermint::misbehaviour::{
     Misbehaviour as TmMisbehaviour, TENDERMINT_MISBEHAVIOR_TYPE_URL,
 };
@@ -6,9 +9,9 @@ use ibc_relayer_types::core::{
     ics02_client::{error::Error, misbehaviour::Misbehaviour},
     ics24_host::identifier::ClientId,
 };
-#[cfg(test)]
+#[cfg(any(test, feature = "mocks"))]
 use ibc_relayer_types::mock::misbehaviour::Misbehaviour as MockMisbehaviour;
-#[cfg(test)]
+#[cfg(any(test, feature = "mocks"))]
 use ibc_relayer_types::mock::misbehaviour::MOCK_MISBEHAVIOUR_TYPE_URL;
 use ibc_relayer_types::Height;
 use serde::{Deserialize, Serialize};
@@ -26,7 +29,9 @@ pub struct MisbehaviourEvidence {
 pub enum AnyMisbehaviour {
     Tendermint(TmMisbehaviour),
 
-    #[cfg(test)]
+    Solomachine(SmMisbehaviour),
+
+    #[cfg(any(test, feature = "mocks"))]
     Mock(MockMisbehaviour),
 }
 
@@ -35,7 +40,9 @@ impl Misbehaviour for AnyMisbehaviour {
         match self {
             Self::Tendermint(misbehaviour) => misbehaviour.client_id(),
 
-            #[cfg(test)]
+            Self::Solomachine(misbehaviour) => misbehaviour.client_id(),
+
+            #[cfg(any(test, feature = "mocks"))]
             Self::Mock(misbehaviour) => misbehaviour.client_id(),
         }
     }
@@ -44,7 +51,9 @@ impl Misbehaviour for AnyMisbehaviour {
         match self {
             Self::Tendermint(misbehaviour) => misbehaviour.height(),
 
-            #[cfg(test)]
+            Self::Solomachine(misbehaviour) => misbehaviour.height(),
+
+            #[cfg(any(test, feature = "mocks"))]
             Self::Mock(misbehaviour) => misbehaviour.height(),
         }
     }
@@ -61,7 +70,11 @@ impl TryFrom<Any> for AnyMisbehaviour {
                 TmMisbehaviour::decode_vec(&raw.value).map_err(Error::decode_raw_misbehaviour)?,
             )),
 
-            #[cfg(test)]
+            SOLOMACHINE_MISBEHAVIOUR_TYPE_URL => Ok(AnyMisbehaviour::Solomachine(
+                SmMisbehaviour::decode_vec(&raw.value).map_err(Error::decode_raw_misbehaviour)?,
+            )),
+
+            #[cfg(any(test, feature = "mocks"))]
             MOCK_MISBEHAVIOUR_TYPE_URL => Ok(AnyMisbehaviour::Mock(
                 MockMisbehaviour::decode_vec(&raw.value).map_err(Error::decode_raw_misbehaviour)?,
             )),
@@ -79,7 +92,12 @@ impl From<AnyMisbehaviour> for Any {
                 value: misbehaviour.encode_vec(),
             },
 
-            #[cfg(test)]
+            AnyMisbehaviour::Solomachine(misbehaviour) => Any {
+                type_url: SOLOMACHINE_MISBEHAVIOUR_TYPE_URL.to_string(),
+                value: misbehaviour.encode_vec(),
+            },
+
+            #[cfg(any(test, feature = "mocks"))]
             AnyMisbehaviour::Mock(misbehaviour) => Any {
                 type_url: MOCK_MISBEHAVIOUR_TYPE_URL.to_string(),
                 value: misbehaviour.encode_vec(),
@@ -93,7 +111,9 @@ impl core::fmt::Display for AnyMisbehaviour {
         match self {
             AnyMisbehaviour::Tendermint(tm) => write!(f, "{tm}"),
 
-            #[cfg(test)]
+            AnyMisbehaviour::Solomachine(sm) => write!(f, "{sm}"),
+
+            #[cfg(any(test, feature = "mocks"))]
             AnyMisbehaviour::Mock(mock) => write!(f, "{mock:?}"),
         }
     }
@@ -105,7 +125,13 @@ impl From<TmMisbehaviour> for AnyMisbehaviour {
     }
 }
 
-#[cfg(test)]
+impl From<SmMisbehaviour> for AnyMisbehaviour {
+    fn from(misbehaviour: SmMisbehaviour) -> Self {
+        Self::Solomachine(misbehaviour)
+    }
+}
+
+#[cfg(any(test, feature = "mocks"))]
 impl From<MockMisbehaviour> for AnyMisbehaviour {
     fn from(misbehaviour: MockMisbehaviour) -> Self {
         Self::Mock(misbehaviour)
@@ -6,7 +6,8 @@ use ibc_relayer_types::core::{
     ics02_client::{client_state::ClientState, events::UpdateClient},
     ics03_connection::events::Attributes as ConnectionAttributes,
     ics04_channel::events::{
-        Attributes, CloseInit, SendPacket, TimeoutPacket, WriteAcknowledgement,
+        AcknowledgePacket, Attributes, CloseInit, SendPacket, TimeoutPacket, UpgradeAttributes,
+        WriteAcknowledgement,
     },
     ics24_host::identifier::{ChainId, ChannelId, ClientId, ConnectionId, PortId},
 };
@@ -445,6 +446,27 @@ impl Object {
         .into())
     }
 
+    /// Build the Channel object associated with the given channel upgrade event.
+    pub fn for_channel_upgrade_event(
+        attributes: &UpgradeAttributes,
+        src_chain: &impl ChainHandle,
+    ) -> Result<Self, ObjectError> {
+        let dst_chain_id = counterparty_chain_from_channel(
+            src_chain,
+            attributes.channel_id(),
+            attributes.port_id(),
+        )
+        .map_err(ObjectError::supervisor)?;
+
+        Ok(Channel {
+            dst_chain_id,
+            src_chain_id: src_chain.id(),
+            src_channel_id: attributes.channel_id().clone(),
+            src_port_id: attributes.port_id().clone(),
+        }
+        .into())
+    }
+
     /// Build the object associated with the given [`SendPacket`] event.
     pub fn for_send_packet(
         e: &SendPacket,
@@ -508,6 +530,27 @@ impl Object {
         .into())
     }
 
+    /// Build the object associated with the given [`AcknowledgePacket`] event.
+    pub fn for_acknowledge_packet(
+        e: &AcknowledgePacket,
+        src_chain: &impl ChainHandle,
+    ) -> Result<Self, ObjectError> {
+        let dst_chain_id = counterparty_chain_from_channel(
+            src_chain,
+            &e.packet.source_channel,
+            &e.packet.source_port,
+        )
+        .map_err(ObjectError::supervisor)?;
+
+        Ok(Packet {
+            dst_chain_id,
+            src_chain_id: src_chain.id(),
+            src_channel_id: e.src_channel_id().clone(),
+            src_port_id: e.src_port_id().clone(),
+        }
+        .into())
+    }
+
     /// Build the object associated with the given [`CloseInit`] event.
     pub fn for_close_init_channel(
         e: &CloseInit,
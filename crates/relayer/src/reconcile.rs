@@ -0,0 +1,1105 @@
+//! Reconciliation of the `[[paths]]` declared in the configuration.
+//!
+//! The chains of the paths are scanned once, and each path is then looked up in the
+//! scans: an open channel between the ports of the path, over an open connection with
+//! the desired delay, between clients with the desired settings. The pieces which already
+//! exist are reused, starting from the channel down to the clients, and only the missing
+//! ones are created, then added to the scans for the paths reconciled next.
+//!
+//! An open channel between the ports of the path over a connection whose delay or clients
+//! do not match the path is reported as an error, rather than created again.
+
+use alloc::collections::btree_map::Entry;
+use alloc::collections::{BTreeMap, BTreeSet};
+use core::fmt::{Display, Error as FmtError, Formatter};
+
+use flex_error::define_error;
+use serde::Serialize;
+use tracing::{error, error_span, info, warn};
+
+use ibc_relayer_types::core::ics02_client::client_state::ClientState;
+use ibc_relayer_types::core::ics03_connection::connection::{
+    IdentifiedConnectionEnd, State as ConnectionState,
+};
+use ibc_relayer_types::core::ics04_channel::channel::{
+    IdentifiedChannelEnd, State as ChannelState,
+};
+use ibc_relayer_types::core::ics24_host::identifier::{
+    ChainId, ChannelId, ClientId, ConnectionId, PortId,
+};
+
+use crate::chain::handle::ChainHandle;
+use crate::chain::requests::{
+    IncludeProof, QueryChannelRequest, QueryClientStateRequest, QueryConnectionRequest, QueryHeight,
+};
+use crate::channel::{Channel, ChannelError};
+use crate::client_state::IdentifiedAnyClientState;
+use crate::config::filter::PacketFilter;
+use crate::config::paths::{PathClientSettings, PathConfig};
+use crate::config::Config;
+use crate::connection::{Connection, ConnectionError};
+use crate::error::Error;
+use crate::foreign_client::{extract_client_id, ForeignClient, ForeignClientError};
+use crate::registry::{Registry, SharedRegistry};
+use crate::spawn::SpawnError;
+use crate::supervisor::client_state_filter::FilterPolicy;
+use crate::supervisor::scan::{
+    ChainScan, ChainScanner, ChannelScan, ClientScan, ConnectionScan, ScanMode,
+};
+
+define_error! {
+    ReconcileError {
+        MissingChainConfig
+            { chain_id: ChainId }
+            |e| { format!("missing configuration for chain {}", e.chain_id) },
+
+        Spawn
+            [ SpawnError ]
+            |_| { "failed to spawn chain runtime" },
+
+        Scan
+            {
+                chain_id: ChainId,
+                reason: String,
+            }
+            |e| { format!("failed to scan chain {}: {}", e.chain_id, e.reason) },
+
+        MismatchedChannel
+            {
+                chain_id: ChainId,
+                port_id: PortId,
+                channel_id: ChannelId,
+                connection_id: ConnectionId,
+            }
+            |e| {
+                format!("channel {}/{} on chain {} is open over connection {} whose delay or clients do not match the path, \
+                    refusing to create another channel: either update the path to match or close the channel",
+                    e.port_id, e.channel_id, e.chain_id, e.connection_id)
+            },
+
+        ForeignClient
+            [ ForeignClientError ]
+            |_| { "failed to find or create client" },
+
+        Connection
+            [ ConnectionError ]
+            |_| { "failed to find or create connection" },
+
+        Channel
+            [ ChannelError ]
+            |_| { "failed to create channel" },
+
+        Query
+            [ Error ]
+            |_| { "failed to query the pieces of the path" },
+    }
+}
+
+/// Whether a piece of a path already existed, or was created by the reconciliation.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+#[serde(tag = "status", content = "id", rename_all = "snake_case")]
+pub enum Reconciled<Id> {
+    Existing(Id),
+    Created(Id),
+}
+
+impl<Id> Reconciled<Id> {
+    pub fn id(&self) -> &Id {
+        match self {
+            Self::Existing(id) | Self::Created(id) => id,
+        }
+    }
+
+    pub fn is_created(&self) -> bool {
+        matches!(self, Self::Created(_))
+    }
+
+    fn created_if(created: bool, id: Id) -> Self {
+        if created {
+            Self::Created(id)
+        } else {
+            Self::Existing(id)
+        }
+    }
+}
+
+impl<Id: Display> Display for Reconciled<Id> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), FmtError> {
+        match self {
+            Self::Existing(id) => write!(f, "{id} (existing)"),
+            Self::Created(id) => write!(f, "{id} (created)"),
+        }
+    }
+}
+
+/// The identifiers of the pieces of a reconciled path, on both chains.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct PathReport {
+    pub a_chain: ChainId,
+    pub b_chain: ChainId,
+    pub a_client: Reconciled<ClientId>,
+    pub b_client: Reconciled<ClientId>,
+    pub a_connection: Reconciled<ConnectionId>,
+    pub b_connection: Reconciled<ConnectionId>,
+    pub a_port: PortId,
+    pub b_port: PortId,
+    pub a_channel: Reconciled<ChannelId>,
+    pub b_channel: Reconciled<ChannelId>,
+}
+
+impl PathReport {
+    /// Whether any piece of the path was created by the reconciliation.
+    pub fn has_created(&self) -> bool {
+        self.a_client.is_created()
+            || self.b_client.is_created()
+            || self.a_connection.is_created()
+            || self.a_channel.is_created()
+    }
+}
+
+impl Display for PathReport {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), FmtError> {
+        writeln!(f, "# Path: {} <-> {}", self.a_chain, self.b_chain)?;
+        writeln!(f, "  - Clients: {} <-> {}", self.a_client, self.b_client)?;
+        writeln!(
+            f,
+            "  - Connections: {} <-> {}",
+            self.a_connection, self.b_connection
+        )?;
+        writeln!(
+            f,
+            "  - Channels: {}/{} <-> {}/{}",
+            self.a_port, self.a_channel, self.b_port, self.b_channel
+        )
+    }
+}
+
+/// The scans of the chains of the paths, or the reason why a chain could not be scanned.
+type ChainScans = BTreeMap<ChainId, Result<ChainScan, String>>;
+
+/// Reconciles all the paths declared in the configuration, one after the other,
+/// against a single scan of each of the chains of the paths.
+pub fn reconcile_paths<Chain: ChainHandle>(
+    config: &Config,
+    registry: &SharedRegistry<Chain>,
+) -> Vec<Result<PathReport, ReconcileError>> {
+    let mut scans = scan_chains(config, &mut registry.write());
+
+    config
+        .paths
+        .iter()
+        .map(|path| {
+            let result = reconcile_path(registry, &mut scans, path);
+
+            match &result {
+                Ok(report) if report.has_created() => info!("reconciled path:\n{report}"),
+                Ok(report) => info!("path already exists:\n{report}"),
+                Err(e) => error!(
+                    a_chain = %path.a_chain,
+                    b_chain = %path.b_chain,
+                    "failed to reconcile path: {e}"
+                ),
+            }
+
+            result
+        })
+        .collect()
+}
+
+/// Reconciles the given path against the scans of its chains, creating the pieces
+/// of the path which do not exist yet and adding them to the scans.
+fn reconcile_path<Chain: ChainHandle>(
+    registry: &SharedRegistry<Chain>,
+    scans: &mut ChainScans,
+    path: &PathConfig,
+) -> Result<PathReport, ReconcileError> {
+    let _span = error_span!(
+        "reconcile.path",
+        a_chain = %path.a_chain,
+        a_port = %path.a_port,
+        b_chain = %path.b_chain,
+        b_port = %path.b_port,
+    )
+    .entered();
+
+    let chain_scan = |chain_id: &ChainId| match scans.get(chain_id) {
+        Some(Ok(scan)) => Ok(scan),
+        Some(Err(reason)) => Err(ReconcileError::scan(chain_id.clone(), reason.clone())),
+        None => Err(ReconcileError::missing_chain_config(chain_id.clone())),
+    };
+
+    let report = create_missing(
+        registry,
+        path,
+        chain_scan(&path.a_chain)?,
+        chain_scan(&path.b_chain)?,
+    )?;
+
+    if report.has_created() {
+        if let Err(e) = add_to_scans(registry, scans, &report) {
+            warn!("failed to add the created path to the scans of its chains: {e}");
+        }
+    }
+
+    Ok(report)
+}
+
+/// Creates the pieces of the path which are missing from the scans of its chains.
+fn create_missing<Chain: ChainHandle>(
+    registry: &SharedRegistry<Chain>,
+    path: &PathConfig,
+    a_scan: &ChainScan,
+    b_scan: &ChainScan,
+) -> Result<PathReport, ReconcileError> {
+    if let Some(report) = find_channel(path, a_scan, b_scan) {
+        return Ok(report);
+    }
+
+    if let Some((connection, channel)) = find_mismatched_channel(path, a_scan, b_scan) {
+        return Err(ReconcileError::mismatched_channel(
+            path.a_chain.clone(),
+            path.a_port.clone(),
+            channel.id().clone(),
+            connection.id().clone(),
+        ));
+    }
+
+    let a_chain = registry
+        .get_or_spawn(&path.a_chain)
+        .map_err(ReconcileError::spawn)?;
+    let b_chain = registry
+        .get_or_spawn(&path.b_chain)
+        .map_err(ReconcileError::spawn)?;
+
+    let (connection, connection_created, a_client_created, b_client_created) =
+        match find_connection(path, a_scan, b_scan) {
+            Some(connection_scan) => {
+                info!(
+                    connection = %connection_scan.id(),
+                    "found open connection"
+                );
+
+                let end = connection_scan.connection.end();
+
+                let a_client =
+                    ForeignClient::find(b_chain.clone(), a_chain.clone(), end.client_id())
+                        .map_err(ReconcileError::foreign_client)?;
+                let b_client =
+                    ForeignClient::find(a_chain, b_chain, end.counterparty().client_id())
+                        .map_err(ReconcileError::foreign_client)?;
+
+                let connection = Connection::find(a_client, b_client, &connection_scan.connection)
+                    .map_err(ReconcileError::connection)?;
+
+                (connection, false, false, false)
+            }
+            None => {
+                let (a_client, a_created) = find_or_create_client(
+                    a_scan,
+                    &path.a_client,
+                    a_chain.clone(),
+                    b_chain.clone(),
+                )?;
+                let (b_client, b_created) =
+                    find_or_create_client(b_scan, &path.b_client, b_chain, a_chain)?;
+
+                info!(
+                    a_client = %a_client.id(),
+                    b_client = %b_client.id(),
+                    "creating connection..."
+                );
+
+                let connection = Connection::new(a_client, b_client, path.connection_delay)
+                    .map_err(ReconcileError::connection)?;
+
+                (connection, true, a_created, b_created)
+            }
+        };
+
+    let a_client = connection.src_client_id().clone();
+    let b_client = connection.dst_client_id().clone();
+    let a_connection = connection.a_connection_id().cloned().unwrap_or_default();
+    let b_connection = connection.b_connection_id().cloned().unwrap_or_default();
+
+    info!(connection = %a_connection, "creating channel...");
+
+    let channel = Channel::new(
+        connection,
+        path.ordering,
+        path.a_port.clone(),
+        path.b_port.clone(),
+        path.version.clone(),
+    )
+    .map_err(ReconcileError::channel)?;
+
+    Ok(PathReport {
+        a_chain: path.a_chain.clone(),
+        b_chain: path.b_chain.clone(),
+        a_client: Reconciled::created_if(a_client_created, a_client),
+        b_client: Reconciled::created_if(b_client_created, b_client),
+        a_connection: Reconciled::created_if(connection_created, a_connection),
+        b_connection: Reconciled::created_if(connection_created, b_connection),
+        a_port: path.a_port.clone(),
+        b_port: path.b_port.clone(),
+        a_channel: Reconciled::Created(channel.a_channel_id().cloned().unwrap_or_default()),
+        b_channel: Reconciled::Created(channel.b_channel_id().cloned().unwrap_or_default()),
+    })
+}
+
+/// Scans the chains of all the paths for all their clients, connections and channels,
+/// regardless of the packet filters of the chains.
+fn scan_chains<Chain: ChainHandle>(config: &Config, registry: &mut Registry<Chain>) -> ChainScans {
+    let mut scan_config = config.clone();
+    for chain_config in scan_config.chains.iter_mut() {
+        chain_config.packet_filter = PacketFilter::default();
+    }
+
+    let chain_ids: BTreeSet<&ChainId> = config
+        .paths
+        .iter()
+        .flat_map(|path| [&path.a_chain, &path.b_chain])
+        .collect();
+
+    let mut client_state_filter = FilterPolicy::default();
+    let mut scanner = ChainScanner::new(
+        &scan_config,
+        registry,
+        &mut client_state_filter,
+        ScanMode::Full,
+    );
+
+    chain_ids
+        .into_iter()
+        .filter_map(|chain_id| {
+            let chain_config = scan_config.find_chain(chain_id)?;
+
+            let scan = scanner.scan_chain(chain_config).map_err(|e| e.to_string());
+
+            Some((chain_id.clone(), scan))
+        })
+        .collect()
+}
+
+/// Adds the pieces of the given path to the scans of its chains,
+/// so that the paths reconciled next reuse them instead of creating them again.
+fn add_to_scans<Chain: ChainHandle>(
+    registry: &SharedRegistry<Chain>,
+    scans: &mut ChainScans,
+    report: &PathReport,
+) -> Result<(), ReconcileError> {
+    let a_chain = registry
+        .get_or_spawn(&report.a_chain)
+        .map_err(ReconcileError::spawn)?;
+    let b_chain = registry
+        .get_or_spawn(&report.b_chain)
+        .map_err(ReconcileError::spawn)?;
+
+    let a_end = PathEnd {
+        client: report.a_client.id(),
+        connection: report.a_connection.id(),
+        port: &report.a_port,
+        channel: report.a_channel.id(),
+    };
+    let b_end = PathEnd {
+        client: report.b_client.id(),
+        connection: report.b_connection.id(),
+        port: &report.b_port,
+        channel: report.b_channel.id(),
+    };
+
+    let a_channel = query_channel(&a_chain, &a_end).map_err(ReconcileError::query)?;
+    let b_channel = query_channel(&b_chain, &b_end).map_err(ReconcileError::query)?;
+
+    if let Some(Ok(scan)) = scans.get_mut(&report.a_chain) {
+        add_end_to_scan(&a_chain, scan, &a_end, a_channel.clone(), b_channel.clone())
+            .map_err(ReconcileError::query)?;
+    }
+
+    if let Some(Ok(scan)) = scans.get_mut(&report.b_chain) {
+        add_end_to_scan(&b_chain, scan, &b_end, b_channel, a_channel)
+            .map_err(ReconcileError::query)?;
+    }
+
+    Ok(())
+}
+
+/// The identifiers of the pieces of one end of a path.
+struct PathEnd<'a> {
+    client: &'a ClientId,
+    connection: &'a ConnectionId,
+    port: &'a PortId,
+    channel: &'a ChannelId,
+}
+
+fn query_channel<Chain: ChainHandle>(
+    chain: &Chain,
+    end: &PathEnd<'_>,
+) -> Result<IdentifiedChannelEnd, Error> {
+    let (channel_end, _) = chain.query_channel(
+        QueryChannelRequest {
+            port_id: end.port.clone(),
+            channel_id: end.channel.clone(),
+            height: QueryHeight::Latest,
+        },
+        IncludeProof::No,
+    )?;
+
+    Ok(IdentifiedChannelEnd::new(
+        end.port.clone(),
+        end.channel.clone(),
+        channel_end,
+    ))
+}
+
+/// Adds one end of a path to the scan of its chain, querying the client
+/// and connection of the path if they are not in the scan yet.
+fn add_end_to_scan<Chain: ChainHandle>(
+    chain: &Chain,
+    scan: &mut ChainScan,
+    end: &PathEnd<'_>,
+    channel: IdentifiedChannelEnd,
+    counterparty: IdentifiedChannelEnd,
+) -> Result<(), Error> {
+    let client_scan = match scan.clients.entry(end.client.clone()) {
+        Entry::Occupied(entry) => entry.into_mut(),
+        Entry::Vacant(entry) => {
+            let (client_state, _) = chain.query_client_state(
+                QueryClientStateRequest {
+                    client_id: end.client.clone(),
+                    height: QueryHeight::Latest,
+                },
+                IncludeProof::No,
+            )?;
+
+            let client = IdentifiedAnyClientState::new(end.client.clone(), client_state);
+            entry.insert(ClientScan::new(client))
+        }
+    };
+
+    let connection_scan = match client_scan.connections.entry(end.connection.clone()) {
+        Entry::Occupied(entry) => entry.into_mut(),
+        Entry::Vacant(entry) => {
+            let (connection_end, _) = chain.query_connection(
+                QueryConnectionRequest {
+                    connection_id: end.connection.clone(),
+                    height: QueryHeight::Latest,
+                },
+                IncludeProof::No,
+            )?;
+
+            let connection = IdentifiedConnectionEnd::new(end.connection.clone(), connection_end);
+            entry.insert(ConnectionScan::new(connection, Some(ConnectionState::Open)))
+        }
+    };
+
+    connection_scan.channels.insert(
+        end.channel.clone(),
+        ChannelScan::new(channel, Some(counterparty)),
+    );
+
+    Ok(())
+}
+
+/// Whether the given client is not frozen and has the desired settings, if any.
+fn client_matches(client: &IdentifiedAnyClientState, settings: &PathClientSettings) -> bool {
+    let state = &client.client_state;
+
+    !state.is_frozen()
+        && settings
+            .trusting_period
+            .map_or(true, |period| state.trusting_period() == Some(period))
+        && settings
+            .trust_threshold
+            .map_or(true, |threshold| state.trust_threshold() == Some(threshold))
+        && settings
+            .max_clock_drift
+            .map_or(true, |drift| state.max_clock_drift() == drift)
+}
+
+/// The open connections of chain `a` with the desired delay, between
+/// clients tracking the counterparty chain with the desired settings.
+fn matching_connections<'a>(
+    path: &'a PathConfig,
+    a_scan: &'a ChainScan,
+    b_scan: &'a ChainScan,
+) -> impl Iterator<Item = &'a ConnectionScan> {
+    a_scan
+        .clients
+        .values()
+        .filter(|client| {
+            client.counterparty_chain_id() == path.b_chain
+                && client_matches(&client.client, &path.a_client)
+        })
+        .flat_map(|client| client.connections.values())
+        .filter(move |connection| {
+            let end = connection.connection.end();
+
+            let b_client_matches = b_scan
+                .clients
+                .get(end.counterparty().client_id())
+                .map_or(false, |client| {
+                    client_matches(&client.client, &path.b_client)
+                });
+
+            connection.is_open() && end.delay_period() == path.connection_delay && b_client_matches
+        })
+}
+
+/// Whether the given channel is open on both ends, between the ports of the path,
+/// with the desired ordering and version.
+fn channel_matches(path: &PathConfig, channel: &ChannelScan) -> bool {
+    let end = &channel.channel.channel_end;
+
+    let Some(counterparty) = &channel.counterparty else {
+        return false;
+    };
+
+    channel.port() == &path.a_port
+        && counterparty.port_id == path.b_port
+        && end.state_matches(&ChannelState::Open)
+        && counterparty.channel_end.state_matches(&ChannelState::Open)
+        && end.ordering() == &path.ordering
+        && path
+            .version
+            .as_ref()
+            .map_or(true, |version| end.version_matches(version))
+}
+
+/// Finds an open channel matching the path, along with its underlying connection and clients.
+fn find_channel(path: &PathConfig, a_scan: &ChainScan, b_scan: &ChainScan) -> Option<PathReport> {
+    matching_connections(path, a_scan, b_scan).find_map(|connection| {
+        let channel = connection
+            .channels
+            .values()
+            .find(|channel| channel_matches(path, channel))?;
+
+        let counterparty = channel.counterparty.as_ref()?;
+        let connection_end = connection.connection.end();
+
+        Some(PathReport {
+            a_chain: path.a_chain.clone(),
+            b_chain: path.b_chain.clone(),
+            a_client: Reconciled::Existing(connection_end.client_id().clone()),
+            b_client: Reconciled::Existing(connection_end.counterparty().client_id().clone()),
+            a_connection: Reconciled::Existing(connection.id().clone()),
+            b_connection: Reconciled::Existing(
+                connection_end
+                    .counterparty()
+                    .connection_id()
+                    .cloned()
+                    .unwrap_or_default(),
+            ),
+            a_port: path.a_port.clone(),
+            b_port: path.b_port.clone(),
+            a_channel: Reconciled::Existing(channel.id().clone()),
+            b_channel: Reconciled::Existing(counterparty.channel_id.clone()),
+        })
+    })
+}
+
+/// Finds an open channel between the ports of the path over a connection to chain `b`
+/// whose delay or clients do not match the path, along with that connection.
+fn find_mismatched_channel<'a>(
+    path: &'a PathConfig,
+    a_scan: &'a ChainScan,
+    b_scan: &'a ChainScan,
+) -> Option<(&'a ConnectionScan, &'a ChannelScan)> {
+    let matching: Vec<&ConnectionId> = matching_connections(path, a_scan, b_scan)
+        .map(|connection| connection.id())
+        .collect();
+
+    a_scan
+        .clients
+        .values()
+        .filter(|client| client.counterparty_chain_id() == path.b_chain)
+        .flat_map(|client| client.connections.values())
+        .filter(|connection| !matching.contains(&connection.id()))
+        .find_map(|connection| {
+            connection
+                .channels
+                .values()
+                .find(|channel| channel_matches(path, channel))
+                .map(|channel| (connection, channel))
+        })
+}
+
+/// Finds an open connection matching the path, over which to create the channel.
+fn find_connection<'a>(
+    path: &'a PathConfig,
+    a_scan: &'a ChainScan,
+    b_scan: &'a ChainScan,
+) -> Option<&'a ConnectionScan> {
+    matching_connections(path, a_scan, b_scan).next()
+}
+
+/// Finds an active client hosted on `host_chain` which tracks `target_chain`
+/// with the desired settings, or creates one if there is none.
+///
+/// Returns the client, along with whether it was created.
+fn find_or_create_client<HostChain: ChainHandle, TargetChain: ChainHandle>(
+    host_scan: &ChainScan,
+    settings: &PathClientSettings,
+    host_chain: HostChain,
+    target_chain: TargetChain,
+) -> Result<(ForeignClient<HostChain, TargetChain>, bool), ReconcileError> {
+    let candidates = host_scan.clients.values().filter(|client| {
+        client.counterparty_chain_id() == target_chain.id()
+            && client_matches(&client.client, settings)
+    });
+
+    for candidate in candidates {
+        let client = ForeignClient::restore(
+            candidate.id().clone(),
+            host_chain.clone(),
+            target_chain.clone(),
+        );
+
+        match client.validated_client_state() {
+            Ok(_) => {
+                info!(client = %client.id(), chain = %host_chain.id(), "found active client");
+                return Ok((client, false));
+            }
+            Err(e) => warn!(
+                client = %client.id(),
+                chain = %host_chain.id(),
+                "skipping client which cannot be reused: {e}"
+            ),
+        }
+    }
+
+    info!(chain = %host_chain.id(), "creating client...");
+
+    let client = ForeignClient::restore(ClientId::default(), host_chain, target_chain);
+
+    let event = client
+        .build_create_client_and_send(settings.create_options())
+        .map_err(ReconcileError::foreign_client)?;
+
+    let client_id = extract_client_id(&event.event)
+        .map_err(ReconcileError::foreign_client)?
+        .clone();
+
+    let (host_chain, target_chain) = (client.dst_chain(), client.src_chain());
+
+    Ok((
+        ForeignClient::restore(client_id, host_chain, target_chain),
+        true,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use core::str::FromStr;
+    use core::time::Duration;
+
+    use test_log::test;
+
+    use ibc_relayer_types::clients::ics07_tendermint::client_state::{
+        AllowUpdate, ClientState as TmClientState,
+    };
+    use ibc_relayer_types::core::ics02_client::trust_threshold::TrustThreshold;
+    use ibc_relayer_types::core::ics03_connection::connection::{
+        ConnectionEnd, Counterparty as ConnectionCounterparty,
+    };
+    use ibc_relayer_types::core::ics03_connection::version::get_compatible_versions;
+    use ibc_relayer_types::core::ics04_channel::channel::{
+        ChannelEnd, Counterparty as ChannelCounterparty, Ordering,
+    };
+    use ibc_relayer_types::core::ics04_channel::version::Version;
+    use ibc_relayer_types::core::ics23_commitment::specs::ProofSpecs;
+    use ibc_relayer_types::Height;
+
+    use crate::client_state::AnyClientState;
+
+    const TRUSTING_PERIOD: Duration = Duration::from_secs(64000);
+
+    fn chain_a() -> ChainId {
+        ChainId::from_string("ibc-0")
+    }
+
+    fn chain_b() -> ChainId {
+        ChainId::from_string("ibc-1")
+    }
+
+    fn client_id(id: &str) -> ClientId {
+        ClientId::from_str(id).unwrap()
+    }
+
+    fn connection_id(id: &str) -> ConnectionId {
+        ConnectionId::from_str(id).unwrap()
+    }
+
+    fn channel_id(id: &str) -> ChannelId {
+        ChannelId::from_str(id).unwrap()
+    }
+
+    fn path() -> PathConfig {
+        PathConfig {
+            a_chain: chain_a(),
+            b_chain: chain_b(),
+            a_port: PortId::transfer(),
+            b_port: PortId::transfer(),
+            version: Some(Version::ics20()),
+            ordering: Ordering::Unordered,
+            connection_delay: Duration::ZERO,
+            a_client: PathClientSettings {
+                trusting_period: Some(TRUSTING_PERIOD),
+                ..PathClientSettings::default()
+            },
+            b_client: PathClientSettings::default(),
+        }
+    }
+
+    /// A Tendermint client with the given identifier, tracking the given chain.
+    fn client(id: &str, chain_id: ChainId, trusting_period: Duration) -> IdentifiedAnyClientState {
+        let client_state = TmClientState::new(
+            chain_id,
+            TrustThreshold::ONE_THIRD,
+            trusting_period,
+            Duration::from_secs(128000),
+            Duration::from_millis(3000),
+            Height::new(0, 10).unwrap(),
+            ProofSpecs::default(),
+            vec!["upgrade".to_string(), "upgradedIBCState".to_string()],
+            AllowUpdate {
+                after_expiry: true,
+                after_misbehaviour: true,
+            },
+        )
+        .unwrap();
+
+        IdentifiedAnyClientState::new(client_id(id), client_state.into())
+    }
+
+    /// An open connection with the given delay, between clients with the same identifier.
+    fn connection(id: &str, client: &str, delay_period: Duration) -> IdentifiedConnectionEnd {
+        let end = ConnectionEnd::new(
+            ConnectionState::Open,
+            client_id(client),
+            ConnectionCounterparty::new(
+                client_id(client),
+                Some(connection_id(id)),
+                Default::default(),
+            ),
+            get_compatible_versions(),
+            delay_period,
+        );
+
+        IdentifiedConnectionEnd::new(connection_id(id), end)
+    }
+
+    /// A channel end between `transfer` ports, with the same identifier on both chains.
+    fn channel_end(id: &str, state: ChannelState, connection: &str) -> IdentifiedChannelEnd {
+        let end = ChannelEnd::new(
+            state,
+            Ordering::Unordered,
+            ChannelCounterparty::new(PortId::transfer(), Some(channel_id(id))),
+            vec![connection_id(connection)],
+            Version::ics20(),
+        );
+
+        IdentifiedChannelEnd::new(PortId::transfer(), channel_id(id), end)
+    }
+
+    fn open_channel(id: &str, connection: &str) -> ChannelScan {
+        ChannelScan::new(
+            channel_end(id, ChannelState::Open, connection),
+            Some(channel_end(id, ChannelState::Open, connection)),
+        )
+    }
+
+    fn client_scan(
+        client: IdentifiedAnyClientState,
+        connections: impl IntoIterator<Item = ConnectionScan>,
+    ) -> (ClientId, ClientScan) {
+        let mut scan = ClientScan::new(client);
+        scan.connections = connections
+            .into_iter()
+            .map(|connection| (connection.id().clone(), connection))
+            .collect();
+
+        (scan.id().clone(), scan)
+    }
+
+    fn connection_scan(
+        connection: IdentifiedConnectionEnd,
+        channels: impl IntoIterator<Item = ChannelScan>,
+    ) -> ConnectionScan {
+        let mut scan = ConnectionScan::new(connection, Some(ConnectionState::Open));
+        scan.channels = channels
+            .into_iter()
+            .map(|channel| (channel.id().clone(), channel))
+            .collect();
+
+        scan
+    }
+
+    /// The scans of both chains of the path, with a single client tracking the other chain,
+    /// and a single connection over which the given channels are open, on chain `a`.
+    fn scans(
+        trusting_period: Duration,
+        delay_period: Duration,
+        channels: impl IntoIterator<Item = ChannelScan>,
+    ) -> (ChainScan, ChainScan) {
+        let a_scan = ChainScan {
+            chain_id: chain_a(),
+            clients: BTreeMap::from([client_scan(
+                client("07-tendermint-0", chain_b(), trusting_period),
+                [connection_scan(
+                    connection("connection-0", "07-tendermint-0", delay_period),
+                    channels,
+                )],
+            )]),
+        };
+
+        let b_scan = ChainScan {
+            chain_id: chain_b(),
+            clients: BTreeMap::from([client_scan(
+                client("07-tendermint-0", chain_a(), TRUSTING_PERIOD),
+                [],
+            )]),
+        };
+
+        (a_scan, b_scan)
+    }
+
+    fn mismatched_ids<'a>(
+        path: &'a PathConfig,
+        a_scan: &'a ChainScan,
+        b_scan: &'a ChainScan,
+    ) -> Option<(&'a ConnectionId, &'a ChannelId)> {
+        find_mismatched_channel(path, a_scan, b_scan)
+            .map(|(connection, channel)| (connection.id(), channel.id()))
+    }
+
+    #[test]
+    fn client_matches_settings() {
+        let client = client("07-tendermint-0", chain_b(), TRUSTING_PERIOD);
+
+        // Unspecified settings match any client
+        assert!(client_matches(&client, &PathClientSettings::default()));
+
+        let settings = PathClientSettings {
+            max_clock_drift: Some(Duration::from_millis(3000)),
+            trusting_period: Some(TRUSTING_PERIOD),
+            trust_threshold: Some(TrustThreshold::ONE_THIRD),
+        };
+        assert!(client_matches(&client, &settings));
+
+        for mismatched in [
+            PathClientSettings {
+                max_clock_drift: Some(Duration::from_secs(10)),
+                ..settings.clone()
+            },
+            PathClientSettings {
+                trusting_period: Some(TRUSTING_PERIOD / 2),
+                ..settings.clone()
+            },
+            PathClientSettings {
+                trust_threshold: Some(TrustThreshold::TWO_THIRDS),
+                ..settings.clone()
+            },
+        ] {
+            assert!(!client_matches(&client, &mismatched));
+        }
+    }
+
+    #[test]
+    fn frozen_client_never_matches() {
+        let mut client = client("07-tendermint-0", chain_b(), TRUSTING_PERIOD);
+
+        match &mut client.client_state {
+            AnyClientState::Tendermint(state) => {
+                state.frozen_height = Some(Height::new(0, 1).unwrap())
+            }
+            state => panic!("unexpected client state: {state:?}"),
+        }
+
+        assert!(!client_matches(&client, &PathClientSettings::default()));
+    }
+
+    #[test]
+    fn channel_matches_path() {
+        let path = path();
+
+        assert!(channel_matches(
+            &path,
+            &open_channel("channel-0", "connection-0")
+        ));
+
+        let mut channel = open_channel("channel-0", "connection-0");
+        channel.channel.channel_end.version = Version::ics20_with_fee();
+        assert!(!channel_matches(&path, &channel));
+
+        // Any version matches when the version of the path is unspecified
+        assert!(channel_matches(
+            &PathConfig {
+                version: None,
+                ..path.clone()
+            },
+            &channel
+        ));
+
+        let mut channel = open_channel("channel-0", "connection-0");
+        channel.channel.channel_end.ordering = Ordering::Ordered;
+        assert!(!channel_matches(&path, &channel));
+
+        let mut channel = open_channel("channel-0", "connection-0");
+        channel.channel.port_id = PortId::from_str("other").unwrap();
+        assert!(!channel_matches(&path, &channel));
+
+        let mut channel = open_channel("channel-0", "connection-0");
+        channel.counterparty.as_mut().unwrap().port_id = PortId::from_str("other").unwrap();
+        assert!(!channel_matches(&path, &channel));
+    }
+
+    #[test]
+    fn channel_open_on_one_end_only_does_not_match() {
+        let path = path();
+
+        let channel = ChannelScan::new(
+            channel_end("channel-0", ChannelState::Open, "connection-0"),
+            Some(channel_end(
+                "channel-0",
+                ChannelState::TryOpen,
+                "connection-0",
+            )),
+        );
+        assert!(!channel_matches(&path, &channel));
+
+        let channel = ChannelScan::new(
+            channel_end("channel-0", ChannelState::Open, "connection-0"),
+            None,
+        );
+        assert!(!channel_matches(&path, &channel));
+    }
+
+    #[test]
+    fn find_matching_channel() {
+        let path = path();
+        let (a_scan, b_scan) = scans(
+            TRUSTING_PERIOD,
+            Duration::ZERO,
+            [open_channel("channel-0", "connection-0")],
+        );
+
+        let report = find_channel(&path, &a_scan, &b_scan).unwrap();
+
+        assert_eq!(
+            report,
+            PathReport {
+                a_chain: chain_a(),
+                b_chain: chain_b(),
+                a_client: Reconciled::Existing(client_id("07-tendermint-0")),
+                b_client: Reconciled::Existing(client_id("07-tendermint-0")),
+                a_connection: Reconciled::Existing(connection_id("connection-0")),
+                b_connection: Reconciled::Existing(connection_id("connection-0")),
+                a_port: PortId::transfer(),
+                b_port: PortId::transfer(),
+                a_channel: Reconciled::Existing(channel_id("channel-0")),
+                b_channel: Reconciled::Existing(channel_id("channel-0")),
+            }
+        );
+        assert!(!report.has_created());
+
+        assert_eq!(mismatched_ids(&path, &a_scan, &b_scan), None);
+    }
+
+    #[test]
+    fn find_no_channel_over_matching_connection() {
+        let path = path();
+        let (a_scan, b_scan) = scans(TRUSTING_PERIOD, Duration::ZERO, []);
+
+        assert_eq!(find_channel(&path, &a_scan, &b_scan), None);
+        assert_eq!(mismatched_ids(&path, &a_scan, &b_scan), None);
+
+        // The channel is to be created over the existing connection
+        assert_eq!(
+            find_connection(&path, &a_scan, &b_scan).map(|connection| connection.id()),
+            Some(&connection_id("connection-0"))
+        );
+    }
+
+    #[test]
+    fn find_channel_over_connection_with_mismatched_delay() {
+        let path = path();
+        let (a_scan, b_scan) = scans(
+            TRUSTING_PERIOD,
+            Duration::from_secs(10),
+            [open_channel("channel-0", "connection-0")],
+        );
+
+        assert_eq!(find_channel(&path, &a_scan, &b_scan), None);
+        assert_eq!(
+            find_connection(&path, &a_scan, &b_scan).map(|c| c.id()),
+            None
+        );
+        assert_eq!(
+            mismatched_ids(&path, &a_scan, &b_scan),
+            Some((&connection_id("connection-0"), &channel_id("channel-0")))
+        );
+    }
+
+    #[test]
+    fn find_channel_over_connection_with_mismatched_client() {
+        let path = path();
+
+        // Mismatched client on chain `a`
+        let (a_scan, b_scan) = scans(
+            TRUSTING_PERIOD / 2,
+            Duration::ZERO,
+            [open_channel("channel-0", "connection-0")],
+        );
+
+        assert_eq!(find_channel(&path, &a_scan, &b_scan), None);
+        assert_eq!(
+            mismatched_ids(&path, &a_scan, &b_scan),
+            Some((&connection_id("connection-0"), &channel_id("channel-0")))
+        );
+
+        // Mismatched client on chain `b`
+        let (a_scan, b_scan) = scans(
+            TRUSTING_PERIOD,
+            Duration::ZERO,
+            [open_channel("channel-0", "connection-0")],
+        );
+        let path = PathConfig {
+            b_client: PathClientSettings {
+                trusting_period: Some(TRUSTING_PERIOD / 2),
+                ..PathClientSettings::default()
+            },
+            ..path
+        };
+
+        assert_eq!(find_channel(&path, &a_scan, &b_scan), None);
+        assert_eq!(
+            mismatched_ids(&path, &a_scan, &b_scan),
+            Some((&connection_id("connection-0"), &channel_id("channel-0")))
+        );
+    }
+
+    #[test]
+    fn ignore_channels_to_other_chains() {
+        let path = path();
+        let (mut a_scan, b_scan) = scans(TRUSTING_PERIOD, Duration::ZERO, []);
+
+        // A channel over a connection to another chain, with a mismatched delay
+        let (id, scan) = client_scan(
+            client(
+                "07-tendermint-1",
+                ChainId::from_string("ibc-2"),
+                TRUSTING_PERIOD,
+            ),
+            [connection_scan(
+                connection("connection-1", "07-tendermint-1", Duration::from_secs(10)),
+                [open_channel("channel-1", "connection-1")],
+            )],
+        );
+        a_scan.clients.insert(id, scan);
+
+        assert_eq!(find_channel(&path, &a_scan, &b_scan), None);
+        assert_eq!(mismatched_ids(&path, &a_scan, &b_scan), None);
+    }
+}
@@ -0,0 +1,451 @@
+//! Recovery of expired or frozen clients through governance.
+//!
+//! An expired or frozen client (the subject) can be recovered by replacing its state with
+//! the state of an active client tracking the same chain (the substitute), provided that both
+//! clients have the same parameters. This module creates the substitute client if needed,
+//! checks that it matches the subject client, and builds the governance proposal to submit
+//! to the host chain in order to perform the recovery.
+
+use bech32::{ToBase32, Variant};
+use flex_error::define_error;
+use serde_json::{json, Value};
+use sha2::{Digest, Sha256};
+use tracing::info;
+
+use ibc_relayer_types::clients::ics07_tendermint::client_state::ClientState as TmClientState;
+use ibc_relayer_types::core::ics02_client::msgs::recover_client::TYPE_URL as RECOVER_CLIENT_TYPE_URL;
+use ibc_relayer_types::core::ics24_host::identifier::{ChainId, ClientId};
+
+use crate::chain::handle::ChainHandle;
+use crate::chain::requests::{IncludeProof, QueryClientStateRequest, QueryHeight};
+use crate::client_state::AnyClientState;
+use crate::error::Error;
+use crate::foreign_client::{
+    extract_client_id, ClientExpiryStatus, CreateOptions, ForeignClient, ForeignClientError,
+};
+
+const CLIENT_UPDATE_PROPOSAL_TYPE_URL: &str = "/ibc.core.client.v1.ClientUpdateProposal";
+const EXEC_LEGACY_CONTENT_TYPE_URL: &str = "/cosmos.gov.v1.MsgExecLegacyContent";
+
+define_error! {
+    RecoverClientError {
+        Query
+            [ Error ]
+            |_| { "error during a query" },
+
+        ForeignClient
+            [ ForeignClientError ]
+            |_| { "error while creating the substitute client or checking its status" },
+
+        TendermintOnly
+            { client_id: ClientId }
+            |e| { format!("only Tendermint clients can be recovered, client {} is not one", e.client_id) },
+
+        SubjectActive
+            { client_id: ClientId }
+            |e| { format!("client {} is neither expired nor frozen and does not need to be recovered", e.client_id) },
+
+        SubstituteNotActive
+            { client_id: ClientId }
+            |e| { format!("substitute client {} is expired or frozen", e.client_id) },
+
+        SubstituteMismatch
+            {
+                subject: ClientId,
+                substitute: ClientId,
+                fields: Vec<&'static str>,
+            }
+            |e| {
+                format!("substitute client {} does not match subject client {}, the following parameters differ: {}",
+                    e.substitute, e.subject, e.fields.join(", "))
+            },
+
+        SubstituteNotAhead
+            {
+                subject: ClientId,
+                substitute: ClientId,
+            }
+            |e| {
+                format!("the latest height of substitute client {} must be greater than the one of subject client {}",
+                    e.substitute, e.subject)
+            },
+
+        Authority
+            { chain_id: ChainId }
+            |e| { format!("failed to derive the address of the governance module of chain {}", e.chain_id) },
+    }
+}
+
+/// The format of the governance proposal recovering the client.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ProposalKind {
+    /// A `MsgRecoverClient` message, for chains using ibc-go v8 and later.
+    RecoverClient,
+    /// A legacy `ClientUpdateProposal`, wrapped in a `MsgExecLegacyContent` message,
+    /// for chains using earlier versions of ibc-go.
+    ClientUpdate,
+}
+
+#[derive(Clone, Debug)]
+pub struct RecoverClientOptions {
+    pub subject_client_id: ClientId,
+    /// The substitute client, which is created when not given.
+    pub substitute_client_id: Option<ClientId>,
+    pub kind: ProposalKind,
+    /// The signer of the proposal messages, which defaults to the governance module account.
+    pub authority: Option<String>,
+    /// The initial deposit of the proposal, eg. `10000000stake`.
+    pub deposit: String,
+    pub title: Option<String>,
+}
+
+/// The outcome of [`build_recover_client_proposal`].
+#[derive(Clone, Debug)]
+pub struct RecoverClientProposal {
+    pub subject_client_id: ClientId,
+    pub substitute_client_id: ClientId,
+    /// Whether the substitute client was created.
+    pub created_substitute: bool,
+    /// The proposal to submit with `<chain binary> tx gov submit-proposal <file>`.
+    pub proposal: Value,
+}
+
+/// Builds the governance proposal recovering the subject client hosted on `host_chain`,
+/// with a substitute client tracking `reference_chain`.
+pub fn build_recover_client_proposal<HostChain: ChainHandle, ReferenceChain: ChainHandle>(
+    host_chain: HostChain,
+    reference_chain: ReferenceChain,
+    opts: &RecoverClientOptions,
+) -> Result<RecoverClientProposal, RecoverClientError> {
+    let subject_state = query_tendermint_client_state(&host_chain, &opts.subject_client_id)?;
+
+    let subject = ForeignClient::restore(
+        opts.subject_client_id.clone(),
+        host_chain.clone(),
+        reference_chain.clone(),
+    );
+
+    if let ClientExpiryStatus::Active { .. } = subject
+        .expiry_status()
+        .map_err(RecoverClientError::foreign_client)?
+    {
+        return Err(RecoverClientError::subject_active(
+            opts.subject_client_id.clone(),
+        ));
+    }
+
+    let (substitute_client_id, created_substitute) = match &opts.substitute_client_id {
+        Some(substitute_client_id) => (substitute_client_id.clone(), false),
+        None => {
+            let client = ForeignClient::restore(
+                ClientId::default(),
+                host_chain.clone(),
+                reference_chain.clone(),
+            );
+
+            let options = CreateOptions {
+                max_clock_drift: Some(subject_state.max_clock_drift),
+                trusting_period: Some(subject_state.trusting_period),
+                trust_threshold: Some(subject_state.trust_threshold),
+            };
+
+            let event = client
+                .build_create_client_and_send(options)
+                .map_err(RecoverClientError::foreign_client)?;
+
+            let client_id = extract_client_id(&event.event)
+                .map_err(RecoverClientError::foreign_client)?
+                .clone();
+
+            info!(
+                "created substitute client {} on chain {}",
+                client_id,
+                host_chain.id()
+            );
+
+            (client_id, true)
+        }
+    };
+
+    let substitute = ForeignClient::restore(
+        substitute_client_id.clone(),
+        host_chain.clone(),
+        reference_chain,
+    );
+
+    if !matches!(
+        substitute
+            .expiry_status()
+            .map_err(RecoverClientError::foreign_client)?,
+        ClientExpiryStatus::Active { .. }
+    ) {
+        return Err(RecoverClientError::substitute_not_active(
+            substitute_client_id,
+        ));
+    }
+
+    let substitute_state = query_tendermint_client_state(&host_chain, &substitute_client_id)?;
+
+    check_substitute_client_state(
+        &opts.subject_client_id,
+        &subject_state,
+        &substitute_client_id,
+        &substitute_state,
+    )?;
+
+    let authority = match &opts.authority {
+        Some(authority) => authority.clone(),
+        None => {
+            let account_prefix = host_chain
+                .config()
+                .map_err(RecoverClientError::query)?
+                .account_prefix;
+
+            gov_module_address(&account_prefix)
+                .ok_or_else(|| RecoverClientError::authority(host_chain.id()))?
+        }
+    };
+
+    let proposal = build_proposal(
+        opts,
+        &substitute_client_id,
+        &authority,
+        &subject_state.chain_id,
+    );
+
+    Ok(RecoverClientProposal {
+        subject_client_id: opts.subject_client_id.clone(),
+        substitute_client_id,
+        created_substitute,
+        proposal,
+    })
+}
+
+fn query_tendermint_client_state(
+    host_chain: &impl ChainHandle,
+    client_id: &ClientId,
+) -> Result<TmClientState, RecoverClientError> {
+    let (client_state, _) = host_chain
+        .query_client_state(
+            QueryClientStateRequest {
+                client_id: client_id.clone(),
+                height: QueryHeight::Latest,
+            },
+            IncludeProof::No,
+        )
+        .map_err(RecoverClientError::query)?;
+
+    match client_state {
+        AnyClientState::Tendermint(client_state) => Ok(client_state),
+        _ => Err(RecoverClientError::tendermint_only(client_id.clone())),
+    }
+}
+
+/// Checks that the substitute client can replace the subject client, following the
+/// rules of ibc-go: all the parameters of both clients must be equal, except for their
+/// chain identifier, latest height, frozen height, trusting period and the deprecated
+/// `allow_update` flags, and the substitute client must be ahead of the subject client.
+pub fn check_substitute_client_state(
+    subject_client_id: &ClientId,
+    subject: &TmClientState,
+    substitute_client_id: &ClientId,
+    substitute: &TmClientState,
+) -> Result<(), RecoverClientError> {
+    let mut fields = Vec::new();
+
+    if subject.trust_threshold != substitute.trust_threshold {
+        fields.push("trust_threshold");
+    }
+    if subject.unbonding_period != substitute.unbonding_period {
+        fields.push("unbonding_period");
+    }
+    if subject.max_clock_drift != substitute.max_clock_drift {
+        fields.push("max_clock_drift");
+    }
+    if subject.proof_specs != substitute.proof_specs {
+        fields.push("proof_specs");
+    }
+    if subject.upgrade_path != substitute.upgrade_path {
+        fields.push("upgrade_path");
+    }
+
+    if !fields.is_empty() {
+        return Err(RecoverClientError::substitute_mismatch(
+            subject_client_id.clone(),
+            substitute_client_id.clone(),
+            fields,
+        ));
+    }
+
+    if substitute.latest_height <= subject.latest_height {
+        return Err(RecoverClientError::substitute_not_ahead(
+            subject_client_id.clone(),
+            substitute_client_id.clone(),
+        ));
+    }
+
+    Ok(())
+}
+
+/// The address of the governance module account, which is the authority of the
+/// `02-client` module unless configured otherwise.
+fn gov_module_address(account_prefix: &str) -> Option<String> {
+    let hash = Sha256::digest(b"gov");
+
+    bech32::encode(account_prefix, (&hash[..20]).to_base32(), Variant::Bech32).ok()
+}
+
+/// Builds the proposal in the format expected by `<chain binary> tx gov submit-proposal`.
+fn build_proposal(
+    opts: &RecoverClientOptions,
+    substitute_client_id: &ClientId,
+    authority: &str,
+    reference_chain_id: &ChainId,
+) -> Value {
+    let title = opts
+        .title
+        .clone()
+        .unwrap_or_else(|| format!("Recover client {}", opts.subject_client_id));
+
+    let summary = format!(
+        "Replace the state of the expired or frozen client {} tracking chain {} with the state of client {}",
+        opts.subject_client_id, reference_chain_id, substitute_client_id
+    );
+
+    let message = match opts.kind {
+        ProposalKind::RecoverClient => json!({
+            "@type": RECOVER_CLIENT_TYPE_URL,
+            "subject_client_id": opts.subject_client_id,
+            "substitute_client_id": substitute_client_id,
+            "signer": authority,
+        }),
+        ProposalKind::ClientUpdate => json!({
+            "@type": EXEC_LEGACY_CONTENT_TYPE_URL,
+            "content": {
+                "@type": CLIENT_UPDATE_PROPOSAL_TYPE_URL,
+                "title": title,
+                "description": summary,
+                "subject_client_id": opts.subject_client_id,
+                "substitute_client_id": substitute_client_id,
+            },
+            "authority": authority,
+        }),
+    };
+
+    json!({
+        "messages": [message],
+        "metadata": "",
+        "deposit": opts.deposit,
+        "title": title,
+        "summary": summary,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use core::str::FromStr;
+    use core::time::Duration;
+
+    use test_log::test;
+
+    use ibc_relayer_types::clients::ics07_tendermint::client_state::AllowUpdate;
+    use ibc_relayer_types::core::ics23_commitment::specs::ProofSpecs;
+    use ibc_relayer_types::Height;
+
+    fn client_state(height: u64) -> TmClientState {
+        TmClientState::new(
+            ChainId::from_string("chain-a"),
+            Default::default(),
+            Duration::from_secs(64000),
+            Duration::from_secs(128000),
+            Duration::from_millis(3000),
+            Height::new(0, height).unwrap(),
+            ProofSpecs::default(),
+            vec!["upgrade".to_string(), "upgradedIBCState".to_string()],
+            AllowUpdate {
+                after_expiry: true,
+                after_misbehaviour: true,
+            },
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn substitute_matches() {
+        let subject_id = ClientId::from_str("07-tendermint-0").unwrap();
+        let substitute_id = ClientId::from_str("07-tendermint-1").unwrap();
+
+        let subject = client_state(10);
+        let mut substitute = client_state(20);
+
+        // The trusting period and the deprecated `allow_update` flags of the substitute
+        // client are allowed to differ
+        substitute.trusting_period = Duration::from_secs(32000);
+        substitute.allow_update.after_expiry = false;
+
+        assert!(
+            check_substitute_client_state(&subject_id, &subject, &substitute_id, &substitute)
+                .is_ok()
+        );
+
+        assert!(
+            check_substitute_client_state(&subject_id, &subject, &substitute_id, &subject).is_err()
+        );
+
+        substitute.max_clock_drift = Duration::from_secs(10);
+        substitute.unbonding_period = Duration::from_secs(10);
+
+        let err = check_substitute_client_state(&subject_id, &subject, &substitute_id, &substitute)
+            .unwrap_err();
+
+        match err.detail() {
+            RecoverClientErrorDetail::SubstituteMismatch(e) => {
+                assert_eq!(e.fields, vec!["unbonding_period", "max_clock_drift"])
+            }
+            _ => panic!("unexpected error: {err}"),
+        }
+    }
+
+    #[test]
+    fn gov_address() {
+        assert_eq!(
+            gov_module_address("cosmos").unwrap(),
+            "cosmos10d07y265gmmuvt4z0w9aw880jnsr700j6zn9kn"
+        );
+    }
+
+    #[test]
+    fn recover_client_proposal() {
+        let opts = RecoverClientOptions {
+            subject_client_id: ClientId::from_str("07-tendermint-0").unwrap(),
+            substitute_client_id: None,
+            kind: ProposalKind::RecoverClient,
+            authority: None,
+            deposit: "10000000stake".to_string(),
+            title: None,
+        };
+
+        let substitute_id = ClientId::from_str("07-tendermint-1").unwrap();
+
+        let proposal = build_proposal(
+            &opts,
+            &substitute_id,
+            "cosmos10d07y265gmmuvt4z0w9aw880jnsr700j6zn9kn",
+            &ChainId::from_string("chain-a"),
+        );
+
+        assert_eq!(proposal["deposit"], "10000000stake");
+        assert_eq!(proposal["title"], "Recover client 07-tendermint-0");
+        assert_eq!(
+            proposal["messages"][0],
+            json!({
+                "@type": "/ibc.core.client.v1.MsgRecoverClient",
+                "subject_client_id": "07-tendermint-0",
+                "substitute_client_id": "07-tendermint-1",
+                "signer": "cosmos10d07y265gmmuvt4z0w9aw880jnsr700j6zn9kn",
+            })
+        );
+    }
+}
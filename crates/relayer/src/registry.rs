@@ -81,6 +81,14 @@ impl<Chain: ChainHandle> Registry<Chain> {
         }
     }
 
+    /// Replace the configuration used when spawning new chain runtimes.
+    ///
+    /// Runtimes which have already been spawned are not affected,
+    /// they must be shut down and respawned to pick up the new configuration.
+    pub fn update_config(&mut self, config: Config) {
+        self.config = config;
+    }
+
     /// Shutdown the runtime associated with the given chain identifier.
     pub fn shutdown(&mut self, chain_id: &ChainId) {
         if let Some(handle) = self.handles.remove(chain_id) {
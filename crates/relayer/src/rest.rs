@@ -1,10 +1,13 @@
 use crossbeam_channel::TryRecvError;
 use tracing::{error, trace};
 
+use ibc_relayer_types::core::ics24_host::identifier::{ChainId, ChannelId, PortId};
+
 use crate::{
+    chain::counterparty::PendingPackets,
     config::Config,
     rest::request::ReplySender,
-    rest::request::{Request, VersionInfo},
+    rest::request::{ClearPacketsOutcome, Request, VersionInfo},
     supervisor::dump_state::SupervisorState,
 };
 
@@ -31,6 +34,18 @@ pub type Receiver = crossbeam_channel::Receiver<Request>;
 //  e.g., adjusting chain config, removing chains, etc.
 pub enum Command {
     DumpState(ReplySender<SupervisorState>),
+    PendingPackets {
+        chain_id: ChainId,
+        port_id: PortId,
+        channel_id: ChannelId,
+        reply_to: ReplySender<PendingPackets>,
+    },
+    ClearPackets {
+        chain_id: ChainId,
+        port_id: PortId,
+        channel_id: ChannelId,
+        reply_to: ReplySender<ClearPacketsOutcome>,
+    },
 }
 
 /// Process incoming REST requests.
@@ -82,6 +97,49 @@ pub fn process_incoming_requests(config: &Config, channel: &Receiver) -> Option<
 
                 return Some(Command::DumpState(reply_to));
             }
+
+            Request::GetPendingPackets {
+                chain_id,
+                port_id,
+                channel_id,
+                reply_to,
+            } => {
+                trace!("GetPendingPackets {}/{}/{}", chain_id, port_id, channel_id);
+
+                return Some(Command::PendingPackets {
+                    chain_id,
+                    port_id,
+                    channel_id,
+                    reply_to,
+                });
+            }
+
+            Request::ClearPackets {
+                chain_id,
+                port_id,
+                channel_id,
+                reply_to,
+            } => {
+                trace!("ClearPackets {}/{}/{}", chain_id, port_id, channel_id);
+
+                return Some(Command::ClearPackets {
+                    chain_id,
+                    port_id,
+                    channel_id,
+                    reply_to,
+                });
+            }
+
+            Request::ReloadConfig { reply_to } => {
+                trace!("ReloadConfig");
+
+                // The configuration can only be reloaded by the process which loaded it from
+                // the configuration file, ie. `hermes start`, which handles these requests
+                // before forwarding the other ones to the supervisor.
+                reply_to
+                    .send(Err(RestApiError::Unimplemented))
+                    .unwrap_or_else(|e| error!("error replying to a REST request {}", e));
+            }
         },
         Err(e) => {
             if !matches!(e, TryRecvError::Empty) {
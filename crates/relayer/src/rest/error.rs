@@ -1,7 +1,10 @@
 use serde::ser::{Serialize, SerializeMap, Serializer};
 use thiserror::Error;
 
-use ibc_relayer_types::core::ics24_host::{error::ValidationErrorDetail, identifier::ChainId};
+use ibc_relayer_types::core::ics24_host::{
+    error::ValidationErrorDetail,
+    identifier::{ChainId, ChannelId, PortId},
+};
 
 #[derive(Error, Debug)]
 pub enum RestApiError {
@@ -23,6 +26,27 @@ pub enum RestApiError {
     #[error("failed while parsing the request body into a chain configuration: {0}")]
     InvalidChainConfig(String),
 
+    #[error("failed to parse the string {0} into a valid port identifier: {1}")]
+    InvalidPortId(String, ValidationErrorDetail),
+
+    #[error("failed to parse the string {0} into a valid channel identifier: {1}")]
+    InvalidChannelId(String, ValidationErrorDetail),
+
+    #[error("chain {0} is not relayed by the supervisor")]
+    ChainNotRelayed(ChainId),
+
+    #[error("could not find a packet worker relaying from channel {2} on port {1} of chain {0}")]
+    PacketWorkerNotFound(ChainId, PortId, ChannelId),
+
+    #[error("failed to query the pending packets: {0}")]
+    PendingPackets(String),
+
+    #[error("failed to clear the pending packets: {0}")]
+    ClearPackets(String),
+
+    #[error("failed to reload the configuration: {0}")]
+    ReloadConfig(String),
+
     #[error("not implemented")]
     Unimplemented,
 }
@@ -36,6 +60,13 @@ impl RestApiError {
             RestApiError::ChainConfigNotFound(_) => "ChainConfigNotFound",
             RestApiError::InvalidChainId(_, _) => "InvalidChainId",
             RestApiError::InvalidChainConfig(_) => "InvalidChainConfig",
+            RestApiError::InvalidPortId(_, _) => "InvalidPortId",
+            RestApiError::InvalidChannelId(_, _) => "InvalidChannelId",
+            RestApiError::ChainNotRelayed(_) => "ChainNotRelayed",
+            RestApiError::PacketWorkerNotFound(_, _, _) => "PacketWorkerNotFound",
+            RestApiError::PendingPackets(_) => "PendingPackets",
+            RestApiError::ClearPackets(_) => "ClearPackets",
+            RestApiError::ReloadConfig(_) => "ReloadConfig",
             RestApiError::Unimplemented => "Unimplemented",
         }
     }
@@ -1,8 +1,13 @@
 use serde::{Deserialize, Serialize};
 
-use ibc_relayer_types::core::ics24_host::identifier::ChainId;
+use ibc_relayer_types::core::ics24_host::identifier::{ChainId, ChannelId, PortId};
 
-use crate::{config::ChainConfig, rest::RestApiError, supervisor::dump_state::SupervisorState};
+use crate::{
+    chain::counterparty::PendingPackets,
+    config::ChainConfig,
+    rest::RestApiError,
+    supervisor::dump_state::{SupervisorState, WorkerDesc},
+};
 
 pub type ReplySender<T> = crossbeam_channel::Sender<Result<T, RestApiError>>;
 pub type ReplyReceiver<T> = crossbeam_channel::Receiver<Result<T, RestApiError>>;
@@ -17,6 +22,16 @@ pub struct VersionInfo {
     pub version: String,
 }
 
+/// The outcome of a request to clear the pending packets of a channel.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct ClearPacketsOutcome {
+    /// The packet worker which cleared the pending packets.
+    ///
+    /// The outcome is only returned once the worker has scheduled and submitted the
+    /// messages relaying the pending packets, a failure to do so is returned as an error.
+    pub worker: WorkerDesc,
+}
+
 /// REST API request variants
 #[derive(Clone, Debug)]
 pub enum Request {
@@ -36,4 +51,29 @@ pub enum Request {
         chain_id: ChainId,
         reply_to: ReplySender<ChainConfig>,
     },
+
+    /// List the packets pending on the channel of a chain,
+    /// ie. the packets sent from this channel and not yet
+    /// received or acknowledged.
+    GetPendingPackets {
+        chain_id: ChainId,
+        port_id: PortId,
+        channel_id: ChannelId,
+        reply_to: ReplySender<PendingPackets>,
+    },
+
+    /// Clear the packets pending on the channel of a chain with
+    /// the packet worker relaying from this channel.
+    ClearPackets {
+        chain_id: ChainId,
+        port_id: PortId,
+        channel_id: ChannelId,
+        reply_to: ReplySender<ClearPacketsOutcome>,
+    },
+
+    /// Reload the configuration file and apply the changes to the chains,
+    /// as on `SIGHUP`, without restarting the relayer.
+    ReloadConfig {
+        reply_to: ReplySender<()>,
+    },
 }
@@ -5,8 +5,13 @@ use tokio::runtime::Runtime as TokioRuntime;
 
 use ibc_relayer_types::core::ics24_host::identifier::ChainId;
 
+#[cfg(any(test, feature = "mocks"))]
+use crate::chain::mock::MockChain;
 use crate::{
-    chain::{cosmos::CosmosSdkChain, handle::ChainHandle, runtime::ChainRuntime, ChainType},
+    chain::{
+        cosmos::CosmosSdkChain, handle::ChainHandle, runtime::ChainRuntime,
+        solomachine::SoloMachineChain, ChainType,
+    },
     config::Config,
     error::Error as RelayerError,
 };
@@ -25,6 +30,12 @@ define_error! {
             | e | {
                 format_args!("missing chain config for '{}' in configuration file", e.chain_id)
             },
+
+        MocksDisabled
+            { chain_id: ChainId }
+            | e | {
+                format_args!("cannot spawn the mock chain '{}', the relayer was built without the `mocks` feature", e.chain_id)
+            },
     }
 }
 
@@ -54,6 +65,13 @@ pub fn spawn_chain_runtime<Handle: ChainHandle>(
 
     let handle = match chain_config.r#type {
         ChainType::CosmosSdk => ChainRuntime::<CosmosSdkChain>::spawn::<Handle>(chain_config, rt),
+        #[cfg(any(test, feature = "mocks"))]
+        ChainType::Mock => ChainRuntime::<MockChain>::spawn::<Handle>(chain_config, rt),
+        #[cfg(not(any(test, feature = "mocks")))]
+        ChainType::Mock => return Err(SpawnError::mocks_disabled(chain_id.clone())),
+        ChainType::SoloMachine => {
+            ChainRuntime::<SoloMachineChain>::spawn::<Handle>(chain_config, rt)
+        }
     }
     .map_err(SpawnError::relayer)?;
 
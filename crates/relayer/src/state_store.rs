@@ -0,0 +1,464 @@
+//! Optional on-disk store of the relaying state.
+//!
+//! The store persists, for each packet path, the packets whose relaying is in flight
+//! and the hashes of the transactions relaying them, as well as the height of the last
+//! event batch handled for each chain. When Hermes restarts, the packet workers resume
+//! relaying from this state instead of clearing all the pending packets: they relay the
+//! packets left in flight and the ones sent since the last handled height.
+//!
+//! The state is kept in memory and periodically written to a JSON file with [`StateStore::flush`],
+//! as well as when the store is dropped. The file is replaced atomically on every write.
+
+use alloc::collections::btree_map::BTreeMap as HashMap;
+use alloc::collections::BTreeSet;
+use alloc::sync::Arc;
+use std::fs;
+use std::io;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Mutex;
+
+use flex_error::{define_error, TraceError};
+use serde::{Deserialize, Serialize};
+use tendermint::Hash as TxHash;
+use tracing::{debug, warn};
+
+use ibc_relayer_types::core::ics04_channel::packet::Sequence;
+use ibc_relayer_types::core::ics24_host::identifier::{ChainId, ChannelId, PortId};
+use ibc_relayer_types::Height;
+
+use crate::config::StateConfig;
+
+pub const STATE_DEFAULT_PATH: &str = ".hermes/state.json";
+
+define_error! {
+    StateStoreError {
+        HomeLocationUnavailable
+            |_| { "home location is unavailable" },
+
+        Io
+            { path: PathBuf }
+            [ TraceError<io::Error> ]
+            |e| { format!("I/O error on state file {}", e.path.display()) },
+
+        Decode
+            { path: PathBuf }
+            [ TraceError<serde_json::Error> ]
+            |e| { format!("failed to decode state file {}", e.path.display()) },
+
+        Encode
+            [ TraceError<serde_json::Error> ]
+            |_| { "failed to encode the relaying state" },
+    }
+}
+
+/// The relaying state persisted by the [`StateStore`].
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RelayState {
+    /// The state of each chain, indexed by chain identifier.
+    pub chains: HashMap<ChainId, ChainState>,
+    /// The state of each packet path, indexed by [`path_key`].
+    pub paths: HashMap<String, PathState>,
+}
+
+/// The relaying state of a chain.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ChainState {
+    /// Height of the last event batch of the chain handled by the supervisor.
+    pub last_handled_height: Height,
+}
+
+/// The relaying state of a packet path, ie. of the packets sent on a channel end
+/// of the source chain and of the acknowledgements written on it.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PathState {
+    /// Sequences of the packets whose reception or timeout is being relayed.
+    pub tracked_packets: BTreeSet<Sequence>,
+    /// Sequences of the packets whose acknowledgement is being relayed.
+    pub tracked_acks: BTreeSet<Sequence>,
+    /// Transactions submitted by the relayer which are not confirmed yet.
+    pub pending_txs: Vec<PendingTx>,
+}
+
+/// A transaction submitted by the relayer which is not confirmed yet.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PendingTx {
+    pub tracking_id: String,
+    pub chain_id: ChainId,
+    pub tx_hashes: Vec<TxHash>,
+}
+
+/// The key of the state of the packet path from the given channel end of the source chain.
+pub fn path_key(chain_id: &ChainId, port_id: &PortId, channel_id: &ChannelId) -> String {
+    format!("{chain_id}/{port_id}/{channel_id}")
+}
+
+/// A handle to the on-disk store of the relaying state, shared by the supervisor and the workers.
+///
+/// Failures to write the state file are logged and do not interrupt relaying.
+#[derive(Clone, Debug)]
+pub struct StateStore {
+    inner: Arc<StoreInner>,
+}
+
+#[derive(Debug)]
+struct StoreInner {
+    path: PathBuf,
+    /// The state persisted before the relayer was restarted, as loaded when opening the store,
+    /// out of which the state of each path is taken once its worker resumed relaying from it.
+    restored: Mutex<RelayState>,
+    state: Mutex<RelayState>,
+    /// Whether the state changed since it was last written to disk.
+    dirty: AtomicBool,
+    /// Prevents concurrent writes of the state file.
+    write_lock: Mutex<()>,
+}
+
+impl StateStore {
+    /// Opens the store configured in the given `[state]` section, if it is enabled.
+    pub fn from_config(config: &StateConfig) -> Result<Option<Self>, StateStoreError> {
+        if !config.enabled {
+            return Ok(None);
+        }
+
+        let path = match &config.path {
+            Some(path) => path.clone(),
+            None => {
+                let home =
+                    dirs_next::home_dir().ok_or_else(StateStoreError::home_location_unavailable)?;
+                home.join(STATE_DEFAULT_PATH)
+            }
+        };
+
+        Self::open(path).map(Some)
+    }
+
+    /// Opens the store at the given path, loading the state persisted there if any.
+    pub fn open(path: impl Into<PathBuf>) -> Result<Self, StateStoreError> {
+        let path = path.into();
+
+        let state = match fs::read(&path) {
+            Ok(bytes) => serde_json::from_slice(&bytes)
+                .map_err(|e| StateStoreError::decode(path.clone(), e))?,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => RelayState::default(),
+            Err(e) => return Err(StateStoreError::io(path, e)),
+        };
+
+        debug!(path = %path.display(), "opened relaying state store");
+
+        let inner = StoreInner {
+            path,
+            restored: Mutex::new(state.clone()),
+            state: Mutex::new(state),
+            dirty: AtomicBool::new(false),
+            write_lock: Mutex::new(()),
+        };
+
+        Ok(Self {
+            inner: Arc::new(inner),
+        })
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.inner.path
+    }
+
+    /// Returns a copy of the whole relaying state.
+    pub fn state(&self) -> RelayState {
+        self.inner.state.lock().unwrap().clone()
+    }
+
+    pub fn path_state(&self, key: &str) -> Option<PathState> {
+        self.inner.state.lock().unwrap().paths.get(key).cloned()
+    }
+
+    /// Whether a state of the given path was persisted before the relayer was restarted,
+    /// and has not been taken yet.
+    pub fn has_restored_path_state(&self, key: &str) -> bool {
+        self.inner.restored.lock().unwrap().paths.contains_key(key)
+    }
+
+    /// Takes the state of the given path persisted before the relayer was restarted,
+    /// so that relaying is only resumed from it once.
+    pub fn take_restored_path_state(&self, key: &str) -> Option<PathState> {
+        self.inner.restored.lock().unwrap().paths.remove(key)
+    }
+
+    /// Returns the height of the last event batch of the given chain handled
+    /// before the relayer was restarted.
+    pub fn restored_last_handled_height(&self, chain_id: &ChainId) -> Option<Height> {
+        self.inner
+            .restored
+            .lock()
+            .unwrap()
+            .chains
+            .get(chain_id)
+            .map(|chain| chain.last_handled_height)
+    }
+
+    /// Records the height of the last event batch of the given chain handled by the supervisor.
+    pub fn update_last_handled_height(&self, chain_id: &ChainId, height: Height) {
+        self.update(|state| {
+            let chain_state = ChainState {
+                last_handled_height: height,
+            };
+
+            state.chains.insert(chain_id.clone(), chain_state.clone()) != Some(chain_state)
+        })
+    }
+
+    /// Writes the state to disk if it changed since it was last written.
+    pub fn flush(&self) {
+        self.inner.flush()
+    }
+
+    /// Marks the given packets and acknowledgements of a path as being relayed.
+    pub fn track(&self, key: &str, packets: &[Sequence], acks: &[Sequence]) {
+        if packets.is_empty() && acks.is_empty() {
+            return;
+        }
+
+        self.update(|state| {
+            let path_state = state.paths.entry(key.to_string()).or_default();
+
+            path_state.tracked_packets.extend(packets.iter().copied());
+            path_state.tracked_acks.extend(acks.iter().copied());
+
+            true
+        })
+    }
+
+    /// Marks the given packets and acknowledgements of a path as relayed.
+    pub fn untrack(&self, key: &str, packets: &[Sequence], acks: &[Sequence]) {
+        self.update(|state| {
+            let Some(path_state) = state.paths.get_mut(key) else { return false };
+
+            let before = path_state.tracked_packets.len() + path_state.tracked_acks.len();
+
+            for sequence in packets {
+                path_state.tracked_packets.remove(sequence);
+            }
+
+            for sequence in acks {
+                path_state.tracked_acks.remove(sequence);
+            }
+
+            before != path_state.tracked_packets.len() + path_state.tracked_acks.len()
+        })
+    }
+
+    /// Keeps tracking only the packets of a path which are still not received by the
+    /// destination chain, out of the given sequences of unreceived packets.
+    pub fn retain_tracked_packets(&self, key: &str, unreceived: &[Sequence]) {
+        self.update(|state| {
+            let Some(path_state) = state.paths.get_mut(key) else { return false };
+
+            let before = path_state.tracked_packets.len();
+            path_state
+                .tracked_packets
+                .retain(|sequence| unreceived.contains(sequence));
+
+            before != path_state.tracked_packets.len()
+        })
+    }
+
+    /// Keeps tracking only the acknowledgements of a path which are still not received
+    /// by the source chain, out of the given sequences of unreceived acknowledgements.
+    pub fn retain_tracked_acks(&self, key: &str, unreceived: &[Sequence]) {
+        self.update(|state| {
+            let Some(path_state) = state.paths.get_mut(key) else { return false };
+
+            let before = path_state.tracked_acks.len();
+            path_state
+                .tracked_acks
+                .retain(|sequence| unreceived.contains(sequence));
+
+            before != path_state.tracked_acks.len()
+        })
+    }
+
+    pub fn insert_pending_tx(&self, key: &str, pending_tx: PendingTx) {
+        if pending_tx.tx_hashes.is_empty() {
+            return;
+        }
+
+        self.update(|state| {
+            let path_state = state.paths.entry(key.to_string()).or_default();
+            path_state.pending_txs.push(pending_tx);
+            true
+        })
+    }
+
+    /// Forgets the pending transaction with the given hashes.
+    pub fn remove_pending_tx(&self, key: &str, tx_hashes: &[TxHash]) {
+        self.update(|state| {
+            let Some(path_state) = state.paths.get_mut(key) else { return false };
+
+            let before = path_state.pending_txs.len();
+            path_state
+                .pending_txs
+                .retain(|pending_tx| pending_tx.tx_hashes != tx_hashes);
+
+            before != path_state.pending_txs.len()
+        })
+    }
+
+    /// Applies the given update to the state, which is marked as
+    /// to be written to disk on the next flush if it changed.
+    fn update(&self, f: impl FnOnce(&mut RelayState) -> bool) {
+        let mut state = self.inner.state.lock().unwrap();
+
+        if f(&mut state) {
+            self.inner.dirty.store(true, Ordering::Release);
+        }
+    }
+}
+
+impl StoreInner {
+    fn flush(&self) {
+        let _write_guard = self.write_lock.lock().unwrap();
+
+        if !self.dirty.swap(false, Ordering::AcqRel) {
+            return;
+        }
+
+        let bytes =
+            serde_json::to_vec(&*self.state.lock().unwrap()).map_err(StateStoreError::encode);
+
+        if let Err(e) = bytes.and_then(|bytes| write_state(&self.path, &bytes)) {
+            // Retry on the next flush
+            self.dirty.store(true, Ordering::Release);
+            warn!("failed to persist the relaying state: {}", e);
+        }
+    }
+}
+
+impl Drop for StoreInner {
+    fn drop(&mut self) {
+        self.flush()
+    }
+}
+
+/// Writes the state to a temporary file which then replaces the state file,
+/// so that the state file is never left partially written.
+fn write_state(path: &Path, bytes: &[u8]) -> Result<(), StateStoreError> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).map_err(|e| StateStoreError::io(parent.to_path_buf(), e))?;
+    }
+
+    let tmp_path = path.with_extension("tmp");
+
+    let mut file =
+        fs::File::create(&tmp_path).map_err(|e| StateStoreError::io(tmp_path.clone(), e))?;
+
+    file.write_all(bytes)
+        .and_then(|()| file.sync_all())
+        .map_err(|e| StateStoreError::io(tmp_path.clone(), e))?;
+
+    fs::rename(&tmp_path, path).map_err(|e| StateStoreError::io(path.to_path_buf(), e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::str::FromStr;
+    use test_log::test;
+
+    fn temp_state_path(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("hermes-state-{}", std::process::id()));
+        dir.join(format!("{name}.json"))
+    }
+
+    fn key() -> String {
+        path_key(
+            &ChainId::from_string("ibc-0"),
+            &PortId::transfer(),
+            &ChannelId::from_str("channel-0").unwrap(),
+        )
+    }
+
+    fn sequences(sequences: &[u64]) -> Vec<Sequence> {
+        sequences.iter().copied().map(Sequence::from).collect()
+    }
+
+    #[test]
+    fn state_survives_reopening() {
+        let path = temp_state_path("reopen");
+        let _ = fs::remove_file(&path);
+
+        let store = StateStore::open(&path).unwrap();
+
+        store.track(&key(), &sequences(&[1, 2, 3]), &sequences(&[7]));
+        store.untrack(&key(), &sequences(&[2]), &[]);
+        store.update_last_handled_height(
+            &ChainId::from_string("ibc-0"),
+            Height::new(0, 42).unwrap(),
+        );
+        store.insert_pending_tx(
+            &key(),
+            PendingTx {
+                tracking_id: "tracking".to_string(),
+                chain_id: ChainId::from_string("ibc-1"),
+                tx_hashes: vec![TxHash::Sha256([1; 32])],
+            },
+        );
+
+        // The state is only written on flush
+        assert!(!path.exists());
+        store.flush();
+
+        let reopened = StateStore::open(&path).unwrap();
+        assert_eq!(reopened.state(), store.state());
+        assert!(reopened.has_restored_path_state(&key()));
+        assert_eq!(
+            reopened.restored_last_handled_height(&ChainId::from_string("ibc-0")),
+            Some(Height::new(0, 42).unwrap())
+        );
+
+        let path_state = reopened.path_state(&key()).unwrap();
+        assert_eq!(
+            path_state.tracked_packets,
+            sequences(&[1, 3]).into_iter().collect()
+        );
+        assert_eq!(
+            path_state.tracked_acks,
+            sequences(&[7]).into_iter().collect()
+        );
+        assert_eq!(path_state.pending_txs.len(), 1);
+
+        // The restored state of a path is only taken once
+        assert_eq!(reopened.take_restored_path_state(&key()), Some(path_state));
+        assert!(!reopened.has_restored_path_state(&key()));
+        assert!(reopened.take_restored_path_state(&key()).is_none());
+
+        reopened.remove_pending_tx(&key(), &[TxHash::Sha256([1; 32])]);
+        assert!(reopened.path_state(&key()).unwrap().pending_txs.is_empty());
+
+        let _ = fs::remove_file(&path);
+    }
+
+    #[test]
+    fn received_packets_are_untracked() {
+        let path = temp_state_path("received");
+        let _ = fs::remove_file(&path);
+
+        let store = StateStore::open(&path).unwrap();
+        store.track(&key(), &sequences(&[3, 5]), &sequences(&[1, 2]));
+        store.retain_tracked_packets(&key(), &sequences(&[2, 3, 4]));
+        store.retain_tracked_acks(&key(), &[]);
+
+        let path_state = store.path_state(&key()).unwrap();
+
+        // Packet 5 has been received since it was tracked, packet 3 is still in flight
+        assert_eq!(
+            path_state.tracked_packets,
+            sequences(&[3]).into_iter().collect()
+        );
+        assert!(path_state.tracked_acks.is_empty());
+
+        let _ = fs::remove_file(&path);
+    }
+}
@@ -1,37 +1,49 @@
 use alloc::collections::btree_map::BTreeMap as HashMap;
+use alloc::collections::BTreeSet;
 use alloc::sync::Arc;
 use core::convert::Infallible;
 use core::ops::Deref;
 use core::time::Duration;
 use std::sync::RwLock;
+use std::thread;
 
-use crossbeam_channel::{unbounded, Receiver, Sender};
+use crossbeam_channel::{bounded, unbounded, Receiver, Sender};
 use itertools::Itertools;
 use tracing::{debug, error, error_span, info, instrument, trace, warn};
 
 use ibc_relayer_types::{
-    core::ics24_host::identifier::{ChainId, ChannelId, PortId},
+    core::{
+        ics04_channel::channel::IdentifiedChannelEnd,
+        ics24_host::identifier::{ChainId, ChannelId, PortId},
+    },
     events::IbcEvent,
     Height,
 };
 
 use crate::{
-    chain::{endpoint::HealthCheck, handle::ChainHandle, tracking::TrackingId},
-    config::Config,
+    chain::{
+        counterparty::{pending_packet_summary, PendingPackets},
+        endpoint::HealthCheck,
+        handle::ChainHandle,
+        requests::{IncludeProof, QueryChannelRequest, QueryHeight},
+        tracking::TrackingId,
+    },
+    config::{ChainConfig, Config},
     event::{
         monitor::{self, Error as EventError, ErrorDetail as EventErrorDetail, EventBatch},
         IbcEventWithHeight,
     },
-    object::Object,
+    object::{Object, Packet},
     registry::{Registry, SharedRegistry},
-    rest,
+    rest::{self, request::ClearPacketsOutcome, RestApiError},
+    state_store::StateStore,
     supervisor::scan::ScanMode,
     telemetry,
     util::{
         lock::LockExt,
         task::{spawn_background_task, Next, TaskError, TaskHandle},
     },
-    worker::WorkerMap,
+    worker::{WorkerHandle, WorkerMap},
 };
 
 pub mod client_state_filter;
@@ -41,19 +53,23 @@ pub mod error;
 pub use error::{Error, ErrorDetail};
 
 pub mod dump_state;
-use dump_state::SupervisorState;
+use dump_state::{SupervisorState, WorkerDesc};
 
+pub mod expiry;
 pub mod scan;
 pub mod spawn;
 
 pub mod cmd;
-use cmd::SupervisorCmd;
+use cmd::{ConfigUpdate, SupervisorCmd};
 
 use self::{scan::ChainScanner, spawn::SpawnContext};
 
 type ArcBatch = Arc<monitor::Result<EventBatch>>;
 type Subscription = Receiver<ArcBatch>;
 
+/// The tasks processing the event batches of each chain, indexed by chain identifier.
+type BatchTasks = HashMap<ChainId, TaskHandle>;
+
 /**
     A wrapper around the SupervisorCmd sender so that we can
     send stop signal to the supervisor before stopping the
@@ -75,6 +91,10 @@ pub struct SupervisorOptions {
     /// even when an allow list is configured for a chain and the full scan could
     /// be omitted.
     pub force_full_scan: bool,
+
+    /// Simulate the transactions which relay packets to estimate their fees,
+    /// instead of submitting them, and do not submit any other transaction.
+    pub dry_run: bool,
 }
 
 /**
@@ -131,6 +151,14 @@ impl SupervisorHandle {
 
         Ok(state)
     }
+
+    /// Ask the supervisor to apply the given configuration, without restarting
+    /// the workers of the chains whose configuration is unchanged.
+    pub fn update_config(&self, config: Config) -> Result<(), Error> {
+        self.sender
+            .send(SupervisorCmd::UpdateConfig(Box::new(config)))
+            .map_err(|_| Error::handle_send())
+    }
 }
 
 pub fn spawn_supervisor_tasks<Chain: ChainHandle>(
@@ -154,7 +182,29 @@ pub fn spawn_supervisor_tasks<Chain: ChainHandle>(
         }
     }
 
-    let workers = Arc::new(RwLock::new(WorkerMap::new()));
+    // Nothing is relayed in dry-run mode, so there is no relaying state to persist
+    let state_store = if options.dry_run {
+        None
+    } else {
+        StateStore::from_config(&config.state).map_err(Error::state_store)?
+    };
+
+    if let Some(state_store) = &state_store {
+        info!(
+            "persisting the relaying state in {}",
+            state_store.path().display()
+        );
+    }
+
+    if options.dry_run {
+        info!("running in dry-run mode, transactions are simulated instead of being submitted");
+    }
+
+    let state_store_task = state_store.clone().map(spawn_state_store_worker);
+
+    let workers = Arc::new(RwLock::new(
+        WorkerMap::with_state_store(state_store).with_dry_run(options.dry_run),
+    ));
     let client_state_filter = Arc::new(RwLock::new(FilterPolicy::default()));
 
     let scan = chain_scanner(
@@ -172,22 +222,37 @@ pub fn spawn_supervisor_tasks<Chain: ChainHandle>(
     info!("scanned chains:");
     info!("{}", scan);
 
+    let expiry_task = config
+        .alerting
+        .enabled
+        .then(|| expiry::spawn_expiry_monitor(&config.alerting, &mut registry.write(), &scan));
+
     spawn_context(&config, &mut registry.write(), &mut workers.acquire_write()).spawn_workers(scan);
 
     let subscriptions = init_subscriptions(&config, &mut registry.write())?;
 
+    let config = Arc::new(RwLock::new(config));
+
     let batch_tasks = spawn_batch_workers(
         &config,
         registry.clone(),
-        client_state_filter,
+        client_state_filter.clone(),
         workers.clone(),
         subscriptions,
     );
 
-    let cmd_task = spawn_cmd_worker(registry.clone(), workers.clone(), cmd_rx);
+    let cmd_task = spawn_cmd_worker(
+        config.clone(),
+        registry.clone(),
+        client_state_filter,
+        workers.clone(),
+        batch_tasks,
+        cmd_rx,
+    );
 
     let mut tasks = vec![cmd_task];
-    tasks.extend(batch_tasks);
+    tasks.extend(state_store_task);
+    tasks.extend(expiry_task);
 
     if let Some(rest_rx) = rest_rx {
         let rest_task = spawn_rest_worker(config, registry, workers, rest_rx);
@@ -198,48 +263,68 @@ pub fn spawn_supervisor_tasks<Chain: ChainHandle>(
 }
 
 fn spawn_batch_workers<Chain: ChainHandle>(
-    config: &Config,
+    config: &Arc<RwLock<Config>>,
     registry: SharedRegistry<Chain>,
     client_state_filter: Arc<RwLock<FilterPolicy>>,
     workers: Arc<RwLock<WorkerMap>>,
     subscriptions: Vec<(Chain, Subscription)>,
-) -> Vec<TaskHandle> {
-    let mut handles = Vec::with_capacity(subscriptions.len());
+) -> BatchTasks {
+    let mut handles = BatchTasks::new();
 
     for (chain, subscription) in subscriptions {
-        let config = config.clone();
-        let registry = registry.clone();
-        let client_state_filter = client_state_filter.clone();
-        let workers = workers.clone();
-
-        let handle = spawn_background_task(
-            error_span!("worker.batch", chain = %chain.id()),
-            Some(Duration::from_millis(5)),
-            move || -> Result<Next, TaskError<Infallible>> {
-                if let Ok(batch) = subscription.try_recv() {
-                    handle_batch(
-                        &config,
-                        &mut registry.write(),
-                        &mut client_state_filter.acquire_write(),
-                        &mut workers.acquire_write(),
-                        chain.clone(),
-                        batch,
-                    );
-                }
-
-                Ok(Next::Continue)
-            },
+        let handle = spawn_batch_worker(
+            config.clone(),
+            registry.clone(),
+            client_state_filter.clone(),
+            workers.clone(),
+            chain.clone(),
+            subscription,
         );
 
-        handles.push(handle);
+        handles.insert(chain.id(), handle);
     }
 
     handles
 }
 
+fn spawn_batch_worker<Chain: ChainHandle>(
+    config: Arc<RwLock<Config>>,
+    registry: SharedRegistry<Chain>,
+    client_state_filter: Arc<RwLock<FilterPolicy>>,
+    workers: Arc<RwLock<WorkerMap>>,
+    chain: Chain,
+    subscription: Subscription,
+) -> TaskHandle {
+    spawn_background_task(
+        error_span!("worker.batch", chain = %chain.id()),
+        Some(Duration::from_millis(5)),
+        move || -> Result<Next, TaskError<Infallible>> {
+            if let Ok(batch) = subscription.try_recv() {
+                handle_batch(
+                    &config.acquire_read(),
+                    &mut registry.write(),
+                    &mut client_state_filter.acquire_write(),
+                    &mut workers.acquire_write(),
+                    chain.clone(),
+                    batch,
+                );
+            }
+
+            Ok(Next::Continue)
+        },
+    )
+}
+
+/// Spawn the task handling the [`SupervisorCmd`]s sent to the supervisor.
+///
+/// The task takes ownership of the event batch tasks, so that
+/// it can stop and respawn them when the configuration is updated.
 pub fn spawn_cmd_worker<Chain: ChainHandle>(
+    config: Arc<RwLock<Config>>,
     registry: SharedRegistry<Chain>,
+    client_state_filter: Arc<RwLock<FilterPolicy>>,
     workers: Arc<RwLock<WorkerMap>>,
+    mut batch_tasks: BatchTasks,
     cmd_rx: Receiver<SupervisorCmd>,
 ) -> TaskHandle {
     spawn_background_task(
@@ -251,6 +336,16 @@ pub fn spawn_cmd_worker<Chain: ChainHandle>(
                     SupervisorCmd::DumpState(reply_to) => {
                         dump_state(&registry.read(), &workers.acquire_read(), reply_to);
                     }
+                    SupervisorCmd::UpdateConfig(new_config) => {
+                        update_config(
+                            &config,
+                            &registry,
+                            &client_state_filter,
+                            &workers,
+                            &mut batch_tasks,
+                            *new_config,
+                        );
+                    }
                 }
             }
 
@@ -260,7 +355,7 @@ pub fn spawn_cmd_worker<Chain: ChainHandle>(
 }
 
 pub fn spawn_rest_worker<Chain: ChainHandle>(
-    config: Config,
+    config: Arc<RwLock<Config>>,
     registry: SharedRegistry<Chain>,
     workers: Arc<RwLock<WorkerMap>>,
     rest_rx: rest::Receiver,
@@ -269,13 +364,31 @@ pub fn spawn_rest_worker<Chain: ChainHandle>(
         error_span!("rest"),
         Some(Duration::from_millis(500)),
         move || -> Result<Next, TaskError<Infallible>> {
-            handle_rest_requests(&config, &registry.read(), &workers.acquire_read(), &rest_rx);
+            handle_rest_requests(
+                &config.acquire_read(),
+                &registry.read(),
+                &workers.acquire_read(),
+                &rest_rx,
+            );
 
             Ok(Next::Continue)
         },
     )
 }
 
+/// Spawn the task periodically writing the relaying state to disk,
+/// so that it is not written on every update.
+fn spawn_state_store_worker(state_store: StateStore) -> TaskHandle {
+    spawn_background_task(
+        error_span!("worker.state_store"),
+        Some(Duration::from_secs(1)),
+        move || -> Result<Next, TaskError<Infallible>> {
+            state_store.flush();
+            Ok(Next::Continue)
+        },
+    )
+}
+
 /// Returns `true` if the relayer should filter based on
 /// client state attributes, e.g., trust threshold.
 /// Returns `false` otherwise.
@@ -504,6 +617,29 @@ pub fn collect_events(
                     || Object::client_from_chan_open_events(&attributes, src_chain).ok(),
                 );
             }
+            IbcEvent::UpgradeInitChannel(..)
+            | IbcEvent::UpgradeTryChannel(..)
+            | IbcEvent::UpgradeAckChannel(..)
+            | IbcEvent::UpgradeConfirmChannel(..)
+            | IbcEvent::UpgradeOpenChannel(..)
+            | IbcEvent::UpgradeTimeoutChannel(..)
+            | IbcEvent::UpgradeCancelChannel(..)
+            | IbcEvent::UpgradeErrorChannel(..) => {
+                // If handshake message relaying is enabled create worker to complete the upgrade
+                collect_event(
+                    &mut collected,
+                    event_with_height.clone(),
+                    mode.channels.enabled,
+                    || {
+                        event_with_height
+                            .event
+                            .channel_upgrade_attributes()
+                            .and_then(|attr| {
+                                Object::for_channel_upgrade_event(attr, src_chain).ok()
+                            })
+                    },
+                );
+            }
             IbcEvent::SendPacket(ref packet) => {
                 collect_event(
                     &mut collected,
@@ -528,6 +664,16 @@ pub fn collect_events(
                     || Object::for_write_ack(packet, src_chain).ok(),
                 );
             }
+            // Only used to measure the end-to-end latency of the packets
+            #[cfg(feature = "telemetry")]
+            IbcEvent::AcknowledgePacket(ref packet) => {
+                collect_event(
+                    &mut collected,
+                    event_with_height.clone(),
+                    mode.packets.enabled,
+                    || Object::for_acknowledge_packet(packet, src_chain).ok(),
+                );
+            }
             IbcEvent::CloseInitChannel(ref packet) => {
                 collect_event(
                     &mut collected,
@@ -646,6 +792,227 @@ fn init_subscriptions<Chain: ChainHandle>(
     Ok(subscriptions)
 }
 
+/// Apply the differences between the current configuration and `new_config`.
+///
+/// The chain runtimes, workers and event subscriptions of the chains which
+/// were removed or whose configuration changed are shut down, and the ones
+/// of the chains which were added or changed are (re)spawned. The workers of
+/// the counterparty chains which were shut down in the process are respawned
+/// as well, while all the other workers keep running untouched.
+///
+/// Only the `chains` section of the configuration is reloaded,
+/// changes to the other sections require a restart to take effect.
+#[instrument(name = "supervisor.update_config", level = "error", skip_all)]
+fn update_config<Chain: ChainHandle>(
+    config: &Arc<RwLock<Config>>,
+    registry: &SharedRegistry<Chain>,
+    client_state_filter: &Arc<RwLock<FilterPolicy>>,
+    workers: &Arc<RwLock<WorkerMap>>,
+    batch_tasks: &mut BatchTasks,
+    new_config: Config,
+) {
+    let updates = {
+        let current = config.acquire_read();
+
+        if current.global != new_config.global
+            || current.mode != new_config.mode
+            || current.rest != new_config.rest
+            || current.telemetry != new_config.telemetry
+        {
+            warn!(
+                "changes to the `global`, `mode`, `rest` and `telemetry` sections \
+                 cannot be applied at runtime and will be ignored until Hermes is restarted"
+            );
+        }
+
+        ConfigUpdate::diff(&current, &new_config)
+    };
+
+    if updates.is_empty() {
+        info!("chains configuration is unchanged, nothing to update");
+        return;
+    }
+
+    // Counterparty chains of the workers which get shut down along with
+    // the removed or updated chains, and whose workers must be respawned.
+    let mut counterparties = BTreeSet::new();
+
+    for update in &updates {
+        if matches!(update, ConfigUpdate::Remove(_) | ConfigUpdate::Update(_)) {
+            let chain_id = update.chain_id();
+
+            info!(chain = %chain_id, "shutting down chain");
+
+            let chains = shutdown_chain(registry, workers, batch_tasks, chain_id);
+            counterparties.extend(chains);
+        }
+    }
+
+    {
+        let mut config = config.acquire_write();
+        config.chains = new_config.chains;
+        registry.write().update_config(config.clone());
+    }
+
+    for update in &updates {
+        if let ConfigUpdate::Add(chain_config) | ConfigUpdate::Update(chain_config) = update {
+            info!(chain = %chain_config.id, "spawning chain");
+
+            spawn_chain(
+                config,
+                registry,
+                client_state_filter,
+                workers,
+                batch_tasks,
+                chain_config,
+            );
+        }
+    }
+
+    let config = config.acquire_read();
+
+    for chain_id in counterparties {
+        let Some(chain_config) = config.find_chain(&chain_id) else {
+            continue;
+        };
+
+        if updates.iter().any(|update| update.chain_id() == &chain_id) {
+            continue;
+        }
+
+        info!(chain = %chain_id, "respawning workers for counterparty chain");
+
+        spawn_workers_for_chain(
+            &config,
+            &mut registry.write(),
+            &mut client_state_filter.acquire_write(),
+            &mut workers.acquire_write(),
+            chain_config,
+        );
+    }
+}
+
+/// Stop processing the events of the given chain, shut down all the workers
+/// relaying to or from that chain, and then its runtime.
+///
+/// Returns the identifiers of the counterparty chains of the workers which were shut down.
+fn shutdown_chain<Chain: ChainHandle>(
+    registry: &SharedRegistry<Chain>,
+    workers: &Arc<RwLock<WorkerMap>>,
+    batch_tasks: &mut BatchTasks,
+    chain_id: &ChainId,
+) -> BTreeSet<ChainId> {
+    // Dropping the handle waits for the batch task to terminate,
+    // so we must not hold any of the locks it needs at this point.
+    if let Some(batch_task) = batch_tasks.remove(chain_id) {
+        batch_task.shutdown_and_wait();
+    }
+
+    // Acquire the locks in the same order as the batch tasks to avoid deadlocks.
+    let mut registry = registry.write();
+    let mut workers = workers.acquire_write();
+
+    let objects = workers.objects_for_chain(chain_id);
+
+    let counterparties = objects
+        .iter()
+        .flat_map(|object| [object.src_chain_id(), object.dst_chain_id()])
+        .filter(|id| *id != chain_id)
+        .cloned()
+        .collect();
+
+    for object in objects {
+        debug!("shutting down worker {}", object.short_name());
+        workers.shutdown_worker(&object);
+    }
+
+    registry.shutdown(chain_id);
+
+    counterparties
+}
+
+/// Spawn the runtime of the given chain, scan it to spawn its workers
+/// and start processing its events.
+fn spawn_chain<Chain: ChainHandle>(
+    config: &Arc<RwLock<Config>>,
+    registry: &SharedRegistry<Chain>,
+    client_state_filter: &Arc<RwLock<FilterPolicy>>,
+    workers: &Arc<RwLock<WorkerMap>>,
+    batch_tasks: &mut BatchTasks,
+    chain_config: &ChainConfig,
+) {
+    let chain = {
+        let config = config.acquire_read();
+        let mut registry = registry.write();
+
+        spawn_workers_for_chain(
+            &config,
+            &mut registry,
+            &mut client_state_filter.acquire_write(),
+            &mut workers.acquire_write(),
+            chain_config,
+        );
+
+        match registry.get_or_spawn(&chain_config.id) {
+            Ok(chain) => chain,
+            Err(e) => {
+                error!(
+                    "failed to spawn chain runtime for {}: {}",
+                    chain_config.id, e
+                );
+
+                return;
+            }
+        }
+    };
+
+    if config.acquire_read().telemetry.enabled {
+        if let Ok(_key) = chain.get_key() {
+            telemetry!(add_visible_fee_address, _key.account());
+        }
+    }
+
+    match chain.subscribe() {
+        Ok(subscription) => {
+            let batch_task = spawn_batch_worker(
+                config.clone(),
+                registry.clone(),
+                client_state_filter.clone(),
+                workers.clone(),
+                chain.clone(),
+                subscription,
+            );
+
+            batch_tasks.insert(chain.id(), batch_task);
+        }
+        Err(e) => error!(
+            "failed to subscribe to events of {}: {}",
+            chain_config.id, e
+        ),
+    }
+}
+
+/// Scan the given chain and spawn the workers for the objects found,
+/// skipping the ones for which a worker already exists.
+fn spawn_workers_for_chain<Chain: ChainHandle>(
+    config: &Config,
+    registry: &mut Registry<Chain>,
+    client_state_filter: &mut FilterPolicy,
+    workers: &mut WorkerMap,
+    chain_config: &ChainConfig,
+) {
+    let scan = chain_scanner(config, registry, client_state_filter, ScanMode::Auto)
+        .scan_chain(chain_config);
+
+    match scan {
+        Ok(scan) => spawn_context(config, registry, workers).spawn_workers_for_chain(scan),
+        Err(e) => error!(
+            "failed to scan chain {} for workers to spawn: {}",
+            chain_config.id, e
+        ),
+    }
+}
+
 /// Dump the state of the supervisor into a [`SupervisorState`] value,
 /// and send it back through the given channel.
 fn dump_state<Chain: ChainHandle>(
@@ -688,9 +1055,147 @@ fn handle_rest_cmd<Chain: ChainHandle>(
                 .send(Ok(state))
                 .unwrap_or_else(|e| error!("error replying to a REST request {}", e));
         }
+        rest::Command::PendingPackets {
+            chain_id,
+            port_id,
+            channel_id,
+            reply_to,
+        } => {
+            // Query the pending packets in the background, so as not to block the supervisor
+            match path_chains(registry, workers, &chain_id, &port_id, &channel_id) {
+                Ok((chain, counterparty_chain)) => {
+                    thread::spawn(move || {
+                        let result =
+                            pending_packets(chain, counterparty_chain, &port_id, &channel_id);
+
+                        reply_to
+                            .send(result)
+                            .unwrap_or_else(|e| error!("error replying to a REST request {}", e));
+                    });
+                }
+                Err(e) => reply_to
+                    .send(Err(e))
+                    .unwrap_or_else(|e| error!("error replying to a REST request {}", e)),
+            }
+        }
+        rest::Command::ClearPackets {
+            chain_id,
+            port_id,
+            channel_id,
+            reply_to,
+        } => match packet_worker(workers, &chain_id, &port_id, &channel_id) {
+            Ok((worker, _)) => {
+                info!(
+                    "clearing pending packets of channel {}/{} on chain {} on request",
+                    port_id, channel_id, chain_id
+                );
+
+                let (clear_tx, clear_rx) = bounded(1);
+                worker.clear_pending_packets_with_reply(clear_tx);
+
+                let worker =
+                    WorkerDesc::new(worker.id(), worker.object().clone(), worker.data().cloned());
+
+                // Wait for the outcome of the clearing in the background
+                thread::spawn(move || {
+                    let result = match clear_rx.recv() {
+                        Ok(Ok(())) => Ok(ClearPacketsOutcome { worker }),
+                        Ok(Err(e)) => Err(RestApiError::ClearPackets(e)),
+                        Err(_) => Err(RestApiError::ClearPackets(
+                            "the packet worker stopped before clearing the packets".to_string(),
+                        )),
+                    };
+
+                    reply_to
+                        .send(result)
+                        .unwrap_or_else(|e| error!("error replying to a REST request {}", e));
+                });
+            }
+            Err(e) => reply_to
+                .send(Err(e))
+                .unwrap_or_else(|e| error!("error replying to a REST request {}", e)),
+        },
     }
 }
 
+/// Find the packet worker relaying the packets sent from
+/// the given channel of the given chain, along with its path.
+fn packet_worker<'a>(
+    workers: &'a WorkerMap,
+    chain_id: &ChainId,
+    port_id: &PortId,
+    channel_id: &ChannelId,
+) -> Result<(&'a WorkerHandle, &'a Packet), RestApiError> {
+    workers
+        .handles()
+        .find_map(|worker| match worker.object() {
+            Object::Packet(path)
+                if &path.src_chain_id == chain_id
+                    && &path.src_port_id == port_id
+                    && &path.src_channel_id == channel_id =>
+            {
+                Some((worker, path))
+            }
+            _ => None,
+        })
+        .ok_or_else(|| {
+            RestApiError::PacketWorkerNotFound(
+                chain_id.clone(),
+                port_id.clone(),
+                channel_id.clone(),
+            )
+        })
+}
+
+/// Find the chain handles of the supervisor for the path relayed by the packet
+/// worker relaying the packets sent from the given channel of the given chain.
+fn path_chains<Chain: ChainHandle>(
+    registry: &Registry<Chain>,
+    workers: &WorkerMap,
+    chain_id: &ChainId,
+    port_id: &PortId,
+    channel_id: &ChannelId,
+) -> Result<(Chain, Chain), RestApiError> {
+    let (_, path) = packet_worker(workers, chain_id, port_id, channel_id)?;
+
+    let find_chain = |chain_id: &ChainId| {
+        registry
+            .chains()
+            .find(|chain| &chain.id() == chain_id)
+            .cloned()
+            .ok_or_else(|| RestApiError::ChainNotRelayed(chain_id.clone()))
+    };
+
+    Ok((
+        find_chain(&path.src_chain_id)?,
+        find_chain(&path.dst_chain_id)?,
+    ))
+}
+
+/// Query the packets pending on the given channel of the given chain.
+fn pending_packets<Chain: ChainHandle>(
+    chain: Chain,
+    counterparty_chain: Chain,
+    port_id: &PortId,
+    channel_id: &ChannelId,
+) -> Result<PendingPackets, RestApiError> {
+    let (channel_end, _) = chain
+        .query_channel(
+            QueryChannelRequest {
+                port_id: port_id.clone(),
+                channel_id: channel_id.clone(),
+                height: QueryHeight::Latest,
+            },
+            IncludeProof::No,
+        )
+        .map_err(|e| RestApiError::PendingPackets(e.to_string()))?;
+
+    let channel = IdentifiedChannelEnd::new(port_id.clone(), channel_id.clone(), channel_end);
+
+    pending_packet_summary(&chain, &counterparty_chain, &channel)
+        .map_err(|e| RestApiError::PendingPackets(e.to_string()))
+}
+
 #[instrument(
     name = "supervisor.clear_pending_packets",
     level = "error",
@@ -776,6 +1281,10 @@ fn process_batch<Chain: ChainHandle>(
         );
     }
 
+    if let Some(state_store) = workers.state_store() {
+        state_store.update_last_handled_height(&batch.chain_id, batch.height);
+    }
+
     Ok(())
 }
 
@@ -791,12 +1300,8 @@ fn process_batch<Chain: ChainHandle>(
 /// event with `chain_id = A` and `counterparty_chain_id = B` and then a WriteAcknowlegment
 /// event with `chain_id = B` and `counterparty_chain_id = A`.
 #[cfg(feature = "telemetry")]
-fn send_telemetry<Src, Dst>(
-    src: &Src,
-    dst: &Dst,
-    events: &[IbcEventWithHeight],
-    path: &crate::object::Packet,
-) where
+fn send_telemetry<Src, Dst>(src: &Src, dst: &Dst, events: &[IbcEventWithHeight], path: &Packet)
+where
     Src: ChainHandle,
     Dst: ChainHandle,
 {
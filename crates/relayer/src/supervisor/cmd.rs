@@ -1,8 +1,113 @@
 use crossbeam_channel::Sender;
 
+use ibc_relayer_types::core::ics24_host::identifier::ChainId;
+
+use crate::config::{ChainConfig, Config};
+
 use super::dump_state::SupervisorState;
 
 #[derive(Clone, Debug)]
 pub enum SupervisorCmd {
     DumpState(Sender<SupervisorState>),
+    UpdateConfig(Box<Config>),
+}
+
+/// A change to the configuration of a single chain,
+/// obtained by diffing two [`Config`] values with [`ConfigUpdate::diff`].
+#[derive(Clone, Debug, PartialEq)]
+pub enum ConfigUpdate {
+    /// A chain was added to the configuration
+    Add(ChainConfig),
+
+    /// A chain was removed from the configuration
+    Remove(ChainId),
+
+    /// The configuration of an existing chain was changed
+    Update(ChainConfig),
+}
+
+impl ConfigUpdate {
+    /// Compute the chain-level changes needed to go from the `old` configuration
+    /// to the `new` one. Chains whose configuration did not change are not
+    /// included in the result.
+    pub fn diff(old: &Config, new: &Config) -> Vec<ConfigUpdate> {
+        let old_chains = old.chains_map();
+        let new_chains = new.chains_map();
+
+        let mut updates: Vec<_> = old_chains
+            .keys()
+            .filter(|id| !new_chains.contains_key(*id))
+            .map(|id| ConfigUpdate::Remove((*id).clone()))
+            .collect();
+
+        for (id, chain) in new_chains {
+            match old_chains.get(id) {
+                None => updates.push(ConfigUpdate::Add(chain.clone())),
+                Some(old_chain) if *old_chain != chain => {
+                    updates.push(ConfigUpdate::Update(chain.clone()))
+                }
+                Some(_) => (),
+            }
+        }
+
+        updates
+    }
+
+    /// The identifier of the chain affected by this update.
+    pub fn chain_id(&self) -> &ChainId {
+        match self {
+            ConfigUpdate::Add(chain) | ConfigUpdate::Update(chain) => &chain.id,
+            ConfigUpdate::Remove(chain_id) => chain_id,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ConfigUpdate;
+    use crate::config::{load, types::MaxMsgNum};
+
+    use ibc_relayer_types::core::ics24_host::identifier::ChainId;
+
+    fn example_config() -> crate::config::Config {
+        let path = concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/tests/config/fixtures/relayer_conf_example.toml"
+        );
+
+        load(path).expect("could not parse config")
+    }
+
+    #[test]
+    fn diff_identical_configs() {
+        let config = example_config();
+
+        assert!(ConfigUpdate::diff(&config, &config.clone()).is_empty());
+    }
+
+    #[test]
+    fn diff_added_removed_and_updated_chains() {
+        let old = example_config();
+        let mut new = old.clone();
+
+        let removed = new.chains.remove(0);
+
+        new.chains[0].max_msg_num = MaxMsgNum::new(10).unwrap();
+        let updated = new.chains[0].clone();
+
+        let mut added = updated.clone();
+        added.id = ChainId::from_string("chain_C");
+        new.chains.push(added.clone());
+
+        let updates = ConfigUpdate::diff(&old, &new);
+
+        assert_eq!(
+            updates,
+            vec![
+                ConfigUpdate::Remove(removed.id),
+                ConfigUpdate::Update(updated),
+                ConfigUpdate::Add(added),
+            ]
+        );
+    }
 }
@@ -5,6 +5,7 @@ use ibc_relayer_types::core::ics24_host::identifier::{ChainId, ChannelId, Connec
 
 use crate::error::Error as RelayerError;
 use crate::spawn::SpawnError;
+use crate::state_store::StateStoreError;
 use crate::supervisor::scan::Error as ScanError;
 
 define_error! {
@@ -70,6 +71,10 @@ define_error! {
             [ ScanError ]
             |_| { "supervisor encountered an error when scanning chains" },
 
+        StateStore
+            [ StateStoreError ]
+            |_| { "supervisor was not able to open the relaying state store" },
+
         HandleSend
             |_| { "failed to send a command to the supervisor through a channel" },
 
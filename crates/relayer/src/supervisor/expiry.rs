@@ -0,0 +1,134 @@
+//! Monitoring of the expiry of the clients hosted on the scanned chains.
+
+use core::convert::Infallible;
+use core::time::Duration;
+use std::time::Instant;
+
+use tracing::{debug, error_span, warn};
+
+use ibc_relayer_types::core::ics02_client::client_state::ClientState;
+
+use crate::alerting::{AlertNotifier, ExpiryAlerts};
+use crate::chain::handle::ChainHandle;
+use crate::config::alerting::AlertingConfig;
+use crate::foreign_client::ForeignClient;
+use crate::registry::Registry;
+use crate::telemetry;
+use crate::util::task::{spawn_background_task, Next, TaskError, TaskHandle};
+
+use super::scan::ChainsScan;
+
+/// Spawns the task which periodically checks the time left in the trusting period of
+/// all the clients hosted on the scanned chains, reporting it as telemetry and alerting
+/// when it drops below the configured thresholds, or when a client becomes expired or frozen.
+///
+/// Unlike the client workers, which are only spawned for the clients of the channels
+/// relayed on when client workers are enabled, this task monitors every scanned client,
+/// including the frozen or expired ones, so that an alert gets raised for them.
+pub fn spawn_expiry_monitor<Chain: ChainHandle>(
+    config: &AlertingConfig,
+    registry: &mut Registry<Chain>,
+    scan: &ChainsScan,
+) -> TaskHandle {
+    let notifier = AlertNotifier::new(config.webhook_url.clone());
+
+    let mut clients = Vec::new();
+
+    for chain_scan in scan.chains.iter().flatten() {
+        for client_scan in chain_scan.clients.values() {
+            let client_id = &client_scan.client.client_id;
+            let src_chain_id = client_scan.client.client_state.chain_id();
+
+            let chains = registry.get_or_spawn(&src_chain_id).and_then(|src_chain| {
+                let dst_chain = registry.get_or_spawn(&chain_scan.chain_id)?;
+                Ok((src_chain, dst_chain))
+            });
+
+            match chains {
+                Ok((src_chain, dst_chain)) => {
+                    let alerts = ExpiryAlerts::new(
+                        client_id.clone(),
+                        src_chain.id(),
+                        dst_chain.id(),
+                        config.thresholds.clone(),
+                    );
+
+                    let client = ForeignClient::restore(client_id.clone(), dst_chain, src_chain);
+
+                    clients.push((client, alerts));
+                }
+                Err(e) => warn!(
+                    client = %client_id,
+                    src_chain = %src_chain_id,
+                    dst_chain = %chain_scan.chain_id,
+                    "not monitoring the expiry of the client: {e}"
+                ),
+            }
+        }
+    }
+
+    let interval = config.interval;
+    let mut next_check = Instant::now();
+
+    spawn_background_task(
+        error_span!("supervisor.expiry"),
+        Some(Duration::from_secs(1)),
+        move || -> Result<Next, TaskError<Infallible>> {
+            // Check often whether the task must stop, but only query the clients every `interval`
+            if Instant::now() < next_check {
+                return Ok(Next::Continue);
+            }
+
+            next_check = Instant::now() + interval;
+
+            for (client, alerts) in clients.iter_mut() {
+                check_expiry(client, alerts, &notifier);
+            }
+
+            Ok(Next::Continue)
+        },
+    )
+}
+
+fn check_expiry<Chain: ChainHandle>(
+    client: &ForeignClient<Chain, Chain>,
+    alerts: &mut ExpiryAlerts,
+    notifier: &AlertNotifier,
+) {
+    let _span = error_span!(
+        "client",
+        client = %client.id,
+        src_chain = %client.src_chain.id(),
+        dst_chain = %client.dst_chain.id(),
+    )
+    .entered();
+
+    match client.expiry_status() {
+        Ok(status) => {
+            debug!(?status, "checked client expiry");
+
+            telemetry!(expiry_metrics(client, &status));
+
+            if let Some(alert) = alerts.update(status) {
+                notifier.notify(&alert);
+            }
+        }
+        Err(e) => warn!("failed to check the expiry of the client: {e}"),
+    }
+}
+
+#[cfg(feature = "telemetry")]
+fn expiry_metrics<Chain: ChainHandle>(
+    client: &ForeignClient<Chain, Chain>,
+    status: &crate::foreign_client::ClientExpiryStatus,
+) {
+    if let Some(time_left) = status.time_left() {
+        telemetry!(
+            client_expiry_time_left,
+            &client.src_chain.id(),
+            &client.dst_chain.id(),
+            &client.id,
+            time_left.as_secs()
+        );
+    }
+}
@@ -158,7 +158,7 @@ pub struct ClientScan {
 }
 
 impl ClientScan {
-    fn new(client: IdentifiedAnyClientState) -> ClientScan {
+    pub fn new(client: IdentifiedAnyClientState) -> ClientScan {
         Self {
             client,
             connections: BTreeMap::new(),
@@ -404,31 +404,42 @@ impl<'a, Chain: ChainHandle> ChainScanner<'a, Chain> {
         let clients = query_all_clients(chain)?;
 
         for client in clients {
-            if let Some(client_scan) = self.scan_client(chain, client)? {
-                if self.config.telemetry.enabled {
-                    // discovery phase : query every chain, connections and channels
-                    let connection_scans = client_scan.connections.values();
-
-                    for connection_scan in connection_scans {
-                        for channel in connection_scan.channels.values() {
-                            if let Some(counterparty_channel) = &channel.counterparty {
-                                init_telemetry(
-                                    &chain.id(),
-                                    client_scan.id(),
-                                    &client_scan.counterparty_chain_id(),
-                                    channel.id(),
-                                    &counterparty_channel.channel_id,
-                                    channel.port(),
-                                    &counterparty_channel.port_id,
-                                    self.config,
-                                );
-                            }
+            let client_id = client.client_id.clone();
+
+            // A client which cannot be scanned, eg. because of a failing query,
+            // is skipped instead of aborting the scan of the other clients
+            let client_scan = match self.scan_client(chain, client) {
+                Ok(Some(client_scan)) => client_scan,
+                Ok(None) => continue,
+                Err(e) => {
+                    error!(client = %client_id, "failed to scan client, reason: {}", e);
+                    continue;
+                }
+            };
+
+            if self.config.telemetry.enabled {
+                // discovery phase : query every chain, connections and channels
+                let connection_scans = client_scan.connections.values();
+
+                for connection_scan in connection_scans {
+                    for channel in connection_scan.channels.values() {
+                        if let Some(counterparty_channel) = &channel.counterparty {
+                            init_telemetry(
+                                &chain.id(),
+                                client_scan.id(),
+                                &client_scan.counterparty_chain_id(),
+                                channel.id(),
+                                &counterparty_channel.channel_id,
+                                channel.port(),
+                                &counterparty_channel.port_id,
+                                self.config,
+                            );
                         }
                     }
                 }
-
-                scan.clients.insert(client_scan.id().clone(), client_scan);
             }
+
+            scan.clients.insert(client_scan.id().clone(), client_scan);
         }
 
         Ok(())
@@ -3,14 +3,16 @@ use core::fmt::{Display, Error as FmtError, Formatter};
 use ibc_relayer_types::core::ics04_channel::channel::Ordering;
 use serde::{Deserialize, Serialize};
 use std::sync::Mutex;
-use tracing::error;
+use tracing::{error, info};
 
+use crate::config::channel_overrides::ChannelOverrides;
 use crate::foreign_client::ForeignClient;
-use crate::link::{Link, LinkParameters, Resubmit};
+use crate::link::{Link, LinkParameters, RelayPath, Resubmit};
 use crate::{
     chain::handle::{ChainHandle, ChainHandlePair},
     config::Config,
     object::Object,
+    state_store::StateStore,
 };
 
 pub mod retry_strategy;
@@ -59,9 +61,22 @@ pub fn spawn_worker_tasks<ChainA: ChainHandle, ChainB: ChainHandle>(
     id: WorkerId,
     object: Object,
     config: &Config,
+    state_store: Option<StateStore>,
+    dry_run: bool,
 ) -> WorkerHandle {
     let mut task_handles = Vec::new();
 
+    // In dry-run mode, only the packet workers are spawned, since they simulate
+    // the transactions they would submit, while the other workers would submit theirs
+    if dry_run && !matches!(object, Object::Packet(_) | Object::Wallet(_)) {
+        info!(
+            worker.object = %object.short_name(),
+            "not spawning worker in dry-run mode"
+        );
+
+        return WorkerHandle::new(id, object, None, None, task_handles);
+    }
+
     let (cmd_tx, data) = match &object {
         Object::Client(client) => {
             let client = ForeignClient::restore(client.dst_client_id.clone(), chains.b, chains.a);
@@ -119,18 +134,37 @@ pub fn spawn_worker_tasks<ChainA: ChainHandle, ChainB: ChainHandle>(
                     src_channel_id: path.src_channel_id.clone(),
                 },
                 packets_config.tx_confirmation,
-                packets_config.auto_register_counterparty_payee,
-            );
+                packets_config.auto_register_counterparty_payee && !dry_run,
+            )
+            .map(|link| link.with_state_store(state_store).with_dry_run(dry_run));
 
             match link_res {
                 Ok(link) => {
+                    let overrides = find_channel_overrides(config, &link.a_to_b);
+                    let link = link.with_channel_overrides(overrides.clone());
+
+                    let clear_on_start = overrides
+                        .as_ref()
+                        .and_then(|o| o.clear_on_start)
+                        .unwrap_or(packets_config.clear_on_start);
+                    let clear_interval = overrides
+                        .as_ref()
+                        .and_then(|o| o.clear_interval)
+                        .unwrap_or(packets_config.clear_interval);
+                    let batch_delay = overrides.as_ref().and_then(|o| o.batch_delay);
+
                     let channel_ordering = link.a_to_b.channel().ordering;
-                    let should_clear_on_start =
-                        packets_config.clear_on_start || channel_ordering == Ordering::Ordered;
+
+                    // If a relaying state was persisted before a restart, relaying is resumed from
+                    // it on start instead of clearing the pending packets, which are only cleared
+                    // if relaying cannot be resumed
+                    let should_resume_on_start = link.a_to_b.has_persisted_state();
+                    let should_clear_on_start = (clear_on_start && !should_resume_on_start)
+                        || channel_ordering == Ordering::Ordered;
 
                     let (cmd_tx, cmd_rx) = crossbeam_channel::unbounded();
                     let link = Arc::new(Mutex::new(link));
-                    let resubmit = Resubmit::from_clear_interval(packets_config.clear_interval);
+                    let resubmit = Resubmit::from_clear_interval(clear_interval);
 
                     let src_chain_config =
                         config.chains.iter().find(|chain| chain.id == chains.a.id());
@@ -156,12 +190,15 @@ pub fn spawn_worker_tasks<ChainA: ChainHandle, ChainB: ChainHandle>(
                             link.clone(),
                             path.clone(),
                             filter,
+                            batch_delay,
                         ),
                         None => packet::spawn_packet_cmd_worker(
                             cmd_rx,
                             link.clone(),
+                            should_resume_on_start,
                             should_clear_on_start,
-                            packets_config.clear_interval,
+                            clear_interval,
+                            batch_delay,
                             path.clone(),
                         ),
                     };
@@ -204,3 +241,27 @@ pub fn spawn_worker_tasks<ChainA: ChainHandle, ChainB: ChainHandle>(
 
     WorkerHandle::new(id, object, data, cmd_tx, task_handles)
 }
+
+/// The relaying settings configured for the channel of the given path, namely the
+/// `channel_overrides` of the source chain matching the source channel, if any,
+/// or else the ones of the destination chain matching the destination channel.
+fn find_channel_overrides<ChainA: ChainHandle, ChainB: ChainHandle>(
+    config: &Config,
+    relay_path: &RelayPath<ChainA, ChainB>,
+) -> Option<ChannelOverrides> {
+    let src_overrides = config
+        .find_chain(&relay_path.src_chain().id())
+        .and_then(|chain| {
+            chain.channel_overrides(relay_path.src_port_id(), relay_path.src_channel_id())
+        });
+
+    let dst_overrides = || {
+        config
+            .find_chain(&relay_path.dst_chain().id())
+            .and_then(|chain| {
+                chain.channel_overrides(relay_path.dst_port_id(), relay_path.dst_channel_id())
+            })
+    };
+
+    src_overrides.or_else(dst_overrides).cloned()
+}
@@ -34,6 +34,10 @@ pub fn spawn_channel_worker<ChainA: ChainHandle, ChainB: ChainHandle>(
     cmd_rx: Receiver<WorkerCmd>,
 ) -> TaskHandle {
     let mut complete_handshake_on_new_block = true;
+    // Whether the channel is being upgraded, in which case the worker keeps checking
+    // the state of the channel ends on new blocks, as the channel ends move to
+    // `FlushComplete` without emitting any event once their in-flight packets are flushed.
+    let mut upgrading = false;
     spawn_background_task(
         error_span!("worker.channel", channel = %channel.short_name()),
         Some(Duration::from_millis(200)),
@@ -49,19 +53,45 @@ pub fn spawn_channel_worker<ChainA: ChainHandle, ChainB: ChainHandle>(
 
                         complete_handshake_on_new_block = false;
                         if let Some(event_with_height) = last_event {
-                            retry_with_index(
+                            upgrading = event_with_height
+                                .event
+                                .channel_upgrade_attributes()
+                                .is_some();
+
+                            let result = retry_with_index(
                                 channel_handshake_retry::default_strategy(max_block_times),
-                                |index| match RelayChannel::restore_from_event(
-                                    chains.a.clone(),
-                                    chains.b.clone(),
-                                    event_with_height.event.clone(),
-                                ) {
-                                    Ok(mut handshake_channel) => handshake_channel
-                                        .step_event(&event_with_height.event, index),
-                                    Err(_) => RetryResult::Retry(index),
+                                |index| {
+                                    // The upgrade events do not include the connection of the channel,
+                                    // so the channel is restored from the state of the chain instead.
+                                    let restored = if upgrading {
+                                        RelayChannel::restore_from_state(
+                                            chains.a.clone(),
+                                            chains.b.clone(),
+                                            channel.clone(),
+                                            event_with_height.height,
+                                        )
+                                        .map(|(handshake_channel, _)| handshake_channel)
+                                    } else {
+                                        RelayChannel::restore_from_event(
+                                            chains.a.clone(),
+                                            chains.b.clone(),
+                                            event_with_height.event.clone(),
+                                        )
+                                    };
+
+                                    match restored {
+                                        Ok(mut handshake_channel) => handshake_channel
+                                            .step_event(&event_with_height.event, index),
+                                        Err(_) => RetryResult::Retry(index),
+                                    }
                                 },
                             )
-                            .map_err(|e| TaskError::Fatal(RunError::retry(e)))
+                            .map_err(|e| TaskError::Fatal(RunError::retry(e)));
+
+                            complete_handshake_on_new_block =
+                                upgrading && matches!(result, Ok(Next::Continue));
+
+                            result
                         } else {
                             Ok(Next::Continue)
                         }
@@ -77,8 +107,7 @@ pub fn spawn_channel_worker<ChainA: ChainHandle, ChainB: ChainHandle>(
                             .decrement()
                             .map_err(|e| TaskError::Fatal(RunError::ics02(e)))?;
 
-                        complete_handshake_on_new_block = false;
-                        retry_with_index(
+                        let result = retry_with_index(
                             channel_handshake_retry::default_strategy(max_block_times),
                             |index| match RelayChannel::restore_from_state(
                                 chains.a.clone(),
@@ -87,12 +116,18 @@ pub fn spawn_channel_worker<ChainA: ChainHandle, ChainB: ChainHandle>(
                                 height,
                             ) {
                                 Ok((mut handshake_channel, state)) => {
+                                    upgrading = upgrading || state.is_upgrading();
                                     handshake_channel.step_state(state, index)
                                 }
                                 Err(_) => RetryResult::Retry(index),
                             },
                         )
-                        .map_err(|e| TaskError::Fatal(RunError::retry(e)))
+                        .map_err(|e| TaskError::Fatal(RunError::retry(e)));
+
+                        complete_handshake_on_new_block =
+                            upgrading && matches!(result, Ok(Next::Continue));
+
+                        result
                     }
 
                     // nothing to do
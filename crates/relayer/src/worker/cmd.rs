@@ -1,5 +1,7 @@
 use core::fmt::{Display, Error as FmtError, Formatter};
 
+use crossbeam_channel::Sender;
+
 use ibc_relayer_types::{core::ics02_client::events::NewBlock, Height};
 
 use crate::event::monitor::EventBatch;
@@ -13,8 +15,11 @@ pub enum WorkerCmd {
     /// A new block has been committed
     NewBlock { height: Height, new_block: NewBlock },
 
-    /// Trigger a pending packets clear
-    ClearPendingPackets,
+    /// Trigger a pending packets clear, optionally replying with
+    /// the outcome of the clearing once it is done
+    ClearPendingPackets {
+        reply_to: Option<Sender<Result<(), String>>>,
+    },
 }
 
 impl Display for WorkerCmd {
@@ -30,7 +35,7 @@ impl Display for WorkerCmd {
             WorkerCmd::NewBlock { height, new_block } => {
                 write!(f, "NewBlock({height}, {new_block})")
             }
-            WorkerCmd::ClearPendingPackets => write!(f, "CleaPendingPackets"),
+            WorkerCmd::ClearPendingPackets { .. } => write!(f, "CleaPendingPackets"),
         }
     }
 }
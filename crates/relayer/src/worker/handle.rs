@@ -88,7 +88,15 @@ impl WorkerHandle {
 
     /// Instruct the worker to clear pending packets.
     pub fn clear_pending_packets(&self) {
-        self.try_send_command(WorkerCmd::ClearPendingPackets);
+        self.try_send_command(WorkerCmd::ClearPendingPackets { reply_to: None });
+    }
+
+    /// Instruct the worker to clear pending packets, and to send
+    /// the outcome of the clearing to the given channel once done.
+    pub fn clear_pending_packets_with_reply(&self, reply_to: Sender<Result<(), String>>) {
+        self.try_send_command(WorkerCmd::ClearPendingPackets {
+            reply_to: Some(reply_to),
+        });
     }
 
     /// Shutdown all worker tasks without waiting for them to terminate.
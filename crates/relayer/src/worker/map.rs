@@ -10,6 +10,7 @@ use crate::{
     chain::handle::{ChainHandle, ChainHandlePair},
     config::Config,
     object::Object,
+    state_store::StateStore,
     telemetry,
 };
 
@@ -20,14 +21,13 @@ use super::{spawn_worker_tasks, WorkerHandle, WorkerId};
 pub struct WorkerMap {
     workers: HashMap<Object, WorkerHandle>,
     latest_worker_id: WorkerId,
+    state_store: Option<StateStore>,
+    dry_run: bool,
 }
 
 impl Default for WorkerMap {
     fn default() -> Self {
-        Self {
-            workers: HashMap::new(),
-            latest_worker_id: WorkerId::new(0),
-        }
+        Self::with_state_store(None)
     }
 }
 
@@ -39,6 +39,29 @@ impl WorkerMap {
         Self::default()
     }
 
+    /// Create a new worker map, whose packet workers persist
+    /// their relaying state in the given store.
+    pub fn with_state_store(state_store: Option<StateStore>) -> Self {
+        Self {
+            workers: HashMap::new(),
+            latest_worker_id: WorkerId::new(0),
+            state_store,
+            dry_run: false,
+        }
+    }
+
+    /// Spawn workers which simulate the transactions they would
+    /// submit instead of submitting them, if `dry_run` is set.
+    pub fn with_dry_run(mut self, dry_run: bool) -> Self {
+        self.dry_run = dry_run;
+        self
+    }
+
+    /// The store in which the relaying state is persisted, if enabled.
+    pub fn state_store(&self) -> Option<&StateStore> {
+        self.state_store.as_ref()
+    }
+
     /// Returns `true` if there is a spawned [`WorkerHandle`] associated with the given [`Object`].
     pub fn contains(&self, object: &Object) -> bool {
         self.workers.contains_key(object)
@@ -166,6 +189,8 @@ impl WorkerMap {
             self.next_worker_id(),
             object.clone(),
             config,
+            self.state_store.clone(),
+            self.dry_run,
         )
     }
 
@@ -7,8 +7,8 @@ use {
 use core::time::Duration;
 use itertools::Itertools;
 use moka::sync::Cache;
-use std::borrow::BorrowMut;
 use std::sync::{Arc, Mutex};
+use std::time::Instant;
 use tracing::debug;
 
 use crossbeam_channel::Receiver;
@@ -19,16 +19,17 @@ use ibc_relayer_types::applications::transfer::{Amount, Coin, RawCoin};
 use ibc_relayer_types::core::ics04_channel::events::WriteAcknowledgement;
 use ibc_relayer_types::core::ics04_channel::packet::Sequence;
 use ibc_relayer_types::events::{IbcEvent, IbcEventType};
-use tracing::{error, error_span, trace};
+use tracing::{error, error_span, info, trace, warn};
 
+use ibc_relayer_types::bigint::U256;
 use ibc_relayer_types::Height;
 
 use crate::chain::handle::ChainHandle;
-use crate::config::filter::FeePolicy;
+use crate::config::filter::{FeePolicy, ProfitabilityPolicy};
 use crate::event::monitor::EventBatch;
 use crate::foreign_client::HasExpiredOrFrozenError;
-use crate::link::Resubmit;
 use crate::link::{error::LinkError, Link};
+use crate::link::{RelayPath, Resubmit};
 use crate::object::Packet;
 use crate::telemetry;
 use crate::util::lock::{LockExt, RwArc};
@@ -79,8 +80,10 @@ pub fn spawn_packet_cmd_worker<ChainA: ChainHandle, ChainB: ChainHandle>(
     cmd_rx: Receiver<WorkerCmd>,
     // Mutex is used to prevent race condition between the packet workers
     link: Arc<Mutex<Link<ChainA, ChainB>>>,
+    mut should_resume_on_start: bool,
     mut should_clear_on_start: bool,
     clear_interval: u64,
+    batch_delay: Option<Duration>,
     path: Packet,
 ) -> TaskHandle {
     let span = {
@@ -94,8 +97,12 @@ pub fn spawn_packet_cmd_worker<ChainA: ChainHandle, ChainB: ChainHandle>(
         )
     };
 
+    let mut deferred_cmd = None;
+
     spawn_background_task(span, Some(Duration::from_millis(200)), move || {
-        if let Ok(cmd) = cmd_rx.try_recv() {
+        if let Some(CoalescedCmd { cmd, later_batches }) =
+            next_cmd(&cmd_rx, &mut deferred_cmd, batch_delay)
+        {
             // Try to clear pending packets. At different levels down in `handle_packet_cmd` there
             // are retries mechanisms for MAX_RETRIES (current value hardcoded at 5).
             // If clearing fails after all these retries with ignorable error the task continues
@@ -103,10 +110,12 @@ pub fn spawn_packet_cmd_worker<ChainA: ChainHandle, ChainB: ChainHandle>(
             // (`NewBlock`) `cmd` that matches the clearing interval.
             handle_packet_cmd(
                 &mut link.lock().unwrap(),
+                &mut should_resume_on_start,
                 &mut should_clear_on_start,
                 clear_interval,
                 &path,
                 cmd,
+                later_batches,
             )?;
         }
 
@@ -120,6 +129,7 @@ pub fn spawn_incentivized_packet_cmd_worker<ChainA: ChainHandle, ChainB: ChainHa
     link: Arc<Mutex<Link<ChainA, ChainB>>>,
     path: Packet,
     fee_filter: FeePolicy,
+    batch_delay: Option<Duration>,
 ) -> TaskHandle {
     let span = {
         let relay_path = &link.lock().unwrap().a_to_b;
@@ -141,12 +151,17 @@ pub fn spawn_incentivized_packet_cmd_worker<ChainA: ChainHandle, ChainB: ChainHa
             .build(),
     );
 
+    let mut deferred_cmd = None;
+
     spawn_background_task(span, Some(Duration::from_millis(200)), move || {
-        if let Ok(cmd) = cmd_rx.try_recv() {
+        if let Some(CoalescedCmd { cmd, later_batches }) =
+            next_cmd(&cmd_rx, &mut deferred_cmd, batch_delay)
+        {
             handle_incentivized_packet_cmd(
                 &mut link.lock().unwrap(),
                 &path,
                 cmd,
+                later_batches,
                 &incentivized_recv_cache,
                 &fee_filter,
             )?;
@@ -156,10 +171,76 @@ pub fn spawn_incentivized_packet_cmd_worker<ChainA: ChainHandle, ChainB: ChainHa
     })
 }
 
+/// A command to handle, along with the batches of events coalesced into it.
+struct CoalescedCmd {
+    cmd: WorkerCmd,
+    /// The batches of events received within the batch delay of the channel after
+    /// an `IbcEvents` command, at later heights than the batch of this command,
+    /// with one batch per height, in order of height.
+    later_batches: Vec<EventBatch>,
+}
+
+/// Returns the next command to handle, if any, starting with the command
+/// deferred by the previous call.
+///
+/// If a `batch_delay` is configured for the channel, the `IbcEvents` commands
+/// received within that delay are coalesced into the first one. Their events keep
+/// the height they were emitted at, so that they are relayed with proofs at that
+/// height, and are grouped into one batch per height. The first command which
+/// cannot be coalesced is deferred.
+fn next_cmd(
+    cmd_rx: &Receiver<WorkerCmd>,
+    deferred_cmd: &mut Option<WorkerCmd>,
+    batch_delay: Option<Duration>,
+) -> Option<CoalescedCmd> {
+    let cmd = deferred_cmd.take().or_else(|| cmd_rx.try_recv().ok())?;
+
+    let (batch_delay, batch) = match (batch_delay, cmd) {
+        (Some(batch_delay), WorkerCmd::IbcEvents { batch }) => (batch_delay, batch),
+        (_, cmd) => {
+            return Some(CoalescedCmd {
+                cmd,
+                later_batches: vec![],
+            })
+        }
+    };
+
+    let deadline = Instant::now() + batch_delay;
+    let mut batches = vec![batch];
+
+    while let Ok(cmd) = cmd_rx.recv_deadline(deadline) {
+        match cmd {
+            WorkerCmd::IbcEvents { batch: next } => {
+                match batches.iter_mut().find(|batch| batch.height == next.height) {
+                    Some(batch) => batch.events.extend(next.events),
+                    None => batches.push(next),
+                }
+            }
+            cmd => {
+                *deferred_cmd = Some(cmd);
+                break;
+            }
+        }
+    }
+
+    batches.sort_by_key(|batch| batch.height);
+    let batch = batches.remove(0);
+
+    Some(CoalescedCmd {
+        cmd: WorkerCmd::IbcEvents { batch },
+        later_batches: batches,
+    })
+}
+
 /// Receives worker commands and handles them accordingly.
 ///
-/// Given an `IbcEvent` command, updates the schedule and initiates
-/// packet clearing if the `should_clear_on_start` flag has been toggled.
+/// On the first command, resumes relaying from the state persisted before a restart
+/// if the `should_resume_on_start` flag has been toggled, or toggles the
+/// `should_clear_on_start` flag if relaying cannot be resumed.
+///
+/// Given an `IbcEvent` command, updates the schedule with its batch of events and
+/// the `later_batches` coalesced into it, and initiates packet clearing if the
+/// `should_clear_on_start` flag has been toggled.
 ///
 /// Given a `NewBlock` command, checks if packet clearing should occur
 /// and performs it if so.
@@ -170,11 +251,35 @@ pub fn spawn_incentivized_packet_cmd_worker<ChainA: ChainHandle, ChainB: ChainHa
 /// and executes any scheduled operational data that is ready.
 fn handle_packet_cmd<ChainA: ChainHandle, ChainB: ChainHandle>(
     link: &mut Link<ChainA, ChainB>,
+    should_resume_on_start: &mut bool,
     should_clear_on_start: &mut bool,
     clear_interval: u64,
     path: &Packet,
     cmd: WorkerCmd,
+    later_batches: Vec<EventBatch>,
 ) -> Result<(), TaskError<RunError>> {
+    if *should_resume_on_start {
+        *should_resume_on_start = false;
+
+        let height = match &cmd {
+            WorkerCmd::IbcEvents { batch } => Some(batch.height),
+            WorkerCmd::NewBlock { height, .. } => Some(*height),
+            WorkerCmd::ClearPendingPackets { .. } => None,
+        };
+
+        match link.a_to_b.resume_persisted_state(height) {
+            Ok(true) => {}
+            Ok(false) => *should_clear_on_start = true,
+            Err(e) => {
+                error!(
+                    "failed to resume relaying from the persisted state, clearing pending packets instead: {}",
+                    e
+                );
+                *should_clear_on_start = true;
+            }
+        }
+    }
+
     // Handle packet clearing which is triggered from a command
     let (do_clear, maybe_height) = match &cmd {
         WorkerCmd::IbcEvents { batch } => {
@@ -195,7 +300,7 @@ fn handle_packet_cmd<ChainA: ChainHandle, ChainB: ChainHandle>(
             }
         }
 
-        WorkerCmd::ClearPendingPackets => (true, None),
+        WorkerCmd::ClearPendingPackets { .. } => (true, None),
     };
 
     if do_clear {
@@ -204,12 +309,30 @@ fn handle_packet_cmd<ChainA: ChainHandle, ChainB: ChainHandle>(
         if *should_clear_on_start {
             *should_clear_on_start = false;
         }
-        handle_clear_packet(link, clear_interval, path, maybe_height)?;
+
+        let result = handle_clear_packet(link, clear_interval, path, maybe_height);
+
+        if let WorkerCmd::ClearPendingPackets {
+            reply_to: Some(reply_to),
+        } = &cmd
+        {
+            let outcome = match &result {
+                Ok(()) => Ok(()),
+                Err(TaskError::Ignore(e) | TaskError::Fatal(e)) => Err(e.to_string()),
+            };
+
+            reply_to
+                .send(outcome)
+                .unwrap_or_else(|e| error!("error replying to a clearing request: {}", e));
+        }
+
+        result?;
     }
 
     // Handle command-specific task
     if let WorkerCmd::IbcEvents { batch } = cmd {
-        handle_update_schedule(link, clear_interval, path, batch)
+        let batches = core::iter::once(batch).chain(later_batches);
+        handle_update_schedule(link, clear_interval, path, batches)
     } else {
         Ok(())
     }
@@ -218,7 +341,8 @@ fn handle_packet_cmd<ChainA: ChainHandle, ChainB: ChainHandle>(
 /// Receives incentivized worker commands and handles them accordingly.
 ///
 /// Given an `IbcEvent` command, filters the SendPacket and WriteAcknowledgment
-/// events using the FeesFilters and updates the schedule.
+/// events of its batch and of the `later_batches` coalesced into it using the
+/// FeesFilters and updates the schedule.
 ///
 /// The incentivized worker does not clear packet, so it only looks for
 /// `IbcEvent` commands.
@@ -229,29 +353,35 @@ fn handle_incentivized_packet_cmd<ChainA: ChainHandle, ChainB: ChainHandle>(
     link: &mut Link<ChainA, ChainB>,
     path: &Packet,
     cmd: WorkerCmd,
+    later_batches: Vec<EventBatch>,
     incentivized_recv_cache: &RwArc<Cache<Sequence, IncentivizedPacket>>,
     fee_filter: &FeePolicy,
 ) -> Result<(), TaskError<RunError>> {
     // Handle command-specific task
-    if let WorkerCmd::IbcEvents { mut batch } = cmd {
-        // Iterate through the batch in order to retrieve the IncentivizedPacket
-        // which will be used to confirm if a SendPacket event is incentivized.
-        for event in batch.events.clone() {
-            if let IbcEvent::IncentivizedPacket(packet) = event.event {
-                incentivized_recv_cache
-                    .acquire_write()
-                    .insert(packet.sequence, packet.clone());
+    if let WorkerCmd::IbcEvents { batch } = cmd {
+        let mut batches: Vec<_> = core::iter::once(batch).chain(later_batches).collect();
+
+        for batch in batches.iter_mut() {
+            // Iterate through the batch in order to retrieve the IncentivizedPacket
+            // which will be used to confirm if a SendPacket event is incentivized.
+            for event in batch.events.clone() {
+                if let IbcEvent::IncentivizedPacket(packet) = event.event {
+                    incentivized_recv_cache
+                        .acquire_write()
+                        .insert(packet.sequence, packet.clone());
+                }
+                // It is not authorized to filter WriteAcknowledgement at the moment.
+                // This is because in order to filter them the worker would need to
+                // query the IncentivizedPacket events seen by the worker handling packets
+                // from ChainB to ChainA or it would need to query ChainA for incentivized
+                // packets.
+                // In addition if the WriteAcknowledgment are not relayed, no fees will be paid.
+                //IbcEvent::WriteAcknowledgement(ack) => get_incentivized_for_write_acknowledgement(link, ack, event.height.revision_height(), incentivized_ack_cache.clone()),
             }
-            // It is not authorized to filter WriteAcknowledgement at the moment.
-            // This is because in order to filter them the worker would need to
-            // query the IncentivizedPacket events seen by the worker handling packets
-            // from ChainB to ChainA or it would need to query ChainA for incentivized
-            // packets.
-            // In addition if the WriteAcknowledgment are not relayed, no fees will be paid.
-            //IbcEvent::WriteAcknowledgement(ack) => get_incentivized_for_write_acknowledgement(link, ack, event.height.revision_height(), incentivized_ack_cache.clone()),
+            filter_batch(link, batch, incentivized_recv_cache, fee_filter);
         }
-        filter_batch(batch.borrow_mut(), incentivized_recv_cache, fee_filter);
-        handle_update_schedule(link, 0, path, batch)
+
+        handle_update_schedule(link, 0, path, batches)
     } else {
         Ok(())
     }
@@ -302,7 +432,8 @@ fn _get_incentivized_for_write_acknowledgement<ChainA: ChainHandle, ChainB: Chai
 /// Using the configured FeesFilter and observed/queried information for
 /// incentivized packets, determine if the SendPacket and WriteAcknowledgement events
 /// should be relayed or not.
-fn filter_batch(
+fn filter_batch<ChainA: ChainHandle, ChainB: ChainHandle>(
+    link: &Link<ChainA, ChainB>,
     batch: &mut EventBatch,
     incentivized_recv_cache: &RwArc<Cache<Sequence, IncentivizedPacket>>,
     fee_filter: &FeePolicy,
@@ -319,6 +450,101 @@ fn filter_batch(
             }),
         _ => true,
     });
+
+    if let Some(profitability) = fee_filter.profitability() {
+        filter_unprofitable_packets(&link.a_to_b, batch, incentivized_recv_cache, profitability);
+    }
+}
+
+/// Only keeps the SendPacket events whose incentives exceed the estimated cost
+/// of relaying them by the margin required by the profitability policy.
+///
+/// The cost is estimated once for the whole batch, by simulating the transaction
+/// receiving the packets on the destination chain, and split evenly across the packets.
+/// If the cost cannot be estimated or valued, the packets are relayed regardless.
+fn filter_unprofitable_packets<ChainA: ChainHandle, ChainB: ChainHandle>(
+    relay_path: &RelayPath<ChainA, ChainB>,
+    batch: &mut EventBatch,
+    incentivized_recv_cache: &RwArc<Cache<Sequence, IncentivizedPacket>>,
+    profitability: &ProfitabilityPolicy,
+) {
+    let packets = batch
+        .events
+        .iter()
+        .filter_map(|e| match &e.event {
+            IbcEvent::SendPacket(packet) => Some(packet.packet.clone()),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+
+    if packets.is_empty() {
+        return;
+    }
+
+    let batch_cost = match relay_path.estimate_recv_packets_fee(&packets, batch.height) {
+        Ok(cost) => cost,
+        Err(e) => {
+            warn!(
+                "failed to estimate the cost of relaying {} packets, relaying them regardless: {}",
+                packets.len(),
+                e
+            );
+            return;
+        }
+    };
+
+    let packet_cost = batch_cost
+        .into_iter()
+        .map(|fee| {
+            let amount = fee.amount.0 / U256::from(packets.len());
+            Coin::new(fee.denom, Amount(amount))
+        })
+        .collect::<Vec<_>>();
+
+    let mut skipped = 0;
+
+    batch.events.retain(|e| match &e.event {
+        IbcEvent::SendPacket(packet) => {
+            let incentives = incentivized_recv_cache
+                .acquire_read()
+                .get(&packet.packet.sequence)
+                .map(retrieve_all_incentives_from_incentivized_packet)
+                .unwrap_or_default();
+
+            match profitability.is_profitable(&incentives, &packet_cost) {
+                Some(true) => true,
+                Some(false) => {
+                    debug!(
+                        packet = %packet.packet,
+                        "skipping packet as relaying it is not profitable enough"
+                    );
+                    skipped += 1;
+                    false
+                }
+                None => {
+                    warn!(
+                        packet = %packet.packet,
+                        "no price is configured for the denom of the cost of relaying the packet, relaying it regardless"
+                    );
+                    true
+                }
+            }
+        }
+        _ => true,
+    });
+
+    if skipped > 0 {
+        info!("skipped {skipped} packets as relaying them is not profitable enough");
+
+        telemetry!(
+            unprofitable_packets_skipped,
+            &relay_path.src_chain().id(),
+            relay_path.src_channel_id(),
+            relay_path.src_port_id(),
+            &relay_path.dst_chain().id(),
+            skipped
+        );
+    }
 }
 
 /// Multiple fees with different denoms can be specified as rewards,
@@ -339,6 +565,19 @@ fn retrieve_all_fees_from_incentivized_packet(
         .collect()
 }
 
+/// Retrieves all the incentives of an `IncentivizedPacket`,
+/// ie. its recv, ack and timeout fees, in any denom.
+fn retrieve_all_incentives_from_incentivized_packet(
+    incentivized_packet: IncentivizedPacket,
+) -> Vec<RawCoin> {
+    incentivized_packet
+        .total_recv_fee
+        .into_iter()
+        .chain(incentivized_packet.total_ack_fee)
+        .chain(incentivized_packet.total_timeout_fee)
+        .collect()
+}
+
 /// Whether or not to clear pending packets at this `step` for some height.
 /// If the relayer has been configured to clear packets on start and that has not
 /// occurred yet, then packets are cleared.
@@ -353,11 +592,13 @@ fn handle_update_schedule<ChainA: ChainHandle, ChainB: ChainHandle>(
     link: &mut Link<ChainA, ChainB>,
     clear_interval: u64,
     path: &Packet,
-    batch: EventBatch,
+    batches: impl IntoIterator<Item = EventBatch>,
 ) -> Result<(), TaskError<RunError>> {
-    link.a_to_b
-        .update_schedule(batch)
-        .map_err(handle_link_error_in_task)?;
+    for batch in batches {
+        link.a_to_b
+            .update_schedule(batch)
+            .map_err(handle_link_error_in_task)?;
+    }
 
     handle_execute_schedule(link, path, Resubmit::from_clear_interval(clear_interval))
 }
@@ -393,6 +634,15 @@ fn handle_execute_schedule<ChainA: ChainHandle, ChainB: ChainHandle>(
         }
     })?;
 
+    // The transactions simulated in dry-run mode are logged as they are simulated
+    let simulated_txs = link.a_to_b.take_simulated_txs();
+    if !simulated_txs.is_empty() {
+        debug!(
+            "simulated {} transaction(s) in dry-run mode",
+            simulated_txs.len()
+        );
+    }
+
     let summary = link.a_to_b.process_pending_txs(resubmit);
 
     if !summary.is_empty() {
@@ -502,3 +752,188 @@ fn timeout_metrics(
         count as u64,
     );
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use alloc::sync::Arc;
+
+    use crossbeam_channel::unbounded;
+    use tokio::runtime::Runtime as TokioRuntime;
+
+    use ibc_relayer_types::core::ics02_client::events::NewBlock;
+    use ibc_relayer_types::core::ics04_channel::channel::Ordering;
+    use ibc_relayer_types::core::ics04_channel::timeout::TimeoutHeight;
+    use ibc_relayer_types::core::ics24_host::identifier::ChainId;
+
+    use crate::chain::mock::test_utils::{link, open_channel, spawn_chain, transfer};
+    use crate::chain::requests::QueryUnreceivedPacketsRequest;
+    use crate::chain::tracking::TrackingId;
+    use crate::event::IbcEventWithHeight;
+    use crate::state_store::{path_key, StateStore};
+
+    fn events_cmd(height: u64, count: usize) -> WorkerCmd {
+        let height = Height::new(0, height).unwrap();
+        let events = (0..count)
+            .map(|_| IbcEventWithHeight::new(IbcEvent::NewBlock(NewBlock::new(height)), height))
+            .collect();
+
+        WorkerCmd::IbcEvents {
+            batch: EventBatch {
+                chain_id: ChainId::new("chain".to_string(), 0),
+                tracking_id: TrackingId::new_static("test"),
+                height,
+                events,
+            },
+        }
+    }
+
+    #[test]
+    fn coalesce_batches_within_delay_keeping_heights() {
+        let (cmd_tx, cmd_rx) = unbounded();
+        let mut deferred_cmd = None;
+
+        cmd_tx.send(events_cmd(10, 2)).unwrap();
+        cmd_tx.send(events_cmd(12, 1)).unwrap();
+        cmd_tx.send(events_cmd(11, 1)).unwrap();
+        cmd_tx.send(events_cmd(11, 1)).unwrap();
+        cmd_tx
+            .send(WorkerCmd::ClearPendingPackets { reply_to: None })
+            .unwrap();
+        cmd_tx.send(events_cmd(13, 1)).unwrap();
+
+        let delay = Some(Duration::from_millis(10));
+
+        let Some(CoalescedCmd {
+            cmd: WorkerCmd::IbcEvents { batch },
+            later_batches,
+        }) = next_cmd(&cmd_rx, &mut deferred_cmd, delay)
+        else {
+            panic!("expected a batch of events");
+        };
+
+        // The batches received before the clearing command are coalesced into the
+        // first one, grouped by height, and their events keep their own height
+        let batches: Vec<_> = core::iter::once(batch).chain(later_batches).collect();
+
+        let heights: Vec<_> = batches.iter().map(|batch| batch.height).collect();
+        assert_eq!(
+            heights,
+            [10, 11, 12].map(|height| Height::new(0, height).unwrap())
+        );
+
+        let lens: Vec<_> = batches.iter().map(|batch| batch.events.len()).collect();
+        assert_eq!(lens, [2, 2, 1]);
+
+        assert!(batches
+            .iter()
+            .all(|batch| batch.events.iter().all(|ev| ev.height == batch.height)));
+
+        assert!(matches!(
+            next_cmd(&cmd_rx, &mut deferred_cmd, delay),
+            Some(CoalescedCmd {
+                cmd: WorkerCmd::ClearPendingPackets { .. },
+                ..
+            })
+        ));
+
+        let Some(CoalescedCmd {
+            cmd: WorkerCmd::IbcEvents { batch },
+            later_batches,
+        }) = next_cmd(&cmd_rx, &mut deferred_cmd, delay)
+        else {
+            panic!("expected a batch of events");
+        };
+        assert_eq!(batch.events.len(), 1);
+        assert!(later_batches.is_empty());
+    }
+
+    #[test]
+    fn resume_from_persisted_state_on_restart() {
+        let rt = Arc::new(TokioRuntime::new().unwrap());
+        let a = spawn_chain("mock-1", &rt);
+        let b = spawn_chain("mock-2", &rt);
+
+        let channel = open_channel(&a, &b, Ordering::Unordered);
+        let (src_port_id, src_channel_id) = (
+            channel.src_port_id().clone(),
+            channel.src_channel_id().unwrap().clone(),
+        );
+        let key = path_key(&a.id(), &src_port_id, &src_channel_id);
+
+        let state_path = std::env::temp_dir()
+            .join(format!("hermes-state-{}", std::process::id()))
+            .join("resume.json");
+        let _ = std::fs::remove_file(&state_path);
+
+        // Before the restart, packet 1 was handled, packet 2 was in flight
+        // at the last handled height, and packet 3 was sent afterwards
+        {
+            let store = StateStore::open(&state_path).unwrap();
+
+            let first_height = transfer(&channel, TimeoutHeight::Never)[0].height;
+            let last_handled_height = transfer(&channel, TimeoutHeight::Never)[0].height;
+            assert!(first_height < last_handled_height);
+
+            store.track(&key, &[Sequence::from(2)], &[]);
+            store.update_last_handled_height(&a.id(), last_handled_height);
+            store.flush();
+        }
+
+        transfer(&channel, TimeoutHeight::Never);
+
+        // Restart the worker from the persisted state
+        let store = StateStore::open(&state_path).unwrap();
+        let mut link = link(&channel).with_state_store(Some(store));
+        let path = Packet {
+            dst_chain_id: b.id(),
+            src_chain_id: a.id(),
+            src_channel_id,
+            src_port_id,
+        };
+
+        let mut should_resume_on_start = link.a_to_b.has_persisted_state();
+        let mut should_clear_on_start = false;
+        assert!(should_resume_on_start);
+
+        let height = a.query_latest_height().unwrap().increment();
+        let cmd = WorkerCmd::NewBlock {
+            height,
+            new_block: NewBlock::new(height),
+        };
+
+        let result = handle_packet_cmd(
+            &mut link,
+            &mut should_resume_on_start,
+            &mut should_clear_on_start,
+            0,
+            &path,
+            cmd,
+            vec![],
+        );
+        assert!(result.is_ok());
+        link.a_to_b.execute_schedule().unwrap();
+
+        // Relaying was resumed instead of clearing all the pending packets,
+        // and only once
+        assert!(!should_resume_on_start);
+        assert!(!should_clear_on_start);
+        assert!(!link.a_to_b.has_persisted_state());
+
+        // Only the packet in flight and the one sent after the last handled height were relayed
+        let unreceived = b
+            .query_unreceived_packets(QueryUnreceivedPacketsRequest {
+                port_id: channel.dst_port_id().clone(),
+                channel_id: channel.dst_channel_id().unwrap().clone(),
+                packet_commitment_sequences: (1..=3).map(Sequence::from).collect(),
+            })
+            .unwrap();
+        assert_eq!(unreceived, vec![Sequence::from(1)]);
+
+        let _ = std::fs::remove_file(&state_path);
+
+        a.shutdown().unwrap();
+        b.shutdown().unwrap();
+    }
+}
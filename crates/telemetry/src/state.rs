@@ -17,6 +17,7 @@ use ibc_relayer_types::{
     applications::transfer::Coin,
     core::ics24_host::identifier::{ChainId, ChannelId, ClientId, PortId},
     signer::Signer,
+    timestamp::Timestamp,
 };
 
 use tendermint::Time;
@@ -127,9 +128,19 @@ pub struct TelemetryState {
     /// Number of messages submitted to a specific chain
     messages_submitted: Counter<u64>,
 
+    /// Time left in the trusting period of each client monitored by Hermes, in seconds
+    client_expiry_time_left: ObservableGauge<u64>,
+
     /// The balance of each wallet Hermes uses per chain
     wallet_balance: ObservableGauge<f64>,
 
+    /// The gas price used by Hermes for the last transaction submitted to a specific chain,
+    /// as computed from the fee market's base fee when the dynamic gas price is enabled
+    dynamic_gas_price: ObservableGauge<f64>,
+
+    /// Whether each of the nodes configured for a chain is the one in use (1) or not (0)
+    active_endpoint: ObservableGauge<u64>,
+
     /// Indicates the latency for all transactions submitted to a specific chain,
     /// i.e. the difference between the moment when Hermes received a batch of events
     /// until the corresponding transaction(s) were submitted. Milliseconds.
@@ -159,6 +170,9 @@ pub struct TelemetryState {
     /// Number of WriteAcknowledgement events received during the initial and periodic clearing
     cleared_acknowledgment_events: Counter<u64>,
 
+    /// Number of packets skipped because their sequence is excluded in the configuration
+    skipped_excluded_packets: Counter<u64>,
+
     /// Records the sequence number of the oldest pending packet. This corresponds to
     /// the sequence number of the oldest SendPacket event for which no
     /// WriteAcknowledgement or Timeout events have been received. The value is 0 if all the
@@ -181,6 +195,22 @@ pub struct TelemetryState {
     /// Timeout event.
     backlogs: DashMap<PathIdentifier, DashMap<u64, u64>>,
 
+    /// Stores the time at which the packets the relayer observed were sent, for all the paths
+    /// the relayer is active on, until the packets are acknowledged or time out.
+    /// This is a map of multiple inner maps, one per path, from the packet sequence number to
+    /// the timestamp of the block which includes the SendPacket event.
+    sent_packets: DashMap<PathIdentifier, DashMap<u64, Timestamp>>,
+
+    /// The end-to-end latency of the packets relayed to a specific chain, i.e. the difference
+    /// between the timestamps of the block which includes the SendPacket event on the source chain
+    /// and the block which includes the WriteAcknowledgement event on the destination chain. Milliseconds.
+    packet_latency_recv: ObservableGauge<u64>,
+
+    /// The end-to-end latency of the packets relayed from a specific chain, i.e. the difference
+    /// between the timestamps of the block which includes the SendPacket event on the source chain
+    /// and the block which includes the AcknowledgePacket event on the source chain. Milliseconds.
+    packet_latency_ack: ObservableGauge<u64>,
+
     /// Total amount of fees received from ICS29 fees.
     fee_amounts: Counter<u64>,
 
@@ -192,6 +222,18 @@ pub struct TelemetryState {
 
     /// Sum of rewarded fees over the past FEE_LIFETIME seconds
     period_fees: ObservableGauge<u64>,
+
+    /// Total amount of fees paid by Hermes for the transactions it submitted,
+    /// per chain, channel and denom.
+    relayer_fees_paid: Counter<u64>,
+
+    /// Total amount of ICS29 incentives earned by Hermes for the packets it relayed,
+    /// per chain, channel and denom.
+    relayer_incentives_earned: Counter<u64>,
+
+    /// Number of SendPacket events which were not relayed because relaying them was not
+    /// profitable enough, per chain, counterparty chain, channel and port.
+    unprofitable_packets_skipped: Counter<u64>,
 }
 
 impl TelemetryState {
@@ -267,6 +309,8 @@ impl TelemetryState {
             self.cleared_acknowledgment_events.add(&cx, 0, labels);
         }
 
+        self.skipped_excluded_packets.add(&cx, 0, labels);
+
         self.backlog_oldest_sequence.observe(&cx, 0, labels);
         self.backlog_oldest_timestamp.observe(&cx, 0, labels);
         self.backlog_size.observe(&cx, 0, labels);
@@ -479,6 +523,27 @@ impl TelemetryState {
         self.ws_reconnect.add(&cx, 1, labels);
     }
 
+    /// Whether the node with the given endpoints is the one in use for a chain.
+    pub fn active_endpoint(
+        &self,
+        chain_id: &ChainId,
+        rpc_addr: &str,
+        websocket_addr: &str,
+        grpc_addr: &str,
+        active: bool,
+    ) {
+        let cx = Context::current();
+
+        let labels = &[
+            KeyValue::new("chain", chain_id.to_string()),
+            KeyValue::new("rpc_addr", rpc_addr.to_string()),
+            KeyValue::new("websocket_addr", websocket_addr.to_string()),
+            KeyValue::new("grpc_addr", grpc_addr.to_string()),
+        ];
+
+        self.active_endpoint.observe(&cx, u64::from(active), labels);
+    }
+
     /// How many IBC events did Hermes receive via the WebSocket subscription, per chain
     pub fn ws_events(&self, chain_id: &ChainId, count: u64) {
         let cx = Context::current();
@@ -497,6 +562,25 @@ impl TelemetryState {
         self.messages_submitted.add(&cx, count, labels);
     }
 
+    /// The time left in the trusting period of a client, in seconds.
+    pub fn client_expiry_time_left(
+        &self,
+        src_chain: &ChainId,
+        dst_chain: &ChainId,
+        client: &ClientId,
+        seconds: u64,
+    ) {
+        let cx = Context::current();
+
+        let labels = &[
+            KeyValue::new("src_chain", src_chain.to_string()),
+            KeyValue::new("dst_chain", dst_chain.to_string()),
+            KeyValue::new("client", client.to_string()),
+        ];
+
+        self.client_expiry_time_left.observe(&cx, seconds, labels);
+    }
+
     /// The balance in each wallet that Hermes is using, per account, denom and chain.
     /// The amount given is of unit: 10^6 * `denom`
     pub fn wallet_balance(&self, chain_id: &ChainId, account: &str, amount: f64, denom: &str) {
@@ -511,6 +595,17 @@ impl TelemetryState {
         self.wallet_balance.observe(&cx, amount, labels);
     }
 
+    pub fn dynamic_gas_price(&self, chain_id: &ChainId, price: f64, denom: &str) {
+        let cx = Context::current();
+
+        let labels = &[
+            KeyValue::new("chain", chain_id.to_string()),
+            KeyValue::new("denom", denom.to_string()),
+        ];
+
+        self.dynamic_gas_price.observe(&cx, price, labels);
+    }
+
     pub fn received_event_batch(&self, tracking_id: impl ToString) {
         self.in_flight_events
             .insert(tracking_id.to_string(), Instant::now());
@@ -679,8 +774,31 @@ impl TelemetryState {
         self.cleared_acknowledgment_events.add(&cx, 1, labels);
     }
 
+    pub fn skipped_excluded_packets(
+        &self,
+        chain_id: &ChainId,
+        channel_id: &ChannelId,
+        port_id: &PortId,
+        counterparty_chain_id: &ChainId,
+        count: u64,
+    ) {
+        let cx = Context::current();
+
+        let labels: &[KeyValue; 4] = &[
+            KeyValue::new("chain", chain_id.to_string()),
+            KeyValue::new("counterparty", counterparty_chain_id.to_string()),
+            KeyValue::new("channel", channel_id.to_string()),
+            KeyValue::new("port", port_id.to_string()),
+        ];
+
+        self.skipped_excluded_packets.add(&cx, count, labels);
+    }
+
     /// Inserts in the backlog a new event for the given sequence number.
     /// This happens when the relayer observed a new SendPacket event.
+    ///
+    /// The `sent_at` timestamp, i.e. the timestamp of the block which includes the
+    /// SendPacket event, is recorded to compute the end-to-end latency of the packet.
     pub fn backlog_insert(
         &self,
         seq_nr: u64,
@@ -688,6 +806,7 @@ impl TelemetryState {
         channel_id: &ChannelId,
         port_id: &PortId,
         counterparty_chain_id: &ChainId,
+        sent_at: Timestamp,
     ) {
         let cx = Context::current();
 
@@ -746,12 +865,28 @@ impl TelemetryState {
             let new_path_backlog = DashMap::with_capacity(BACKLOG_CAPACITY);
             new_path_backlog.insert(seq_nr, timestamp);
             // Record it in the global backlog
-            self.backlogs.insert(path_uid, new_path_backlog);
+            self.backlogs.insert(path_uid.clone(), new_path_backlog);
 
             // Return the current event information to be recorded in telemetry
             (seq_nr, timestamp, 1)
         };
 
+        // Record the time at which the packet was sent
+        let path_sent_packets = self
+            .sent_packets
+            .entry(path_uid)
+            .or_insert_with(|| DashMap::with_capacity(BACKLOG_CAPACITY));
+
+        // As for the backlog, avoid having the map growing more than a given threshold,
+        // e.g. because of packets whose timeout was not observed, by removing the oldest
+        // sequence number entry.
+        if path_sent_packets.len() > BACKLOG_RESET_THRESHOLD {
+            if let Some(min) = path_sent_packets.iter().map(|v| *v.key()).min() {
+                path_sent_packets.remove(&min);
+            }
+        }
+        path_sent_packets.insert(seq_nr, sent_at);
+
         // Update metrics to reflect the new state of the backlog
         self.backlog_oldest_sequence.observe(&cx, oldest_sn, labels);
         self.backlog_oldest_timestamp
@@ -812,6 +947,107 @@ impl TelemetryState {
         }
     }
 
+    /// Stops tracking the time at which the packet with the given sequence number was sent,
+    /// when the relayer observed a timeout for it, as its latency will not be measured.
+    pub fn sent_packet_remove(
+        &self,
+        seq_nr: u64,
+        chain_id: &ChainId,
+        channel_id: &ChannelId,
+        port_id: &PortId,
+    ) {
+        let path_uid = PathIdentifier::new(
+            chain_id.to_string(),
+            channel_id.to_string(),
+            port_id.to_string(),
+        );
+
+        if let Some(path_sent_packets) = self.sent_packets.get(&path_uid) {
+            path_sent_packets.remove(&seq_nr);
+        }
+    }
+
+    /// Records the latency of a packet from its SendPacket event until it was received,
+    /// given the timestamp of the block which includes the WriteAcknowledgement event
+    /// on the counterparty chain.
+    ///
+    /// The chain, channel and port identify the end of the channel which sent the packet.
+    pub fn packet_latency_recv(
+        &self,
+        seq_nr: u64,
+        chain_id: &ChainId,
+        channel_id: &ChannelId,
+        port_id: &PortId,
+        counterparty_chain_id: &ChainId,
+        received_at: Timestamp,
+    ) {
+        let path_uid = PathIdentifier::new(
+            chain_id.to_string(),
+            channel_id.to_string(),
+            port_id.to_string(),
+        );
+
+        let sent_at = match self.sent_packets.get(&path_uid) {
+            Some(path_sent_packets) => path_sent_packets.get(&seq_nr).map(|v| *v.value()),
+            None => None,
+        };
+
+        if let Some(latency) = sent_at.and_then(|sent_at| received_at.duration_since(&sent_at)) {
+            let cx = Context::current();
+
+            let labels = &[
+                KeyValue::new("chain", chain_id.to_string()),
+                KeyValue::new("counterparty", counterparty_chain_id.to_string()),
+                KeyValue::new("channel", channel_id.to_string()),
+                KeyValue::new("port", port_id.to_string()),
+            ];
+
+            self.packet_latency_recv
+                .observe(&cx, latency.as_millis() as u64, labels);
+        }
+    }
+
+    /// Records the latency of a packet from its SendPacket event until it was acknowledged,
+    /// given the timestamp of the block which includes the AcknowledgePacket event,
+    /// and stops tracking the packet.
+    ///
+    /// The chain, channel and port identify the end of the channel which sent the packet.
+    pub fn packet_latency_ack(
+        &self,
+        seq_nr: u64,
+        chain_id: &ChainId,
+        channel_id: &ChannelId,
+        port_id: &PortId,
+        counterparty_chain_id: &ChainId,
+        acknowledged_at: Timestamp,
+    ) {
+        let path_uid = PathIdentifier::new(
+            chain_id.to_string(),
+            channel_id.to_string(),
+            port_id.to_string(),
+        );
+
+        let sent_at = match self.sent_packets.get(&path_uid) {
+            Some(path_sent_packets) => path_sent_packets.remove(&seq_nr).map(|(_, v)| v),
+            None => None,
+        };
+
+        if let Some(latency) = sent_at.and_then(|sent_at| acknowledged_at.duration_since(&sent_at))
+        {
+            let cx = Context::current();
+
+            let labels = &[
+                KeyValue::new("chain", chain_id.to_string()),
+                KeyValue::new("counterparty", counterparty_chain_id.to_string()),
+                KeyValue::new("channel", channel_id.to_string()),
+                KeyValue::new("port", port_id.to_string()),
+            ];
+
+            self.packet_latency_ack
+                .observe(&cx, latency.as_millis() as u64, labels);
+        }
+    }
+
     /// Record the rewarded fee from ICS29 if the address is in the registered addresses
     /// list.
     pub fn fees_amount(&self, chain_id: &ChainId, receiver: &Signer, fee_amounts: Coin<String>) {
@@ -870,6 +1106,76 @@ impl TelemetryState {
     pub fn add_visible_fee_address(&self, address: String) {
         self.visible_fee_addresses.insert(address);
     }
+
+    /// Record the fees paid for a transaction submitted by Hermes.
+    /// Fees which cannot be attributed to a channel are recorded without port and channel.
+    pub fn relayer_fees_paid(
+        &self,
+        chain_id: &ChainId,
+        channel: Option<(&PortId, &ChannelId)>,
+        denom: &str,
+        amount: u64,
+    ) {
+        let cx = Context::current();
+
+        let labels = economics_labels(chain_id, channel, denom);
+
+        self.relayer_fees_paid.add(&cx, amount, &labels);
+    }
+
+    /// Record the ICS29 incentives earned by Hermes for relaying packets on a channel.
+    pub fn relayer_incentives_earned(
+        &self,
+        chain_id: &ChainId,
+        channel: Option<(&PortId, &ChannelId)>,
+        denom: &str,
+        amount: u64,
+    ) {
+        let cx = Context::current();
+
+        let labels = economics_labels(chain_id, channel, denom);
+
+        self.relayer_incentives_earned.add(&cx, amount, &labels);
+    }
+
+    /// Record the number of SendPacket events skipped because relaying them was not profitable enough.
+    pub fn unprofitable_packets_skipped(
+        &self,
+        chain_id: &ChainId,
+        channel_id: &ChannelId,
+        port_id: &PortId,
+        counterparty_chain_id: &ChainId,
+        count: u64,
+    ) {
+        let cx = Context::current();
+
+        let labels = &[
+            KeyValue::new("chain", chain_id.to_string()),
+            KeyValue::new("counterparty", counterparty_chain_id.to_string()),
+            KeyValue::new("channel", channel_id.to_string()),
+            KeyValue::new("port", port_id.to_string()),
+        ];
+
+        self.unprofitable_packets_skipped.add(&cx, count, labels);
+    }
+}
+
+fn economics_labels(
+    chain_id: &ChainId,
+    channel: Option<(&PortId, &ChannelId)>,
+    denom: &str,
+) -> [KeyValue; 4] {
+    let (port, channel) = match channel {
+        Some((port_id, channel_id)) => (port_id.to_string(), channel_id.to_string()),
+        None => (String::new(), String::new()),
+    };
+
+    [
+        KeyValue::new("chain", chain_id.to_string()),
+        KeyValue::new("port", port),
+        KeyValue::new("channel", channel),
+        KeyValue::new("denom", denom.to_string()),
+    ]
 }
 
 use std::sync::Arc;
@@ -886,7 +1192,10 @@ struct CustomAggregatorSelector;
 impl AggregatorSelector for CustomAggregatorSelector {
     fn aggregator_for(&self, descriptor: &Descriptor) -> Option<Arc<dyn Aggregator + Send + Sync>> {
         match descriptor.name() {
+            "client_expiry_time_left" => Some(Arc::new(last_value())),
             "wallet_balance" => Some(Arc::new(last_value())),
+            "dynamic_gas_price" => Some(Arc::new(last_value())),
+            "active_endpoint" => Some(Arc::new(last_value())),
             "backlog_oldest_sequence" => Some(Arc::new(last_value())),
             "backlog_oldest_timestamp" => Some(Arc::new(last_value())),
             "backlog_size" => Some(Arc::new(last_value())),
@@ -899,6 +1208,12 @@ impl AggregatorSelector for CustomAggregatorSelector {
             "tx_latency_confirmed" => Some(Arc::new(histogram(&[
                 1000.0, 5000.0, 9000.0, 13000.0, 17000.0, 20000.0,
             ]))),
+            "packet_latency_recv" => Some(Arc::new(histogram(&[
+                5000.0, 10000.0, 20000.0, 30000.0, 60000.0, 120000.0, 300000.0, 600000.0,
+            ]))),
+            "packet_latency_ack" => Some(Arc::new(histogram(&[
+                10000.0, 20000.0, 30000.0, 60000.0, 120000.0, 300000.0, 600000.0, 1200000.0,
+            ]))),
             "ics29_period_fees" => Some(Arc::new(last_value())),
             _ => Some(Arc::new(sum())),
         }
@@ -980,11 +1295,27 @@ impl Default for TelemetryState {
                 .with_description("Number of messages submitted to a specific chain")
                 .init(),
 
+            client_expiry_time_left: meter
+                .u64_observable_gauge("client_expiry_time_left")
+                .with_unit(Unit::new("seconds"))
+                .with_description("Time left in the trusting period of each client monitored by Hermes. Seconds.")
+                .init(),
+
             wallet_balance: meter
                 .f64_observable_gauge("wallet_balance")
                 .with_description("The balance of each wallet Hermes uses per chain. Please note that when converting the balance to f64 a loss in precision might be introduced in the displayed value")
                 .init(),
 
+            dynamic_gas_price: meter
+                .f64_observable_gauge("dynamic_gas_price")
+                .with_description("The gas price used for the last transaction submitted to each chain with the dynamic gas price enabled")
+                .init(),
+
+            active_endpoint: meter
+                .u64_observable_gauge("active_endpoint")
+                .with_description("Whether each of the nodes configured for a chain is the one in use (1) or not (0)")
+                .init(),
+
             send_packet_events: meter
                 .u64_counter("send_packet_events")
                 .with_description("Number of SendPacket events received")
@@ -1010,6 +1341,11 @@ impl Default for TelemetryState {
                 .with_description("Number of WriteAcknowledgement events received during the initial and periodic clearing")
                 .init(),
 
+            skipped_excluded_packets: meter
+                .u64_counter("skipped_excluded_packets")
+                .with_description("Number of packets skipped because their sequence is excluded in the configuration")
+                .init(),
+
             tx_latency_submitted: meter
                 .u64_observable_gauge("tx_latency_submitted")
                 .with_unit(Unit::new("milliseconds"))
@@ -1049,6 +1385,24 @@ impl Default for TelemetryState {
                 .with_description("Total number of SendPacket events in the backlog")
                 .init(),
 
+            sent_packets: DashMap::new(),
+
+            packet_latency_recv: meter
+                .u64_observable_gauge("packet_latency_recv")
+                .with_unit(Unit::new("milliseconds"))
+                .with_description("The end-to-end latency of the packets relayed by Hermes, \
+                    i.e. the difference between the timestamps of the blocks which include the SendPacket event \
+                    and the WriteAcknowledgement event of each packet. Milliseconds.")
+                .init(),
+
+            packet_latency_ack: meter
+                .u64_observable_gauge("packet_latency_ack")
+                .with_unit(Unit::new("milliseconds"))
+                .with_description("The end-to-end latency of the packets relayed by Hermes, \
+                    i.e. the difference between the timestamps of the blocks which include the SendPacket event \
+                    and the AcknowledgePacket event of each packet. Milliseconds.")
+                .init(),
+
             fee_amounts: meter
                 .u64_counter("ics29_fee_amounts")
                 .with_description("Total amount received from ICS29 fees")
@@ -1062,6 +1416,170 @@ impl Default for TelemetryState {
                 .u64_observable_gauge("ics29_period_fees")
                 .with_description("Amount of ICS29 fees rewarded over the past 7 days")
                 .init(),
+
+            relayer_fees_paid: meter
+                .u64_counter("relayer_fees_paid")
+                .with_description("Total amount of fees paid for the transactions submitted by Hermes")
+                .init(),
+
+            relayer_incentives_earned: meter
+                .u64_counter("relayer_incentives_earned")
+                .with_description("Total amount of ICS29 incentives earned for the packets relayed by Hermes")
+                .init(),
+
+            unprofitable_packets_skipped: meter
+                .u64_counter("unprofitable_packets_skipped")
+                .with_description("Number of SendPacket events not relayed because relaying them was not profitable enough")
+                .init(),
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Each telemetry state replaces the global meter provider with which its instruments
+    // are created, so the tests creating one must not run concurrently.
+    static LOCK: Mutex<()> = Mutex::new(());
+
+    fn timestamp(millis: u64) -> Timestamp {
+        Timestamp::from_nanoseconds(millis * 1_000_000).unwrap()
+    }
+
+    /// The number of samples and their sum recorded by the latency histogram with the given name.
+    fn latency_samples(state: &TelemetryState, name: &str) -> (u64, f64) {
+        state
+            .gather()
+            .iter()
+            .filter(|family| family.get_name() == name)
+            .flat_map(|family| family.get_metric())
+            .fold((0, 0.0), |(count, sum), metric| {
+                let histogram = metric.get_histogram();
+                (
+                    count + histogram.get_sample_count(),
+                    sum + histogram.get_sample_sum(),
+                )
+            })
+    }
+
+    fn is_sent(state: &TelemetryState, seq_nr: u64, chain_id: &ChainId) -> bool {
+        let path_uid = PathIdentifier::new(
+            chain_id.to_string(),
+            ChannelId::default().to_string(),
+            PortId::transfer().to_string(),
+        );
+
+        state
+            .sent_packets
+            .get(&path_uid)
+            .map_or(false, |path_sent_packets| {
+                path_sent_packets.contains_key(&seq_nr)
+            })
+    }
+
+    #[test]
+    fn packet_latency_recv_and_ack() {
+        let _guard = LOCK.lock().unwrap_or_else(|e| e.into_inner());
+
+        let state = TelemetryState::default();
+        let (chain_a, chain_b) = (ChainId::from_string("a-0"), ChainId::from_string("b-0"));
+        let (channel_id, port_id) = (ChannelId::default(), PortId::transfer());
+
+        state.backlog_insert(
+            1,
+            &chain_a,
+            &channel_id,
+            &port_id,
+            &chain_b,
+            timestamp(1_000),
+        );
+        assert!(is_sent(&state, 1, &chain_a));
+
+        // The latency of a packet which was not observed being sent is not recorded
+        state.packet_latency_recv(
+            2,
+            &chain_a,
+            &channel_id,
+            &port_id,
+            &chain_b,
+            timestamp(2_000),
+        );
+
+        state.packet_latency_recv(
+            1,
+            &chain_a,
+            &channel_id,
+            &port_id,
+            &chain_b,
+            timestamp(3_500),
+        );
+        assert!(is_sent(&state, 1, &chain_a));
+
+        state.packet_latency_ack(
+            1,
+            &chain_a,
+            &channel_id,
+            &port_id,
+            &chain_b,
+            timestamp(7_000),
+        );
+        assert!(!is_sent(&state, 1, &chain_a));
+
+        // The packet is no longer tracked once acknowledged
+        state.packet_latency_ack(
+            1,
+            &chain_a,
+            &channel_id,
+            &port_id,
+            &chain_b,
+            timestamp(8_000),
+        );
+
+        // The metrics are collected at most once per collect period, so only once per test
+        assert_eq!(latency_samples(&state, "packet_latency_recv"), (1, 2_500.0));
+        assert_eq!(latency_samples(&state, "packet_latency_ack"), (1, 6_000.0));
+    }
+
+    #[test]
+    fn sent_packet_removed_on_timeout() {
+        let _guard = LOCK.lock().unwrap_or_else(|e| e.into_inner());
+
+        let state = TelemetryState::default();
+        let (chain_a, chain_b) = (ChainId::from_string("a-0"), ChainId::from_string("b-0"));
+        let (channel_id, port_id) = (ChannelId::default(), PortId::transfer());
+
+        state.backlog_insert(
+            1,
+            &chain_a,
+            &channel_id,
+            &port_id,
+            &chain_b,
+            timestamp(1_000),
+        );
+        state.backlog_insert(
+            2,
+            &chain_a,
+            &channel_id,
+            &port_id,
+            &chain_b,
+            timestamp(1_000),
+        );
+
+        state.backlog_remove(1, &chain_a, &channel_id, &port_id, &chain_b);
+        state.sent_packet_remove(1, &chain_a, &channel_id, &port_id);
+
+        assert!(!is_sent(&state, 1, &chain_a));
+        assert!(is_sent(&state, 2, &chain_a));
+
+        state.packet_latency_ack(
+            1,
+            &chain_a,
+            &channel_id,
+            &port_id,
+            &chain_b,
+            timestamp(5_000),
+        );
+        assert_eq!(latency_samples(&state, "packet_latency_ack"), (0, 0.0));
+    }
+}
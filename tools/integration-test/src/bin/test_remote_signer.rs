@@ -0,0 +1,62 @@
+/*!
+    A stand-in remote signer for manually testing Hermes with
+    `key_store_type = 'Remote'`.
+
+    The signer serves the keys of the given chain found in the plaintext
+    (`Test`) key store, and stays in the foreground until interrupted:
+
+    ```bash
+    $ cargo run --bin test_remote_signer -- /tmp/hermes-signer.sock $HOME/.hermes/keys ibc-0
+    ```
+
+    The chain can then be configured to sign its transactions via the signer with:
+
+    ```toml
+    key_store_type = 'Remote'
+    remote_signer = { socket = '/tmp/hermes-signer.sock' }
+    ```
+*/
+
+use std::env;
+use std::path::PathBuf;
+use std::thread;
+
+use ibc_relayer::keyring::{KeyRing, Store};
+use ibc_relayer_types::core::ics24_host::identifier::ChainId;
+use ibc_test_framework::error::{handle_generic_error, Error};
+use ibc_test_framework::util::remote_signer::spawn_remote_signer;
+
+fn main() -> Result<(), Error> {
+    let args = env::args().collect::<Vec<_>>();
+
+    let [_, socket, key_store_folder, chain_id] = &args[..] else {
+        eprintln!("usage: test_remote_signer <SOCKET> <KEY_STORE_FOLDER> <CHAIN_ID>");
+        std::process::exit(1);
+    };
+
+    let keyring = KeyRing::new_secp256k1(
+        Store::Test,
+        "",
+        &ChainId::from_string(chain_id),
+        &Some(PathBuf::from(key_store_folder)),
+        &None,
+    )
+    .map_err(handle_generic_error)?;
+
+    let keys = keyring
+        .keys()
+        .map_err(handle_generic_error)?
+        .into_iter()
+        .collect();
+
+    let handle = spawn_remote_signer(&PathBuf::from(socket), keys)?;
+
+    println!(
+        "stand-in remote signer listening on {}",
+        handle.socket().display()
+    );
+
+    loop {
+        thread::park();
+    }
+}
@@ -0,0 +1,102 @@
+//! Tests that the relayer can sign the transactions of a chain via a remote
+//! signer, when the chain is configured with `key_store_type = 'Remote'`.
+//!
+//! The test spawns a stand-in remote signer holding the relayer key of chain A,
+//! and then spawns the supervisor with chain A configured to use the remote
+//! signer. A token is then transferred from chain B to chain A, which requires
+//! the relayer to sign the `MsgRecvPacket` submitted on chain A remotely.
+
+use std::collections::BTreeMap;
+
+use ibc_relayer::keyring::remote::RemoteSignerConfig;
+use ibc_relayer::keyring::Store;
+use ibc_relayer::registry::SharedRegistry;
+use ibc_test_framework::prelude::*;
+use ibc_test_framework::util::random::{random_string, random_u128_range};
+use ibc_test_framework::util::remote_signer::spawn_remote_signer;
+
+#[test]
+fn test_remote_signer() -> Result<(), Error> {
+    run_binary_channel_test(&RemoteSignerTest)
+}
+
+pub struct RemoteSignerTest;
+
+impl TestOverrides for RemoteSignerTest {
+    fn should_spawn_supervisor(&self) -> bool {
+        false
+    }
+}
+
+impl BinaryChannelTest for RemoteSignerTest {
+    fn run<ChainA: ChainHandle, ChainB: ChainHandle>(
+        &self,
+        _config: &TestConfig,
+        relayer: RelayerDriver,
+        chains: ConnectedChains<ChainA, ChainB>,
+        channel: ConnectedChannel<ChainA, ChainB>,
+    ) -> Result<(), Error> {
+        let relayer_wallet_a = chains.node_a.wallets().relayer().cloned();
+
+        let socket = std::env::temp_dir().join(format!("hermes-signer-{}.sock", random_string()));
+
+        let signer = spawn_remote_signer(
+            &socket,
+            BTreeMap::from([(
+                relayer_wallet_a.value().id.0.clone(),
+                relayer_wallet_a.value().key.clone(),
+            )]),
+        )?;
+
+        let mut config = relayer.config.clone();
+
+        for chain in config.chains.iter_mut() {
+            if &chain.id == *chains.chain_id_a().value() {
+                chain.key_store_type = Store::Remote;
+                chain.remote_signer = Some(RemoteSignerConfig::new(socket.clone()));
+            }
+        }
+
+        let relayer = RelayerDriver {
+            registry: SharedRegistry::new(config.clone()),
+            config,
+            ..relayer
+        };
+
+        let denom_b = chains.node_b.denom();
+        let wallet_a = chains.node_a.wallets().user1().cloned();
+        let wallet_b = chains.node_b.wallets().user1().cloned();
+
+        let amount = denom_b.with_amount(random_u128_range(1000, 5000));
+
+        relayer.with_supervisor(|| {
+            info!(
+                "Performing IBC transfer with amount {} from chain B to chain A",
+                amount
+            );
+
+            chains.node_b.chain_driver().ibc_transfer_token(
+                &channel.port_b.as_ref(),
+                &channel.channel_id_b.as_ref(),
+                &wallet_b.as_ref(),
+                &wallet_a.address(),
+                &amount.as_ref(),
+            )?;
+
+            let amount_a =
+                amount.transfer(&channel.port_a.as_ref(), &channel.channel_id_a.as_ref())?;
+
+            chains
+                .node_a
+                .chain_driver()
+                .assert_eventual_wallet_amount(&wallet_a.address(), &amount_a.as_ref())?;
+
+            assert!(
+                signer.sign_count() > 0,
+                "expected the transactions of chain A to be signed by the remote signer"
+            );
+
+            Ok(())
+        })
+    }
+}
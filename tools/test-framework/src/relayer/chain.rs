@@ -23,6 +23,7 @@
 use crossbeam_channel as channel;
 use tracing::Span;
 
+use ibc_proto::cosmos::tx::v1beta1::Fee;
 use ibc_proto::ibc::apps::fee::v1::{
     QueryIncentivizedPacketRequest, QueryIncentivizedPacketResponse,
 };
@@ -50,6 +51,7 @@ use ibc_relayer_types::core::ics03_connection::version::Version;
 use ibc_relayer_types::core::ics04_channel::channel::ChannelEnd;
 use ibc_relayer_types::core::ics04_channel::channel::IdentifiedChannelEnd;
 use ibc_relayer_types::core::ics04_channel::packet::{PacketMsgType, Sequence};
+use ibc_relayer_types::core::ics04_channel::upgrade::{ErrorReceipt, Upgrade};
 use ibc_relayer_types::core::ics23_commitment::commitment::CommitmentPrefix;
 use ibc_relayer_types::core::ics23_commitment::merkle::MerkleProof;
 use ibc_relayer_types::core::ics24_host::identifier::ChainId;
@@ -105,10 +107,18 @@ where
         self.value().send_messages_and_wait_check_tx(tracked_msgs)
     }
 
+    fn estimate_tx_fee(&self, tracked_msgs: TrackedMsgs) -> Result<Fee, Error> {
+        self.value().estimate_tx_fee(tracked_msgs)
+    }
+
     fn get_signer(&self) -> Result<Signer, Error> {
         self.value().get_signer()
     }
 
+    fn select_signer(&self) -> Result<Signer, Error> {
+        self.value().select_signer()
+    }
+
     fn config(&self) -> Result<ChainConfig, Error> {
         self.value().config()
     }
@@ -117,6 +127,10 @@ where
         self.value().get_key()
     }
 
+    fn get_wallet_keys(&self) -> Result<Vec<(String, AnySigningKeyPair)>, Error> {
+        self.value().get_wallet_keys()
+    }
+
     fn add_key(&self, key_name: String, key: AnySigningKeyPair) -> Result<(), Error> {
         self.value().add_key(key_name, key)
     }
@@ -238,6 +252,22 @@ where
         self.value().query_channel(request, include_proof)
     }
 
+    fn query_upgrade(
+        &self,
+        request: QueryUpgradeRequest,
+        include_proof: IncludeProof,
+    ) -> Result<(Upgrade, Option<MerkleProof>), Error> {
+        self.value().query_upgrade(request, include_proof)
+    }
+
+    fn query_upgrade_error(
+        &self,
+        request: QueryUpgradeErrorRequest,
+        include_proof: IncludeProof,
+    ) -> Result<(ErrorReceipt, Option<MerkleProof>), Error> {
+        self.value().query_upgrade_error(request, include_proof)
+    }
+
     fn query_channel_client_state(
         &self,
         request: QueryChannelClientStateRequest,
@@ -308,6 +338,26 @@ where
             .build_channel_proofs(port_id, channel_id, height)
     }
 
+    fn build_channel_upgrade_proofs(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        height: Height,
+    ) -> Result<Proofs, Error> {
+        self.value()
+            .build_channel_upgrade_proofs(port_id, channel_id, height)
+    }
+
+    fn build_channel_upgrade_error_proofs(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        height: Height,
+    ) -> Result<(ErrorReceipt, Proofs), Error> {
+        self.value()
+            .build_channel_upgrade_error_proofs(port_id, channel_id, height)
+    }
+
     fn build_packet_proofs(
         &self,
         packet_type: PacketMsgType,
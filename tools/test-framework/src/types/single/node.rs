@@ -140,12 +140,15 @@ impl FullNode {
             rpc_addr: Url::from_str(&self.chain_driver.rpc_address())?,
             websocket_addr: WebSocketClientUrl::from_str(&self.chain_driver.websocket_address())?,
             grpc_addr: Url::from_str(&self.chain_driver.grpc_address())?,
+            endpoint_health_check_interval:
+                ibc_relayer::config::default::endpoint_health_check_interval(),
             rpc_timeout: ibc_relayer::config::default::rpc_timeout(),
             batch_delay: ibc_relayer::config::default::batch_delay(),
             trusted_node: false,
             genesis_restart: None,
             account_prefix: self.chain_driver.account_prefix.clone(),
             key_name: self.wallets.relayer.id.0.clone(),
+            key_names: Vec::new(),
             key_store_type: Store::Test,
             key_store_folder: Some(hermes_keystore_dir.into()),
             store_prefix: "ibc".to_string(),
@@ -163,11 +166,17 @@ impl FullNode {
             ccv_consumer_chain: false,
             trust_threshold: Default::default(),
             gas_price: config::GasPrice::new(0.003, "stake".to_string()),
+            dynamic_gas_price: Default::default(),
+            event_source: Default::default(),
             packet_filter: Default::default(),
+            excluded_sequences: Default::default(),
+            remote_signer: None,
             address_type: chain_type.address_type(),
             memo_prefix: Default::default(),
             proof_specs: Default::default(),
             extension_options: Default::default(),
+            backup_endpoints: Default::default(),
+            channel_overrides: Default::default(),
             sequential_batch_tx: false,
         })
     }
@@ -6,5 +6,6 @@ pub mod array;
 pub mod assert;
 pub mod file;
 pub mod random;
+pub mod remote_signer;
 pub mod retry;
 pub mod suspend;
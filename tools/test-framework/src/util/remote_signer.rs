@@ -0,0 +1,166 @@
+/*!
+   A stand-in remote signer, which holds keys in memory and serves them
+   over a Unix socket using the protocol described in
+   [`ibc_relayer::keyring::remote`].
+
+   This allows testing the relayer with `key_store_type = 'Remote'`
+   without running an actual signing daemon.
+*/
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::io::{BufRead, BufReader, Write};
+use std::os::unix::net::{UnixListener, UnixStream};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::thread;
+
+use ibc_relayer::keyring::remote::{RemoteSignerRequest, RemoteSignerResponse};
+use ibc_relayer::keyring::{Secp256k1KeyPair, SigningKeyPair};
+use tracing::{debug, warn};
+
+use crate::error::{handle_generic_error, Error};
+
+/**
+   Handle to a stand-in remote signer running in a background thread.
+
+   The signer is stopped and its socket removed when the handle is dropped.
+*/
+pub struct RemoteSignerHandle {
+    socket: PathBuf,
+    sign_count: Arc<AtomicUsize>,
+    stopped: Arc<AtomicBool>,
+}
+
+impl RemoteSignerHandle {
+    pub fn socket(&self) -> &Path {
+        &self.socket
+    }
+
+    /**
+       The number of sign requests which the signer has served successfully.
+    */
+    pub fn sign_count(&self) -> usize {
+        self.sign_count.load(Ordering::SeqCst)
+    }
+}
+
+impl Drop for RemoteSignerHandle {
+    fn drop(&mut self) {
+        self.stopped.store(true, Ordering::SeqCst);
+
+        // Wake up the signer thread blocked on `accept`
+        let _ = UnixStream::connect(&self.socket);
+        let _ = fs::remove_file(&self.socket);
+    }
+}
+
+/**
+   Spawn a stand-in remote signer listening on the given socket,
+   which signs with the given keys, indexed by key name.
+*/
+pub fn spawn_remote_signer(
+    socket: &Path,
+    keys: BTreeMap<String, Secp256k1KeyPair>,
+) -> Result<RemoteSignerHandle, Error> {
+    if socket.exists() {
+        fs::remove_file(socket)?;
+    }
+
+    let listener = UnixListener::bind(socket)?;
+
+    let sign_count = Arc::new(AtomicUsize::new(0));
+    let stopped = Arc::new(AtomicBool::new(false));
+
+    {
+        let sign_count = sign_count.clone();
+        let stopped = stopped.clone();
+
+        thread::spawn(move || {
+            for stream in listener.incoming() {
+                if stopped.load(Ordering::SeqCst) {
+                    break;
+                }
+
+                match stream {
+                    Ok(stream) => {
+                        if let Err(e) = serve_connection(stream, &keys, &sign_count) {
+                            warn!("stand-in remote signer failed to serve request: {e}");
+                        }
+                    }
+                    Err(e) => warn!("stand-in remote signer failed to accept connection: {e}"),
+                }
+            }
+        });
+    }
+
+    Ok(RemoteSignerHandle {
+        socket: socket.to_path_buf(),
+        sign_count,
+        stopped,
+    })
+}
+
+fn serve_connection(
+    stream: UnixStream,
+    keys: &BTreeMap<String, Secp256k1KeyPair>,
+    sign_count: &AtomicUsize,
+) -> Result<(), Error> {
+    let mut line = String::new();
+    BufReader::new(&stream).read_line(&mut line)?;
+
+    let response = match serde_json::from_str::<RemoteSignerRequest>(&line) {
+        Ok(request) => {
+            debug!("stand-in remote signer received request: {request:?}");
+            handle_request(request, keys, sign_count)
+        }
+        Err(e) => RemoteSignerResponse::Error(format!("invalid request: {e}")),
+    };
+
+    let mut response = serde_json::to_string(&response).map_err(handle_generic_error)?;
+    response.push('\n');
+
+    (&stream).write_all(response.as_bytes())?;
+
+    Ok(())
+}
+
+fn handle_request(
+    request: RemoteSignerRequest,
+    keys: &BTreeMap<String, Secp256k1KeyPair>,
+    sign_count: &AtomicUsize,
+) -> RemoteSignerResponse {
+    let get_key = |key_name: &str| {
+        keys.get(key_name)
+            .ok_or_else(|| RemoteSignerResponse::Error(format!("unknown key '{key_name}'")))
+    };
+
+    let response = match request {
+        RemoteSignerRequest::ListKeys => Ok(RemoteSignerResponse::KeyNames(
+            keys.keys().cloned().collect(),
+        )),
+
+        RemoteSignerRequest::GetKey { key_name } => {
+            get_key(&key_name).map(|key| RemoteSignerResponse::Key {
+                public_key: hex::encode(key.public_key.serialize()),
+                address: key.account(),
+            })
+        }
+
+        RemoteSignerRequest::Sign { key_name, sign_doc } => get_key(&key_name).and_then(|key| {
+            let sign_doc = hex::decode(sign_doc)
+                .map_err(|e| RemoteSignerResponse::Error(format!("invalid sign doc: {e}")))?;
+
+            let signature = key
+                .sign(&sign_doc)
+                .map_err(|e| RemoteSignerResponse::Error(e.to_string()))?;
+
+            sign_count.fetch_add(1, Ordering::SeqCst);
+
+            Ok(RemoteSignerResponse::Signature(hex::encode(signature)))
+        }),
+    };
+
+    response.unwrap_or_else(|error| error)
+}